@@ -36,10 +36,53 @@ impl TokenResponse {
     }
 }
 
+/// Options of a token request.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TokenRequestOptions {
+    claims: Option<String>,
+}
+
+impl TokenRequestOptions {
+    /// Create a new `TokenRequestOptions`. `default()` may also be used.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request a token with these additional claims, e.g. the claims of the challenge of a
+    /// service using [Continuous Access Evaluation](https://docs.microsoft.com/azure/active-directory/conditional-access/concept-continuous-access-evaluation)
+    /// which rejected the previous token.
+    ///
+    /// A token with claims is never returned from a cache, as the challenge means the cached
+    /// tokens are no longer accepted.
+    #[must_use]
+    pub fn claims(mut self, claims: impl Into<String>) -> Self {
+        self.claims = Some(claims.into());
+        self
+    }
+
+    /// The additional claims of the token, as a JSON object.
+    pub fn get_claims(&self) -> Option<&str> {
+        self.claims.as_deref()
+    }
+}
+
 /// Represents a credential capable of providing an OAuth token.
 #[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
 #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
 pub trait TokenCredential: Send + Sync {
     /// Gets a `TokenResponse` for the specified resource
     async fn get_token(&self, resource: &str) -> crate::Result<TokenResponse>;
+
+    /// Gets a `TokenResponse` for the specified resource, with the options of the request.
+    ///
+    /// Credentials which do not support the options return the token of
+    /// [`get_token`](TokenCredential::get_token).
+    async fn get_token_with_options(
+        &self,
+        resource: &str,
+        options: &TokenRequestOptions,
+    ) -> crate::Result<TokenResponse> {
+        let _ = options;
+        self.get_token(resource).await
+    }
 }
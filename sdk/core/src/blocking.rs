@@ -0,0 +1,79 @@
+//! Utilities to call the SDK from synchronous code.
+//!
+//! The clients of the SDK are asynchronous. This module runs their futures on a runtime owned by
+//! the SDK, so that command line tools and other synchronous code bases don't have to create and
+//! manage one themselves. The runtime is started on first use and shared by the whole process.
+//!
+//! ```no_run
+//! # async fn get_secret() -> azure_core::Result<String> { Ok(String::new()) }
+//! let secret = azure_core::blocking::block_on(get_secret())?;
+//! # Ok::<(), azure_core::Error>(())
+//! ```
+use futures::{Stream, StreamExt};
+use once_cell::sync::Lazy;
+use std::future::Future;
+use std::pin::Pin;
+use tokio::runtime::{Builder, Runtime};
+
+static RUNTIME: Lazy<Runtime> = Lazy::new(|| {
+    Builder::new_multi_thread()
+        .enable_all()
+        .thread_name("azure-sdk-blocking")
+        .build()
+        .expect("failed to start the runtime of the blocking clients")
+});
+
+/// Runs `future` to completion, blocking the current thread.
+///
+/// # Panics
+///
+/// Panics when called from an asynchronous context, where the future should be awaited instead.
+pub fn block_on<F>(future: F) -> F::Output
+where
+    F: Future,
+{
+    RUNTIME.block_on(future)
+}
+
+/// Turns `stream`, for example a [`Pageable`](crate::Pageable), into a blocking iterator.
+///
+/// The same restrictions as for [`block_on`] apply when calling `next` on the iterator.
+pub fn iter<S>(stream: S) -> BlockingIter<S::Item>
+where
+    S: Stream + Send + 'static,
+{
+    BlockingIter {
+        stream: Box::pin(stream),
+    }
+}
+
+/// An iterator over the items of a stream, returned by [`iter`].
+pub struct BlockingIter<T> {
+    stream: Pin<Box<dyn Stream<Item = T> + Send>>,
+}
+
+impl<T> Iterator for BlockingIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        block_on(self.stream.next())
+    }
+}
+
+impl<T> std::fmt::Debug for BlockingIter<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BlockingIter").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_futures_and_streams() {
+        assert_eq!(block_on(async { 42 }), 42);
+        let items: Vec<_> = iter(futures::stream::iter(vec![1, 2, 3])).collect();
+        assert_eq!(items, vec![1, 2, 3]);
+    }
+}
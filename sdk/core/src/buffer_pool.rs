@@ -0,0 +1,147 @@
+use once_cell::sync::Lazy;
+use std::ops::{Deref, DerefMut};
+use std::sync::Mutex;
+
+/// A pool of reusable scratch buffers.
+///
+/// Streaming large bodies reads them in chunks, and allocating and zeroing a new buffer for each
+/// chunk puts a lot of pressure on the allocator for multi-GB transfers. A buffer acquired from
+/// the pool is only zeroed when it is first allocated, and it goes back to the pool when it is
+/// dropped. The bytes read in a buffer are copied out of it, so that it is never shared and can
+/// always be reused.
+///
+/// ```
+/// use azure_core::BufferPool;
+/// use bytes::Bytes;
+/// use std::io::Read;
+///
+/// let mut reader = &b"chunk"[..];
+/// let mut buffer = BufferPool::global().acquire();
+/// let read = reader.read(&mut buffer).unwrap();
+/// let chunk = Bytes::copy_from_slice(&buffer[..read]);
+/// # assert_eq!(chunk, "chunk");
+/// ```
+#[derive(Debug)]
+pub struct BufferPool {
+    buffers: Mutex<Vec<Box<[u8]>>>,
+    buffer_size: usize,
+    max_buffers: usize,
+}
+
+static GLOBAL: Lazy<BufferPool> = Lazy::new(|| {
+    BufferPool::new(
+        BufferPool::DEFAULT_BUFFER_SIZE,
+        BufferPool::DEFAULT_MAX_BUFFERS,
+    )
+});
+
+impl BufferPool {
+    /// The size of the buffers of the global pool, 64KiB.
+    pub const DEFAULT_BUFFER_SIZE: usize = 64 * 1024;
+    /// The number of buffers kept by the global pool.
+    pub const DEFAULT_MAX_BUFFERS: usize = 64;
+
+    /// Creates a pool of buffers of `buffer_size` bytes, keeping at most `max_buffers` of them.
+    pub fn new(buffer_size: usize, max_buffers: usize) -> Self {
+        Self {
+            buffers: Mutex::new(Vec::new()),
+            buffer_size,
+            max_buffers,
+        }
+    }
+
+    /// The pool shared by the transports and the chunked transfers of the SDK.
+    pub fn global() -> &'static BufferPool {
+        &GLOBAL
+    }
+
+    /// The size of the acquired buffers.
+    pub fn buffer_size(&self) -> usize {
+        self.buffer_size
+    }
+
+    /// Acquires a buffer of [`buffer_size`](Self::buffer_size) bytes, returned to the pool when
+    /// it is dropped. Its content is whatever was last read in it.
+    pub fn acquire(&self) -> PooledBuffer<'_> {
+        let buffer = self
+            .lock()
+            .pop()
+            .unwrap_or_else(|| vec![0; self.buffer_size].into_boxed_slice());
+        PooledBuffer {
+            buffer: Some(buffer),
+            pool: self,
+        }
+    }
+
+    fn release(&self, buffer: Box<[u8]>) {
+        let mut buffers = self.lock();
+        if buffers.len() < self.max_buffers {
+            buffers.push(buffer);
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<Box<[u8]>>> {
+        self.buffers.lock().expect("buffer pool lock poisoned")
+    }
+}
+
+/// A buffer of a [`BufferPool`], returned to the pool when it is dropped.
+#[derive(Debug)]
+pub struct PooledBuffer<'a> {
+    buffer: Option<Box<[u8]>>,
+    pool: &'a BufferPool,
+}
+
+impl Deref for PooledBuffer<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.buffer
+            .as_deref()
+            .expect("the buffer is only taken on drop")
+    }
+}
+
+impl DerefMut for PooledBuffer<'_> {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        self.buffer
+            .as_deref_mut()
+            .expect("the buffer is only taken on drop")
+    }
+}
+
+impl Drop for PooledBuffer<'_> {
+    fn drop(&mut self) {
+        if let Some(buffer) = self.buffer.take() {
+            self.pool.release(buffer);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reuses_dropped_buffers() {
+        let pool = BufferPool::new(16, 1);
+        let mut buffer = pool.acquire();
+        assert_eq!(buffer.len(), 16);
+        buffer[..10].copy_from_slice(b"0123456789");
+        let address = buffer.as_ptr();
+        drop(buffer);
+
+        // the buffer is not zeroed again
+        let buffer = pool.acquire();
+        assert_eq!(buffer.as_ptr(), address);
+        assert_eq!(&buffer[..10], b"0123456789");
+
+        // the pool keeps at most `max_buffers` buffers
+        let other = pool.acquire();
+        assert_ne!(other.as_ptr(), address);
+        assert_eq!(&other[..], &[0; 16]);
+        drop(buffer);
+        drop(other);
+        assert_eq!(pool.lock().len(), 1);
+    }
+}
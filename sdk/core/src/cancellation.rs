@@ -0,0 +1,155 @@
+use crate::error::{Error, ErrorKind};
+use futures::future::{select, Either};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// A token to cooperatively cancel operations.
+///
+/// Insert a clone of the token in the [`Context`](crate::Context) passed to an operation and call
+/// [`cancel`](Self::cancel) to stop it: the pending HTTP attempt is abandoned, no further retries
+/// are made and pollers stop polling. The operation then returns an error of kind
+/// [`ErrorKind::Cancelled`], which gives the caller a chance to clean up (for example to abort a
+/// copy or to commit the blocks already uploaded) rather than dropping the future.
+///
+/// ```
+/// use azure_core::{CancellationToken, Context};
+///
+/// let token = CancellationToken::new();
+/// let mut context = Context::new();
+/// context.insert(token.clone());
+/// // later, from another task
+/// token.cancel();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    next_id: AtomicUsize,
+    wakers: Mutex<HashMap<usize, Waker>>,
+}
+
+impl CancellationToken {
+    /// Creates a token which is not cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the operations using this token, or any of its clones.
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        let wakers = std::mem::take(&mut *self.lock_wakers());
+        for waker in wakers.into_values() {
+            waker.wake();
+        }
+    }
+
+    /// Returns `true` if the token was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Fails with an [`ErrorKind::Cancelled`] error if the token was cancelled.
+    pub fn ensure_not_cancelled(&self) -> crate::Result<()> {
+        if self.is_cancelled() {
+            return Err(cancelled_error());
+        }
+        Ok(())
+    }
+
+    /// A future completing once the token is cancelled.
+    pub fn cancelled(&self) -> Cancelled {
+        Cancelled {
+            token: self.clone(),
+            id: self.inner.next_id.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+
+    /// Runs `future` until it completes or the token is cancelled, whichever comes first.
+    pub async fn run<F, T>(&self, future: F) -> crate::Result<T>
+    where
+        F: Future<Output = crate::Result<T>>,
+    {
+        self.ensure_not_cancelled()?;
+        futures::pin_mut!(future);
+        match select(future, self.cancelled()).await {
+            Either::Left((result, _)) => result,
+            Either::Right(_) => Err(cancelled_error()),
+        }
+    }
+
+    fn lock_wakers(&self) -> std::sync::MutexGuard<'_, HashMap<usize, Waker>> {
+        self.inner
+            .wakers
+            .lock()
+            .expect("cancellation token lock poisoned")
+    }
+}
+
+fn cancelled_error() -> Error {
+    Error::message(ErrorKind::Cancelled, "the operation was cancelled")
+}
+
+/// A future completing once a [`CancellationToken`] is cancelled.
+#[derive(Debug)]
+pub struct Cancelled {
+    token: CancellationToken,
+    id: usize,
+}
+
+impl Future for Cancelled {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+        self.token.lock_wakers().insert(self.id, cx.waker().clone());
+        // the token may have been cancelled before the waker was registered
+        if self.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for Cancelled {
+    fn drop(&mut self) {
+        self.token.lock_wakers().remove(&self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    #[test]
+    fn cancels_pending_futures() {
+        let token = CancellationToken::new();
+        let canceller = token.clone();
+        let result = block_on(token.run(async {
+            canceller.cancel();
+            futures::future::pending::<crate::Result<()>>().await
+        }));
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::Cancelled);
+        assert!(token.lock_wakers().is_empty());
+    }
+
+    #[test]
+    fn runs_futures_to_completion() {
+        let token = CancellationToken::new();
+        assert_eq!(block_on(token.run(async { Ok(42) })).unwrap(), 42);
+
+        token.cancel();
+        assert!(block_on(token.run(async { Ok(42) })).is_err());
+        block_on(token.cancelled());
+    }
+}
@@ -0,0 +1,170 @@
+use crate::error::{Error, ErrorKind};
+use crate::resource_manager_endpoint;
+use std::borrow::Cow;
+use std::str::FromStr;
+
+/// A well-known Azure cloud.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AzureCloud {
+    /// The Azure public cloud.
+    Public,
+    /// The Azure US Government cloud.
+    UsGovernment,
+    /// The Azure China cloud, operated by 21Vianet.
+    China,
+}
+
+impl AzureCloud {
+    /// The endpoints of the cloud.
+    pub fn configuration(self) -> CloudConfiguration {
+        match self {
+            AzureCloud::Public => CloudConfiguration {
+                authority_host: Cow::Borrowed("https://login.microsoftonline.com"),
+                resource_manager_endpoint: Cow::Borrowed(
+                    resource_manager_endpoint::AZURE_PUBLIC_CLOUD,
+                ),
+                storage_endpoint_suffix: Cow::Borrowed("core.windows.net"),
+                storage_audience: Cow::Borrowed("https://storage.azure.com"),
+                keyvault_endpoint_suffix: Cow::Borrowed("vault.azure.net"),
+                keyvault_audience: Cow::Borrowed("https://vault.azure.net"),
+                cosmos_endpoint_suffix: Cow::Borrowed("documents.azure.com"),
+            },
+            AzureCloud::UsGovernment => CloudConfiguration {
+                authority_host: Cow::Borrowed("https://login.microsoftonline.us"),
+                resource_manager_endpoint: Cow::Borrowed(
+                    resource_manager_endpoint::AZURE_US_GOVERNMENT_CLOUD,
+                ),
+                storage_endpoint_suffix: Cow::Borrowed("core.usgovcloudapi.net"),
+                storage_audience: Cow::Borrowed("https://storage.azure.com"),
+                keyvault_endpoint_suffix: Cow::Borrowed("vault.usgovcloudapi.net"),
+                keyvault_audience: Cow::Borrowed("https://vault.usgovcloudapi.net"),
+                cosmos_endpoint_suffix: Cow::Borrowed("documents.azure.us"),
+            },
+            AzureCloud::China => CloudConfiguration {
+                authority_host: Cow::Borrowed("https://login.chinacloudapi.cn"),
+                resource_manager_endpoint: Cow::Borrowed(
+                    resource_manager_endpoint::AZURE_CHINA_CLOUD,
+                ),
+                storage_endpoint_suffix: Cow::Borrowed("core.chinacloudapi.cn"),
+                storage_audience: Cow::Borrowed("https://storage.azure.com"),
+                keyvault_endpoint_suffix: Cow::Borrowed("vault.azure.cn"),
+                keyvault_audience: Cow::Borrowed("https://vault.azure.cn"),
+                cosmos_endpoint_suffix: Cow::Borrowed("documents.azure.cn"),
+            },
+        }
+    }
+}
+
+impl Default for AzureCloud {
+    fn default() -> Self {
+        AzureCloud::Public
+    }
+}
+
+impl FromStr for AzureCloud {
+    type Err = Error;
+
+    /// Parses the names used by the Azure CLI, e.g. `AzureUSGovernment`.
+    fn from_str(s: &str) -> crate::Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "azurecloud" | "public" => Ok(AzureCloud::Public),
+            "azureusgovernment" | "usgovernment" => Ok(AzureCloud::UsGovernment),
+            "azurechinacloud" | "china" => Ok(AzureCloud::China),
+            _ => Err(Error::with_message(ErrorKind::DataConversion, || {
+                format!("unknown Azure cloud: {s}")
+            })),
+        }
+    }
+}
+
+/// The endpoints and audiences of an Azure cloud.
+///
+/// Use [`AzureCloud`] for the well-known clouds, or fill in the fields for an Azure Stack or other
+/// private cloud.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CloudConfiguration {
+    /// The Azure Active Directory authority host, e.g. `https://login.microsoftonline.com`, of the
+    /// `TokenCredentialOptions` of `azure_identity` created from the cloud.
+    pub authority_host: Cow<'static, str>,
+    /// The Azure Resource Manager endpoint, e.g. `https://management.azure.com`, which is also the
+    /// audience of its tokens.
+    pub resource_manager_endpoint: Cow<'static, str>,
+    /// The suffix of the storage account endpoints, e.g. `core.windows.net`.
+    pub storage_endpoint_suffix: Cow<'static, str>,
+    /// The audience of the storage tokens, requested by the storage clients of the cloud.
+    pub storage_audience: Cow<'static, str>,
+    /// The suffix of the Key Vault endpoints, e.g. `vault.azure.net`.
+    pub keyvault_endpoint_suffix: Cow<'static, str>,
+    /// The audience of the Key Vault tokens, requested by the Key Vault clients of the cloud.
+    pub keyvault_audience: Cow<'static, str>,
+    /// The suffix of the Cosmos DB account endpoints, e.g. `documents.azure.com`.
+    pub cosmos_endpoint_suffix: Cow<'static, str>,
+}
+
+impl CloudConfiguration {
+    /// The URL of a storage account service, where `subdomain` is e.g. `blob` or `queue`.
+    pub fn storage_account_url(&self, account: &str, subdomain: &str) -> String {
+        format!(
+            "https://{account}.{subdomain}.{}",
+            self.storage_endpoint_suffix
+        )
+    }
+
+    /// The URL of a key vault.
+    pub fn keyvault_url(&self, vault_name: &str) -> String {
+        format!("https://{vault_name}.{}", self.keyvault_endpoint_suffix)
+    }
+
+    /// The URL of a Cosmos DB account.
+    pub fn cosmos_account_url(&self, account: &str) -> String {
+        format!("https://{account}.{}", self.cosmos_endpoint_suffix)
+    }
+
+    /// The scope to request tokens for `audience`, e.g. `https://vault.azure.net/.default`.
+    pub fn scope(audience: &str) -> String {
+        format!("{}/.default", audience.trim_end_matches('/'))
+    }
+}
+
+impl Default for CloudConfiguration {
+    fn default() -> Self {
+        AzureCloud::Public.configuration()
+    }
+}
+
+impl From<AzureCloud> for CloudConfiguration {
+    fn from(cloud: AzureCloud) -> Self {
+        cloud.configuration()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_cloud_urls() {
+        let cloud = CloudConfiguration::from(AzureCloud::UsGovernment);
+        assert_eq!(
+            cloud.storage_account_url("myaccount", "blob"),
+            "https://myaccount.blob.core.usgovcloudapi.net"
+        );
+        assert_eq!(
+            cloud.keyvault_url("myvault"),
+            "https://myvault.vault.usgovcloudapi.net"
+        );
+        assert_eq!(
+            CloudConfiguration::scope(&cloud.resource_manager_endpoint),
+            "https://management.usgovcloudapi.net/.default"
+        );
+        assert_eq!(
+            CloudConfiguration::default().cosmos_account_url("myaccount"),
+            "https://myaccount.documents.azure.com"
+        );
+        assert_eq!(
+            "AzureChinaCloud".parse::<AzureCloud>().unwrap(),
+            AzureCloud::China
+        );
+        assert!("AzureGermanCloud".parse::<AzureCloud>().is_err());
+    }
+}
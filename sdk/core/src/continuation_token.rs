@@ -0,0 +1,162 @@
+use crate::error::{Error, ErrorKind};
+use crate::headers::Header;
+use crate::prelude::Continuation;
+use crate::request_options::{NextMarker, Range};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The continuation state of a listing which can be persisted, e.g. to resume listing blobs or
+/// querying documents after a process restart.
+///
+/// ```
+/// use azure_core::{prelude::NextMarker, ContinuationToken};
+///
+/// let marker = NextMarker::new("2!72!MDAwMDE0IWJsb2I".to_owned());
+/// let saved = marker.to_continuation_string();
+/// assert_eq!(NextMarker::from_continuation_string(&saved).unwrap(), marker);
+/// ```
+///
+/// The listings accepting a `continuation` resume from the restored token, and the opaque
+/// tokens, [`NextMarker`] and [`Continuation`], can also be serialized with serde as part of a
+/// larger checkpoint.
+///
+/// # Empty tokens
+///
+/// The services end a listing with an empty token, or with no token at all, so the empty string
+/// is never a token: it is the continuation string of `None`, the state of a listing with
+/// nothing left, and `from_continuation_string` fails on it for every token type. An empty
+/// token, e.g. `Some(NextMarker::new(String::new()))`, is saved as the end of the listing too.
+pub trait ContinuationToken: Sized {
+    /// Serializes the token into an opaque string.
+    fn to_continuation_string(&self) -> String;
+
+    /// Restores a token serialized with [`ContinuationToken::to_continuation_string`].
+    ///
+    /// Fails on the empty string, which is not a token.
+    fn from_continuation_string(s: &str) -> crate::Result<Self>;
+}
+
+/// Fails on the empty continuation string, which marks the end of a listing.
+fn ensure_not_empty(s: &str) -> crate::Result<&str> {
+    if s.is_empty() {
+        Err(Error::message(
+            ErrorKind::DataConversion,
+            "an empty continuation string is the end of a listing, not a continuation token",
+        ))
+    } else {
+        Ok(s)
+    }
+}
+
+impl ContinuationToken for NextMarker {
+    fn to_continuation_string(&self) -> String {
+        self.as_str().to_owned()
+    }
+
+    fn from_continuation_string(s: &str) -> crate::Result<Self> {
+        Ok(NextMarker::new(ensure_not_empty(s)?.to_owned()))
+    }
+}
+
+impl ContinuationToken for Continuation {
+    fn to_continuation_string(&self) -> String {
+        self.value().as_str().to_owned()
+    }
+
+    fn from_continuation_string(s: &str) -> crate::Result<Self> {
+        Ok(Continuation::new(ensure_not_empty(s)?.to_owned()))
+    }
+}
+
+/// Serialized as its continuation string, like [`NextMarker`].
+impl Serialize for Continuation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_continuation_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Continuation {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Continuation::new)
+    }
+}
+
+impl ContinuationToken for Range {
+    fn to_continuation_string(&self) -> String {
+        self.to_string()
+    }
+
+    fn from_continuation_string(s: &str) -> crate::Result<Self> {
+        ensure_not_empty(s)?.parse()
+    }
+}
+
+/// `None`, the end of the listing, is the empty string, and so is an empty token.
+impl<T: ContinuationToken> ContinuationToken for Option<T> {
+    fn to_continuation_string(&self) -> String {
+        self.as_ref()
+            .map(ContinuationToken::to_continuation_string)
+            .unwrap_or_default()
+    }
+
+    fn from_continuation_string(s: &str) -> crate::Result<Self> {
+        if s.is_empty() {
+            Ok(None)
+        } else {
+            T::from_continuation_string(s).map(Some)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip<T: ContinuationToken>(token: &T) -> T {
+        T::from_continuation_string(&token.to_continuation_string()).unwrap()
+    }
+
+    #[test]
+    fn round_trips_continuation_tokens() {
+        let marker = NextMarker::new("marker".to_owned());
+        assert_eq!(round_trip(&marker), marker);
+        assert_eq!(
+            round_trip(&Continuation::new("+RID:~abc")).value(),
+            Continuation::new("+RID:~abc").value()
+        );
+        assert_eq!(round_trip(&Range::new(0, 1024)), Range::new(0, 1024));
+        assert_eq!(round_trip(&None::<NextMarker>), None);
+        let marker = Some(NextMarker::new("marker".to_owned()));
+        assert_eq!(round_trip(&marker), marker);
+    }
+
+    #[test]
+    fn the_empty_string_is_the_end_of_the_listing() {
+        assert!(NextMarker::from_continuation_string("").is_err());
+        assert!(Continuation::from_continuation_string("").is_err());
+        assert!(Range::from_continuation_string("").is_err());
+
+        assert_eq!(None::<NextMarker>.to_continuation_string(), "");
+        assert_eq!(
+            Option::<NextMarker>::from_continuation_string("").unwrap(),
+            None
+        );
+        // an empty token ends the listing as well
+        let empty = Some(NextMarker::new(String::new()));
+        assert_eq!(empty.to_continuation_string(), "");
+        assert_eq!(round_trip(&empty), None);
+    }
+
+    #[test]
+    fn serializes_opaque_tokens_as_strings() {
+        let marker = NextMarker::new("marker".to_owned());
+        let json = serde_json::to_string(&Some(marker.clone())).unwrap();
+        assert_eq!(json, r#""marker""#);
+        assert_eq!(
+            serde_json::from_str::<Option<NextMarker>>(&json).unwrap(),
+            Some(marker)
+        );
+
+        let continuation: Continuation = serde_json::from_str(r#""+RID:~abc""#).unwrap();
+        assert_eq!(continuation.value().as_str(), "+RID:~abc");
+    }
+}
@@ -0,0 +1,153 @@
+use crate::error::{Error, ErrorKind};
+use crate::sleep::sleep;
+use futures::future::{select, Either};
+use futures::Future;
+use std::time::Duration;
+use time::OffsetDateTime;
+
+/// A point in time after which a request should no longer be attempted.
+///
+/// Insert a `Deadline` in the [`Context`](crate::Context) passed to an operation to bound the
+/// total time spent on it, retries included. Once the deadline has expired no further attempts
+/// are made and the pipeline returns an error of kind [`ErrorKind::Timeout`].
+///
+/// ```
+/// use azure_core::{Context, Deadline};
+/// use std::time::Duration;
+///
+/// let mut context = Context::new();
+/// context.insert(Deadline::after(Duration::from_secs(30)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Deadline(OffsetDateTime);
+
+impl Deadline {
+    /// Creates a deadline expiring at the given point in time.
+    pub fn new(at: OffsetDateTime) -> Self {
+        Self(at)
+    }
+
+    /// Creates a deadline expiring once `duration` has elapsed from now.
+    pub fn after(duration: Duration) -> Self {
+        Self(OffsetDateTime::now_utc() + duration)
+    }
+
+    /// The point in time at which the deadline expires.
+    pub fn at(&self) -> OffsetDateTime {
+        self.0
+    }
+
+    /// The time left before the deadline expires. Zero if it has already expired.
+    pub fn remaining(&self) -> Duration {
+        (self.0 - OffsetDateTime::now_utc())
+            .try_into()
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if the deadline has expired.
+    pub fn is_expired(&self) -> bool {
+        OffsetDateTime::now_utc() >= self.0
+    }
+
+    /// Fails with an [`ErrorKind::Timeout`] error if the deadline has expired.
+    pub(crate) fn ensure_not_expired(&self) -> crate::Result<()> {
+        if self.is_expired() {
+            return Err(Error::with_message(ErrorKind::Timeout, || {
+                format!("the request deadline ({}) expired", self.0)
+            }));
+        }
+        Ok(())
+    }
+
+    /// Runs `future` until it completes or the deadline expires, whichever comes first.
+    ///
+    /// Fails with an [`ErrorKind::Timeout`] error if the deadline expires first, in which case
+    /// `future` is dropped. Otherwise the timer of the deadline is dropped, and stops, as soon as
+    /// `future` completes.
+    pub(crate) async fn run<F>(&self, future: F) -> crate::Result<F::Output>
+    where
+        F: Future,
+    {
+        self.ensure_not_expired()?;
+        futures::pin_mut!(future);
+        let expiry = sleep(self.remaining());
+        futures::pin_mut!(expiry);
+        match select(future, expiry).await {
+            Either::Left((output, _)) => Ok(output),
+            Either::Right(_) => Err(Error::with_message(ErrorKind::Timeout, || {
+                format!("the request deadline ({}) expired", self.0)
+            })),
+        }
+    }
+}
+
+impl From<OffsetDateTime> for Deadline {
+    fn from(at: OffsetDateTime) -> Self {
+        Self::new(at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn future_deadline_is_not_expired() {
+        let deadline = Deadline::after(Duration::from_secs(60));
+        assert!(!deadline.is_expired());
+        assert!(deadline.remaining() > Duration::from_secs(50));
+        deadline.ensure_not_expired().unwrap();
+    }
+
+    #[test]
+    fn past_deadline_is_expired() {
+        let deadline = Deadline::new(OffsetDateTime::now_utc() - time::Duration::seconds(1));
+        assert!(deadline.is_expired());
+        assert_eq!(deadline.remaining(), Duration::ZERO);
+        assert_eq!(
+            deadline.ensure_not_expired().unwrap_err().kind(),
+            &ErrorKind::Timeout
+        );
+    }
+
+    #[test]
+    fn run_completes_before_the_deadline() {
+        let deadline = Deadline::after(Duration::from_secs(60));
+        let output = futures::executor::block_on(deadline.run(async { 42 })).unwrap();
+        assert_eq!(output, 42);
+    }
+
+    #[test]
+    fn run_is_not_interrupted_by_the_wake_ups_of_the_future() {
+        /// Yields `count` times, waking the task every time.
+        struct Yield(u32);
+
+        impl Future for Yield {
+            type Output = u32;
+
+            fn poll(
+                mut self: std::pin::Pin<&mut Self>,
+                cx: &mut std::task::Context<'_>,
+            ) -> std::task::Poll<u32> {
+                if self.0 == 0 {
+                    return std::task::Poll::Ready(42);
+                }
+                self.0 -= 1;
+                cx.waker().wake_by_ref();
+                std::task::Poll::Pending
+            }
+        }
+
+        let deadline = Deadline::after(Duration::from_secs(60));
+        let output = futures::executor::block_on(deadline.run(Yield(10))).unwrap();
+        assert_eq!(output, 42);
+    }
+
+    #[test]
+    fn run_stops_at_the_deadline() {
+        let deadline = Deadline::after(Duration::from_millis(50));
+        let error = futures::executor::block_on(deadline.run(futures::future::pending::<()>()))
+            .unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::Timeout);
+    }
+}
@@ -50,6 +50,21 @@ impl HttpError {
     pub fn error_message(&self) -> Option<&str> {
         self.details.message.as_deref()
     }
+
+    /// Get the value of a header of the response, such as `x-ms-request-id`.
+    pub fn header(&self, name: &headers::HeaderName) -> Option<&str> {
+        self.headers.get(name.as_str()).map(String::as_str)
+    }
+
+    /// Get the headers of the response.
+    pub fn headers(&self) -> &HashMap<String, String> {
+        &self.headers
+    }
+
+    /// Get the body of the response.
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
 }
 
 impl std::fmt::Display for HttpError {
@@ -66,6 +81,9 @@ impl std::fmt::Display for HttpError {
                 .as_deref()
                 .unwrap_or("<unknown error code>")
         )?;
+        if let Some(message) = &self.details.message {
+            write!(f, "{tab}Message: \"{message}\",{newline}")?;
+        }
         // TODO: sanitize body
         write!(f, "{tab}Body: \"{:?}\",{newline}", self.body)?;
         write!(f, "{tab}Headers: [{newline}")?;
@@ -81,7 +99,7 @@ impl std::fmt::Display for HttpError {
 
 impl std::error::Error for HttpError {}
 
-#[derive(Debug)]
+#[derive(Debug, Default, PartialEq, Eq)]
 struct ErrorDetails {
     code: Option<String>,
     message: Option<String>,
@@ -89,10 +107,61 @@ struct ErrorDetails {
 
 impl ErrorDetails {
     fn new(headers: &HashMap<String, String>, body: &[u8]) -> Self {
-        let mut code = get_error_code_from_header(headers);
-        code = code.or_else(|| get_error_code_from_body(body));
-        let message = get_error_message_from_body(body);
-        Self { code, message }
+        let details = Self::from_body(body);
+        Self {
+            code: get_error_code_from_header(headers).or(details.code),
+            message: details.message,
+        }
+    }
+
+    fn from_body(body: &[u8]) -> Self {
+        if let Some(details) = Self::from_json(body) {
+            return details;
+        }
+        #[cfg(feature = "xml")]
+        if let Some(details) = Self::from_xml(body) {
+            return details;
+        }
+        Self::default()
+    }
+
+    /// Reads the JSON error payloads: `{"error": {"code", "message"}}` as described in the
+    /// [Azure REST API guidelines](https://github.com/microsoft/api-guidelines/blob/vNext/azure/Guidelines.md#handling-errors),
+    /// a top level `code` and `message`, or the OData `{"odata.error": {"code", "message": {"value"}}}`
+    /// of the Table service.
+    fn from_json(body: &[u8]) -> Option<Self> {
+        let json = serde_json::from_slice::<serde_json::Value>(body).ok()?;
+        let error = json
+            .get("error")
+            .or_else(|| json.get("odata.error"))
+            .unwrap_or(&json);
+        let code = error.get("code").and_then(|c| c.as_str());
+        let message = error.get("message").and_then(|m| {
+            m.as_str()
+                .or_else(|| m.get("value").and_then(|v| v.as_str()))
+        });
+        Some(Self {
+            code: code.map(ToOwned::to_owned),
+            message: message.map(ToOwned::to_owned),
+        })
+    }
+
+    /// Reads the XML error payload of the storage services:
+    /// `<Error><Code>..</Code><Message>..</Message></Error>`.
+    #[cfg(feature = "xml")]
+    fn from_xml(body: &[u8]) -> Option<Self> {
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct XmlError {
+            code: Option<String>,
+            message: Option<String>,
+        }
+
+        let error: XmlError = crate::xml::read_xml(body).ok()?;
+        Some(Self {
+            code: error.code,
+            message: error.message.map(|m| m.trim().to_owned()),
+        })
     }
 }
 
@@ -107,20 +176,60 @@ fn get_error_code_from_header(headers: &HashMap<String, String>) -> Option<Strin
 ///
 /// For more info, see [here](https://github.com/microsoft/api-guidelines/blob/vNext/azure/Guidelines.md#handling-errors)
 pub(crate) fn get_error_code_from_body(body: &[u8]) -> Option<String> {
-    let json = serde_json::from_slice::<serde_json::Value>(body).ok()?;
-    let nested = || json.get("error")?.get("code")?.as_str();
-    let top_level = || json.get("code")?.as_str();
-    let code = nested().or_else(top_level);
-    code.map(|c| c.to_owned())
+    ErrorDetails::from_body(body).code
 }
 
-/// Gets the error message if it's present in the body
-///
-/// For more info, see [here](https://github.com/microsoft/api-guidelines/blob/vNext/azure/Guidelines.md#handling-errors)
-pub(crate) fn get_error_message_from_body(body: &[u8]) -> Option<String> {
-    let json = serde_json::from_slice::<serde_json::Value>(body).ok()?;
-    let nested = || json.get("error")?.get("message")?.as_str();
-    let top_level = || json.get("message")?.as_str();
-    let code = nested().or_else(top_level);
-    code.map(|c| c.to_owned())
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_json_errors() {
+        let details = ErrorDetails::from_body(
+            br#"{"error":{"code":"ResourceNotFound","message":"The resource was not found."}}"#,
+        );
+        assert_eq!(details.code.as_deref(), Some("ResourceNotFound"));
+        assert_eq!(
+            details.message.as_deref(),
+            Some("The resource was not found.")
+        );
+
+        let details = ErrorDetails::from_body(br#"{"code":"Conflict","message":"Exists"}"#);
+        assert_eq!(details.code.as_deref(), Some("Conflict"));
+        assert_eq!(details.message.as_deref(), Some("Exists"));
+
+        let details = ErrorDetails::from_body(
+            br#"{"odata.error":{"code":"TableNotFound","message":{"lang":"en-US","value":"The table specified does not exist."}}}"#,
+        );
+        assert_eq!(details.code.as_deref(), Some("TableNotFound"));
+        assert_eq!(
+            details.message.as_deref(),
+            Some("The table specified does not exist.")
+        );
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn parses_xml_errors() {
+        let details = ErrorDetails::from_body(
+            br#"<?xml version="1.0" encoding="utf-8"?><Error><Code>BlobNotFound</Code><Message>The specified blob does not exist.
+RequestId:0b8b9e2c</Message></Error>"#,
+        );
+        assert_eq!(details.code.as_deref(), Some("BlobNotFound"));
+        assert_eq!(
+            details.message.as_deref(),
+            Some("The specified blob does not exist.\nRequestId:0b8b9e2c")
+        );
+    }
+
+    #[test]
+    fn prefers_error_code_header() {
+        let headers = HashMap::from([(
+            headers::ERROR_CODE.as_str().to_owned(),
+            "ContainerNotFound".to_owned(),
+        )]);
+        let details = ErrorDetails::new(&headers, b"");
+        assert_eq!(details.code.as_deref(), Some("ContainerNotFound"));
+        assert_eq!(details.message, None);
+    }
 }
@@ -24,6 +24,10 @@ pub enum ErrorKind {
     DataConversion,
     /// An error getting an API credential token
     Credential,
+    /// An operation did not complete before its deadline
+    Timeout,
+    /// The operation was cancelled with a [`CancellationToken`](crate::CancellationToken)
+    Cancelled,
     /// An error having to do with the mock framework
     MockFramework,
     /// A catch all for other kinds of errors
@@ -61,6 +65,8 @@ impl Display for ErrorKind {
             ErrorKind::Io => write!(f, "Io"),
             ErrorKind::DataConversion => write!(f, "DataConversion"),
             ErrorKind::Credential => write!(f, "Credential"),
+            ErrorKind::Timeout => write!(f, "Timeout"),
+            ErrorKind::Cancelled => write!(f, "Cancelled"),
             ErrorKind::MockFramework => write!(f, "MockFramework"),
             ErrorKind::Other => write!(f, "Other"),
         }
@@ -0,0 +1,33 @@
+use serde::de::DeserializeOwned;
+
+/// A format response bodies are deserialized from.
+///
+/// The formats give the same error context to deserialization failures across all the crates:
+/// the type that could not be deserialized and the size of the body. The body itself may hold
+/// secrets, so it is only logged at the trace level.
+pub trait Format {
+    /// Deserialize the body into a `T`.
+    fn deserialize<T: DeserializeOwned>(body: &[u8]) -> crate::Result<T>;
+}
+
+/// JSON, the format of most Azure services.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonFormat;
+
+impl Format for JsonFormat {
+    fn deserialize<T: DeserializeOwned>(body: &[u8]) -> crate::Result<T> {
+        crate::from_json(body)
+    }
+}
+
+/// XML, the format of the storage services.
+#[cfg(feature = "xml")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct XmlFormat;
+
+#[cfg(feature = "xml")]
+impl Format for XmlFormat {
+    fn deserialize<T: DeserializeOwned>(body: &[u8]) -> crate::Result<T> {
+        crate::xml::read_xml(body)
+    }
+}
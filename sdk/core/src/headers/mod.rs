@@ -5,6 +5,9 @@ use crate::error::{Error, ErrorKind, ResultExt};
 use std::{fmt::Debug, str::FromStr};
 pub use utilities::*;
 
+// Typed values of the headers which are parsed from responses as well as sent with requests.
+pub use crate::request_options::{ContentRange, LeaseId, Range, SourceUri};
+
 /// A trait for converting a type into request headers
 pub trait AsHeaders {
     type Iter: Iterator<Item = (HeaderName, HeaderValue)>;
@@ -135,6 +138,14 @@ impl Headers {
         self.0.insert(key.into(), value.into());
     }
 
+    /// Remove a header, returning its value if it was present
+    pub fn remove<K>(&mut self, key: K) -> Option<HeaderValue>
+    where
+        K: Into<HeaderName>,
+    {
+        self.0.remove(&key.into())
+    }
+
     /// Add headers to the headers collection
     pub fn add<H>(&mut self, header: H)
     where
@@ -356,6 +367,7 @@ pub const SOURCE_IF_NONE_MATCH: HeaderName = HeaderName::from_static("x-ms-sourc
 pub const SOURCE_IF_UNMODIFIED_SINCE: HeaderName =
     HeaderName::from_static("x-ms-source-if-unmodified-since");
 pub const SOURCE_LEASE_ID: HeaderName = HeaderName::from_static("x-ms-source-lease-id");
+pub const SOURCE_RANGE: HeaderName = HeaderName::from_static("x-ms-source-range");
 pub const TAGS: HeaderName = HeaderName::from_static("x-ms-tags");
 pub const USER: HeaderName = HeaderName::from_static("x-ms-user");
 pub const USER_AGENT: HeaderName = HeaderName::from_static("user-agent");
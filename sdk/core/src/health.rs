@@ -0,0 +1,119 @@
+use crate::error::{Error, ErrorKind};
+use crate::StatusCode;
+use std::future::Future;
+use std::time::Duration;
+use time::OffsetDateTime;
+
+/// The health of a service endpoint, as seen from a client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthStatus {
+    /// The endpoint answered an authenticated request.
+    Healthy,
+    /// The endpoint was reached, but the credential was rejected, or no token could be acquired.
+    AuthenticationFailed,
+    /// The endpoint is throttling the client or temporarily unavailable.
+    Throttled,
+    /// The endpoint answered with another error, e.g. the resource does not exist.
+    Unhealthy,
+    /// The endpoint could not be reached.
+    Unreachable,
+}
+
+/// The outcome of a health check of a service endpoint, e.g. for the readiness probe of a
+/// service using the client.
+///
+/// A health check sends the cheapest authenticated request of the service, so that it verifies
+/// the network path, the credential and the permissions of the client at once.
+#[derive(Debug, Clone)]
+pub struct HealthReport {
+    /// The endpoint checked.
+    pub endpoint: String,
+    pub status: HealthStatus,
+    /// The status code of the response, if the endpoint answered.
+    pub status_code: Option<StatusCode>,
+    /// The time the request took, retries included.
+    pub latency: Duration,
+    /// The error of an unhealthy endpoint.
+    pub error: Option<String>,
+    pub checked_on: OffsetDateTime,
+}
+
+impl HealthReport {
+    /// Runs the request of a health check of `endpoint` and reports its outcome.
+    pub async fn check<T>(
+        endpoint: impl Into<String>,
+        request: impl Future<Output = crate::Result<T>>,
+    ) -> Self {
+        let checked_on = OffsetDateTime::now_utc();
+        let result = request.await;
+        let latency = (OffsetDateTime::now_utc() - checked_on)
+            .try_into()
+            .unwrap_or_default();
+        let (status, status_code, error) = match result {
+            Ok(_) => (HealthStatus::Healthy, None, None),
+            Err(error) => {
+                let (status, status_code) = classify(&error);
+                (status, status_code, Some(error.to_string()))
+            }
+        };
+        Self {
+            endpoint: endpoint.into(),
+            status,
+            status_code,
+            latency,
+            error,
+            checked_on,
+        }
+    }
+
+    /// Whether the endpoint answered the health check.
+    pub fn is_healthy(&self) -> bool {
+        self.status == HealthStatus::Healthy
+    }
+}
+
+fn classify(error: &Error) -> (HealthStatus, Option<StatusCode>) {
+    match error.kind() {
+        ErrorKind::HttpResponse { status, .. } => {
+            let health = match status {
+                StatusCode::Unauthorized | StatusCode::Forbidden => {
+                    HealthStatus::AuthenticationFailed
+                }
+                StatusCode::TooManyRequests | StatusCode::ServiceUnavailable => {
+                    HealthStatus::Throttled
+                }
+                _ => HealthStatus::Unhealthy,
+            };
+            (health, Some(*status))
+        }
+        ErrorKind::Credential => (HealthStatus::AuthenticationFailed, None),
+        ErrorKind::Io | ErrorKind::Timeout => (HealthStatus::Unreachable, None),
+        _ => (HealthStatus::Unhealthy, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    #[test]
+    fn classifies_the_failures() {
+        let endpoint = "https://contoso.vault.azure.net";
+        let report = block_on(HealthReport::check(endpoint, async { Ok::<_, Error>(()) }));
+        assert!(report.is_healthy());
+        assert_eq!(report.error, None);
+
+        let report = block_on(HealthReport::check(endpoint, async {
+            Err::<(), _>(ErrorKind::http_response(StatusCode::Forbidden, None).into_error())
+        }));
+        assert_eq!(report.status, HealthStatus::AuthenticationFailed);
+        assert_eq!(report.status_code, Some(StatusCode::Forbidden));
+
+        let report = block_on(HealthReport::check(endpoint, async {
+            Err::<(), _>(Error::message(ErrorKind::Io, "connection refused"))
+        }));
+        assert_eq!(report.status, HealthStatus::Unreachable);
+        assert_eq!(report.status_code, None);
+    }
+}
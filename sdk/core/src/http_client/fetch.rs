@@ -0,0 +1,130 @@
+use crate::error::{Error, ErrorKind, ResultExt};
+use crate::headers::{HeaderName, HeaderValue, Headers};
+use crate::{Body, HttpClient, PinnedStream};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::AsyncReadExt;
+use js_sys::{Array, Promise, Uint8Array};
+use std::collections::HashMap;
+use std::sync::Arc;
+use wasm_bindgen::{prelude::wasm_bindgen, JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+
+/// Construct a new `HttpClient` with the `fetch` backend.
+pub fn new_fetch_client() -> Arc<dyn HttpClient> {
+    log::debug!("instantiating an http client using the fetch backend");
+    Arc::new(FetchClient)
+}
+
+/// An `HttpClient` sending requests with the Fetch API of the JavaScript host.
+///
+/// This is the default client on `wasm32` targets. It works in browsers as well as in other
+/// hosts exposing a global `fetch` function, such as Cloudflare Workers, Deno or Node.js 18+.
+///
+/// JavaScript values cannot be shared across threads, so the response body is read completely
+/// before the response is returned.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FetchClient;
+
+#[wasm_bindgen]
+extern "C" {
+    // bound to the global `fetch` rather than `Window::fetch` as workers have no window
+    #[wasm_bindgen(js_name = fetch)]
+    fn fetch_with_request(input: &web_sys::Request) -> Promise;
+}
+
+#[async_trait(?Send)]
+impl HttpClient for FetchClient {
+    async fn execute_request(&self, request: &crate::Request) -> crate::Result<crate::Response> {
+        let url = request.url();
+        let method = request.method();
+
+        let headers = web_sys::Headers::new().map_err(js_error("failed to create headers"))?;
+        for (name, value) in request.headers().iter() {
+            headers
+                .append(name.as_str(), value.as_str())
+                .map_err(js_error("failed to set request header"))?;
+        }
+
+        let mut init = web_sys::RequestInit::new();
+        init.method(method.as_ref()).headers(&headers);
+
+        let body = match request.body().clone() {
+            Body::Bytes(bytes) => bytes,
+            Body::SeekableStream(mut seekable_stream) => {
+                seekable_stream.reset().await.context(
+                    ErrorKind::Other,
+                    "failed to reset body stream when building request",
+                )?;
+                let mut buffer = Vec::with_capacity(seekable_stream.len());
+                seekable_stream
+                    .read_to_end(&mut buffer)
+                    .await
+                    .context(ErrorKind::Io, "failed to read body stream")?;
+                buffer.into()
+            }
+        };
+        if !body.is_empty() {
+            init.body(Some(&Uint8Array::from(&body[..])));
+        }
+
+        let fetch_request = web_sys::Request::new_with_str_and_init(url.as_str(), &init)
+            .map_err(js_error("failed to build `fetch` request"))?;
+
+        log::debug!("performing request {method} '{url}' with `fetch`");
+        let rsp: web_sys::Response = JsFuture::from(fetch_with_request(&fetch_request))
+            .await
+            .map_err(js_error("failed to execute `fetch` request"))?
+            .unchecked_into();
+
+        let status = try_from_status(rsp.status())?;
+        let headers = to_headers(&rsp.headers())?;
+
+        let buffer = rsp
+            .array_buffer()
+            .map_err(js_error("failed to read `fetch` response body"))?;
+        let buffer = JsFuture::from(buffer)
+            .await
+            .map_err(js_error("failed to read `fetch` response body"))?;
+        let body = Bytes::from(Uint8Array::new(&buffer).to_vec());
+        let body: PinnedStream = Box::pin(futures::stream::once(async move { Ok(body) }));
+
+        Ok(crate::Response::new(status, headers, body))
+    }
+}
+
+fn to_headers(headers: &web_sys::Headers) -> crate::Result<Headers> {
+    let entries = js_sys::try_iter(headers)
+        .map_err(js_error("failed to read response headers"))?
+        .ok_or_else(|| Error::message(ErrorKind::Io, "response headers are not iterable"))?;
+
+    let mut map = HashMap::new();
+    for entry in entries {
+        let entry: Array = entry
+            .map_err(js_error("failed to read response headers"))?
+            .unchecked_into();
+        match (entry.get(0).as_string(), entry.get(1).as_string()) {
+            (Some(name), Some(value)) => {
+                map.insert(HeaderName::from(name), HeaderValue::from(value));
+            }
+            _ => log::warn!("ignoring response header that is not a string"),
+        }
+    }
+    Ok(Headers::from(map))
+}
+
+fn try_from_status(status: u16) -> crate::Result<crate::StatusCode> {
+    crate::StatusCode::try_from(status).map_err(|_| {
+        Error::with_message(ErrorKind::DataConversion, || {
+            format!("invalid status code {status}")
+        })
+    })
+}
+
+/// Converts a JavaScript exception into an `Error`.
+///
+/// Exceptions are not Rust errors, so only their debug representation is kept.
+fn js_error(message: &'static str) -> impl Fn(JsValue) -> Error {
+    move |error| Error::with_message(ErrorKind::Io, || format!("{message}: {error:?}"))
+}
@@ -0,0 +1,340 @@
+use crate::error::{Error, ErrorKind, ResultExt};
+use crate::{
+    Body, Certificate, ClientIdentity, ConnectionPoolOptions, HttpClient, HttpClientOptions,
+    HttpVersion, PinnedStream, TlsOptions, TlsVersion,
+};
+
+use ::hyper::client::connect::{Connect, HttpConnector};
+use async_trait::async_trait;
+use futures::TryStreamExt;
+use hyper_rustls::HttpsConnectorBuilder;
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+/// Construct a new `HttpClient` with the `hyper` backend.
+///
+/// The connections are secured with `rustls`, trusting the Mozilla root certificates.
+pub fn new_hyper_client() -> Arc<dyn HttpClient> {
+    log::debug!("instantiating an http client using the hyper backend");
+    new_hyper_client_with_options(&HttpClientOptions::default())
+        .expect("the default hyper client options are valid")
+}
+
+/// Construct a new `HttpClient` with the `hyper` backend configured with the given options.
+///
+/// Proxies are not supported by this backend. To control how connections are established, e.g.
+/// to go through a Unix socket or resolve names differently, build a [`hyper::Client`] with your
+/// own connector instead: it implements [`HttpClient`] as well.
+pub fn new_hyper_client_with_options(
+    options: &HttpClientOptions,
+) -> crate::Result<Arc<dyn HttpClient>> {
+    log::debug!("instantiating an http client using the hyper backend with {options:?}");
+    if options.proxy.is_some() {
+        return Err(Error::message(
+            ErrorKind::Other,
+            "proxies are not supported by the hyper backend",
+        ));
+    }
+
+    let mut http = HttpConnector::new();
+    http.enforce_http(false);
+    let mut builder = ::hyper::Client::builder();
+    if let Some(pool) = &options.connection_pool {
+        configure_connection_pool(&mut builder, &mut http, pool);
+    }
+
+    let tls = tls_config(options.tls.as_ref())?;
+    let https = HttpsConnectorBuilder::new()
+        .with_tls_config(tls)
+        .https_or_http();
+    let https = match options.http_version {
+        HttpVersion::Auto => https.enable_http1().enable_http2().wrap_connector(http),
+        HttpVersion::Http1Only => https.enable_http1().wrap_connector(http),
+        HttpVersion::Http2Only => {
+            builder.http2_only(true);
+            https.enable_http2().wrap_connector(http)
+        }
+    };
+    Ok(Arc::new(builder.build::<_, ::hyper::Body>(https)))
+}
+
+fn configure_connection_pool(
+    builder: &mut ::hyper::client::Builder,
+    http: &mut HttpConnector,
+    pool: &ConnectionPoolOptions,
+) {
+    if let Some(max_idle_per_host) = pool.max_idle_per_host {
+        builder.pool_max_idle_per_host(max_idle_per_host);
+    }
+    if let Some(idle_timeout) = pool.idle_timeout {
+        builder.pool_idle_timeout(idle_timeout);
+    }
+    if let Some(tcp_keepalive) = pool.tcp_keepalive {
+        http.set_keepalive(Some(tcp_keepalive));
+    }
+}
+
+fn tls_config(tls: Option<&TlsOptions>) -> crate::Result<rustls::ClientConfig> {
+    let mut roots = rustls::RootCertStore::empty();
+    roots.add_server_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.0.iter().map(|anchor| {
+        rustls::OwnedTrustAnchor::from_subject_spki_name_constraints(
+            anchor.subject,
+            anchor.spki,
+            anchor.name_constraints,
+        )
+    }));
+    let versions: &[&rustls::SupportedProtocolVersion] =
+        match tls.and_then(|tls| tls.min_tls_version) {
+            Some(TlsVersion::Tls1_3) => &[&rustls::version::TLS13],
+            // TLS 1.0 and 1.1 are not implemented by rustls
+            _ => rustls::ALL_VERSIONS,
+        };
+
+    let mut client_identity = None;
+    if let Some(tls) = tls {
+        for certificate in &tls.root_certificates {
+            let certificates = match certificate {
+                Certificate::Pem(pem) => rustls_pemfile::certs(&mut pem.as_slice()).context(
+                    ErrorKind::DataConversion,
+                    "failed to parse root certificate",
+                )?,
+                Certificate::Der(der) => vec![der.clone()],
+            };
+            for certificate in certificates {
+                roots.add(&rustls::Certificate(certificate)).context(
+                    ErrorKind::DataConversion,
+                    "failed to parse root certificate",
+                )?;
+            }
+        }
+        client_identity = tls.client_identity.as_ref();
+    }
+
+    let builder = rustls::ClientConfig::builder()
+        .with_safe_default_cipher_suites()
+        .with_safe_default_kx_groups()
+        .with_protocol_versions(versions)
+        .context(ErrorKind::Other, "failed to configure the TLS versions")?
+        .with_root_certificates(roots);
+    match client_identity {
+        None => Ok(builder.with_no_client_auth()),
+        Some(ClientIdentity::Pem(pem)) => {
+            let (certificates, key) = parse_identity(pem)?;
+            builder
+                .with_single_cert(certificates, key)
+                .context(ErrorKind::DataConversion, "invalid PEM client identity")
+        }
+        Some(ClientIdentity::Pkcs12 { .. }) => {
+            Err(crate::UnsupportedClientIdentityError::Pkcs12.into())
+        }
+    }
+}
+
+fn parse_identity(pem: &[u8]) -> crate::Result<(Vec<rustls::Certificate>, rustls::PrivateKey)> {
+    let items = rustls_pemfile::read_all(&mut &pem[..]).context(
+        ErrorKind::DataConversion,
+        "failed to parse PEM client identity",
+    )?;
+    let mut certificates = Vec::new();
+    let mut key = None;
+    for item in items {
+        match item {
+            rustls_pemfile::Item::X509Certificate(der) => {
+                certificates.push(rustls::Certificate(der));
+            }
+            rustls_pemfile::Item::PKCS8Key(der)
+            | rustls_pemfile::Item::RSAKey(der)
+            | rustls_pemfile::Item::ECKey(der) => key = Some(rustls::PrivateKey(der)),
+            _ => {}
+        }
+    }
+    let key = key.ok_or_else(|| {
+        Error::message(
+            ErrorKind::DataConversion,
+            "the PEM client identity has no private key",
+        )
+    })?;
+    Ok((certificates, key))
+}
+
+#[async_trait]
+impl<C> HttpClient for ::hyper::Client<C, ::hyper::Body>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    async fn execute_request(&self, request: &crate::Request) -> crate::Result<crate::Response> {
+        let url = request.url().clone();
+        let method = request.method();
+        let mut builder = ::hyper::Request::builder()
+            .method(try_from_method(method)?)
+            .uri(url.as_str());
+        for (name, value) in request.headers().iter() {
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+
+        let body = match request.body().clone() {
+            Body::Bytes(bytes) => ::hyper::Body::from(bytes),
+            Body::SeekableStream(mut seekable_stream) => {
+                seekable_stream.reset().await.context(
+                    ErrorKind::Other,
+                    "failed to reset body stream when building request",
+                )?;
+                ::hyper::Body::wrap_stream(seekable_stream)
+            }
+        };
+        let hyper_request = builder
+            .body(body)
+            .context(ErrorKind::Other, "failed to build `hyper` request")?;
+
+        log::debug!("performing request {method} '{url}' with `hyper`");
+        let rsp = self
+            .request(hyper_request)
+            .await
+            .context(ErrorKind::Io, "failed to execute `hyper` request")?;
+
+        let status = rsp.status();
+        let headers = to_headers(rsp.headers());
+        let body: PinnedStream = Box::pin(rsp.into_body().map_err(|error| {
+            Error::full(
+                ErrorKind::Io,
+                error,
+                "error converting `hyper` response into a byte stream",
+            )
+        }));
+
+        Ok(crate::Response::new(
+            try_from_status(status)?,
+            headers,
+            body,
+        ))
+    }
+}
+
+fn to_headers(map: &::hyper::HeaderMap) -> crate::headers::Headers {
+    let map = map
+        .iter()
+        .filter_map(|(k, v)| {
+            let key = k.as_str();
+            match std::str::from_utf8(v.as_bytes()) {
+                Ok(value) => Some((
+                    crate::headers::HeaderName::from(key.to_owned()),
+                    crate::headers::HeaderValue::from(value.to_owned()),
+                )),
+                Err(_) => {
+                    log::warn!("header value for `{key}` is not utf8");
+                    None
+                }
+            }
+        })
+        .collect::<HashMap<_, _>>();
+    crate::headers::Headers::from(map)
+}
+
+fn try_from_method(method: &crate::Method) -> crate::Result<::hyper::Method> {
+    match method {
+        crate::Method::Connect => Ok(::hyper::Method::CONNECT),
+        crate::Method::Delete => Ok(::hyper::Method::DELETE),
+        crate::Method::Get => Ok(::hyper::Method::GET),
+        crate::Method::Head => Ok(::hyper::Method::HEAD),
+        crate::Method::Options => Ok(::hyper::Method::OPTIONS),
+        crate::Method::Patch => Ok(::hyper::Method::PATCH),
+        crate::Method::Post => Ok(::hyper::Method::POST),
+        crate::Method::Put => Ok(::hyper::Method::PUT),
+        crate::Method::Trace => Ok(::hyper::Method::TRACE),
+        _ => ::hyper::Method::from_str(method.as_ref()).map_kind(ErrorKind::DataConversion),
+    }
+}
+
+fn try_from_status(status: ::hyper::StatusCode) -> crate::Result<crate::StatusCode> {
+    let status = status.as_u16();
+    crate::StatusCode::try_from(status).map_err(|_| {
+        Error::with_message(ErrorKind::DataConversion, || {
+            format!("invalid status code {status}")
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{headers::HeaderName, Method, Request, StatusCode};
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    /// Accepts one connection, replies with `response` and returns the request received.
+    fn serve_once(
+        listener: TcpListener,
+        response: &'static str,
+    ) -> std::thread::JoinHandle<String> {
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut received = Vec::new();
+            let mut buffer = [0; 1024];
+            loop {
+                let read = stream.read(&mut buffer).unwrap();
+                received.extend_from_slice(&buffer[..read]);
+                let text = String::from_utf8_lossy(&received).to_string();
+                if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                    let content_length = head
+                        .lines()
+                        .find_map(|line| line.strip_prefix("content-length: "))
+                        .map_or(0, |length| length.parse().unwrap());
+                    if body.len() >= content_length {
+                        stream.write_all(response.as_bytes()).unwrap();
+                        return text;
+                    }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn sends_the_request_and_reads_the_response() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!(
+            "http://{}/container?comp=list",
+            listener.local_addr().unwrap()
+        );
+        let server = serve_once(
+            listener,
+            "HTTP/1.1 201 Created\r\nx-ms-request-id: id\r\ncontent-length: 7\r\n\r\ncreated",
+        );
+
+        let mut request = Request::new(url.parse().unwrap(), Method::Merge);
+        request.insert_header("x-ms-version", "2020-06-12");
+        request.set_body(bytes::Bytes::from_static(b"entity"));
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let (status, request_id, body) = runtime.block_on(async {
+            let response = new_hyper_client().execute_request(&request).await.unwrap();
+            let status = response.status();
+            let request_id = response
+                .headers()
+                .get_optional_string(&HeaderName::from_static("x-ms-request-id"));
+            (
+                status,
+                request_id,
+                response.into_body().collect().await.unwrap(),
+            )
+        });
+
+        assert_eq!(status, StatusCode::Created);
+        assert_eq!(request_id.as_deref(), Some("id"));
+        assert_eq!(body.as_ref(), b"created");
+        let received = server.join().unwrap();
+        assert!(received.starts_with("MERGE /container?comp=list HTTP/1.1\r\n"));
+        assert!(received.contains("x-ms-version: 2020-06-12\r\n"));
+        assert!(received.ends_with("\r\n\r\nentity"));
+    }
+
+    #[test]
+    fn converts_the_methods_and_statuses() {
+        assert_eq!(try_from_method(&Method::Get).unwrap(), ::hyper::Method::GET);
+        assert_eq!(try_from_method(&Method::Merge).unwrap().as_str(), "MERGE");
+        assert_eq!(
+            try_from_status(::hyper::StatusCode::NOT_FOUND).unwrap(),
+            StatusCode::NotFound
+        );
+    }
+}
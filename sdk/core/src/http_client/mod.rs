@@ -1,3 +1,7 @@
+#[cfg(target_arch = "wasm32")]
+mod fetch;
+#[cfg(all(not(target_arch = "wasm32"), feature = "enable_hyper"))]
+mod hyper;
 mod noop;
 #[cfg(all(
     not(target_arch = "wasm32"),
@@ -9,32 +13,89 @@ mod reqwest;
     any(feature = "enable_reqwest", feature = "enable_reqwest_rustls")
 ))]
 compile_error!("The `enable_request` and `enable_reqwest_rustls` features are not allowed for `wasm32` targets");
+#[cfg(all(target_arch = "wasm32", feature = "enable_hyper"))]
+compile_error!("The `enable_hyper` feature is not allowed for `wasm32` targets");
 
+#[cfg(all(not(target_arch = "wasm32"), feature = "enable_hyper"))]
+pub use self::hyper::*;
 #[cfg(all(
     not(target_arch = "wasm32"),
     any(feature = "enable_reqwest", feature = "enable_reqwest_rustls")
 ))]
 pub use self::reqwest::*;
+#[cfg(target_arch = "wasm32")]
+pub use fetch::*;
 pub use noop::*;
 
 use std::sync::Arc;
 
 /// Construct a new `HttpClient`
+///
+/// The `reqwest` backend is used if it is enabled, the `hyper` backend otherwise.
 pub fn new_http_client() -> Arc<dyn HttpClient> {
     #[allow(unused)]
     let http_client: Arc<dyn HttpClient> = Arc::new(NoopClient);
+    #[cfg(all(
+        not(target_arch = "wasm32"),
+        feature = "enable_hyper",
+        not(any(feature = "enable_reqwest", feature = "enable_reqwest_rustls"))
+    ))]
+    let http_client = new_hyper_client();
     #[cfg(all(
         not(target_arch = "wasm32"),
         any(feature = "enable_reqwest", feature = "enable_reqwest_rustls")
     ))]
     let http_client = new_reqwest_client();
+    #[cfg(target_arch = "wasm32")]
+    let http_client = new_fetch_client();
     http_client
 }
 
-use crate::error::ErrorKind;
+/// Construct a new `HttpClient` configured with the given options.
+///
+/// Fails if the options cannot be applied to the HTTP client backend.
+pub fn new_http_client_with_options(
+    options: &HttpClientOptions,
+) -> crate::Result<Arc<dyn HttpClient>> {
+    #[allow(unused)]
+    let http_client: Arc<dyn HttpClient> = Arc::new(NoopClient);
+    #[cfg(all(
+        not(target_arch = "wasm32"),
+        feature = "enable_hyper",
+        not(any(feature = "enable_reqwest", feature = "enable_reqwest_rustls"))
+    ))]
+    let http_client = new_hyper_client_with_options(options)?;
+    #[cfg(all(
+        not(target_arch = "wasm32"),
+        any(feature = "enable_reqwest", feature = "enable_reqwest_rustls")
+    ))]
+    let http_client = new_reqwest_client_with_options(options)?;
+    #[cfg(target_arch = "wasm32")]
+    let http_client = {
+        // proxies, TLS and connections are handled by the JavaScript host
+        if options.proxy.is_some() || options.tls.is_some() {
+            log::warn!("proxy and TLS options are ignored by the fetch backend");
+        }
+        if options.connection_pool.is_some() || options.http_version != crate::HttpVersion::Auto {
+            log::warn!("connection options are ignored by the fetch backend");
+        }
+        new_fetch_client()
+    };
+    #[cfg(not(any(
+        target_arch = "wasm32",
+        feature = "enable_reqwest",
+        feature = "enable_reqwest_rustls",
+        feature = "enable_hyper"
+    )))]
+    let _ = options;
+    Ok(http_client)
+}
+
+use crate::error::{ErrorKind, ResultExt};
+use crate::HttpClientOptions;
 use async_trait::async_trait;
 use bytes::Bytes;
-use serde::Serialize;
+use serde::{de::DeserializeOwned, Serialize};
 
 /// An HTTP client which can send requests.
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
@@ -72,3 +133,47 @@ where
 {
     Ok(Bytes::from(serde_json::to_vec(value)?))
 }
+
+/// Deserialize json into a type.
+///
+/// The error does not contain the json, which may hold secrets: it is only logged at the trace
+/// level.
+pub fn from_json<S, T>(body: S) -> crate::Result<T>
+where
+    S: AsRef<[u8]>,
+    T: DeserializeOwned,
+{
+    let body = body.as_ref();
+    serde_json::from_slice(body).with_context(ErrorKind::DataConversion, || {
+        let t = core::any::type_name::<T>();
+        log::trace!(
+            "failed to deserialize the following json into a {t}\n{}",
+            String::from_utf8_lossy(body)
+        );
+        format!(
+            "failed to deserialize {} bytes of json into a {t}",
+            body.len()
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    struct Secret {
+        #[allow(dead_code)]
+        value: u64,
+    }
+
+    #[test]
+    fn from_json_errors_do_not_contain_the_body() {
+        let error = from_json::<_, Secret>(r#"{"value":"hunter2"}"#).unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::DataConversion);
+        let message = error.to_string();
+        assert!(message.contains("failed to deserialize 19 bytes of json into a"));
+        assert!(!message.contains("hunter2"));
+    }
+}
@@ -1,5 +1,8 @@
 use crate::error::{ErrorKind, ResultExt};
-use crate::{Body, HttpClient, PinnedStream};
+use crate::{
+    Body, Certificate, ClientIdentity, ConnectionPoolOptions, HttpClient, HttpClientOptions,
+    HttpVersion, PinnedStream, ProxyOptions, TlsOptions, TlsVersion,
+};
 
 use async_trait::async_trait;
 use futures::TryStreamExt;
@@ -11,6 +14,117 @@ pub fn new_reqwest_client() -> std::sync::Arc<dyn HttpClient> {
     std::sync::Arc::new(::reqwest::Client::new())
 }
 
+/// Construct a new `HttpClient` with the `reqwest` backend configured with the given options.
+pub fn new_reqwest_client_with_options(
+    options: &HttpClientOptions,
+) -> crate::Result<std::sync::Arc<dyn HttpClient>> {
+    log::debug!("instantiating an http client using the reqwest backend with {options:?}");
+    let mut builder = ::reqwest::Client::builder();
+    if let Some(proxy) = &options.proxy {
+        builder = builder.proxy(to_reqwest_proxy(proxy));
+    } else if let Some(proxy) = ProxyOptions::from_env() {
+        // `reqwest` does not honor `NO_PROXY` when it reads the proxies of the environment itself
+        builder = builder.proxy(to_reqwest_proxy(&proxy));
+    }
+    if let Some(tls) = &options.tls {
+        builder = configure_tls(builder, tls)?;
+    }
+    if let Some(pool) = &options.connection_pool {
+        builder = configure_connection_pool(builder, pool);
+    }
+    builder = match options.http_version {
+        HttpVersion::Auto => builder,
+        HttpVersion::Http1Only => builder.http1_only(),
+        HttpVersion::Http2Only => builder.http2_prior_knowledge(),
+    };
+    let client = builder
+        .build()
+        .context(ErrorKind::Other, "failed to build `reqwest` client")?;
+    Ok(std::sync::Arc::new(client))
+}
+
+fn configure_tls(
+    mut builder: ::reqwest::ClientBuilder,
+    tls: &TlsOptions,
+) -> crate::Result<::reqwest::ClientBuilder> {
+    for certificate in &tls.root_certificates {
+        let certificate = match certificate {
+            Certificate::Pem(pem) => ::reqwest::Certificate::from_pem(pem),
+            Certificate::Der(der) => ::reqwest::Certificate::from_der(der),
+        }
+        .context(
+            ErrorKind::DataConversion,
+            "failed to parse root certificate",
+        )?;
+        builder = builder.add_root_certificate(certificate);
+    }
+    if let Some(identity) = &tls.client_identity {
+        builder = builder.identity(to_reqwest_identity(identity)?);
+    }
+    if let Some(version) = tls.min_tls_version {
+        builder = builder.min_tls_version(match version {
+            TlsVersion::Tls1_0 => ::reqwest::tls::Version::TLS_1_0,
+            TlsVersion::Tls1_1 => ::reqwest::tls::Version::TLS_1_1,
+            TlsVersion::Tls1_2 => ::reqwest::tls::Version::TLS_1_2,
+            TlsVersion::Tls1_3 => ::reqwest::tls::Version::TLS_1_3,
+        });
+    }
+    Ok(builder)
+}
+
+fn configure_connection_pool(
+    mut builder: ::reqwest::ClientBuilder,
+    pool: &ConnectionPoolOptions,
+) -> ::reqwest::ClientBuilder {
+    if let Some(max_idle_per_host) = pool.max_idle_per_host {
+        builder = builder.pool_max_idle_per_host(max_idle_per_host);
+    }
+    if let Some(idle_timeout) = pool.idle_timeout {
+        builder = builder.pool_idle_timeout(idle_timeout);
+    }
+    if let Some(tcp_keepalive) = pool.tcp_keepalive {
+        builder = builder.tcp_keepalive(tcp_keepalive);
+    }
+    builder
+}
+
+fn to_reqwest_identity(identity: &ClientIdentity) -> crate::Result<::reqwest::Identity> {
+    match identity {
+        #[cfg(feature = "enable_reqwest_native_tls")]
+        ClientIdentity::Pkcs12 { der, password } => {
+            ::reqwest::Identity::from_pkcs12_der(der, password).context(
+                ErrorKind::DataConversion,
+                "failed to parse PKCS #12 client identity",
+            )
+        }
+        #[cfg(not(feature = "enable_reqwest_native_tls"))]
+        ClientIdentity::Pkcs12 { .. } => Err(crate::UnsupportedClientIdentityError::Pkcs12.into()),
+        #[cfg(feature = "enable_reqwest_rustls")]
+        ClientIdentity::Pem(pem) => ::reqwest::Identity::from_pem(pem).context(
+            ErrorKind::DataConversion,
+            "failed to parse PEM client identity",
+        ),
+        #[cfg(not(feature = "enable_reqwest_rustls"))]
+        ClientIdentity::Pem(_) => Err(crate::UnsupportedClientIdentityError::Pem.into()),
+    }
+}
+
+fn to_reqwest_proxy(proxy: &ProxyOptions) -> ::reqwest::Proxy {
+    let options = proxy.clone();
+    // the proxies of both schemes may have credentials of their own, so they are set in the URL
+    // returned for each request rather than with `basic_auth`
+    ::reqwest::Proxy::custom(move |url| {
+        options.proxy(url).map(|proxy| {
+            let mut proxy_url = proxy.url.clone();
+            if let Some((username, password)) = &proxy.credentials {
+                let _ = proxy_url.set_username(username);
+                let _ = proxy_url.set_password(Some(password));
+            }
+            proxy_url
+        })
+    })
+}
+
 #[async_trait]
 impl HttpClient for ::reqwest::Client {
     async fn execute_request(&self, request: &crate::Request) -> crate::Result<crate::Response> {
@@ -102,3 +216,66 @@ fn try_from_status(status: ::reqwest::StatusCode) -> crate::Result<crate::Status
         })
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a self-signed client certificate and its key, protected by the password `password`
+    const PKCS12_IDENTITY: &str = concat!(
+        "MIIDegIBAzCCA0AGCSqGSIb3DQEHAaCCAzEEggMtMIIDKTCCAh8GCSqGSIb3DQEHBqCCAhAwggIMAgEAMIICBQYJ",
+        "KoZIhvcNAQcBMBwGCiqGSIb3DQEMAQMwDgQIyKCkeRbJ+/0CAggAgIIB2NHRmm3aMwMgSmN1fwebQvamocQKcow8",
+        "7PSMKTFTWd+gilCveXS/XJsRnqvI9EU5/fCgxp95mZDsJhAeoFo5ef9OYZzqOS7/9zxZrSG2osfhe3y8CVV6tKEu",
+        "cnOJtgwhZ20R+HF7bEuvv+7uCiimvNAFxFsCE3cvn/Mw4idXCr98ESA2q9aUNVccf6CqUcRgq+0VgSjsx5rulSbS",
+        "3vsqGpcx6WHYrwE3GwXG1j41+NYM/ow0c7OruidscHo3lEzuueC3Uf1e6Ul7f2wb3VGBrNRXKnDFMIzchVryXyVF",
+        "BzrNbYyck76N1LjV+2e1TlEXFT9aPYm//cR6unfBxfRpzaPaUiMncuZNlIYA114x2BE4H7ioep89zWSzD0xItsBa",
+        "Jt8nVKkYByjE/aLCKdZuiRO8vx+6GU9hTzxAQBQqeYwF8ZlFst7WBychrpO1hawu7yrkDz+7S1WSkfoX+6AGNvMm",
+        "xxZCXQ9wChBEiQ0T344m3ecZZ0Xgv09vtairE5E2daO9e9EqG++TUsn74gio4gjk6XrxfnxrfAcAMZVA7KHy2azd",
+        "Vn4QXihfM8XcS3HIJxG08y6h4nY1cP2LroA5wQiD7Rl+wASYK6vfc0Kfs4GURf3EoBSmtt0wggECBgkqhkiG9w0B",
+        "BwGggfQEgfEwge4wgesGCyqGSIb3DQEMCgECoIG0MIGxMBwGCiqGSIb3DQEMAQMwDgQIpWytRQ8qySgCAggABIGQ",
+        "cM7icuZylwnNFVTZUeheKLQKgSEtoQw/9OpbZaCysdX+/ONgR2gXkDvABghS3hGrBLVP7rz5yf0wtzJVR687K+RL",
+        "VF394MvBSn8IzOES8l3UOO7V5cxo/YzNEr51RTsk1LY5YRJQkirOowz808IFT7fBcsDSbUn8zyaGVcaPlfSW2/ET",
+        "dHTabASNjnt/SIeVMSUwIwYJKoZIhvcNAQkVMRYEFG1CTOhWidoj1vpSnSaftC727cPiMDEwITAJBgUrDgMCGgUA",
+        "BBR7X0Hl/Q+N/46yWAI5B7VYaVhotQQIrQg9WZXoRHACAggA",
+    );
+
+    fn pkcs12_identity() -> ClientIdentity {
+        ClientIdentity::Pkcs12 {
+            der: base64::decode(PKCS12_IDENTITY).unwrap(),
+            password: "password".to_owned(),
+        }
+    }
+
+    #[cfg(feature = "enable_reqwest_native_tls")]
+    #[test]
+    fn builds_a_client_from_a_pkcs12_identity() {
+        let options = HttpClientOptions::default()
+            .tls(TlsOptions::default().client_identity(pkcs12_identity()));
+        new_reqwest_client_with_options(&options).unwrap();
+    }
+
+    #[cfg(feature = "enable_reqwest_native_tls")]
+    #[test]
+    fn rejects_a_pkcs12_identity_with_the_wrong_password() {
+        let identity = ClientIdentity::Pkcs12 {
+            der: base64::decode(PKCS12_IDENTITY).unwrap(),
+            password: "wrong".to_owned(),
+        };
+        let options =
+            HttpClientOptions::default().tls(TlsOptions::default().client_identity(identity));
+        let error = new_reqwest_client_with_options(&options).unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::DataConversion);
+    }
+
+    #[cfg(not(feature = "enable_reqwest_native_tls"))]
+    #[test]
+    fn pkcs12_identity_requires_native_tls() {
+        let options = HttpClientOptions::default()
+            .tls(TlsOptions::default().client_identity(pkcs12_identity()));
+        let error = new_reqwest_client_with_options(&options).unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<crate::UnsupportedClientIdentityError>(),
+            Some(&crate::UnsupportedClientIdentityError::Pkcs12)
+        );
+    }
+}
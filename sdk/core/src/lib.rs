@@ -11,12 +11,20 @@
 #[macro_use]
 mod macros;
 
+mod buffer_pool;
 mod bytes_stream;
+mod cancellation;
+mod cloud;
 mod constants;
 mod context;
+mod continuation_token;
 pub mod date;
+mod deadline;
 pub mod error;
+mod format;
+mod health;
 mod http_client;
+mod metrics;
 mod models;
 mod options;
 mod pageable;
@@ -27,7 +35,12 @@ mod response;
 mod seekable_stream;
 
 pub mod auth;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+pub mod blocking;
 pub mod headers;
+pub mod lro;
+#[cfg(feature = "mock_transport_framework")]
+pub mod mock;
 pub mod parsing;
 pub mod prelude;
 pub mod request_options;
@@ -39,13 +52,23 @@ use uuid::Uuid;
 #[cfg(feature = "xml")]
 pub mod xml;
 
+pub use buffer_pool::{BufferPool, PooledBuffer};
 pub use bytes_stream::*;
+pub use cancellation::{CancellationToken, Cancelled};
+pub use cloud::{AzureCloud, CloudConfiguration};
 pub use constants::*;
 pub use context::Context;
+pub use continuation_token::ContinuationToken;
+pub use deadline::Deadline;
 pub use error::Result;
+pub use format::*;
 #[doc(inline)]
 pub use headers::Header;
-pub use http_client::{new_http_client, to_json, HttpClient};
+pub use health::{HealthReport, HealthStatus};
+pub use http_client::{
+    from_json, new_http_client, new_http_client_with_options, to_json, HttpClient,
+};
+pub use metrics::{Metric, MetricsRecorder};
 pub use models::*;
 pub use options::*;
 pub use pageable::*;
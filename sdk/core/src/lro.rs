@@ -0,0 +1,286 @@
+//! Polling of the long-running operations of Azure Resource Manager.
+//!
+//! A long-running operation is accepted with a `202 Accepted` response, which points with an
+//! `Azure-AsyncOperation` or a `Location` header to where its status is reported. The
+//! [`LroPoller`] gets that status until the operation completes, fails or the poller times out.
+
+use crate::error::{Error, ErrorKind};
+use crate::headers::{HeaderName, Headers, LOCATION, RETRY_AFTER};
+use crate::sleep::sleep;
+use crate::{Deadline, Response, StatusCode, Url};
+use futures::Future;
+use serde::Deserialize;
+use std::time::Duration;
+
+const AZURE_ASYNC_OPERATION: HeaderName = HeaderName::from_static("azure-asyncoperation");
+const DEFAULT_POLLING_INTERVAL: Duration = Duration::from_secs(10);
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+/// Waits for a long-running operation of Azure Resource Manager to complete.
+///
+/// The status is checked after the delay of the `Retry-After` header of the last response or,
+/// without one, after the polling interval. The poller gives up, with an [`ErrorKind::Timeout`]
+/// error, once the timeout elapsed; the operation itself keeps running on the service.
+#[derive(Debug, Clone)]
+pub struct LroPoller {
+    /// How long to wait between status checks without a `Retry-After` header.
+    polling_interval: Duration,
+    /// How long to wait for the operation to complete, at most.
+    timeout: Duration,
+}
+
+impl Default for LroPoller {
+    fn default() -> Self {
+        Self {
+            polling_interval: DEFAULT_POLLING_INTERVAL,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+}
+
+impl LroPoller {
+    setters! {
+        polling_interval: Duration => polling_interval,
+        timeout: Duration => timeout,
+    }
+
+    /// Waits for the operation started with `response` to complete.
+    ///
+    /// `get` sends an authorized `GET` request to the given status URL. The operation is complete
+    /// right away unless `response` is a `202 Accepted` response with a status URL.
+    pub async fn wait<F, Fut>(&self, response: Response, mut get: F) -> crate::Result<()>
+    where
+        F: FnMut(Url) -> Fut,
+        Fut: Future<Output = crate::Result<Response>>,
+    {
+        if response.status() != StatusCode::Accepted {
+            return Ok(());
+        }
+        let headers = response.headers();
+        let monitor = match Monitor::from_headers(headers)? {
+            Some(monitor) => monitor,
+            None => return Ok(()),
+        };
+        let deadline = Deadline::after(self.timeout);
+        let mut delay = self.delay(headers);
+
+        loop {
+            if deadline.is_expired() {
+                return Err(Error::with_message(ErrorKind::Timeout, || {
+                    format!(
+                        "the operation did not complete within {:?}: its status is reported at {}",
+                        self.timeout,
+                        monitor.url()
+                    )
+                }));
+            }
+            sleep(delay.min(deadline.remaining())).await;
+
+            let response = get(monitor.url().clone()).await?;
+            delay = self.delay(response.headers());
+            match &monitor {
+                Monitor::AsyncOperation(_) => {
+                    let operation: OperationStatus = response.into_body().collect_json().await?;
+                    match operation.status.as_str() {
+                        "Succeeded" => return Ok(()),
+                        "Failed" | "Canceled" | "Cancelled" => return Err(operation.into_error()),
+                        _ => {}
+                    }
+                }
+                Monitor::Location(_) if response.status() != StatusCode::Accepted => return Ok(()),
+                Monitor::Location(_) => {}
+            }
+        }
+    }
+
+    fn delay(&self, headers: &Headers) -> Duration {
+        headers
+            .get_optional_str(&RETRY_AFTER)
+            .and_then(|seconds| seconds.trim().parse().ok())
+            .map(Duration::from_secs)
+            .unwrap_or(self.polling_interval)
+    }
+}
+
+/// Where the status of the operation is reported.
+enum Monitor {
+    /// The `Azure-AsyncOperation` URL, returning the status of the operation.
+    AsyncOperation(Url),
+    /// The `Location` URL, returning `202 Accepted` until the operation completes.
+    Location(Url),
+}
+
+impl Monitor {
+    fn from_headers(headers: &Headers) -> crate::Result<Option<Self>> {
+        let parse = |url: &str| {
+            Url::parse(url).map_err(|error| {
+                Error::full(
+                    ErrorKind::DataConversion,
+                    error,
+                    format!("invalid operation url: {url}"),
+                )
+            })
+        };
+        if let Some(url) = headers.get_optional_str(&AZURE_ASYNC_OPERATION) {
+            return Ok(Some(Monitor::AsyncOperation(parse(url)?)));
+        }
+        if let Some(url) = headers.get_optional_str(&LOCATION) {
+            return Ok(Some(Monitor::Location(parse(url)?)));
+        }
+        Ok(None)
+    }
+
+    fn url(&self) -> &Url {
+        match self {
+            Monitor::AsyncOperation(url) | Monitor::Location(url) => url,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct OperationStatus {
+    status: String,
+    #[serde(default)]
+    error: Option<OperationError>,
+}
+
+#[derive(Deserialize)]
+struct OperationError {
+    #[serde(default)]
+    code: Option<String>,
+    #[serde(default)]
+    message: Option<String>,
+}
+
+impl OperationStatus {
+    fn into_error(self) -> Error {
+        let status = self.status.to_lowercase();
+        match self.error {
+            Some(OperationError { code, message }) => Error::with_message(ErrorKind::Other, || {
+                format!(
+                    "operation {status}: {}: {}",
+                    code.unwrap_or_default(),
+                    message.unwrap_or_default()
+                )
+            }),
+            None => Error::with_message(ErrorKind::Other, || format!("operation {status}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BytesStream;
+    use futures::executor::block_on;
+    use std::sync::Mutex;
+
+    fn response(status: StatusCode, headers: &[(&'static str, &str)], body: &str) -> Response {
+        let mut response_headers = Headers::new();
+        for (name, value) in headers {
+            response_headers.insert(*name, value.to_string());
+        }
+        Response::new(
+            status,
+            response_headers,
+            Box::pin(BytesStream::new(body.to_owned())),
+        )
+    }
+
+    fn accepted() -> Response {
+        response(
+            StatusCode::Accepted,
+            &[
+                (
+                    "azure-asyncoperation",
+                    "https://management.azure.com/operation",
+                ),
+                ("retry-after", "0"),
+            ],
+            "",
+        )
+    }
+
+    /// Waits with the statuses of `bodies`, returning the result and the number of status checks.
+    fn wait(poller: &LroPoller, bodies: &[&'static str]) -> (crate::Result<()>, usize) {
+        let bodies = Mutex::new(bodies.to_vec());
+        let checks = Mutex::new(0);
+        let result = block_on(poller.wait(accepted(), |url| {
+            assert_eq!(url.as_str(), "https://management.azure.com/operation");
+            *checks.lock().unwrap() += 1;
+            let mut bodies = bodies.lock().unwrap();
+            let body = if bodies.len() > 1 {
+                bodies.remove(0)
+            } else {
+                bodies[0]
+            };
+            async move { Ok(response(StatusCode::Ok, &[("retry-after", "0")], body)) }
+        }));
+        let checks = *checks.lock().unwrap();
+        (result, checks)
+    }
+
+    #[test]
+    fn waits_for_the_operation_to_succeed() {
+        let (result, checks) = wait(
+            &LroPoller::default(),
+            &[r#"{"status":"InProgress"}"#, r#"{"status":"Succeeded"}"#],
+        );
+        result.unwrap();
+        assert_eq!(checks, 2);
+    }
+
+    #[test]
+    fn fails_with_the_error_of_the_operation() {
+        let (result, checks) = wait(
+            &LroPoller::default(),
+            &[r#"{"status":"Failed","error":{"code":"Conflict","message":"busy"}}"#],
+        );
+        let error = result.unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::Other);
+        assert_eq!(error.to_string(), "operation failed: Conflict: busy");
+        assert_eq!(checks, 1);
+    }
+
+    #[test]
+    fn times_out() {
+        let poller = LroPoller::default().timeout(Duration::from_millis(50));
+        let (result, checks) = wait(&poller, &[r#"{"status":"InProgress"}"#]);
+        assert_eq!(result.unwrap_err().kind(), &ErrorKind::Timeout);
+        assert!(checks >= 1);
+    }
+
+    #[test]
+    fn completed_operations_are_not_polled() {
+        let result = block_on(LroPoller::default().wait(
+            response(StatusCode::Ok, &[], ""),
+            |_| async {
+                Err(Error::message(
+                    ErrorKind::Other,
+                    "the operation is complete",
+                ))
+            },
+        ));
+        result.unwrap();
+    }
+
+    #[test]
+    fn waits_for_the_location_to_stop_accepting() {
+        let statuses = Mutex::new(vec![StatusCode::Accepted, StatusCode::Ok]);
+        let accepted = response(
+            StatusCode::Accepted,
+            &[
+                ("location", "https://management.azure.com/location"),
+                ("retry-after", "0"),
+            ],
+            "",
+        );
+        let result = block_on(LroPoller::default().wait(accepted, |url| {
+            assert_eq!(url.as_str(), "https://management.azure.com/location");
+            let status = statuses.lock().unwrap().remove(0);
+            async move { Ok(response(status, &[("retry-after", "0")], "")) }
+        }));
+        result.unwrap();
+        assert!(statuses.lock().unwrap().is_empty());
+    }
+}
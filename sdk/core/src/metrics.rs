@@ -0,0 +1,48 @@
+use std::fmt::Debug;
+
+/// A measurement taken by a pipeline, e.g. the duration of an operation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Metric {
+    /// The name of the metric, e.g. `az.client.request.duration`.
+    pub name: &'static str,
+    /// The measured value.
+    pub value: f64,
+    /// The unit of the value, e.g. `ms`.
+    pub unit: &'static str,
+    /// The dimensions of the measurement, e.g. `http.status_code`.
+    pub attributes: Vec<(&'static str, String)>,
+}
+
+impl Metric {
+    /// The value of the attribute named `key`, if any.
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Receives the metrics of the operations sent by a client, to export them to a metrics backend.
+///
+/// Set one with [`ClientOptions::metrics`](crate::ClientOptions::metrics). Recording must be
+/// cheap and must not block: it is called on the request path.
+///
+/// ```
+/// use azure_core::{ClientOptions, Metric, MetricsRecorder};
+/// use std::sync::Arc;
+///
+/// #[derive(Debug)]
+/// struct LogRecorder;
+///
+/// impl MetricsRecorder for LogRecorder {
+///     fn record(&self, metric: Metric) {
+///         println!("{} = {}{} {:?}", metric.name, metric.value, metric.unit, metric.attributes);
+///     }
+/// }
+///
+/// let options = ClientOptions::default().metrics(Arc::new(LogRecorder));
+/// ```
+pub trait MetricsRecorder: Debug + Send + Sync {
+    fn record(&self, metric: Metric);
+}
@@ -0,0 +1,70 @@
+use super::mock_response::MockResponse;
+use crate::error::{Error, ErrorKind};
+use crate::headers::Headers;
+use crate::{Context, Policy, PolicyResult, Request, StatusCode};
+use bytes::Bytes;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+/// A transport policy replying to the requests with canned responses, in order, without any
+/// recorded transaction.
+///
+/// The requests are kept to check how an operation builds them.
+///
+/// ```
+/// use azure_core::{headers::Headers, mock::MockTransportCannedPolicy, StatusCode};
+///
+/// let transport = MockTransportCannedPolicy::new()
+///     .reply(StatusCode::Created, Headers::new(), "")
+///     .reply(StatusCode::Ok, Headers::new(), r#"{"id":"1"}"#);
+/// assert!(transport.requests().is_empty());
+/// ```
+#[derive(Debug, Default)]
+pub struct MockTransportCannedPolicy {
+    responses: Mutex<VecDeque<MockResponse>>,
+    requests: Mutex<Vec<Request>>,
+}
+
+impl MockTransportCannedPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reply to the next request with this response.
+    #[must_use]
+    pub fn reply(self, status: StatusCode, headers: Headers, body: impl Into<Bytes>) -> Self {
+        self.responses
+            .lock()
+            .unwrap()
+            .push_back(MockResponse::new(status, headers, body.into()));
+        self
+    }
+
+    /// The requests sent so far, in order.
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Policy for MockTransportCannedPolicy {
+    async fn send(
+        &self,
+        _ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        // there must be no more policies
+        assert_eq!(0, next.len());
+
+        self.requests.lock().unwrap().push(request.clone());
+        let response = self.responses.lock().unwrap().pop_front();
+        match response {
+            Some(response) => Ok(response.into()),
+            None => Err(Error::with_message(ErrorKind::MockFramework, || {
+                format!("no canned response left for {}", request.url())
+            })),
+        }
+    }
+}
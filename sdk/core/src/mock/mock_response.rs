@@ -1,4 +1,4 @@
-use azure_core::{
+use crate::{
     error,
     headers::{HeaderName, HeaderValue, Headers},
     BytesStream, Response, StatusCode,
@@ -16,7 +16,7 @@ pub(crate) struct MockResponse {
 
 impl From<MockResponse> for Response {
     fn from(mock_response: MockResponse) -> Self {
-        let bytes_stream: azure_core::BytesStream = mock_response.body.into();
+        let bytes_stream: crate::BytesStream = mock_response.body.into();
 
         Self::new(
             mock_response.status,
@@ -1,4 +1,4 @@
-use azure_core::error::{Error, ErrorKind, ResultExt};
+use crate::error::{Error, ErrorKind, ResultExt};
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
@@ -31,7 +31,7 @@ impl MockTransaction {
         self.number.fetch_add(1, Ordering::SeqCst)
     }
 
-    pub(crate) fn file_path(&self, create_when_not_exist: bool) -> azure_core::Result<PathBuf> {
+    pub(crate) fn file_path(&self, create_when_not_exist: bool) -> crate::Result<PathBuf> {
         let root_path = {
             let mut cache = self.workspace_root.lock().unwrap();
             match &*cache {
@@ -77,7 +77,7 @@ impl MockTransaction {
 }
 
 /// Run cargo to get the root of the workspace
-fn workspace_root() -> azure_core::Result<String> {
+fn workspace_root() -> crate::Result<String> {
     let output = std::process::Command::new("cargo")
         .arg("metadata")
         .output()?;
@@ -0,0 +1,66 @@
+//! A testing framework that records the requests sent to Azure and their responses, and replays
+//! them afterwards without reaching Azure.
+//!
+//! The transport policies of this module are used with [`TransportOptions::new_custom_policy`](crate::TransportOptions::new_custom_policy).
+//! Transactions are stored in the `test/transactions` directory at the root of the workspace.
+//! See the [mock testing framework's documentation](https://github.com/Azure/azure-sdk-for-rust/blob/main/docs/mock_transport.md)
+//! for more information.
+//!
+//! [`MockTransportCannedPolicy`] replies with responses given by the test instead, to check how
+//! the requests are built.
+
+mod canned_policy;
+mod mock_request;
+mod mock_response;
+mod mock_transaction;
+mod player_policy;
+mod recorder_policy;
+
+pub use canned_policy::MockTransportCannedPolicy;
+use mock_transaction::MockTransaction;
+use player_policy::MockTransportPlayerPolicy;
+use recorder_policy::MockTransportRecorderPolicy;
+use std::sync::Arc;
+
+use crate::{HttpClient, Policy};
+
+pub const TESTING_MODE_KEY: &str = "TESTING_MODE";
+pub const TESTING_MODE_REPLAY: &str = "REPLAY";
+pub const TESTING_MODE_RECORD: &str = "RECORD";
+
+/// Whether live calls are recorded, that is whether the environment variable "TESTING_MODE" is set
+/// to "RECORD".
+///
+/// Tests only need real credentials and account names when recording.
+pub fn is_recording() -> bool {
+    std::env::var(TESTING_MODE_KEY).as_deref() == Ok(TESTING_MODE_RECORD)
+}
+
+/// Create a new mock transport policy.
+///
+/// Returns a reply mock policy unless the environment variable  "TESTING_MODE" is set to "RECORD".
+pub fn new_mock_transport(transaction_name: String) -> Arc<dyn Policy> {
+    if is_recording() {
+        log::warn!("mock testing framework record mode enabled");
+        new_recorder_transport(transaction_name, crate::new_http_client())
+    } else {
+        log::info!("mock testing framework replay mode enabled");
+        new_replay_transport(transaction_name)
+    }
+}
+
+/// Create a mock transport policy that replays recorded mock requests/responses.
+pub fn new_replay_transport(transaction_name: String) -> Arc<dyn Policy> {
+    Arc::new(MockTransportPlayerPolicy::new(transaction_name))
+}
+
+/// Create a mock transport policy that records live calls.
+pub fn new_recorder_transport(
+    transaction_name: String,
+    http_client: Arc<dyn HttpClient>,
+) -> Arc<dyn Policy> {
+    Arc::new(MockTransportRecorderPolicy::new(
+        transaction_name,
+        http_client,
+    ))
+}
@@ -1,9 +1,9 @@
-use crate::mock_request::RequestDeserializer;
+use super::mock_request::RequestDeserializer;
 
 use super::mock_response::MockResponse;
 use super::mock_transaction::MockTransaction;
-use azure_core::error::{Error, ErrorKind};
-use azure_core::{Body, Context, Policy, PolicyResult, Request};
+use crate::error::{Error, ErrorKind};
+use crate::{Body, Context, Policy, PolicyResult, Request};
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -64,7 +64,13 @@ impl Policy for MockTransportPlayerPolicy {
         // check if the passed request matches the one read from disk
         // We will ignore some headers that are bound to change every time
         // We'll probabily want to make the exclusion list dynamic at some point.
-        const SKIPPED_HEADERS: &[&str] = &["Date", "x-ms-date", "authorization", "user-agent"];
+        const SKIPPED_HEADERS: &[&str] = &[
+            "Date",
+            "x-ms-date",
+            "authorization",
+            "user-agent",
+            "x-ms-client-request-id",
+        ];
         let actual_headers = request
             .headers()
             .iter()
@@ -1,9 +1,9 @@
-use crate::mock_request::RequestSerializer;
+use super::mock_request::RequestSerializer;
 
 use super::mock_response::MockResponse;
 use super::MockTransaction;
-use azure_core::error::{ErrorKind, ResultExt};
-use azure_core::{Context, HttpClient, Policy, PolicyResult, Request};
+use crate::error::{ErrorKind, ResultExt};
+use crate::{Context, HttpClient, Policy, PolicyResult, Request};
 use std::io::Write;
 use std::sync::Arc;
 
@@ -1,9 +1,33 @@
+use crate::headers::{Headers, ETAG};
 use serde::{Deserialize, Serialize};
 use std::{fmt, str::FromStr};
 
+/// The entity tag of a resource, identifying a specific version of it.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Etag(String);
 
+impl Etag {
+    /// The wildcard entity tag `*`, matching any version of a resource.
+    pub fn any() -> Self {
+        Self("*".to_owned())
+    }
+
+    /// Whether this is the wildcard entity tag `*`.
+    pub fn is_any(&self) -> bool {
+        self.0 == "*"
+    }
+
+    /// Reads the entity tag from the `ETag` response header.
+    pub fn from_headers(headers: &Headers) -> crate::Result<Self> {
+        headers.get_str(&ETAG).map(Self::from)
+    }
+
+    /// Reads the entity tag from the `ETag` response header, if present.
+    pub fn from_headers_optional(headers: &Headers) -> crate::Result<Option<Self>> {
+        Ok(headers.get_optional_string(&ETAG).map(Self))
+    }
+}
+
 impl<T> From<T> for Etag
 where
     T: Into<String>,
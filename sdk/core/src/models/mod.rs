@@ -1,5 +1,7 @@
 mod etag;
 mod lease;
+mod resource_id;
 
 pub use etag::Etag;
 pub use lease::*;
+pub use resource_id::ResourceId;
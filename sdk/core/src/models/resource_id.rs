@@ -0,0 +1,394 @@
+use crate::error::{Error, ErrorKind};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+
+/// The identifier of an Azure Resource Manager resource.
+///
+/// Resource ids look like
+/// `/subscriptions/{subscription}/resourceGroups/{group}/providers/{namespace}/{type}/{name}`,
+/// optionally followed by the `{type}/{name}` of child resources, and by
+/// `/providers/{namespace}/{type}/{name}` for extension resources such as role assignments.
+/// Subscriptions, resource groups and tenant level resources are also identified this way.
+///
+/// # Example
+///
+/// ```
+/// use azure_core::ResourceId;
+/// let id: ResourceId = "/subscriptions/00000000-0000-0000-0000-000000000000/resourceGroups/rg\
+///     /providers/Microsoft.Storage/storageAccounts/account/blobServices/default"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(id.resource_group_name(), Some("rg"));
+/// assert_eq!(id.provider(), Some("Microsoft.Storage"));
+/// assert_eq!(
+///     id.resource_type().as_deref(),
+///     Some("Microsoft.Storage/storageAccounts/blobServices")
+/// );
+/// assert_eq!(id.name(), Some("default"));
+/// assert_eq!(id.parent().unwrap().name(), Some("account"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ResourceId {
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Segment {
+    Subscription(String),
+    ResourceGroup(String),
+    Resource {
+        /// The namespace of the provider, only set for the top level resources of a provider.
+        namespace: Option<String>,
+        resource_type: String,
+        name: String,
+    },
+}
+
+impl ResourceId {
+    /// The id of a subscription.
+    pub fn subscription(subscription_id: impl Into<String>) -> Self {
+        Self {
+            segments: vec![Segment::Subscription(subscription_id.into())],
+        }
+    }
+
+    /// The id of a resource group.
+    pub fn resource_group(
+        subscription_id: impl Into<String>,
+        resource_group: impl Into<String>,
+    ) -> Self {
+        let mut id = Self::subscription(subscription_id);
+        id.segments
+            .push(Segment::ResourceGroup(resource_group.into()));
+        id
+    }
+
+    /// The id of a resource, such as `Microsoft.Storage` `storageAccounts` `myaccount`, in a
+    /// resource group.
+    pub fn resource(
+        subscription_id: impl Into<String>,
+        resource_group: impl Into<String>,
+        provider: impl Into<String>,
+        resource_type: impl Into<String>,
+        name: impl Into<String>,
+    ) -> Self {
+        Self::resource_group(subscription_id, resource_group).provider_resource(
+            provider,
+            resource_type,
+            name,
+        )
+    }
+
+    /// The id of a child resource of this resource, such as `blobServices` `default` for a
+    /// storage account.
+    pub fn child(&self, resource_type: impl Into<String>, name: impl Into<String>) -> Self {
+        self.push(Segment::Resource {
+            namespace: None,
+            resource_type: resource_type.into(),
+            name: name.into(),
+        })
+    }
+
+    /// The id of a resource of a provider within the scope of this resource, such as an extension
+    /// resource like `Microsoft.Authorization` `roleAssignments` `{id}`.
+    pub fn provider_resource(
+        &self,
+        provider: impl Into<String>,
+        resource_type: impl Into<String>,
+        name: impl Into<String>,
+    ) -> Self {
+        self.push(Segment::Resource {
+            namespace: Some(provider.into()),
+            resource_type: resource_type.into(),
+            name: name.into(),
+        })
+    }
+
+    fn push(&self, segment: Segment) -> Self {
+        let mut id = self.clone();
+        id.segments.push(segment);
+        id
+    }
+
+    /// The id of the subscription of the resource, if any.
+    pub fn subscription_id(&self) -> Option<&str> {
+        self.segments.iter().find_map(|segment| match segment {
+            Segment::Subscription(subscription_id) => Some(subscription_id.as_str()),
+            _ => None,
+        })
+    }
+
+    /// The name of the resource group of the resource, if any.
+    pub fn resource_group_name(&self) -> Option<&str> {
+        self.segments.iter().find_map(|segment| match segment {
+            Segment::ResourceGroup(resource_group) => Some(resource_group.as_str()),
+            _ => None,
+        })
+    }
+
+    /// The namespace of the provider of the resource, such as `Microsoft.Storage`.
+    ///
+    /// Subscriptions and resource groups have no provider.
+    pub fn provider(&self) -> Option<&str> {
+        self.provider_segments()
+            .first()
+            .and_then(|segment| match segment {
+                Segment::Resource { namespace, .. } => namespace.as_deref(),
+                _ => None,
+            })
+    }
+
+    /// The full type of the resource, such as `Microsoft.Storage/storageAccounts/blobServices`.
+    ///
+    /// Subscriptions and resource groups have no type.
+    pub fn resource_type(&self) -> Option<String> {
+        let provider = self.provider()?;
+        let types = self
+            .provider_segments()
+            .iter()
+            .filter_map(|segment| match segment {
+                Segment::Resource { resource_type, .. } => Some(resource_type.as_str()),
+                _ => None,
+            });
+        Some(
+            std::iter::once(provider)
+                .chain(types)
+                .collect::<Vec<_>>()
+                .join("/"),
+        )
+    }
+
+    /// The name of the resource.
+    ///
+    /// This is the id of a subscription and the name of a resource group.
+    pub fn name(&self) -> Option<&str> {
+        self.segments.last().map(|segment| match segment {
+            Segment::Subscription(name) | Segment::ResourceGroup(name) => name.as_str(),
+            Segment::Resource { name, .. } => name.as_str(),
+        })
+    }
+
+    /// The id of the parent of the resource.
+    ///
+    /// The parent of a child resource is the resource owning it, the parent of a top level
+    /// resource is its resource group (or the scope it was created in) and the parent of a
+    /// resource group is its subscription.
+    pub fn parent(&self) -> Option<Self> {
+        match self.segments.split_last() {
+            Some((_, parent)) if !parent.is_empty() => Some(Self {
+                segments: parent.to_vec(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// The segments from the last top level resource of a provider.
+    fn provider_segments(&self) -> &[Segment] {
+        let start = self.segments.iter().rposition(|segment| {
+            matches!(
+                segment,
+                Segment::Resource {
+                    namespace: Some(_),
+                    ..
+                }
+            )
+        });
+        match start {
+            Some(start) => &self.segments[start..],
+            None => &[],
+        }
+    }
+}
+
+impl FromStr for ResourceId {
+    type Err = Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let invalid = |reason: &str| {
+            Error::with_message(ErrorKind::DataConversion, || {
+                format!("invalid resource id '{s}': {reason}")
+            })
+        };
+
+        let path = s
+            .strip_prefix('/')
+            .ok_or_else(|| invalid("it must start with '/'"))?;
+        let mut parts = path.trim_end_matches('/').split('/');
+        let mut segments = Vec::new();
+        while let Some(part) = parts.next() {
+            let mut next = |what: &str| match parts.next() {
+                Some(value) if !value.is_empty() => Ok(value.to_owned()),
+                _ => Err(invalid(&format!("missing {what} after '{part}'"))),
+            };
+            let segment = if part.eq_ignore_ascii_case("subscriptions") && segments.is_empty() {
+                Segment::Subscription(next("subscription id")?)
+            } else if part.eq_ignore_ascii_case("resourceGroups")
+                && matches!(segments.as_slice(), [Segment::Subscription(_)])
+            {
+                Segment::ResourceGroup(next("resource group name")?)
+            } else if part.eq_ignore_ascii_case("providers") {
+                Segment::Resource {
+                    namespace: Some(next("provider namespace")?),
+                    resource_type: next("resource type")?,
+                    name: next("resource name")?,
+                }
+            } else if matches!(segments.last(), Some(Segment::Resource { .. })) && !part.is_empty()
+            {
+                Segment::Resource {
+                    namespace: None,
+                    resource_type: part.to_owned(),
+                    name: next("resource name")?,
+                }
+            } else {
+                return Err(invalid(&format!("unexpected segment '{part}'")));
+            };
+            segments.push(segment);
+        }
+
+        if segments.is_empty() {
+            return Err(invalid("it is empty"));
+        }
+        Ok(Self { segments })
+    }
+}
+
+impl fmt::Display for ResourceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for segment in &self.segments {
+            match segment {
+                Segment::Subscription(subscription_id) => {
+                    write!(f, "/subscriptions/{subscription_id}")?;
+                }
+                Segment::ResourceGroup(resource_group) => {
+                    write!(f, "/resourceGroups/{resource_group}")?;
+                }
+                Segment::Resource {
+                    namespace,
+                    resource_type,
+                    name,
+                } => {
+                    if let Some(namespace) = namespace {
+                        write!(f, "/providers/{namespace}")?;
+                    }
+                    write!(f, "/{resource_type}/{name}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for ResourceId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ResourceId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SUBSCRIPTION: &str = "00000000-0000-0000-0000-000000000000";
+
+    #[test]
+    fn parses_resources() {
+        let id: ResourceId = format!(
+            "/subscriptions/{SUBSCRIPTION}/resourcegroups/rg/providers/Microsoft.Compute/virtualMachines/vm/extensions/ext"
+        )
+        .parse()
+        .unwrap();
+        assert_eq!(id.subscription_id(), Some(SUBSCRIPTION));
+        assert_eq!(id.resource_group_name(), Some("rg"));
+        assert_eq!(id.provider(), Some("Microsoft.Compute"));
+        assert_eq!(
+            id.resource_type().as_deref(),
+            Some("Microsoft.Compute/virtualMachines/extensions")
+        );
+        assert_eq!(id.name(), Some("ext"));
+
+        let parent = id.parent().unwrap();
+        assert_eq!(
+            parent,
+            ResourceId::resource(
+                SUBSCRIPTION,
+                "rg",
+                "Microsoft.Compute",
+                "virtualMachines",
+                "vm"
+            )
+        );
+        let resource_group = parent.parent().unwrap();
+        assert_eq!(
+            resource_group,
+            ResourceId::resource_group(SUBSCRIPTION, "rg")
+        );
+        assert_eq!(resource_group.resource_type(), None);
+        assert_eq!(
+            resource_group.parent(),
+            Some(ResourceId::subscription(SUBSCRIPTION))
+        );
+        assert_eq!(ResourceId::subscription(SUBSCRIPTION).parent(), None);
+    }
+
+    #[test]
+    fn parses_extension_and_tenant_resources() {
+        let vm = ResourceId::resource(
+            SUBSCRIPTION,
+            "rg",
+            "Microsoft.Compute",
+            "virtualMachines",
+            "vm",
+        );
+        let role_assignment =
+            vm.provider_resource("Microsoft.Authorization", "roleAssignments", "ra");
+        let id = format!("{role_assignment}");
+        assert_eq!(
+            id,
+            format!("/subscriptions/{SUBSCRIPTION}/resourceGroups/rg/providers/Microsoft.Compute/virtualMachines/vm/providers/Microsoft.Authorization/roleAssignments/ra")
+        );
+        let parsed: ResourceId = id.parse().unwrap();
+        assert_eq!(parsed, role_assignment);
+        assert_eq!(parsed.provider(), Some("Microsoft.Authorization"));
+        assert_eq!(
+            parsed.resource_type().as_deref(),
+            Some("Microsoft.Authorization/roleAssignments")
+        );
+        assert_eq!(parsed.parent(), Some(vm));
+
+        let tenant: ResourceId = "/providers/Microsoft.Management/managementGroups/mg"
+            .parse()
+            .unwrap();
+        assert_eq!(tenant.subscription_id(), None);
+        assert_eq!(tenant.name(), Some("mg"));
+        assert_eq!(tenant.parent(), None);
+    }
+
+    #[test]
+    fn rejects_invalid_ids() {
+        for id in [
+            "",
+            "/",
+            "subscriptions/sub",
+            "/subscriptions",
+            "/subscriptions/sub/resourceGroups",
+            "/subscriptions/sub/providers/Microsoft.Compute/virtualMachines",
+            "/resourceGroups/rg",
+            "/subscriptions/sub/virtualMachines/vm",
+        ] {
+            assert!(id.parse::<ResourceId>().is_err(), "{id}");
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+/// Options to tune how connections are opened and reused.
+///
+/// The defaults of the HTTP client backend are used for the options which are not set. High
+/// throughput clients, such as blob uploaders sending many blocks in parallel, usually need more
+/// idle connections per host than the default.
+///
+/// # Example
+///
+/// ```
+/// use azure_core::{ConnectionPoolOptions, HttpClientOptions, TransportOptions};
+/// use std::time::Duration;
+/// let pool = ConnectionPoolOptions::default()
+///     .max_idle_per_host(64usize)
+///     .idle_timeout(Duration::from_secs(30))
+///     .tcp_keepalive(Duration::from_secs(60));
+/// let transport =
+///     TransportOptions::with_http_client_options(HttpClientOptions::default().connection_pool(pool))
+///         .unwrap();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConnectionPoolOptions {
+    /// The maximum number of idle connections kept open for each host.
+    pub(crate) max_idle_per_host: Option<usize>,
+    /// How long an idle connection is kept open before being closed.
+    pub(crate) idle_timeout: Option<Duration>,
+    /// The interval of the TCP keep-alive probes.
+    pub(crate) tcp_keepalive: Option<Duration>,
+}
+
+impl ConnectionPoolOptions {
+    setters! {
+        max_idle_per_host: usize => Some(max_idle_per_host),
+        idle_timeout: Duration => Some(idle_timeout),
+        tcp_keepalive: Duration => Some(tcp_keepalive),
+    }
+}
+
+/// The versions of HTTP used to send requests.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HttpVersion {
+    /// HTTP/2 is used if the server offers it during the TLS handshake, HTTP/1.1 otherwise.
+    Auto,
+    /// Only HTTP/1.1 is used.
+    Http1Only,
+    /// HTTP/2 is used without negotiation, the server must support it.
+    Http2Only,
+}
+
+impl Default for HttpVersion {
+    fn default() -> Self {
+        Self::Auto
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HttpClientOptions;
+
+    #[test]
+    fn unset_options_use_backend_defaults() {
+        let options = HttpClientOptions::default();
+        assert_eq!(options.connection_pool, None);
+        assert_eq!(options.http_version, HttpVersion::Auto);
+
+        let pool = ConnectionPoolOptions::default().max_idle_per_host(16usize);
+        assert_eq!(pool.max_idle_per_host, Some(16));
+        assert_eq!(pool.idle_timeout, None);
+    }
+}
@@ -1,5 +1,19 @@
-use crate::policies::{ExponentialRetryPolicy, FixedRetryPolicy, NoRetryPolicy, Policy};
-use crate::{http_client, TimeoutPolicy};
+mod connection_pool;
+mod proxy;
+mod tls;
+
+pub use connection_pool::{ConnectionPoolOptions, HttpVersion};
+pub use proxy::ProxyOptions;
+pub use tls::{
+    Certificate, ClientIdentity, TlsOptions, TlsVersion, UnsupportedClientIdentityError,
+};
+
+use crate::headers::HeaderName;
+use crate::policies::{
+    ClassifiedRetryPolicy, ExponentialRetryPolicy, FixedRetryPolicy, NoRetryPolicy, Policy,
+    RetryClassifier,
+};
+use crate::{http_client, MetricsRecorder, TimeoutPolicy};
 use crate::{HttpClient, RetryPolicy};
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -27,6 +41,19 @@ pub struct ClientOptions {
     pub(crate) retry: RetryOptions,
     /// Telemetry options.
     pub(crate) telemetry: TelemetryOptions,
+    /// Client request id options.
+    pub(crate) client_request_id: ClientRequestIdOptions,
+    /// Logging options. Requests are not logged unless set.
+    pub(crate) logging: Option<LoggingOptions>,
+    /// Circuit breaker options. Requests do not fail fast unless set.
+    pub(crate) circuit_breaker: Option<CircuitBreakerOptions>,
+    /// Rate limit options. Requests are not rate limited unless set.
+    pub(crate) rate_limit: Option<RateLimitOptions>,
+    /// Receives the metrics of the operations. No metrics are recorded unless set.
+    pub(crate) metrics: Option<Arc<dyn MetricsRecorder>>,
+    /// Compression options. Requests and responses are not compressed unless set.
+    #[cfg(feature = "compression")]
+    pub(crate) compression: Option<CompressionOptions>,
     /// Transport options.
     pub(crate) transport: TransportOptions,
     /// Transport options.
@@ -40,6 +67,13 @@ impl ClientOptions {
             per_retry_policies: Vec::new(),
             retry: RetryOptions::default(),
             telemetry: TelemetryOptions::default(),
+            client_request_id: ClientRequestIdOptions::default(),
+            logging: None,
+            circuit_breaker: None,
+            rate_limit: None,
+            metrics: None,
+            #[cfg(feature = "compression")]
+            compression: None,
             transport,
             timeout: TimeoutPolicy::default(),
         }
@@ -60,9 +94,30 @@ impl ClientOptions {
         per_retry_policies: Vec<Arc<dyn Policy>> => per_retry_policies,
         retry: RetryOptions => retry,
         telemetry: TelemetryOptions => telemetry,
+        client_request_id: ClientRequestIdOptions => client_request_id,
+        logging: LoggingOptions => Some(logging),
+        circuit_breaker: CircuitBreakerOptions => Some(circuit_breaker),
+        rate_limit: RateLimitOptions => Some(rate_limit),
+        metrics: Arc<dyn MetricsRecorder> => Some(metrics),
         transport: TransportOptions => transport,
         timeout: TimeoutPolicy => timeout,
     }
+
+    /// The recorder receiving the metrics of the operations, if any.
+    ///
+    /// Client libraries use it to record metrics of their own, e.g. the request units consumed by
+    /// the Cosmos DB operations.
+    pub fn metrics_recorder(&self) -> Option<&Arc<dyn MetricsRecorder>> {
+        self.metrics.as_ref()
+    }
+
+    /// Enable compression of requests and responses.
+    #[cfg(feature = "compression")]
+    #[must_use]
+    pub fn compression(mut self, compression: CompressionOptions) -> Self {
+        self.compression = Some(compression);
+        self
+    }
 }
 
 /// The algorithm to apply when calculating the delay between retry attempts.
@@ -111,6 +166,8 @@ impl Default for RetryMode {
 pub struct RetryOptions {
     /// The algorithm to use for calculating retry delays.
     mode: RetryMode,
+    /// Decides which failed requests are retried.
+    classifier: Option<Arc<dyn RetryClassifier>>,
 }
 
 impl RetryOptions {
@@ -118,6 +175,7 @@ impl RetryOptions {
     pub fn exponential(options: ExponentialRetryOptions) -> Self {
         Self {
             mode: RetryMode::Exponential(options),
+            classifier: None,
         }
     }
 
@@ -125,6 +183,7 @@ impl RetryOptions {
     pub fn fixed(options: FixedRetryOptions) -> Self {
         Self {
             mode: RetryMode::Fixed(options),
+            classifier: None,
         }
     }
 
@@ -132,6 +191,7 @@ impl RetryOptions {
     pub fn custom<T: RetryPolicy + 'static>(policy: Arc<T>) -> Self {
         Self {
             mode: RetryMode::Custom(policy),
+            classifier: None,
         }
     }
 
@@ -139,26 +199,51 @@ impl RetryOptions {
     pub fn none() -> Self {
         Self {
             mode: RetryMode::None,
+            classifier: None,
         }
     }
 
+    /// Decide which failed requests are retried, instead of the
+    /// [`DefaultRetryClassifier`](crate::DefaultRetryClassifier).
+    ///
+    /// This applies to the exponential and fixed strategies: a custom retry policy classifies
+    /// failures with its own [`RetryPolicy::classifier`].
+    #[must_use]
+    pub fn classifier(mut self, classifier: Arc<dyn RetryClassifier>) -> Self {
+        self.classifier = Some(classifier);
+        self
+    }
+
     pub(crate) fn to_policy(&self) -> Arc<dyn Policy> {
         match &self.mode {
-            RetryMode::Exponential(options) => Arc::new(ExponentialRetryPolicy::new(
-                options.initial_delay,
-                options.max_retries,
-                options.max_total_elapsed,
-                options.max_delay,
-            )),
-            RetryMode::Fixed(options) => Arc::new(FixedRetryPolicy::new(
-                options.delay,
-                options.max_retries,
-                options.max_total_elapsed,
-            )),
+            RetryMode::Exponential(options) => {
+                let policy = ExponentialRetryPolicy::new(
+                    options.initial_delay,
+                    options.max_retries,
+                    options.max_total_elapsed,
+                    options.max_delay,
+                );
+                self.classified(policy)
+            }
+            RetryMode::Fixed(options) => {
+                let policy = FixedRetryPolicy::new(
+                    options.delay,
+                    options.max_retries,
+                    options.max_total_elapsed,
+                );
+                self.classified(policy)
+            }
             RetryMode::Custom(c) => c.clone(),
             RetryMode::None => Arc::new(NoRetryPolicy::default()),
         }
     }
+
+    fn classified<P: RetryPolicy + 'static>(&self, policy: P) -> Arc<dyn Policy> {
+        match &self.classifier {
+            Some(classifier) => Arc::new(ClassifiedRetryPolicy::new(policy, classifier.clone())),
+            None => Arc::new(policy),
+        }
+    }
 }
 
 /// Options for how an exponential retry strategy should behave.
@@ -270,11 +355,248 @@ impl Default for FixedRetryOptions {
 pub struct TelemetryOptions {
     /// Optional application ID to telemeter.
     pub(crate) application_id: Option<String>,
+    /// Whether to leave the SDK information out of the `User-Agent` header.
+    ///
+    /// The application ID, if any, is still sent. The default is `false`.
+    pub(crate) disabled: bool,
 }
 
 impl TelemetryOptions {
     setters! {
         application_id: String => Some(application_id),
+        disabled: bool => disabled,
+    }
+}
+
+/// Options for the client request id policy.
+#[derive(Clone, Debug, Default)]
+pub struct ClientRequestIdOptions {
+    /// Whether to fail when the service echoes back a different `x-ms-client-request-id`.
+    ///
+    /// The default is `false`.
+    pub(crate) validate_echo: bool,
+}
+
+impl ClientRequestIdOptions {
+    setters! {
+        validate_echo: bool => validate_echo,
+    }
+}
+
+/// Options for the HTTP logging policy.
+///
+/// Setting these options on the `ClientOptions` enables logging of every request attempt and its
+/// response. Well known secrets (`Authorization` headers, SAS signatures, account keys) are always
+/// redacted; additional headers and query parameters to redact can be specified here.
+///
+/// # Example
+///
+/// ```
+/// use azure_core::{ClientOptions, LoggingOptions};
+/// let options = ClientOptions::default()
+///     .logging(LoggingOptions::default().redacted_query_parameters(vec!["code".to_owned()]));
+/// ```
+#[derive(Clone, Debug)]
+pub struct LoggingOptions {
+    /// Additional headers whose values must not be logged.
+    pub(crate) redacted_headers: Vec<HeaderName>,
+    /// Additional query parameters whose values must not be logged.
+    pub(crate) redacted_query_parameters: Vec<String>,
+    /// Whether request and response headers are logged.
+    ///
+    /// The default is `true`.
+    pub(crate) log_headers: bool,
+}
+
+impl LoggingOptions {
+    setters! {
+        redacted_headers: Vec<HeaderName> => redacted_headers,
+        redacted_query_parameters: Vec<String> => redacted_query_parameters,
+        log_headers: bool => log_headers,
+    }
+}
+
+impl Default for LoggingOptions {
+    fn default() -> Self {
+        Self {
+            redacted_headers: Vec::new(),
+            redacted_query_parameters: Vec::new(),
+            log_headers: true,
+        }
+    }
+}
+
+/// Options for the circuit breaker policy.
+///
+/// # Example
+///
+/// ```
+/// use azure_core::{CircuitBreakerOptions, ClientOptions};
+/// use std::time::Duration;
+/// let options = ClientOptions::default().circuit_breaker(
+///     CircuitBreakerOptions::default()
+///         .failure_threshold(10u32)
+///         .cool_down(Duration::from_secs(60)),
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct CircuitBreakerOptions {
+    /// The number of consecutive failed attempts opening the circuit.
+    ///
+    /// The default is 5.
+    pub(crate) failure_threshold: u32,
+    /// How long requests fail fast once the circuit is open.
+    ///
+    /// The default is 30 seconds.
+    pub(crate) cool_down: Duration,
+}
+
+impl CircuitBreakerOptions {
+    setters! {
+        failure_threshold: u32 => failure_threshold,
+        cool_down: Duration => cool_down,
+    }
+}
+
+impl Default for CircuitBreakerOptions {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            cool_down: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Options for the rate limit policy.
+///
+/// Requests are sent at most at `requests_per_second` on average, with bursts of up to `burst`
+/// requests. Retries count as requests.
+///
+/// # Example
+///
+/// ```
+/// use azure_core::{ClientOptions, RateLimitOptions};
+/// let options = ClientOptions::default().rate_limit(RateLimitOptions::new(100.0).burst(20u32));
+/// ```
+#[derive(Clone, Debug)]
+pub struct RateLimitOptions {
+    /// The average number of requests sent per second.
+    pub(crate) requests_per_second: f64,
+    /// The maximum number of requests sent at once after a period of inactivity.
+    ///
+    /// The default is one second worth of requests.
+    pub(crate) burst: u32,
+}
+
+impl RateLimitOptions {
+    /// Limits requests to `requests_per_second`, which must be positive.
+    pub fn new(requests_per_second: f64) -> Self {
+        assert!(
+            requests_per_second > 0.0 && requests_per_second.is_finite(),
+            "requests per second must be positive, got {requests_per_second}"
+        );
+        Self {
+            requests_per_second,
+            burst: requests_per_second.ceil().min(f64::from(u32::MAX)) as u32,
+        }
+    }
+
+    setters! {
+        burst: u32 => burst,
+    }
+}
+
+/// Options for the HTTP compression policy.
+///
+/// Setting these options on the `ClientOptions` asks services for `gzip` or `deflate` compressed
+/// responses, which are transparently decompressed. Request bodies are only compressed if a
+/// `request_encoding` is set, as only some services (for example Azure Monitor ingestion) accept
+/// compressed payloads.
+///
+/// # Example
+///
+/// ```
+/// use azure_core::{ClientOptions, CompressionOptions, ContentEncoding};
+/// let options = ClientOptions::default()
+///     .compression(CompressionOptions::default().request_encoding(ContentEncoding::Gzip));
+/// ```
+#[cfg(feature = "compression")]
+#[derive(Clone, Debug)]
+pub struct CompressionOptions {
+    /// The encoding used to compress request bodies. Requests are not compressed unless set.
+    pub(crate) request_encoding: Option<ContentEncoding>,
+    /// Request bodies smaller than this size, in bytes, are not compressed.
+    ///
+    /// The default is 1 KiB.
+    pub(crate) min_request_size: usize,
+}
+
+#[cfg(feature = "compression")]
+impl CompressionOptions {
+    setters! {
+        request_encoding: ContentEncoding => Some(request_encoding),
+        min_request_size: usize => min_request_size,
+    }
+}
+
+#[cfg(feature = "compression")]
+impl Default for CompressionOptions {
+    fn default() -> Self {
+        Self {
+            request_encoding: None,
+            min_request_size: 1024,
+        }
+    }
+}
+
+/// A content encoding supported by the compression policy.
+#[cfg(feature = "compression")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContentEncoding {
+    Gzip,
+    Deflate,
+}
+
+#[cfg(feature = "compression")]
+impl ContentEncoding {
+    /// The value of the `Content-Encoding` header for this encoding.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Gzip => "gzip",
+            Self::Deflate => "deflate",
+        }
+    }
+}
+
+/// Options used to build the default `HttpClient`.
+#[derive(Clone, Debug, Default)]
+pub struct HttpClientOptions {
+    /// The proxy to send requests through.
+    pub(crate) proxy: Option<ProxyOptions>,
+    /// TLS customizations.
+    pub(crate) tls: Option<TlsOptions>,
+    /// Connection pool and keep-alive tuning.
+    pub(crate) connection_pool: Option<ConnectionPoolOptions>,
+    /// The versions of HTTP to use.
+    pub(crate) http_version: HttpVersion,
+}
+
+impl HttpClientOptions {
+    /// Creates options using the proxy configured in the environment, if any.
+    ///
+    /// See [`ProxyOptions::from_env`].
+    pub fn from_env() -> Self {
+        Self {
+            proxy: ProxyOptions::from_env(),
+            ..Default::default()
+        }
+    }
+
+    setters! {
+        proxy: ProxyOptions => Some(proxy),
+        tls: TlsOptions => Some(tls),
+        connection_pool: ConnectionPoolOptions => Some(connection_pool),
+        http_version: HttpVersion => http_version,
     }
 }
 
@@ -300,6 +622,25 @@ impl TransportOptions {
         Self { inner }
     }
 
+    /// Creates a new `TransportOptions` using the default `HttpClient` configured with the given
+    /// options.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use azure_core::{ClientOptions, HttpClientOptions, ProxyOptions, TransportOptions};
+    /// let proxy = ProxyOptions::new("proxy.contoso.com", 8080).unwrap();
+    /// let transport =
+    ///     TransportOptions::with_http_client_options(HttpClientOptions::default().proxy(proxy))
+    ///         .unwrap();
+    /// let options = ClientOptions::new(transport);
+    /// ```
+    pub fn with_http_client_options(options: HttpClientOptions) -> crate::Result<Self> {
+        Ok(Self::new(http_client::new_http_client_with_options(
+            &options,
+        )?))
+    }
+
     /// Creates a new `TransportOptions` using the custom policy.
     ///
     /// This policy is expected to be the last policy in the pipeline.
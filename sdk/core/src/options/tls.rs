@@ -0,0 +1,133 @@
+/// Options to customize how TLS connections are established.
+///
+/// These are needed to reach endpoints whose certificates are issued by an enterprise CA (for
+/// example through private links), or emulators using self-signed certificates, as well as
+/// services requiring mutual TLS.
+///
+/// # Example
+///
+/// ```no_run
+/// use azure_core::{HttpClientOptions, TlsOptions, TlsVersion, TransportOptions};
+/// let emulator_certificate = std::fs::read("emulator.pem").unwrap();
+/// let tls = TlsOptions::default()
+///     .add_root_certificate_pem(emulator_certificate)
+///     .min_tls_version(TlsVersion::Tls1_2);
+/// let transport =
+///     TransportOptions::with_http_client_options(HttpClientOptions::default().tls(tls)).unwrap();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct TlsOptions {
+    /// Certificates trusted in addition to the system's root certificates.
+    pub(crate) root_certificates: Vec<Certificate>,
+    /// The identity presented to the server for mutual TLS.
+    pub(crate) client_identity: Option<ClientIdentity>,
+    /// The minimum TLS version to negotiate.
+    pub(crate) min_tls_version: Option<TlsVersion>,
+}
+
+impl TlsOptions {
+    /// Trusts the PEM encoded certificate in addition to the system's root certificates.
+    pub fn add_root_certificate_pem(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.root_certificates.push(Certificate::Pem(pem.into()));
+        self
+    }
+
+    /// Trusts the DER encoded certificate in addition to the system's root certificates.
+    pub fn add_root_certificate_der(mut self, der: impl Into<Vec<u8>>) -> Self {
+        self.root_certificates.push(Certificate::Der(der.into()));
+        self
+    }
+
+    setters! {
+        client_identity: ClientIdentity => Some(client_identity),
+        min_tls_version: TlsVersion => Some(min_tls_version),
+    }
+}
+
+/// An encoded X.509 certificate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Certificate {
+    /// A PEM encoded certificate.
+    Pem(Vec<u8>),
+    /// A DER encoded certificate.
+    Der(Vec<u8>),
+}
+
+/// A client certificate and its private key, used for mutual TLS.
+///
+/// Which formats are supported depends on the TLS backend: PKCS #12 archives require the
+/// `enable_reqwest_native_tls` feature, PEM bundles require `enable_reqwest_rustls`. Building a
+/// transport with an unsupported format fails with an [`UnsupportedClientIdentityError`].
+#[derive(Clone)]
+pub enum ClientIdentity {
+    /// A DER encoded PKCS #12 archive and the password protecting it.
+    Pkcs12 { der: Vec<u8>, password: String },
+    /// A PEM bundle containing the private key and the certificate chain.
+    Pem(Vec<u8>),
+}
+
+impl std::fmt::Debug for ClientIdentity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pkcs12 { .. } => f.write_str("Pkcs12 { .. }"),
+            Self::Pem(_) => f.write_str("Pem(..)"),
+        }
+    }
+}
+
+/// The format of a [`ClientIdentity`] is not supported by the enabled TLS backend.
+///
+/// Returned, as the source of an [`ErrorKind::Other`](crate::error::ErrorKind::Other) error, when
+/// building a transport.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnsupportedClientIdentityError {
+    /// PKCS #12 archives require the `enable_reqwest_native_tls` feature.
+    Pkcs12,
+    /// PEM bundles require the `enable_reqwest_rustls` feature.
+    Pem,
+}
+
+impl std::error::Error for UnsupportedClientIdentityError {}
+
+impl std::fmt::Display for UnsupportedClientIdentityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pkcs12 => f.write_str(
+                "PKCS #12 client identities require the `enable_reqwest_native_tls` feature",
+            ),
+            Self::Pem => {
+                f.write_str("PEM client identities require the `enable_reqwest_rustls` feature")
+            }
+        }
+    }
+}
+
+impl From<UnsupportedClientIdentityError> for crate::error::Error {
+    fn from(error: UnsupportedClientIdentityError) -> Self {
+        crate::error::Error::new(crate::error::ErrorKind::Other, error)
+    }
+}
+
+/// A version of the TLS protocol.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TlsVersion {
+    Tls1_0,
+    Tls1_1,
+    Tls1_2,
+    Tls1_3,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn client_identity_debug_hides_secrets() {
+        let identity = ClientIdentity::Pkcs12 {
+            der: vec![1, 2, 3],
+            password: "secret".to_owned(),
+        };
+        let options = TlsOptions::default().client_identity(identity);
+        assert!(!format!("{options:?}").contains("secret"));
+    }
+}
@@ -1,5 +1,8 @@
 use crate::policies::TransportPolicy;
-use crate::policies::{CustomHeadersPolicy, Policy, TelemetryPolicy};
+use crate::policies::{
+    CircuitBreakerPolicy, ClientRequestIdPolicy, CustomHeadersPolicy, LoggingPolicy, MetricsPolicy,
+    Policy, RateLimitPolicy, TelemetryPolicy,
+};
 use crate::{ClientOptions, Context, Request, Response};
 use std::sync::Arc;
 
@@ -11,14 +14,28 @@ use std::sync::Arc;
 ///    immediately.
 /// 2. User-specified per-call policies are executed.
 /// 3. Telemetry policy.
-/// 4. Retry policy. It allows to re-execute the following policies.
-/// 5. Client library-specified per-retry policies. Per-retry polices are always executed at least once but are re-executed
+/// 4. Client request id policy. It identifies the operation with the `x-ms-client-request-id`
+///    header, so every retry of a request carries the same id.
+/// 5. Metrics policy, if a metrics recorder is set in the `ClientOptions`. It measures the
+///    duration of the whole operation, retries included.
+/// 6. Compression policy, if the `compression` feature is enabled and compression is enabled in
+///    the `ClientOptions`. The request body is compressed once, before it is authorized.
+/// 7. Retry policy. It allows to re-execute the following policies.
+/// 8. Circuit breaker policy, if enabled in the `ClientOptions`. It fails attempts fast while
+///    the service keeps failing.
+/// 9. Rate limit policy, if enabled in the `ClientOptions`. Every attempt waits for its turn
+///    before it is authorized, so that time based signatures do not go stale while waiting.
+/// 10. Client library-specified per-retry policies. Per-retry polices are always executed at least once but are re-executed
 ///    in case of retries.
-/// 6. User-specified per-retry policies are executed.
-/// 7. Authorization policy. Authorization can depend on the HTTP headers and/or the request body so it
+/// 11. User-specified per-retry policies are executed.
+/// 12. Authorization policy. Authorization can depend on the HTTP headers and/or the request body so it
 ///    must be executed right before sending the request to the transport. Also, the authorization
 ///    can depend on the current time so it must be executed at every retry.
-/// 8. Transport policy. Transport policy is always the last policy and is the policy that
+/// 13. Tracing policy, if the `opentelemetry` feature is enabled. It creates a span for every
+///    attempt and propagates it to the service with the `traceparent` header.
+/// 14. Logging policy, if enabled in the `ClientOptions`. It logs the request exactly as it will be
+///    sent (with secrets redacted) and the response it gets back.
+/// 15. Transport policy. Transport policy is always the last policy and is the policy that
 ///    actually constructs the `Response` to be passed up the pipeline.
 ///
 /// A pipeline is immutable. In other words a policy can either succeed and call the following
@@ -53,7 +70,7 @@ impl Pipeline {
                 + per_call_policies.len()
                 + options.per_retry_policies.len()
                 + per_retry_policies.len()
-                + 3,
+                + 11,
         );
 
         pipeline.extend_from_slice(&per_call_policies);
@@ -64,12 +81,50 @@ impl Pipeline {
 
         pipeline.push(Arc::new(CustomHeadersPolicy::default()));
 
+        pipeline.push(Arc::new(ClientRequestIdPolicy::new(
+            options.client_request_id.clone(),
+        )));
+
+        if let Some(metrics) = &options.metrics {
+            pipeline.push(Arc::new(MetricsPolicy::new(crate_name, metrics.clone())));
+        }
+
+        #[cfg(feature = "compression")]
+        if let Some(compression) = &options.compression {
+            pipeline.push(Arc::new(crate::policies::CompressionPolicy::new(
+                compression.clone(),
+            )));
+        }
+
         let retry_policy = options.retry.to_policy();
         pipeline.push(retry_policy);
 
+        if let Some(circuit_breaker) = &options.circuit_breaker {
+            pipeline.push(Arc::new(CircuitBreakerPolicy::new(circuit_breaker.clone())));
+        }
+
+        if let Some(rate_limit) = &options.rate_limit {
+            pipeline.push(Arc::new(RateLimitPolicy::new(rate_limit.clone())));
+        }
+
         pipeline.extend_from_slice(&per_retry_policies);
         pipeline.extend_from_slice(&options.per_retry_policies);
 
+        #[cfg(feature = "opentelemetry")]
+        pipeline.push(Arc::new(
+            crate::policies::TracingPolicy::new(crate_name).redacted_query_parameters(
+                options
+                    .logging
+                    .as_ref()
+                    .map(|logging| logging.redacted_query_parameters.clone())
+                    .unwrap_or_default(),
+            ),
+        ));
+
+        if let Some(logging) = &options.logging {
+            pipeline.push(Arc::new(LoggingPolicy::new(logging.clone())));
+        }
+
         let transport: Arc<dyn Policy> = Arc::new(TransportPolicy::new(options.transport.clone()));
 
         pipeline.push(transport);
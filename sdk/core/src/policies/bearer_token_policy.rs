@@ -0,0 +1,245 @@
+use crate::auth::{TokenCredential, TokenRequestOptions};
+use crate::error::{ErrorKind, ResultExt};
+use crate::headers::{AUTHORIZATION, WWW_AUTHENTICATE};
+use crate::policies::{Policy, PolicyResult};
+use crate::{Context, Request, StatusCode};
+use std::sync::Arc;
+
+/// Authorizes the requests with a bearer token of the credential, and handles the claims
+/// challenges of [Continuous Access Evaluation](https://docs.microsoft.com/azure/active-directory/conditional-access/concept-continuous-access-evaluation).
+///
+/// A service using Continuous Access Evaluation rejects a token which was revoked, e.g. because
+/// the user was disabled or changed location, with a `401 Unauthorized` response and a
+/// `WWW-Authenticate` challenge with the `insufficient_claims` error. The policy then requests a
+/// new token with the claims of the challenge, and sends the request once more with it.
+///
+/// The token is requested for every request: the credential is expected to cache the tokens, e.g.
+/// with a token cache.
+#[derive(Clone)]
+pub struct BearerTokenCredentialPolicy {
+    credential: Arc<dyn TokenCredential>,
+    resource: String,
+}
+
+impl BearerTokenCredentialPolicy {
+    /// Create a new `BearerTokenCredentialPolicy` authorizing the requests with the tokens of
+    /// `resource`, e.g. `https://vault.azure.net`.
+    pub fn new(credential: Arc<dyn TokenCredential>, resource: impl Into<String>) -> Self {
+        Self {
+            credential,
+            resource: resource.into(),
+        }
+    }
+
+    async fn authorize(
+        &self,
+        request: &mut Request,
+        options: &TokenRequestOptions,
+    ) -> crate::Result<()> {
+        let token = self
+            .credential
+            .get_token_with_options(&self.resource, options)
+            .await
+            .context(ErrorKind::Credential, "failed to get bearer token")?;
+        request.insert_header(AUTHORIZATION, format!("Bearer {}", token.token.secret()));
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for BearerTokenCredentialPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BearerTokenCredentialPolicy")
+            .field("resource", &self.resource)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Policy for BearerTokenCredentialPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        assert!(
+            !next.is_empty(),
+            "Authorization policies cannot be the last policy of a pipeline"
+        );
+        self.authorize(request, &TokenRequestOptions::default())
+            .await?;
+        let response = next[0].send(ctx, request, &next[1..]).await?;
+        if response.status() != StatusCode::Unauthorized {
+            return Ok(response);
+        }
+
+        let claims = match response
+            .headers()
+            .get_optional_str(&WWW_AUTHENTICATE)
+            .and_then(claims_challenge)
+        {
+            Some(claims) => claims,
+            None => return Ok(response),
+        };
+        log::debug!("the token was rejected with a claims challenge, requesting a new one");
+        self.authorize(request, &TokenRequestOptions::new().claims(claims))
+            .await?;
+        next[0].send(ctx, request, &next[1..]).await
+    }
+}
+
+/// The decoded claims of an `insufficient_claims` challenge, e.g.
+/// `Bearer realm="", error="insufficient_claims", claims="eyJhY2Nlc3NfdG9rZW4iOnt9fQ=="`.
+fn claims_challenge(www_authenticate: &str) -> Option<String> {
+    let parameters = www_authenticate
+        .trim()
+        .strip_prefix("Bearer")?
+        .split(',')
+        .filter_map(|parameter| {
+            let (name, value) = parameter.split_once('=')?;
+            Some((name.trim(), value.trim().trim_matches('"')))
+        })
+        .collect::<Vec<_>>();
+    let parameter = |name: &str| {
+        parameters
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, value)| *value)
+    };
+
+    if parameter("error") != Some("insufficient_claims") {
+        return None;
+    }
+    let claims = parameter("claims")?;
+    let decoded = base64::decode(claims)
+        .or_else(|_| base64::decode_config(claims, base64::URL_SAFE_NO_PAD))
+        .ok()?;
+    String::from_utf8(decoded).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::{AccessToken, TokenResponse};
+    use crate::headers::Headers;
+    use crate::{Method, Response};
+    use futures::executor::block_on;
+    use std::sync::Mutex;
+    use time::OffsetDateTime;
+
+    const CHALLENGE: &str =
+        r#"Bearer realm="", error="insufficient_claims", claims="eyJhY2Nlc3NfdG9rZW4iOnt9fQ==""#;
+
+    /// Returns a token with the claims it was requested with.
+    #[derive(Debug, Default)]
+    struct FakeCredential {
+        requests: Mutex<Vec<Option<String>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl TokenCredential for FakeCredential {
+        async fn get_token(&self, resource: &str) -> crate::Result<TokenResponse> {
+            self.get_token_with_options(resource, &TokenRequestOptions::default())
+                .await
+        }
+
+        async fn get_token_with_options(
+            &self,
+            _resource: &str,
+            options: &TokenRequestOptions,
+        ) -> crate::Result<TokenResponse> {
+            let claims = options.get_claims().map(ToOwned::to_owned);
+            self.requests.lock().unwrap().push(claims.clone());
+            Ok(TokenResponse::new(
+                AccessToken::new(claims.unwrap_or_else(|| "revoked".to_owned())),
+                OffsetDateTime::now_utc() + time::Duration::hours(1),
+            ))
+        }
+    }
+
+    /// Rejects the tokens without claims with a claims challenge, or `challenge` if set.
+    #[derive(Debug)]
+    struct ChallengingPolicy {
+        challenge: &'static str,
+        authorizations: Mutex<Vec<String>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Policy for ChallengingPolicy {
+        async fn send(
+            &self,
+            _ctx: &Context,
+            request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            let authorization = request.headers().get_str(&AUTHORIZATION)?.to_owned();
+            let status = if authorization == "Bearer revoked" {
+                StatusCode::Unauthorized
+            } else {
+                StatusCode::Ok
+            };
+            self.authorizations.lock().unwrap().push(authorization);
+            let mut headers = Headers::new();
+            headers.insert(WWW_AUTHENTICATE, self.challenge);
+            let body = futures::stream::iter(Vec::<crate::Result<bytes::Bytes>>::new());
+            Ok(Response::new(status, headers, Box::pin(body)))
+        }
+    }
+
+    fn send(challenge: &'static str) -> (StatusCode, Arc<FakeCredential>, Arc<ChallengingPolicy>) {
+        let credential = Arc::new(FakeCredential::default());
+        let policy =
+            BearerTokenCredentialPolicy::new(credential.clone(), "https://vault.azure.net");
+        let service = Arc::new(ChallengingPolicy {
+            challenge,
+            authorizations: Mutex::new(Vec::new()),
+        });
+        let next: Vec<Arc<dyn Policy>> = vec![service.clone()];
+        let mut request = Request::new(
+            url::Url::parse("https://myvault.vault.azure.net/secrets/secret").unwrap(),
+            Method::Get,
+        );
+        let response = block_on(policy.send(&Context::new(), &mut request, &next)).unwrap();
+        (response.status(), credential, service)
+    }
+
+    #[test]
+    fn retries_with_the_claims_of_the_challenge() {
+        let (status, credential, service) = send(CHALLENGE);
+        assert_eq!(status, StatusCode::Ok);
+        assert_eq!(
+            *credential.requests.lock().unwrap(),
+            vec![None, Some(r#"{"access_token":{}}"#.to_owned())]
+        );
+        assert_eq!(
+            *service.authorizations.lock().unwrap(),
+            vec![
+                "Bearer revoked".to_owned(),
+                r#"Bearer {"access_token":{}}"#.to_owned()
+            ]
+        );
+    }
+
+    #[test]
+    fn returns_other_rejections() {
+        let (status, credential, service) =
+            send(r#"Bearer realm="", error="invalid_token", error_description="expired""#);
+        assert_eq!(status, StatusCode::Unauthorized);
+        assert_eq!(credential.requests.lock().unwrap().len(), 1);
+        assert_eq!(service.authorizations.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn parses_claims_challenges() {
+        let challenge = r#"Bearer realm="", authorization_uri="https://login.microsoftonline.com/common/oauth2/authorize", error="insufficient_claims", claims="eyJhY2Nlc3NfdG9rZW4iOnsibmJmIjp7ImVzc2VudGlhbCI6dHJ1ZSwidmFsdWUiOiIxNjA0MTA2NjUxIn19fQ==""#;
+        assert_eq!(
+            claims_challenge(challenge).as_deref(),
+            Some(r#"{"access_token":{"nbf":{"essential":true,"value":"1604106651"}}}"#)
+        );
+
+        let expired = r#"Bearer realm="", error="invalid_token", error_description="expired""#;
+        assert_eq!(claims_challenge(expired), None);
+        assert_eq!(claims_challenge(r#"Basic realm="storage""#), None);
+    }
+}
@@ -0,0 +1,344 @@
+use crate::error::{Error, ErrorKind};
+use crate::headers::{Headers, RETRY_AFTER};
+use crate::options::CircuitBreakerOptions;
+use crate::policies::{Policy, PolicyResult};
+use crate::{date, Context, Request, StatusCode};
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use time::OffsetDateTime;
+
+/// Decides which attempts count as failures for a [`CircuitBreakerPolicy`].
+pub trait FailureClassifier: Debug + Send + Sync {
+    /// Whether the attempt, with the given outcome, failed.
+    fn is_failure(&self, result: &PolicyResult) -> bool;
+}
+
+/// Counts the server errors (`5xx` or `408 Request Timeout`), the IO errors and the timeouts as
+/// failures.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ServerFailureClassifier;
+
+impl FailureClassifier for ServerFailureClassifier {
+    fn is_failure(&self, result: &PolicyResult) -> bool {
+        match result {
+            Ok(response) => is_server_failure(response.status()),
+            Err(error) => match error.kind() {
+                ErrorKind::Io | ErrorKind::Timeout => true,
+                ErrorKind::HttpResponse { status, .. } => is_server_failure(*status),
+                _ => false,
+            },
+        }
+    }
+}
+
+/// Splits the requests of a [`CircuitBreakerPolicy`] into partitions, each with its own circuit.
+pub trait CircuitPartition: Debug + Send + Sync {
+    /// The key of the partition of the request.
+    fn key(&self, request: &Request) -> String;
+}
+
+/// A single circuit for all the requests of the pipeline.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SinglePartition;
+
+impl CircuitPartition for SinglePartition {
+    fn key(&self, _request: &Request) -> String {
+        String::new()
+    }
+}
+
+/// A circuit for each host, e.g. `myaccount.blob.core.windows.net`, so that a pipeline shared by
+/// many accounts keeps sending requests to the hosts which are healthy.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HostPartition;
+
+impl CircuitPartition for HostPartition {
+    fn key(&self, request: &Request) -> String {
+        let url = request.url();
+        match url.port() {
+            Some(port) => format!("{}:{port}", url.host_str().unwrap_or_default()),
+            None => url.host_str().unwrap_or_default().to_owned(),
+        }
+    }
+}
+
+/// Fails fast when the service keeps failing.
+///
+/// The circuit opens after `failure_threshold` consecutive attempts fail. By default, the server
+/// errors (`5xx` or `408 Request Timeout`), the IO errors and the timeouts are failures, and all
+/// the requests share a single circuit: [`CircuitBreakerPolicy::with_classifier`] takes a
+/// [`FailureClassifier`] and a [`CircuitPartition`] to count other failures, or to keep a circuit
+/// for each host.
+///
+/// While a circuit is open, its requests fail immediately without reaching the service, for the
+/// cool-down period or the `Retry-After` of the last failed response if it is longer. A single
+/// request is then let through: the circuit closes again if it succeeds, and opens for another
+/// cool-down period if it fails.
+///
+/// The errors returned while the circuit is open are not retried, so that callers polling a
+/// service during a regional outage do not pile up requests.
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerPolicy<C = ServerFailureClassifier, P = SinglePartition> {
+    options: CircuitBreakerOptions,
+    classifier: C,
+    partition: P,
+    /// The circuits which are not closed without failures, by partition key.
+    circuits: Arc<Mutex<HashMap<String, State>>>,
+}
+
+impl CircuitBreakerPolicy {
+    pub fn new(options: CircuitBreakerOptions) -> Self {
+        Self::with_classifier(options, ServerFailureClassifier, SinglePartition)
+    }
+}
+
+impl<C, P> CircuitBreakerPolicy<C, P> {
+    pub fn with_classifier(options: CircuitBreakerOptions, classifier: C, partition: P) -> Self {
+        Self {
+            options,
+            classifier,
+            partition,
+            circuits: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Whether a request of the partition `key` can be sent now.
+    fn try_acquire(&self, key: &str, now: OffsetDateTime) -> crate::Result<()> {
+        let mut circuits = self.circuits.lock().expect("circuit breaker lock poisoned");
+        let state = match circuits.get_mut(key) {
+            Some(state) => state,
+            None => return Ok(()),
+        };
+        match *state {
+            State::Closed { .. } => Ok(()),
+            // a trial request that never completed (for example because it was dropped) must not
+            // keep the circuit half open forever
+            State::Open { until } | State::HalfOpen { until } if now >= until => {
+                *state = State::HalfOpen {
+                    until: now + self.options.cool_down,
+                };
+                Ok(())
+            }
+            State::Open { until } | State::HalfOpen { until } => {
+                Err(Error::with_message(ErrorKind::Other, || {
+                    let remaining = (until - now).whole_milliseconds();
+                    let circuit = if key.is_empty() {
+                        "the circuit breaker".to_owned()
+                    } else {
+                        format!("the circuit breaker of {key}")
+                    };
+                    format!("{circuit} is open, requests fail fast for another {remaining}ms")
+                }))
+            }
+        }
+    }
+
+    fn record(&self, key: &str, failed: bool, retry_after: Option<Duration>, now: OffsetDateTime) {
+        let mut circuits = self.circuits.lock().expect("circuit breaker lock poisoned");
+        let consecutive_failures = match circuits.get(key) {
+            None => 0,
+            Some(State::Closed {
+                consecutive_failures,
+            }) => *consecutive_failures,
+            // the outcome of a trial request decides right away
+            Some(State::HalfOpen { .. }) => self.options.failure_threshold.saturating_sub(1),
+            // another request sent before the circuit opened
+            Some(State::Open { .. }) => return,
+        };
+
+        if !failed {
+            // forget the partitions which are healthy again
+            circuits.remove(key);
+        } else if consecutive_failures + 1 >= self.options.failure_threshold {
+            log::warn!(
+                "circuit breaker {}opened after {} consecutive failures",
+                if key.is_empty() {
+                    String::new()
+                } else {
+                    format!("of {key} ")
+                },
+                consecutive_failures + 1
+            );
+            let cool_down = retry_after.map_or(self.options.cool_down, |retry_after| {
+                retry_after.max(self.options.cool_down)
+            });
+            circuits.insert(
+                key.to_owned(),
+                State::Open {
+                    until: now + cool_down,
+                },
+            );
+        } else {
+            circuits.insert(
+                key.to_owned(),
+                State::Closed {
+                    consecutive_failures: consecutive_failures + 1,
+                },
+            );
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum State {
+    /// Requests are sent.
+    Closed { consecutive_failures: u32 },
+    /// Requests fail fast until the end of the cool-down period.
+    Open { until: OffsetDateTime },
+    /// A trial request was sent, the others fail fast.
+    HalfOpen { until: OffsetDateTime },
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl<C, P> Policy for CircuitBreakerPolicy<C, P>
+where
+    C: FailureClassifier + 'static,
+    P: CircuitPartition + 'static,
+{
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let key = self.partition.key(request);
+        self.try_acquire(&key, OffsetDateTime::now_utc())?;
+
+        let result = next[0].send(ctx, request, &next[1..]).await;
+        let now = OffsetDateTime::now_utc();
+        let failed = self.classifier.is_failure(&result);
+        let retry_after = match &result {
+            Ok(response) if failed => retry_after(response.headers(), now),
+            _ => None,
+        };
+        self.record(&key, failed, retry_after, now);
+        result
+    }
+}
+
+fn is_server_failure(status: StatusCode) -> bool {
+    status == StatusCode::RequestTimeout || status.is_server_error()
+}
+
+/// The delay of a `Retry-After` header, either in seconds or as an HTTP date.
+fn retry_after(headers: &Headers, now: OffsetDateTime) -> Option<Duration> {
+    let retry_after = headers.get_optional_str(&RETRY_AFTER)?.trim();
+    if let Ok(seconds) = retry_after.parse() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = date::parse_rfc1123(retry_after).ok()?;
+    (date - now).try_into().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn policy() -> (CircuitBreakerPolicy, OffsetDateTime) {
+        let options = CircuitBreakerOptions::default()
+            .failure_threshold(2u32)
+            .cool_down(Duration::from_secs(30));
+        (
+            CircuitBreakerPolicy::new(options),
+            OffsetDateTime::now_utc(),
+        )
+    }
+
+    #[test]
+    fn opens_after_consecutive_failures() {
+        let (policy, now) = policy();
+        policy.record("", true, None, now);
+        policy.record("", false, None, now);
+        policy.record("", true, None, now);
+        assert!(policy.try_acquire("", now).is_ok());
+
+        policy.record("", true, None, now);
+        assert!(policy.try_acquire("", now).is_err());
+        assert!(policy
+            .try_acquire("", now + Duration::from_secs(29))
+            .is_err());
+    }
+
+    #[test]
+    fn closes_after_successful_trial() {
+        let (policy, now) = policy();
+        policy.record("", true, None, now);
+        policy.record("", true, None, now);
+
+        let now = now + Duration::from_secs(30);
+        assert!(policy.try_acquire("", now).is_ok());
+        // only one trial request at a time
+        assert!(policy.try_acquire("", now).is_err());
+        policy.record("", false, None, now);
+        assert!(policy.try_acquire("", now).is_ok());
+        assert!(policy.try_acquire("", now).is_ok());
+    }
+
+    #[test]
+    fn reopens_after_failed_trial() {
+        let (policy, now) = policy();
+        policy.record("", true, None, now);
+        policy.record("", true, None, now);
+
+        let now = now + Duration::from_secs(30);
+        assert!(policy.try_acquire("", now).is_ok());
+        policy.record("", true, None, now);
+        assert!(policy.try_acquire("", now).is_err());
+        assert!(policy
+            .try_acquire("", now + Duration::from_secs(30))
+            .is_ok());
+    }
+
+    #[test]
+    fn keeps_a_circuit_per_partition() {
+        let (policy, now) = policy();
+        policy.record("busy.blob.core.windows.net", true, None, now);
+        policy.record("busy.blob.core.windows.net", true, None, now);
+        assert!(policy
+            .try_acquire("busy.blob.core.windows.net", now)
+            .is_err());
+        assert!(policy
+            .try_acquire("other.blob.core.windows.net", now)
+            .is_ok());
+
+        let request = Request::new(
+            "https://busy.blob.core.windows.net:8443/container"
+                .parse()
+                .unwrap(),
+            crate::Method::Get,
+        );
+        assert_eq!(
+            HostPartition.key(&request),
+            "busy.blob.core.windows.net:8443"
+        );
+        assert_eq!(SinglePartition.key(&request), "");
+    }
+
+    #[test]
+    fn honors_longer_retry_after() {
+        let (policy, now) = policy();
+        policy.record("", true, None, now);
+        policy.record("", true, Some(Duration::from_secs(60)), now);
+        assert!(policy
+            .try_acquire("", now + Duration::from_secs(59))
+            .is_err());
+        assert!(policy
+            .try_acquire("", now + Duration::from_secs(60))
+            .is_ok());
+    }
+
+    #[test]
+    fn parses_retry_after() {
+        let mut headers = Headers::new();
+        let now = date::parse_rfc1123("Sun, 06 Nov 1994 08:49:37 GMT").unwrap();
+        assert_eq!(retry_after(&headers, now), None);
+        headers.insert(RETRY_AFTER, "10");
+        assert_eq!(retry_after(&headers, now), Some(Duration::from_secs(10)));
+        headers.insert(RETRY_AFTER, "Sun, 06 Nov 1994 08:50:37 GMT");
+        assert_eq!(retry_after(&headers, now), Some(Duration::from_secs(60)));
+    }
+}
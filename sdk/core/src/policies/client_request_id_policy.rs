@@ -0,0 +1,149 @@
+use crate::error::{Error, ErrorKind};
+use crate::headers::{CLIENT_REQUEST_ID, REQUEST_ID};
+use crate::options::ClientRequestIdOptions;
+use crate::policies::{Policy, PolicyResult};
+use crate::request_options::ClientRequestId;
+use crate::{Context, Header, Request};
+use std::sync::Arc;
+
+/// Identifies every operation with an `x-ms-client-request-id` header.
+///
+/// The id is taken, in order, from the request itself, from a [`ClientRequestId`] in the
+/// [`Context`], or is a random UUID. As this policy runs before the retry policy, all the attempts
+/// of an operation share the same id.
+///
+/// The `x-ms-request-id` the service assigns to each attempt is logged with the client request id,
+/// so that the two can be correlated when investigating an issue with Azure support.
+#[derive(Debug, Clone, Default)]
+pub struct ClientRequestIdPolicy {
+    options: ClientRequestIdOptions,
+}
+
+impl ClientRequestIdPolicy {
+    pub fn new(options: ClientRequestIdOptions) -> Self {
+        Self { options }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Policy for ClientRequestIdPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let client_request_id = match request.headers().get_optional_string(&CLIENT_REQUEST_ID) {
+            Some(client_request_id) => client_request_id,
+            None => {
+                let client_request_id = match ctx.get::<ClientRequestId>() {
+                    Some(client_request_id) => client_request_id.value().as_str().to_owned(),
+                    None => uuid::Uuid::new_v4().to_string(),
+                };
+                request.insert_header(CLIENT_REQUEST_ID, client_request_id.clone());
+                client_request_id
+            }
+        };
+
+        let response = next[0].send(ctx, request, &next[1..]).await?;
+
+        if let Some(request_id) = response.headers().get_optional_str(&REQUEST_ID) {
+            log::debug!(
+                "client request id {client_request_id} was assigned request id {request_id}"
+            );
+        }
+
+        if self.options.validate_echo {
+            if let Some(echoed) = response.headers().get_optional_str(&CLIENT_REQUEST_ID) {
+                if echoed != client_request_id {
+                    return Err(Error::with_message(ErrorKind::DataConversion, || {
+                        format!(
+                            "the service echoed client request id {echoed} instead of {client_request_id}"
+                        )
+                    }));
+                }
+            }
+        }
+
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::headers::Headers;
+    use crate::{Method, Response, StatusCode};
+    use futures::executor::block_on;
+
+    /// Answers with the client request id of the request, or with `echo` if set.
+    #[derive(Debug)]
+    struct EchoPolicy {
+        echo: Option<&'static str>,
+    }
+
+    #[async_trait::async_trait]
+    impl Policy for EchoPolicy {
+        async fn send(
+            &self,
+            _ctx: &Context,
+            request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            let echo = match self.echo {
+                Some(echo) => echo.to_owned(),
+                None => request.headers().get_str(&CLIENT_REQUEST_ID)?.to_owned(),
+            };
+            let mut headers = Headers::new();
+            headers.insert(CLIENT_REQUEST_ID, echo);
+            headers.insert(REQUEST_ID, "f9e0e7b4-1234-4321-abcd-0123456789ab");
+            let body = futures::stream::iter(Vec::<crate::Result<bytes::Bytes>>::new());
+            Ok(Response::new(StatusCode::Ok, headers, Box::pin(body)))
+        }
+    }
+
+    fn send(
+        options: ClientRequestIdOptions,
+        ctx: &Context,
+        echo: Option<&'static str>,
+    ) -> (Request, crate::Result<Response>) {
+        let policy = ClientRequestIdPolicy::new(options);
+        let mut request =
+            Request::new(url::Url::parse("https://example.com").unwrap(), Method::Get);
+        let next: Vec<Arc<dyn Policy>> = vec![Arc::new(EchoPolicy { echo })];
+        let response = block_on(policy.send(ctx, &mut request, &next));
+        (request, response)
+    }
+
+    #[test]
+    fn generates_client_request_id() {
+        let (request, response) = send(ClientRequestIdOptions::default(), &Context::new(), None);
+        let client_request_id = request.headers().get_str(&CLIENT_REQUEST_ID).unwrap();
+        assert!(uuid::Uuid::parse_str(client_request_id).is_ok());
+        assert!(response.is_ok());
+    }
+
+    #[test]
+    fn uses_client_request_id_from_context() {
+        let mut ctx = Context::new();
+        ctx.insert(ClientRequestId::new("my-operation"));
+        let (request, _) = send(ClientRequestIdOptions::default(), &ctx, None);
+        assert_eq!(
+            request.headers().get_optional_str(&CLIENT_REQUEST_ID),
+            Some("my-operation")
+        );
+    }
+
+    #[test]
+    fn validates_echoed_client_request_id() {
+        let mut ctx = Context::new();
+        ctx.insert(ClientRequestId::new("my-operation"));
+        let options = ClientRequestIdOptions::default().validate_echo(true);
+        let (_, response) = send(options.clone(), &ctx, Some("my-operation"));
+        assert!(response.is_ok());
+
+        let (_, response) = send(options, &ctx, Some("another-operation"));
+        assert!(response.is_err());
+    }
+}
@@ -0,0 +1,358 @@
+use crate::error::{ErrorKind, ResultExt};
+use crate::headers::{ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_LENGTH};
+use crate::options::{CompressionOptions, ContentEncoding};
+use crate::policies::{Policy, PolicyResult};
+use crate::{Body, Context, Method, Request, Response, ResponseBody};
+use bytes::Bytes;
+use flate2::write::{GzDecoder, GzEncoder, ZlibDecoder, ZlibEncoder};
+use flate2::Compression;
+use futures::{ready, Stream};
+use std::io::Write;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::Poll;
+
+/// Compresses requests and decompresses responses.
+///
+/// The policy asks for compressed responses with the `Accept-Encoding` header and transparently
+/// decompresses `gzip` and `deflate` response bodies, which are then streamed to the caller
+/// without the `Content-Encoding` and `Content-Length` headers of the compressed body. If the
+/// caller already set `Accept-Encoding` on the request, the response is left untouched.
+///
+/// Request bodies are only compressed when enabled in the [`CompressionOptions`], as only some
+/// services accept compressed payloads.
+#[derive(Debug, Clone)]
+pub struct CompressionPolicy {
+    options: CompressionOptions,
+}
+
+impl CompressionPolicy {
+    pub fn new(options: CompressionOptions) -> Self {
+        Self { options }
+    }
+
+    fn compress_request(&self, request: &mut Request) -> crate::Result<()> {
+        let encoding = match self.options.request_encoding {
+            Some(encoding) => encoding,
+            None => return Ok(()),
+        };
+        if request
+            .headers()
+            .get_optional_str(&CONTENT_ENCODING)
+            .is_some()
+        {
+            return Ok(());
+        }
+        let body = match request.body() {
+            Body::Bytes(bytes) if bytes.len() >= self.options.min_request_size => bytes,
+            _ => return Ok(()),
+        };
+
+        let compressed = compress(body, encoding)
+            .context(ErrorKind::DataConversion, "failed to compress request body")?;
+        request.insert_header(CONTENT_ENCODING, encoding.as_str());
+        request.insert_header(CONTENT_LENGTH, compressed.len().to_string());
+        request.set_body(compressed);
+        Ok(())
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Policy for CompressionPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        self.compress_request(request)?;
+
+        let accept_encoding = request
+            .headers()
+            .get_optional_str(&ACCEPT_ENCODING)
+            .is_none();
+        if accept_encoding {
+            request.insert_header(ACCEPT_ENCODING, "gzip, deflate");
+        }
+
+        let response = next[0].send(ctx, request, &next[1..]).await?;
+        if !accept_encoding || request.method() == &Method::Head {
+            return Ok(response);
+        }
+
+        let decoder = response
+            .headers()
+            .get_optional_str(&CONTENT_ENCODING)
+            .and_then(Decoder::for_content_encoding);
+        match decoder {
+            Some(decoder) => {
+                let (status, mut headers, body) = response.deconstruct();
+                headers.remove(CONTENT_ENCODING);
+                headers.remove(CONTENT_LENGTH);
+                let body = DecompressedBody::new(body, decoder);
+                Ok(Response::new(status, headers, Box::pin(body)))
+            }
+            None => Ok(response),
+        }
+    }
+}
+
+fn compress(data: &[u8], encoding: ContentEncoding) -> std::io::Result<Bytes> {
+    let compressed = match encoding {
+        ContentEncoding::Gzip => {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(data)?;
+            encoder.finish()?
+        }
+        ContentEncoding::Deflate => {
+            let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(data)?;
+            encoder.finish()?
+        }
+    };
+    Ok(compressed.into())
+}
+
+enum Decoder {
+    Gzip(GzDecoder<Vec<u8>>),
+    Deflate(ZlibDecoder<Vec<u8>>),
+}
+
+impl Decoder {
+    fn for_content_encoding(content_encoding: &str) -> Option<Self> {
+        match content_encoding.trim().to_ascii_lowercase().as_str() {
+            "gzip" | "x-gzip" => Some(Self::Gzip(GzDecoder::new(Vec::new()))),
+            // HTTP `deflate` is the zlib format
+            "deflate" => Some(Self::Deflate(ZlibDecoder::new(Vec::new()))),
+            _ => None,
+        }
+    }
+
+    fn write_all(&mut self, data: &[u8]) -> std::io::Result<Vec<u8>> {
+        match self {
+            Self::Gzip(decoder) => {
+                decoder.write_all(data)?;
+                Ok(std::mem::take(decoder.get_mut()))
+            }
+            Self::Deflate(decoder) => {
+                decoder.write_all(data)?;
+                Ok(std::mem::take(decoder.get_mut()))
+            }
+        }
+    }
+
+    fn finish(&mut self) -> std::io::Result<Vec<u8>> {
+        match self {
+            Self::Gzip(decoder) => {
+                decoder.try_finish()?;
+                Ok(std::mem::take(decoder.get_mut()))
+            }
+            Self::Deflate(decoder) => {
+                decoder.try_finish()?;
+                Ok(std::mem::take(decoder.get_mut()))
+            }
+        }
+    }
+}
+
+/// Wraps `body` so that it is decompressed as it is streamed, according to its
+/// `content_encoding`, e.g. the content encoding a blob was stored with.
+///
+/// Only `gzip` and `deflate` are supported: bodies with any other, or no, content encoding are
+/// returned unchanged.
+pub fn decompress_body(body: ResponseBody, content_encoding: Option<&str>) -> ResponseBody {
+    match content_encoding.and_then(Decoder::for_content_encoding) {
+        Some(decoder) => ResponseBody::from_stream(DecompressedBody::new(body, decoder)),
+        None => body,
+    }
+}
+
+/// A response body decompressed as it is streamed.
+struct DecompressedBody {
+    body: ResponseBody,
+    decoder: Decoder,
+    /// Whether any data was received: empty bodies are not compressed, even if they claim to be.
+    received: bool,
+    done: bool,
+}
+
+impl DecompressedBody {
+    fn new(body: ResponseBody, decoder: Decoder) -> Self {
+        Self {
+            body,
+            decoder,
+            received: false,
+            done: false,
+        }
+    }
+}
+
+impl Stream for DecompressedBody {
+    type Item = crate::Result<Bytes>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if this.done {
+                return Poll::Ready(None);
+            }
+            let decompressed = match ready!(Pin::new(&mut this.body).poll_next(cx)) {
+                Some(Ok(data)) => {
+                    this.received |= !data.is_empty();
+                    this.decoder.write_all(&data)
+                }
+                Some(Err(error)) => {
+                    this.done = true;
+                    return Poll::Ready(Some(Err(error)));
+                }
+                None => {
+                    this.done = true;
+                    if !this.received {
+                        return Poll::Ready(None);
+                    }
+                    this.decoder.finish()
+                }
+            };
+            match decompressed {
+                // the decoder may need more input before producing any output
+                Ok(decompressed) if decompressed.is_empty() => continue,
+                Ok(decompressed) => return Poll::Ready(Some(Ok(decompressed.into()))),
+                Err(error) => {
+                    this.done = true;
+                    return Poll::Ready(Some(Err(crate::Error::full(
+                        ErrorKind::DataConversion,
+                        error,
+                        "failed to decompress response body",
+                    ))));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::headers::Headers;
+    use crate::StatusCode;
+    use futures::executor::block_on;
+
+    /// Answers with the request body, compressed as the request asked for.
+    #[derive(Debug)]
+    struct EchoPolicy;
+
+    #[async_trait::async_trait]
+    impl Policy for EchoPolicy {
+        async fn send(
+            &self,
+            _ctx: &Context,
+            request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            let body = match request.body() {
+                Body::Bytes(bytes) => bytes.clone(),
+                Body::SeekableStream(_) => {
+                    unreachable!("the compressed requests have a bytes body")
+                }
+            };
+            let mut headers = Headers::new();
+            if let Some(encoding) = request.headers().get_optional_str(&CONTENT_ENCODING) {
+                headers.insert(CONTENT_ENCODING, encoding.to_owned());
+            }
+            let body = futures::stream::iter(vec![Ok(body)]);
+            Ok(Response::new(StatusCode::Ok, headers, Box::pin(body)))
+        }
+    }
+
+    fn send(options: CompressionOptions, body: &'static [u8]) -> (Request, Response) {
+        let policy = CompressionPolicy::new(options);
+        let mut request = Request::new(
+            url::Url::parse("https://example.com").unwrap(),
+            Method::Post,
+        );
+        request.set_body(Bytes::from_static(body));
+        let next: Vec<Arc<dyn Policy>> = vec![Arc::new(EchoPolicy)];
+        let response = block_on(policy.send(&Context::new(), &mut request, &next)).unwrap();
+        (request, response)
+    }
+
+    #[test]
+    fn round_trips_compressed_bodies() {
+        let data = "a large payload ".repeat(100);
+        let data: &'static [u8] = Box::leak(data.into_bytes().into_boxed_slice());
+        for encoding in [ContentEncoding::Gzip, ContentEncoding::Deflate] {
+            let options = CompressionOptions::default().request_encoding(encoding);
+            let (request, response) = send(options, data);
+
+            assert_eq!(
+                request.headers().get_optional_str(&CONTENT_ENCODING),
+                Some(encoding.as_str())
+            );
+            assert!(request.body().len() < data.len());
+            assert!(response
+                .headers()
+                .get_optional_str(&CONTENT_ENCODING)
+                .is_none());
+            assert_eq!(block_on(response.into_body().collect()).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn leaves_small_bodies_uncompressed() {
+        let options = CompressionOptions::default()
+            .request_encoding(ContentEncoding::Gzip)
+            .min_request_size(1024usize);
+        let (request, response) = send(options, b"small");
+
+        assert!(request
+            .headers()
+            .get_optional_str(&CONTENT_ENCODING)
+            .is_none());
+        assert_eq!(
+            request.headers().get_optional_str(&ACCEPT_ENCODING),
+            Some("gzip, deflate")
+        );
+        assert_eq!(
+            block_on(response.into_body().collect()).unwrap(),
+            &b"small"[..]
+        );
+    }
+
+    fn chunked_body(data: &[u8], chunk_size: usize) -> ResponseBody {
+        let chunks = data
+            .chunks(chunk_size)
+            .map(|chunk| Ok(Bytes::copy_from_slice(chunk)))
+            .collect::<Vec<_>>();
+        ResponseBody::from_stream(futures::stream::iter(chunks))
+    }
+
+    #[test]
+    fn decompresses_bodies_in_chunks() {
+        let data = "some log line\n".repeat(1000);
+        for encoding in [ContentEncoding::Gzip, ContentEncoding::Deflate] {
+            let compressed = compress(data.as_bytes(), encoding).unwrap();
+            let body = decompress_body(chunked_body(&compressed, 7), Some(encoding.as_str()));
+            assert_eq!(block_on(body.collect()).unwrap(), data.as_bytes());
+        }
+    }
+
+    #[test]
+    fn leaves_unknown_encodings_untouched() {
+        let body = decompress_body(chunked_body(b"raw", 2), Some("br"));
+        assert_eq!(block_on(body.collect()).unwrap(), &b"raw"[..]);
+
+        let body = decompress_body(chunked_body(b"raw", 2), None);
+        assert_eq!(block_on(body.collect()).unwrap(), &b"raw"[..]);
+    }
+
+    #[test]
+    fn fails_on_corrupted_bodies() {
+        let body = decompress_body(chunked_body(b"not gzip at all", 4), Some("gzip"));
+        let error = block_on(body.collect()).unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::DataConversion);
+    }
+}
@@ -0,0 +1,241 @@
+use crate::headers::{HeaderName, HeaderValue, Headers};
+use crate::options::LoggingOptions;
+use crate::policies::{Policy, PolicyResult};
+use crate::{Context, Request};
+use std::fmt::Write;
+use std::sync::Arc;
+use time::OffsetDateTime;
+use url::Url;
+
+const REDACTED: &str = "REDACTED";
+
+/// Headers whose values are never logged.
+const REDACTED_HEADERS: &[&str] = &[
+    "authorization",
+    "proxy-authorization",
+    "cookie",
+    "set-cookie",
+    "x-ms-copy-source-authorization",
+    "x-ms-encryption-key",
+    "x-ms-source-encryption-key",
+    "ocp-apim-subscription-key",
+];
+
+/// Query parameters whose values are never logged.
+const REDACTED_QUERY_PARAMETERS: &[&str] = &["sig"];
+
+/// Connection string keys whose values are never logged.
+const REDACTED_CONNECTION_STRING_KEYS: &[&str] = &["accountkey", "sharedaccesskey"];
+
+/// Logs every request attempt and its response.
+///
+/// The method, URL, status, headers and elapsed time are logged at the `info` level. Secrets are
+/// redacted before logging: `Authorization` (and similar) headers, SAS signatures in URLs and
+/// account keys in connection-string-like values.
+#[derive(Debug, Clone)]
+pub struct LoggingPolicy {
+    options: LoggingOptions,
+}
+
+impl LoggingPolicy {
+    pub fn new(options: LoggingOptions) -> Self {
+        Self { options }
+    }
+
+    fn is_redacted_header(&self, name: &HeaderName) -> bool {
+        REDACTED_HEADERS.contains(&name.as_str()) || self.options.redacted_headers.contains(name)
+    }
+
+    fn sanitize_url(&self, url: &Url) -> String {
+        sanitize_url(url, &self.options.redacted_query_parameters)
+    }
+
+    fn sanitize_header(&self, name: &HeaderName, value: &HeaderValue) -> String {
+        if self.is_redacted_header(name) {
+            return REDACTED.to_owned();
+        }
+        let value = value.as_str();
+        match Url::parse(value) {
+            Ok(url) if url.scheme() == "http" || url.scheme() == "https" => self.sanitize_url(&url),
+            _ => sanitize_connection_string(value),
+        }
+    }
+
+    fn format_headers(&self, message: &mut String, headers: &Headers) {
+        if !self.options.log_headers {
+            return;
+        }
+        let mut headers = headers.iter().collect::<Vec<_>>();
+        headers.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        for (name, value) in headers {
+            let _ = write!(
+                message,
+                "\n\t{}: {}",
+                name.as_str(),
+                self.sanitize_header(name, value)
+            );
+        }
+    }
+}
+
+/// Redacts the SAS signature, the query parameters in `redacted_query_parameters` and the
+/// password of `url`.
+pub(crate) fn sanitize_url(url: &Url, redacted_query_parameters: &[String]) -> String {
+    let is_redacted = |name: &str| {
+        REDACTED_QUERY_PARAMETERS
+            .iter()
+            .any(|p| p.eq_ignore_ascii_case(name))
+            || redacted_query_parameters
+                .iter()
+                .any(|p| p.eq_ignore_ascii_case(name))
+    };
+    let mut url = url.clone();
+    if url.query().is_some() {
+        let pairs = url
+            .query_pairs()
+            .map(|(name, value)| {
+                let value = if is_redacted(&name) {
+                    REDACTED.to_owned()
+                } else {
+                    value.into_owned()
+                };
+                (name.into_owned(), value)
+            })
+            .collect::<Vec<_>>();
+        url.query_pairs_mut().clear().extend_pairs(pairs);
+    }
+    if url.password().is_some() {
+        let _ = url.set_password(Some(REDACTED));
+    }
+    url.to_string()
+}
+
+/// Redacts the values of secret keys in `key=value;key=value` formatted strings.
+fn sanitize_connection_string(value: &str) -> String {
+    if !value.contains('=') {
+        return value.to_owned();
+    }
+    value
+        .split(';')
+        .map(|part| match part.split_once('=') {
+            Some((key, _))
+                if REDACTED_CONNECTION_STRING_KEYS
+                    .iter()
+                    .any(|k| k.eq_ignore_ascii_case(key.trim())) =>
+            {
+                format!("{key}={REDACTED}")
+            }
+            _ => part.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Policy for LoggingPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let method = request.method().clone();
+        let url = self.sanitize_url(request.url());
+
+        let mut message = format!("==> {method} {url}");
+        self.format_headers(&mut message, request.headers());
+        log::info!("{message}");
+
+        let start = OffsetDateTime::now_utc();
+        let result = next[0].send(ctx, request, &next[1..]).await;
+        let elapsed = (OffsetDateTime::now_utc() - start).whole_milliseconds();
+
+        match &result {
+            Ok(response) => {
+                let mut message = format!(
+                    "<== {} {method} {url} ({elapsed} ms)",
+                    u16::from(response.status())
+                );
+                self.format_headers(&mut message, response.headers());
+                log::info!("{message}");
+            }
+            Err(error) => {
+                log::warn!("<== {method} {url} failed after {elapsed} ms: {error}");
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacts_sas_signature() {
+        let policy = LoggingPolicy::new(LoggingOptions::default());
+        let url = Url::parse(
+            "https://account.blob.core.windows.net/container/blob?sv=2019-12-12&sig=secret%2Bsignature",
+        )
+        .unwrap();
+        assert_eq!(
+            policy.sanitize_url(&url),
+            "https://account.blob.core.windows.net/container/blob?sv=2019-12-12&sig=REDACTED"
+        );
+    }
+
+    #[test]
+    fn redacts_additional_query_parameters() {
+        let options = LoggingOptions::default().redacted_query_parameters(vec!["code".to_owned()]);
+        let policy = LoggingPolicy::new(options);
+        let url = Url::parse("https://example.com/api?code=secret&name=value").unwrap();
+        assert_eq!(
+            policy.sanitize_url(&url),
+            "https://example.com/api?code=REDACTED&name=value"
+        );
+    }
+
+    #[test]
+    fn redacts_authorization_header() {
+        let policy = LoggingPolicy::new(LoggingOptions::default());
+        assert_eq!(
+            policy.sanitize_header(
+                &crate::headers::AUTHORIZATION,
+                &HeaderValue::from_static("Bearer token")
+            ),
+            REDACTED
+        );
+        assert_eq!(
+            policy.sanitize_header(
+                &crate::headers::CONTENT_TYPE,
+                &HeaderValue::from_static("application/json")
+            ),
+            "application/json"
+        );
+    }
+
+    #[test]
+    fn redacts_urls_in_headers() {
+        let policy = LoggingPolicy::new(LoggingOptions::default());
+        assert_eq!(
+            policy.sanitize_header(
+                &crate::headers::COPY_SOURCE,
+                &HeaderValue::from_static("https://account.blob.core.windows.net/c/b?sig=secret")
+            ),
+            "https://account.blob.core.windows.net/c/b?sig=REDACTED"
+        );
+    }
+
+    #[test]
+    fn redacts_account_keys() {
+        assert_eq!(
+            sanitize_connection_string(
+                "DefaultEndpointsProtocol=https;AccountName=account;AccountKey=c2VjcmV0"
+            ),
+            "DefaultEndpointsProtocol=https;AccountName=account;AccountKey=REDACTED"
+        );
+        assert_eq!(sanitize_connection_string("no secrets"), "no secrets");
+    }
+}
@@ -0,0 +1,135 @@
+use crate::error::ErrorKind;
+use crate::policies::{namespace, Policy, PolicyResult};
+use crate::{Context, Metric, MetricsRecorder, Request};
+use std::sync::Arc;
+use time::OffsetDateTime;
+
+/// The name of the metric recorded by the [`MetricsPolicy`].
+pub const REQUEST_DURATION_METRIC: &str = "az.client.request.duration";
+
+/// Records the duration of every operation, retries included, with a [`MetricsRecorder`].
+///
+/// The measurements have the `az.namespace`, `http.method`, `net.peer.name` and
+/// `http.status_code` attributes, or an `error.type` attribute when no response was received.
+#[derive(Debug, Clone)]
+pub struct MetricsPolicy {
+    namespace: Option<&'static str>,
+    recorder: Arc<dyn MetricsRecorder>,
+}
+
+impl MetricsPolicy {
+    /// Creates a metrics policy for the client library crate `crate_name`, which is used to
+    /// derive the `az.namespace` attribute.
+    pub fn new(crate_name: Option<&str>, recorder: Arc<dyn MetricsRecorder>) -> Self {
+        Self {
+            namespace: crate_name.and_then(namespace),
+            recorder,
+        }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Policy for MetricsPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let mut attributes = vec![("http.method", request.method().to_string())];
+        if let Some(namespace) = self.namespace {
+            attributes.push(("az.namespace", namespace.to_owned()));
+        }
+        if let Some(host) = request.url().host_str() {
+            attributes.push(("net.peer.name", host.to_owned()));
+        }
+
+        let start = OffsetDateTime::now_utc();
+        let result = next[0].send(ctx, request, &next[1..]).await;
+        let duration = OffsetDateTime::now_utc() - start;
+
+        match &result {
+            Ok(response) => {
+                attributes.push(("http.status_code", u16::from(response.status()).to_string()));
+            }
+            Err(error) => match error.kind() {
+                ErrorKind::HttpResponse { status, .. } => {
+                    attributes.push(("http.status_code", u16::from(*status).to_string()));
+                }
+                kind => attributes.push(("error.type", kind.to_string())),
+            },
+        }
+        self.recorder.record(Metric {
+            name: REQUEST_DURATION_METRIC,
+            value: duration.as_seconds_f64() * 1000.0,
+            unit: "ms",
+            attributes,
+        });
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::headers::Headers;
+    use crate::{Method, Response, StatusCode};
+    use futures::executor::block_on;
+    use std::sync::Mutex;
+
+    #[derive(Debug, Default)]
+    struct Recorded(Mutex<Vec<Metric>>);
+
+    impl MetricsRecorder for Recorded {
+        fn record(&self, metric: Metric) {
+            self.0.lock().unwrap().push(metric);
+        }
+    }
+
+    #[derive(Debug)]
+    struct NotFoundPolicy;
+
+    #[async_trait::async_trait]
+    impl Policy for NotFoundPolicy {
+        async fn send(
+            &self,
+            _ctx: &Context,
+            _request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            let body = futures::stream::iter(Vec::<crate::Result<bytes::Bytes>>::new());
+            Ok(Response::new(
+                StatusCode::NotFound,
+                Headers::new(),
+                Box::pin(body),
+            ))
+        }
+    }
+
+    #[test]
+    fn records_request_duration() {
+        let recorder = Arc::new(Recorded::default());
+        let policy = MetricsPolicy::new(Some("azure_data_tables"), recorder.clone());
+        let mut request = Request::new(
+            url::Url::parse("https://account.table.core.windows.net/t").unwrap(),
+            Method::Get,
+        );
+        let next: Vec<Arc<dyn Policy>> = vec![Arc::new(NotFoundPolicy)];
+        block_on(policy.send(&Context::new(), &mut request, &next)).unwrap();
+
+        let metrics = recorder.0.lock().unwrap();
+        assert_eq!(metrics.len(), 1);
+        assert_eq!(metrics[0].name, REQUEST_DURATION_METRIC);
+        assert_eq!(metrics[0].attribute("http.status_code"), Some("404"));
+        assert_eq!(
+            metrics[0].attribute("az.namespace"),
+            Some("Microsoft.Tables")
+        );
+        assert_eq!(
+            metrics[0].attribute("net.peer.name"),
+            Some("account.table.core.windows.net")
+        );
+    }
+}
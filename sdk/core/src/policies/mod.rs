@@ -1,13 +1,38 @@
+mod bearer_token_policy;
+mod circuit_breaker_policy;
+mod client_request_id_policy;
+#[cfg(feature = "compression")]
+mod compression_policy;
 mod custom_headers_policy;
+mod logging_policy;
+mod metrics_policy;
+mod rate_limit_policy;
 mod retry_policies;
 mod telemetry_policy;
 mod timeout_policy;
+#[cfg(feature = "opentelemetry")]
+mod tracing_policy;
 mod transport;
 
+pub use bearer_token_policy::BearerTokenCredentialPolicy;
+pub use circuit_breaker_policy::{
+    CircuitBreakerPolicy, CircuitPartition, FailureClassifier, HostPartition,
+    ServerFailureClassifier, SinglePartition,
+};
+pub use client_request_id_policy::ClientRequestIdPolicy;
+#[cfg(feature = "compression")]
+pub use compression_policy::{decompress_body, CompressionPolicy};
 pub use custom_headers_policy::{CustomHeaders, CustomHeadersPolicy};
+#[cfg(feature = "opentelemetry")]
+pub(crate) use logging_policy::sanitize_url;
+pub use logging_policy::LoggingPolicy;
+pub use metrics_policy::{MetricsPolicy, REQUEST_DURATION_METRIC};
+pub use rate_limit_policy::RateLimitPolicy;
 pub use retry_policies::*;
 pub use telemetry_policy::*;
 pub use timeout_policy::*;
+#[cfg(feature = "opentelemetry")]
+pub use tracing_policy::TracingPolicy;
 pub use transport::*;
 
 use crate::{Context, Request, Response};
@@ -35,3 +60,18 @@ pub trait Policy: Send + Sync + std::fmt::Debug {
         next: &[Arc<dyn Policy>],
     ) -> PolicyResult;
 }
+
+/// Maps a client library crate name to the Azure resource provider namespace it talks to.
+pub(crate) fn namespace(crate_name: &str) -> Option<&'static str> {
+    match crate_name {
+        name if name.starts_with("azure_storage") => Some("Microsoft.Storage"),
+        "azure_data_cosmos" => Some("Microsoft.DocumentDB"),
+        "azure_data_tables" => Some("Microsoft.Tables"),
+        "azure_security_keyvault" => Some("Microsoft.KeyVault"),
+        "azure_iot_hub" => Some("Microsoft.Devices"),
+        "azure_messaging_servicebus" => Some("Microsoft.ServiceBus"),
+        "azure_messaging_eventgrid" => Some("Microsoft.EventGrid"),
+        "azure_data_appconfiguration" => Some("Microsoft.AppConfiguration"),
+        _ => None,
+    }
+}
@@ -0,0 +1,129 @@
+use crate::options::RateLimitOptions;
+use crate::policies::{Policy, PolicyResult};
+use crate::sleep::sleep;
+use crate::{Context, Request};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use time::OffsetDateTime;
+
+/// Limits the rate of the requests sent by a pipeline with a token bucket.
+///
+/// The bucket holds up to `burst` tokens and is refilled at `requests_per_second`. Every attempt
+/// takes a token, waiting for one to be available if the bucket is empty, so a client can throttle
+/// itself before the service answers with `429 Too Many Requests` or `503 Server Busy`.
+///
+/// The bucket is shared by all the operations sent through the pipeline, and by all the clones of
+/// the client owning it.
+#[derive(Debug, Clone)]
+pub struct RateLimitPolicy {
+    bucket: Arc<Mutex<TokenBucket>>,
+}
+
+impl RateLimitPolicy {
+    pub fn new(options: RateLimitOptions) -> Self {
+        Self {
+            bucket: Arc::new(Mutex::new(TokenBucket::new(
+                options,
+                OffsetDateTime::now_utc(),
+            ))),
+        }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Policy for RateLimitPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let delay = self
+            .bucket
+            .lock()
+            .expect("rate limit bucket lock poisoned")
+            .acquire(OffsetDateTime::now_utc());
+        if let Some(delay) = delay {
+            log::debug!("rate limit reached, delaying request by {delay:?}");
+            sleep(delay).await;
+        }
+        next[0].send(ctx, request, &next[1..]).await
+    }
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    requests_per_second: f64,
+    burst: f64,
+    tokens: f64,
+    refilled_at: OffsetDateTime,
+}
+
+impl TokenBucket {
+    fn new(options: RateLimitOptions, now: OffsetDateTime) -> Self {
+        let burst = f64::from(options.burst.max(1));
+        Self {
+            requests_per_second: options.requests_per_second,
+            burst,
+            tokens: burst,
+            refilled_at: now,
+        }
+    }
+
+    /// Takes a token and returns how long to wait before it can be used.
+    ///
+    /// The token is reserved even when the bucket is empty, so concurrent requests are delayed one
+    /// after the other rather than all at once.
+    fn acquire(&mut self, now: OffsetDateTime) -> Option<Duration> {
+        let elapsed = (now - self.refilled_at).as_seconds_f64().max(0.0);
+        self.tokens = (self.tokens + elapsed * self.requests_per_second).min(self.burst);
+        self.refilled_at = now;
+
+        self.tokens -= 1.0;
+        if self.tokens >= 0.0 {
+            None
+        } else {
+            Some(Duration::from_secs_f64(
+                -self.tokens / self.requests_per_second,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bucket(requests_per_second: f64, burst: u32) -> (TokenBucket, OffsetDateTime) {
+        let now = OffsetDateTime::now_utc();
+        let options = RateLimitOptions::new(requests_per_second).burst(burst);
+        (TokenBucket::new(options, now), now)
+    }
+
+    #[test]
+    fn allows_bursts() {
+        let (mut bucket, now) = bucket(10.0, 3);
+        assert_eq!(bucket.acquire(now), None);
+        assert_eq!(bucket.acquire(now), None);
+        assert_eq!(bucket.acquire(now), None);
+        assert_eq!(bucket.acquire(now), Some(Duration::from_millis(100)));
+        assert_eq!(bucket.acquire(now), Some(Duration::from_millis(200)));
+    }
+
+    #[test]
+    fn refills_over_time() {
+        let (mut bucket, now) = bucket(2.0, 1);
+        assert_eq!(bucket.acquire(now), None);
+        assert_eq!(bucket.acquire(now), Some(Duration::from_millis(500)));
+
+        // the reserved token is available after 500ms, the next one after 1s
+        let now = now + Duration::from_millis(500);
+        assert_eq!(bucket.acquire(now), Some(Duration::from_millis(500)));
+
+        // the bucket never holds more than `burst` tokens
+        let now = now + Duration::from_secs(10);
+        assert_eq!(bucket.acquire(now), None);
+        assert_eq!(bucket.acquire(now), Some(Duration::from_millis(500)));
+    }
+}
@@ -1,9 +1,12 @@
 mod exponential_retry;
 mod fixed_retry;
 mod no_retry;
+mod retry_classifier;
 mod retry_policy;
 
 pub use exponential_retry::*;
 pub use fixed_retry::*;
 pub use no_retry::*;
+pub(crate) use retry_classifier::ClassifiedRetryPolicy;
+pub use retry_classifier::{DefaultRetryClassifier, RetryClassifier};
 pub use retry_policy::RetryPolicy;
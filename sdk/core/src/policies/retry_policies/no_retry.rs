@@ -1,5 +1,5 @@
 use crate::policies::{Policy, PolicyResult, Request};
-use crate::Context;
+use crate::{CancellationToken, Context, Deadline};
 use std::sync::Arc;
 
 /// Retry policy that does not retry.
@@ -19,7 +19,14 @@ impl Policy for NoRetryPolicy {
         request: &mut Request,
         next: &[Arc<dyn Policy>],
     ) -> PolicyResult {
+        if let Some(token) = ctx.get::<CancellationToken>() {
+            token.ensure_not_cancelled()?;
+        }
         // just call the following policies and bubble up the error
-        next[0].send(ctx, request, &next[1..]).await
+        let attempt = next[0].send(ctx, request, &next[1..]);
+        match ctx.get::<Deadline>() {
+            Some(deadline) => deadline.run(attempt).await?,
+            None => attempt.await,
+        }
     }
 }
@@ -0,0 +1,221 @@
+use super::RetryPolicy;
+use crate::error::{Error, ErrorKind};
+use crate::{Request, StatusCode};
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The status codes where a retry is attempted by default.
+///
+/// On all other 4xx and 5xx status codes no retry is attempted.
+const RETRY_STATUSES: &[StatusCode] = &[
+    StatusCode::RequestTimeout,
+    StatusCode::TooManyRequests,
+    StatusCode::InternalServerError,
+    StatusCode::BadGateway,
+    StatusCode::ServiceUnavailable,
+    StatusCode::GatewayTimeout,
+];
+
+/// Decides which failed requests are retried.
+///
+/// The default methods implement the classification of [`DefaultRetryClassifier`], so an
+/// implementation can override only the cases it cares about, e.g. to retry `409 Conflict` lease
+/// errors or to never retry `POST` requests.
+///
+/// ```
+/// use azure_core::{
+///     DefaultRetryClassifier, ExponentialRetryOptions, Method, Request, RetryClassifier,
+///     RetryOptions, StatusCode,
+/// };
+///
+/// #[derive(Debug)]
+/// struct NoPostRetries;
+///
+/// impl RetryClassifier for NoPostRetries {
+///     fn is_retryable_status(
+///         &self,
+///         request: &Request,
+///         status: StatusCode,
+///         error_code: Option<&str>,
+///     ) -> bool {
+///         request.method() != &Method::Post
+///             && DefaultRetryClassifier.is_retryable_status(request, status, error_code)
+///     }
+/// }
+///
+/// let options = RetryOptions::exponential(ExponentialRetryOptions::default())
+///     .classifier(std::sync::Arc::new(NoPostRetries));
+/// ```
+pub trait RetryClassifier: std::fmt::Debug + Send + Sync {
+    /// Whether a request which failed with `status` is retried.
+    ///
+    /// `error_code` is the error code returned by the service, if any.
+    fn is_retryable_status(
+        &self,
+        _request: &Request,
+        status: StatusCode,
+        _error_code: Option<&str>,
+    ) -> bool {
+        RETRY_STATUSES.contains(&status)
+    }
+
+    /// Whether a request which failed without a response, e.g. because of a connection error, is
+    /// retried.
+    fn is_retryable_error(&self, _request: &Request, error: &Error) -> bool {
+        error.kind() == &ErrorKind::Io
+    }
+}
+
+/// The classification used by the retry policies unless configured otherwise.
+///
+/// Request timeouts, throttling, the `500`, `502`, `503` and `504` status codes, and io errors are
+/// retried.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DefaultRetryClassifier;
+
+impl RetryClassifier for DefaultRetryClassifier {}
+
+/// A retry policy using another classifier than its own.
+#[derive(Debug)]
+pub(crate) struct ClassifiedRetryPolicy<P> {
+    policy: P,
+    classifier: Arc<dyn RetryClassifier>,
+}
+
+impl<P: RetryPolicy> ClassifiedRetryPolicy<P> {
+    pub(crate) fn new(policy: P, classifier: Arc<dyn RetryClassifier>) -> Self {
+        Self { policy, classifier }
+    }
+}
+
+#[async_trait]
+impl<P: RetryPolicy> RetryPolicy for ClassifiedRetryPolicy<P> {
+    fn is_expired(&self, duration_since_start: Duration, retry_count: u32) -> bool {
+        self.policy.is_expired(duration_since_start, retry_count)
+    }
+
+    fn sleep_duration(&self, retry_count: u32) -> Duration {
+        self.policy.sleep_duration(retry_count)
+    }
+
+    async fn wait(&self, error: &Error, retry_count: u32) {
+        self.policy.wait(error, retry_count).await;
+    }
+
+    fn classifier(&self) -> &dyn RetryClassifier {
+        self.classifier.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::headers::{self, Headers};
+    use crate::{
+        BytesStream, Context, FixedRetryOptions, Method, Policy, PolicyResult, Response,
+        RetryOptions,
+    };
+    use futures::executor::block_on;
+    use std::sync::Mutex;
+
+    #[derive(Debug)]
+    struct RetryConflicts;
+
+    impl RetryClassifier for RetryConflicts {
+        fn is_retryable_status(
+            &self,
+            request: &Request,
+            status: StatusCode,
+            error_code: Option<&str>,
+        ) -> bool {
+            (status == StatusCode::Conflict && error_code == Some("LeaseAlreadyPresent"))
+                || DefaultRetryClassifier.is_retryable_status(request, status, error_code)
+        }
+    }
+
+    #[test]
+    fn classifies_statuses_and_errors() {
+        let request = Request::new("https://example.com".parse().unwrap(), Method::Put);
+
+        let classifier = DefaultRetryClassifier;
+        assert!(classifier.is_retryable_status(&request, StatusCode::TooManyRequests, None));
+        assert!(!classifier.is_retryable_status(&request, StatusCode::NotFound, None));
+        assert!(classifier.is_retryable_error(&request, &Error::message(ErrorKind::Io, "reset")));
+        assert!(!classifier
+            .is_retryable_error(&request, &Error::message(ErrorKind::DataConversion, "bad")));
+
+        let classifier = RetryConflicts;
+        assert!(classifier.is_retryable_status(
+            &request,
+            StatusCode::Conflict,
+            Some("LeaseAlreadyPresent")
+        ));
+        assert!(!classifier.is_retryable_status(&request, StatusCode::Conflict, None));
+        assert!(classifier.is_retryable_status(&request, StatusCode::BadGateway, None));
+    }
+
+    /// Replies with the statuses and error codes in turn, recording the attempts.
+    #[derive(Debug)]
+    struct ScriptedPolicy {
+        replies: Mutex<Vec<(StatusCode, &'static str)>>,
+        attempts: Mutex<usize>,
+    }
+
+    impl ScriptedPolicy {
+        fn new(replies: &[(StatusCode, &'static str)]) -> Self {
+            Self {
+                replies: Mutex::new(replies.to_vec()),
+                attempts: Mutex::new(0),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl Policy for ScriptedPolicy {
+        async fn send(
+            &self,
+            _ctx: &Context,
+            _request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            *self.attempts.lock().unwrap() += 1;
+            let (status, error_code) = self.replies.lock().unwrap().remove(0);
+            let mut headers = Headers::new();
+            headers.insert(headers::ERROR_CODE, error_code);
+            Ok(Response::new(
+                status,
+                headers,
+                Box::pin(BytesStream::new(bytes::Bytes::new())),
+            ))
+        }
+    }
+
+    /// Sends a request with `options`, returning the final status and the number of attempts.
+    fn send(options: RetryOptions) -> (Option<StatusCode>, usize) {
+        let transport = Arc::new(ScriptedPolicy::new(&[
+            (StatusCode::Conflict, "LeaseAlreadyPresent"),
+            (StatusCode::Ok, ""),
+        ]));
+        let next: Vec<Arc<dyn Policy>> = vec![transport.clone()];
+        let mut request = Request::new("https://example.com".parse().unwrap(), Method::Put);
+        let result = block_on(
+            options
+                .to_policy()
+                .send(&Context::new(), &mut request, &next),
+        );
+        let attempts = *transport.attempts.lock().unwrap();
+        (result.ok().map(|response| response.status()), attempts)
+    }
+
+    #[test]
+    fn retries_with_the_classifier_of_the_options() {
+        let fixed = || FixedRetryOptions::default().delay(Duration::from_millis(1));
+
+        assert_eq!(send(RetryOptions::fixed(fixed())), (None, 1));
+        assert_eq!(
+            send(RetryOptions::fixed(fixed()).classifier(Arc::new(RetryConflicts))),
+            (Some(StatusCode::Ok), 2)
+        );
+    }
+}
@@ -1,7 +1,8 @@
+use super::{DefaultRetryClassifier, RetryClassifier};
 use crate::error::{Error, ErrorKind, HttpError};
 use crate::policies::{Policy, PolicyResult, Request};
 use crate::sleep::sleep;
-use crate::{Context, StatusCode};
+use crate::{CancellationToken, Context, Deadline};
 
 use async_trait::async_trait;
 use time::OffsetDateTime;
@@ -30,19 +31,14 @@ pub trait RetryPolicy: std::fmt::Debug + Send + Sync {
     async fn wait(&self, _error: &Error, retry_count: u32) {
         sleep(self.sleep_duration(retry_count)).await;
     }
-}
 
-/// The status codes where a retry should be attempted.
-///
-/// On all other 4xx and 5xx status codes no retry is attempted.
-const RETRY_STATUSES: &[StatusCode] = &[
-    StatusCode::RequestTimeout,
-    StatusCode::TooManyRequests,
-    StatusCode::InternalServerError,
-    StatusCode::BadGateway,
-    StatusCode::ServiceUnavailable,
-    StatusCode::GatewayTimeout,
-];
+    /// Decides which failed requests are retried.
+    ///
+    /// The default is [`DefaultRetryClassifier`].
+    fn classifier(&self) -> &dyn RetryClassifier {
+        &DefaultRetryClassifier
+    }
+}
 
 #[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
 #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
@@ -58,9 +54,20 @@ where
     ) -> PolicyResult {
         let mut retry_count = 0;
         let mut start = None;
+        let deadline = ctx.get::<Deadline>();
+        let cancellation_token = ctx.get::<CancellationToken>();
+        let classifier = self.classifier();
 
         loop {
-            let result = next[0].send(ctx, request, &next[1..]).await;
+            if let Some(token) = cancellation_token {
+                token.ensure_not_cancelled()?;
+            }
+            // an attempt still in flight when the deadline expires is abandoned
+            let attempt = next[0].send(ctx, request, &next[1..]);
+            let result = match deadline {
+                Some(deadline) => deadline.run(attempt).await?,
+                None => attempt.await,
+            };
             // only start keeping track of time after the first request is made
             let start = start.get_or_insert_with(OffsetDateTime::now_utc);
             let last_error = match result {
@@ -82,19 +89,21 @@ where
                         http_error.error_code().map(std::borrow::ToOwned::to_owned),
                     );
 
-                    if !RETRY_STATUSES.contains(&status) {
+                    if !classifier.is_retryable_status(request, status, http_error.error_code()) {
                         log::debug!(
                             "server returned error status which will not be retried: {}",
                             status
                         );
                         // Server didn't return a status we retry on so return early
-                        let error = Error::full(
-                            error_kind,
-                            http_error,
-                            format!(
+                        let message = match http_error.error_message() {
+                            Some(message) => format!(
+                                "server returned error status which will not be retried: {status}: {message}"
+                            ),
+                            None => format!(
                                 "server returned error status which will not be retried: {status}"
                             ),
-                        );
+                        };
+                        let error = Error::full(error_kind, http_error, message);
                         return Err(error);
                     }
                     log::debug!(
@@ -104,16 +113,14 @@ where
                     Error::new(error_kind, http_error)
                 }
                 Err(error) => {
-                    if error.kind() == &ErrorKind::Io {
+                    if classifier.is_retryable_error(request, &error) {
                         log::debug!(
-                            "io error occurred when making request which will be retried: {}",
+                            "error occurred when making request which will be retried: {}",
                             error
                         );
                         error
                     } else {
-                        return Err(
-                            error.context("non-io error occurred which will not be retried")
-                        );
+                        return Err(error.context("error occurred which will not be retried"));
                     }
                 }
             };
@@ -127,7 +134,62 @@ where
             }
             retry_count += 1;
 
-            self.wait(&last_error, retry_count).await;
+            if let Some(deadline) = deadline {
+                if deadline.remaining() <= self.sleep_duration(retry_count) {
+                    return Err(Error::full(
+                        ErrorKind::Timeout,
+                        last_error,
+                        "the request deadline expires before the next retry and the request will no longer be retried",
+                    ));
+                }
+            }
+
+            match cancellation_token {
+                Some(token) => {
+                    token
+                        .run(async {
+                            self.wait(&last_error, retry_count).await;
+                            Ok(())
+                        })
+                        .await?;
+                }
+                None => self.wait(&last_error, retry_count).await,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policies::FixedRetryPolicy;
+    use crate::Method;
+    use futures::executor::block_on;
+
+    #[derive(Debug)]
+    struct HangingPolicy;
+
+    #[async_trait::async_trait]
+    impl Policy for HangingPolicy {
+        async fn send(
+            &self,
+            _ctx: &Context,
+            _request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            futures::future::pending().await
         }
     }
+
+    #[test]
+    fn abandons_the_attempt_in_flight_at_the_deadline() {
+        let policy = FixedRetryPolicy::new(Duration::from_secs(1), 3, Duration::from_secs(60));
+        let mut ctx = Context::new();
+        ctx.insert(Deadline::after(Duration::from_millis(50)));
+        let mut request = Request::new("https://example.com".parse().unwrap(), Method::Get);
+        let next: Vec<Arc<dyn Policy>> = vec![Arc::new(HangingPolicy)];
+
+        let error = block_on(policy.send(&ctx, &mut request, &next)).unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::Timeout);
+    }
 }
@@ -7,7 +7,7 @@ use std::sync::Arc;
 
 #[derive(Clone, Debug)]
 pub struct TelemetryPolicy {
-    header: String,
+    header: Option<String>,
 }
 
 /// Sets the User-Agent header with useful information in a typical format for Azure SDKs.
@@ -41,15 +41,17 @@ impl<'a> TelemetryPolicy {
             crate_name = name;
         }
 
-        let header = match &options.application_id {
-            Some(application_id) => format!(
+        let header = match (&options.application_id, options.disabled) {
+            (Some(application_id), true) => Some(application_id.clone()),
+            (None, true) => None,
+            (Some(application_id), false) => Some(format!(
                 "{} azsdk-rust-{}/{} {}",
                 application_id, crate_name, crate_version, platform_info
-            ),
-            None => format!(
+            )),
+            (None, false) => Some(format!(
                 "azsdk-rust-{}/{} {}",
                 crate_name, crate_version, platform_info
-            ),
+            )),
         };
 
         TelemetryPolicy { header }
@@ -65,7 +67,9 @@ impl Policy for TelemetryPolicy {
         request: &mut Request,
         next: &[Arc<dyn Policy>],
     ) -> PolicyResult {
-        request.insert_header(USER_AGENT, HeaderValue::from(self.header.to_string()));
+        if let Some(header) = &self.header {
+            request.insert_header(USER_AGENT, HeaderValue::from(header.to_string()));
+        }
 
         next[0].send(ctx, request, &next[1..]).await
     }
@@ -84,7 +88,7 @@ mod test {
             &TelemetryOptions::default(),
         );
         assert_eq!(
-            policy.header,
+            policy.header.unwrap(),
             format!("azsdk-rust-test/1.2.3 (4.5.6; {}; {})", OS, ARCH)
         );
     }
@@ -93,6 +97,7 @@ mod test {
     fn test_with_application_id() {
         let options = TelemetryOptions {
             application_id: Some("my_app".to_string()),
+            ..Default::default()
         };
         let policy = TelemetryPolicy::new_with_rustc_version(
             Some("test"),
@@ -101,7 +106,7 @@ mod test {
             &options,
         );
         assert_eq!(
-            policy.header,
+            policy.header.unwrap(),
             format!("my_app azsdk-rust-test/1.2.3 (4.5.6; {}; {})", OS, ARCH)
         );
     }
@@ -112,8 +117,29 @@ mod test {
         let policy =
             TelemetryPolicy::new_with_rustc_version(None, None, None, &TelemetryOptions::default());
         assert_eq!(
-            policy.header,
+            policy.header.unwrap(),
             format!("azsdk-rust-unknown/unknown (unknown; {}; {})", OS, ARCH)
         )
     }
+
+    #[test]
+    fn test_disabled() {
+        let options = TelemetryOptions::default().disabled(true);
+        let policy = TelemetryPolicy::new_with_rustc_version(
+            Some("test"),
+            Some("1.2.3"),
+            Some("4.5.6"),
+            &options,
+        );
+        assert_eq!(policy.header, None);
+
+        let options = options.application_id("my_app".to_owned());
+        let policy = TelemetryPolicy::new_with_rustc_version(
+            Some("test"),
+            Some("1.2.3"),
+            Some("4.5.6"),
+            &options,
+        );
+        assert_eq!(policy.header.as_deref(), Some("my_app"));
+    }
 }
@@ -0,0 +1,196 @@
+use crate::headers::{HeaderName, CLIENT_REQUEST_ID, REQUEST_ID, USER_AGENT};
+use crate::policies::{namespace, sanitize_url, Policy, PolicyResult};
+use crate::{Context, Request};
+use opentelemetry::global::{self, BoxedTracer};
+use opentelemetry::propagation::{Injector, TextMapPropagator};
+use opentelemetry::sdk::propagation::TraceContextPropagator;
+use opentelemetry::trace::{SpanKind, Status, TraceContextExt, Tracer};
+use opentelemetry::KeyValue;
+use std::sync::Arc;
+
+/// Creates an OpenTelemetry span for every HTTP attempt.
+///
+/// The span is a child of the `opentelemetry::Context` found in the request
+/// [`Context`](crate::Context) or, if there is none, of the current OpenTelemetry context. The
+/// W3C `traceparent` (and `tracestate`) headers are added to the request so that the service can
+/// correlate its own traces, and the span is decorated with the standard Azure SDK attributes
+/// (`az.namespace`, `http.method`, `http.url`, `http.status_code`, `requestId`, ...). The SAS
+/// signature and the query parameters redacted by the [`LoggingOptions`](crate::LoggingOptions)
+/// are redacted from `http.url`, as they are from the logs.
+///
+/// Spans are exported by whatever tracer provider the application installed with
+/// `opentelemetry::global::set_tracer_provider`. Without one, spans are no-ops.
+#[derive(Debug, Clone)]
+pub struct TracingPolicy {
+    namespace: Option<&'static str>,
+    redacted_query_parameters: Vec<String>,
+}
+
+impl TracingPolicy {
+    /// Creates a tracing policy for the client library crate `crate_name`, which is used to
+    /// derive the `az.namespace` span attribute.
+    pub fn new(crate_name: Option<&str>) -> Self {
+        Self {
+            namespace: crate_name.and_then(namespace),
+            redacted_query_parameters: Vec::new(),
+        }
+    }
+
+    setters! {
+        redacted_query_parameters: Vec<String> => redacted_query_parameters,
+    }
+
+    /// The attributes of the span of `request`.
+    fn attributes(&self, request: &Request) -> Vec<KeyValue> {
+        let mut attributes = vec![
+            KeyValue::new("http.method", request.method().to_string()),
+            KeyValue::new(
+                "http.url",
+                sanitize_url(request.url(), &self.redacted_query_parameters),
+            ),
+        ];
+        if let Some(host) = request.url().host_str() {
+            attributes.push(KeyValue::new("net.peer.name", host.to_owned()));
+        }
+        if let Some(namespace) = self.namespace {
+            attributes.push(KeyValue::new("az.namespace", namespace));
+        }
+        if let Some(user_agent) = request.headers().get_optional_string(&USER_AGENT) {
+            attributes.push(KeyValue::new("http.user_agent", user_agent));
+        }
+        if let Some(request_id) = request.headers().get_optional_string(&CLIENT_REQUEST_ID) {
+            attributes.push(KeyValue::new("requestId", request_id));
+        }
+        attributes
+    }
+
+    fn tracer() -> BoxedTracer {
+        global::tracer("azure_core")
+    }
+}
+
+struct RequestInjector<'a>(&'a mut Request);
+
+impl<'a> Injector for RequestInjector<'a> {
+    fn set(&mut self, key: &str, value: String) {
+        let name = HeaderName::from(key.to_lowercase());
+        self.0.insert_header(name, value);
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Policy for TracingPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let parent = ctx
+            .get::<opentelemetry::Context>()
+            .cloned()
+            .unwrap_or_else(opentelemetry::Context::current);
+
+        let method = request.method().to_string();
+        let attributes = self.attributes(request);
+
+        let tracer = Self::tracer();
+        let span = tracer
+            .span_builder(format!("HTTP {method}"))
+            .with_kind(SpanKind::Client)
+            .with_attributes(attributes)
+            .start_with_context(&tracer, &parent);
+        let cx = parent.with_span(span);
+
+        TraceContextPropagator::new().inject_context(&cx, &mut RequestInjector(request));
+
+        let result = next[0].send(ctx, request, &next[1..]).await;
+
+        let span = cx.span();
+        match &result {
+            Ok(response) => {
+                let status = u16::from(response.status());
+                span.set_attribute(KeyValue::new("http.status_code", i64::from(status)));
+                if let Some(request_id) = response.headers().get_optional_string(&REQUEST_ID) {
+                    span.set_attribute(KeyValue::new("serviceRequestId", request_id));
+                }
+                if status >= 400 {
+                    span.set_status(Status::error(format!("HTTP status {status}")));
+                }
+            }
+            Err(error) => {
+                span.set_status(Status::error(error.to_string()));
+            }
+        }
+        span.end();
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Method;
+    use opentelemetry::trace::{SpanContext, SpanId, TraceFlags, TraceId, TraceState};
+
+    #[test]
+    fn namespace_from_crate_name() {
+        assert_eq!(namespace("azure_storage_blobs"), Some("Microsoft.Storage"));
+        assert_eq!(namespace("azure_data_cosmos"), Some("Microsoft.DocumentDB"));
+        assert_eq!(
+            namespace("azure_security_keyvault"),
+            Some("Microsoft.KeyVault")
+        );
+        assert_eq!(namespace("some_other_crate"), None);
+    }
+
+    #[test]
+    fn redacts_sas_signature_from_url() {
+        let policy = TracingPolicy::new(Some("azure_storage_blobs"))
+            .redacted_query_parameters(vec!["code".to_owned()]);
+        let request = Request::new(
+            url::Url::parse(
+                "https://account.blob.core.windows.net/container/blob?sv=2019-12-12&se=2026-10-16&sig=secret%2Bsignature&code=secret",
+            )
+            .unwrap(),
+            Method::Get,
+        );
+
+        let url = policy
+            .attributes(&request)
+            .into_iter()
+            .find(|attribute| attribute.key.as_str() == "http.url")
+            .unwrap()
+            .value
+            .to_string();
+        assert_eq!(
+            url,
+            "https://account.blob.core.windows.net/container/blob?sv=2019-12-12&se=2026-10-16&sig=REDACTED&code=REDACTED"
+        );
+    }
+
+    #[test]
+    fn injects_traceparent() {
+        let span_context = SpanContext::new(
+            TraceId::from_hex("4bf92f3577b34da6a3ce929d0e0e4736").unwrap(),
+            SpanId::from_hex("00f067aa0ba902b7").unwrap(),
+            TraceFlags::SAMPLED,
+            true,
+            TraceState::default(),
+        );
+        let cx = opentelemetry::Context::new().with_remote_span_context(span_context);
+
+        let mut request =
+            Request::new(url::Url::parse("https://example.com").unwrap(), Method::Get);
+        TraceContextPropagator::new().inject_context(&cx, &mut RequestInjector(&mut request));
+
+        assert_eq!(
+            request
+                .headers()
+                .get_optional_str(&HeaderName::from_static("traceparent")),
+            Some("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01")
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use crate::policies::{Policy, PolicyResult};
 use crate::TransportOptions;
-use crate::{Context, Request};
+use crate::{CancellationToken, Context, Request};
 use async_trait::async_trait;
 use std::sync::Arc;
 
@@ -30,6 +30,10 @@ impl Policy for TransportPolicy {
         log::debug!("the following request will be passed to the transport policy: {request:#?}");
         let response = { self.transport_options.send(ctx, request) };
 
-        response.await
+        // a cancelled request is abandoned rather than awaited until the service answers
+        match ctx.get::<CancellationToken>() {
+            Some(token) => token.run(response).await,
+            None => response.await,
+        }
     }
 }
@@ -1,3 +1,4 @@
+use super::Range;
 use crate::error::{Error, ErrorKind, ResultExt};
 use std::fmt;
 use std::str::FromStr;
@@ -35,6 +36,11 @@ impl ContentRange {
     pub fn is_empty(&self) -> bool {
         self.end == self.start
     }
+
+    /// The range of bytes of the content, the end of a `ContentRange` being inclusive.
+    pub fn range(&self) -> Range {
+        Range::new(self.start, self.end + 1)
+    }
 }
 
 impl FromStr for ContentRange {
@@ -135,6 +141,7 @@ mod test {
         assert_eq!(range.start(), 172032);
         assert_eq!(range.end(), 172489);
         assert_eq!(range.total_length(), 172490);
+        assert_eq!(range.range(), Range::new(172032, 172490));
     }
 
     #[test]
@@ -1,12 +1,49 @@
 use crate::headers::{self, Header};
+use crate::Etag;
 use headers::{IF_MATCH, IF_NONE_MATCH};
 
+/// A condition on the entity tag of a resource, sent as `If-Match` or `If-None-Match`.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum IfMatchCondition {
     Match(String),
     NotMatch(String),
 }
 
+impl IfMatchCondition {
+    /// The request succeeds only if the resource has the given entity tag.
+    pub fn matches(etag: impl Into<Etag>) -> Self {
+        Self::Match(etag.into().to_string())
+    }
+
+    /// The request succeeds only if the resource does not have the given entity tag.
+    pub fn not_matches(etag: impl Into<Etag>) -> Self {
+        Self::NotMatch(etag.into().to_string())
+    }
+
+    /// The request succeeds only if the resource exists (`If-Match: *`).
+    pub fn exists() -> Self {
+        Self::Match(Etag::any().to_string())
+    }
+
+    /// The request succeeds only if the resource does not exist (`If-None-Match: *`).
+    pub fn not_exists() -> Self {
+        Self::NotMatch(Etag::any().to_string())
+    }
+
+    /// The entity tag the condition applies to.
+    pub fn etag(&self) -> Etag {
+        match self {
+            IfMatchCondition::Match(etag) | IfMatchCondition::NotMatch(etag) => etag.into(),
+        }
+    }
+}
+
+impl From<Etag> for IfMatchCondition {
+    fn from(etag: Etag) -> Self {
+        Self::matches(etag)
+    }
+}
+
 impl Header for IfMatchCondition {
     fn name(&self) -> headers::HeaderName {
         match self {
@@ -21,3 +58,25 @@ impl Header for IfMatchCondition {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wildcard_conditions() {
+        let exists = IfMatchCondition::exists();
+        assert_eq!(exists.name(), IF_MATCH);
+        assert_eq!(exists.value().as_str(), "*");
+
+        let not_exists = IfMatchCondition::not_exists();
+        assert_eq!(not_exists.name(), IF_NONE_MATCH);
+        assert!(not_exists.etag().is_any());
+    }
+
+    #[test]
+    fn from_etag() {
+        let condition = IfMatchCondition::from(Etag::from("\"0x8D9\""));
+        assert_eq!(condition, IfMatchCondition::Match("\"0x8D9\"".to_owned()));
+    }
+}
@@ -4,12 +4,26 @@ use crate::{
 };
 use time::OffsetDateTime;
 
+/// A condition on the last modification time of a resource, sent as `If-Modified-Since` or
+/// `If-Unmodified-Since`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum IfModifiedSinceCondition {
     Modified(OffsetDateTime),
     Unmodified(OffsetDateTime),
 }
 
+impl IfModifiedSinceCondition {
+    /// The request succeeds only if the resource was modified after `date`.
+    pub fn modified_since(date: OffsetDateTime) -> Self {
+        Self::Modified(date)
+    }
+
+    /// The request succeeds only if the resource was not modified after `date`.
+    pub fn unmodified_since(date: OffsetDateTime) -> Self {
+        Self::Unmodified(date)
+    }
+}
+
 impl Header for IfModifiedSinceCondition {
     fn name(&self) -> HeaderName {
         match self {
@@ -5,6 +5,29 @@ use uuid::Uuid;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct LeaseId(Uuid);
 
+impl LeaseId {
+    /// Creates a random lease id, to propose when acquiring or changing a lease.
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    pub fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl Default for LeaseId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Uuid> for LeaseId {
+    fn from(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+}
+
 impl std::fmt::Display for LeaseId {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         self.0.fmt(fmt)
@@ -28,3 +51,19 @@ impl Header for LeaseId {
         format!("{}", self.0).into()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format() {
+        let lease_id: LeaseId = "b9e9a3c2-2e7f-4c2a-8f6e-9d6f1e4e5a6b".parse().unwrap();
+        assert_eq!(lease_id.name(), headers::LEASE_ID);
+        assert_eq!(
+            lease_id.value().as_str(),
+            "b9e9a3c2-2e7f-4c2a-8f6e-9d6f1e4e5a6b"
+        );
+        "not-a-lease".parse::<LeaseId>().unwrap_err();
+    }
+}
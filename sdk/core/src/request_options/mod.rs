@@ -15,8 +15,10 @@ mod metadata;
 mod next_marker;
 mod proposed_lease_id;
 mod range;
+mod request_conditions;
 mod sequence_number;
 mod source_lease_id;
+mod source_uri;
 mod timeout;
 
 pub use content_length::ContentLength;
@@ -36,8 +38,10 @@ pub use metadata::Metadata;
 pub use next_marker::NextMarker;
 pub use proposed_lease_id::ProposedLeaseId;
 pub use range::Range;
+pub use request_conditions::RequestConditions;
 pub use sequence_number::SequenceNumber;
 pub use source_lease_id::SourceLeaseId;
+pub use source_uri::SourceUri;
 pub use timeout::Timeout;
 
 request_header!(
@@ -4,6 +4,8 @@ use std::convert::From;
 use std::fmt;
 use std::str::FromStr;
 
+const PREFIX: &str = "bytes=";
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct Range {
     pub start: u64,
@@ -68,7 +70,24 @@ impl From<std::ops::Range<usize>> for Range {
 
 impl FromStr for Range {
     type Err = Error;
+
+    /// Parses either the `bytes=<start>-<end>` format of the `Range` headers or the
+    /// `<start>/<end>` format of the `x-ms-copy-progress` header.
     fn from_str(s: &str) -> crate::Result<Range> {
+        if let Some(remaining) = s.strip_prefix(PREFIX) {
+            let (start, end) = remaining.split_once('-').ok_or_else(|| {
+                Error::with_message(ErrorKind::Other, || {
+                    format!(
+                        "expected token \"{}\" not found when parsing Range from \"{}\"",
+                        "-", s
+                    )
+                })
+            })?;
+            let start = start.parse::<u64>().map_kind(ErrorKind::DataConversion)?;
+            let end = end.parse::<u64>().map_kind(ErrorKind::DataConversion)? + 1;
+            return Ok(Range { start, end });
+        }
+
         let v = s.split('/').collect::<Vec<&str>>();
         if v.len() != 2 {
             return Err(Error::with_message(ErrorKind::Other, || {
@@ -91,7 +110,7 @@ impl FromStr for Range {
 
 impl fmt::Display for Range {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "bytes={}-{}", self.start, self.end - 1)
+        write!(f, "{}{}-{}", PREFIX, self.start, self.end - 1)
     }
 }
 
@@ -117,6 +136,15 @@ mod test {
         "1000-2000".parse::<Range>().unwrap_err();
     }
 
+    #[test]
+    fn test_range_parse_header() {
+        let range = "bytes=100-500".parse::<Range>().unwrap();
+
+        assert_eq!(range, Range::new(100, 501));
+        assert_eq!(range.to_string(), "bytes=100-500");
+        "bytes=100".parse::<Range>().unwrap_err();
+    }
+
     #[test]
     fn test_range_display() {
         let range = Range {
@@ -0,0 +1,83 @@
+use crate::headers::{AsHeaders, Header, HeaderName, HeaderValue};
+use crate::request_options::{IfMatchCondition, IfModifiedSinceCondition};
+
+/// The conditions under which a request succeeds, based on the state of the targeted resource.
+///
+/// The conditions are added to a request with [`Request::insert_headers`](crate::Request::insert_headers).
+///
+/// # Example
+///
+/// ```
+/// use azure_core::request_options::RequestConditions;
+/// use azure_core::{Etag, Method, Request};
+/// let mut request = Request::new(
+///     url::Url::parse("https://account.blob.core.windows.net/container/blob").unwrap(),
+///     Method::Put,
+/// );
+/// let conditions = RequestConditions::default().if_match(Etag::from("\"0x8D9\""));
+/// request.insert_headers(&conditions);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RequestConditions {
+    if_match: Option<IfMatchCondition>,
+    if_modified_since: Option<IfModifiedSinceCondition>,
+}
+
+impl RequestConditions {
+    setters! {
+        if_match: IfMatchCondition => Some(if_match),
+        if_modified_since: IfModifiedSinceCondition => Some(if_modified_since),
+    }
+
+    /// The entity tag condition, if any.
+    pub fn if_match_condition(&self) -> Option<&IfMatchCondition> {
+        self.if_match.as_ref()
+    }
+
+    /// The modification time condition, if any.
+    pub fn if_modified_since_condition(&self) -> Option<&IfModifiedSinceCondition> {
+        self.if_modified_since.as_ref()
+    }
+}
+
+impl AsHeaders for RequestConditions {
+    type Iter = std::vec::IntoIter<(HeaderName, HeaderValue)>;
+
+    fn as_headers(&self) -> Self::Iter {
+        let if_match = self.if_match.as_ref().map(|c| (c.name(), c.value()));
+        let if_modified_since = self.if_modified_since.map(|c| (c.name(), c.value()));
+        if_match
+            .into_iter()
+            .chain(if_modified_since)
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::headers::{IF_MATCH, IF_NONE_MATCH, IF_UNMODIFIED_SINCE};
+    use crate::{Etag, Method, Request};
+    use time::macros::datetime;
+
+    #[test]
+    fn conditions_are_inserted_as_headers() {
+        let mut request =
+            Request::new(url::Url::parse("https://example.com").unwrap(), Method::Put);
+        let conditions = RequestConditions::default()
+            .if_match(IfMatchCondition::not_matches(Etag::from("\"1\"")))
+            .if_modified_since(IfModifiedSinceCondition::unmodified_since(datetime!(
+                2022-08-01 10:00:00 UTC
+            )));
+        request.insert_headers(&conditions);
+
+        let headers = request.headers();
+        assert!(headers.get_optional_str(&IF_MATCH).is_none());
+        assert_eq!(headers.get_optional_str(&IF_NONE_MATCH), Some("\"1\""));
+        assert_eq!(
+            headers.get_optional_str(&IF_UNMODIFIED_SINCE),
+            Some("Mon, 01 Aug 2022 10:00:00 GMT")
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use crate::error::{Error, ErrorKind, ResultExt};
+use crate::headers::{self, Header};
+use std::str::FromStr;
+use url::Url;
+
+/// The URL of the source of a copy, sent in the `x-ms-copy-source` header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceUri(Url);
+
+impl SourceUri {
+    pub fn new(url: Url) -> Self {
+        Self(url)
+    }
+
+    pub fn url(&self) -> &Url {
+        &self.0
+    }
+}
+
+impl From<Url> for SourceUri {
+    fn from(url: Url) -> Self {
+        Self(url)
+    }
+}
+
+impl std::fmt::Display for SourceUri {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl FromStr for SourceUri {
+    type Err = Error;
+
+    fn from_str(s: &str) -> crate::Result<Self> {
+        let url = Url::parse(s).with_context(ErrorKind::DataConversion, || {
+            format!("invalid copy source url: {s}")
+        })?;
+        Ok(Self(url))
+    }
+}
+
+impl Header for SourceUri {
+    fn name(&self) -> headers::HeaderName {
+        headers::COPY_SOURCE
+    }
+
+    fn value(&self) -> headers::HeaderValue {
+        self.0.as_str().to_owned().into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format() {
+        let source: SourceUri = "https://account.blob.core.windows.net/container/blob?sv=2020"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            source.url().host_str(),
+            Some("account.blob.core.windows.net")
+        );
+        assert_eq!(
+            source.value().as_str(),
+            "https://account.blob.core.windows.net/container/blob?sv=2020"
+        );
+
+        "container/blob".parse::<SourceUri>().unwrap_err();
+    }
+}
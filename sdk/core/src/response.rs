@@ -1,9 +1,11 @@
 use crate::error::{ErrorKind, ResultExt};
-use crate::headers::Headers;
-use crate::StatusCode;
+use crate::headers::{Headers, CONTENT_LENGTH};
+use crate::{Format, JsonFormat, StatusCode};
 use bytes::Bytes;
-use futures::{Stream, StreamExt};
+use futures::{AsyncWrite, AsyncWriteExt, Stream, StreamExt};
+use serde::de::DeserializeOwned;
 use std::fmt::Debug;
+use std::marker::PhantomData;
 use std::pin::Pin;
 
 pub(crate) type PinnedStream = Pin<Box<dyn Stream<Item = crate::Result<Bytes>> + Send + Sync>>;
@@ -43,6 +45,53 @@ impl Response {
     pub fn into_body(self) -> ResponseBody {
         self.body
     }
+
+    /// Consume the HTTP response and return the HTTP body, reporting the progress of the download
+    /// to `callback` as the body is streamed.
+    ///
+    /// The total size of the body is taken from the `Content-Length` header, if present.
+    pub fn into_body_with_progress<F>(self, callback: F) -> ResponseBody
+    where
+        F: FnMut(TransferProgress) + Send + Sync + 'static,
+    {
+        let total_bytes = self
+            .headers
+            .get_optional_as::<u64, _>(&CONTENT_LENGTH)
+            .ok()
+            .flatten();
+        self.body.with_progress(total_bytes, callback)
+    }
+
+    /// Consume the HTTP response and return the HTTP body, to be deserialized into `T`.
+    pub fn into_typed_body<T>(self) -> ResponseBody<T> {
+        self.body.typed()
+    }
+
+    /// Consume the HTTP response and collect the body as bytes.
+    pub async fn bytes(self) -> crate::Result<Bytes> {
+        self.body.collect().await
+    }
+
+    /// Consume the HTTP response and collect the body as a UTF-8 `String`.
+    pub async fn text(self) -> crate::Result<String> {
+        self.body.collect_string().await
+    }
+
+    /// Consume the HTTP response and deserialize the body from JSON.
+    pub async fn json<T: DeserializeOwned>(self) -> crate::Result<T> {
+        self.body.collect_json().await
+    }
+
+    /// Consume the HTTP response and deserialize the body from XML.
+    #[cfg(feature = "xml")]
+    pub async fn xml<T: DeserializeOwned>(self) -> crate::Result<T> {
+        self.body.collect_xml().await
+    }
+
+    /// Consume the HTTP response and deserialize the body from the format `F`.
+    pub async fn deserialize_as<F: Format, T: DeserializeOwned>(self) -> crate::Result<T> {
+        self.body.collect_as::<F, T>().await
+    }
 }
 
 impl std::fmt::Debug for Response {
@@ -94,24 +143,118 @@ impl CollectedResponse {
         let body = body.collect().await?;
         Ok(Self::new(status, headers, body))
     }
+
+    /// Get the body as bytes
+    pub fn bytes(&self) -> Bytes {
+        self.body.clone()
+    }
+
+    /// Get the body as a UTF-8 string
+    pub fn text(&self) -> crate::Result<&str> {
+        std::str::from_utf8(&self.body).context(
+            ErrorKind::DataConversion,
+            "response body was not utf-8 like expected",
+        )
+    }
+
+    /// Deserialize the body from JSON
+    pub fn json<T: DeserializeOwned>(&self) -> crate::Result<T> {
+        self.deserialize_as::<JsonFormat, T>()
+    }
+
+    /// Deserialize the body from XML
+    #[cfg(feature = "xml")]
+    pub fn xml<T: DeserializeOwned>(&self) -> crate::Result<T> {
+        self.deserialize_as::<crate::XmlFormat, T>()
+    }
+
+    /// Deserialize the body from the format `F`
+    pub fn deserialize_as<F: Format, T: DeserializeOwned>(&self) -> crate::Result<T> {
+        F::deserialize(&self.body)
+    }
 }
 
 /// A response body stream
 ///
-/// This body can either be streamed or collected into `Bytes`
+/// This body can either be streamed or collected into `Bytes`. Large bodies should be consumed
+/// chunk by chunk (it implements `Stream`), or written to their destination with
+/// [`write_to`](Self::write_to), rather than collected in memory.
+///
+/// `T` is the model the body deserializes into. Services use JSON or XML, so a typed body is
+/// collected with [`json`](Self::json) or [`xml`](Self::xml). The default, untyped body is only a
+/// stream of bytes.
 #[pin_project::pin_project]
-pub struct ResponseBody(#[pin] PinnedStream);
+pub struct ResponseBody<T = Bytes> {
+    #[pin]
+    stream: PinnedStream,
+    model: PhantomData<fn() -> T>,
+}
 
 impl ResponseBody {
     fn new(stream: PinnedStream) -> Self {
-        Self(stream)
+        Self {
+            stream,
+            model: PhantomData,
+        }
+    }
+
+    /// Create a response body from a stream of bytes.
+    ///
+    /// This is useful to wrap an existing body, for example to transform its contents as they
+    /// are streamed.
+    pub fn from_stream<S>(stream: S) -> Self
+    where
+        S: Stream<Item = crate::Result<Bytes>> + Send + Sync + 'static,
+    {
+        Self::new(Box::pin(stream))
+    }
+
+    /// Report the progress of the transfer to `callback` every time a chunk of the body is
+    /// received.
+    ///
+    /// `total_bytes` is the expected size of the body, if known.
+    pub fn with_progress<F>(self, total_bytes: Option<u64>, callback: F) -> Self
+    where
+        F: FnMut(TransferProgress) + Send + Sync + 'static,
+    {
+        Self::from_stream(ProgressStream {
+            body: self.stream,
+            progress: TransferProgress {
+                bytes_transferred: 0,
+                total_bytes,
+            },
+            callback: Box::new(callback),
+        })
+    }
+
+    /// Write the stream into `writer` chunk by chunk, without buffering the whole body.
+    ///
+    /// Returns the number of bytes written.
+    pub async fn write_to<W>(mut self, writer: &mut W) -> crate::Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let mut bytes_written = 0;
+        while let Some(chunk) = self.stream.next().await {
+            let chunk = chunk?;
+            writer
+                .write_all(&chunk)
+                .await
+                .context(ErrorKind::Io, "failed to write response body")?;
+            bytes_written += chunk.len() as u64;
+        }
+        writer
+            .flush()
+            .await
+            .context(ErrorKind::Io, "failed to flush response body")?;
+        Ok(bytes_written)
     }
 
     /// Collect the stream into a `Bytes` collection
     pub async fn collect(mut self) -> crate::Result<Bytes> {
         let mut final_result = Vec::new();
 
-        while let Some(res) = self.0.next().await {
+        while let Some(res) = self.stream.next().await {
             final_result.extend(&res?);
         }
 
@@ -127,21 +270,198 @@ impl ResponseBody {
             )
             .map(ToOwned::to_owned)
     }
+
+    /// Collect the stream and deserialize it from JSON.
+    pub async fn collect_json<U: DeserializeOwned>(self) -> crate::Result<U> {
+        self.collect_as::<JsonFormat, U>().await
+    }
+
+    /// Collect the stream and deserialize it from XML.
+    #[cfg(feature = "xml")]
+    pub async fn collect_xml<U: DeserializeOwned>(self) -> crate::Result<U> {
+        self.collect_as::<crate::XmlFormat, U>().await
+    }
+
+    /// Collect the stream and deserialize it from the format `F`.
+    pub async fn collect_as<F: Format, U: DeserializeOwned>(self) -> crate::Result<U> {
+        F::deserialize(&self.collect().await?)
+    }
+
+    /// Type the body with the model it deserializes into.
+    pub fn typed<T>(self) -> ResponseBody<T> {
+        ResponseBody {
+            stream: self.stream,
+            model: PhantomData,
+        }
+    }
+}
+
+impl<T> ResponseBody<T> {
+    /// Drop the type of the body, to handle it as a stream of bytes.
+    pub fn into_raw(self) -> ResponseBody {
+        ResponseBody::new(self.stream)
+    }
+}
+
+impl<T: DeserializeOwned> ResponseBody<T> {
+    /// Collect the body and deserialize it from JSON.
+    pub async fn json(self) -> crate::Result<T> {
+        self.into_raw().collect_json().await
+    }
+
+    /// Collect the body and deserialize it from XML.
+    #[cfg(feature = "xml")]
+    pub async fn xml(self) -> crate::Result<T> {
+        self.into_raw().collect_xml().await
+    }
 }
 
-impl Stream for ResponseBody {
+impl<T> Stream for ResponseBody<T> {
     type Item = crate::Result<Bytes>;
     fn poll_next(
         self: Pin<&mut Self>,
         cx: &mut std::task::Context<'_>,
     ) -> std::task::Poll<Option<Self::Item>> {
         let this = self.project();
-        this.0.poll_next(cx)
+        this.stream.poll_next(cx)
     }
 }
 
-impl Debug for ResponseBody {
+impl<T> Debug for ResponseBody<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str("ResonseBody")
     }
 }
+
+/// The progress of a transfer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransferProgress {
+    /// The number of bytes transferred so far.
+    pub bytes_transferred: u64,
+    /// The total number of bytes to transfer, if known.
+    pub total_bytes: Option<u64>,
+}
+
+impl TransferProgress {
+    /// The fraction of the transfer completed, between `0.0` and `1.0`, if the total is known.
+    pub fn fraction(&self) -> Option<f64> {
+        self.total_bytes.map(|total_bytes| {
+            if total_bytes == 0 {
+                1.0
+            } else {
+                self.bytes_transferred as f64 / total_bytes as f64
+            }
+        })
+    }
+}
+
+type ProgressCallback = Box<dyn FnMut(TransferProgress) + Send + Sync>;
+
+struct ProgressStream {
+    body: PinnedStream,
+    progress: TransferProgress,
+    callback: ProgressCallback,
+}
+
+impl Stream for ProgressStream {
+    type Item = crate::Result<Bytes>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let chunk = futures::ready!(this.body.as_mut().poll_next(cx));
+        if let Some(Ok(bytes)) = &chunk {
+            this.progress.bytes_transferred += bytes.len() as u64;
+            (this.callback)(this.progress);
+        }
+        std::task::Poll::Ready(chunk)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use std::sync::{Arc, Mutex};
+
+    fn chunked_body(chunks: &[&'static [u8]]) -> ResponseBody {
+        let chunks = chunks
+            .iter()
+            .map(|chunk| Ok(Bytes::from_static(chunk)))
+            .collect::<Vec<_>>();
+        ResponseBody::from_stream(futures::stream::iter(chunks))
+    }
+
+    #[test]
+    fn reports_progress() {
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let body = chunked_body(&[b"hello", b" ", b"world"]).with_progress(Some(11), {
+            let reported = reported.clone();
+            move |progress| reported.lock().unwrap().push(progress.bytes_transferred)
+        });
+
+        assert_eq!(block_on(body.collect()).unwrap(), &b"hello world"[..]);
+        assert_eq!(*reported.lock().unwrap(), vec![5, 6, 11]);
+    }
+
+    #[test]
+    fn collects_json() {
+        #[derive(serde::Deserialize, Debug, PartialEq)]
+        struct Model {
+            name: String,
+        }
+
+        let body = chunked_body(&[b"{\"name\":", b"\"blob\"}"]);
+        let model: Model = block_on(body.collect_json()).unwrap();
+        assert_eq!(model.name, "blob");
+
+        let body = chunked_body(&[b"{\"name\":\"blob\"}"]).typed::<Model>();
+        assert_eq!(
+            block_on(body.json()).unwrap(),
+            Model {
+                name: "blob".to_owned()
+            }
+        );
+
+        let error = block_on(chunked_body(&[b"{"]).collect_json::<Model>()).unwrap_err();
+        assert!(format!("{error}").contains("Model"));
+    }
+
+    #[test]
+    fn deserializes_collected_responses() {
+        let body = Bytes::from_static(b"{\"name\":\"blob\"}");
+        let response = CollectedResponse::new(StatusCode::Ok, Headers::new(), body.clone());
+        let value: serde_json::Value = response.json().unwrap();
+        assert_eq!(value["name"], "blob");
+        assert_eq!(response.text().unwrap(), "{\"name\":\"blob\"}");
+        assert_eq!(response.bytes(), body);
+
+        let response = CollectedResponse::new(StatusCode::Ok, Headers::new(), Bytes::new());
+        assert!(response.json::<serde_json::Value>().is_err());
+    }
+
+    #[test]
+    fn writes_chunks() {
+        let mut writer = Vec::new();
+        let written =
+            block_on(chunked_body(&[b"hello", b" ", b"world"]).write_to(&mut writer)).unwrap();
+        assert_eq!(written, 11);
+        assert_eq!(writer, b"hello world");
+    }
+
+    #[test]
+    fn progress_fraction() {
+        let progress = TransferProgress {
+            bytes_transferred: 5,
+            total_bytes: Some(10),
+        };
+        assert_eq!(progress.fraction(), Some(0.5));
+        let progress = TransferProgress {
+            bytes_transferred: 5,
+            total_bytes: None,
+        };
+        assert_eq!(progress.fraction(), None);
+    }
+}
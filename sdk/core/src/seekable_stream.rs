@@ -1,3 +1,4 @@
+use crate::BufferPool;
 use bytes::Bytes;
 use futures::io::AsyncRead;
 use futures::stream::Stream;
@@ -27,14 +28,12 @@ impl Stream for dyn SeekableStream {
         self: std::pin::Pin<&mut Self>,
         cx: &mut std::task::Context<'_>,
     ) -> std::task::Poll<Option<Self::Item>> {
-        let mut buffer = vec![0_u8; 1024 * 64];
-
+        let mut buffer = BufferPool::global().acquire();
         match self.poll_read(cx, &mut buffer) {
             Poll::Ready(Ok(0)) => Poll::Ready(None),
+            // the bytes are copied so that the buffer goes back to the pool
             Poll::Ready(Ok(bytes_read)) => {
-                let bytes: Bytes = buffer.into();
-                let bytes = bytes.slice(0..bytes_read);
-                Poll::Ready(Some(Ok(bytes)))
+                Poll::Ready(Some(Ok(Bytes::copy_from_slice(&buffer[..bytes_read]))))
             }
             Poll::Ready(Err(err)) => Poll::Ready(Some(Err(crate::error::Error::full(
                 crate::error::ErrorKind::Io,
@@ -1,36 +1,193 @@
-use futures::Future;
-use std::pin::Pin;
-use std::task::{Context, Poll};
-use std::thread;
-use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+pub use native::{sleep, Sleep};
+#[cfg(target_arch = "wasm32")]
+pub use timeout::{sleep, Sleep};
 
-pub fn sleep(duration: Duration) -> Sleep {
-    Sleep {
-        thread: None,
-        duration,
+#[cfg(not(target_arch = "wasm32"))]
+mod native {
+    use futures::Future;
+    use std::pin::Pin;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::mpsc::{self, RecvTimeoutError};
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll, Waker};
+    use std::thread;
+    use std::time::Duration;
+
+    pub fn sleep(duration: Duration) -> Sleep {
+        Sleep {
+            timer: None,
+            duration,
+        }
     }
-}
 
-#[derive(Debug)]
-pub struct Sleep {
-    thread: Option<thread::JoinHandle<()>>,
-    duration: Duration,
+    /// Completes once `duration` has elapsed since it was first polled.
+    ///
+    /// The time is kept by a thread, which stops as soon as the `Sleep` is dropped: e.g. when it
+    /// lost a race against another future.
+    #[derive(Debug)]
+    pub struct Sleep {
+        timer: Option<Timer>,
+        duration: Duration,
+    }
+
+    #[derive(Debug)]
+    struct Timer {
+        elapsed: Arc<AtomicBool>,
+        waker: Arc<Mutex<Waker>>,
+        /// Dropping the sender disconnects the channel, which stops the thread.
+        _cancel: mpsc::Sender<()>,
+    }
+
+    impl Timer {
+        fn start(duration: Duration, waker: &Waker) -> Self {
+            let elapsed = Arc::new(AtomicBool::new(false));
+            let waker = Arc::new(Mutex::new(waker.clone()));
+            let (cancel, cancelled) = mpsc::channel::<()>();
+            {
+                let elapsed = elapsed.clone();
+                let waker = waker.clone();
+                thread::spawn(move || {
+                    if let Err(RecvTimeoutError::Timeout) = cancelled.recv_timeout(duration) {
+                        elapsed.store(true, Ordering::Release);
+                        waker.lock().unwrap().wake_by_ref();
+                    }
+                });
+            }
+            Self {
+                elapsed,
+                waker,
+                _cancel: cancel,
+            }
+        }
+
+        fn poll(&self, waker: &Waker) -> Poll<()> {
+            if self.elapsed.load(Ordering::Acquire) {
+                return Poll::Ready(());
+            }
+            {
+                let mut current = self.waker.lock().unwrap();
+                if !current.will_wake(waker) {
+                    *current = waker.clone();
+                }
+            }
+            // the time may have elapsed while the waker was replaced
+            if self.elapsed.load(Ordering::Acquire) {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        }
+    }
+
+    impl Future for Sleep {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let this = self.get_mut();
+            match &this.timer {
+                Some(timer) => timer.poll(cx.waker()),
+                None => {
+                    this.timer = Some(Timer::start(this.duration, cx.waker()));
+                    Poll::Pending
+                }
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use futures::task::noop_waker;
+        use std::time::Instant;
+
+        #[test]
+        fn is_pending_until_the_duration_elapsed() {
+            let waker = noop_waker();
+            let mut cx = Context::from_waker(&waker);
+            let mut sleep = sleep(Duration::from_millis(100));
+
+            assert!(Pin::new(&mut sleep).poll(&mut cx).is_pending());
+            // polled again before the time elapsed, e.g. by `select` when the other future woke
+            assert!(Pin::new(&mut sleep).poll(&mut cx).is_pending());
+
+            thread::sleep(Duration::from_millis(200));
+            assert!(Pin::new(&mut sleep).poll(&mut cx).is_ready());
+        }
+
+        #[test]
+        fn completes_after_the_duration() {
+            let started = Instant::now();
+            futures::executor::block_on(sleep(Duration::from_millis(50)));
+            assert!(started.elapsed() >= Duration::from_millis(50));
+        }
+
+        #[test]
+        fn dropping_stops_the_timer() {
+            let waker = noop_waker();
+            let mut cx = Context::from_waker(&waker);
+            let mut sleep = sleep(Duration::from_secs(3600));
+            assert!(Pin::new(&mut sleep).poll(&mut cx).is_pending());
+
+            let elapsed = sleep.timer.as_ref().unwrap().elapsed.clone();
+            // the thread holds the other reference to the flag until it stops
+            assert_eq!(Arc::strong_count(&elapsed), 2);
+            drop(sleep);
+
+            let started = Instant::now();
+            while Arc::strong_count(&elapsed) > 1 {
+                assert!(started.elapsed() < Duration::from_secs(5));
+                thread::sleep(Duration::from_millis(10));
+            }
+            assert!(!elapsed.load(Ordering::Acquire));
+        }
+    }
 }
 
-impl Future for Sleep {
-    type Output = ();
-
-    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        if self.thread.is_none() {
-            let waker = cx.waker().clone();
-            let duration = self.duration;
-            self.get_mut().thread = Some(thread::spawn(move || {
-                thread::sleep(duration);
-                waker.wake();
-            }));
-            Poll::Pending
-        } else {
-            Poll::Ready(())
+/// `wasm32` targets cannot spawn threads, so the JavaScript host's `setTimeout` is used instead.
+#[cfg(target_arch = "wasm32")]
+mod timeout {
+    use futures::Future;
+    use js_sys::{Function, Promise};
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+    use std::time::Duration;
+    use wasm_bindgen::prelude::wasm_bindgen;
+    use wasm_bindgen_futures::JsFuture;
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_name = setTimeout)]
+        fn set_timeout(handler: &Function, timeout: i32);
+    }
+
+    pub fn sleep(duration: Duration) -> Sleep {
+        Sleep {
+            timeout: None,
+            duration,
+        }
+    }
+
+    #[derive(Debug)]
+    pub struct Sleep {
+        timeout: Option<JsFuture>,
+        duration: Duration,
+    }
+
+    impl Future for Sleep {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let this = self.get_mut();
+            let duration = this.duration;
+            let timeout = this.timeout.get_or_insert_with(|| {
+                let millis = duration.as_millis().try_into().unwrap_or(i32::MAX);
+                JsFuture::from(Promise::new(&mut |resolve, _reject| {
+                    set_timeout(&resolve, millis)
+                }))
+            });
+            // `setTimeout` never rejects
+            Pin::new(timeout).poll(cx).map(|_| ())
         }
     }
 }
@@ -4,11 +4,20 @@ use crate::error::{ErrorKind, ResultExt};
 const UTF8_BOM: [u8; 3] = [0xEF, 0xBB, 0xBF];
 
 /// Reads the XML from bytes.
+///
+/// The error does not contain the XML, which may hold secrets: it is only logged at the trace
+/// level.
 pub fn read_xml<'de, T: serde::de::Deserialize<'de>>(body: &[u8]) -> crate::Result<T> {
     serde_xml_rs::from_reader(slice_bom(body)).with_context(ErrorKind::DataConversion, || {
         let t = core::any::type_name::<T>();
-        let xml = std::str::from_utf8(body).unwrap_or("<XML IS NOT UTF-8>");
-        format!("failed to deserialize the following xml into a {t}\n{xml}")
+        log::trace!(
+            "failed to deserialize the following xml into a {t}\n{}",
+            String::from_utf8_lossy(body)
+        );
+        format!(
+            "failed to deserialize {} bytes of xml into a {t}",
+            body.len()
+        )
     })
 }
 
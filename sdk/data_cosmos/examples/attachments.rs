@@ -124,7 +124,7 @@ async fn main() -> azure_core::Result<()> {
     println!("creating slug attachment");
     let attachment = document.attachment_client("slug00".to_owned());
     let resp = attachment
-        .create_slug("FFFFF".into())
+        .create_slug("FFFFF")
         .consistency_level(&resp_delete)
         .content_type("text/plain")
         .into_future()
@@ -132,6 +132,16 @@ async fn main() -> azure_core::Result<()> {
 
     println!("create slug == {:#?}", resp);
 
+    println!("downloading slug media");
+    let media = attachment
+        .get_media(resp.attachment.media.clone())
+        .into_future()
+        .await?
+        .data
+        .collect_string()
+        .await?;
+    println!("slug media == {}", media);
+
     println!("deleting");
     let resp_delete = attachment
         .delete()
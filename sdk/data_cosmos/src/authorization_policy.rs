@@ -105,6 +105,7 @@ fn generate_resource_link(request: &Request) -> String {
         "/pkranges",
         "/udfs",
         "/triggers",
+        "/conflicts",
     ];
 
     // This strips the leading slash from the uri of the passed request.
@@ -112,6 +113,11 @@ fn generate_resource_link(request: &Request) -> String {
     let uri = uri_path.trim_start_matches('/');
     trace!("uri used by AuthorizationPolicy == {:#?}", uri);
 
+    // Media are addressed by their id, which is a resource id and is signed in lowercase.
+    if let Some(media_id) = uri.strip_prefix("media/") {
+        return media_id.to_lowercase();
+    }
+
     // We find the above resource names. If found, we strip it and eagerly return. Note that the
     // resource names have a leading slash so the suffix will match `test/users` but not
     // `test-users`.
@@ -213,6 +219,9 @@ fn string_to_sign(
             ResourceType::PartitionKeyRanges => "pkranges",
             ResourceType::UserDefinedFunctions => "udfs",
             ResourceType::Triggers => "triggers",
+            ResourceType::Conflicts => "conflicts",
+            ResourceType::Media => "media",
+            ResourceType::DatabaseAccount => "",
         },
         resource_link,
         date::to_rfc1123(&time_nonce).to_lowercase()
@@ -342,4 +351,17 @@ mon, 01 jan 1900 01:00:00 gmt
         );
         assert_eq!(&generate_resource_link(&request), "dbs/test_db");
     }
+
+    #[test]
+    fn generate_resource_link_04() {
+        let request = Request::new(
+            reqwest::Url::parse("https://.documents.azure.com/media/Sl8fAN4EjgsBAAAAAAAAAHk8FmU=")
+                .unwrap(),
+            azure_core::Method::Get,
+        );
+        assert_eq!(
+            &generate_resource_link(&request),
+            "sl8fan4ejgsbaaaaaaaaahk8fmu="
+        );
+    }
 }
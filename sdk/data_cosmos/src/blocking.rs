@@ -0,0 +1,145 @@
+//! Synchronous clients for the common collection and document operations.
+//!
+//! These clients wrap the asynchronous ones and run their requests with
+//! [`azure_core::blocking::block_on`]. Other operations are available through the wrapped
+//! client, e.g. `block_on(client.as_async().list_triggers().into_stream().next())`.
+//!
+//! ```no_run
+//! use azure_data_cosmos::{blocking, prelude::*};
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Item {
+//!     id: String,
+//! }
+//!
+//! let authorization_token = AuthorizationToken::primary_from_base64("a2V5")?;
+//! let collection = blocking::CollectionClient::new(
+//!     CosmosClient::new("account", authorization_token)
+//!         .database_client("database")
+//!         .collection_client("collection"),
+//! );
+//! for item in collection.query_documents::<Item, _>("SELECT * FROM c") {
+//!     println!("{}", item?.id);
+//! }
+//! # Ok::<(), azure_core::Error>(())
+//! ```
+use crate::prelude::{self, CosmosEntity, Query};
+use azure_core::blocking::block_on;
+use futures::StreamExt;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// A synchronous [`CollectionClient`](prelude::CollectionClient).
+#[derive(Debug, Clone)]
+pub struct CollectionClient {
+    client: prelude::CollectionClient,
+}
+
+impl CollectionClient {
+    pub fn new(client: prelude::CollectionClient) -> Self {
+        Self { client }
+    }
+
+    /// The wrapped asynchronous client.
+    pub fn as_async(&self) -> &prelude::CollectionClient {
+        &self.client
+    }
+
+    /// Get a collection.
+    pub fn get_collection(&self) -> azure_core::Result<prelude::GetCollectionResponse> {
+        block_on(self.client.get_collection().into_future())
+    }
+
+    /// Create a document in the collection.
+    pub fn create_document<D: Serialize + CosmosEntity + Send + 'static>(
+        &self,
+        document: D,
+    ) -> azure_core::Result<prelude::CreateDocumentResponse> {
+        block_on(self.client.create_document(document).into_future())
+    }
+
+    /// Query documents in the collection.
+    ///
+    /// The pages of results are requested as the iterator is consumed.
+    pub fn query_documents<T, Q>(&self, query: Q) -> impl Iterator<Item = azure_core::Result<T>>
+    where
+        T: DeserializeOwned + Send + Sync + 'static,
+        Q: Into<Query>,
+    {
+        let pages = self
+            .client
+            .query_documents(query)
+            .into_stream::<T>()
+            .map(|page| match page {
+                Ok(page) => page
+                    .results
+                    .into_iter()
+                    .map(|(document, _)| Ok(document))
+                    .collect(),
+                Err(error) => vec![Err(error)],
+            });
+        azure_core::blocking::iter(pages).flatten()
+    }
+
+    /// Convert into a [`DocumentClient`].
+    pub fn document_client<S: Into<String>, PK: Serialize>(
+        &self,
+        document_name: S,
+        partition_key: &PK,
+    ) -> azure_core::Result<DocumentClient> {
+        self.client
+            .document_client(document_name, partition_key)
+            .map(DocumentClient::new)
+    }
+}
+
+impl From<prelude::CollectionClient> for CollectionClient {
+    fn from(client: prelude::CollectionClient) -> Self {
+        Self::new(client)
+    }
+}
+
+/// A synchronous [`DocumentClient`](prelude::DocumentClient).
+#[derive(Debug, Clone)]
+pub struct DocumentClient {
+    client: prelude::DocumentClient,
+}
+
+impl DocumentClient {
+    pub fn new(client: prelude::DocumentClient) -> Self {
+        Self { client }
+    }
+
+    /// The wrapped asynchronous client.
+    pub fn as_async(&self) -> &prelude::DocumentClient {
+        &self.client
+    }
+
+    /// Get the document, or `None` if it does not exist.
+    pub fn get_document<T: DeserializeOwned + Send>(&self) -> azure_core::Result<Option<T>> {
+        let response = block_on(self.client.get_document::<T>().into_future())?;
+        Ok(match response {
+            prelude::GetDocumentResponse::Found(found) => Some(found.document.document),
+            prelude::GetDocumentResponse::NotFound(_) => None,
+        })
+    }
+
+    /// Replace the document.
+    pub fn replace_document<D: Serialize + Send + 'static>(
+        &self,
+        document: D,
+    ) -> azure_core::Result<prelude::ReplaceDocumentResponse> {
+        block_on(self.client.replace_document(document).into_future())
+    }
+
+    /// Delete the document.
+    pub fn delete_document(&self) -> azure_core::Result<prelude::DeleteDocumentResponse> {
+        block_on(self.client.delete_document().into_future())
+    }
+}
+
+impl From<prelude::DocumentClient> for DocumentClient {
+    fn from(client: prelude::DocumentClient) -> Self {
+        Self::new(client)
+    }
+}
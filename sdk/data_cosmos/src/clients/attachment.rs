@@ -1,8 +1,7 @@
 use crate::clients::*;
 use crate::operations::*;
 use crate::ReadonlyString;
-use azure_core::{Pipeline, Request};
-use bytes::Bytes;
+use azure_core::{Body, Pipeline, Request};
 
 /// A client for Cosmos attachment resources.
 #[derive(Debug, Clone)]
@@ -34,13 +33,22 @@ impl AttachmentClient {
     }
 
     /// Create an attachment with a slug.
-    pub fn create_slug(&self, body: Bytes) -> CreateOrReplaceSlugAttachmentBuilder {
-        CreateOrReplaceSlugAttachmentBuilder::new(self.clone(), true, body)
+    ///
+    /// The body is the media of the attachment, either in memory or as a stream.
+    pub fn create_slug(&self, body: impl Into<Body>) -> CreateOrReplaceSlugAttachmentBuilder {
+        CreateOrReplaceSlugAttachmentBuilder::new(self.clone(), true, body.into())
     }
 
     /// Replace an attachment with a slug.
-    pub fn replace_slug(&self, body: Bytes) -> CreateOrReplaceSlugAttachmentBuilder {
-        CreateOrReplaceSlugAttachmentBuilder::new(self.clone(), false, body)
+    pub fn replace_slug(&self, body: impl Into<Body>) -> CreateOrReplaceSlugAttachmentBuilder {
+        CreateOrReplaceSlugAttachmentBuilder::new(self.clone(), false, body.into())
+    }
+
+    /// Download the media of an attachment created with a slug.
+    ///
+    /// `media` is the media link of the attachment, as returned by [`get`](Self::get).
+    pub fn get_media<M: Into<String>>(&self, media: M) -> GetAttachmentMediaBuilder {
+        GetAttachmentMediaBuilder::new(self.clone(), media.into())
     }
 
     /// Create a reference attachment.
@@ -124,6 +132,11 @@ impl AttachmentClient {
         )
     }
 
+    pub(crate) fn media_request(&self, media: &str, method: azure_core::Method) -> Request {
+        self.cosmos_client()
+            .request(media.trim_start_matches('/'), method)
+    }
+
     pub(crate) fn pipeline(&self) -> &Pipeline {
         self.cosmos_client().pipeline()
     }
@@ -62,6 +62,21 @@ impl CollectionClient {
         QueryDocumentsBuilder::new(self.clone(), query.into())
     }
 
+    /// Read many documents of the collection, given their ids and partition keys.
+    ///
+    /// The documents are read with parallel point reads, which are cheaper than a query. The
+    /// results are in the order of `items`, each with its own error.
+    pub fn read_many<S: AsRef<str>, PK: Serialize>(
+        &self,
+        items: &[(S, PK)],
+    ) -> ReadManyDocumentsBuilder {
+        let documents = items
+            .iter()
+            .map(|(id, partition_key)| self.document_client(id.as_ref(), partition_key))
+            .collect();
+        ReadManyDocumentsBuilder::new(documents)
+    }
+
     /// List stored procedures in a collection.
     pub fn list_stored_procedures(&self) -> ListStoredProceduresBuilder {
         ListStoredProceduresBuilder::new(self.clone())
@@ -77,6 +92,14 @@ impl CollectionClient {
         ListTriggersBuilder::new(self.clone())
     }
 
+    /// List the conflicts between concurrent writes in a collection.
+    ///
+    /// Conflicts are only recorded in accounts with multiple write regions, when the collection
+    /// uses a custom conflict resolution policy.
+    pub fn list_conflicts(&self) -> ListConflictsBuilder {
+        ListConflictsBuilder::new(self.clone())
+    }
+
     /// List the partition key ranges in a collection.
     pub fn get_partition_key_ranges(&self) -> GetPartitionKeyRangesBuilder {
         GetPartitionKeyRangesBuilder::new(self.clone())
@@ -91,6 +114,17 @@ impl CollectionClient {
         DocumentClient::new(self.clone(), document_name, partition_key)
     }
 
+    /// Convert into a [`ConflictClient`].
+    ///
+    /// The partition key is the one of the conflicting resource.
+    pub fn conflict_client<S: Into<ReadonlyString>, PK: Serialize>(
+        &self,
+        conflict_name: S,
+        partition_key: &PK,
+    ) -> azure_core::Result<ConflictClient> {
+        ConflictClient::new(self.clone(), conflict_name, partition_key)
+    }
+
     /// Convert into a [`TriggerClient`].
     pub fn trigger_client<S: Into<ReadonlyString>>(&self, trigger_name: S) -> TriggerClient {
         TriggerClient::new(self.clone(), trigger_name)
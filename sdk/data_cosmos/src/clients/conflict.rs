@@ -0,0 +1,98 @@
+use crate::clients::*;
+use crate::operations::*;
+use crate::ReadonlyString;
+use azure_core::{Pipeline, Request};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// A client for Cosmos conflict resources.
+#[derive(Debug, Clone)]
+pub struct ConflictClient {
+    collection: CollectionClient,
+    conflict_name: ReadonlyString,
+    partition_key_serialized: String,
+}
+
+impl ConflictClient {
+    /// Create a new conflict client.
+    ///
+    /// A conflict is identified by its id and the partition key of the conflicting resource.
+    pub(crate) fn new<S: Into<ReadonlyString>, PK: Serialize>(
+        collection: CollectionClient,
+        conflict_name: S,
+        partition_key: &PK,
+    ) -> azure_core::Result<Self> {
+        Ok(Self {
+            collection,
+            conflict_name: conflict_name.into(),
+            partition_key_serialized: crate::cosmos_entity::serialize_partition_key(partition_key)?,
+        })
+    }
+
+    /// Get the conflict.
+    pub fn get_conflict(&self) -> GetConflictBuilder {
+        GetConflictBuilder::new(self.clone())
+    }
+
+    /// Get the winning version of the conflicting resource, along with its etag.
+    ///
+    /// The winning version is the one currently stored in the collection. Replace it with an
+    /// [`IfMatchCondition`](azure_core::prelude::IfMatchCondition) on its etag to resolve the conflict
+    /// only if no other write happened in the meantime.
+    pub fn get_winning_document<T: DeserializeOwned + Send + Sync>(
+        &self,
+    ) -> GetWinningDocumentBuilder<T> {
+        GetWinningDocumentBuilder::new(self.clone())
+    }
+
+    /// Delete the conflict, once it has been resolved.
+    pub fn delete_conflict(&self) -> DeleteConflictBuilder {
+        DeleteConflictBuilder::new(self.clone())
+    }
+
+    /// Get a [`CosmosClient`].
+    pub fn cosmos_client(&self) -> &CosmosClient {
+        self.collection.cosmos_client()
+    }
+
+    /// Get a [`DatabaseClient`].
+    pub fn database_client(&self) -> &DatabaseClient {
+        self.collection.database_client()
+    }
+
+    /// Get a [`CollectionClient`].
+    pub fn collection_client(&self) -> &CollectionClient {
+        &self.collection
+    }
+
+    /// Get the conflict name.
+    pub fn conflict_name(&self) -> &str {
+        &self.conflict_name
+    }
+
+    /// Get the partition key
+    pub fn partition_key_serialized(&self) -> &str {
+        &self.partition_key_serialized
+    }
+
+    /// Create a request for a specific collection conflict
+    pub(crate) fn conflict_request(&self, method: azure_core::Method) -> Request {
+        let mut request = self.cosmos_client().request(
+            &format!(
+                "dbs/{}/colls/{}/conflicts/{}",
+                self.database_client().database_name(),
+                self.collection_client().collection_name(),
+                self.conflict_name()
+            ),
+            method,
+        );
+        crate::cosmos_entity::add_as_partition_key_header_serialized(
+            self.partition_key_serialized(),
+            &mut request,
+        );
+        request
+    }
+
+    pub(crate) fn pipeline(&self) -> &Pipeline {
+        self.cosmos_client().pipeline()
+    }
+}
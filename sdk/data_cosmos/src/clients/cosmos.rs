@@ -4,7 +4,10 @@ use crate::resources::permission::AuthorizationToken;
 use crate::resources::ResourceType;
 use crate::ReadonlyString;
 
-use azure_core::{ClientOptions, Context, Pipeline, Request, Response};
+use azure_core::{
+    AzureCloud, ClientOptions, CloudConfiguration, Context, HealthReport, Pipeline, Request,
+    Response,
+};
 
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -45,8 +48,13 @@ impl CosmosClientBuilder {
     pub fn build(self) -> CosmosClient {
         let auth_token = self.cloud_location.auth_token();
         CosmosClient {
-            pipeline: new_pipeline_from_options(self.options, auth_token),
+            pipeline: new_pipeline_from_options(
+                self.options.clone(),
+                auth_token,
+                &self.cloud_location,
+            ),
             cloud_location: self.cloud_location,
+            options: self.options,
         }
     }
 
@@ -57,6 +65,23 @@ impl CosmosClientBuilder {
         self
     }
 
+    /// Set the endpoint of the account, e.g. a private endpoint, keeping the auth token.
+    #[must_use]
+    pub fn endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.cloud_location = CloudLocation::Custom {
+            uri: endpoint.into(),
+            auth_token: self.cloud_location.auth_token(),
+        };
+        self
+    }
+
+    /// Set the auth token, keeping the endpoint.
+    #[must_use]
+    pub fn credential(mut self, auth_token: AuthorizationToken) -> Self {
+        self.cloud_location = self.cloud_location.with_auth_token(auth_token);
+        self
+    }
+
     /// Set the retry options.
     #[must_use]
     pub fn retry(mut self, retry: impl Into<azure_core::RetryOptions>) -> Self {
@@ -86,6 +111,7 @@ impl CosmosClientBuilder {
 pub struct CosmosClient {
     pipeline: Pipeline,
     cloud_location: CloudLocation,
+    options: ClientOptions,
 }
 
 impl CosmosClient {
@@ -107,13 +133,10 @@ impl CosmosClient {
     /// Set the auth token used
     #[must_use]
     pub fn auth_token(mut self, auth_token: AuthorizationToken) -> Self {
-        // we replace the AuthorizationPolicy. This is
-        // the last-1 policy by construction.
-        let auth_policy: Arc<dyn azure_core::Policy> =
-            Arc::new(crate::AuthorizationPolicy::new(auth_token));
-
-        self.pipeline
-            .replace_policy(auth_policy, self.pipeline.policies().len() - 2);
+        // we rebuild the pipeline from the same options so that the
+        // AuthorizationPolicy uses the new token.
+        self.pipeline =
+            new_pipeline_from_options(self.options.clone(), auth_token, &self.cloud_location);
         self
     }
 
@@ -127,6 +150,25 @@ impl CosmosClient {
         ListDatabasesBuilder::new(self.clone())
     }
 
+    /// Read the metadata of the account: its regions, its default consistency and its
+    /// replication settings.
+    pub fn read_database_account(&self) -> ReadDatabaseAccountBuilder {
+        ReadDatabaseAccountBuilder::new(self.clone())
+    }
+
+    /// Checks that the account can be reached with the auth token of the client, e.g. for a
+    /// readiness probe, by reading the database account.
+    ///
+    /// Reading the database account requires a primary or secondary key: with a resource token
+    /// the account is reported with a failed authentication.
+    pub async fn health_check(&self) -> HealthReport {
+        HealthReport::check(
+            self.cloud_location.url(),
+            self.read_database_account().into_future(),
+        )
+        .await
+    }
+
     /// Create a [`DatabaseClient`].
     pub fn database_client<S: Into<ReadonlyString>>(&self, database_name: S) -> DatabaseClient {
         DatabaseClient::new(self.clone(), database_name)
@@ -164,14 +206,24 @@ impl CosmosClient {
 fn new_pipeline_from_options(
     options: ClientOptions,
     authorization_token: AuthorizationToken,
+    cloud_location: &CloudLocation,
 ) -> Pipeline {
     let auth_policy: Arc<dyn azure_core::Policy> =
         Arc::new(crate::AuthorizationPolicy::new(authorization_token));
 
+    // The request charge is recorded per retry, as every attempt consumes request units.
+    let mut per_retry_policies: Vec<Arc<dyn azure_core::Policy>> =
+        vec![Arc::new(crate::RequestChargePolicy)];
+    if let Some(recorder) = options.metrics_recorder() {
+        per_retry_policies.push(Arc::new(crate::MetricsPolicy::new(
+            cloud_location.account().map(ToOwned::to_owned),
+            recorder.clone(),
+        )));
+    }
     // The `AuthorizationPolicy` must be the **last** retry policy.
     // Policies can change the url and/or the headers, and the `AuthorizationPolicy`
     // must be able to inspect them or the resulting token will be invalid.
-    let per_retry_policies = vec![auth_policy];
+    per_retry_policies.push(auth_policy);
 
     Pipeline::new(
         option_env!("CARGO_PKG_NAME"),
@@ -183,7 +235,6 @@ fn new_pipeline_from_options(
 }
 
 /// The cloud with which you want to interact.
-// TODO: Other govt clouds?
 #[derive(Debug, Clone)]
 pub enum CloudLocation {
     /// Azure public cloud
@@ -200,6 +251,22 @@ pub enum CloudLocation {
         /// The auth token
         auth_token: AuthorizationToken,
     },
+    /// Azure US Government cloud
+    UsGovernment {
+        /// The account name
+        account: String,
+        /// The auth token
+        auth_token: AuthorizationToken,
+    },
+    /// Another cloud, such as Azure Stack
+    Sovereign {
+        /// The endpoints of the cloud
+        cloud: CloudConfiguration,
+        /// The account name
+        account: String,
+        /// The auth token
+        auth_token: AuthorizationToken,
+    },
     /// Use the well-known Cosmos emulator
     Emulator {
         /// The emulator's address
@@ -220,23 +287,127 @@ impl CloudLocation {
     /// the base URL for a given cloud location
     fn url(&self) -> String {
         match self {
-            CloudLocation::Public { account, .. } => {
-                format!("https://{account}.documents.azure.com")
-            }
-            CloudLocation::China { account, .. } => format!("https://{account}.documents.azure.cn"),
+            CloudLocation::Public { account, .. } => AzureCloud::Public
+                .configuration()
+                .cosmos_account_url(account),
+            CloudLocation::China { account, .. } => AzureCloud::China
+                .configuration()
+                .cosmos_account_url(account),
+            CloudLocation::UsGovernment { account, .. } => AzureCloud::UsGovernment
+                .configuration()
+                .cosmos_account_url(account),
+            CloudLocation::Sovereign { cloud, account, .. } => cloud.cosmos_account_url(account),
             CloudLocation::Custom { uri, .. } => uri.clone(),
             CloudLocation::Emulator { address, port } => format!("https://{address}:{port}"),
         }
     }
 
+    /// The account name, unless the location is a custom URL or the emulator.
+    fn account(&self) -> Option<&str> {
+        match self {
+            CloudLocation::Public { account, .. }
+            | CloudLocation::China { account, .. }
+            | CloudLocation::UsGovernment { account, .. }
+            | CloudLocation::Sovereign { account, .. } => Some(account),
+            CloudLocation::Emulator { .. } | CloudLocation::Custom { .. } => None,
+        }
+    }
+
     fn auth_token(&self) -> AuthorizationToken {
         match self {
             CloudLocation::Public { auth_token, .. } => auth_token.clone(),
             CloudLocation::China { auth_token, .. } => auth_token.clone(),
+            CloudLocation::UsGovernment { auth_token, .. } => auth_token.clone(),
+            CloudLocation::Sovereign { auth_token, .. } => auth_token.clone(),
             CloudLocation::Emulator { .. } => {
                 AuthorizationToken::primary_from_base64(EMULATOR_ACCOUNT_KEY).unwrap()
             }
             CloudLocation::Custom { auth_token, .. } => auth_token.clone(),
         }
     }
+
+    /// The same location with another auth token. The emulator only accepts its well-known key,
+    /// so it becomes a custom location at the URL of the emulator.
+    fn with_auth_token(self, auth_token: AuthorizationToken) -> Self {
+        match self {
+            CloudLocation::Public { account, .. } => CloudLocation::Public {
+                account,
+                auth_token,
+            },
+            CloudLocation::China { account, .. } => CloudLocation::China {
+                account,
+                auth_token,
+            },
+            CloudLocation::UsGovernment { account, .. } => CloudLocation::UsGovernment {
+                account,
+                auth_token,
+            },
+            CloudLocation::Sovereign { cloud, account, .. } => CloudLocation::Sovereign {
+                cloud,
+                account,
+                auth_token,
+            },
+            location @ CloudLocation::Emulator { .. } => CloudLocation::Custom {
+                uri: location.url(),
+                auth_token,
+            },
+            CloudLocation::Custom { uri, .. } => CloudLocation::Custom { uri, auth_token },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::RetryOptions;
+
+    fn key(key: &str) -> AuthorizationToken {
+        AuthorizationToken::primary_from_base64(&base64::encode(key)).unwrap()
+    }
+
+    #[test]
+    fn the_endpoint_keeps_the_auth_token() {
+        let builder = CosmosClientBuilder::new("account", key("key"))
+            .endpoint("https://account.privatelink.documents.azure.com");
+        assert_eq!(
+            builder.cloud_location.url(),
+            "https://account.privatelink.documents.azure.com"
+        );
+        assert!(builder.cloud_location.auth_token() == key("key"));
+    }
+
+    #[test]
+    fn the_credential_keeps_the_endpoint() {
+        let location = CloudLocation::China {
+            account: "account".to_owned(),
+            auth_token: key("key"),
+        };
+        let builder = CosmosClientBuilder::with_location(location).credential(key("other"));
+        assert_eq!(
+            builder.cloud_location.url(),
+            "https://account.documents.azure.cn"
+        );
+        assert!(builder.cloud_location.auth_token() == key("other"));
+
+        // the emulator only accepts its own key
+        let location = CloudLocation::Emulator {
+            address: "localhost".to_owned(),
+            port: 8081,
+        };
+        let builder = CosmosClientBuilder::with_location(location).credential(key("other"));
+        assert!(matches!(
+            &builder.cloud_location,
+            CloudLocation::Custom { uri, .. } if uri == "https://localhost:8081"
+        ));
+        assert!(builder.cloud_location.auth_token() == key("other"));
+    }
+
+    #[test]
+    fn client_options_replace_the_options() {
+        let options = ClientOptions::default().retry(RetryOptions::none());
+        let client = CosmosClientBuilder::new("account", key("key"))
+            .client_options(options.clone())
+            .build();
+        assert_eq!(format!("{:?}", client.options), format!("{options:?}"));
+    }
 }
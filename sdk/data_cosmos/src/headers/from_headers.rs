@@ -183,3 +183,13 @@ pub(crate) fn date_from_headers(headers: &Headers) -> azure_core::Result<OffsetD
     let s = headers.get_str(&headers::DATE)?;
     parse_rfc1123(s)
 }
+
+/// The output of the `console.log` calls of a stored procedure, when script logging is enabled.
+pub(crate) fn script_log_from_headers(headers: &Headers) -> Option<String> {
+    let encoded = headers.get_optional_str(&HEADER_SCRIPT_LOG_RESULTS)?;
+    // the log is encoded with `encodeURIComponent`, so `&` and `=` are escaped: the whole value
+    // is parsed as a single key
+    url::form_urlencoded::parse(encoded.as_bytes())
+        .map(|(log, _)| log.into_owned())
+        .next()
+}
@@ -69,3 +69,7 @@ pub(crate) const HEADER_MAX_MEDIA_STORAGE_USAGE_MB: HeaderName =
     HeaderName::from_static("x-ms-max-media-storage-usage-mb");
 pub(crate) const HEADER_MEDIA_STORAGE_USAGE_MB: HeaderName =
     HeaderName::from_static("x-ms-media-storage-usage-mb");
+pub(crate) const HEADER_SCRIPT_ENABLE_LOGGING: HeaderName =
+    HeaderName::from_static("x-ms-documentdb-script-enable-logging");
+pub(crate) const HEADER_SCRIPT_LOG_RESULTS: HeaderName =
+    HeaderName::from_static("x-ms-documentdb-script-log-results");
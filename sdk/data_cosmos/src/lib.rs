@@ -94,6 +94,8 @@ extern crate serde;
 #[macro_use]
 extern crate azure_core;
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod clients;
 mod operations;
 pub mod prelude;
@@ -103,12 +105,17 @@ mod authorization_policy;
 mod consistency_level;
 mod cosmos_entity;
 mod headers;
+mod metrics_policy;
+mod request_charge_policy;
 mod resource_quota;
 
 pub(crate) use authorization_policy::AuthorizationPolicy;
+pub(crate) use metrics_policy::MetricsPolicy;
+pub(crate) use request_charge_policy::RequestChargePolicy;
 
 pub use consistency_level::ConsistencyLevel;
 pub use cosmos_entity::CosmosEntity;
+pub use metrics_policy::REQUEST_CHARGE_METRIC;
 pub use resource_quota::ResourceQuota;
 
 type ReadonlyString = std::borrow::Cow<'static, str>;
@@ -0,0 +1,198 @@
+use crate::headers::HEADER_REQUEST_CHARGE;
+use crate::resources::ResourceType;
+use azure_core::{Context, Metric, MetricsRecorder, Policy, PolicyResult, Request};
+use std::sync::Arc;
+
+/// The name of the metric of the request units consumed by a request.
+pub const REQUEST_CHARGE_METRIC: &str = "cosmos.request_charge";
+
+/// Records the request charge of every attempt of a Cosmos operation, the failed and retried
+/// ones included, as they consume request units too. The duration of the operations is recorded
+/// by the [`MetricsPolicy`](azure_core::MetricsPolicy) of the pipeline.
+///
+/// The metric has the `az.namespace`, `db.cosmosdb.operation` (e.g. `GET Documents`) and
+/// `http.status_code` attributes and, when the operation targets them, the `db.name`,
+/// `db.cosmosdb.container` and `cosmos.region` attributes. The region is only known when the
+/// client talks to a regional endpoint, e.g. `https://myaccount-westus.documents.azure.com`.
+#[derive(Debug, Clone)]
+pub(crate) struct MetricsPolicy {
+    account: Option<String>,
+    recorder: Arc<dyn MetricsRecorder>,
+}
+
+impl MetricsPolicy {
+    pub(crate) fn new(account: Option<String>, recorder: Arc<dyn MetricsRecorder>) -> Self {
+        Self { account, recorder }
+    }
+
+    fn attributes(&self, ctx: &Context, request: &Request) -> Vec<(&'static str, String)> {
+        let mut operation = request.method().to_string();
+        if let Some(resource_type) = ctx.get::<ResourceType>() {
+            operation = format!("{operation} {resource_type:?}");
+        }
+        let mut attributes = vec![
+            ("az.namespace", "Microsoft.DocumentDB".to_owned()),
+            ("db.cosmosdb.operation", operation),
+        ];
+
+        let mut segments = request.url().path_segments().into_iter().flatten();
+        while let Some(segment) = segments.next() {
+            match (segment, segments.next()) {
+                ("dbs", Some(database)) => attributes.push(("db.name", database.to_owned())),
+                ("colls", Some(collection)) => {
+                    attributes.push(("db.cosmosdb.container", collection.to_owned()));
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(region) = self.region(request) {
+            attributes.push(("cosmos.region", region));
+        }
+        attributes
+    }
+
+    /// The region of a regional endpoint, e.g. `westus` for `myaccount-westus.documents.azure.com`.
+    fn region(&self, request: &Request) -> Option<String> {
+        let account = self.account.as_deref()?;
+        let label = request.url().host_str()?.split('.').next()?;
+        label
+            .strip_prefix(account)?
+            .strip_prefix('-')
+            .filter(|region| !region.is_empty())
+            .map(ToOwned::to_owned)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Policy for MetricsPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let result = next[0].send(ctx, request, &next[1..]).await;
+
+        // the policy runs for each attempt, before the retry policy turns the unsuccessful
+        // responses into errors
+        if let Ok(response) = &result {
+            let charge = response
+                .headers()
+                .get_optional_as(&HEADER_REQUEST_CHARGE)
+                .ok()
+                .flatten();
+            if let Some(charge) = charge {
+                let mut attributes = self.attributes(ctx, request);
+                attributes.push(("http.status_code", u16::from(response.status()).to_string()));
+                self.recorder.record(Metric {
+                    name: REQUEST_CHARGE_METRIC,
+                    value: charge,
+                    unit: "RU",
+                    attributes,
+                });
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::headers::Headers;
+    use azure_core::{Method, Response, StatusCode};
+    use std::sync::Mutex;
+
+    #[derive(Debug, Default)]
+    struct Recorded(Mutex<Vec<Metric>>);
+
+    impl MetricsRecorder for Recorded {
+        fn record(&self, metric: Metric) {
+            self.0.lock().unwrap().push(metric);
+        }
+    }
+
+    #[derive(Debug)]
+    struct ThrottledPolicy;
+
+    #[async_trait::async_trait]
+    impl Policy for ThrottledPolicy {
+        async fn send(
+            &self,
+            _ctx: &Context,
+            _request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            let mut headers = Headers::new();
+            headers.insert(HEADER_REQUEST_CHARGE, "1.5");
+            let body = futures::stream::iter(Vec::<azure_core::Result<bytes::Bytes>>::new());
+            Ok(Response::new(
+                StatusCode::TooManyRequests,
+                headers,
+                Box::pin(body),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn records_the_charge_of_unsuccessful_attempts() {
+        let recorder = Arc::new(Recorded::default());
+        let policy = MetricsPolicy::new(Some("myaccount".to_owned()), recorder.clone());
+        let mut request = Request::new(
+            "https://myaccount.documents.azure.com/dbs/mydb"
+                .parse()
+                .unwrap(),
+            Method::Get,
+        );
+        let next: Vec<Arc<dyn Policy>> = vec![Arc::new(ThrottledPolicy)];
+        policy
+            .send(&Context::new(), &mut request, &next)
+            .await
+            .unwrap();
+
+        let metrics = recorder.0.lock().unwrap();
+        assert_eq!(metrics.len(), 1);
+        assert_eq!(metrics[0].name, REQUEST_CHARGE_METRIC);
+        assert_eq!(metrics[0].value, 1.5);
+        assert!(metrics[0]
+            .attributes
+            .contains(&("http.status_code", "429".to_owned())));
+    }
+
+    #[derive(Debug)]
+    struct NoRecorder;
+
+    impl MetricsRecorder for NoRecorder {
+        fn record(&self, _metric: Metric) {}
+    }
+
+    #[test]
+    fn attributes_from_request() {
+        let policy = MetricsPolicy::new(Some("myaccount".to_owned()), Arc::new(NoRecorder));
+        let request = Request::new(
+            "https://myaccount-westus.documents.azure.com/dbs/mydb/colls/mycoll/docs/mydoc"
+                .parse()
+                .unwrap(),
+            Method::Get,
+        );
+        let mut ctx = Context::new();
+        ctx.insert(ResourceType::Documents);
+
+        let attributes = policy.attributes(&ctx, &request);
+        assert!(attributes.contains(&("db.cosmosdb.operation", "GET Documents".to_owned())));
+        assert!(attributes.contains(&("db.name", "mydb".to_owned())));
+        assert!(attributes.contains(&("db.cosmosdb.container", "mycoll".to_owned())));
+        assert!(attributes.contains(&("cosmos.region", "westus".to_owned())));
+
+        let request = Request::new(
+            "https://myaccount.documents.azure.com/dbs".parse().unwrap(),
+            Method::Get,
+        );
+        let attributes = policy.attributes(&Context::new(), &request);
+        assert!(!attributes.iter().any(|(key, _)| *key == "cosmos.region"));
+        assert!(!attributes.iter().any(|(key, _)| *key == "db.name"));
+    }
+}
@@ -1,6 +1,6 @@
 use crate::headers::from_headers::*;
 use crate::prelude::*;
-use crate::resources::collection::{IndexingPolicy, PartitionKey};
+use crate::resources::collection::{IndexingPolicy, PartitionKey, TimeToLive};
 use azure_core::headers::{etag_from_headers, session_token_from_headers};
 use azure_core::Response as HttpResponse;
 use time::OffsetDateTime;
@@ -12,7 +12,8 @@ operation! {
     partition_key: PartitionKey,
     ?consistency_level: ConsistencyLevel,
     ?indexing_policy: IndexingPolicy,
-    ?offer: Offer
+    ?offer: Offer,
+    ?default_ttl: TimeToLive
 }
 
 impl CreateCollectionBuilder {
@@ -32,12 +33,15 @@ impl CreateCollectionBuilder {
                 pub indexing_policy: &'a Option<IndexingPolicy>,
                 #[serde(rename = "partitionKey")]
                 pub partition_key: &'a PartitionKey,
+                #[serde(rename = "defaultTtl", skip_serializing_if = "Option::is_none")]
+                pub default_ttl: Option<TimeToLive>,
             }
 
             let collection = CreateCollectionBody {
                 id: &self.collection_name,
                 indexing_policy: &self.indexing_policy,
                 partition_key: &self.partition_key,
+                default_ttl: self.default_ttl,
             };
 
             request.set_body(serde_json::to_vec(&collection)?);
@@ -76,7 +80,7 @@ impl CreateCollectionResponse {
         let body = body.collect().await?;
 
         Ok(Self {
-            collection: serde_json::from_slice(&body)?,
+            collection: azure_core::from_json(&body)?,
             charge: request_charge_from_headers(&headers)?,
             activity_id: activity_id_from_headers(&headers)?,
             etag: etag_from_headers(&headers)?,
@@ -64,7 +64,7 @@ impl CreateDatabaseResponse {
         let body = body.collect().await?;
 
         Ok(Self {
-            database: serde_json::from_slice(&body)?,
+            database: azure_core::from_json(&body)?,
             charge: request_charge_from_headers(&headers)?,
             etag: etag_from_headers(&headers)?,
             session_token: session_token_from_headers(&headers)?,
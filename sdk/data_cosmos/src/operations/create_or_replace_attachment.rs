@@ -96,7 +96,7 @@ impl CreateOrReplaceAttachmentResponse {
         let (_status_code, headers, body) = response.deconstruct();
         let body = body.collect().await?;
 
-        let attachment: Attachment = serde_json::from_slice(&body)?;
+        let attachment: Attachment = azure_core::from_json(&body)?;
 
         Ok(Self {
             attachment,
@@ -6,18 +6,20 @@ use azure_core::headers;
 use azure_core::headers::{
     date_from_headers, etag_from_headers, session_token_from_headers, HeaderValue,
 };
-use azure_core::Method;
 use azure_core::Response as HttpResponse;
 use azure_core::SessionToken;
 use azure_core::{content_type, prelude::*};
-use bytes::Bytes;
+use azure_core::{Body, Method};
 use time::OffsetDateTime;
 
 operation! {
+    /// Uploads media along with the attachment.
+    ///
+    /// The body can be a stream, so that large media are not buffered in memory.
     CreateOrReplaceSlugAttachment,
     client: AttachmentClient,
     is_create: bool,
-    body: Bytes,
+    body: Body,
     ?if_match_condition: IfMatchCondition,
     ?consistency_level: ConsistencyLevel,
     ?content_type: String
@@ -106,7 +108,7 @@ impl CreateOrReplaceSlugAttachmentResponse {
         let (_status_code, headers, body) = response.deconstruct();
         let body = body.collect().await?;
 
-        let attachment: Attachment = serde_json::from_slice(&body)?;
+        let attachment: Attachment = azure_core::from_json(&body)?;
 
         Ok(Self {
             attachment,
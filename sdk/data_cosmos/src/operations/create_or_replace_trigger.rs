@@ -98,7 +98,7 @@ impl CreateOrReplaceTriggerResponse {
         let body = body.collect().await?;
 
         Ok(Self {
-            trigger: serde_json::from_slice(&body)?,
+            trigger: azure_core::from_json(&body)?,
             server: server_from_headers(&headers)?,
             last_state_change: last_state_change_from_headers(&headers)?,
             etag: etag_from_headers(&headers)?,
@@ -88,7 +88,7 @@ impl CreateOrReplaceUserDefinedFunctionResponse {
         let body = body.collect().await?;
 
         Ok(Self {
-            user_defined_function: serde_json::from_slice(&body)?,
+            user_defined_function: azure_core::from_json(&body)?,
             server: server_from_headers(&headers)?,
             last_state_change: last_state_change_from_headers(&headers)?,
             etag: etag_from_headers(&headers)?,
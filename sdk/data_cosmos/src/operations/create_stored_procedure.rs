@@ -71,7 +71,7 @@ impl CreateStoredProcedureResponse {
         let body = body.collect().await?;
 
         Ok(Self {
-            stored_procedure: serde_json::from_slice(&body)?,
+            stored_procedure: azure_core::from_json(&body)?,
             charge: request_charge_from_headers(&headers)?,
             activity_id: activity_id_from_headers(&headers)?,
             etag: etag_from_headers(&headers)?,
@@ -0,0 +1,55 @@
+use crate::headers::from_headers::*;
+use crate::prelude::*;
+
+use azure_core::headers::session_token_from_headers;
+use azure_core::Response as HttpResponse;
+
+operation! {
+    DeleteConflict,
+    client: ConflictClient,
+    ?if_match_condition: IfMatchCondition,
+    ?consistency_level: ConsistencyLevel
+}
+
+impl DeleteConflictBuilder {
+    pub fn into_future(self) -> DeleteConflict {
+        Box::pin(async move {
+            let mut request = self.client.conflict_request(azure_core::Method::Delete);
+
+            request.insert_headers(&self.if_match_condition);
+            if let Some(cl) = &self.consistency_level {
+                request.insert_headers(cl);
+            }
+
+            let response = self
+                .client
+                .pipeline()
+                .send(
+                    self.context.clone().insert(ResourceType::Conflicts),
+                    &mut request,
+                )
+                .await?;
+
+            DeleteConflictResponse::try_from(response).await
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeleteConflictResponse {
+    pub charge: f64,
+    pub activity_id: uuid::Uuid,
+    pub session_token: String,
+}
+
+impl DeleteConflictResponse {
+    pub async fn try_from(response: HttpResponse) -> azure_core::Result<Self> {
+        let (_status_code, headers, _pinned_stream) = response.deconstruct();
+
+        Ok(Self {
+            charge: request_charge_from_headers(&headers)?,
+            activity_id: activity_id_from_headers(&headers)?,
+            session_token: session_token_from_headers(&headers)?,
+        })
+    }
+}
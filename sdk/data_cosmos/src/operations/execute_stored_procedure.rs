@@ -1,6 +1,7 @@
 use std::marker::PhantomData;
 
 use crate::headers::from_headers::*;
+use crate::headers::HEADER_SCRIPT_ENABLE_LOGGING;
 use crate::prelude::*;
 use crate::resources::stored_procedure::Parameters;
 
@@ -18,6 +19,7 @@ pub struct ExecuteStoredProcedureBuilder<T> {
     consistency_level: Option<ConsistencyLevel>,
     allow_tentative_writes: TentativeWritesAllowance,
     partition_key: Option<String>,
+    script_logging: bool,
     context: Context,
     _stored_proc: PhantomData<T>,
 }
@@ -32,6 +34,7 @@ impl<T> ExecuteStoredProcedureBuilder<T> {
             consistency_level: None,
             allow_tentative_writes: TentativeWritesAllowance::Deny,
             partition_key: None,
+            script_logging: false,
             context: Context::new(),
             _stored_proc: PhantomData,
         }
@@ -44,6 +47,15 @@ impl<T> ExecuteStoredProcedureBuilder<T> {
         context: Context,
     }
 
+    /// Return the output of the `console.log` calls of the stored procedure in
+    /// [`ExecuteStoredProcedureResponse::script_log`].
+    pub fn script_logging(self, script_logging: bool) -> Self {
+        Self {
+            script_logging,
+            ..self
+        }
+    }
+
     pub fn partition_key<PK: serde::Serialize>(self, pk: &PK) -> azure_core::Result<Self> {
         Ok(Self {
             partition_key: Some(crate::cosmos_entity::serialize_partition_key(pk)?),
@@ -67,6 +79,9 @@ impl<T: DeserializeOwned + Send> ExecuteStoredProcedureBuilder<T> {
                 request.insert_headers(cl);
             }
             request.insert_headers(&self.allow_tentative_writes);
+            if self.script_logging {
+                request.insert_header(HEADER_SCRIPT_ENABLE_LOGGING, "true");
+            }
 
             let body = if let Some(parameters) = self.parameters.as_ref() {
                 Bytes::from(parameters.to_json())
@@ -107,6 +122,9 @@ where
     T: DeserializeOwned,
 {
     pub payload: T,
+    /// The output of the `console.log` calls of the stored procedure, if
+    /// [`script_logging`](ExecuteStoredProcedureBuilder::script_logging) was enabled.
+    pub script_log: Option<String>,
 
     pub last_state_change: OffsetDateTime,
     pub schema_version: String,
@@ -138,7 +156,8 @@ where
         let body = body.collect().await?;
 
         Ok(Self {
-            payload: serde_json::from_slice(&body)?,
+            payload: azure_core::from_json(&body)?,
+            script_log: script_log_from_headers(&headers),
             last_state_change: last_state_change_from_headers(&headers)?,
             schema_version: schema_version_from_headers(&headers)?,
             alt_content_path: alt_content_path_from_headers(&headers)?,
@@ -79,7 +79,7 @@ impl GetAttachmentResponse {
         let body = body.collect().await?;
 
         Ok(Self {
-            attachment: serde_json::from_slice(&body)?,
+            attachment: azure_core::from_json(&body)?,
             content_type: content_type_from_headers(&headers)?,
             content_location: content_location_from_headers(&headers)?,
             last_change: last_state_change_from_headers(&headers)?,
@@ -0,0 +1,78 @@
+use crate::headers::from_headers::*;
+use crate::prelude::*;
+use azure_core::headers::{
+    self, content_type_from_headers, date_from_headers, etag_from_headers_optional,
+};
+use azure_core::{prelude::*, Response as HttpResponse, ResponseBody};
+use time::OffsetDateTime;
+
+operation! {
+    /// Downloads the media of an attachment, as a stream.
+    ///
+    /// The media link is the `media` of the [`Attachment`](crate::resources::Attachment), for
+    /// example `/media/Sl8fAN4EjgsBAAAAAAAAAHk8FmU=`. Large media can be downloaded in parts by
+    /// setting the range of bytes to download.
+    GetAttachmentMedia,
+    client: AttachmentClient,
+    media: String,
+    ?range: Range
+}
+
+impl GetAttachmentMediaBuilder {
+    pub fn into_future(self) -> GetAttachmentMedia {
+        Box::pin(async move {
+            let mut request = self
+                .client
+                .media_request(&self.media, azure_core::Method::Get);
+
+            if let Some(range) = &self.range {
+                request.insert_header(headers::RANGE, range.to_string());
+            }
+
+            let response = self
+                .client
+                .pipeline()
+                .send(
+                    self.context.clone().insert(ResourceType::Media),
+                    &mut request,
+                )
+                .await?;
+
+            GetAttachmentMediaResponse::try_from(response)
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct GetAttachmentMediaResponse {
+    pub content_type: String,
+    pub content_length: u64,
+    /// The range of bytes returned, when a range was requested.
+    pub content_range: Option<ContentRange>,
+    pub etag: Option<String>,
+    pub activity_id: Option<uuid::Uuid>,
+    pub date: OffsetDateTime,
+    pub data: ResponseBody,
+}
+
+impl GetAttachmentMediaResponse {
+    pub fn try_from(response: HttpResponse) -> azure_core::Result<Self> {
+        let headers = response.headers();
+        let content_type = content_type_from_headers(headers)?;
+        let content_length = headers.get_as(&headers::CONTENT_LENGTH)?;
+        let content_range = headers.get_optional_as(&headers::CONTENT_RANGE)?;
+        let etag = etag_from_headers_optional(headers)?;
+        let activity_id = activity_id_from_headers(headers).ok();
+        let date = date_from_headers(headers)?;
+
+        Ok(Self {
+            content_type,
+            content_length,
+            content_range,
+            etag,
+            activity_id,
+            date,
+            data: response.into_body(),
+        })
+    }
+}
@@ -71,7 +71,7 @@ impl GetCollectionResponse {
         let body = body.collect().await?;
 
         Ok(Self {
-            collection: serde_json::from_slice(&body)?,
+            collection: azure_core::from_json(&body)?,
             last_state_change: last_state_change_from_headers(&headers)?,
             etag: etag_from_headers(&headers)?,
             collection_partition_index: collection_partition_index_from_headers(&headers)?,
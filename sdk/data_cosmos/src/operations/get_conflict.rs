@@ -0,0 +1,59 @@
+use crate::headers::from_headers::*;
+use crate::prelude::*;
+
+use azure_core::headers::{etag_from_headers, session_token_from_headers};
+use azure_core::Response as HttpResponse;
+
+operation! {
+    GetConflict,
+    client: ConflictClient,
+    ?consistency_level: ConsistencyLevel
+}
+
+impl GetConflictBuilder {
+    pub fn into_future(self) -> GetConflict {
+        Box::pin(async move {
+            let mut request = self.client.conflict_request(azure_core::Method::Get);
+
+            if let Some(cl) = &self.consistency_level {
+                request.insert_headers(cl);
+            }
+            request.set_body(azure_core::EMPTY_BODY);
+
+            let response = self
+                .client
+                .pipeline()
+                .send(
+                    self.context.clone().insert(ResourceType::Conflicts),
+                    &mut request,
+                )
+                .await?;
+
+            GetConflictResponse::try_from(response).await
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GetConflictResponse {
+    pub conflict: Conflict,
+    pub charge: f64,
+    pub activity_id: uuid::Uuid,
+    pub etag: String,
+    pub session_token: String,
+}
+
+impl GetConflictResponse {
+    pub async fn try_from(response: HttpResponse) -> azure_core::Result<Self> {
+        let (_status_code, headers, body) = response.deconstruct();
+        let body = body.collect().await?;
+
+        Ok(Self {
+            conflict: azure_core::from_json(&body)?,
+            charge: request_charge_from_headers(&headers)?,
+            activity_id: activity_id_from_headers(&headers)?,
+            etag: etag_from_headers(&headers)?,
+            session_token: session_token_from_headers(&headers)?,
+        })
+    }
+}
@@ -154,7 +154,7 @@ where
 {
     async fn try_from(headers: &Headers, body: bytes::Bytes) -> azure_core::Result<Self> {
         Ok(Self {
-            document: serde_json::from_slice(&body)?,
+            document: azure_core::from_json(&body)?,
 
             content_location: content_location_from_headers(headers)?,
             last_state_change: last_state_change_from_headers(headers)?,
@@ -84,7 +84,7 @@ impl GetPartitionKeyRangesResponse {
             pub partition_key_ranges: Vec<PartitionKeyRange>,
         }
 
-        let r: Response = serde_json::from_slice(&body)?;
+        let r: Response = azure_core::from_json(&body)?;
 
         Ok(Self {
             rid: r.rid,
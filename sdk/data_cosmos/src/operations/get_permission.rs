@@ -1,8 +1,12 @@
-use crate::{prelude::*, resources::permission::PermissionResponse as GetPermissionResponse};
+use crate::{
+    prelude::*,
+    resources::permission::{ExpirySeconds, PermissionResponse as GetPermissionResponse},
+};
 
 operation! {
     GetPermission,
     client: PermissionClient,
+    ?expiry_seconds: ExpirySeconds,
     ?consistency_level: ConsistencyLevel
 }
 
@@ -14,6 +18,8 @@ impl GetPermissionBuilder {
             if let Some(cl) = &self.consistency_level {
                 request.insert_headers(cl);
             }
+            // the validity of the resource token returned
+            request.insert_headers(&self.expiry_seconds);
 
             let response = self
                 .client
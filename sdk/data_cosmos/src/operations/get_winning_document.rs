@@ -0,0 +1,262 @@
+use std::marker::PhantomData;
+
+use crate::prelude::*;
+use crate::resources::Document;
+use azure_core::prelude::*;
+use futures::StreamExt;
+use serde::de::DeserializeOwned;
+
+#[derive(Debug, Clone)]
+pub struct GetWinningDocumentBuilder<T> {
+    client: ConflictClient,
+    consistency_level: Option<ConsistencyLevel>,
+    context: Context,
+    _document: PhantomData<T>,
+}
+
+impl<T> GetWinningDocumentBuilder<T> {
+    pub(crate) fn new(client: ConflictClient) -> Self {
+        Self {
+            client,
+            consistency_level: None,
+            context: Context::new(),
+            _document: PhantomData,
+        }
+    }
+
+    setters! {
+        consistency_level: ConsistencyLevel => Some(consistency_level),
+        context: Context => context,
+    }
+}
+
+impl<T: DeserializeOwned + Send + Sync> GetWinningDocumentBuilder<T> {
+    /// Convert into a future
+    ///
+    /// The conflict is read first, then the winning version of its resource is queried within
+    /// the partition of the conflict client.
+    pub fn into_future(self) -> GetWinningDocument<T> {
+        Box::pin(async move {
+            let mut get_conflict = self.client.get_conflict().context(self.context.clone());
+            if let Some(cl) = &self.consistency_level {
+                get_conflict = get_conflict.consistency_level(cl.clone());
+            }
+            let conflict = get_conflict.into_future().await?.conflict;
+
+            let mut query = self
+                .client
+                .collection_client()
+                .query_documents(conflict.winning_document_query())
+                .partition_key_serialized(self.client.partition_key_serialized().to_owned())
+                .context(self.context.clone());
+            if let Some(cl) = &self.consistency_level {
+                query = query.consistency_level(cl.clone());
+            }
+
+            let mut pages = query.into_stream::<Document<T>>();
+            let mut charge = 0.0;
+            let mut document = None;
+            while let Some(page) = pages.next().await {
+                let page = page?;
+                charge += page.charge;
+                if let Some((winning, _)) = page.results.into_iter().next() {
+                    document = Some(winning);
+                    break;
+                }
+            }
+
+            Ok(GetWinningDocumentResponse {
+                conflict,
+                document,
+                charge,
+            })
+        })
+    }
+}
+
+azure_core::future!(GetWinningDocument<T>);
+
+#[cfg(feature = "into_future")]
+impl<T: DeserializeOwned + Send + Sync> std::future::IntoFuture for GetWinningDocumentBuilder<T> {
+    type IntoFuture = GetWinningDocument<T>;
+    type Output = <GetWinningDocument<T> as std::future::Future>::Output;
+    fn into_future(self) -> Self::IntoFuture {
+        Self::into_future(self)
+    }
+}
+
+/// The winning version of a conflicting resource.
+#[derive(Debug, Clone)]
+pub struct GetWinningDocumentResponse<T> {
+    pub conflict: Conflict,
+    /// The winning version, or `None` if it has been deleted since the conflict
+    pub document: Option<Document<T>>,
+    /// The charge of querying the winning version
+    pub charge: f64,
+}
+
+impl<T> GetWinningDocumentResponse<T> {
+    /// The etag of the winning version.
+    ///
+    /// Use it in an [`IfMatchCondition`] to replace the winning version only if it is still
+    /// the current one when resolving the conflict.
+    pub fn etag(&self) -> Option<&str> {
+        self.document
+            .as_ref()
+            .map(|document| document.document_attributes.etag())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::headers::*;
+    use azure_core::{headers::Headers, mock::MockTransportCannedPolicy, StatusCode};
+    use azure_core::{Body, Method, TransportOptions};
+    use serde::Deserialize;
+    use std::sync::Arc;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Item {
+        id: String,
+        value: u32,
+    }
+
+    fn conflict_client(transport: Arc<MockTransportCannedPolicy>) -> ConflictClient {
+        let key = AuthorizationToken::primary_from_base64(&base64::encode("key")).unwrap();
+        CosmosClientBuilder::new("account", key)
+            .transport(TransportOptions::new_custom_policy(transport))
+            .build()
+            .database_client("database")
+            .collection_client("collection")
+            .conflict_client("conflict", &"pk")
+            .unwrap()
+    }
+
+    fn conflict_headers() -> Headers {
+        let mut headers = Headers::new();
+        headers.insert(HEADER_REQUEST_CHARGE, "1");
+        headers.insert(
+            azure_core::headers::ACTIVITY_ID,
+            "00000000-0000-0000-0000-000000000000",
+        );
+        headers.insert(azure_core::headers::ETAG, "\"conflict-etag\"");
+        headers.insert(HEADER_SESSION_TOKEN, "0:-1#1");
+        headers
+    }
+
+    fn query_headers(item_count: &str) -> Headers {
+        let mut headers = conflict_headers();
+        headers.insert(HEADER_REQUEST_CHARGE, "2.5");
+        headers.insert(
+            HEADER_LAST_STATE_CHANGE_UTC,
+            "Thu, 15 Oct 2026 10:00:00.000 GMT",
+        );
+        headers.insert(HEADER_RESOURCE_QUOTA, "documentSize=10240;");
+        headers.insert(HEADER_RESOURCE_USAGE, "documentSize=0;");
+        headers.insert(HEADER_LSN, "1");
+        headers.insert(azure_core::headers::ITEM_COUNT, item_count.to_owned());
+        headers.insert(HEADER_SCHEMA_VERSION, "1.14");
+        headers.insert(HEADER_ALT_CONTENT_PATH, "dbs/database/colls/collection");
+        headers.insert(HEADER_CONTENT_PATH, "rid");
+        headers.insert(HEADER_ROLE, "1");
+        headers.insert(HEADER_GLOBAL_COMMITTED_LSN, "1");
+        headers.insert(HEADER_NUMBER_OF_READ_REGIONS, "0");
+        headers.insert(HEADER_TRANSPORT_REQUEST_ID, "1");
+        headers.insert(HEADER_COSMOS_LLSN, "1");
+        headers.insert(HEADER_SERVICE_VERSION, "version=2.14.0.0");
+        headers.insert(HEADER_GATEWAY_VERSION, "2.0.0");
+        headers.insert(azure_core::headers::DATE, "Thu, 15 Oct 2026 10:00:00 GMT");
+        headers
+    }
+
+    const CONFLICT: &str = r#"{
+        "id": "conflict",
+        "_rid": "conflict-rid",
+        "_ts": 1600000000,
+        "_self": "dbs/database/colls/collection/conflicts/conflict/",
+        "_etag": "\"conflict-etag\"",
+        "resourceType": "document",
+        "operationType": "replace",
+        "resourceId": "doc-rid",
+        "content": "{\"id\":\"doc\",\"value\":1}"
+    }"#;
+
+    #[tokio::test]
+    async fn queries_the_winning_document_of_the_conflict() {
+        let documents = r#"{"_rid":"collection-rid","_count":1,"Documents":[
+            {"id":"doc","value":2,"_rid":"doc-rid","_ts":1600000001,"_self":"self","_etag":"\"winning-etag\"","_attachments":"attachments/"}
+        ]}"#;
+        let transport = Arc::new(
+            MockTransportCannedPolicy::new()
+                .reply(StatusCode::Ok, conflict_headers(), CONFLICT)
+                .reply(StatusCode::Ok, query_headers("1"), documents),
+        );
+
+        let response = conflict_client(transport.clone())
+            .get_winning_document::<Item>()
+            .into_future()
+            .await
+            .unwrap();
+
+        assert_eq!(response.etag(), Some("\"winning-etag\""));
+        assert_eq!(
+            response.document.unwrap().document,
+            Item {
+                id: "doc".into(),
+                value: 2
+            }
+        );
+        let losing: Item = response.conflict.losing_document().unwrap();
+        assert_eq!(losing.value, 1);
+        assert_eq!(response.charge, 2.5);
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method(), &Method::Get);
+        assert_eq!(
+            requests[0].url().path(),
+            "/dbs/database/colls/collection/conflicts/conflict"
+        );
+        assert_eq!(requests[1].method(), &Method::Post);
+        assert_eq!(
+            requests[1].url().path(),
+            "/dbs/database/colls/collection/docs"
+        );
+        assert_eq!(
+            requests[1]
+                .headers()
+                .get_optional_str(&HEADER_DOCUMENTDB_PARTITIONKEY),
+            Some(r#"["pk"]"#)
+        );
+        match requests[1].body() {
+            Body::Bytes(bytes) => {
+                let query: serde_json::Value = serde_json::from_slice(bytes).unwrap();
+                assert_eq!(query["parameters"][0]["value"], "doc-rid");
+            }
+            Body::SeekableStream(_) => panic!("the query is not streamed"),
+        }
+    }
+
+    #[tokio::test]
+    async fn has_no_winning_document_once_deleted() {
+        let transport = Arc::new(
+            MockTransportCannedPolicy::new()
+                .reply(StatusCode::Ok, conflict_headers(), CONFLICT)
+                .reply(
+                    StatusCode::Ok,
+                    query_headers("0"),
+                    r#"{"_rid":"collection-rid","_count":0,"Documents":[]}"#,
+                ),
+        );
+
+        let response = conflict_client(transport)
+            .get_winning_document::<Item>()
+            .into_future()
+            .await
+            .unwrap();
+
+        assert!(response.document.is_none());
+        assert_eq!(response.etag(), None);
+    }
+}
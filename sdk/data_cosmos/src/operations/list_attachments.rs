@@ -109,7 +109,7 @@ impl ListAttachmentsResponse {
         let (_status_code, headers, body) = response.deconstruct();
         let body = body.collect().await?;
 
-        let json: JsonListAttachmentResponse = serde_json::from_slice(&body)?;
+        let json: JsonListAttachmentResponse = azure_core::from_json(&body)?;
 
         Ok(Self {
             rid: json.rid,
@@ -1,4 +1,5 @@
 use crate::headers::from_headers::*;
+use crate::operations::CumulativeCharge;
 use crate::prelude::*;
 use crate::resources::Collection;
 use crate::ResourceQuota;
@@ -18,9 +19,12 @@ operation! {
 
 impl ListCollectionsBuilder {
     pub fn into_stream(self) -> ListCollections {
+        let cumulative_charge = CumulativeCharge::default();
         let make_request = move |continuation: Option<Continuation>| {
             let this = self.clone();
-            let ctx = self.context.clone();
+            let mut ctx = self.context.clone();
+            ctx.insert(cumulative_charge.clone());
+            let cumulative_charge = cumulative_charge.clone();
             async move {
                 let mut request = this.client.collections_request(azure_core::Method::Get);
                 if let Some(cl) = &this.consistency_level {
@@ -35,7 +39,9 @@ impl ListCollectionsBuilder {
                     .cosmos_client()
                     .send(request, ctx.clone(), ResourceType::Collections)
                     .await?;
-                ListCollectionsResponse::try_from(response).await
+                let mut response = ListCollectionsResponse::try_from(response).await?;
+                response.cumulative_charge = cumulative_charge.total();
+                Ok(response)
             }
         };
 
@@ -57,6 +63,8 @@ pub struct ListCollectionsResponse {
     pub alt_content_path: String,
     pub content_path: String,
     pub charge: f64,
+    /// The request charge of this page and of all the previous ones, retried attempts included.
+    pub cumulative_charge: f64,
     pub service_version: String,
     pub activity_id: uuid::Uuid,
     pub session_token: String,
@@ -78,7 +86,7 @@ impl ListCollectionsResponse {
             pub count: u32,
         }
 
-        let response: Response = serde_json::from_slice(&*body)?;
+        let response: Response = azure_core::from_json(&*body)?;
 
         Ok(Self {
             rid: response._rid,
@@ -91,6 +99,7 @@ impl ListCollectionsResponse {
             alt_content_path: alt_content_path_from_headers(&headers)?,
             content_path: content_path_from_headers(&headers)?,
             charge: request_charge_from_headers(&headers)?,
+            cumulative_charge: 0.0,
             service_version: service_version_from_headers(&headers)?,
             activity_id: activity_id_from_headers(&headers)?,
             session_token: session_token_from_headers(&headers)?,
@@ -106,3 +115,13 @@ impl Continuable for ListCollectionsResponse {
         self.continuation_token.clone()
     }
 }
+
+impl IntoIterator for ListCollectionsResponse {
+    type Item = Collection;
+
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.collections.into_iter()
+    }
+}
@@ -1,4 +1,5 @@
 use crate::headers::from_headers::*;
+use crate::operations::CumulativeCharge;
 use crate::prelude::*;
 use crate::resources::Database;
 use crate::ResourceQuota;
@@ -17,9 +18,12 @@ operation! {
 
 impl ListDatabasesBuilder {
     pub fn into_stream(self) -> ListDatabases {
+        let cumulative_charge = CumulativeCharge::default();
         let make_request = move |continuation: Option<Continuation>| {
             let this = self.clone();
-            let ctx = self.context.clone();
+            let mut ctx = self.context.clone();
+            ctx.insert(cumulative_charge.clone());
+            let cumulative_charge = cumulative_charge.clone();
             async move {
                 let mut request = this.client.request("dbs", azure_core::Method::Get);
                 if let Some(cl) = &this.consistency_level {
@@ -34,7 +38,9 @@ impl ListDatabasesBuilder {
                     .send(ctx.clone().insert(ResourceType::Databases), &mut request)
                     .await?;
 
-                ListDatabasesResponse::try_from(response).await
+                let mut response = ListDatabasesResponse::try_from(response).await?;
+                response.cumulative_charge = cumulative_charge.total();
+                Ok(response)
             }
         };
 
@@ -51,6 +57,8 @@ pub struct ListDatabasesResponse {
     pub count: u32,
     pub activity_id: uuid::Uuid,
     pub charge: f64,
+    /// The request charge of this page and of all the previous ones, retried attempts included.
+    pub cumulative_charge: f64,
     pub session_token: String,
     pub last_state_change: OffsetDateTime,
     pub resource_quota: Vec<ResourceQuota>,
@@ -76,13 +84,14 @@ impl ListDatabasesResponse {
             pub count: u32,
         }
 
-        let response: Response = serde_json::from_slice(&body)?;
+        let response: Response = azure_core::from_json(&body)?;
 
         Ok(Self {
             rid: response.rid,
             databases: response.databases,
             count: response.count,
             charge: request_charge_from_headers(&headers)?,
+            cumulative_charge: 0.0,
             activity_id: activity_id_from_headers(&headers)?,
             session_token: session_token_from_headers(&headers)?,
             last_state_change: last_state_change_from_headers(&headers)?,
@@ -112,3 +121,85 @@ impl IntoIterator for ListDatabasesResponse {
         self.databases.into_iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::headers::*;
+    use azure_core::headers::{Headers, CONTINUATION};
+    use azure_core::{mock::MockTransportCannedPolicy, StatusCode, TransportOptions};
+    use azure_core::{FixedRetryOptions, RetryOptions};
+    use futures::StreamExt;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    const NO_DATABASES: &str = r#"{"_rid":"","Databases":[],"_count":0}"#;
+
+    fn cosmos_client(transport: Arc<MockTransportCannedPolicy>) -> CosmosClient {
+        let key = AuthorizationToken::primary_from_base64(&base64::encode("key")).unwrap();
+        CosmosClientBuilder::new("account", key)
+            .transport(TransportOptions::new_custom_policy(transport))
+            .retry(RetryOptions::fixed(
+                FixedRetryOptions::default().delay(Duration::from_millis(1)),
+            ))
+            .build()
+    }
+
+    fn headers(charge: &str) -> Headers {
+        let mut headers = Headers::new();
+        headers.insert(HEADER_REQUEST_CHARGE, charge.to_owned());
+        headers.insert(
+            azure_core::headers::ACTIVITY_ID,
+            "00000000-0000-0000-0000-000000000000",
+        );
+        headers.insert(HEADER_SESSION_TOKEN, "0:-1#1");
+        headers.insert(
+            HEADER_LAST_STATE_CHANGE_UTC,
+            "Thu, 15 Oct 2026 10:00:00.000 GMT",
+        );
+        headers.insert(HEADER_RESOURCE_QUOTA, "databases=100;");
+        headers.insert(HEADER_RESOURCE_USAGE, "databases=0;");
+        headers.insert(HEADER_SCHEMA_VERSION, "1.14");
+        headers.insert(HEADER_SERVICE_VERSION, "version=2.14.0.0");
+        headers.insert(HEADER_GATEWAY_VERSION, "2.0.0");
+        headers
+    }
+
+    #[tokio::test]
+    async fn sums_the_charge_of_the_pages() {
+        let mut first_page = headers("1.5");
+        first_page.insert(CONTINUATION, "next");
+        let transport = Arc::new(
+            MockTransportCannedPolicy::new()
+                .reply(StatusCode::Ok, first_page, NO_DATABASES)
+                .reply(StatusCode::Ok, headers("2"), NO_DATABASES),
+        );
+        let mut pages = cosmos_client(transport.clone())
+            .list_databases()
+            .into_stream();
+
+        let page = pages.next().await.unwrap().unwrap();
+        assert_eq!((page.charge, page.cumulative_charge), (1.5, 1.5));
+        let page = pages.next().await.unwrap().unwrap();
+        assert_eq!((page.charge, page.cumulative_charge), (2.0, 3.5));
+        assert!(pages.next().await.is_none());
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn sums_the_charge_of_the_retried_attempts() {
+        let transport = Arc::new(
+            MockTransportCannedPolicy::new()
+                .reply(StatusCode::TooManyRequests, headers("0.5"), "")
+                .reply(StatusCode::Ok, headers("1.5"), NO_DATABASES),
+        );
+        let mut pages = cosmos_client(transport.clone())
+            .list_databases()
+            .into_stream();
+
+        let page = pages.next().await.unwrap().unwrap();
+        assert_eq!((page.charge, page.cumulative_charge), (1.5, 2.0));
+        assert!(pages.next().await.is_none());
+        assert_eq!(transport.requests().len(), 2);
+    }
+}
@@ -126,8 +126,8 @@ where
         // 2- Deserialize the result a type T. The extra fields will be ignored.
         // 3- Zip 1 and 2 in the resulting structure.
         // There is a lot of data movement here, let's hope the compiler is smarter than me :)
-        let document_attributes: ListDocumentsResponseAttributes = serde_json::from_slice(&body)?;
-        let entries: ListDocumentsResponseEntities<T> = serde_json::from_slice(&body)?;
+        let document_attributes: ListDocumentsResponseAttributes = azure_core::from_json(&body)?;
+        let entries: ListDocumentsResponseEntities<T> = azure_core::from_json(&body)?;
 
         let documents = document_attributes
             .documents
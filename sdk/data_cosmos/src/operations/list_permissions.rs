@@ -1,5 +1,6 @@
 use crate::headers::from_headers::*;
 use crate::prelude::*;
+use crate::resources::permission::ExpirySeconds;
 use crate::resources::Permission;
 use crate::resources::ResourceType;
 
@@ -12,6 +13,7 @@ operation! {
     ListPermissions,
     client: UserClient,
     ?max_item_count: MaxItemCount,
+    ?expiry_seconds: ExpirySeconds,
     ?consistency_level: ConsistencyLevel
 }
 
@@ -34,6 +36,8 @@ impl ListPermissionsBuilder {
                     request.insert_headers(cl);
                 }
                 request.insert_headers(&this.max_item_count.unwrap_or_default());
+                // the validity of the resource tokens returned
+                request.insert_headers(&this.expiry_seconds);
 
                 request.insert_headers(&continuation);
 
@@ -76,7 +80,7 @@ impl ListPermissionsResponse {
             _count: u32,
         }
 
-        let response: Response = serde_json::from_slice(&body)?;
+        let response: Response = azure_core::from_json(&body)?;
         let permissions = response.permissions;
 
         Ok(Self {
@@ -84,7 +84,7 @@ impl ListStoredProceduresResponse {
         }
 
         Ok(Self {
-            stored_procedures: serde_json::from_slice::<Response>(&body)?.stored_procedures,
+            stored_procedures: azure_core::from_json::<_, Response>(&body)?.stored_procedures,
             charge: request_charge_from_headers(&headers)?,
             activity_id: activity_id_from_headers(&headers)?,
             session_token: session_token_from_headers(&headers)?,
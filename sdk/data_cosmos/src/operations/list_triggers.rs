@@ -100,7 +100,7 @@ impl ListTriggersResponse {
             #[allow(unused)]
             count: u32,
         }
-        let response: Response = serde_json::from_slice(&body)?;
+        let response: Response = azure_core::from_json(&body)?;
 
         Ok(Self {
             rid: response.rid.to_owned(),
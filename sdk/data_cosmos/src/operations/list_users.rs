@@ -78,7 +78,7 @@ impl ListUsersResponse {
             pub count: u32,
         }
 
-        let response: Response = serde_json::from_slice(&body)?;
+        let response: Response = azure_core::from_json(&body)?;
 
         Ok(Self {
             users: response.users,
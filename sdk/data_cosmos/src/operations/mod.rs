@@ -14,6 +14,7 @@ mod create_stored_procedure;
 mod create_user;
 mod delete_attachment;
 mod delete_collection;
+mod delete_conflict;
 mod delete_database;
 mod delete_document;
 mod delete_permission;
@@ -23,14 +24,18 @@ mod delete_user;
 mod delete_user_defined_function;
 mod execute_stored_procedure;
 mod get_attachment;
+mod get_attachment_media;
 mod get_collection;
+mod get_conflict;
 mod get_database;
 mod get_document;
 mod get_partition_key_ranges;
 mod get_permission;
 mod get_user;
+mod get_winning_document;
 mod list_attachments;
 mod list_collections;
+mod list_conflicts;
 mod list_databases;
 mod list_documents;
 mod list_permissions;
@@ -39,6 +44,8 @@ mod list_triggers;
 mod list_user_defined_functions;
 mod list_users;
 mod query_documents;
+mod read_database_account;
+mod read_many_documents;
 mod replace_collection;
 mod replace_document;
 mod replace_permission;
@@ -57,6 +64,7 @@ pub use create_stored_procedure::*;
 pub use create_user::*;
 pub use delete_attachment::*;
 pub use delete_collection::*;
+pub use delete_conflict::*;
 pub use delete_database::*;
 pub use delete_document::*;
 pub use delete_permission::*;
@@ -66,14 +74,18 @@ pub use delete_user::*;
 pub use delete_user_defined_function::*;
 pub use execute_stored_procedure::*;
 pub use get_attachment::*;
+pub use get_attachment_media::*;
 pub use get_collection::*;
+pub use get_conflict::*;
 pub use get_database::*;
 pub use get_document::*;
 pub use get_partition_key_ranges::*;
 pub use get_permission::*;
 pub use get_user::*;
+pub use get_winning_document::*;
 pub use list_attachments::*;
 pub use list_collections::*;
+pub use list_conflicts::*;
 pub use list_databases::*;
 pub use list_documents::*;
 pub use list_permissions::*;
@@ -82,8 +94,30 @@ pub use list_triggers::*;
 pub use list_user_defined_functions::*;
 pub use list_users::*;
 pub use query_documents::*;
+pub use read_database_account::*;
+pub use read_many_documents::*;
 pub use replace_collection::*;
 pub use replace_document::*;
 pub use replace_permission::*;
 pub use replace_stored_procedure::*;
 pub use replace_user::*;
+
+/// The request charge of all the pages of a `Pageable` returned so far, the charge of their
+/// retried attempts included.
+///
+/// Inserted in the context of the requests of the pages, the charge of every attempt is added by
+/// the `RequestChargePolicy` of the pipeline.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CumulativeCharge(std::sync::Arc<std::sync::Mutex<f64>>);
+
+impl CumulativeCharge {
+    /// Adds the charge of an attempt.
+    pub(crate) fn add(&self, charge: f64) {
+        *self.0.lock().unwrap() += charge;
+    }
+
+    /// The charge of all the attempts so far.
+    pub(crate) fn total(&self) -> f64 {
+        *self.0.lock().unwrap()
+    }
+}
@@ -29,6 +29,7 @@ operation! {
     ?consistency_level: ConsistencyLevel,
     ?parallelize_cross_partition_query: ParallelizeCrossPartition,
     ?query_cross_partition: QueryCrossPartition,
+    ?continuation: Continuation,
     #[skip]
     partition_key_serialized: String
 }
@@ -41,6 +42,18 @@ impl QueryDocumentsBuilder {
         })
     }
 
+    pub(crate) fn partition_key_serialized(self, partition_key_serialized: String) -> Self {
+        Self {
+            partition_key_serialized: Some(partition_key_serialized),
+            ..self
+        }
+    }
+
+    /// The stream of the pages of documents.
+    ///
+    /// The query resumes after the documents of a previous query when a
+    /// [`continuation`](Self::continuation) is set, e.g. the persisted
+    /// [`ContinuationToken`](azure_core::ContinuationToken) of its last page.
     pub fn into_stream<T>(self) -> QueryDocuments<T>
     where
         T: DeserializeOwned + Send + Sync,
@@ -48,6 +61,7 @@ impl QueryDocumentsBuilder {
         let make_request = move |continuation: Option<Continuation>| {
             let this = self.clone();
             let ctx = self.context.clone();
+            let continuation = continuation.or_else(|| self.continuation.clone());
             async move {
                 let mut request = this.client.cosmos_client().request(
                     &format!(
@@ -149,7 +163,7 @@ where
         let (_status_code, headers, body) = response.deconstruct();
         let body = body.collect().await?;
 
-        let inner: Value = serde_json::from_slice(&body)?;
+        let inner: Value = azure_core::from_json(&body)?;
         let results = if let Value::Array(documents) = &inner["Documents"] {
             documents
                 .iter()
@@ -194,7 +208,7 @@ where
             gateway_version: gateway_version_from_headers(&headers)?,
             continuation_token: continuation_token_from_headers_optional(&headers)?,
             date: date_from_headers(&headers)?,
-            query_response_meta: serde_json::from_slice(&body)?,
+            query_response_meta: azure_core::from_json(&body)?,
         })
     }
 }
@@ -213,3 +227,44 @@ impl<T> Continuable for QueryDocumentsResponse<T> {
         self.continuation_token.clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::{mock::MockTransportCannedPolicy, TransportOptions};
+    use futures::StreamExt;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn resumes_from_the_continuation() {
+        // no response is needed, only the request is checked
+        let transport = Arc::new(MockTransportCannedPolicy::new());
+        let key = AuthorizationToken::primary_from_base64(&base64::encode("key")).unwrap();
+        let collection = CosmosClientBuilder::new("account", key)
+            .transport(TransportOptions::new_custom_policy(transport.clone()))
+            .build()
+            .database_client("database")
+            .collection_client("collection");
+
+        let page = collection
+            .query_documents("SELECT * FROM c")
+            .continuation(Continuation::new("+RID:~abc"))
+            .into_stream::<Value>()
+            .next()
+            .await;
+        assert!(matches!(page, Some(Err(_))));
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(
+            requests[0].url().path(),
+            "/dbs/database/colls/collection/docs"
+        );
+        assert_eq!(
+            requests[0]
+                .headers()
+                .get_optional_str(&headers::CONTINUATION),
+            Some("+RID:~abc")
+        );
+    }
+}
@@ -0,0 +1,42 @@
+use crate::headers::from_headers::*;
+use crate::prelude::*;
+use crate::resources::DatabaseAccount;
+
+use azure_core::Response as HttpResponse;
+
+operation! {
+    ReadDatabaseAccount,
+    client: CosmosClient,
+}
+
+impl ReadDatabaseAccountBuilder {
+    pub fn into_future(self) -> ReadDatabaseAccount {
+        Box::pin(async move {
+            let request = self.client.request("", azure_core::Method::Get);
+
+            let response = self
+                .client
+                .send(request, self.context.clone(), ResourceType::DatabaseAccount)
+                .await?;
+            ReadDatabaseAccountResponse::try_from(response).await
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ReadDatabaseAccountResponse {
+    pub database_account: DatabaseAccount,
+    pub activity_id: uuid::Uuid,
+}
+
+impl ReadDatabaseAccountResponse {
+    pub async fn try_from(response: HttpResponse) -> azure_core::Result<Self> {
+        let (_status_code, headers, body) = response.deconstruct();
+        let body = body.collect().await?;
+
+        Ok(Self {
+            database_account: azure_core::from_json(&body)?,
+            activity_id: activity_id_from_headers(&headers)?,
+        })
+    }
+}
@@ -0,0 +1,241 @@
+use crate::prelude::*;
+use crate::resources::Document;
+use azure_core::{error::ErrorKind, prelude::*, StatusCode};
+use futures::StreamExt;
+use serde::de::DeserializeOwned;
+
+/// Reads many documents of a collection with point reads.
+///
+/// The reads are sent in parallel, at most [`max_concurrency`](Self::max_concurrency) at a time.
+#[derive(Debug, Clone)]
+pub struct ReadManyDocumentsBuilder {
+    documents: Vec<azure_core::Result<DocumentClient>>,
+    max_concurrency: usize,
+    consistency_level: Option<ConsistencyLevel>,
+    context: Context,
+}
+
+impl ReadManyDocumentsBuilder {
+    /// The default number of reads sent at the same time.
+    pub const DEFAULT_MAX_CONCURRENCY: usize = 10;
+
+    pub(crate) fn new(documents: Vec<azure_core::Result<DocumentClient>>) -> Self {
+        Self {
+            documents,
+            max_concurrency: Self::DEFAULT_MAX_CONCURRENCY,
+            consistency_level: None,
+            context: Context::new(),
+        }
+    }
+
+    setters! {
+        max_concurrency: usize => max_concurrency.max(1),
+        consistency_level: ConsistencyLevel => Some(consistency_level),
+        context: Context => context,
+    }
+
+    /// Convert into a future
+    ///
+    /// The future fails only if no read could be sent: each item has its own result in the
+    /// response.
+    pub fn into_future<T>(self) -> ReadManyDocuments<T>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        Box::pin(async move {
+            let consistency_level = self.consistency_level;
+            let context = self.context;
+            let reads = self.documents.into_iter().map(|document| {
+                let consistency_level = consistency_level.clone();
+                let context = context.clone();
+                async move {
+                    let mut builder = document?.get_document::<T>().context(context);
+                    if let Some(consistency_level) = consistency_level {
+                        builder = builder.consistency_level(consistency_level);
+                    }
+                    match builder.into_future().await {
+                        Ok(GetDocumentResponse::Found(found)) => {
+                            Ok((found.charge, Some(found.document)))
+                        }
+                        Ok(GetDocumentResponse::NotFound(not_found)) => {
+                            Ok((not_found.charge, None))
+                        }
+                        Err(error) if is_not_found(&error) => Ok((0.0, None)),
+                        Err(error) => Err(error),
+                    }
+                }
+            });
+            let reads: Vec<_> = futures::stream::iter(reads)
+                .buffered(self.max_concurrency)
+                .collect()
+                .await;
+
+            let mut charge = 0.0;
+            let results = reads
+                .into_iter()
+                .map(|read| {
+                    read.map(|(read_charge, document)| {
+                        charge += read_charge;
+                        document
+                    })
+                })
+                .collect();
+            Ok(ReadManyDocumentsResponse { results, charge })
+        })
+    }
+}
+
+fn is_not_found(error: &azure_core::Error) -> bool {
+    matches!(
+        error.kind(),
+        ErrorKind::HttpResponse {
+            status: StatusCode::NotFound,
+            ..
+        }
+    )
+}
+
+azure_core::future!(ReadManyDocuments<T>);
+
+#[derive(Debug)]
+pub struct ReadManyDocumentsResponse<T> {
+    /// The documents, in the order they were requested, `None` for the ones which don't exist.
+    pub results: Vec<azure_core::Result<Option<Document<T>>>>,
+    /// The request units consumed by all the reads.
+    pub charge: f64,
+}
+
+impl<T> ReadManyDocumentsResponse<T> {
+    /// An iterator over the documents which were found.
+    pub fn documents(&self) -> impl Iterator<Item = &T> {
+        self.results
+            .iter()
+            .filter_map(|result| result.as_ref().ok().and_then(Option::as_ref))
+            .map(|document| &document.document)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::headers::*;
+    use azure_core::{headers::Headers, mock::MockTransportCannedPolicy, TransportOptions};
+    use serde::Deserialize;
+    use std::sync::Arc;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Item {
+        id: String,
+    }
+
+    fn collection_client(transport: Arc<MockTransportCannedPolicy>) -> CollectionClient {
+        let key = AuthorizationToken::primary_from_base64(&base64::encode("key")).unwrap();
+        CosmosClientBuilder::new("account", key)
+            .transport(TransportOptions::new_custom_policy(transport))
+            .build()
+            .database_client("database")
+            .collection_client("collection")
+    }
+
+    fn found_headers(charge: &str) -> Headers {
+        let mut headers = Headers::new();
+        headers.insert(
+            HEADER_LAST_STATE_CHANGE_UTC,
+            "Thu, 15 Oct 2026 10:00:00.000 GMT",
+        );
+        headers.insert(azure_core::headers::ETAG, "\"etag\"");
+        headers.insert(HEADER_RESOURCE_QUOTA, "documentSize=10240;");
+        headers.insert(HEADER_RESOURCE_USAGE, "documentSize=0;");
+        headers.insert(HEADER_LSN, "1");
+        headers.insert(HEADER_SCHEMA_VERSION, "1.14");
+        headers.insert(HEADER_ALT_CONTENT_PATH, "dbs/database/colls/collection");
+        headers.insert(HEADER_CONTENT_PATH, "rid");
+        headers.insert(HEADER_ROLE, "1");
+        headers.insert(HEADER_GLOBAL_COMMITTED_LSN, "1");
+        headers.insert(HEADER_NUMBER_OF_READ_REGIONS, "0");
+        headers.insert(HEADER_ITEM_LSN, "1");
+        headers.insert(HEADER_TRANSPORT_REQUEST_ID, "1");
+        headers.insert(HEADER_COSMOS_LLSN, "1");
+        headers.insert(HEADER_COSMOS_ITEM_LLSN, "1");
+        headers.insert(HEADER_SESSION_TOKEN, "0:-1#1");
+        headers.insert(HEADER_REQUEST_CHARGE, charge.to_owned());
+        headers.insert(HEADER_SERVICE_VERSION, "version=2.14.0.0");
+        headers.insert(
+            azure_core::headers::ACTIVITY_ID,
+            "00000000-0000-0000-0000-000000000000",
+        );
+        headers.insert(HEADER_GATEWAY_VERSION, "2.0.0");
+        headers.insert(azure_core::headers::DATE, "Thu, 15 Oct 2026 10:00:00 GMT");
+        headers
+    }
+
+    #[tokio::test]
+    async fn reads_each_document_with_its_partition_key() {
+        let document = r#"{"id":"a","_rid":"rid","_ts":1,"_self":"self","_etag":"etag","_attachments":"attachments/"}"#;
+        let transport = Arc::new(
+            MockTransportCannedPolicy::new()
+                .reply(StatusCode::Ok, found_headers("1.5"), document)
+                .reply(StatusCode::NotFound, Headers::new(), ""),
+        );
+        let collection = collection_client(transport.clone());
+
+        let response = collection
+            .read_many(&[("a", "pk-a"), ("missing", "pk-b")])
+            .max_concurrency(1)
+            .consistency_level(ConsistencyLevel::Eventual)
+            .into_future::<Item>()
+            .await
+            .unwrap();
+
+        assert_eq!(response.charge, 1.5);
+        assert_eq!(response.results.len(), 2);
+        assert_eq!(
+            response.documents().collect::<Vec<_>>(),
+            vec![&Item { id: "a".into() }]
+        );
+        assert!(matches!(response.results[1], Ok(None)));
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        for (request, (id, partition_key)) in requests
+            .iter()
+            .zip([("a", r#"["pk-a"]"#), ("missing", r#"["pk-b"]"#)])
+        {
+            assert_eq!(request.method(), &azure_core::Method::Get);
+            assert_eq!(
+                request.url().path(),
+                format!("/dbs/database/colls/collection/docs/{id}")
+            );
+            assert_eq!(
+                request
+                    .headers()
+                    .get_optional_str(&HEADER_DOCUMENTDB_PARTITIONKEY),
+                Some(partition_key)
+            );
+            assert_eq!(
+                request
+                    .headers()
+                    .get_optional_str(&HEADER_CONSISTENCY_LEVEL),
+                Some("Eventual")
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn keeps_the_error_of_each_read() {
+        // no reply is canned: every read fails
+        let transport = Arc::new(MockTransportCannedPolicy::new());
+        let collection = collection_client(transport.clone());
+
+        let response = collection
+            .read_many(&[("a", "pk-a"), ("b", "pk-b")])
+            .into_future::<Item>()
+            .await
+            .unwrap();
+
+        assert_eq!(response.charge, 0.0);
+        assert!(response.results.iter().all(Result::is_err));
+        assert_eq!(response.documents().count(), 0);
+        assert_eq!(transport.requests().len(), 2);
+    }
+}
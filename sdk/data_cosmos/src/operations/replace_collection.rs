@@ -1,6 +1,6 @@
 use crate::headers::from_headers::*;
 use crate::prelude::*;
-use crate::resources::collection::{IndexingPolicy, PartitionKey};
+use crate::resources::collection::{IndexingPolicy, PartitionKey, TimeToLive};
 use azure_core::headers::{
     content_type_from_headers, etag_from_headers, session_token_from_headers,
 };
@@ -12,7 +12,8 @@ operation! {
     client: CollectionClient,
     partition_key: PartitionKey,
     ?indexing_policy: IndexingPolicy,
-    ?consistency_level: ConsistencyLevel
+    ?consistency_level: ConsistencyLevel,
+    ?default_ttl: TimeToLive
 }
 
 impl ReplaceCollectionBuilder {
@@ -28,6 +29,7 @@ impl ReplaceCollectionBuilder {
                 id: self.client.collection_name(),
                 indexing_policy: &self.indexing_policy,
                 partition_key: &self.partition_key,
+                default_ttl: self.default_ttl,
             };
 
             request.set_body(serde_json::to_vec(&collection)?);
@@ -53,6 +55,8 @@ struct ReplaceCollectionBody<'a> {
     pub indexing_policy: &'a Option<IndexingPolicy>,
     #[serde(rename = "partitionKey")]
     pub partition_key: &'a PartitionKey,
+    #[serde(rename = "defaultTtl", skip_serializing_if = "Option::is_none")]
+    pub default_ttl: Option<TimeToLive>,
 }
 
 #[derive(Debug, Clone)]
@@ -90,7 +94,7 @@ impl ReplaceCollectionResponse {
         let (_status_code, headers, body) = response.deconstruct();
         let body = body.collect().await?;
         Ok(Self {
-            collection: serde_json::from_slice(&body)?,
+            collection: azure_core::from_json(&body)?,
             last_state_change: last_state_change_from_headers(&headers)?,
             etag: etag_from_headers(&headers)?,
             collection_partition_index: collection_partition_index_from_headers(&headers)?,
@@ -0,0 +1,37 @@
+use crate::headers::HEADER_REQUEST_CHARGE;
+use crate::operations::CumulativeCharge;
+use azure_core::{Context, Policy, PolicyResult, Request};
+use std::sync::Arc;
+
+/// Adds the request charge of every attempt of a request to the [`CumulativeCharge`] of its
+/// context, if any, so that the charge of the failed and retried attempts is accounted for too.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RequestChargePolicy;
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Policy for RequestChargePolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let result = next[0].send(ctx, request, &next[1..]).await;
+
+        // the policy runs for each attempt, before the retry policy turns the unsuccessful
+        // responses into errors
+        if let (Some(cumulative_charge), Ok(response)) = (ctx.get::<CumulativeCharge>(), &result) {
+            let charge = response
+                .headers()
+                .get_optional_as(&HEADER_REQUEST_CHARGE)
+                .ok()
+                .flatten();
+            if let Some(charge) = charge {
+                cumulative_charge.add(charge);
+            }
+        }
+
+        result
+    }
+}
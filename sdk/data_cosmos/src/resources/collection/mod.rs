@@ -45,6 +45,13 @@ pub struct Collection {
     /// the addressable path of the conflicts resource
     #[serde(rename = "_conflicts")]
     pub conflicts: String,
+    /// The default time to live of the documents. `None` if expiration is disabled.
+    #[serde(
+        rename = "defaultTtl",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub default_ttl: Option<TimeToLive>,
 }
 
 impl Resource for Collection {
@@ -170,3 +177,84 @@ pub struct IndexingPolicy {
     /// Array containing document paths to be excluded from indexing
     pub excluded_paths: Vec<ExcludedPath>,
 }
+
+/// A time to live, either the default of a collection or the one of a single document.
+///
+/// On a collection, `Never` enables expiration without expiring documents by default: only
+/// documents with their own time to live expire. On a document, `Never` prevents it from
+/// expiring even if the collection has a default time to live.
+///
+/// You can learn more about time to live [here](https://docs.microsoft.com/azure/cosmos-db/sql/time-to-live).
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialOrd, Ord, PartialEq, Eq, Hash)]
+#[serde(try_from = "i64", into = "i64")]
+pub enum TimeToLive {
+    /// Never expire (serialized as `-1`)
+    Never,
+    /// Expire after the given number of seconds since the last modification
+    Seconds(u32),
+}
+
+impl TimeToLive {
+    /// The time to live as a `Duration`. `None` if it never expires.
+    pub fn duration(&self) -> Option<std::time::Duration> {
+        match self {
+            Self::Never => None,
+            Self::Seconds(seconds) => Some(std::time::Duration::from_secs(u64::from(*seconds))),
+        }
+    }
+}
+
+impl From<TimeToLive> for i64 {
+    fn from(ttl: TimeToLive) -> Self {
+        match ttl {
+            TimeToLive::Never => -1,
+            TimeToLive::Seconds(seconds) => i64::from(seconds),
+        }
+    }
+}
+
+impl TryFrom<i64> for TimeToLive {
+    type Error = azure_core::error::Error;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        match value {
+            -1 => Ok(Self::Never),
+            1.. => u32::try_from(value).map(Self::Seconds).map_err(|e| {
+                azure_core::error::Error::full(
+                    azure_core::error::ErrorKind::DataConversion,
+                    e,
+                    format!("time to live out of range: {value}"),
+                )
+            }),
+            _ => Err(azure_core::error::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                || {
+                    format!("invalid time to live: {value}. Expected -1 or a positive number of seconds")
+                },
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_to_live_serialization() {
+        assert_eq!(serde_json::to_string(&TimeToLive::Never).unwrap(), "-1");
+        assert_eq!(
+            serde_json::to_string(&TimeToLive::Seconds(3600)).unwrap(),
+            "3600"
+        );
+        assert_eq!(
+            serde_json::from_str::<TimeToLive>("-1").unwrap(),
+            TimeToLive::Never
+        );
+        assert_eq!(
+            serde_json::from_str::<TimeToLive>("60").unwrap(),
+            TimeToLive::Seconds(60)
+        );
+        assert!(serde_json::from_str::<TimeToLive>("0").is_err());
+    }
+}
@@ -0,0 +1,111 @@
+//! Utilities for interacting with [`Conflict`]s.
+
+#![allow(missing_docs)]
+
+use super::document::{Param, Query};
+use super::Resource;
+use azure_core::error::{ErrorKind, ResultExt};
+use serde::de::DeserializeOwned;
+
+/// A conflict between concurrent writes in an account with multiple write regions.
+///
+/// When the conflict resolution policy of a collection is custom and no stored procedure
+/// resolves it, the losing write is recorded in the collection's conflict feed. The winning
+/// write is the version of the resource currently stored in the collection.
+///
+/// You can learn more about conflicts [here](https://docs.microsoft.com/azure/cosmos-db/sql/how-to-manage-conflicts).
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Conflict {
+    /// The conflict id
+    pub id: String,
+    /// The resource id
+    #[serde(rename = "_rid")]
+    pub rid: String,
+    /// The last updated timestamp
+    #[serde(rename = "_ts")]
+    pub ts: u64,
+    /// The resource's uri
+    pub _self: String,
+    /// The resource's etag used for concurrency control
+    #[serde(rename = "_etag")]
+    pub etag: String,
+    /// The type of the conflicting resource, for example `document`
+    #[serde(rename = "resourceType")]
+    pub resource_type: String,
+    /// The operation of the losing write
+    #[serde(rename = "operationType")]
+    pub operation_type: ConflictOperationType,
+    /// The resource id of the conflicting resource
+    #[serde(rename = "resourceId")]
+    pub resource_id: String,
+    /// The losing version of the resource, serialized as JSON
+    #[serde(default)]
+    pub content: String,
+}
+
+impl Conflict {
+    /// Deserialize the losing version of the resource.
+    ///
+    /// The losing version of a deleted resource may not be available.
+    pub fn losing_document<T: DeserializeOwned>(&self) -> azure_core::Result<T> {
+        serde_json::from_str(&self.content).with_context(ErrorKind::DataConversion, || {
+            format!("failed to deserialize the content of conflict {}", self.id)
+        })
+    }
+
+    /// A query returning the winning version of the resource, if it still exists.
+    ///
+    /// The query must be executed across partitions with
+    /// [`CollectionClient::query_documents`](crate::prelude::CollectionClient::query_documents)
+    /// unless the partition key of the resource is known.
+    pub fn winning_document_query(&self) -> Query {
+        Query::with_params(
+            "SELECT * FROM c WHERE c._rid = @rid".to_owned(),
+            vec![Param::new("@rid".to_owned(), self.resource_id.clone())],
+        )
+    }
+}
+
+impl Resource for Conflict {
+    fn uri(&self) -> &str {
+        &self._self
+    }
+}
+
+create_enum!(
+    ConflictOperationType,
+    (Create, "create"),
+    (Replace, "replace"),
+    (Delete, "delete")
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_conflict() {
+        let conflict: Conflict = serde_json::from_str(
+            r#"{
+                "id": "K1hKAJrGQgMBAAAAAAAAAA==",
+                "_rid": "K1hKAJrGQgMBAAAAAAAAAA==",
+                "_ts": 1600000000,
+                "_self": "dbs/K1hKAA==/colls/K1hKAJrGQgM=/conflicts/K1hKAJrGQgMBAAAAAAAAAA==/",
+                "_etag": "\"00000000-0000-0000-0000-000000000000\"",
+                "resourceType": "document",
+                "operationType": "replace",
+                "resourceId": "K1hKAJrGQgMBAAAAAAAAAA==",
+                "content": "{\"id\":\"doc\",\"value\":42}"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(conflict.operation_type, ConflictOperationType::Replace);
+
+        let losing: serde_json::Value = conflict.losing_document().unwrap();
+        assert_eq!(losing["value"], 42);
+        assert_eq!(
+            *conflict.winning_document_query().params()[0].value(),
+            "K1hKAJrGQgMBAAAAAAAAAA=="
+        );
+    }
+}
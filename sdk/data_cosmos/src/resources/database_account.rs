@@ -0,0 +1,162 @@
+//! Utilities for interacting with the [`DatabaseAccount`].
+
+/// The metadata of a Cosmos account: its regions, its default consistency and its replication
+/// settings.
+///
+/// You can learn more about the database account [here](https://docs.microsoft.com/rest/api/cosmos-db/get-database-account).
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseAccount {
+    /// The account name
+    pub id: String,
+    /// The resource id of the account
+    #[serde(rename = "_rid")]
+    pub rid: String,
+    /// The path to the databases resource
+    #[serde(rename = "_dbs")]
+    pub dbs: String,
+    /// The regions accepting writes, the first one being the write region of single-write
+    /// accounts
+    #[serde(default)]
+    pub writable_locations: Vec<DatabaseAccountLocation>,
+    /// The regions accepting reads, in order of failover priority
+    #[serde(default)]
+    pub readable_locations: Vec<DatabaseAccountLocation>,
+    /// Whether all the regions accept writes
+    #[serde(default)]
+    pub enable_multiple_write_locations: bool,
+    /// The consistency policy used by the requests without a consistency level
+    pub user_consistency_policy: ConsistencyPolicy,
+    /// The replication settings of the account
+    pub user_replication_policy: Option<ReplicationPolicy>,
+    /// The replication settings used by the service for its own resources
+    pub system_replication_policy: Option<ReplicationPolicy>,
+    /// The weights of the reads sent to the primary and secondary replicas
+    pub read_policy: Option<ReadPolicy>,
+    /// The configuration of the query engine, a JSON document describing the query features
+    /// supported by the account, e.g. the maximum number of joins
+    pub query_engine_configuration: Option<String>,
+}
+
+impl DatabaseAccount {
+    /// The endpoint of the write region, or the first write region of multi-write accounts.
+    pub fn write_endpoint(&self) -> Option<&str> {
+        self.writable_locations
+            .first()
+            .map(|location| location.database_account_endpoint.as_str())
+    }
+}
+
+/// A region of the account.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseAccountLocation {
+    /// The name of the region, e.g. `West US`
+    pub name: String,
+    /// The endpoint of the account in this region
+    pub database_account_endpoint: String,
+}
+
+/// The default consistency of the account.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsistencyPolicy {
+    /// The default consistency level
+    pub default_consistency_level: DefaultConsistencyLevel,
+    /// The maximum number of stale requests of the bounded staleness consistency
+    #[serde(rename = "maxStalenessPrefix")]
+    pub max_staleness_prefix: Option<u64>,
+    /// The maximum lag in seconds of the bounded staleness consistency
+    #[serde(rename = "maxIntervalInSeconds")]
+    pub max_interval_in_seconds: Option<u64>,
+}
+
+/// The consistency level configured on the account.
+///
+/// Requests can ask for a weaker consistency with a [`ConsistencyLevel`](crate::ConsistencyLevel).
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DefaultConsistencyLevel {
+    /// A linearizability guarantee
+    Strong,
+    /// Reads lag behind writes by at most a number of versions or a time interval
+    BoundedStaleness,
+    /// Reads honor the consistent-prefix, monotonic reads, monotonic writes, read-your-writes,
+    /// and write-follows-reads guarantees within a session
+    Session,
+    /// Updates that are returned contain some prefix of all the updates, with no gaps
+    ConsistentPrefix,
+    /// No ordering guarantee for reads
+    Eventual,
+}
+
+/// The replication settings of the account.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplicationPolicy {
+    /// Whether the writes are replicated asynchronously
+    #[serde(default)]
+    pub async_replication: bool,
+    /// The minimum number of replicas of a partition
+    pub min_replica_set_size: Option<u32>,
+    /// The maximum number of replicas of a partition
+    #[serde(rename = "maxReplicasetSize")]
+    pub max_replica_set_size: Option<u32>,
+}
+
+/// The weights of the reads sent to the primary and secondary replicas.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadPolicy {
+    /// The weight of the primary replica
+    pub primary_read_coefficient: u32,
+    /// The weight of the secondary replicas
+    pub secondary_read_coefficient: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_database_account() {
+        let json = br#"{
+            "_self": "",
+            "id": "myaccount",
+            "_rid": "myaccount.documents.azure.com",
+            "media": "//media/",
+            "addresses": "//addresses/",
+            "_dbs": "//dbs/",
+            "writableLocations": [
+                {"name": "West US", "databaseAccountEndpoint": "https://myaccount-westus.documents.azure.com:443/"}
+            ],
+            "readableLocations": [
+                {"name": "West US", "databaseAccountEndpoint": "https://myaccount-westus.documents.azure.com:443/"},
+                {"name": "East US", "databaseAccountEndpoint": "https://myaccount-eastus.documents.azure.com:443/"}
+            ],
+            "enableMultipleWriteLocations": false,
+            "userReplicationPolicy": {"asyncReplication": false, "minReplicaSetSize": 3, "maxReplicasetSize": 4},
+            "userConsistencyPolicy": {"defaultConsistencyLevel": "Session"},
+            "systemReplicationPolicy": {"minReplicaSetSize": 3, "maxReplicasetSize": 4},
+            "readPolicy": {"primaryReadCoefficient": 1, "secondaryReadCoefficient": 1},
+            "queryEngineConfiguration": "{\"maxJoinsPerSqlQuery\":5}"
+        }"#;
+        let account: DatabaseAccount = serde_json::from_slice(json).unwrap();
+        assert_eq!(account.id, "myaccount");
+        assert_eq!(
+            account.write_endpoint(),
+            Some("https://myaccount-westus.documents.azure.com:443/")
+        );
+        assert_eq!(account.readable_locations.len(), 2);
+        assert_eq!(account.readable_locations[1].name, "East US");
+        assert_eq!(
+            account.user_consistency_policy.default_consistency_level,
+            DefaultConsistencyLevel::Session
+        );
+        assert_eq!(
+            account
+                .user_replication_policy
+                .and_then(|policy| policy.max_replica_set_size),
+            Some(4)
+        );
+    }
+}
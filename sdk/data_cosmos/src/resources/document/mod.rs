@@ -3,17 +3,22 @@
 mod document_attributes;
 mod indexing_directive;
 mod query;
+mod with_ttl;
 
 pub use document_attributes::DocumentAttributes;
 pub use indexing_directive::IndexingDirective;
 pub use query::{Param, Query};
+pub use with_ttl::WithTtl;
 
+use super::collection::TimeToLive;
 use super::Resource;
 use crate::headers;
 
 use azure_core::headers::{AsHeaders, HeaderName, HeaderValue, Headers};
 use azure_core::Header;
 use serde::de::DeserializeOwned;
+use std::time::Duration;
+use time::OffsetDateTime;
 
 /// User-defined content in JSON format.
 ///
@@ -37,6 +42,40 @@ impl<T> Document<T> {
             document,
         }
     }
+
+    /// Set the time to live of the document, overriding the collection's default
+    pub fn with_ttl(self, ttl: TimeToLive) -> Document<WithTtl<T>> {
+        Document {
+            document_attributes: self.document_attributes,
+            document: WithTtl::new(self.document, ttl),
+        }
+    }
+}
+
+impl<T> Document<WithTtl<T>> {
+    /// The time to live of the document, if it has its own.
+    pub fn ttl(&self) -> Option<TimeToLive> {
+        self.document.ttl
+    }
+
+    /// The time left before the document expires, given the default time to live of its
+    /// collection.
+    ///
+    /// Returns `None` if the document never expires: either expiration is disabled on the
+    /// collection (`collection_default_ttl` is `None`) or the effective time to live is
+    /// [`TimeToLive::Never`]. Returns a zero duration if the document has already expired but
+    /// has not been deleted yet.
+    pub fn remaining_ttl(&self, collection_default_ttl: Option<TimeToLive>) -> Option<Duration> {
+        let ttl = self
+            .document
+            .ttl
+            .unwrap_or(collection_default_ttl?)
+            .duration()?;
+        let last_modified =
+            OffsetDateTime::from_unix_timestamp(self.document_attributes.ts() as i64).ok()?;
+        let remaining = last_modified + ttl - OffsetDateTime::now_utc();
+        Some(remaining.try_into().unwrap_or_default())
+    }
 }
 
 impl<T> std::convert::TryFrom<(&Headers, &[u8])> for Document<T>
@@ -256,3 +295,60 @@ impl Header for PartitionRangeId {
         self.0.clone().into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document(ts: i64, ttl: Option<i64>) -> Document<WithTtl<serde_json::Value>> {
+        let mut json = serde_json::json!({
+            "id": "doc",
+            "_rid": "rid",
+            "_ts": ts,
+            "_self": "self",
+            "_etag": "etag",
+            "_attachments": "attachments/",
+        });
+        if let Some(ttl) = ttl {
+            json["ttl"] = ttl.into();
+        }
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn ttl_is_deserialized() {
+        assert_eq!(document(0, Some(-1)).ttl(), Some(TimeToLive::Never));
+        assert_eq!(document(0, Some(10)).ttl(), Some(TimeToLive::Seconds(10)));
+        assert_eq!(document(0, None).ttl(), None);
+        // the document sees its ttl as well
+        assert_eq!(document(0, Some(10)).document.document["ttl"], 10);
+    }
+
+    #[test]
+    fn remaining_ttl() {
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+
+        // expiration disabled on the collection
+        assert_eq!(document(now, Some(60)).remaining_ttl(None), None);
+        // the document never expires
+        assert_eq!(
+            document(now, Some(-1)).remaining_ttl(Some(TimeToLive::Seconds(60))),
+            None
+        );
+        // the document's ttl overrides the collection default
+        let remaining = document(now, Some(3600))
+            .remaining_ttl(Some(TimeToLive::Seconds(60)))
+            .unwrap();
+        assert!(remaining > Duration::from_secs(3500));
+        // the collection default applies
+        let remaining = document(now, None)
+            .remaining_ttl(Some(TimeToLive::Seconds(60)))
+            .unwrap();
+        assert!(remaining <= Duration::from_secs(60));
+        // already expired
+        assert_eq!(
+            document(now - 120, None).remaining_ttl(Some(TimeToLive::Seconds(60))),
+            Some(Duration::ZERO)
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use crate::resources::collection::TimeToLive;
+use crate::CosmosEntity;
+use serde::de::{DeserializeOwned, Deserializer, Error as _};
+use serde::ser::{Error as _, Serializer};
+use serde::{Deserialize, Serialize};
+
+const TTL: &str = "ttl";
+
+/// A document with its own time to live, overriding the default of its collection.
+///
+/// The time to live is the `ttl` key of the document, so it is merged into the JSON object of
+/// the document when serialized: if the document has a `ttl` field of its own, it is replaced.
+/// When deserialized, the document sees the `ttl` key as well, so that a `ttl` field of its own
+/// keeps working.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn run(collection: azure_data_cosmos::prelude::CollectionClient) -> azure_core::Result<()> {
+/// use azure_data_cosmos::prelude::*;
+///
+/// let document = serde_json::json!({ "id": "session", "user": "alice" });
+/// let document = WithTtl::new(document, TimeToLive::Seconds(3600));
+/// collection
+///     .create_document(document)
+///     .is_upsert(true)
+///     .into_future()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithTtl<T> {
+    /// The document.
+    pub document: T,
+    /// The time to live of the document, or `None` to use the default of its collection.
+    pub ttl: Option<TimeToLive>,
+}
+
+impl<T> WithTtl<T> {
+    /// A document with its own time to live.
+    pub fn new(document: T, ttl: TimeToLive) -> Self {
+        Self {
+            document,
+            ttl: Some(ttl),
+        }
+    }
+}
+
+impl<T> From<T> for WithTtl<T> {
+    fn from(document: T) -> Self {
+        Self {
+            document,
+            ttl: None,
+        }
+    }
+}
+
+impl<T: Serialize> Serialize for WithTtl<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut document = serde_json::to_value(&self.document).map_err(S::Error::custom)?;
+        if let Some(ttl) = self.ttl {
+            document
+                .as_object_mut()
+                .ok_or_else(|| S::Error::custom("a document must be a JSON object"))?
+                .insert(TTL.to_owned(), i64::from(ttl).into());
+        }
+        document.serialize(serializer)
+    }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for WithTtl<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let document = serde_json::Value::deserialize(deserializer)?;
+        let ttl = match document.get(TTL) {
+            Some(ttl) => Some(TimeToLive::deserialize(ttl).map_err(D::Error::custom)?),
+            None => None,
+        };
+        let document = serde_json::from_value(document).map_err(D::Error::custom)?;
+        Ok(Self { document, ttl })
+    }
+}
+
+impl<T: CosmosEntity> CosmosEntity for WithTtl<T> {
+    type Entity = T::Entity;
+
+    fn partition_key(&self) -> Self::Entity {
+        self.document.partition_key()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Session {
+        id: String,
+        #[serde(default)]
+        ttl: Option<i64>,
+    }
+
+    #[test]
+    fn merges_the_ttl_into_the_document() {
+        let document = WithTtl::new(
+            serde_json::json!({ "id": "session" }),
+            TimeToLive::Seconds(60),
+        );
+        assert_eq!(
+            serde_json::to_value(&document).unwrap(),
+            serde_json::json!({ "id": "session", "ttl": 60 })
+        );
+
+        let document = WithTtl::from(serde_json::json!({ "id": "session" }));
+        assert_eq!(
+            serde_json::to_value(&document).unwrap(),
+            serde_json::json!({ "id": "session" })
+        );
+
+        assert!(serde_json::to_value(WithTtl::new(1, TimeToLive::Never)).is_err());
+    }
+
+    #[test]
+    fn round_trips_a_document_with_its_own_ttl() {
+        let document = WithTtl::new(
+            Session {
+                id: "session".to_owned(),
+                ttl: Some(10),
+            },
+            TimeToLive::Never,
+        );
+        let json = serde_json::to_string(&document).unwrap();
+        assert_eq!(json.matches("\"ttl\"").count(), 1);
+
+        let document: WithTtl<Session> = serde_json::from_str(&json).unwrap();
+        assert_eq!(document.ttl, Some(TimeToLive::Never));
+        assert_eq!(
+            document.document,
+            Session {
+                id: "session".to_owned(),
+                ttl: Some(-1),
+            }
+        );
+
+        let document: WithTtl<Session> = serde_json::from_str(r#"{"id":"session"}"#).unwrap();
+        assert_eq!(document.ttl, None);
+        assert_eq!(document.document.ttl, None);
+    }
+}
@@ -3,6 +3,8 @@
 //! You can learn about the Cosmos DB resource model [here](https://docs.microsoft.com/azure/cosmos-db/account-databases-containers-items).
 
 pub mod collection;
+pub mod conflict;
+pub mod database_account;
 pub mod document;
 pub mod permission;
 pub mod stored_procedure;
@@ -18,8 +20,12 @@ pub use attachment::Attachment;
 #[doc(inline)]
 pub use collection::Collection;
 #[doc(inline)]
+pub use conflict::Conflict;
+#[doc(inline)]
 pub use database::Database;
 #[doc(inline)]
+pub use database_account::DatabaseAccount;
+#[doc(inline)]
 pub use document::Document;
 #[doc(inline)]
 pub use permission::Permission;
@@ -62,4 +68,7 @@ pub(crate) enum ResourceType {
     PartitionKeyRanges,
     UserDefinedFunctions,
     Triggers,
+    Conflicts,
+    Media,
+    DatabaseAccount,
 }
@@ -16,14 +16,28 @@ use crate::headers;
 use azure_core::Header;
 
 /// The amount of time before authorization expires
-#[derive(Debug, Clone, Copy)]
+///
+/// The resource tokens of permissions are valid for one hour by default. The validity can be set
+/// when creating, replacing, reading or listing permissions, between
+/// [`MIN`](Self::MIN) and [`MAX`](Self::MAX).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ExpirySeconds(u64);
 
 impl ExpirySeconds {
+    /// The shortest validity of a resource token, 10 minutes.
+    pub const MIN: Self = Self(600);
+    /// The longest validity of a resource token, 5 hours.
+    pub const MAX: Self = Self(18_000);
+
     /// Create an `ExpirySeconds` from a `u64`
     pub fn new(secs: u64) -> Self {
         Self(secs)
     }
+
+    /// The number of seconds.
+    pub fn as_secs(&self) -> u64 {
+        self.0
+    }
 }
 
 impl Header for ExpirySeconds {
@@ -41,3 +55,25 @@ impl From<u64> for ExpirySeconds {
         Self::new(s)
     }
 }
+
+impl From<std::time::Duration> for ExpirySeconds {
+    fn from(duration: std::time::Duration) -> Self {
+        Self::new(duration.as_secs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn sets_the_expiry_of_resource_tokens() {
+        let expiry = ExpirySeconds::from(Duration::from_secs(2 * 60 * 60));
+        assert_eq!(expiry.as_secs(), 7200);
+        assert!(ExpirySeconds::MIN.as_secs() <= expiry.as_secs());
+        assert!(expiry.as_secs() <= ExpirySeconds::MAX.as_secs());
+        assert_eq!(expiry.name(), headers::HEADER_DOCUMENTDB_EXPIRY_SECONDS);
+        assert_eq!(expiry.value().as_str(), "7200");
+    }
+}
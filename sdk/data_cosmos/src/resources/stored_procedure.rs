@@ -59,6 +59,35 @@ impl Parameters {
         Ok(())
     }
 
+    /// Create a parameter list from a value serialized as a JSON array, e.g. a tuple or a
+    /// `Vec`, so that parameters of different types are checked by the compiler.
+    ///
+    /// ```
+    /// # use azure_data_cosmos::resources::stored_procedure::Parameters;
+    /// let parameters = Parameters::from_array(&("order-1", 3, true)).unwrap();
+    /// ```
+    pub fn from_array<T: Serialize>(parameters: &T) -> azure_core::Result<Self> {
+        let value = serde_json::to_value(parameters).with_context(
+            azure_core::error::ErrorKind::DataConversion,
+            || {
+                let ty = std::any::type_name::<T>();
+                format!("failed to convert `{ty}` to StoredProcedure parameters")
+            },
+        )?;
+        match value {
+            serde_json::Value::Array(items) => Ok(Self {
+                vec: items.iter().map(|item| item.to_string()).collect(),
+            }),
+            _ => Err(azure_core::error::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                || {
+                    let ty = std::any::type_name::<T>();
+                    format!("`{ty}` is not serialized as an array of StoredProcedure parameters")
+                },
+            )),
+        }
+    }
+
     /// Convert the list to json
     pub(crate) fn to_json(&self) -> String {
         let mut result = String::from("[");
@@ -115,4 +144,12 @@ mod tests {
         let parameters: Parameters = slice.into();
         assert_eq!(parameters.to_json(), "[\"pollo\", \"arrosto\"]");
     }
+
+    #[test]
+    fn from_array() {
+        let parameters = Parameters::from_array(&("pollo", 3u8, Some(true))).unwrap();
+        assert_eq!(parameters.to_json(), "[\"pollo\", 3, true]");
+
+        assert!(Parameters::from_array(&"pollo").is_err());
+    }
 }
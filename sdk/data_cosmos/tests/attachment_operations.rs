@@ -121,7 +121,7 @@ async fn attachment_operations() -> azure_core::Result<()> {
     // create slug attachment
     let attachment = document.attachment_client("slug");
     let resp = attachment
-        .create_slug("something cool here".into())
+        .create_slug("something cool here")
         .consistency_level(&resp)
         .content_type("text/plain")
         .into_future()
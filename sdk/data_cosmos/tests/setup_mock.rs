@@ -3,14 +3,13 @@ use azure_data_cosmos::prelude::*;
 pub fn initialize(transaction_name: impl Into<String>) -> azure_core::Result<CosmosClient> {
     use azure_core::TransportOptions;
 
-    let account_name = (std::env::var(mock_transport::TESTING_MODE_KEY).as_deref()
-        == Ok(mock_transport::TESTING_MODE_RECORD))
-    .then(get_account)
-    .unwrap_or_else(|| String::from("MOCK_ACCOUNT"));
+    let account_name = azure_core::mock::is_recording()
+        .then(get_account)
+        .unwrap_or_else(|| String::from("MOCK_ACCOUNT"));
     let authorization_token = get_authorization_token();
 
     let transport_options = TransportOptions::new_custom_policy(
-        mock_transport::new_mock_transport(transaction_name.into()),
+        azure_core::mock::new_mock_transport(transaction_name.into()),
     );
     let client = CosmosClient::builder(account_name, authorization_token)
         .transport(transport_options)
@@ -24,14 +23,13 @@ fn get_account() -> String {
 }
 
 fn get_authorization_token() -> AuthorizationToken {
-    (std::env::var(mock_transport::TESTING_MODE_KEY).as_deref()
-        == Ok(mock_transport::TESTING_MODE_RECORD))
-    .then(|| {
-        let key = std::env::var("COSMOS_PRIMARY_KEY")
-            .expect("Set env variable COSMOS_PRIMARY_KEY first!");
+    azure_core::mock::is_recording()
+        .then(|| {
+            let key = std::env::var("COSMOS_PRIMARY_KEY")
+                .expect("Set env variable COSMOS_PRIMARY_KEY first!");
 
-        AuthorizationToken::primary_from_base64(&key).ok()
-    })
-    .flatten()
-    .unwrap_or_else(|| AuthorizationToken::new_resource(String::from("MOCK_RESOURCE")))
+            AuthorizationToken::primary_from_base64(&key).ok()
+        })
+        .flatten()
+        .unwrap_or_else(|| AuthorizationToken::new_resource(String::from("MOCK_RESOURCE")))
 }
@@ -0,0 +1,30 @@
+use azure_data_tables::prelude::*;
+use azure_identity::DefaultAzureCredential;
+use futures::stream::StreamExt;
+use std::sync::Arc;
+
+#[tokio::main]
+async fn main() -> azure_core::Result<()> {
+    env_logger::init();
+
+    // Shared key access might be disabled on the storage account: authenticate with an Azure
+    // Active Directory identity instead. The identity needs a data plane role such as
+    // "Storage Table Data Reader".
+    let account = std::env::args()
+        .nth(1)
+        .expect("please specify the account name as first command line parameter");
+
+    let table_service = TableServiceClient::new_token_credential(
+        account,
+        Arc::new(DefaultAzureCredential::default()),
+    )?;
+
+    let mut stream = table_service.list().into_stream();
+    while let Some(response) = stream.next().await {
+        for table in response?.tables {
+            println!("{}", table.name);
+        }
+    }
+
+    Ok(())
+}
@@ -105,6 +105,20 @@ impl EntityClient {
         ))
     }
 
+    /// Insert the entity, or update it with `mode` if it already exists.
+    pub fn upsert<E: Serialize>(
+        &self,
+        entity: E,
+        mode: UpdateMode,
+    ) -> azure_core::Result<InsertOrReplaceOrMergeEntityBuilder> {
+        let body = serde_json::to_string(&entity)?.into();
+        Ok(InsertOrReplaceOrMergeEntityBuilder::new(
+            self.clone(),
+            body,
+            mode.into(),
+        ))
+    }
+
     pub fn delete(&self) -> DeleteEntityBuilder {
         DeleteEntityBuilder::new(self.clone())
     }
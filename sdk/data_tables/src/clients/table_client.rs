@@ -1,5 +1,9 @@
-use crate::{clients::*, operations::*};
-use azure_core::{headers::Headers, Body, Context, Method, Request, Response, Url};
+use crate::{clients::*, operations::*, UpdateMode};
+use azure_core::{
+    error::{Error, ErrorKind},
+    headers::Headers,
+    Body, Context, Method, Request, Response, Url,
+};
 use azure_storage::clients::StorageClient;
 use serde::{de::DeserializeOwned, Serialize};
 
@@ -41,6 +45,33 @@ impl TableClient {
         Ok(InsertEntityBuilder::new(self.clone(), body))
     }
 
+    /// Insert the entity, or update it with `mode` if it already exists.
+    ///
+    /// The entity is addressed by its `PartitionKey` and `RowKey` properties, so there is no need
+    /// to handle a conflict on insert or a missing entity on update.
+    pub fn upsert_entity<E: Serialize>(
+        &self,
+        entity: E,
+        mode: UpdateMode,
+    ) -> azure_core::Result<InsertOrReplaceOrMergeEntityBuilder> {
+        let entity = serde_json::to_value(&entity)?;
+        let key = |name: &str| {
+            entity
+                .get(name)
+                .and_then(|key| key.as_str())
+                .ok_or_else(|| {
+                    Error::with_message(ErrorKind::DataConversion, || {
+                        format!("the entity has no {name} string property")
+                    })
+                })
+        };
+        let partition_key = key("PartitionKey")?;
+        let row_key = key("RowKey")?;
+        self.partition_key_client(partition_key)
+            .entity_client(row_key)?
+            .upsert(&entity, mode)
+    }
+
     pub(crate) fn url(&self) -> &url::Url {
         self.table_service_client.url()
     }
@@ -189,3 +220,83 @@ mod integration_tests {
         // TODO: Validate that the entity was inserted
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::{
+        mock::MockTransportCannedPolicy, ClientOptions, StatusCode, TransportOptions,
+    };
+    use serde_json::json;
+    use std::sync::Arc;
+
+    fn table_client(transport: Arc<MockTransportCannedPolicy>) -> TableClient {
+        StorageClient::new_access_key("account", "a2V5")
+            .with_options(ClientOptions::new(TransportOptions::new_custom_policy(
+                transport,
+            )))
+            .table_service_client()
+            .unwrap()
+            .table_client("table")
+    }
+
+    fn response_headers() -> Headers {
+        let mut headers = Headers::new();
+        headers.insert("x-ms-request-id", "00000000-0000-0000-0000-000000000000");
+        headers.insert("x-ms-version", "2019-12-12");
+        headers.insert("date", "Thu, 15 Oct 2026 10:00:00 GMT");
+        headers.insert("server", "Windows-Azure-Table/1.0");
+        headers.insert("etag", "W/\"datetime'2026-10-15T10%3A00%3A00Z'\"");
+        headers
+    }
+
+    #[tokio::test]
+    async fn upserts_the_entity_at_its_keys() {
+        let transport = Arc::new(
+            MockTransportCannedPolicy::new()
+                .reply(StatusCode::NoContent, response_headers(), "")
+                .reply(StatusCode::NoContent, response_headers(), ""),
+        );
+        let table = table_client(transport.clone());
+        let entity = json!({"PartitionKey": "p", "RowKey": "r", "Value": 1});
+
+        for mode in [UpdateMode::Merge, UpdateMode::Replace] {
+            let response = table
+                .upsert_entity(&entity, mode)
+                .unwrap()
+                .into_future()
+                .await
+                .unwrap();
+            assert_eq!(
+                response.etag.as_ref(),
+                "W/\"datetime'2026-10-15T10%3A00%3A00Z'\""
+            );
+        }
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method(), &Method::Merge);
+        assert_eq!(requests[1].method(), &Method::Put);
+        for request in &requests {
+            assert_eq!(request.url().path(), "/table(PartitionKey='p',RowKey='r')");
+            match request.body() {
+                Body::Bytes(bytes) => assert_eq!(
+                    serde_json::from_slice::<serde_json::Value>(bytes).unwrap(),
+                    entity
+                ),
+                Body::SeekableStream(_) => panic!("the entity is not streamed"),
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_entities_without_keys() {
+        let table = table_client(Arc::new(MockTransportCannedPolicy::new()));
+        assert!(table
+            .upsert_entity(json!({"PartitionKey": "p"}), UpdateMode::Merge)
+            .is_err());
+        assert!(table
+            .upsert_entity(json!({"PartitionKey": "p", "RowKey": 1}), UpdateMode::Merge)
+            .is_err());
+    }
+}
@@ -1,6 +1,9 @@
 use crate::operations::ListTablesBuilder;
-use azure_core::{headers::Headers, Body, Context, Method, Request, Response};
+use azure_core::{
+    auth::TokenCredential, headers::Headers, Body, Context, Method, Request, Response,
+};
 use azure_storage::clients::{ServiceType, StorageClient};
+use std::sync::Arc;
 use url::Url;
 
 use super::TableClient;
@@ -34,6 +37,24 @@ impl TableServiceClient {
         })
     }
 
+    /// Create a new `TableServiceClient` for the account, authenticating with Azure Active
+    /// Directory tokens obtained from `token_credential`.
+    ///
+    /// Use this when shared key access is disabled on the storage account. The identity needs a
+    /// data plane role such as *Storage Table Data Contributor*.
+    pub fn new_token_credential<A>(
+        account: A,
+        token_credential: Arc<dyn TokenCredential>,
+    ) -> azure_core::Result<Self>
+    where
+        A: Into<String>,
+    {
+        Self::new(StorageClient::new_token_credential(
+            account,
+            token_credential,
+        ))
+    }
+
     pub fn list(&self) -> ListTablesBuilder {
         ListTablesBuilder::new(self.clone())
     }
@@ -108,3 +129,28 @@ mod integration_tests {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_identity::DefaultAzureCredential;
+    use azure_storage::clients::StorageCredentials;
+
+    #[test]
+    fn new_token_credential_authorizes_with_the_credential() {
+        let table_service = TableServiceClient::new_token_credential(
+            "account",
+            Arc::new(DefaultAzureCredential::default()),
+        )
+        .unwrap();
+
+        assert_eq!(
+            table_service.url().as_str(),
+            "https://account.table.core.windows.net/Tables"
+        );
+        assert!(matches!(
+            table_service.storage_client.storage_credentials(),
+            StorageCredentials::TokenCredential(_)
+        ));
+    }
+}
@@ -6,6 +6,7 @@ extern crate serde_derive;
 extern crate azure_core;
 
 pub mod clients;
+mod entity_continuation;
 mod entity_metadata;
 mod entity_with_metadata;
 mod filter;
@@ -18,6 +19,8 @@ mod select;
 mod top;
 mod transaction;
 mod transaction_operation;
+mod update_mode;
+pub use entity_continuation::EntityContinuation;
 pub use entity_metadata::EntityMetadata;
 pub use entity_with_metadata::EntityWithMetadata;
 pub use filter::Filter;
@@ -26,3 +29,4 @@ pub use model::Table;
 pub use return_entity::ReturnEntity;
 pub use select::Select;
 pub use top::Top;
+pub use update_mode::UpdateMode;
@@ -17,17 +17,25 @@ operation! {
     ?select: Select,
     ?top: Top,
     ?initial_partition_key: String,
-    ?initial_row_key: String
+    ?initial_row_key: String,
+    ?continuation: EntityContinuation
 }
 
 impl QueryEntityBuilder {
+    /// The stream of the pages of entities.
+    ///
+    /// The query resumes from the next partition and row keys of a previous query when a
+    /// [`continuation`](Self::continuation) is set, e.g. the persisted
+    /// [`ContinuationToken`](azure_core::ContinuationToken) of its last page. It takes
+    /// precedence over the initial partition and row keys.
     pub fn into_stream<E>(self) -> Pageable<QueryEntityResponse<E>, Error>
     where
         E: DeserializeOwned + Send + Sync,
     {
-        let make_request = move |continuation: Option<(String, Option<String>)>| {
+        let make_request = move |continuation: Option<EntityContinuation>| {
             let this = self.clone();
             let mut ctx = self.context.clone();
+            let continuation = continuation.or_else(|| self.continuation.clone());
             async move {
                 let mut url = this.client.url().to_owned();
                 url.path_segments_mut()
@@ -39,13 +47,8 @@ impl QueryEntityBuilder {
                 this.select.append_to_url_query(&mut url);
                 this.top.append_to_url_query(&mut url);
 
-                if let Some((partition_key, row_key)) = continuation {
-                    url.query_pairs_mut()
-                        .append_pair("NextPartitionKey", &partition_key);
-
-                    if let Some(row_key) = row_key {
-                        url.query_pairs_mut().append_pair("NextRowKey", &row_key);
-                    }
+                if let Some(continuation) = continuation {
+                    continuation.append_to_url_query(&mut url);
                 } else if let Some(initial_paritition_key) = this.initial_partition_key {
                     url.query_pairs_mut()
                         .append_pair("NextPartitionKey", &initial_paritition_key);
@@ -88,12 +91,12 @@ impl<E> Continuable for QueryEntityResponse<E>
 where
     E: DeserializeOwned + Send + Sync,
 {
-    type Continuation = (String, Option<String>);
+    type Continuation = EntityContinuation;
 
     fn continuation(&self) -> Option<Self::Continuation> {
-        self.next_partition_key
-            .clone()
-            .map(|partition_key| (partition_key, self.next_row_key.clone()))
+        self.next_partition_key.clone().map(|next_partition_key| {
+            EntityContinuation::new(next_partition_key, self.next_row_key.clone())
+        })
     }
 }
 
@@ -130,3 +133,80 @@ impl<E: DeserializeOwned + Send + Sync> TryFrom<CollectedResponse> for QueryEnti
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::{
+        mock::MockTransportCannedPolicy, ClientOptions, StatusCode, TransportOptions,
+    };
+    use azure_storage::clients::StorageClient;
+    use futures::StreamExt;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    fn table_client(transport: Arc<MockTransportCannedPolicy>) -> TableClient {
+        StorageClient::new_access_key("account", "a2V5")
+            .with_options(ClientOptions::new(TransportOptions::new_custom_policy(
+                transport,
+            )))
+            .table_service_client()
+            .unwrap()
+            .table_client("table")
+    }
+
+    fn response_headers(next_keys: Option<(&str, &str)>) -> Headers {
+        let mut headers = Headers::new();
+        headers.insert("x-ms-request-id", "00000000-0000-0000-0000-000000000000");
+        headers.insert("x-ms-version", "2019-12-12");
+        headers.insert("date", "Thu, 15 Oct 2026 10:00:00 GMT");
+        headers.insert("server", "Windows-Azure-Table/1.0");
+        if let Some((partition_key, row_key)) = next_keys {
+            headers.insert("x-ms-continuation-nextpartitionkey", partition_key);
+            headers.insert("x-ms-continuation-nextrowkey", row_key);
+        }
+        headers
+    }
+
+    const PAGE: &str = r#"{"odata.metadata":"https://account.table.core.windows.net/$metadata#table","value":[{"PartitionKey":"p","RowKey":"r"}]}"#;
+
+    #[tokio::test]
+    async fn resumes_from_the_continuation() {
+        let transport = Arc::new(
+            MockTransportCannedPolicy::new()
+                .reply(StatusCode::Ok, response_headers(Some(("p2", "r2"))), PAGE)
+                .reply(StatusCode::Ok, response_headers(None), PAGE),
+        );
+
+        let pages: Vec<azure_core::Result<QueryEntityResponse<serde_json::Value>>> =
+            table_client(transport.clone())
+                .query()
+                .initial_partition_key("ignored")
+                .continuation(EntityContinuation::new("p1", Some("r1".to_owned())))
+                .into_stream()
+                .collect()
+                .await;
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[0].as_ref().unwrap().entities[0]["RowKey"], "r");
+        assert!(pages[1].as_ref().unwrap().continuation().is_none());
+
+        let keys: Vec<_> = transport
+            .requests()
+            .iter()
+            .map(|request| {
+                let query: HashMap<_, _> = request.url().query_pairs().into_owned().collect();
+                (
+                    query.get("NextPartitionKey").cloned(),
+                    query.get("NextRowKey").cloned(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            keys,
+            [
+                (Some("p1".to_owned()), Some("r1".to_owned())),
+                (Some("p2".to_owned()), Some("r2".to_owned())),
+            ]
+        );
+    }
+}
@@ -2,5 +2,5 @@ pub use crate::{
     clients::{
         AsTableServiceClient, EntityClient, PartitionKeyClient, TableClient, TableServiceClient,
     },
-    Filter, IfMatchCondition, ReturnEntity, Select, Table, Top,
+    EntityContinuation, Filter, IfMatchCondition, ReturnEntity, Select, Table, Top, UpdateMode,
 };
@@ -0,0 +1,19 @@
+use crate::operations::InsertOperation;
+
+/// How an upsert updates an entity which already exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateMode {
+    /// Merge the properties into the existing entity (InsertOrMerge).
+    Merge,
+    /// Replace the existing entity (InsertOrReplace).
+    Replace,
+}
+
+impl From<UpdateMode> for InsertOperation {
+    fn from(mode: UpdateMode) -> Self {
+        match mode {
+            UpdateMode::Merge => InsertOperation::InsertOrMerge,
+            UpdateMode::Replace => InsertOperation::InsertOrReplace,
+        }
+    }
+}
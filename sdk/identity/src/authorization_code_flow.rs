@@ -3,10 +3,11 @@
 //! You can learn more about the OAuth2 authorization code flow [here](https://docs.microsoft.com/azure/active-directory/develop/v2-oauth2-auth-code-flow).
 
 use crate::oauth2_http_client::Oauth2HttpClient;
-use azure_core::error::{ErrorKind, ResultExt};
+use crate::token_credentials::authority_hosts;
+use azure_core::error::{Error, ErrorKind, ResultExt};
 use azure_core::HttpClient;
 use oauth2::basic::BasicClient;
-use oauth2::{ClientId, ClientSecret};
+use oauth2::{AuthorizationCode, ClientId, ClientSecret, CsrfToken};
 use std::sync::Arc;
 use url::Url;
 
@@ -21,27 +22,33 @@ pub fn start(
     redirect_url: Url,
     resource: &str,
 ) -> AuthorizationCodeFlow {
-    let auth_url = oauth2::AuthUrl::from_url(
-        Url::parse(&format!(
-            "https://login.microsoftonline.com/{}/oauth2/v2.0/authorize",
-            tenant_id
-        ))
-        .expect("Invalid authorization endpoint URL"),
-    );
-    let token_url = oauth2::TokenUrl::from_url(
-        Url::parse(&format!(
-            "https://login.microsoftonline.com/{}/oauth2/v2.0/token",
-            tenant_id
-        ))
-        .expect("Invalid token endpoint URL"),
-    );
+    start_with_authority_host(
+        authority_hosts::AZURE_PUBLIC_CLOUD,
+        client_id,
+        client_secret,
+        tenant_id,
+        redirect_url,
+        resource,
+    )
+}
 
-    // Set up the config for the Microsoft Graph OAuth2 process.
-    let client = BasicClient::new(client_id, client_secret, auth_url, Some(token_url))
-        // Microsoft Graph requires client_id and client_secret in URL rather than
-        // using Basic authentication.
-        .set_auth_type(oauth2::AuthType::RequestBody)
-        .set_redirect_uri(oauth2::RedirectUrl::from_url(redirect_url));
+/// Start an authorization code flow with the authority host of another cloud, e.g.
+/// [`authority_hosts::AZURE_CHINA`].
+pub fn start_with_authority_host(
+    authority_host: &str,
+    client_id: ClientId,
+    client_secret: Option<ClientSecret>,
+    tenant_id: &str,
+    redirect_url: Url,
+    resource: &str,
+) -> AuthorizationCodeFlow {
+    let client = client(
+        authority_host,
+        client_id,
+        client_secret,
+        tenant_id,
+        redirect_url,
+    );
 
     // Microsoft Graph supports Proof Key for Code Exchange (PKCE - https://oauth.net/2/pkce/).
     // Create a PKCE code verifier and SHA-256 encode it as a code challenge.
@@ -62,6 +69,36 @@ pub fn start(
     }
 }
 
+fn client(
+    authority_host: &str,
+    client_id: ClientId,
+    client_secret: Option<ClientSecret>,
+    tenant_id: &str,
+    redirect_url: Url,
+) -> BasicClient {
+    let auth_url = oauth2::AuthUrl::from_url(
+        Url::parse(&format!(
+            "{}/{}/oauth2/v2.0/authorize",
+            authority_host, tenant_id
+        ))
+        .expect("Invalid authorization endpoint URL"),
+    );
+    let token_url = oauth2::TokenUrl::from_url(
+        Url::parse(&format!(
+            "{}/{}/oauth2/v2.0/token",
+            authority_host, tenant_id
+        ))
+        .expect("Invalid token endpoint URL"),
+    );
+
+    // Set up the config for the Microsoft Graph OAuth2 process.
+    BasicClient::new(client_id, client_secret, auth_url, Some(token_url))
+        // Microsoft Graph requires client_id and client_secret in URL rather than
+        // using Basic authentication.
+        .set_auth_type(oauth2::AuthType::RequestBody)
+        .set_redirect_uri(oauth2::RedirectUrl::from_url(redirect_url))
+}
+
 /// An object representing an OAuth 2.0 authorization code flow.
 #[derive(Debug)]
 pub struct AuthorizationCodeFlow {
@@ -97,3 +134,134 @@ impl AuthorizationCodeFlow {
             )
     }
 }
+
+/// The optional parameters of the exchange of an authorization code obtained without an
+/// [`AuthorizationCodeFlow`], by an app which runs the sign in itself.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct ExchangeParameters<'a> {
+    /// The PKCE code verifier whose challenge was sent with the authorization request.
+    pub code_verifier: Option<&'a str>,
+    /// The scope of the access token.
+    pub scope: Option<&'a str>,
+    /// The additional claims of the access token, e.g. those of a claims challenge.
+    pub claims: Option<&'a str>,
+}
+
+/// Exchange an authorization code returned to `redirect_url` for a token.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn exchange_with_parameters(
+    http_client: Arc<dyn HttpClient>,
+    authority_host: &str,
+    client_id: ClientId,
+    client_secret: Option<ClientSecret>,
+    tenant_id: &str,
+    redirect_url: Url,
+    code: AuthorizationCode,
+    parameters: ExchangeParameters<'_>,
+) -> azure_core::Result<
+    oauth2::StandardTokenResponse<oauth2::EmptyExtraTokenFields, oauth2::basic::BasicTokenType>,
+> {
+    let client = client(
+        authority_host,
+        client_id,
+        client_secret,
+        tenant_id,
+        redirect_url,
+    );
+    let mut request = client.exchange_code(code);
+    if let Some(code_verifier) = parameters.code_verifier {
+        request =
+            request.set_pkce_verifier(oauth2::PkceCodeVerifier::new(code_verifier.to_owned()));
+    }
+    if let Some(scope) = parameters.scope {
+        request = request.add_extra_param("scope", scope);
+    }
+    if let Some(claims) = parameters.claims {
+        request = request.add_extra_param("claims", claims);
+    }
+    let oauth_http_client = Oauth2HttpClient::new(http_client);
+    request
+        .request_async(|request| oauth_http_client.request(request))
+        .await
+        .context(
+            ErrorKind::Credential,
+            "exchanging an authorization code for a token failed",
+        )
+}
+
+/// Extracts the authorization code from the URL redirected to, checking its CSRF state.
+pub(crate) fn parse_redirect(
+    redirect_url: &Url,
+    csrf_state: &CsrfToken,
+) -> azure_core::Result<AuthorizationCode> {
+    let query_pair = |name: &str| {
+        redirect_url
+            .query_pairs()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.into_owned())
+    };
+
+    if let Some(error) = query_pair("error") {
+        return Err(Error::with_message(ErrorKind::Credential, || {
+            format!(
+                "authorization failed: {}: {}",
+                error,
+                query_pair("error_description").unwrap_or_default()
+            )
+        }));
+    }
+
+    let code = match query_pair("code") {
+        Some(code) => AuthorizationCode::new(code),
+        None => {
+            return Err(Error::message(
+                ErrorKind::Credential,
+                "query pair not found: code",
+            ))
+        }
+    };
+
+    let state = match query_pair("state") {
+        Some(state) => CsrfToken::new(state),
+        None => {
+            return Err(Error::message(
+                ErrorKind::Credential,
+                "query pair not found: state",
+            ))
+        }
+    };
+
+    if state.secret() != csrf_state.secret() {
+        return Err(Error::with_message(ErrorKind::Credential, || {
+            format!(
+                "State secret mismatch: expected {}, received: {}",
+                csrf_state.secret(),
+                state.secret()
+            )
+        }));
+    }
+
+    Ok(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_redirect() {
+        let csrf_state = CsrfToken::new("state".to_owned());
+        let url = Url::parse("http://localhost:3003/?code=abc&state=state").unwrap();
+        assert_eq!(parse_redirect(&url, &csrf_state).unwrap().secret(), "abc");
+
+        let url = Url::parse("http://localhost:3003/?code=abc&state=other").unwrap();
+        assert!(parse_redirect(&url, &csrf_state).is_err());
+
+        let url = Url::parse(
+            "http://localhost:3003/?error=access_denied&error_description=denied&state=state",
+        )
+        .unwrap();
+        let error = parse_redirect(&url, &csrf_state).unwrap_err();
+        assert!(error.to_string().contains("access_denied"));
+    }
+}
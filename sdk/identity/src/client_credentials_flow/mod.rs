@@ -37,6 +37,7 @@
 
 mod login_response;
 
+use crate::token_credentials::authority_hosts;
 use azure_core::Method;
 use azure_core::{
     content_type,
@@ -56,6 +57,29 @@ pub async fn perform(
     client_secret: &str,
     scopes: &[&str],
     tenant_id: &str,
+) -> azure_core::Result<LoginResponse> {
+    perform_with_authority_host(
+        http_client,
+        authority_hosts::AZURE_PUBLIC_CLOUD,
+        client_id,
+        client_secret,
+        scopes,
+        tenant_id,
+    )
+    .await
+}
+
+/// Perform the client credentials flow with the authority host of another cloud, e.g.
+/// [`authority_hosts::AZURE_GOVERNMENT`].
+#[allow(clippy::manual_async_fn)]
+#[fix_hidden_lifetime_bug::fix_hidden_lifetime_bug]
+pub async fn perform_with_authority_host(
+    http_client: Arc<dyn HttpClient>,
+    authority_host: &str,
+    client_id: &str,
+    client_secret: &str,
+    scopes: &[&str],
+    tenant_id: &str,
 ) -> azure_core::Result<LoginResponse> {
     let encoded: String = form_urlencoded::Serializer::new(String::new())
         .append_pair("client_id", client_id)
@@ -65,8 +89,8 @@ pub async fn perform(
         .finish();
 
     let url = Url::parse(&format!(
-        "https://login.microsoftonline.com/{}/oauth2/v2.0/token",
-        tenant_id
+        "{}/{}/oauth2/v2.0/token",
+        authority_host, tenant_id
     ))
     .with_context(ErrorKind::DataConversion, || {
         format!("The supplied tenant id could not be url encoded: {tenant_id}")
@@ -1,10 +1,10 @@
 //! Utilities for aiding in development
 //!
 //! These utilities should not be used in production
-use crate::authorization_code_flow::AuthorizationCodeFlow;
+use crate::authorization_code_flow::{parse_redirect, AuthorizationCodeFlow};
 use azure_core::error::{Error, ErrorKind};
 use log::debug;
-use oauth2::{AuthorizationCode, CsrfToken};
+use oauth2::AuthorizationCode;
 use std::io::{BufRead, BufReader, Write};
 use std::net::TcpListener;
 use url::Url;
@@ -40,35 +40,7 @@ pub fn naive_redirect_server(
 
         debug!("url == {}", url);
 
-        let code = match url.query_pairs().find(|(key, _)| key == "code") {
-            Some((_, value)) => AuthorizationCode::new(value.into_owned()),
-            None => {
-                return Err(Error::message(
-                    ErrorKind::Credential,
-                    "query pair not found: code",
-                ))
-            }
-        };
-
-        let state = match url.query_pairs().find(|(key, _)| key == "state") {
-            Some((_, value)) => CsrfToken::new(value.into_owned()),
-            None => {
-                return Err(Error::message(
-                    ErrorKind::Credential,
-                    "query pair not found: state",
-                ))
-            }
-        };
-
-        if state.secret() != auth_obj.csrf_state.secret() {
-            return Err(Error::with_message(ErrorKind::Credential, || {
-                format!(
-                    "State secret mismatch: expected {}, received: {}",
-                    auth_obj.csrf_state.secret(),
-                    state.secret()
-                )
-            }));
-        }
+        let code = parse_redirect(&url, &auth_obj.csrf_state)?;
 
         let message = "Authentication complete. You can close this window now.";
         let response = format!(
@@ -5,6 +5,7 @@
 //! You can learn more about this authorization flow [here](https://docs.microsoft.com/azure/active-directory/develop/v2-oauth2-device-code).
 mod device_code_responses;
 
+use crate::token_credentials::authority_hosts;
 use azure_core::{
     content_type,
     error::{Error, ErrorKind},
@@ -25,14 +26,34 @@ pub async fn start<'a, 'b, T>(
     client_id: &str,
     scopes: &'b [&'b str],
 ) -> azure_core::Result<DeviceCodePhaseOneResponse<'a>>
+where
+    T: Into<Cow<'a, str>>,
+{
+    start_with_authority_host(
+        http_client,
+        authority_hosts::AZURE_PUBLIC_CLOUD,
+        tenant_id,
+        client_id,
+        scopes,
+    )
+    .await
+}
+
+/// Start the device authorization grant flow with the authority host of another cloud, e.g.
+/// [`authority_hosts::AZURE_GOVERNMENT`].
+pub async fn start_with_authority_host<'a, 'b, T>(
+    http_client: Arc<dyn HttpClient>,
+    authority_host: &str,
+    tenant_id: T,
+    client_id: &str,
+    scopes: &'b [&'b str],
+) -> azure_core::Result<DeviceCodePhaseOneResponse<'a>>
 where
     T: Into<Cow<'a, str>>,
 {
     let tenant_id = tenant_id.into();
-    let url = &format!(
-        "https://login.microsoftonline.com/{}/oauth2/v2.0/devicecode",
-        tenant_id
-    );
+    let authority_host = authority_host.trim_end_matches('/').to_owned();
+    let url = &format!("{}/{}/oauth2/v2.0/devicecode", authority_host, tenant_id);
 
     let encoded = form_urlencoded::Serializer::new(String::new())
         .append_pair("client_id", client_id)
@@ -57,6 +78,7 @@ where
         interval: device_code_response.interval,
         message: device_code_response.message,
         http_client: Some(http_client),
+        authority_host,
         tenant_id,
         client_id: client_id.to_string(),
     })
@@ -76,6 +98,8 @@ pub struct DeviceCodePhaseOneResponse<'a> {
     #[serde(skip)]
     http_client: Option<Arc<dyn HttpClient>>,
     #[serde(skip)]
+    authority_host: String,
+    #[serde(skip)]
     tenant_id: Cow<'a, str>,
     // We store the ClientId as string instead of the original type, because it
     // does not implement Default, and it's in another crate
@@ -104,8 +128,8 @@ impl<'a> DeviceCodePhaseOneResponse<'a> {
             match state {
                 NextState::Continue => {
                     let url = &format!(
-                        "https://login.microsoftonline.com/{}/oauth2/v2.0/token",
-                        self.tenant_id,
+                        "{}/{}/oauth2/v2.0/token",
+                        self.authority_host, self.tenant_id,
                     );
 
                     // Throttle down as specified by Azure. This could be
@@ -1,5 +1,6 @@
 //! Refresh token utilities
 
+use crate::token_credentials::authority_hosts;
 use azure_core::Method;
 use azure_core::{
     auth::AccessToken,
@@ -21,6 +22,62 @@ pub async fn exchange(
     client_id: &str,
     client_secret: Option<&str>,
     refresh_token: &AccessToken,
+) -> azure_core::Result<RefreshTokenResponse> {
+    exchange_with_authority_host(
+        http_client,
+        authority_hosts::AZURE_PUBLIC_CLOUD,
+        tenant_id,
+        client_id,
+        client_secret,
+        refresh_token,
+    )
+    .await
+}
+
+/// Exchange a refresh token with the authority host of another cloud, e.g.
+/// [`authority_hosts::AZURE_CHINA`].
+#[allow(clippy::manual_async_fn)]
+#[fix_hidden_lifetime_bug::fix_hidden_lifetime_bug]
+pub async fn exchange_with_authority_host(
+    http_client: Arc<dyn HttpClient>,
+    authority_host: &str,
+    tenant_id: &str,
+    client_id: &str,
+    client_secret: Option<&str>,
+    refresh_token: &AccessToken,
+) -> azure_core::Result<RefreshTokenResponse> {
+    exchange_with_parameters(
+        http_client,
+        authority_host,
+        tenant_id,
+        client_id,
+        client_secret,
+        refresh_token,
+        ExchangeParameters::default(),
+    )
+    .await
+}
+
+/// The optional parameters of a refresh token exchange.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct ExchangeParameters<'a> {
+    /// The scope of the new access token, instead of the one of the refresh token.
+    pub scope: Option<&'a str>,
+    /// The additional claims of the new access token, e.g. those of a claims challenge.
+    pub claims: Option<&'a str>,
+}
+
+/// Exchange a refresh token, with the optional parameters of the exchange.
+#[allow(clippy::manual_async_fn)]
+#[fix_hidden_lifetime_bug::fix_hidden_lifetime_bug]
+pub(crate) async fn exchange_with_parameters(
+    http_client: Arc<dyn HttpClient>,
+    authority_host: &str,
+    tenant_id: &str,
+    client_id: &str,
+    client_secret: Option<&str>,
+    refresh_token: &AccessToken,
+    parameters: ExchangeParameters<'_>,
 ) -> azure_core::Result<RefreshTokenResponse> {
     let encoded = {
         let mut encoded = &mut form_urlencoded::Serializer::new(String::new());
@@ -32,12 +89,18 @@ pub async fn exchange(
         if let Some(client_secret) = client_secret {
             encoded = encoded.append_pair("client_secret", client_secret)
         };
+        if let Some(scope) = parameters.scope {
+            encoded = encoded.append_pair("scope", scope)
+        };
+        if let Some(claims) = parameters.claims {
+            encoded = encoded.append_pair("claims", claims)
+        };
         encoded.finish()
     };
 
     let url = Url::parse(&format!(
-        "https://login.microsoftonline.com/{}/oauth2/v2.0/token",
-        tenant_id
+        "{}/{}/oauth2/v2.0/token",
+        authority_host, tenant_id
     ))?;
 
     let mut req = Request::new(url, Method::Post);
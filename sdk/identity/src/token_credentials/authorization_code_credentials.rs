@@ -0,0 +1,290 @@
+use super::TokenCredentialOptions;
+use crate::{authorization_code_flow, refresh_token};
+use azure_core::auth::{AccessToken, TokenCredential, TokenRequestOptions, TokenResponse};
+use azure_core::error::{Error, ErrorKind, ResultExt};
+use azure_core::HttpClient;
+use oauth2::{AuthorizationCode, ClientId, ClientSecret, TokenResponse as _};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use time::OffsetDateTime;
+use url::Url;
+
+/// Tokens expiring within this margin are not returned from the cache.
+const EXPIRY_MARGIN: Duration = Duration::from_secs(20);
+
+/// Enables authentication of a user to Azure Active Directory with an authorization code, for
+/// web apps which run the sign in of the
+/// [authorization code flow](https://docs.microsoft.com/azure/active-directory/develop/v2-oauth2-auth-code-flow)
+/// themselves.
+///
+/// The code returned to the redirect URI of the app is redeemed on the first token request, with
+/// the same redirect URI and, if the app used PKCE, the code verifier matching the challenge sent
+/// to the authorize endpoint. The refresh token returned along with the first token is then used
+/// to get the tokens of the other resources, and new tokens once they expire, so the code is only
+/// redeemed once. The access tokens are cached for each resource.
+///
+/// ```no_run
+/// use azure_core::auth::TokenCredential;
+/// use azure_identity::AuthorizationCodeCredential;
+/// use url::Url;
+///
+/// # async fn run(code: String, code_verifier: String) -> azure_core::Result<()> {
+/// let credential = AuthorizationCodeCredential::new(
+///     azure_core::new_http_client(),
+///     "tenant_id".to_owned(),
+///     "client_id".to_owned(),
+///     code,
+///     Url::parse("https://myapp.example.com/signin-oidc")?,
+///     None,
+/// )
+/// .client_secret("client_secret")
+/// .code_verifier(code_verifier);
+/// let token = credential.get_token("https://graph.microsoft.com").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct AuthorizationCodeCredential {
+    http_client: Arc<dyn HttpClient>,
+    tenant_id: String,
+    client_id: String,
+    client_secret: Option<String>,
+    redirect_uri: Url,
+    code_verifier: Option<String>,
+    options: TokenCredentialOptions,
+    state: async_lock::Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    /// The authorization code, until it is redeemed.
+    authorization_code: Option<String>,
+    refresh_token: Option<AccessToken>,
+    tokens: HashMap<String, TokenResponse>,
+}
+
+impl AuthorizationCodeCredential {
+    /// Create a new `AuthorizationCodeCredential` redeeming `authorization_code`, which was
+    /// returned to `redirect_uri`.
+    pub fn new(
+        http_client: Arc<dyn HttpClient>,
+        tenant_id: String,
+        client_id: String,
+        authorization_code: String,
+        redirect_uri: Url,
+        options: Option<TokenCredentialOptions>,
+    ) -> Self {
+        Self {
+            http_client,
+            tenant_id,
+            client_id,
+            client_secret: None,
+            redirect_uri,
+            code_verifier: None,
+            options: options.unwrap_or_default(),
+            state: async_lock::Mutex::new(State {
+                authorization_code: Some(authorization_code),
+                ..State::default()
+            }),
+        }
+    }
+
+    /// Authenticate the app with a client secret, required for confidential clients such as web
+    /// apps.
+    #[must_use]
+    pub fn client_secret(mut self, client_secret: impl Into<String>) -> Self {
+        self.client_secret = Some(client_secret.into());
+        self
+    }
+
+    /// The PKCE code verifier whose challenge was sent with the authorization request.
+    #[must_use]
+    pub fn code_verifier(mut self, code_verifier: impl Into<String>) -> Self {
+        self.code_verifier = Some(code_verifier.into());
+        self
+    }
+
+    /// Redeems the authorization code, returning the token and the refresh token.
+    async fn redeem(
+        &self,
+        code: &str,
+        scope: &str,
+        claims: Option<&str>,
+    ) -> azure_core::Result<(TokenResponse, Option<AccessToken>)> {
+        let response = authorization_code_flow::exchange_with_parameters(
+            self.http_client.clone(),
+            self.options.authority_host(),
+            ClientId::new(self.client_id.clone()),
+            self.client_secret.clone().map(ClientSecret::new),
+            &self.tenant_id,
+            self.redirect_uri.clone(),
+            AuthorizationCode::new(code.to_owned()),
+            authorization_code_flow::ExchangeParameters {
+                code_verifier: self.code_verifier.as_deref(),
+                scope: Some(scope),
+                claims,
+            },
+        )
+        .await
+        .context(
+            ErrorKind::Credential,
+            "redeeming the authorization code failed",
+        )?;
+        Ok((
+            TokenResponse::new(
+                AccessToken::new(response.access_token().secret().to_owned()),
+                OffsetDateTime::now_utc() + response.expires_in().unwrap_or_default(),
+            ),
+            response
+                .refresh_token()
+                .map(|refresh_token| AccessToken::new(refresh_token.secret().to_owned())),
+        ))
+    }
+
+    /// Exchanges the refresh token, returning the token and the new refresh token.
+    async fn refresh(
+        &self,
+        refresh_token: &AccessToken,
+        scope: &str,
+        claims: Option<&str>,
+    ) -> azure_core::Result<(TokenResponse, Option<AccessToken>)> {
+        let response = refresh_token::exchange_with_parameters(
+            self.http_client.clone(),
+            self.options.authority_host(),
+            &self.tenant_id,
+            &self.client_id,
+            self.client_secret.as_deref(),
+            refresh_token,
+            refresh_token::ExchangeParameters {
+                scope: Some(scope),
+                claims,
+            },
+        )
+        .await
+        .context(ErrorKind::Credential, "refreshing the token failed")?;
+        Ok((
+            TokenResponse::new(
+                response.access_token().clone(),
+                OffsetDateTime::now_utc() + Duration::from_secs(response.expires_in()),
+            ),
+            Some(response.refresh_token().clone()),
+        ))
+    }
+}
+
+impl std::fmt::Debug for AuthorizationCodeCredential {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthorizationCodeCredential")
+            .field("tenant_id", &self.tenant_id)
+            .field("client_id", &self.client_id)
+            .field("redirect_uri", &self.redirect_uri)
+            .field("options", &self.options)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl TokenCredential for AuthorizationCodeCredential {
+    async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
+        self.get_token_with_options(resource, &TokenRequestOptions::default())
+            .await
+    }
+
+    async fn get_token_with_options(
+        &self,
+        resource: &str,
+        options: &TokenRequestOptions,
+    ) -> azure_core::Result<TokenResponse> {
+        let claims = options.get_claims();
+        // the lock also makes sure the authorization code is redeemed once
+        let mut state = self.state.lock().await;
+        // the cached token was rejected if there are claims, it is replaced
+        if let Some(token) = state.tokens.get(resource).filter(|_| claims.is_none()) {
+            if token.expires_on > OffsetDateTime::now_utc() + EXPIRY_MARGIN {
+                return Ok(token.clone());
+            }
+        }
+
+        let scope = format!("{resource}/.default offline_access");
+        let (token, refresh_token) = if let Some(code) = &state.authorization_code {
+            let response = self.redeem(code, &scope, claims).await?;
+            state.authorization_code = None;
+            response
+        } else if let Some(refresh_token) = &state.refresh_token {
+            self.refresh(refresh_token, &scope, claims).await?
+        } else {
+            return Err(Error::message(
+                ErrorKind::Credential,
+                "the authorization code was redeemed without returning a refresh token",
+            ));
+        };
+
+        if let Some(refresh_token) = refresh_token {
+            state.refresh_token = Some(refresh_token);
+        }
+        state.tokens.insert(resource.to_owned(), token.clone());
+        Ok(token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token_credentials::token_request::tests::{parameters, FakeTokenEndpoint};
+
+    #[tokio::test]
+    async fn redeems_the_code_then_the_refresh_token() -> azure_core::Result<()> {
+        let endpoint = Arc::new(FakeTokenEndpoint::default());
+        let credential = AuthorizationCodeCredential::new(
+            endpoint.clone(),
+            "tenant".to_owned(),
+            "client".to_owned(),
+            "code".to_owned(),
+            Url::parse("https://app.example.com/signin").unwrap(),
+            None,
+        )
+        .client_secret("secret")
+        .code_verifier("verifier");
+
+        let token = credential.get_token("https://graph.microsoft.com").await?;
+        assert_eq!(token.token.secret(), "TOKEN");
+        let requests = endpoint.requests();
+        let body = parameters(&requests[0].1);
+        assert_eq!(body["grant_type"], "authorization_code");
+        assert_eq!(body["code"], "code");
+        assert_eq!(body["redirect_uri"], "https://app.example.com/signin");
+        assert_eq!(body["code_verifier"], "verifier");
+        assert_eq!(body["client_id"], "client");
+        assert_eq!(body["client_secret"], "secret");
+        assert!(!body.contains_key("claims"));
+        assert_eq!(
+            body["scope"],
+            "https://graph.microsoft.com/.default offline_access"
+        );
+
+        // cached
+        credential.get_token("https://graph.microsoft.com").await?;
+        assert_eq!(endpoint.requests().len(), 1);
+
+        let claims = r#"{"access_token":{}}"#;
+        credential
+            .get_token_with_options(
+                "https://storage.azure.com",
+                &TokenRequestOptions::default().claims(claims),
+            )
+            .await?;
+        let requests = endpoint.requests();
+        assert_eq!(requests.len(), 2);
+        let body = parameters(&requests[1].1);
+        assert_eq!(body["grant_type"], "refresh_token");
+        assert_eq!(body["refresh_token"], "REFRESH");
+        assert!(!body.contains_key("code"));
+        assert_eq!(body["claims"], claims);
+        assert_eq!(
+            body["scope"],
+            "https://storage.azure.com/.default offline_access"
+        );
+        Ok(())
+    }
+}
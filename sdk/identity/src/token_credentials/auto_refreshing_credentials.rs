@@ -1,5 +1,5 @@
 use async_lock::RwLock;
-use azure_core::auth::{TokenCredential, TokenResponse};
+use azure_core::auth::{TokenCredential, TokenRequestOptions, TokenResponse};
 use azure_core::error::{Error, ErrorKind};
 use std::sync::Arc;
 use std::time::Duration;
@@ -38,6 +38,27 @@ impl AutoRefreshingTokenCredential {
 #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
 impl TokenCredential for AutoRefreshingTokenCredential {
     async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
+        self.get_token_with_options(resource, &TokenRequestOptions::default())
+            .await
+    }
+
+    async fn get_token_with_options(
+        &self,
+        resource: &str,
+        options: &TokenRequestOptions,
+    ) -> azure_core::Result<TokenResponse> {
+        if options.get_claims().is_some() {
+            // the current token was rejected, it is replaced by the token with the claims
+            let mut guard = self.current_token.write().await;
+            let res = self
+                .credential
+                .get_token_with_options(resource, options)
+                .await;
+            if let Ok(token) = &res {
+                *guard = Some(Ok(token.clone()));
+            }
+            return res;
+        }
         if let Some(Ok(token)) = self.current_token.read().await.as_ref() {
             if !is_expired(token) {
                 return Ok(token.clone());
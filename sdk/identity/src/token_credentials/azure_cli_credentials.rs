@@ -1,4 +1,4 @@
-use azure_core::auth::{AccessToken, TokenCredential, TokenResponse};
+use azure_core::auth::{AccessToken, TokenCredential, TokenRequestOptions, TokenResponse};
 use azure_core::error::{Error, ErrorKind, ResultExt};
 use serde::Deserialize;
 use std::process::Command;
@@ -47,9 +47,9 @@ struct CliTokenResponse {
 }
 
 /// Enables authentication to Azure Active Directory using Azure CLI to obtain an access token.
-#[derive(Default)]
+#[derive(Debug, Default)]
 pub struct AzureCliCredential {
-    _private: (),
+    tenant_id: Option<String>,
 }
 
 impl AzureCliCredential {
@@ -58,8 +58,21 @@ impl AzureCliCredential {
         Self::default()
     }
 
-    /// Get an access token for an optional resource
-    fn get_access_token(resource: Option<&str>) -> azure_core::Result<CliTokenResponse> {
+    /// Get the tokens from this tenant instead of the tenant of the current subscription.
+    #[must_use]
+    pub fn with_tenant_id<A>(mut self, tenant_id: A) -> Self
+    where
+        A: Into<String>,
+    {
+        self.tenant_id = Some(tenant_id.into());
+        self
+    }
+
+    /// Get an access token for an optional resource, from an optional tenant
+    fn get_access_token(
+        resource: Option<&str>,
+        tenant_id: Option<&str>,
+    ) -> azure_core::Result<CliTokenResponse> {
         // on window az is a cmd and it should be called like this
         // see https://doc.rust-lang.org/nightly/std/process/struct.Command.html
         let program = if cfg!(target_os = "windows") {
@@ -80,6 +93,10 @@ impl AzureCliCredential {
             args.push("--resource");
             args.push(resource);
         }
+        if let Some(tenant_id) = tenant_id {
+            args.push("--tenant");
+            args.push(tenant_id);
+        }
 
         match Command::new(program).args(args).output() {
             Ok(az_output) if az_output.status.success() => {
@@ -108,13 +125,13 @@ impl AzureCliCredential {
 
     /// Returns the current subscription ID from the Azure CLI.
     pub fn get_subscription() -> azure_core::Result<String> {
-        let tr = Self::get_access_token(None)?;
+        let tr = Self::get_access_token(None, None)?;
         Ok(tr.subscription)
     }
 
     /// Returns the current tenant ID from the Azure CLI.
     pub fn get_tenant() -> azure_core::Result<String> {
-        let tr = Self::get_access_token(None)?;
+        let tr = Self::get_access_token(None, None)?;
         Ok(tr.tenant)
     }
 }
@@ -123,9 +140,31 @@ impl AzureCliCredential {
 #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
 impl TokenCredential for AzureCliCredential {
     async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
-        let tr = Self::get_access_token(Some(resource))?;
+        let tr = Self::get_access_token(Some(resource), self.tenant_id.as_deref())?;
         Ok(TokenResponse::new(tr.access_token, tr.expires_on))
     }
+
+    /// The Azure CLI only returns the tokens of its cache, which was rejected if there are
+    /// claims: the user has to sign in again with them.
+    async fn get_token_with_options(
+        &self,
+        resource: &str,
+        options: &TokenRequestOptions,
+    ) -> azure_core::Result<TokenResponse> {
+        match options.get_claims() {
+            Some(claims) => Err(claims_challenge_error(resource, claims)),
+            None => self.get_token(resource).await,
+        }
+    }
+}
+
+fn claims_challenge_error(resource: &str, claims: &str) -> Error {
+    Error::with_message(ErrorKind::Credential, || {
+        format!(
+            "the token of {resource} was rejected with a claims challenge, run 'az login --claims-challenge {}' and retry",
+            base64::encode(claims)
+        )
+    })
 }
 
 #[cfg(test)]
@@ -143,4 +182,13 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn asks_to_sign_in_with_the_claims() {
+        let error = claims_challenge_error("https://vault.azure.net", r#"{"access_token":{}}"#);
+        assert_eq!(error.kind(), &ErrorKind::Credential);
+        assert!(error
+            .to_string()
+            .contains("az login --claims-challenge eyJhY2Nlc3NfdG9rZW4iOnt9fQ=="));
+    }
 }
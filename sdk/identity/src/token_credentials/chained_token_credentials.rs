@@ -0,0 +1,180 @@
+use azure_core::auth::{TokenCredential, TokenRequestOptions, TokenResponse};
+use azure_core::error::{Error, ErrorKind};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// No source has succeeded yet.
+const NO_SOURCE: usize = usize::MAX;
+
+/// Tries a list of credentials in order until one of them returns a token.
+///
+/// The credential which succeeded is remembered and tried first by the next calls, so the sources
+/// which are not available are not tried again for every token. If it fails later on, the other
+/// sources are tried again in order.
+///
+/// ```no_run
+/// use azure_core::auth::TokenCredential;
+/// use azure_identity::{AzureCliCredential, ChainedTokenCredential, EnvironmentCredential};
+/// use std::sync::Arc;
+///
+/// let credential = ChainedTokenCredential::new(vec![
+///     Arc::new(EnvironmentCredential::default()) as Arc<dyn TokenCredential>,
+///     Arc::new(AzureCliCredential::new()),
+/// ]);
+/// ```
+pub struct ChainedTokenCredential {
+    sources: Vec<Arc<dyn TokenCredential>>,
+    successful_source: AtomicUsize,
+}
+
+impl ChainedTokenCredential {
+    /// Create a new `ChainedTokenCredential` trying `sources` in order.
+    pub fn new(sources: Vec<Arc<dyn TokenCredential>>) -> Self {
+        Self {
+            sources,
+            successful_source: AtomicUsize::new(NO_SOURCE),
+        }
+    }
+
+    /// The index in the sources of the credential which returned the last token, if any.
+    pub fn successful_source(&self) -> Option<usize> {
+        match self.successful_source.load(Ordering::Relaxed) {
+            NO_SOURCE => None,
+            index => Some(index),
+        }
+    }
+}
+
+impl std::fmt::Debug for ChainedTokenCredential {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ChainedTokenCredential")
+            .field("sources", &self.sources.len())
+            .field("successful_source", &self.successful_source())
+            .finish()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl TokenCredential for ChainedTokenCredential {
+    async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
+        self.get_token_with_options(resource, &TokenRequestOptions::default())
+            .await
+    }
+
+    async fn get_token_with_options(
+        &self,
+        resource: &str,
+        options: &TokenRequestOptions,
+    ) -> azure_core::Result<TokenResponse> {
+        let mut errors = Vec::new();
+
+        let successful_source = self.successful_source();
+        if let Some(index) = successful_source {
+            match self.sources[index]
+                .get_token_with_options(resource, options)
+                .await
+            {
+                Ok(token) => return Ok(token),
+                Err(error) => {
+                    log::debug!("the credential which succeeded before failed: {error}");
+                    errors.push(error);
+                }
+            }
+        }
+
+        for (index, source) in self.sources.iter().enumerate() {
+            if Some(index) == successful_source {
+                continue;
+            }
+            match source.get_token_with_options(resource, options).await {
+                Ok(token) => {
+                    self.successful_source.store(index, Ordering::Relaxed);
+                    return Ok(token);
+                }
+                Err(error) => errors.push(error),
+            }
+        }
+
+        self.successful_source.store(NO_SOURCE, Ordering::Relaxed);
+        Err(Error::with_message(ErrorKind::Credential, || {
+            format!(
+                "no credential in the chain returned a token:\n{}",
+                errors
+                    .iter()
+                    .map(|error| error.to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            )
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::auth::AccessToken;
+    use std::sync::atomic::AtomicBool;
+    use time::OffsetDateTime;
+
+    #[derive(Default)]
+    struct FakeCredential {
+        available: AtomicBool,
+        calls: AtomicUsize,
+    }
+
+    impl FakeCredential {
+        fn new(available: bool) -> Arc<Self> {
+            let credential = Self::default();
+            credential.available.store(available, Ordering::Relaxed);
+            Arc::new(credential)
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.load(Ordering::Relaxed)
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+    impl TokenCredential for FakeCredential {
+        async fn get_token(&self, _resource: &str) -> azure_core::Result<TokenResponse> {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            if self.available.load(Ordering::Relaxed) {
+                Ok(TokenResponse::new(
+                    AccessToken::new("token"),
+                    OffsetDateTime::now_utc(),
+                ))
+            } else {
+                Err(Error::message(ErrorKind::Credential, "not available"))
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn remembers_the_successful_credential() {
+        let unavailable = FakeCredential::new(false);
+        let available = FakeCredential::new(true);
+        let fallback = FakeCredential::new(true);
+        let credential = ChainedTokenCredential::new(vec![
+            unavailable.clone() as Arc<dyn TokenCredential>,
+            available.clone(),
+            fallback.clone(),
+        ]);
+
+        credential.get_token("resource").await.unwrap();
+        credential.get_token("resource").await.unwrap();
+        assert_eq!(credential.successful_source(), Some(1));
+        assert_eq!(unavailable.calls(), 1);
+        assert_eq!(available.calls(), 2);
+        assert_eq!(fallback.calls(), 0);
+
+        available.available.store(false, Ordering::Relaxed);
+        credential.get_token("resource").await.unwrap();
+        assert_eq!(credential.successful_source(), Some(2));
+
+        fallback.available.store(false, Ordering::Relaxed);
+        assert!(credential.get_token("resource").await.is_err());
+        assert_eq!(credential.successful_source(), None);
+    }
+}
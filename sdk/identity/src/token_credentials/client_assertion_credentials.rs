@@ -0,0 +1,284 @@
+use super::token_request::{request_token, token_request_body};
+use super::TokenCredentialOptions;
+use azure_core::auth::{TokenCredential, TokenRequestOptions, TokenResponse};
+use azure_core::error::{Error, ErrorKind, ResultExt};
+use azure_core::{content_type, headers, HttpClient, Method, Request};
+use serde::Deserialize;
+use std::path::PathBuf;
+use std::sync::Arc;
+use url::Url;
+
+const ACTIONS_ID_TOKEN_REQUEST_URL_ENV_KEY: &str = "ACTIONS_ID_TOKEN_REQUEST_URL";
+const ACTIONS_ID_TOKEN_REQUEST_TOKEN_ENV_KEY: &str = "ACTIONS_ID_TOKEN_REQUEST_TOKEN";
+
+/// The audience of the tokens exchanged with Azure Active Directory, the default of the federated
+/// credentials of the app registrations.
+const TOKEN_EXCHANGE_AUDIENCE: &str = "api://AzureADTokenExchange";
+
+const CLIENT_ASSERTION_TYPE: &str = "urn:ietf:params:oauth:client-assertion-type:jwt-bearer";
+
+/// Enables authentication to Azure Active Directory with a client assertion issued by another
+/// identity provider, trusted by a
+/// [federated identity credential](https://docs.microsoft.com/azure/active-directory/develop/workload-identity-federation)
+/// of the app registration.
+///
+/// In GitHub Actions, [`ClientAssertionCredential::github_actions`] requests the OpenID Connect
+/// token of the workflow run, so that deployments can authenticate without storing a secret in
+/// the repository. The workflow needs the `id-token: write` permission, which sets the
+/// `ACTIONS_ID_TOKEN_REQUEST_URL` and `ACTIONS_ID_TOKEN_REQUEST_TOKEN` environment variables.
+///
+/// [`ClientAssertionCredential::from_file`] reads the assertion from a file rotated by another
+/// process, like the service account token of a Kubernetes
+/// [`WorkloadIdentityCredential`](super::WorkloadIdentityCredential).
+///
+/// ```no_run
+/// use azure_core::auth::TokenCredential;
+/// use azure_identity::{ClientAssertionCredential, TokenCredentialOptions};
+///
+/// # async fn run() -> azure_core::Result<()> {
+/// let credential = ClientAssertionCredential::github_actions(
+///     azure_core::new_http_client(),
+///     "tenant_id".to_owned(),
+///     "client_id".to_owned(),
+///     TokenCredentialOptions::default(),
+/// )?;
+/// let token = credential.get_token("https://management.azure.com").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ClientAssertionCredential {
+    http_client: Arc<dyn HttpClient>,
+    tenant_id: String,
+    client_id: String,
+    assertion: Assertion,
+    options: TokenCredentialOptions,
+}
+
+enum Assertion {
+    /// An assertion obtained by the application, which is used until it expires.
+    Static(String),
+    /// The OpenID Connect token of the GitHub Actions workflow run, requested for every token.
+    GitHubActions {
+        request_url: Url,
+        request_token: String,
+    },
+    /// The content of a file, read again for every token as it is rotated.
+    File(PathBuf),
+}
+
+impl ClientAssertionCredential {
+    /// Create a new `ClientAssertionCredential` exchanging `client_assertion`, a JWT issued by a
+    /// trusted identity provider for the `api://AzureADTokenExchange` audience.
+    pub fn new(
+        http_client: Arc<dyn HttpClient>,
+        tenant_id: String,
+        client_id: String,
+        client_assertion: String,
+        options: TokenCredentialOptions,
+    ) -> Self {
+        Self {
+            http_client,
+            tenant_id,
+            client_id,
+            assertion: Assertion::Static(client_assertion),
+            options,
+        }
+    }
+
+    /// Create a new `ClientAssertionCredential` exchanging the content of the file at `path`.
+    pub fn from_file(
+        http_client: Arc<dyn HttpClient>,
+        tenant_id: String,
+        client_id: String,
+        path: impl Into<PathBuf>,
+        options: TokenCredentialOptions,
+    ) -> Self {
+        Self {
+            http_client,
+            tenant_id,
+            client_id,
+            assertion: Assertion::File(path.into()),
+            options,
+        }
+    }
+
+    /// Create a new `ClientAssertionCredential` exchanging the OpenID Connect token of the
+    /// GitHub Actions workflow run.
+    ///
+    /// Fails if the workflow does not have the `id-token: write` permission.
+    pub fn github_actions(
+        http_client: Arc<dyn HttpClient>,
+        tenant_id: String,
+        client_id: String,
+        options: TokenCredentialOptions,
+    ) -> azure_core::Result<Self> {
+        let request_url = env_var(ACTIONS_ID_TOKEN_REQUEST_URL_ENV_KEY)?;
+        let request_url = Url::parse(&request_url).with_context(ErrorKind::Credential, || {
+            format!("{ACTIONS_ID_TOKEN_REQUEST_URL_ENV_KEY} is not a valid URL: {request_url}")
+        })?;
+        let request_token = env_var(ACTIONS_ID_TOKEN_REQUEST_TOKEN_ENV_KEY)?;
+        Ok(Self {
+            http_client,
+            tenant_id,
+            client_id,
+            assertion: Assertion::GitHubActions {
+                request_url,
+                request_token,
+            },
+            options,
+        })
+    }
+
+    async fn client_assertion(&self) -> azure_core::Result<String> {
+        let (request_url, request_token) = match &self.assertion {
+            Assertion::Static(assertion) => return Ok(assertion.clone()),
+            Assertion::File(path) => {
+                let assertion =
+                    std::fs::read_to_string(path).with_context(ErrorKind::Credential, || {
+                        format!(
+                            "failed to read the client assertion file {}",
+                            path.display()
+                        )
+                    })?;
+                return Ok(assertion.trim().to_owned());
+            }
+            Assertion::GitHubActions {
+                request_url,
+                request_token,
+            } => (request_url, request_token),
+        };
+
+        let mut req = Request::new(github_actions_token_url(request_url), Method::Get);
+        req.insert_header(headers::AUTHORIZATION, format!("Bearer {request_token}"));
+        req.insert_header(headers::ACCEPT, content_type::APPLICATION_JSON);
+
+        let rsp = self.http_client.execute_request(&req).await?;
+        let rsp_status = rsp.status();
+        let rsp_body = rsp.into_body().collect().await?;
+        if !rsp_status.is_success() {
+            return Err(Error::full(
+                ErrorKind::Credential,
+                ErrorKind::http_response_from_body(rsp_status, &rsp_body).into_error(),
+                "requesting the GitHub Actions OIDC token failed",
+            ));
+        }
+        let response: GitHubActionsTokenResponse =
+            serde_json::from_slice(&rsp_body).map_kind(ErrorKind::Credential)?;
+        Ok(response.value)
+    }
+}
+
+impl std::fmt::Debug for ClientAssertionCredential {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let assertion = match &self.assertion {
+            Assertion::Static(_) => "static".to_owned(),
+            Assertion::GitHubActions { .. } => "GitHub Actions".to_owned(),
+            Assertion::File(path) => format!("file {}", path.display()),
+        };
+        f.debug_struct("ClientAssertionCredential")
+            .field("tenant_id", &self.tenant_id)
+            .field("client_id", &self.client_id)
+            .field("assertion", &assertion)
+            .field("options", &self.options)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct GitHubActionsTokenResponse {
+    value: String,
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl TokenCredential for ClientAssertionCredential {
+    async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
+        self.get_token_with_options(resource, &TokenRequestOptions::default())
+            .await
+    }
+
+    async fn get_token_with_options(
+        &self,
+        resource: &str,
+        options: &TokenRequestOptions,
+    ) -> azure_core::Result<TokenResponse> {
+        let client_assertion = self.client_assertion().await?;
+        request_token(
+            self.http_client.as_ref(),
+            self.options.authority_host(),
+            &self.tenant_id,
+            token_request_body(
+                &[
+                    ("client_id", self.client_id.as_str()),
+                    ("client_assertion_type", CLIENT_ASSERTION_TYPE),
+                    ("client_assertion", client_assertion.as_str()),
+                    ("grant_type", "client_credentials"),
+                ],
+                resource,
+                options.get_claims(),
+            ),
+        )
+        .await
+    }
+}
+
+/// The URL of the OIDC token of the workflow run, for the token exchange audience.
+fn github_actions_token_url(request_url: &Url) -> Url {
+    let mut url = request_url.clone();
+    url.query_pairs_mut()
+        .append_pair("audience", TOKEN_EXCHANGE_AUDIENCE);
+    url
+}
+
+fn env_var(name: &str) -> azure_core::Result<String> {
+    std::env::var(name).map_err(|_| {
+        Error::with_message(ErrorKind::Credential, || {
+            format!("missing {name} environment variable, is the workflow allowed to request an OIDC token?")
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token_credentials::token_request::tests::{parameters, FakeTokenEndpoint};
+
+    #[test]
+    fn requests_the_oidc_token_for_the_token_exchange() {
+        let request_url = Url::parse(
+            "https://pipelines.actions.githubusercontent.com/abc/00000000-0000-0000-0000-000000000000/idtoken?api-version=2.0",
+        )
+        .unwrap();
+        assert_eq!(
+            github_actions_token_url(&request_url).as_str(),
+            "https://pipelines.actions.githubusercontent.com/abc/00000000-0000-0000-0000-000000000000/idtoken?api-version=2.0&audience=api%3A%2F%2FAzureADTokenExchange"
+        );
+    }
+
+    #[tokio::test]
+    async fn exchanges_the_assertion_of_the_file() -> azure_core::Result<()> {
+        let path = std::env::temp_dir().join(format!("assertion-{}", uuid::Uuid::new_v4()));
+        std::fs::write(&path, "header.payload.signature\n").unwrap();
+        let endpoint = Arc::new(FakeTokenEndpoint::default());
+        let credential = ClientAssertionCredential::from_file(
+            endpoint.clone(),
+            "tenant".to_owned(),
+            "client".to_owned(),
+            &path,
+            TokenCredentialOptions::default(),
+        );
+
+        let token = credential.get_token("https://vault.azure.net").await;
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(token?.token.secret(), "TOKEN");
+
+        let (_, body) = &endpoint.requests()[0];
+        let parameters = parameters(body);
+        assert_eq!(parameters["client_id"], "client");
+        assert_eq!(parameters["scope"], "https://vault.azure.net/.default");
+        assert_eq!(parameters["client_assertion_type"], CLIENT_ASSERTION_TYPE);
+        assert_eq!(parameters["client_assertion"], "header.payload.signature");
+        assert_eq!(parameters["grant_type"], "client_credentials");
+        Ok(())
+    }
+}
@@ -1,9 +1,9 @@
-use super::authority_hosts;
+use super::client_secret_credentials::{default_authority_host, normalize_authority_host};
 use azure_core::{
-    auth::{AccessToken, TokenCredential, TokenResponse},
+    auth::{AccessToken, TokenCredential, TokenRequestOptions, TokenResponse},
     content_type,
     error::{Error, ErrorKind},
-    headers, new_http_client, HttpClient, Method, Request,
+    headers, new_http_client, AzureCloud, HttpClient, Method, Request,
 };
 use base64::{CharacterSet, Config};
 use openssl::{
@@ -15,8 +15,8 @@ use openssl::{
     x509::X509,
 };
 use serde::Deserialize;
+use std::sync::Arc;
 use std::time::Duration;
-use std::{str, sync::Arc};
 use time::OffsetDateTime;
 use url::{form_urlencoded, Url};
 
@@ -37,7 +37,7 @@ pub struct CertificateCredentialOptions {
 impl Default for CertificateCredentialOptions {
     fn default() -> Self {
         Self {
-            authority_host: authority_hosts::AZURE_PUBLIC_CLOUD.to_owned(),
+            authority_host: default_authority_host(AzureCloud::Public),
             send_certificate_chain: false,
         }
     }
@@ -47,17 +47,17 @@ impl CertificateCredentialOptions {
     /// Create a new TokenCredentialsOptions. default() may also be used.
     pub fn new(authority_host: String, send_certificate_chain: bool) -> Self {
         Self {
-            authority_host,
+            authority_host: normalize_authority_host(authority_host),
             send_certificate_chain,
         }
     }
     /// Set the authority host for authentication requests.
     pub fn set_authority_host(&mut self, authority_host: String) {
-        self.authority_host = authority_host
+        self.authority_host = normalize_authority_host(authority_host)
     }
 
-    /// The authority host to use for authentication requests.  The default is
-    /// <https://login.microsoftonline.com>.
+    /// The authority host to use for authentication requests.  The default is the value of the
+    /// `AZURE_AUTHORITY_HOST` environment variable, or <https://login.microsoftonline.com>.
     pub fn authority_host(&self) -> &str {
         &self.authority_host
     }
@@ -78,16 +78,33 @@ impl CertificateCredentialOptions {
 /// was generated for an App Registration.
 ///
 /// In order to use subject name validation send_cert_chain option must be set to true
-/// The certificate is expected to be in base64 encoded PKCS12 format
+/// The certificate is expected to be in base64 encoded PKCS12 format, or in PEM format with its
+/// private key when created with [`ClientCertificateCredential::from_pem`].
 pub struct ClientCertificateCredential {
     tenant_id: String,
     client_id: String,
-    client_certificate: String,
-    client_certificate_pass: String,
+    certificate: CertificateSource,
     http_client: Arc<dyn HttpClient>,
     options: CertificateCredentialOptions,
 }
 
+enum CertificateSource {
+    /// A base64 encoded PKCS12 archive and its password.
+    Pkcs12 {
+        certificate: String,
+        password: String,
+    },
+    /// PEM encoded certificates and private key.
+    Pem(Vec<u8>),
+}
+
+/// A certificate with its private key and the rest of its chain.
+struct Certificate {
+    pkey: PKey<Private>,
+    cert: X509,
+    chain: Vec<X509>,
+}
+
 impl ClientCertificateCredential {
     /// Create a new ClientCertificateCredential
     pub fn new(
@@ -96,12 +113,47 @@ impl ClientCertificateCredential {
         client_certificate: String,
         client_certificate_pass: String,
         options: CertificateCredentialOptions,
+    ) -> ClientCertificateCredential {
+        Self::with_source(
+            tenant_id,
+            client_id,
+            CertificateSource::Pkcs12 {
+                certificate: client_certificate,
+                password: client_certificate_pass,
+            },
+            options,
+        )
+    }
+
+    /// Create a new ClientCertificateCredential from PEM encoded certificates and an unencrypted
+    /// private key, e.g. the content of a file with both.
+    ///
+    /// The certificate of the private key is used to sign the client assertion, the other
+    /// certificates are the rest of its chain.
+    pub fn from_pem(
+        tenant_id: String,
+        client_id: String,
+        pem: impl Into<Vec<u8>>,
+        options: CertificateCredentialOptions,
+    ) -> ClientCertificateCredential {
+        Self::with_source(
+            tenant_id,
+            client_id,
+            CertificateSource::Pem(pem.into()),
+            options,
+        )
+    }
+
+    fn with_source(
+        tenant_id: String,
+        client_id: String,
+        certificate: CertificateSource,
+        options: CertificateCredentialOptions,
     ) -> ClientCertificateCredential {
         ClientCertificateCredential {
             tenant_id,
             client_id,
-            client_certificate,
-            client_certificate_pass,
+            certificate,
             http_client: new_http_client(),
             options,
         }
@@ -111,6 +163,101 @@ impl ClientCertificateCredential {
         &self.options
     }
 
+    fn load_certificate(&self) -> azure_core::Result<Certificate> {
+        let certificate = match &self.certificate {
+            CertificateSource::Pkcs12 {
+                certificate,
+                password,
+            } => {
+                let certificate = base64::decode(certificate)
+                    .map_err(|_| Error::message(ErrorKind::Credential, "Base64 decode failed"))?;
+                let certificate = Pkcs12::from_der(&certificate)
+                    .map_err(openssl_error)?
+                    .parse(password)
+                    .map_err(openssl_error)?;
+                Certificate {
+                    pkey: certificate.pkey,
+                    cert: certificate.cert,
+                    chain: certificate
+                        .chain
+                        .map(|chain| chain.into_iter().collect())
+                        .unwrap_or_default(),
+                }
+            }
+            CertificateSource::Pem(pem) => {
+                let pkey = PKey::private_key_from_pem(pem).map_err(openssl_error)?;
+                let mut chain = X509::stack_from_pem(pem).map_err(openssl_error)?;
+                let mut leaf = None;
+                for (index, cert) in chain.iter().enumerate() {
+                    if cert.public_key().map_err(openssl_error)?.public_eq(&pkey) {
+                        leaf = Some(index);
+                        break;
+                    }
+                }
+                let leaf = leaf.ok_or_else(|| {
+                    Error::message(
+                        ErrorKind::Credential,
+                        "the PEM has no certificate matching its private key",
+                    )
+                })?;
+                Certificate {
+                    pkey,
+                    cert: chain.remove(leaf),
+                    chain,
+                }
+            }
+        };
+        if certificate.pkey.rsa().is_err() {
+            return Err(Error::message(
+                ErrorKind::Credential,
+                "only RSA certificates are supported",
+            ));
+        }
+        Ok(certificate)
+    }
+
+    /// The JWT signed with the certificate to authenticate to `audience`.
+    fn client_assertion(
+        &self,
+        certificate: &Certificate,
+        audience: &str,
+    ) -> azure_core::Result<String> {
+        let thumbprint = ClientCertificateCredential::get_thumbprint(&certificate.cert)
+            .map_err(openssl_error)?;
+        let mut header = serde_json::json!({
+            "alg": "RS256",
+            "typ": "JWT",
+            "x5t": ClientCertificateCredential::as_jwt_part(&thumbprint),
+        });
+        if self.options.send_certificate_chain {
+            // the x5c header allows subject name and issuer authentication, it lists the
+            // certificate and then the rest of its chain
+            let x5c = std::iter::once(&certificate.cert)
+                .chain(&certificate.chain)
+                .map(|cert| cert.to_der().map(base64::encode).map_err(openssl_error))
+                .collect::<azure_core::Result<Vec<String>>>()?;
+            header["x5c"] = serde_json::json!(x5c);
+        }
+        let header = ClientCertificateCredential::as_jwt_part(header.to_string().as_bytes());
+
+        let current_time = OffsetDateTime::now_utc().unix_timestamp();
+        let payload = serde_json::json!({
+            "aud": audience,
+            "exp": current_time + DEFAULT_REFRESH_TIME,
+            "iss": self.client_id,
+            "jti": uuid::Uuid::new_v4().to_string(),
+            "nbf": current_time,
+            "sub": self.client_id,
+        });
+        let payload = ClientCertificateCredential::as_jwt_part(payload.to_string().as_bytes());
+
+        let jwt = format!("{}.{}", header, payload);
+        let signature =
+            ClientCertificateCredential::sign(&jwt, &certificate.pkey).map_err(openssl_error)?;
+        let sig = ClientCertificateCredential::as_jwt_part(&signature);
+        Ok(format!("{}.{}", jwt, sig))
+    }
+
     fn sign(jwt: &str, pkey: &PKey<Private>) -> Result<Vec<u8>, ErrorStack> {
         let mut signer = Signer::new(MessageDigest::sha256(), pkey)?;
         signer.update(jwt.as_bytes())?;
@@ -128,6 +275,16 @@ impl ClientCertificateCredential {
     }
 }
 
+impl std::fmt::Debug for ClientCertificateCredential {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientCertificateCredential")
+            .field("tenant_id", &self.tenant_id)
+            .field("client_id", &self.client_id)
+            .field("options", &self.options)
+            .finish_non_exhaustive()
+    }
+}
+
 #[derive(Deserialize, Debug, Default)]
 #[serde(default)]
 struct AadTokenResponse {
@@ -137,13 +294,6 @@ struct AadTokenResponse {
     access_token: String,
 }
 
-fn get_encoded_cert(cert: &X509) -> azure_core::Result<String> {
-    Ok(format!(
-        "\"{}\"",
-        base64::encode(cert.to_pem().map_err(openssl_error)?)
-    ))
-}
-
 fn openssl_error(err: ErrorStack) -> azure_core::error::Error {
     Error::new(ErrorKind::Credential, err)
 }
@@ -152,62 +302,23 @@ fn openssl_error(err: ErrorStack) -> azure_core::error::Error {
 #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
 impl TokenCredential for ClientCertificateCredential {
     async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
-        let options = self.options();
+        self.get_token_with_options(resource, &TokenRequestOptions::default())
+            .await
+    }
+
+    async fn get_token_with_options(
+        &self,
+        resource: &str,
+        options: &TokenRequestOptions,
+    ) -> azure_core::Result<TokenResponse> {
         let url = &format!(
             "{}/{}/oauth2/v2.0/token",
-            options.authority_host(),
+            self.options().authority_host(),
             self.tenant_id
         );
 
-        let certificate = base64::decode(&self.client_certificate)
-            .map_err(|_| Error::message(ErrorKind::Credential, "Base64 decode failed"))?;
-        let certificate = Pkcs12::from_der(&certificate)
-            .map_err(openssl_error)?
-            .parse(&self.client_certificate_pass)
-            .map_err(openssl_error)?;
-
-        let thumbprint = ClientCertificateCredential::get_thumbprint(&certificate.cert)
-            .map_err(openssl_error)?;
-
-        let uuid = uuid::Uuid::new_v4();
-        let current_time = OffsetDateTime::now_utc().unix_timestamp();
-        let expiry_time = current_time + DEFAULT_REFRESH_TIME;
-        let x5t = base64::encode(&thumbprint);
-
-        let header = match options.send_certificate_chain {
-            true => {
-                let base_signature = get_encoded_cert(&certificate.cert)?;
-                let x5c = match certificate.chain {
-                    Some(chain) => {
-                        let chain = chain
-                            .into_iter()
-                            .map(|x| get_encoded_cert(&x))
-                            .collect::<azure_core::Result<Vec<String>>>()?
-                            .join(",");
-                        format! {"{},{}", base_signature, chain}
-                    }
-                    None => base_signature,
-                };
-                format!(
-                    r#"{{"alg":"RS256","typ":"JWT", "x5t":"{}", "x5c":[{}]}}"#,
-                    x5t, x5c
-                )
-            }
-            false => format!(r#"{{"alg":"RS256","typ":"JWT", "x5t":"{}"}}"#, x5t),
-        };
-        let header = ClientCertificateCredential::as_jwt_part(header.as_bytes());
-
-        let payload = format!(
-            r#"{{"aud":"{}","exp":{},"iss": "{}", "jti": "{}", "nbf": {}, "sub": "{}"}}"#,
-            url, expiry_time, self.client_id, uuid, current_time, self.client_id
-        );
-        let payload = ClientCertificateCredential::as_jwt_part(payload.as_bytes());
-
-        let jwt = format!("{}.{}", header, payload);
-        let signature =
-            ClientCertificateCredential::sign(&jwt, &certificate.pkey).map_err(openssl_error)?;
-        let sig = ClientCertificateCredential::as_jwt_part(&signature);
-        let client_assertion = format!("{}.{}", jwt, sig);
+        let certificate = self.load_certificate()?;
+        let client_assertion = self.client_assertion(&certificate, url)?;
 
         let encoded = {
             let mut encoded = &mut form_urlencoded::Serializer::new(String::new());
@@ -220,6 +331,9 @@ impl TokenCredential for ClientCertificateCredential {
                 )
                 .append_pair("client_assertion", client_assertion.as_str())
                 .append_pair("grant_type", "client_credentials");
+            if let Some(claims) = options.get_claims() {
+                encoded = encoded.append_pair("claims", claims);
+            }
             encoded.finish()
         };
 
@@ -246,3 +360,72 @@ impl TokenCredential for ClientCertificateCredential {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use openssl::{asn1::Asn1Time, bn::BigNum, rsa::Rsa, sign::Verifier, x509::X509NameBuilder};
+
+    fn self_signed_pem() -> Vec<u8> {
+        let pkey = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+        let mut name = X509NameBuilder::new().unwrap();
+        name.append_entry_by_text("CN", "client").unwrap();
+        let name = name.build();
+        let mut builder = X509::builder().unwrap();
+        builder.set_version(2).unwrap();
+        let serial = BigNum::from_u32(1).unwrap().to_asn1_integer().unwrap();
+        builder.set_serial_number(&serial).unwrap();
+        builder.set_subject_name(&name).unwrap();
+        builder.set_issuer_name(&name).unwrap();
+        builder.set_pubkey(&pkey).unwrap();
+        builder
+            .set_not_before(&Asn1Time::days_from_now(0).unwrap())
+            .unwrap();
+        builder
+            .set_not_after(&Asn1Time::days_from_now(1).unwrap())
+            .unwrap();
+        builder.sign(&pkey, MessageDigest::sha256()).unwrap();
+        let mut pem = builder.build().to_pem().unwrap();
+        pem.extend(pkey.private_key_to_pem_pkcs8().unwrap());
+        pem
+    }
+
+    #[test]
+    fn signs_client_assertion_with_certificate_chain() {
+        let mut options = CertificateCredentialOptions::default();
+        options.set_send_certificate_chain(true);
+        let credential = ClientCertificateCredential::from_pem(
+            "tenant".to_owned(),
+            "client".to_owned(),
+            self_signed_pem(),
+            options,
+        );
+        let certificate = credential.load_certificate().unwrap();
+        let assertion = credential
+            .client_assertion(&certificate, "https://login.microsoftonline.com/tenant")
+            .unwrap();
+
+        let parts = assertion.split('.').collect::<Vec<_>>();
+        assert_eq!(parts.len(), 3);
+        let decode = |part: &str| base64::decode_config(part, BASE64_URL_SAFE).unwrap();
+        let header: serde_json::Value = serde_json::from_slice(&decode(parts[0])).unwrap();
+        let thumbprint = ClientCertificateCredential::get_thumbprint(&certificate.cert).unwrap();
+        assert_eq!(
+            header["x5t"],
+            ClientCertificateCredential::as_jwt_part(&thumbprint)
+        );
+        assert_eq!(
+            header["x5c"],
+            serde_json::json!([base64::encode(certificate.cert.to_der().unwrap())])
+        );
+        let payload: serde_json::Value = serde_json::from_slice(&decode(parts[1])).unwrap();
+        assert_eq!(payload["aud"], "https://login.microsoftonline.com/tenant");
+        assert_eq!(payload["sub"], "client");
+
+        let mut verifier = Verifier::new(MessageDigest::sha256(), &certificate.pkey).unwrap();
+        verifier
+            .update(format!("{}.{}", parts[0], parts[1]).as_bytes())
+            .unwrap();
+        assert!(verifier.verify(&decode(parts[2])).unwrap());
+    }
+}
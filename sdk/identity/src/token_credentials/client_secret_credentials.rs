@@ -1,13 +1,15 @@
 use crate::oauth2_http_client::Oauth2HttpClient;
-use azure_core::auth::{AccessToken, TokenCredential, TokenResponse};
+use azure_core::auth::{AccessToken, TokenCredential, TokenRequestOptions, TokenResponse};
 use azure_core::error::{ErrorKind, ResultExt};
-use azure_core::HttpClient;
+use azure_core::{AzureCloud, CloudConfiguration, HttpClient};
 use oauth2::{basic::BasicClient, AuthType, AuthUrl, Scope, TokenUrl};
 use std::str;
 use std::sync::Arc;
 use time::OffsetDateTime;
 use url::Url;
 
+const AZURE_AUTHORITY_HOST_ENV_KEY: &str = "AZURE_AUTHORITY_HOST";
+
 /// Provides options to configure how the Identity library makes authentication
 /// requests to Azure Active Directory.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -16,30 +18,56 @@ pub struct TokenCredentialOptions {
 }
 
 impl Default for TokenCredentialOptions {
+    /// The authority host is read from the `AZURE_AUTHORITY_HOST` environment variable, and is
+    /// the one of the public cloud if it is not set.
     fn default() -> Self {
         Self {
-            authority_host: authority_hosts::AZURE_PUBLIC_CLOUD.to_owned(),
+            authority_host: default_authority_host(AzureCloud::Public),
         }
     }
 }
 
 impl TokenCredentialOptions {
     /// Create a new TokenCredentialsOptions. `default()` may also be used.
+    ///
+    /// The authority host is the one of a sovereign cloud, see [`authority_hosts`], or of an AD FS
+    /// server, e.g. `https://adfs.contoso.com`, with the tenant id [`tenant_ids::TENANT_ID_ADFS`].
     pub fn new(authority_host: String) -> Self {
-        Self { authority_host }
+        Self {
+            authority_host: normalize_authority_host(authority_host),
+        }
     }
+
+    /// Create a new TokenCredentialsOptions with the authority host of `cloud`, e.g.
+    /// [`AzureCloud::UsGovernment`].
+    pub fn from_cloud(cloud: impl Into<CloudConfiguration>) -> Self {
+        Self::new(cloud.into().authority_host.into_owned())
+    }
+
     /// Set the authority host for authentication requests.
     pub fn set_authority_host(&mut self, authority_host: String) {
-        self.authority_host = authority_host
+        self.authority_host = normalize_authority_host(authority_host)
     }
 
-    /// The authority host to use for authentication requests.  The default is
-    /// `https://login.microsoftonline.com`.
+    /// The authority host to use for authentication requests.  The default is the value of the
+    /// `AZURE_AUTHORITY_HOST` environment variable, or `https://login.microsoftonline.com`.
     pub fn authority_host(&self) -> &str {
         &self.authority_host
     }
 }
 
+impl From<AzureCloud> for TokenCredentialOptions {
+    fn from(cloud: AzureCloud) -> Self {
+        Self::from_cloud(cloud)
+    }
+}
+
+impl From<CloudConfiguration> for TokenCredentialOptions {
+    fn from(cloud: CloudConfiguration) -> Self {
+        Self::from_cloud(cloud)
+    }
+}
+
 /// A list of known Azure authority hosts
 pub mod authority_hosts {
     /// China-based Azure Authority Host
@@ -52,6 +80,22 @@ pub mod authority_hosts {
     pub const AZURE_PUBLIC_CLOUD: &str = "https://login.microsoftonline.com";
 }
 
+/// The authority host set in the `AZURE_AUTHORITY_HOST` environment variable, or the one of
+/// `cloud`.
+pub(crate) fn default_authority_host(cloud: impl Into<CloudConfiguration>) -> String {
+    match std::env::var(AZURE_AUTHORITY_HOST_ENV_KEY) {
+        Ok(authority_host) if !authority_host.trim().is_empty() => {
+            normalize_authority_host(authority_host)
+        }
+        _ => normalize_authority_host(cloud.into().authority_host.into_owned()),
+    }
+}
+
+/// Removes the trailing slash of `authority_host`, as the paths are appended to it.
+pub(crate) fn normalize_authority_host(authority_host: String) -> String {
+    authority_host.trim().trim_end_matches('/').to_owned()
+}
+
 /// A list of tenant IDs
 pub mod tenant_ids {
     /// The tenant ID for multi-tenant apps
@@ -101,8 +145,16 @@ impl ClientSecretCredential {
 #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
 impl TokenCredential for ClientSecretCredential {
     async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
-        let options = self.options();
-        let authority_host = options.authority_host();
+        self.get_token_with_options(resource, &TokenRequestOptions::default())
+            .await
+    }
+
+    async fn get_token_with_options(
+        &self,
+        resource: &str,
+        options: &TokenRequestOptions,
+    ) -> azure_core::Result<TokenResponse> {
+        let authority_host = self.options().authority_host();
 
         let token_url = TokenUrl::from_url(
             Url::parse(&format!(
@@ -139,9 +191,13 @@ impl TokenCredential for ClientSecretCredential {
         .set_auth_type(AuthType::RequestBody);
 
         let oauth_http_client = Oauth2HttpClient::new(self.http_client.clone());
-        let token_result = client
+        let mut token_request = client
             .exchange_client_credentials()
-            .add_scope(Scope::new(format!("{}/.default", resource)))
+            .add_scope(Scope::new(format!("{}/.default", resource)));
+        if let Some(claims) = options.get_claims() {
+            token_request = token_request.add_extra_param("claims", claims);
+        }
+        let token_result = token_request
             .request_async(|request| oauth_http_client.request(request))
             .await
             .map(|r| {
@@ -156,3 +212,34 @@ impl TokenCredential for ClientSecretCredential {
         Ok(token_result)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_the_authority_host() {
+        let mut options =
+            TokenCredentialOptions::new("https://login.microsoftonline.us/".to_owned());
+        assert_eq!(options.authority_host(), authority_hosts::AZURE_GOVERNMENT);
+
+        options.set_authority_host(" https://adfs.contoso.com/adfs/ ".to_owned());
+        assert_eq!(options.authority_host(), "https://adfs.contoso.com/adfs");
+    }
+
+    #[test]
+    fn reads_the_authority_host_of_the_cloud() {
+        assert_eq!(
+            TokenCredentialOptions::from(AzureCloud::China).authority_host(),
+            authority_hosts::AZURE_CHINA
+        );
+        let stack = CloudConfiguration {
+            authority_host: "https://login.contoso.com/".into(),
+            ..AzureCloud::Public.configuration()
+        };
+        assert_eq!(
+            TokenCredentialOptions::from_cloud(stack).authority_host(),
+            "https://login.contoso.com"
+        );
+    }
+}
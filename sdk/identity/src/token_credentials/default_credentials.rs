@@ -1,5 +1,5 @@
-use super::{AzureCliCredential, ImdsManagedIdentityCredential};
-use azure_core::auth::{TokenCredential, TokenResponse};
+use super::{AzureCliCredential, ImdsManagedIdentityCredential, TokenCredentialOptions};
+use azure_core::auth::{TokenCredential, TokenRequestOptions, TokenResponse};
 use azure_core::error::{Error, ErrorKind, ResultExt};
 
 #[derive(Debug)]
@@ -8,6 +8,9 @@ pub struct DefaultAzureCredentialBuilder {
     include_environment_credential: bool,
     include_managed_identity_credential: bool,
     include_azure_cli_credential: bool,
+    tenant_id: Option<String>,
+    managed_identity_client_id: Option<String>,
+    authority_host: Option<String>,
 }
 
 impl Default for DefaultAzureCredentialBuilder {
@@ -16,6 +19,9 @@ impl Default for DefaultAzureCredentialBuilder {
             include_environment_credential: true,
             include_managed_identity_credential: true,
             include_azure_cli_credential: true,
+            tenant_id: None,
+            managed_identity_client_id: None,
+            authority_host: None,
         }
     }
 }
@@ -26,13 +32,32 @@ impl DefaultAzureCredentialBuilder {
         Self::default()
     }
 
+    /// Include using credentials from the environment
+    pub fn include_environment_credential(&mut self) -> &mut Self {
+        self.include_environment_credential = true;
+        self
+    }
+
+    /// Include using managed identity credentials
+    pub fn include_managed_identity_credential(&mut self) -> &mut Self {
+        self.include_managed_identity_credential = true;
+        self
+    }
+
+    /// Include using credentials from the cli
+    pub fn include_azure_cli_credential(&mut self) -> &mut Self {
+        self.include_azure_cli_credential = true;
+        self
+    }
+
     /// Exclude using credentials from the environment
     pub fn exclude_environment_credential(&mut self) -> &mut Self {
         self.include_environment_credential = false;
         self
     }
 
-    /// Exclude using managed identity credentials
+    /// Exclude using managed identity credentials, e.g. to skip probing the instance metadata
+    /// service outside of Azure
     pub fn exclude_managed_identity_credential(&mut self) -> &mut Self {
         self.include_managed_identity_credential = false;
         self
@@ -44,26 +69,69 @@ impl DefaultAzureCredentialBuilder {
         self
     }
 
+    /// Authenticate in this tenant with the environment and cli credentials, instead of the
+    /// tenant of the `AZURE_TENANT_ID` environment variable and of the current cli subscription
+    pub fn with_tenant_id<A>(&mut self, tenant_id: A) -> &mut Self
+    where
+        A: Into<String>,
+    {
+        self.tenant_id = Some(tenant_id.into());
+        self
+    }
+
+    /// Use the user assigned managed identity with this client id, instead of the system
+    /// assigned one
+    pub fn with_managed_identity_client_id<A>(&mut self, client_id: A) -> &mut Self
+    where
+        A: Into<String>,
+    {
+        self.managed_identity_client_id = Some(client_id.into());
+        self
+    }
+
+    /// Authenticate the environment credential with this authority host, e.g.
+    /// [`authority_hosts::AZURE_GOVERNMENT`](super::authority_hosts::AZURE_GOVERNMENT), instead
+    /// of the one of the `AZURE_AUTHORITY_HOST` environment variable or of the public cloud
+    pub fn with_authority_host<A>(&mut self, authority_host: A) -> &mut Self
+    where
+        A: Into<String>,
+    {
+        self.authority_host = Some(authority_host.into());
+        self
+    }
+
     /// Create a `DefaultAzureCredential` from this builder.
     pub fn build(&self) -> DefaultAzureCredential {
-        let source_count = self.include_azure_cli_credential as usize
+        let source_count = self.include_environment_credential as usize
             + self.include_azure_cli_credential as usize
             + self.include_managed_identity_credential as usize;
         let mut sources = Vec::<DefaultAzureCredentialEnum>::with_capacity(source_count);
         if self.include_environment_credential {
-            sources.push(DefaultAzureCredentialEnum::Environment(
-                super::EnvironmentCredential::default(),
-            ));
+            let mut credential = match &self.authority_host {
+                Some(authority_host) => super::EnvironmentCredential::new(
+                    azure_core::new_http_client(),
+                    TokenCredentialOptions::new(authority_host.clone()),
+                ),
+                None => super::EnvironmentCredential::default(),
+            };
+            if let Some(tenant_id) = &self.tenant_id {
+                credential = credential.with_tenant_id(tenant_id);
+            }
+            sources.push(DefaultAzureCredentialEnum::Environment(credential));
         }
         if self.include_managed_identity_credential {
-            sources.push(DefaultAzureCredentialEnum::ManagedIdentity(
-                ImdsManagedIdentityCredential::default(),
-            ))
+            let mut credential = ImdsManagedIdentityCredential::default();
+            if let Some(client_id) = &self.managed_identity_client_id {
+                credential = credential.with_client_id(client_id);
+            }
+            sources.push(DefaultAzureCredentialEnum::ManagedIdentity(credential))
         }
         if self.include_azure_cli_credential {
-            sources.push(DefaultAzureCredentialEnum::AzureCli(
-                AzureCliCredential::new(),
-            ));
+            let mut credential = AzureCliCredential::new();
+            if let Some(tenant_id) = &self.tenant_id {
+                credential = credential.with_tenant_id(tenant_id);
+            }
+            sources.push(DefaultAzureCredentialEnum::AzureCli(credential));
         }
         DefaultAzureCredential::with_sources(sources)
     }
@@ -83,25 +151,37 @@ pub enum DefaultAzureCredentialEnum {
 #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
 impl TokenCredential for DefaultAzureCredentialEnum {
     async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
+        self.get_token_with_options(resource, &TokenRequestOptions::default())
+            .await
+    }
+
+    async fn get_token_with_options(
+        &self,
+        resource: &str,
+        options: &TokenRequestOptions,
+    ) -> azure_core::Result<TokenResponse> {
         match self {
-            DefaultAzureCredentialEnum::Environment(credential) => {
-                credential.get_token(resource).await.context(
+            DefaultAzureCredentialEnum::Environment(credential) => credential
+                .get_token_with_options(resource, options)
+                .await
+                .context(
                     ErrorKind::Credential,
                     "error getting environment credential",
-                )
-            }
-            DefaultAzureCredentialEnum::ManagedIdentity(credential) => {
-                credential.get_token(resource).await.context(
+                ),
+            DefaultAzureCredentialEnum::ManagedIdentity(credential) => credential
+                .get_token_with_options(resource, options)
+                .await
+                .context(
                     ErrorKind::Credential,
                     "error getting managed identity credential",
-                )
-            }
-            DefaultAzureCredentialEnum::AzureCli(credential) => {
-                credential.get_token(resource).await.context(
+                ),
+            DefaultAzureCredentialEnum::AzureCli(credential) => credential
+                .get_token_with_options(resource, options)
+                .await
+                .context(
                     ErrorKind::Credential,
                     "error getting token credential from Azure CLI",
-                )
-            }
+                ),
         }
     }
 }
@@ -145,9 +225,18 @@ impl Default for DefaultAzureCredential {
 impl TokenCredential for DefaultAzureCredential {
     /// Try to fetch a token using each of the credential sources until one succeeds
     async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
+        self.get_token_with_options(resource, &TokenRequestOptions::default())
+            .await
+    }
+
+    async fn get_token_with_options(
+        &self,
+        resource: &str,
+        options: &TokenRequestOptions,
+    ) -> azure_core::Result<TokenResponse> {
         let mut errors = Vec::new();
         for source in &self.sources {
-            let token_res = source.get_token(resource).await;
+            let token_res = source.get_token_with_options(resource, options).await;
 
             match token_res {
                 Ok(token) => return Ok(token),
@@ -276,5 +365,16 @@ mod tests {
         let credential = builder.build();
 
         assert_eq!(credential.sources.len(), 0);
+
+        // include the cli source again
+
+        builder.include_azure_cli_credential();
+        let credential = builder.build();
+
+        assert_eq!(credential.sources.len(), 1);
+        assert!(contains_credential!(
+            credential,
+            DefaultAzureCredentialEnum::AzureCli(_)
+        ));
     }
 }
@@ -1,5 +1,8 @@
-use super::{ClientSecretCredential, TokenCredentialOptions};
-use azure_core::auth::{TokenCredential, TokenResponse};
+use super::{
+    ClientSecretCredential, TokenCredentialOptions, UsernamePasswordCredential,
+    WorkloadIdentityCredential,
+};
+use azure_core::auth::{TokenCredential, TokenRequestOptions, TokenResponse};
 use azure_core::error::{Error, ErrorKind, ResultExt};
 use azure_core::HttpClient;
 use std::sync::Arc;
@@ -10,8 +13,14 @@ const AZURE_CLIENT_SECRET_ENV_KEY: &str = "AZURE_CLIENT_SECRET";
 const AZURE_USERNAME_ENV_KEY: &str = "AZURE_USERNAME";
 const AZURE_PASSWORD_ENV_KEY: &str = "AZURE_PASSWORD";
 const AZURE_CLIENT_CERTIFICATE_PATH_ENV_KEY: &str = "AZURE_CLIENT_CERTIFICATE_PATH";
+const AZURE_FEDERATED_TOKEN_FILE_ENV_KEY: &str = "AZURE_FEDERATED_TOKEN_FILE";
+#[cfg(feature = "client_certificate")]
+const AZURE_CLIENT_CERTIFICATE_PASSWORD_ENV_KEY: &str = "AZURE_CLIENT_CERTIFICATE_PASSWORD";
+#[cfg(feature = "client_certificate")]
+const AZURE_CLIENT_SEND_CERTIFICATE_CHAIN_ENV_KEY: &str = "AZURE_CLIENT_SEND_CERTIFICATE_CHAIN";
 
-/// Enables authentication to Azure Active Directory using client secret, or a username and password.
+/// Enables authentication to Azure Active Directory using client secret, a client certificate, a
+/// username and password or a federated token.
 ///
 /// Details configured in the following environment variables:
 ///
@@ -20,14 +29,24 @@ const AZURE_CLIENT_CERTIFICATE_PATH_ENV_KEY: &str = "AZURE_CLIENT_CERTIFICATE_PA
 /// | `AZURE_TENANT_ID`                   | The Azure Active Directory tenant(directory) ID. |
 /// | `AZURE_CLIENT_ID`                   | The client(application) ID of an App Registration in the tenant. |
 /// | `AZURE_CLIENT_SECRET`               | A client secret that was generated for the App Registration. |
+/// | `AZURE_CLIENT_CERTIFICATE_PATH`     | The path of a PKCS12 or PEM certificate with its private key, used without a client secret. |
+/// | `AZURE_CLIENT_CERTIFICATE_PASSWORD` | The password of the PKCS12 certificate, if any. |
+/// | `AZURE_CLIENT_SEND_CERTIFICATE_CHAIN` | `true` to send the certificate chain, for subject name and issuer authentication. |
+/// | `AZURE_USERNAME`                    | The username of a user, signed in with their password without a client secret or certificate. |
+/// | `AZURE_PASSWORD`                    | The password of the user. |
+/// | `AZURE_FEDERATED_TOKEN_FILE`        | The path of a federated token, like the service account token of a Kubernetes workload identity. |
+/// | `AZURE_AUTHORITY_HOST`              | The authority host of a sovereign cloud, e.g. `https://login.microsoftonline.us`, read by the default `TokenCredentialOptions`. |
 ///
-/// This credential ultimately uses a `ClientSecretCredential` to perform the authentication using
+/// This credential ultimately uses a `ClientSecretCredential`, a `ClientCertificateCredential`
+/// with the `client_certificate` feature, a `UsernamePasswordCredential`, or a
+/// `WorkloadIdentityCredential`, in this order of precedence, to perform the authentication using
 /// these details.
 /// Please consult the documentation of that class for more details.
 #[derive(Clone, Debug)]
 pub struct EnvironmentCredential {
     http_client: Arc<dyn HttpClient>,
     options: TokenCredentialOptions,
+    tenant_id: Option<String>,
 }
 
 impl Default for EnvironmentCredential {
@@ -46,21 +65,46 @@ impl EnvironmentCredential {
         Self {
             http_client,
             options,
+            tenant_id: None,
         }
     }
+
+    /// Use this tenant instead of the one set in the `AZURE_TENANT_ID` environment variable.
+    #[must_use]
+    pub fn with_tenant_id<A>(mut self, tenant_id: A) -> Self
+    where
+        A: Into<String>,
+    {
+        self.tenant_id = Some(tenant_id.into());
+        self
+    }
 }
 
 #[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
 #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
 impl TokenCredential for EnvironmentCredential {
     async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
-        let tenant_id =
-            std::env::var(AZURE_TENANT_ID_ENV_KEY).with_context(ErrorKind::Credential, || {
-                format!(
-                    "missing tenant id set in {} environment variable",
-                    AZURE_TENANT_ID_ENV_KEY
-                )
-            })?;
+        self.get_token_with_options(resource, &TokenRequestOptions::default())
+            .await
+    }
+
+    async fn get_token_with_options(
+        &self,
+        resource: &str,
+        options: &TokenRequestOptions,
+    ) -> azure_core::Result<TokenResponse> {
+        let tenant_id = match &self.tenant_id {
+            Some(tenant_id) => tenant_id.clone(),
+            None => std::env::var(AZURE_TENANT_ID_ENV_KEY).with_context(
+                ErrorKind::Credential,
+                || {
+                    format!(
+                        "missing tenant id set in {} environment variable",
+                        AZURE_TENANT_ID_ENV_KEY
+                    )
+                },
+            )?,
+        };
         let client_id =
             std::env::var(AZURE_CLIENT_ID_ENV_KEY).with_context(ErrorKind::Credential, || {
                 format!(
@@ -69,31 +113,175 @@ impl TokenCredential for EnvironmentCredential {
                 )
             })?;
 
-        let client_secret = std::env::var(AZURE_CLIENT_SECRET_ENV_KEY);
-        let username = std::env::var(AZURE_USERNAME_ENV_KEY);
-        let password = std::env::var(AZURE_PASSWORD_ENV_KEY);
-        let client_certificate_path = std::env::var(AZURE_CLIENT_CERTIFICATE_PATH_ENV_KEY);
-
-        if let Ok(client_secret) = client_secret {
-            let credential = ClientSecretCredential::new(
-                self.http_client.clone(),
-                tenant_id,
-                client_id,
-                client_secret,
-                self.options.clone(),
-            );
-            return credential.get_token(resource).await;
-        } else if username.is_ok() && password.is_ok() {
-            // Could use multiple if-let with #![feature(let_chains)] once stabilised - see https://github.com/rust-lang/rust/issues/53667
-            // TODO: username & password credential
-        } else if let Ok(_path) = client_certificate_path {
-            // TODO: client certificate credential
-            todo!()
+        match source(|name| std::env::var(name).ok()) {
+            Some(Source::ClientSecret(client_secret)) => {
+                let credential = ClientSecretCredential::new(
+                    self.http_client.clone(),
+                    tenant_id,
+                    client_id,
+                    client_secret,
+                    self.options.clone(),
+                );
+                credential.get_token_with_options(resource, options).await
+            }
+            Some(Source::ClientCertificate(path)) => {
+                client_certificate_token(
+                    tenant_id,
+                    client_id,
+                    &path,
+                    &self.options,
+                    resource,
+                    options,
+                )
+                .await
+            }
+            Some(Source::UsernamePassword { username, password }) => {
+                let credential = UsernamePasswordCredential::new(
+                    self.http_client.clone(),
+                    tenant_id,
+                    client_id,
+                    username,
+                    password,
+                    self.options.clone(),
+                );
+                credential.get_token_with_options(resource, options).await
+            }
+            Some(Source::FederatedTokenFile(path)) => {
+                let credential = WorkloadIdentityCredential::new(
+                    self.http_client.clone(),
+                    tenant_id,
+                    client_id,
+                    path,
+                    self.options.clone(),
+                );
+                credential.get_token_with_options(resource, options).await
+            }
+            None => Err(Error::message(
+                ErrorKind::Credential,
+                "no valid environment credential providers",
+            )),
         }
+    }
+}
+
+/// The credential set in the environment variables.
+#[derive(Debug, PartialEq, Eq)]
+enum Source {
+    ClientSecret(String),
+    ClientCertificate(String),
+    UsernamePassword { username: String, password: String },
+    FederatedTokenFile(String),
+}
+
+/// The credential set in the variables returned by `var`, a client secret first, then a client
+/// certificate, then a username and password, then a federated token file.
+fn source(var: impl Fn(&str) -> Option<String>) -> Option<Source> {
+    if let Some(client_secret) = var(AZURE_CLIENT_SECRET_ENV_KEY) {
+        return Some(Source::ClientSecret(client_secret));
+    }
+    if let Some(path) = var(AZURE_CLIENT_CERTIFICATE_PATH_ENV_KEY) {
+        return Some(Source::ClientCertificate(path));
+    }
+    if let (Some(username), Some(password)) =
+        (var(AZURE_USERNAME_ENV_KEY), var(AZURE_PASSWORD_ENV_KEY))
+    {
+        return Some(Source::UsernamePassword { username, password });
+    }
+    var(AZURE_FEDERATED_TOKEN_FILE_ENV_KEY).map(Source::FederatedTokenFile)
+}
+
+#[cfg(feature = "client_certificate")]
+async fn client_certificate_token(
+    tenant_id: String,
+    client_id: String,
+    path: &str,
+    options: &TokenCredentialOptions,
+    resource: &str,
+    request_options: &TokenRequestOptions,
+) -> azure_core::Result<TokenResponse> {
+    use super::{CertificateCredentialOptions, ClientCertificateCredential};
+
+    let certificate = std::fs::read(path).with_context(ErrorKind::Credential, || {
+        format!("failed to read the client certificate {path}")
+    })?;
+    let send_certificate_chain = std::env::var(AZURE_CLIENT_SEND_CERTIFICATE_CHAIN_ENV_KEY)
+        .map_or(false, |value| {
+            value == "1" || value.eq_ignore_ascii_case("true")
+        });
+    let options = CertificateCredentialOptions::new(
+        options.authority_host().to_owned(),
+        send_certificate_chain,
+    );
+    let is_pem = std::str::from_utf8(&certificate).map_or(false, |pem| pem.contains("-----BEGIN"));
+    let credential = if is_pem {
+        ClientCertificateCredential::from_pem(tenant_id, client_id, certificate, options)
+    } else {
+        let password = std::env::var(AZURE_CLIENT_CERTIFICATE_PASSWORD_ENV_KEY).unwrap_or_default();
+        ClientCertificateCredential::new(
+            tenant_id,
+            client_id,
+            base64::encode(certificate),
+            password,
+            options,
+        )
+    };
+    credential
+        .get_token_with_options(resource, request_options)
+        .await
+}
+
+#[cfg(not(feature = "client_certificate"))]
+async fn client_certificate_token(
+    _tenant_id: String,
+    _client_id: String,
+    _path: &str,
+    _options: &TokenCredentialOptions,
+    _resource: &str,
+    _request_options: &TokenRequestOptions,
+) -> azure_core::Result<TokenResponse> {
+    Err(Error::message(
+        ErrorKind::Credential,
+        "client certificate authentication requires the client_certificate feature",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn source_of(vars: &[(&str, &str)]) -> Option<Source> {
+        let vars: HashMap<&str, &str> = vars.iter().copied().collect();
+        source(|name| vars.get(name).map(|value| (*value).to_owned()))
+    }
+
+    #[test]
+    fn prefers_the_secret_then_the_certificate_then_the_password() {
+        let secret = (AZURE_CLIENT_SECRET_ENV_KEY, "secret");
+        let certificate = (AZURE_CLIENT_CERTIFICATE_PATH_ENV_KEY, "cert.pem");
+        let username = (AZURE_USERNAME_ENV_KEY, "user@contoso.com");
+        let password = (AZURE_PASSWORD_ENV_KEY, "password");
+        let token_file = (AZURE_FEDERATED_TOKEN_FILE_ENV_KEY, "token");
 
-        Err(Error::message(
-            ErrorKind::Credential,
-            "no valid environment credential providers",
-        ))
+        assert_eq!(
+            source_of(&[token_file, password, username, certificate, secret]),
+            Some(Source::ClientSecret("secret".to_owned()))
+        );
+        assert_eq!(
+            source_of(&[token_file, password, username, certificate]),
+            Some(Source::ClientCertificate("cert.pem".to_owned()))
+        );
+        assert_eq!(
+            source_of(&[token_file, password, username]),
+            Some(Source::UsernamePassword {
+                username: "user@contoso.com".to_owned(),
+                password: "password".to_owned()
+            })
+        );
+        assert_eq!(
+            source_of(&[token_file, username]),
+            Some(Source::FederatedTokenFile("token".to_owned()))
+        );
+        assert_eq!(source_of(&[password]), None);
     }
 }
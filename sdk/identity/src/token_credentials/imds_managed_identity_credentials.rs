@@ -1,12 +1,13 @@
 use azure_core::{
-    auth::{AccessToken, TokenCredential, TokenResponse},
+    auth::{AccessToken, TokenCredential, TokenRequestOptions, TokenResponse},
     error::{Error, ErrorKind, ResultExt},
-    HttpClient, Method, Request, StatusCode,
+    headers, HttpClient, Method, Request, Response, StatusCode,
 };
 use serde::{
     de::{self, Deserializer},
     Deserialize,
 };
+use std::path::{Path, PathBuf};
 use std::str;
 use std::sync::Arc;
 use time::OffsetDateTime;
@@ -15,11 +16,21 @@ use url::Url;
 const MSI_ENDPOINT_ENV_KEY: &str = "IDENTITY_ENDPOINT";
 const MSI_SECRET_ENV_KEY: &str = "IDENTITY_HEADER";
 const MSI_API_VERSION: &str = "2019-08-01";
+const ARC_IMDS_ENDPOINT_ENV_KEY: &str = "IMDS_ENDPOINT";
+const ARC_API_VERSION: &str = "2020-06-01";
+/// The largest challenge file the Azure Arc agent writes.
+const ARC_MAX_KEY_FILE_SIZE: u64 = 4096;
 
 /// Attempts authentication using a managed identity that has been assigned to the deployment environment.
 ///
 /// This authentication type works in Azure VMs, App Service and Azure Functions applications, as well as the Azure Cloud Shell
 ///
+/// It also works on the servers onboarded to [Azure Arc](https://docs.microsoft.com/azure/azure-arc/servers/managed-identity-authentication),
+/// where the `IDENTITY_ENDPOINT` and `IMDS_ENDPOINT` environment variables are set by the agent.
+/// The agent answers the first token request with a challenge, the path of a file only readable by
+/// the administrators and the members of the `himds` group, and the content of this file
+/// authenticates the second request. Arc servers only have a system assigned identity.
+///
 /// Built up from docs at [https://docs.microsoft.com/azure/app-service/overview-managed-identity#using-the-rest-protocol](https://docs.microsoft.com/azure/app-service/overview-managed-identity#using-the-rest-protocol)
 pub struct ImdsManagedIdentityCredential {
     http_client: Arc<dyn HttpClient>,
@@ -84,16 +95,14 @@ impl ImdsManagedIdentityCredential {
         self.client_id = None;
         self
     }
-}
-
-#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
-#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
-impl TokenCredential for ImdsManagedIdentityCredential {
-    async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
-        let msi_endpoint = std::env::var(MSI_ENDPOINT_ENV_KEY)
-            .unwrap_or_else(|_| "http://169.254.169.254/metadata/identity/oauth2/token".to_owned());
 
-        let mut query_items = vec![("api-version", MSI_API_VERSION), ("resource", resource)];
+    fn token_request(
+        &self,
+        msi_endpoint: &str,
+        api_version: &str,
+        resource: &str,
+    ) -> azure_core::Result<Request> {
+        let mut query_items = vec![("api-version", api_version), ("resource", resource)];
 
         match (
             self.object_id.as_ref(),
@@ -106,7 +115,7 @@ impl TokenCredential for ImdsManagedIdentityCredential {
             _ => (),
         }
 
-        let url = Url::parse_with_params(&msi_endpoint, &query_items).context(
+        let url = Url::parse_with_params(msi_endpoint, &query_items).context(
             ErrorKind::DataConversion,
             "error parsing url for MSI endpoint",
         )?;
@@ -114,13 +123,131 @@ impl TokenCredential for ImdsManagedIdentityCredential {
         let mut req = Request::new(url, Method::Get);
 
         req.insert_header("metadata", "true");
+        Ok(req)
+    }
 
-        let msi_secret = std::env::var(MSI_SECRET_ENV_KEY);
-        if let Ok(val) = msi_secret {
-            req.insert_header("x-identity-header", val);
-        };
+    /// Gets a token from the agent of an Azure Arc server, answering its challenge.
+    async fn arc_token(&self, msi_endpoint: &str, resource: &str) -> azure_core::Result<Response> {
+        if self.object_id.is_some() || self.client_id.is_some() || self.msi_res_id.is_some() {
+            return Err(Error::message(
+                ErrorKind::Credential,
+                "Azure Arc only supports the system assigned managed identity",
+            ));
+        }
 
+        let mut req = self.token_request(msi_endpoint, ARC_API_VERSION, resource)?;
         let rsp = self.http_client.execute_request(&req).await?;
+        if rsp.status() != StatusCode::Unauthorized {
+            return Ok(rsp);
+        }
+
+        let challenge = rsp
+            .headers()
+            .get_optional_str(&headers::WWW_AUTHENTICATE)
+            .ok_or_else(|| {
+                Error::message(
+                    ErrorKind::Credential,
+                    "the Azure Arc agent did not return a challenge",
+                )
+            })?;
+        let key_path = arc_key_path(challenge)?;
+        let key = read_arc_key(&key_path)?;
+        req.insert_header(headers::AUTHORIZATION, format!("Basic {key}"));
+        self.http_client.execute_request(&req).await
+    }
+}
+
+/// The path of the challenge file in the `Basic realm=<path>` challenge of the Azure Arc agent.
+///
+/// The path must be a `.key` file in the directory of the tokens of the agent, so that a process
+/// listening on the port of the agent cannot make the credential read another file.
+fn arc_key_path(challenge: &str) -> azure_core::Result<PathBuf> {
+    let path = challenge
+        .trim()
+        .strip_prefix("Basic realm=")
+        .map(|path| path.trim_matches('"'))
+        .filter(|path| !path.is_empty())
+        .ok_or_else(|| {
+            Error::with_message(ErrorKind::Credential, || {
+                format!("unexpected Azure Arc challenge: {challenge}")
+            })
+        })?;
+    let path = PathBuf::from(path);
+
+    let expected_dir = arc_key_directory()?;
+    if path.parent() != Some(expected_dir.as_path())
+        || path.extension().and_then(|extension| extension.to_str()) != Some("key")
+    {
+        return Err(Error::with_message(ErrorKind::Credential, || {
+            format!(
+                "the Azure Arc challenge file {} is not a .key file of {}",
+                path.display(),
+                expected_dir.display()
+            )
+        }));
+    }
+    Ok(path)
+}
+
+#[cfg(target_os = "windows")]
+fn arc_key_directory() -> azure_core::Result<PathBuf> {
+    let program_data = std::env::var("ProgramData").context(
+        ErrorKind::Credential,
+        "the ProgramData environment variable is not set",
+    )?;
+    Ok(Path::new(&program_data)
+        .join("AzureConnectedMachineAgent")
+        .join("Tokens"))
+}
+
+#[cfg(not(target_os = "windows"))]
+fn arc_key_directory() -> azure_core::Result<PathBuf> {
+    Ok(PathBuf::from("/var/opt/azcmagent/tokens"))
+}
+
+fn read_arc_key(path: &Path) -> azure_core::Result<String> {
+    let size = std::fs::metadata(path)
+        .with_context(ErrorKind::Credential, || {
+            format!(
+                "failed to read the Azure Arc challenge file {}",
+                path.display()
+            )
+        })?
+        .len();
+    if size > ARC_MAX_KEY_FILE_SIZE {
+        return Err(Error::with_message(ErrorKind::Credential, || {
+            format!(
+                "the Azure Arc challenge file {} is larger than {ARC_MAX_KEY_FILE_SIZE} bytes",
+                path.display()
+            )
+        }));
+    }
+    std::fs::read_to_string(path).with_context(ErrorKind::Credential, || {
+        format!(
+            "failed to read the Azure Arc challenge file {}",
+            path.display()
+        )
+    })
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl TokenCredential for ImdsManagedIdentityCredential {
+    async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
+        let msi_endpoint = std::env::var(MSI_ENDPOINT_ENV_KEY)
+            .unwrap_or_else(|_| "http://169.254.169.254/metadata/identity/oauth2/token".to_owned());
+        let msi_secret = std::env::var(MSI_SECRET_ENV_KEY);
+        let is_arc = msi_secret.is_err() && std::env::var(ARC_IMDS_ENDPOINT_ENV_KEY).is_ok();
+
+        let rsp = if is_arc {
+            self.arc_token(&msi_endpoint, resource).await?
+        } else {
+            let mut req = self.token_request(&msi_endpoint, MSI_API_VERSION, resource)?;
+            if let Ok(val) = msi_secret {
+                req.insert_header("x-identity-header", val);
+            };
+            self.http_client.execute_request(&req).await?
+        };
         let rsp_status = rsp.status();
         let rsp_body = rsp.into_body().collect().await?;
 
@@ -152,6 +279,21 @@ impl TokenCredential for ImdsManagedIdentityCredential {
             token_response.expires_on,
         ))
     }
+
+    /// The managed identity endpoints cache the tokens and don't accept claims, so the token
+    /// rejected with a claims challenge can't be replaced.
+    async fn get_token_with_options(
+        &self,
+        resource: &str,
+        options: &TokenRequestOptions,
+    ) -> azure_core::Result<TokenResponse> {
+        if options.get_claims().is_some() {
+            return Err(Error::with_message(ErrorKind::Credential, || {
+                format!("the managed identity token of {resource} was rejected with a claims challenge, which managed identities don't support")
+            }));
+        }
+        self.get_token(resource).await
+    }
 }
 
 fn expires_on_string<'de, D>(deserializer: D) -> std::result::Result<OffsetDateTime, D::Error>
@@ -186,6 +328,19 @@ mod tests {
         date: OffsetDateTime,
     }
 
+    #[test]
+    fn only_reads_key_files_of_the_arc_agent() {
+        let key_file = arc_key_directory().unwrap().join("identity.key");
+        let challenge = format!("Basic realm={}", key_file.display());
+        assert_eq!(arc_key_path(&challenge).unwrap(), key_file);
+
+        let outside = arc_key_directory().unwrap().join("..").join("secret.key");
+        assert!(arc_key_path(&format!("Basic realm={}", outside.display())).is_err());
+        let not_a_key = arc_key_directory().unwrap().join("identity.txt");
+        assert!(arc_key_path(&format!("Basic realm={}", not_a_key.display())).is_err());
+        assert!(arc_key_path("Bearer realm=\"\"").is_err());
+    }
+
     #[test]
     fn check_expires_on_string() {
         let as_string = r#"{"date": "1586984735"}"#;
@@ -0,0 +1,446 @@
+use super::persistent_token_cache::{load_refresh_token, save_refresh_token};
+use super::{SecretStore, TokenCredentialOptions};
+use crate::authorization_code_flow::{self, parse_redirect};
+use crate::refresh_token::{self, ExchangeParameters};
+use azure_core::auth::{AccessToken, TokenCredential, TokenRequestOptions, TokenResponse};
+use azure_core::error::{Error, ErrorKind, ResultExt};
+use azure_core::HttpClient;
+use oauth2::{AuthorizationCode, ClientId, CsrfToken, TokenResponse as _};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, ErrorKind as IoErrorKind, Write};
+use std::net::{TcpListener, TcpStream};
+use std::process::Command;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use time::OffsetDateTime;
+use url::Url;
+
+/// How long to wait for the user to sign in by default.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(300);
+/// The lifetime assumed for access tokens returned without one.
+const DEFAULT_EXPIRES_IN: Duration = Duration::from_secs(3600);
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+/// How long to wait for the request of a connection to the listener, at most: browsers open
+/// idle connections ahead of time, which must not hold up the redirect.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Enables authentication of a user to Azure Active Directory through the system browser, for
+/// desktop tools.
+///
+/// The credential opens the sign in page in the browser and listens on a localhost port for the
+/// redirect of the authorization code flow. The app registration must have `http://localhost` as
+/// a redirect URI of the "Mobile and desktop applications" platform.
+///
+/// The refresh tokens are kept in memory, so the user only signs in once for each resource. They
+/// can also be kept in a [`SecretStore`] with [`InteractiveBrowserCredential::persistent_cache`],
+/// so that the user doesn't sign in again every time the tool runs.
+///
+/// ```no_run
+/// use azure_core::auth::TokenCredential;
+/// use azure_identity::InteractiveBrowserCredential;
+///
+/// # async fn run() -> azure_core::Result<()> {
+/// let credential = InteractiveBrowserCredential::new(
+///     azure_core::new_http_client(),
+///     "tenant_id".to_owned(),
+///     "client_id".to_owned(),
+///     None,
+/// );
+/// let token = credential.get_token("https://management.azure.com").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct InteractiveBrowserCredential {
+    http_client: Arc<dyn HttpClient>,
+    tenant_id: String,
+    client_id: ClientId,
+    options: TokenCredentialOptions,
+    redirect_port: u16,
+    timeout: Duration,
+    refresh_tokens: async_lock::Mutex<HashMap<String, AccessToken>>,
+    secret_store: Option<Arc<dyn SecretStore>>,
+}
+
+impl InteractiveBrowserCredential {
+    /// Create a new `InteractiveBrowserCredential`.
+    pub fn new(
+        http_client: Arc<dyn HttpClient>,
+        tenant_id: String,
+        client_id: String,
+        options: Option<TokenCredentialOptions>,
+    ) -> Self {
+        Self {
+            http_client,
+            tenant_id,
+            client_id: ClientId::new(client_id),
+            options: options.unwrap_or_default(),
+            redirect_port: 0,
+            timeout: DEFAULT_TIMEOUT,
+            refresh_tokens: async_lock::Mutex::new(HashMap::new()),
+            secret_store: None,
+        }
+    }
+
+    /// Listen for the redirect on this port instead of a free port chosen by the system.
+    #[must_use]
+    pub fn redirect_port(mut self, redirect_port: u16) -> Self {
+        self.redirect_port = redirect_port;
+        self
+    }
+
+    /// Set how long to wait for the user to sign in.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Keep the refresh tokens in `store`, shared by the processes of the user.
+    #[must_use]
+    pub fn persistent_cache(mut self, store: Arc<dyn SecretStore>) -> Self {
+        self.secret_store = Some(store);
+        self
+    }
+
+    /// The key of the refresh token of `resource` in the secret store.
+    fn refresh_token_key(&self, resource: &str) -> String {
+        format!(
+            "{}/{}/{resource}/refresh_token",
+            self.tenant_id,
+            self.client_id.as_str()
+        )
+    }
+
+    fn store_refresh_token(&self, resource: &str, refresh_token: &AccessToken) {
+        if let Some(store) = &self.secret_store {
+            save_refresh_token(
+                store.as_ref(),
+                &self.refresh_token_key(resource),
+                refresh_token,
+            );
+        }
+    }
+
+    async fn sign_in(
+        &self,
+        resource: &str,
+        claims: Option<&str>,
+    ) -> azure_core::Result<(TokenResponse, Option<String>)> {
+        let listener = TcpListener::bind(("127.0.0.1", self.redirect_port)).context(
+            ErrorKind::Credential,
+            "failed to listen for the authorization redirect",
+        )?;
+        let port = listener
+            .local_addr()
+            .context(ErrorKind::Credential, "failed to get the redirect port")?
+            .port();
+        let redirect_url = Url::parse(&format!("http://localhost:{port}"))?;
+
+        let mut flow = authorization_code_flow::start_with_authority_host(
+            self.options.authority_host(),
+            self.client_id.clone(),
+            None,
+            &self.tenant_id,
+            redirect_url,
+            &format!("{resource}/.default offline_access"),
+        );
+        if let Some(claims) = claims {
+            flow.authorize_url
+                .query_pairs_mut()
+                .append_pair("claims", claims);
+        }
+        open_browser(&flow.authorize_url)?;
+
+        let (sender, receiver) = futures::channel::oneshot::channel();
+        let csrf_state = flow.csrf_state.clone();
+        let timeout = self.timeout;
+        std::thread::spawn(move || {
+            let _ = sender.send(wait_for_redirect(&listener, &csrf_state, timeout));
+        });
+        let code = receiver.await.map_err(|_| {
+            Error::message(
+                ErrorKind::Credential,
+                "the authorization redirect listener stopped",
+            )
+        })??;
+
+        let response = flow.exchange(self.http_client.clone(), code).await?;
+        let expires_in = response.expires_in().unwrap_or(DEFAULT_EXPIRES_IN);
+        Ok((
+            TokenResponse::new(
+                AccessToken::new(response.access_token().secret().to_owned()),
+                OffsetDateTime::now_utc() + expires_in,
+            ),
+            response
+                .refresh_token()
+                .map(|refresh_token| refresh_token.secret().to_owned()),
+        ))
+    }
+}
+
+impl std::fmt::Debug for InteractiveBrowserCredential {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InteractiveBrowserCredential")
+            .field("tenant_id", &self.tenant_id)
+            .field("client_id", &self.client_id)
+            .field("options", &self.options)
+            .field("redirect_port", &self.redirect_port)
+            .field("timeout", &self.timeout)
+            .field("secret_store", &self.secret_store)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl TokenCredential for InteractiveBrowserCredential {
+    async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
+        self.get_token_with_options(resource, &TokenRequestOptions::default())
+            .await
+    }
+
+    /// The claims are sent along with the refresh token, or the sign in if refreshing fails.
+    async fn get_token_with_options(
+        &self,
+        resource: &str,
+        options: &TokenRequestOptions,
+    ) -> azure_core::Result<TokenResponse> {
+        // the lock also prevents concurrent calls from opening several sign in pages
+        let mut refresh_tokens = self.refresh_tokens.lock().await;
+        if !refresh_tokens.contains_key(resource) {
+            if let Some(refresh_token) = self.secret_store.as_ref().and_then(|store| {
+                load_refresh_token(store.as_ref(), &self.refresh_token_key(resource))
+            }) {
+                refresh_tokens.insert(resource.to_owned(), refresh_token);
+            }
+        }
+        if let Some(refresh_token) = refresh_tokens.get(resource) {
+            match refresh_token::exchange_with_parameters(
+                self.http_client.clone(),
+                self.options.authority_host(),
+                &self.tenant_id,
+                self.client_id.as_str(),
+                None,
+                refresh_token,
+                ExchangeParameters {
+                    claims: options.get_claims(),
+                    ..ExchangeParameters::default()
+                },
+            )
+            .await
+            {
+                Ok(response) => {
+                    self.store_refresh_token(resource, response.refresh_token());
+                    refresh_tokens.insert(resource.to_owned(), response.refresh_token().clone());
+                    return Ok(TokenResponse::new(
+                        response.access_token().clone(),
+                        OffsetDateTime::now_utc() + Duration::from_secs(response.expires_in()),
+                    ));
+                }
+                Err(error) => {
+                    log::debug!("refreshing the token failed, signing in again: {error}");
+                    refresh_tokens.remove(resource);
+                }
+            }
+        }
+
+        let (token, refresh_token) = self.sign_in(resource, options.get_claims()).await?;
+        if let Some(refresh_token) = refresh_token {
+            let refresh_token = AccessToken::new(refresh_token);
+            self.store_refresh_token(resource, &refresh_token);
+            refresh_tokens.insert(resource.to_owned(), refresh_token);
+        }
+        Ok(token)
+    }
+}
+
+/// Opens `url` in the default browser of the system.
+fn open_browser(url: &Url) -> azure_core::Result<()> {
+    #[cfg(target_os = "windows")]
+    let mut command = {
+        let mut command = Command::new("rundll32");
+        command.arg("url.dll,FileProtocolHandler");
+        command
+    };
+    #[cfg(target_os = "macos")]
+    let mut command = Command::new("open");
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let mut command = Command::new("xdg-open");
+
+    command
+        .arg(url.as_str())
+        .spawn()
+        .with_context(ErrorKind::Credential, || {
+            format!("failed to open the browser, browse {url} to sign in")
+        })?;
+    Ok(())
+}
+
+/// Waits for the browser to be redirected to the listener, at most `timeout`.
+fn wait_for_redirect(
+    listener: &TcpListener,
+    csrf_state: &CsrfToken,
+    timeout: Duration,
+) -> azure_core::Result<AuthorizationCode> {
+    listener.set_nonblocking(true).context(
+        ErrorKind::Credential,
+        "failed to listen for the authorization redirect",
+    )?;
+    let deadline = Instant::now() + timeout;
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(Error::message(
+                ErrorKind::Credential,
+                "timed out waiting for the user to sign in",
+            ));
+        }
+        match listener.accept() {
+            Ok((stream, _)) => {
+                let request_timeout = REQUEST_TIMEOUT.min(remaining);
+                if let Some(code) = handle_redirect(stream, csrf_state, request_timeout)? {
+                    return Ok(code);
+                }
+            }
+            Err(error) if error.kind() == IoErrorKind::WouldBlock => {
+                std::thread::sleep(POLL_INTERVAL.min(remaining));
+            }
+            Err(error) => {
+                return Err(Error::full(
+                    ErrorKind::Credential,
+                    error,
+                    "failed to accept the authorization redirect",
+                ))
+            }
+        }
+    }
+}
+
+/// Answers a request of the browser, returning the authorization code if it is the redirect.
+///
+/// The request is read for at most `timeout`. Connections which do not carry the redirect, like
+/// the idle connections opened ahead of time by browsers, requests of other resources or requests
+/// without the CSRF state of the flow, are answered (when possible) and ignored.
+fn handle_redirect(
+    mut stream: TcpStream,
+    csrf_state: &CsrfToken,
+    timeout: Duration,
+) -> azure_core::Result<Option<AuthorizationCode>> {
+    let mut request_line = String::new();
+    let read = stream
+        .set_nonblocking(false)
+        .and_then(|_| stream.set_read_timeout(Some(timeout)))
+        .and_then(|_| stream.set_write_timeout(Some(timeout)))
+        .and_then(|_| BufReader::new(&stream).read_line(&mut request_line));
+    if let Err(error) = read {
+        log::debug!("ignoring a connection without the authorization redirect: {error}");
+        return Ok(None);
+    }
+    let url = match request_line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|path| Url::parse(&format!("http://localhost{path}")).ok())
+    {
+        Some(url) => url,
+        None => {
+            log::debug!("ignoring a connection without the authorization redirect");
+            let _ = stream.write_all(b"HTTP/1.1 400 Bad Request\r\ncontent-length: 0\r\n\r\n");
+            return Ok(None);
+        }
+    };
+
+    // browsers also ask for other resources, like the favicon
+    let has_csrf_state = url
+        .query_pairs()
+        .any(|(key, value)| key == "state" && value == csrf_state.secret().as_str());
+    if url.path() != "/" || !has_csrf_state {
+        let _ = stream.write_all(b"HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\n\r\n");
+        return Ok(None);
+    }
+
+    let code = parse_redirect(&url, csrf_state);
+    let message = match &code {
+        Ok(_) => "Authentication complete. You can close this window now.",
+        Err(_) => "Authentication failed. You can close this window now.",
+    };
+    let response = format!(
+        "HTTP/1.1 200 OK\r\ncontent-length: {}\r\n\r\n{}",
+        message.len(),
+        message
+    );
+    let _ = stream.write_all(response.as_bytes());
+    code.map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    fn send(listener: &TcpListener, request: &[u8]) -> std::thread::JoinHandle<String> {
+        let mut stream = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let request = request.to_vec();
+        std::thread::spawn(move || {
+            stream.write_all(&request).unwrap();
+            let mut response = String::new();
+            let _ = stream.read_to_string(&mut response);
+            response
+        })
+    }
+
+    #[test]
+    fn ignores_the_connections_without_the_redirect() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let csrf_state = CsrfToken::new("state".to_owned());
+
+        // an idle connection, opened ahead of time by the browser
+        let idle = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let favicon = send(&listener, b"GET /favicon.ico HTTP/1.1\r\n\r\n");
+        let malformed = send(&listener, b"\r\n");
+        let forged = send(&listener, b"GET /?code=forged&state=other HTTP/1.1\r\n\r\n");
+        let redirect = send(&listener, b"GET /?code=code&state=state HTTP/1.1\r\n\r\n");
+
+        let started = Instant::now();
+        let code = wait_for_redirect(&listener, &csrf_state, Duration::from_secs(10)).unwrap();
+        assert_eq!(code.secret(), "code");
+        // only the request of the idle connection is waited for
+        assert!(started.elapsed() < REQUEST_TIMEOUT + Duration::from_secs(1));
+
+        drop(idle);
+        assert!(favicon.join().unwrap().starts_with("HTTP/1.1 404"));
+        assert!(malformed.join().unwrap().starts_with("HTTP/1.1 400"));
+        assert!(forged.join().unwrap().starts_with("HTTP/1.1 404"));
+        assert!(redirect
+            .join()
+            .unwrap()
+            .ends_with("Authentication complete. You can close this window now."));
+    }
+
+    #[test]
+    fn fails_with_the_authorization_error() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let csrf_state = CsrfToken::new("state".to_owned());
+
+        let redirect = send(
+            &listener,
+            b"GET /?error=access_denied&state=state HTTP/1.1\r\n\r\n",
+        );
+
+        let error = wait_for_redirect(&listener, &csrf_state, Duration::from_secs(1)).unwrap_err();
+        assert!(error.to_string().contains("access_denied"));
+        assert!(redirect.join().unwrap().starts_with("HTTP/1.1 200"));
+    }
+
+    #[test]
+    fn times_out_on_an_idle_connection() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let csrf_state = CsrfToken::new("state".to_owned());
+        let _idle = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+
+        let started = Instant::now();
+        let error =
+            wait_for_redirect(&listener, &csrf_state, Duration::from_millis(300)).unwrap_err();
+        assert!(error.to_string().contains("timed out"));
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+}
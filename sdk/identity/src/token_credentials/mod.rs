@@ -5,20 +5,51 @@
 //! * Azure CLI credentials cache
 //! * Managed identity
 //! * Client secret
+//! * A client assertion, e.g. the OIDC token of a GitHub Actions workflow
+//! * Interactive sign in through the browser
+//! * An authorization code redeemed for the user who signed in
+//! * Workload identity federation
+//! * On behalf of the caller of a web API
+//! * The username and password of a user
+//! * A chain of the above
+//!
+//! The tokens can be cached in memory, or in the credential store of the system to share them
+//! between processes.
+mod authorization_code_credentials;
 mod auto_refreshing_credentials;
 mod azure_cli_credentials;
+mod chained_token_credentials;
+mod client_assertion_credentials;
 #[cfg(feature = "client_certificate")]
 mod client_certificate_credentials;
 mod client_secret_credentials;
 mod default_credentials;
 mod environment_credentials;
 mod imds_managed_identity_credentials;
+#[cfg(not(target_arch = "wasm32"))]
+mod interactive_browser_credentials;
+mod on_behalf_of_credentials;
+mod persistent_token_cache;
+mod token_cache;
+mod token_request;
+mod username_password_credentials;
+mod workload_identity_credentials;
 
+pub use authorization_code_credentials::*;
 pub use auto_refreshing_credentials::*;
 pub use azure_cli_credentials::*;
+pub use chained_token_credentials::*;
+pub use client_assertion_credentials::*;
 #[cfg(feature = "client_certificate")]
 pub use client_certificate_credentials::*;
 pub use client_secret_credentials::*;
 pub use default_credentials::*;
 pub use environment_credentials::*;
 pub use imds_managed_identity_credentials::*;
+#[cfg(not(target_arch = "wasm32"))]
+pub use interactive_browser_credentials::*;
+pub use on_behalf_of_credentials::*;
+pub use persistent_token_cache::*;
+pub use token_cache::*;
+pub use username_password_credentials::*;
+pub use workload_identity_credentials::*;
@@ -0,0 +1,139 @@
+use super::token_request::{request_token, token_request_body};
+use super::TokenCredentialOptions;
+use azure_core::auth::{TokenCredential, TokenRequestOptions, TokenResponse};
+use azure_core::HttpClient;
+use std::sync::Arc;
+
+const JWT_BEARER_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:jwt-bearer";
+
+/// Enables a middle-tier web API to call other APIs as the user who called it, using the
+/// [on-behalf-of flow](https://docs.microsoft.com/azure/active-directory/develop/v2-oauth2-on-behalf-of-flow).
+///
+/// The access token the web API received from its caller, the user assertion, is exchanged for a
+/// token of the downstream resource, e.g. storage or key vault, on behalf of the same user. The
+/// app registration of the web API authenticates with a client secret, and must be granted the
+/// delegated permissions of the downstream APIs.
+///
+/// A credential is created for each incoming request, with the assertion of its caller:
+///
+/// ```no_run
+/// use azure_core::auth::TokenCredential;
+/// use azure_identity::{OnBehalfOfCredential, TokenCredentialOptions};
+///
+/// # async fn run(user_assertion: String) -> azure_core::Result<()> {
+/// let credential = OnBehalfOfCredential::new(
+///     azure_core::new_http_client(),
+///     "tenant_id".to_owned(),
+///     "client_id".to_owned(),
+///     "client_secret".to_owned(),
+///     user_assertion,
+///     TokenCredentialOptions::default(),
+/// );
+/// let token = credential.get_token("https://storage.azure.com").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct OnBehalfOfCredential {
+    http_client: Arc<dyn HttpClient>,
+    tenant_id: String,
+    client_id: String,
+    client_secret: String,
+    user_assertion: String,
+    options: TokenCredentialOptions,
+}
+
+impl OnBehalfOfCredential {
+    /// Create a new `OnBehalfOfCredential` exchanging `user_assertion`, the access token of the
+    /// caller of the web API.
+    pub fn new(
+        http_client: Arc<dyn HttpClient>,
+        tenant_id: String,
+        client_id: String,
+        client_secret: String,
+        user_assertion: String,
+        options: TokenCredentialOptions,
+    ) -> Self {
+        Self {
+            http_client,
+            tenant_id,
+            client_id,
+            client_secret,
+            user_assertion,
+            options,
+        }
+    }
+
+    fn grant_parameters(&self) -> [(&str, &str); 5] {
+        [
+            ("grant_type", JWT_BEARER_GRANT_TYPE),
+            ("client_id", &self.client_id),
+            ("client_secret", &self.client_secret),
+            ("assertion", &self.user_assertion),
+            ("requested_token_use", "on_behalf_of"),
+        ]
+    }
+}
+
+impl std::fmt::Debug for OnBehalfOfCredential {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OnBehalfOfCredential")
+            .field("tenant_id", &self.tenant_id)
+            .field("client_id", &self.client_id)
+            .field("options", &self.options)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl TokenCredential for OnBehalfOfCredential {
+    async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
+        self.get_token_with_options(resource, &TokenRequestOptions::default())
+            .await
+    }
+
+    async fn get_token_with_options(
+        &self,
+        resource: &str,
+        options: &TokenRequestOptions,
+    ) -> azure_core::Result<TokenResponse> {
+        request_token(
+            self.http_client.as_ref(),
+            self.options.authority_host(),
+            &self.tenant_id,
+            token_request_body(&self.grant_parameters(), resource, options.get_claims()),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token_credentials::token_request::tests::{parameters, FakeTokenEndpoint};
+
+    #[tokio::test]
+    async fn exchanges_the_user_assertion() -> azure_core::Result<()> {
+        let endpoint = Arc::new(FakeTokenEndpoint::default());
+        let credential = OnBehalfOfCredential::new(
+            endpoint.clone(),
+            "tenant".to_owned(),
+            "client".to_owned(),
+            "secret".to_owned(),
+            "header.payload.signature".to_owned(),
+            TokenCredentialOptions::default(),
+        );
+        let token = credential.get_token("https://storage.azure.com").await?;
+        assert_eq!(token.token.secret(), "TOKEN");
+
+        let (_, body) = &endpoint.requests()[0];
+        let pairs = parameters(body);
+        assert_eq!(pairs["grant_type"], JWT_BEARER_GRANT_TYPE);
+        assert_eq!(pairs["client_id"], "client");
+        assert_eq!(pairs["client_secret"], "secret");
+        assert_eq!(pairs["assertion"], "header.payload.signature");
+        assert_eq!(pairs["scope"], "https://storage.azure.com/.default");
+        assert_eq!(pairs["requested_token_use"], "on_behalf_of");
+        Ok(())
+    }
+}
@@ -0,0 +1,313 @@
+use azure_core::auth::{AccessToken, TokenCredential, TokenRequestOptions, TokenResponse};
+#[cfg(feature = "persistent_token_cache")]
+use azure_core::error::{Error, ErrorKind, ResultExt};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Duration;
+use time::OffsetDateTime;
+
+/// Tokens expiring within this margin are not returned from the cache.
+const EXPIRY_MARGIN: Duration = Duration::from_secs(20);
+
+/// A store of secrets shared by the processes of a user, e.g. the keychain of the system.
+///
+/// The secrets are identified by a key, unique within the store.
+pub trait SecretStore: std::fmt::Debug + Send + Sync {
+    /// The secret stored for `key`, if any.
+    fn load(&self, key: &str) -> azure_core::Result<Option<String>>;
+    /// Store `secret` for `key`, replacing the previous one.
+    fn save(&self, key: &str, secret: &str) -> azure_core::Result<()>;
+    /// Remove the secret stored for `key`, if any.
+    fn delete(&self, key: &str) -> azure_core::Result<()>;
+}
+
+/// Stores the secrets in the credential store of the system, encrypted for the current user: the
+/// Credential Manager on Windows, which uses DPAPI, the keychain on macOS and the Secret Service,
+/// e.g. GNOME Keyring or KWallet, on Linux.
+///
+/// The secrets are stored as passwords of the `service`, with their key as account name.
+#[cfg(feature = "persistent_token_cache")]
+#[derive(Debug, Clone)]
+pub struct KeyringSecretStore {
+    service: String,
+}
+
+#[cfg(feature = "persistent_token_cache")]
+impl KeyringSecretStore {
+    /// Create a new `KeyringSecretStore` for the secrets of `service`, e.g. the name of the tool.
+    pub fn new(service: impl Into<String>) -> Self {
+        Self {
+            service: service.into(),
+        }
+    }
+
+    fn entry(&self, key: &str) -> keyring::Entry {
+        keyring::Entry::new(&self.service, key)
+    }
+}
+
+#[cfg(feature = "persistent_token_cache")]
+impl SecretStore for KeyringSecretStore {
+    fn load(&self, key: &str) -> azure_core::Result<Option<String>> {
+        match self.entry(key).get_password() {
+            Ok(secret) => Ok(Some(secret)),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(error) => Err(Error::full(
+                ErrorKind::Credential,
+                error,
+                format!("failed to read {key} from the credential store"),
+            )),
+        }
+    }
+
+    fn save(&self, key: &str, secret: &str) -> azure_core::Result<()> {
+        self.entry(key)
+            .set_password(secret)
+            .with_context(ErrorKind::Credential, || {
+                format!("failed to write {key} to the credential store")
+            })
+    }
+
+    fn delete(&self, key: &str) -> azure_core::Result<()> {
+        match self.entry(key).delete_password() {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(error) => Err(Error::full(
+                ErrorKind::Credential,
+                error,
+                format!("failed to delete {key} from the credential store"),
+            )),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredToken {
+    access_token: AccessToken,
+    #[serde(with = "azure_core::date::rfc3339")]
+    expires_on: OffsetDateTime,
+}
+
+/// Caches the tokens of a credential in a [`SecretStore`], so that they are shared by the
+/// processes of the user, e.g. by the successive runs of a command line tool.
+///
+/// The tokens are stored under `{name}/{resource}`, where `name` identifies the credential, e.g.
+/// its tenant and client ids, so that the tokens of different credentials don't overwrite each
+/// other. A token is requested from the credential when there is none stored or when it expires.
+/// Failing to read or write the store is logged and does not fail the token request. A request
+/// with claims bypasses the stored token, which was rejected, and replaces it.
+///
+/// ```no_run
+/// # #[cfg(feature = "persistent_token_cache")]
+/// # {
+/// use azure_identity::{InteractiveBrowserCredential, KeyringSecretStore, PersistentTokenCache};
+/// use std::sync::Arc;
+///
+/// let store = Arc::new(KeyringSecretStore::new("mytool"));
+/// let credential = InteractiveBrowserCredential::new(
+///     azure_core::new_http_client(),
+///     "tenant_id".to_owned(),
+///     "client_id".to_owned(),
+///     None,
+/// )
+/// .persistent_cache(store.clone());
+/// let credential = PersistentTokenCache::new(Arc::new(credential), store, "tenant_id/client_id");
+/// # }
+/// ```
+pub struct PersistentTokenCache {
+    credential: Arc<dyn TokenCredential>,
+    store: Arc<dyn SecretStore>,
+    name: String,
+}
+
+impl PersistentTokenCache {
+    /// Create a new `PersistentTokenCache` storing the tokens of `credential` in `store`.
+    pub fn new(
+        credential: Arc<dyn TokenCredential>,
+        store: Arc<dyn SecretStore>,
+        name: impl Into<String>,
+    ) -> Self {
+        Self {
+            credential,
+            store,
+            name: name.into(),
+        }
+    }
+
+    /// Remove the stored token of `resource`.
+    pub fn clear(&self, resource: &str) -> azure_core::Result<()> {
+        self.store.delete(&self.key(resource))
+    }
+
+    fn key(&self, resource: &str) -> String {
+        format!("{}/{resource}", self.name)
+    }
+
+    fn load(&self, resource: &str) -> azure_core::Result<Option<TokenResponse>> {
+        let stored = match self.store.load(&self.key(resource))? {
+            Some(stored) => stored,
+            None => return Ok(None),
+        };
+        let stored: StoredToken = serde_json::from_str(&stored)?;
+        Ok(Some(TokenResponse::new(
+            stored.access_token,
+            stored.expires_on,
+        )))
+    }
+
+    fn save(&self, resource: &str, token: &TokenResponse) -> azure_core::Result<()> {
+        let stored = serde_json::to_string(&StoredToken {
+            access_token: token.token.clone(),
+            expires_on: token.expires_on,
+        })?;
+        self.store.save(&self.key(resource), &stored)
+    }
+}
+
+impl std::fmt::Debug for PersistentTokenCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("PersistentTokenCache")
+            .field("credential", &"TokenCredential")
+            .field("store", &self.store)
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl TokenCredential for PersistentTokenCache {
+    async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
+        self.get_token_with_options(resource, &TokenRequestOptions::default())
+            .await
+    }
+
+    async fn get_token_with_options(
+        &self,
+        resource: &str,
+        options: &TokenRequestOptions,
+    ) -> azure_core::Result<TokenResponse> {
+        if options.get_claims().is_none() {
+            match self.load(resource) {
+                Ok(Some(token)) if token.expires_on > OffsetDateTime::now_utc() + EXPIRY_MARGIN => {
+                    return Ok(token)
+                }
+                Ok(_) => {}
+                Err(error) => log::debug!("reading the stored token failed: {error}"),
+            }
+        }
+
+        let token = self
+            .credential
+            .get_token_with_options(resource, options)
+            .await?;
+        if let Err(error) = self.save(resource, &token) {
+            log::debug!("storing the token failed: {error}");
+        }
+        Ok(token)
+    }
+}
+
+/// Loads the refresh token stored for `key`, logging the failures.
+pub(crate) fn load_refresh_token(store: &dyn SecretStore, key: &str) -> Option<AccessToken> {
+    store
+        .load(key)
+        .map_err(|error| log::debug!("reading the stored refresh token failed: {error}"))
+        .ok()
+        .flatten()
+        .map(AccessToken::new)
+}
+
+/// Stores `refresh_token` for `key`, logging the failures.
+pub(crate) fn save_refresh_token(store: &dyn SecretStore, key: &str, refresh_token: &AccessToken) {
+    if let Err(error) = store.save(key, refresh_token.secret()) {
+        log::debug!("storing the refresh token failed: {error}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    #[derive(Debug, Default)]
+    struct MemoryStore(Mutex<HashMap<String, String>>);
+
+    impl SecretStore for MemoryStore {
+        fn load(&self, key: &str) -> azure_core::Result<Option<String>> {
+            Ok(self.0.lock().unwrap().get(key).cloned())
+        }
+
+        fn save(&self, key: &str, secret: &str) -> azure_core::Result<()> {
+            self.0
+                .lock()
+                .unwrap()
+                .insert(key.to_owned(), secret.to_owned());
+            Ok(())
+        }
+
+        fn delete(&self, key: &str) -> azure_core::Result<()> {
+            self.0.lock().unwrap().remove(key);
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeCredential {
+        calls: AtomicUsize,
+    }
+
+    #[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+    impl TokenCredential for FakeCredential {
+        async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(TokenResponse::new(
+                AccessToken::new(format!("{resource} {call}")),
+                OffsetDateTime::now_utc() + time::Duration::hours(1),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn shares_tokens_through_the_store() {
+        let store = Arc::new(MemoryStore::default());
+        let credential = Arc::new(FakeCredential::default());
+
+        let cache = PersistentTokenCache::new(credential.clone(), store.clone(), "tenant/client");
+        assert_eq!(cache.get_token("a").await.unwrap().token.secret(), "a 0");
+        assert!(store.load("tenant/client/a").unwrap().is_some());
+
+        // another process reads the stored token
+        let cache = PersistentTokenCache::new(credential.clone(), store.clone(), "tenant/client");
+        assert_eq!(cache.get_token("a").await.unwrap().token.secret(), "a 0");
+        assert_eq!(credential.calls.load(Ordering::SeqCst), 1);
+
+        cache.clear("a").unwrap();
+        assert_eq!(cache.get_token("a").await.unwrap().token.secret(), "a 1");
+
+        // expired tokens are requested again
+        cache
+            .save(
+                "a",
+                &TokenResponse::new(AccessToken::new("expired"), OffsetDateTime::now_utc()),
+            )
+            .unwrap();
+        assert_eq!(cache.get_token("a").await.unwrap().token.secret(), "a 2");
+    }
+
+    #[tokio::test]
+    async fn replaces_the_token_rejected_with_a_claims_challenge() {
+        let store = Arc::new(MemoryStore::default());
+        let credential = Arc::new(FakeCredential::default());
+        let cache = PersistentTokenCache::new(credential.clone(), store, "tenant/client");
+        assert_eq!(cache.get_token("a").await.unwrap().token.secret(), "a 0");
+
+        let options = TokenRequestOptions::new().claims(r#"{"access_token":{}}"#);
+        let token = cache.get_token_with_options("a", &options).await.unwrap();
+        assert_eq!(token.token.secret(), "a 1");
+        assert_eq!(cache.get_token("a").await.unwrap().token.secret(), "a 1");
+        assert_eq!(credential.calls.load(Ordering::SeqCst), 2);
+    }
+}
@@ -0,0 +1,312 @@
+use async_lock::{Mutex, RwLock};
+use azure_core::auth::{TokenCredential, TokenRequestOptions, TokenResponse};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use time::OffsetDateTime;
+
+/// Tokens expiring within this margin are not returned from the cache.
+const EXPIRY_MARGIN: Duration = Duration::from_secs(20);
+/// The delay before a failed refresh is retried.
+const REFRESH_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone)]
+struct CachedToken {
+    token: TokenResponse,
+    /// Half of the lifetime of the token, after which it is refreshed.
+    refresh_after: OffsetDateTime,
+}
+
+impl CachedToken {
+    fn new(token: TokenResponse) -> Self {
+        let now = OffsetDateTime::now_utc();
+        let refresh_after = now + (token.expires_on - now) / 2;
+        Self {
+            token,
+            refresh_after,
+        }
+    }
+
+    fn is_expired(&self) -> bool {
+        self.token.expires_on < OffsetDateTime::now_utc() + EXPIRY_MARGIN
+    }
+
+    fn needs_refresh(&self) -> bool {
+        self.refresh_after < OffsetDateTime::now_utc()
+    }
+
+    /// Postpones the next refresh after a failed one, without going past the expiry margin.
+    fn back_off(&mut self) {
+        let retry_at = OffsetDateTime::now_utc() + REFRESH_RETRY_DELAY;
+        self.refresh_after = retry_at.min(self.token.expires_on - EXPIRY_MARGIN);
+    }
+}
+
+#[derive(Debug, Default)]
+struct CacheEntry {
+    token: RwLock<Option<CachedToken>>,
+    /// Held while a token is requested, so that a single request is made at a time.
+    refreshing: Mutex<()>,
+}
+
+impl CacheEntry {
+    /// Whether the entry has no valid token and no call uses it.
+    fn is_evictable(self: &Arc<Self>) -> bool {
+        if Arc::strong_count(self) > 1 {
+            return false;
+        }
+        match self.token.try_read() {
+            Some(token) => token.as_ref().map_or(true, CachedToken::is_expired),
+            None => false,
+        }
+    }
+}
+
+/// Caches the tokens of a credential for each resource.
+///
+/// Concurrent calls for the same resource share a single token request, and tokens are refreshed
+/// once half of their lifetime has passed: the call which starts the refresh waits for it, while
+/// the others keep using the cached token until it expires. If the refresh fails, the cached
+/// token is used until it expires, and the refresh is retried after a delay rather than on every
+/// call.
+///
+/// The entries of the resources whose token expired, or could not be requested, are evicted when
+/// a token is requested for a new resource.
+///
+/// Unlike `AutoRefreshingTokenCredential`, a token is cached for each resource.
+///
+/// ```no_run
+/// use azure_identity::{DefaultAzureCredential, TokenCache};
+/// use std::sync::Arc;
+///
+/// let credential = Arc::new(TokenCache::new(Arc::new(DefaultAzureCredential::default())));
+/// ```
+pub struct TokenCache {
+    credential: Arc<dyn TokenCredential>,
+    entries: std::sync::Mutex<HashMap<String, Arc<CacheEntry>>>,
+}
+
+impl TokenCache {
+    /// Create a new `TokenCache` around `credential`.
+    pub fn new(credential: Arc<dyn TokenCredential>) -> Self {
+        Self {
+            credential,
+            entries: std::sync::Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Remove all the cached tokens.
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    fn entry(&self, resource: &str) -> Arc<CacheEntry> {
+        let mut entries = self.entries.lock().unwrap();
+        if let Some(entry) = entries.get(resource) {
+            return entry.clone();
+        }
+        // the cache only grows with new resources, so this is where it is trimmed
+        entries.retain(|_, entry| !entry.is_evictable());
+        entries.entry(resource.to_owned()).or_default().clone()
+    }
+
+    async fn request_token(
+        &self,
+        entry: &CacheEntry,
+        resource: &str,
+        options: &TokenRequestOptions,
+    ) -> azure_core::Result<TokenResponse> {
+        let token = self
+            .credential
+            .get_token_with_options(resource, options)
+            .await?;
+        *entry.token.write().await = Some(CachedToken::new(token.clone()));
+        Ok(token)
+    }
+}
+
+impl std::fmt::Debug for TokenCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("TokenCache")
+            .field("credential", &"TokenCredential")
+            .finish()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl TokenCredential for TokenCache {
+    async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
+        self.get_token_with_options(resource, &TokenRequestOptions::default())
+            .await
+    }
+
+    async fn get_token_with_options(
+        &self,
+        resource: &str,
+        options: &TokenRequestOptions,
+    ) -> azure_core::Result<TokenResponse> {
+        let entry = self.entry(resource);
+        if options.get_claims().is_some() {
+            // the cached token was rejected, it is replaced by the token with the claims
+            let _guard = entry.refreshing.lock().await;
+            return self.request_token(&entry, resource, options).await;
+        }
+
+        let cached = entry.token.read().await.clone();
+        if let Some(cached) = cached.filter(|cached| !cached.is_expired()) {
+            if !cached.needs_refresh() {
+                return Ok(cached.token);
+            }
+            // refresh proactively, unless another call already does
+            return match entry.refreshing.try_lock() {
+                Some(_guard) => match self.request_token(&entry, resource, options).await {
+                    Ok(token) => Ok(token),
+                    Err(error) => {
+                        log::debug!("refreshing the token failed, using the cached one: {error}");
+                        if let Some(cached) = entry.token.write().await.as_mut() {
+                            cached.back_off();
+                        }
+                        Ok(cached.token)
+                    }
+                },
+                None => Ok(cached.token),
+            };
+        }
+
+        let _guard = entry.refreshing.lock().await;
+        // another call may have requested a token while this one was waiting
+        if let Some(cached) = entry.token.read().await.as_ref() {
+            if !cached.is_expired() && !cached.needs_refresh() {
+                return Ok(cached.token.clone());
+            }
+        }
+        self.request_token(&entry, resource, options).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::auth::AccessToken;
+    use azure_core::error::{Error, ErrorKind};
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    struct FakeCredential {
+        lifetime: time::Duration,
+        calls: AtomicUsize,
+        failing: AtomicBool,
+    }
+
+    impl FakeCredential {
+        fn new(lifetime: time::Duration) -> Arc<Self> {
+            Arc::new(Self {
+                lifetime,
+                calls: AtomicUsize::new(0),
+                failing: AtomicBool::new(false),
+            })
+        }
+
+        fn calls(&self) -> usize {
+            self.calls.load(Ordering::SeqCst)
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+    impl TokenCredential for FakeCredential {
+        async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            azure_core::sleep::sleep(Duration::from_millis(10)).await;
+            if self.failing.load(Ordering::SeqCst) {
+                return Err(Error::message(ErrorKind::Credential, "failing"));
+            }
+            Ok(TokenResponse::new(
+                AccessToken::new(format!("{resource} {call}")),
+                OffsetDateTime::now_utc() + self.lifetime,
+            ))
+        }
+    }
+
+    async fn expire_half_life(cache: &TokenCache, resource: &str) {
+        let entry = cache.entry(resource);
+        let mut token = entry.token.write().await;
+        if let Some(cached) = token.as_mut() {
+            cached.refresh_after = OffsetDateTime::now_utc() - time::Duration::seconds(1);
+        }
+    }
+
+    #[tokio::test]
+    async fn shares_concurrent_token_requests() {
+        let credential = FakeCredential::new(time::Duration::hours(1));
+        let cache = TokenCache::new(credential.clone());
+
+        let tokens = futures::future::join_all((0..10).map(|_| cache.get_token("a"))).await;
+        assert!(tokens.iter().all(|token| token.is_ok()));
+        assert_eq!(credential.calls(), 1);
+
+        cache.get_token("b").await.unwrap();
+        assert_eq!(credential.calls(), 2);
+        assert_eq!(cache.get_token("a").await.unwrap().token.secret(), "a 0");
+        assert_eq!(credential.calls(), 2);
+    }
+
+    #[tokio::test]
+    async fn refreshes_tokens_after_half_of_their_lifetime() {
+        let credential = FakeCredential::new(time::Duration::seconds(60));
+        let cache = TokenCache::new(credential.clone());
+        cache.get_token("a").await.unwrap();
+        let cached = cache.entry("a").token.read().await.clone().unwrap();
+        assert!(cached.refresh_after < cached.token.expires_on);
+
+        expire_half_life(&cache, "a").await;
+        assert_eq!(cache.get_token("a").await.unwrap().token.secret(), "a 1");
+
+        // a failed refresh returns the token which is still valid
+        expire_half_life(&cache, "a").await;
+        credential.failing.store(true, Ordering::SeqCst);
+        assert_eq!(cache.get_token("a").await.unwrap().token.secret(), "a 1");
+        assert_eq!(credential.calls(), 3);
+
+        // and is not retried on every call
+        assert_eq!(cache.get_token("a").await.unwrap().token.secret(), "a 1");
+        assert_eq!(credential.calls(), 3);
+        let cached = cache.entry("a").token.read().await.clone().unwrap();
+        assert!(cached.refresh_after > OffsetDateTime::now_utc());
+        assert!(cached.refresh_after <= cached.token.expires_on - EXPIRY_MARGIN);
+    }
+
+    #[tokio::test]
+    async fn evicts_the_entries_without_a_valid_token() {
+        let credential = FakeCredential::new(time::Duration::hours(1));
+        let cache = TokenCache::new(credential.clone());
+        cache.get_token("a").await.unwrap();
+        cache.get_token("b").await.unwrap();
+        credential.failing.store(true, Ordering::SeqCst);
+        cache.get_token("c").await.unwrap_err();
+
+        // expire the token of `a`
+        if let Some(cached) = cache.entry("a").token.write().await.as_mut() {
+            cached.token.expires_on = OffsetDateTime::now_utc();
+        }
+
+        credential.failing.store(false, Ordering::SeqCst);
+        cache.get_token("d").await.unwrap();
+        let mut resources: Vec<_> = cache.entries.lock().unwrap().keys().cloned().collect();
+        resources.sort();
+        assert_eq!(resources, ["b", "d"]);
+    }
+
+    #[tokio::test]
+    async fn replaces_the_token_rejected_with_a_claims_challenge() {
+        let credential = FakeCredential::new(time::Duration::hours(1));
+        let cache = TokenCache::new(credential.clone());
+        cache.get_token("a").await.unwrap();
+
+        let options = TokenRequestOptions::new().claims(r#"{"access_token":{}}"#);
+        let token = cache.get_token_with_options("a", &options).await.unwrap();
+        assert_eq!(token.token.secret(), "a 1");
+        assert_eq!(cache.get_token("a").await.unwrap().token.secret(), "a 1");
+        assert_eq!(credential.calls(), 2);
+    }
+}
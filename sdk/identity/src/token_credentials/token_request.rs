@@ -0,0 +1,165 @@
+//! The token requests of the credentials granting access tokens with a form posted to the token
+//! endpoint of the tenant.
+
+use azure_core::auth::{AccessToken, TokenResponse};
+use azure_core::error::{ErrorKind, ResultExt};
+use azure_core::{content_type, headers, HttpClient, Method, Request};
+use serde::Deserialize;
+use std::time::Duration;
+use time::OffsetDateTime;
+use url::{form_urlencoded, Url};
+
+#[derive(Deserialize, Debug)]
+struct AadTokenResponse {
+    expires_in: u64,
+    access_token: String,
+}
+
+/// Requests an access token from the token endpoint of `tenant_id`, with a body built by
+/// [`token_request_body`].
+#[allow(clippy::manual_async_fn)]
+#[fix_hidden_lifetime_bug::fix_hidden_lifetime_bug]
+pub(crate) async fn request_token(
+    http_client: &dyn HttpClient,
+    authority_host: &str,
+    tenant_id: &str,
+    body: String,
+) -> azure_core::Result<TokenResponse> {
+    let url = Url::parse(&format!("{authority_host}/{tenant_id}/oauth2/v2.0/token"))
+        .with_context(ErrorKind::Credential, || {
+            format!("failed to construct token endpoint with tenant id {tenant_id}")
+        })?;
+
+    let mut req = Request::new(url, Method::Post);
+    req.insert_header(
+        headers::CONTENT_TYPE,
+        content_type::APPLICATION_X_WWW_FORM_URLENCODED,
+    );
+    req.set_body(body);
+
+    let rsp = http_client.execute_request(&req).await?;
+    let rsp_status = rsp.status();
+    let rsp_body = rsp.into_body().collect().await?;
+    if !rsp_status.is_success() {
+        return Err(ErrorKind::http_response_from_body(rsp_status, &rsp_body).into_error());
+    }
+
+    let response: AadTokenResponse =
+        serde_json::from_slice(&rsp_body).map_kind(ErrorKind::Credential)?;
+    Ok(TokenResponse::new(
+        AccessToken::new(response.access_token),
+        OffsetDateTime::now_utc() + Duration::from_secs(response.expires_in),
+    ))
+}
+
+/// The body of a token request for `resource` with the `parameters` of a grant, and the
+/// additional `claims` of the token if any.
+pub(crate) fn token_request_body(
+    parameters: &[(&str, &str)],
+    resource: &str,
+    claims: Option<&str>,
+) -> String {
+    let mut body = form_urlencoded::Serializer::new(String::new());
+    body.extend_pairs(parameters)
+        .append_pair("scope", &format!("{resource}/.default"));
+    if let Some(claims) = claims {
+        body.append_pair("claims", claims);
+    }
+    body.finish()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use azure_core::headers::Headers;
+    use azure_core::{Body, BytesStream, Response, StatusCode};
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    /// The parameters of a token request body, by name.
+    pub(crate) fn parameters(body: &str) -> HashMap<String, String> {
+        form_urlencoded::parse(body.as_bytes())
+            .into_owned()
+            .collect()
+    }
+
+    /// A token endpoint granting the access token `TOKEN` and the refresh token `REFRESH` to
+    /// every request, which it keeps.
+    #[derive(Debug, Default)]
+    pub(crate) struct FakeTokenEndpoint {
+        requests: Mutex<Vec<(Url, String)>>,
+    }
+
+    impl FakeTokenEndpoint {
+        /// The URL and the body of the requests received so far.
+        pub(crate) fn requests(&self) -> Vec<(Url, String)> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl HttpClient for FakeTokenEndpoint {
+        async fn execute_request(&self, request: &Request) -> azure_core::Result<Response> {
+            let body = match request.body() {
+                Body::Bytes(body) => String::from_utf8(body.to_vec()).unwrap(),
+                Body::SeekableStream(_) => panic!("the token requests have a form body"),
+            };
+            self.requests
+                .lock()
+                .unwrap()
+                .push((request.url().clone(), body));
+            let mut response_headers = Headers::new();
+            response_headers.insert(headers::CONTENT_TYPE, content_type::APPLICATION_JSON);
+            Ok(Response::new(
+                StatusCode::Ok,
+                response_headers,
+                Box::pin(BytesStream::new(
+                    r#"{"token_type":"Bearer","scope":"https://vault.azure.net/.default","expires_in":3599,"ext_expires_in":3599,"access_token":"TOKEN","refresh_token":"REFRESH"}"#,
+                )),
+            ))
+        }
+    }
+
+    #[test]
+    fn requests_the_default_scope_of_the_resource() {
+        let body = token_request_body(
+            &[
+                ("grant_type", "client_credentials"),
+                ("client_id", "client"),
+            ],
+            "https://vault.azure.net",
+            Some(r#"{"access_token":{"nbf":{"essential":true}}}"#),
+        );
+        let parameters = parameters(&body);
+        assert_eq!(parameters["grant_type"], "client_credentials");
+        assert_eq!(parameters["client_id"], "client");
+        assert_eq!(parameters["scope"], "https://vault.azure.net/.default");
+        assert_eq!(
+            parameters["claims"],
+            r#"{"access_token":{"nbf":{"essential":true}}}"#
+        );
+        assert!(!parameters.contains_key("client_secret"));
+    }
+
+    #[tokio::test]
+    async fn posts_the_form_to_the_token_endpoint() -> azure_core::Result<()> {
+        let endpoint = FakeTokenEndpoint::default();
+        let token = request_token(
+            &endpoint,
+            "https://login.microsoftonline.us",
+            "tenant",
+            "grant_type=client_credentials".to_owned(),
+        )
+        .await?;
+        assert_eq!(token.token.secret(), "TOKEN");
+        assert!(token.expires_on > OffsetDateTime::now_utc() + Duration::from_secs(3500));
+
+        let requests = endpoint.requests();
+        assert_eq!(
+            requests[0].0.as_str(),
+            "https://login.microsoftonline.us/tenant/oauth2/v2.0/token"
+        );
+        assert_eq!(requests[0].1, "grant_type=client_credentials");
+        Ok(())
+    }
+}
@@ -0,0 +1,142 @@
+use super::token_request::{request_token, token_request_body};
+use super::TokenCredentialOptions;
+use azure_core::auth::{TokenCredential, TokenRequestOptions, TokenResponse};
+use azure_core::HttpClient;
+use std::sync::Arc;
+
+/// Enables authentication of a user to Azure Active Directory with their username and password,
+/// using the [resource owner password credentials flow](https://docs.microsoft.com/azure/active-directory/develop/v2-oauth-ropc).
+///
+/// This flow is only meant for legacy apps which cannot use an interactive flow: it does not work
+/// for users with multi-factor authentication or personal Microsoft accounts, and the app handles
+/// the password of the user. The app registration must allow public client flows, unless a client
+/// secret is set with [`client_secret`](Self::client_secret).
+///
+/// ```no_run
+/// use azure_core::auth::TokenCredential;
+/// use azure_identity::{TokenCredentialOptions, UsernamePasswordCredential};
+///
+/// # async fn run(password: String) -> azure_core::Result<()> {
+/// let credential = UsernamePasswordCredential::new(
+///     azure_core::new_http_client(),
+///     "tenant_id".to_owned(),
+///     "client_id".to_owned(),
+///     "user@contoso.com".to_owned(),
+///     password,
+///     TokenCredentialOptions::default(),
+/// );
+/// let token = credential.get_token("https://management.azure.com").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct UsernamePasswordCredential {
+    http_client: Arc<dyn HttpClient>,
+    tenant_id: String,
+    client_id: String,
+    client_secret: Option<String>,
+    username: String,
+    password: String,
+    options: TokenCredentialOptions,
+}
+
+impl UsernamePasswordCredential {
+    /// Create a new `UsernamePasswordCredential` signing in `username` with `password`.
+    pub fn new(
+        http_client: Arc<dyn HttpClient>,
+        tenant_id: String,
+        client_id: String,
+        username: String,
+        password: String,
+        options: TokenCredentialOptions,
+    ) -> Self {
+        Self {
+            http_client,
+            tenant_id,
+            client_id,
+            client_secret: None,
+            username,
+            password,
+            options,
+        }
+    }
+
+    /// Authenticate the app with a client secret, for confidential client app registrations.
+    #[must_use]
+    pub fn client_secret(mut self, client_secret: impl Into<String>) -> Self {
+        self.client_secret = Some(client_secret.into());
+        self
+    }
+
+    fn token_request_body(&self, resource: &str, claims: Option<&str>) -> String {
+        let mut parameters = vec![
+            ("grant_type", "password"),
+            ("client_id", self.client_id.as_str()),
+            ("username", self.username.as_str()),
+            ("password", self.password.as_str()),
+        ];
+        if let Some(client_secret) = &self.client_secret {
+            parameters.push(("client_secret", client_secret));
+        }
+        token_request_body(&parameters, resource, claims)
+    }
+}
+
+impl std::fmt::Debug for UsernamePasswordCredential {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UsernamePasswordCredential")
+            .field("tenant_id", &self.tenant_id)
+            .field("client_id", &self.client_id)
+            .field("username", &self.username)
+            .field("options", &self.options)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl TokenCredential for UsernamePasswordCredential {
+    async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
+        self.get_token_with_options(resource, &TokenRequestOptions::default())
+            .await
+    }
+
+    async fn get_token_with_options(
+        &self,
+        resource: &str,
+        options: &TokenRequestOptions,
+    ) -> azure_core::Result<TokenResponse> {
+        request_token(
+            self.http_client.as_ref(),
+            self.options.authority_host(),
+            &self.tenant_id,
+            self.token_request_body(resource, options.get_claims()),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::token_credentials::token_request::tests::parameters;
+
+    #[test]
+    fn signs_in_with_the_password() {
+        let credential = UsernamePasswordCredential::new(
+            azure_core::new_http_client(),
+            "tenant".to_owned(),
+            "client".to_owned(),
+            "user@contoso.com".to_owned(),
+            "p@ss&word".to_owned(),
+            TokenCredentialOptions::default(),
+        );
+        let body = credential.token_request_body("https://management.azure.com", None);
+        let pairs = parameters(&body);
+        assert_eq!(pairs["grant_type"], "password");
+        assert_eq!(pairs["client_id"], "client");
+        assert_eq!(pairs["username"], "user@contoso.com");
+        assert_eq!(pairs["password"], "p@ss&word");
+        assert_eq!(pairs["scope"], "https://management.azure.com/.default");
+        assert!(!pairs.contains_key("client_secret"));
+    }
+}
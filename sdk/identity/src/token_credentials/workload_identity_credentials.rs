@@ -0,0 +1,88 @@
+use super::{ClientAssertionCredential, TokenCredentialOptions};
+use azure_core::auth::{TokenCredential, TokenRequestOptions, TokenResponse};
+use azure_core::error::{Error, ErrorKind};
+use azure_core::HttpClient;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+const AZURE_TENANT_ID_ENV_KEY: &str = "AZURE_TENANT_ID";
+const AZURE_CLIENT_ID_ENV_KEY: &str = "AZURE_CLIENT_ID";
+const AZURE_FEDERATED_TOKEN_FILE_ENV_KEY: &str = "AZURE_FEDERATED_TOKEN_FILE";
+
+/// Enables authentication of workloads running in Kubernetes to Azure Active Directory, using
+/// [Azure AD workload identity](https://azure.github.io/azure-workload-identity/docs/).
+///
+/// The service account token projected in the pod is exchanged for an access token of the app
+/// registration or user-assigned managed identity federated with the service account. The token
+/// file is read again for every token request, as it is rotated by the kubelet. This is a
+/// [`ClientAssertionCredential`] reading its assertion from the token file.
+///
+/// On AKS, the workload identity webhook sets the following environment variables, read by
+/// [`WorkloadIdentityCredential::from_env`]:
+///
+/// | Variable                     | Description                                              |
+/// |------------------------------|----------------------------------------------------------|
+/// | `AZURE_TENANT_ID`            | The Azure Active Directory tenant(directory) ID.         |
+/// | `AZURE_CLIENT_ID`            | The client(application) ID federated with the service account. |
+/// | `AZURE_FEDERATED_TOKEN_FILE` | The path of the projected service account token.         |
+/// | `AZURE_AUTHORITY_HOST`       | The authority host, optional.                            |
+#[derive(Debug)]
+pub struct WorkloadIdentityCredential(ClientAssertionCredential);
+
+impl WorkloadIdentityCredential {
+    /// Create a new `WorkloadIdentityCredential` exchanging the token in `token_file_path`.
+    pub fn new(
+        http_client: Arc<dyn HttpClient>,
+        tenant_id: String,
+        client_id: String,
+        token_file_path: impl Into<PathBuf>,
+        options: TokenCredentialOptions,
+    ) -> Self {
+        Self(ClientAssertionCredential::from_file(
+            http_client,
+            tenant_id,
+            client_id,
+            token_file_path,
+            options,
+        ))
+    }
+
+    /// Create a new `WorkloadIdentityCredential` from the environment variables set by the
+    /// workload identity webhook.
+    pub fn from_env(http_client: Arc<dyn HttpClient>) -> azure_core::Result<Self> {
+        let tenant_id = env_var(AZURE_TENANT_ID_ENV_KEY)?;
+        let client_id = env_var(AZURE_CLIENT_ID_ENV_KEY)?;
+        let token_file_path = env_var(AZURE_FEDERATED_TOKEN_FILE_ENV_KEY)?;
+        Ok(Self::new(
+            http_client,
+            tenant_id,
+            client_id,
+            token_file_path,
+            TokenCredentialOptions::default(),
+        ))
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl TokenCredential for WorkloadIdentityCredential {
+    async fn get_token(&self, resource: &str) -> azure_core::Result<TokenResponse> {
+        self.0.get_token(resource).await
+    }
+
+    async fn get_token_with_options(
+        &self,
+        resource: &str,
+        options: &TokenRequestOptions,
+    ) -> azure_core::Result<TokenResponse> {
+        self.0.get_token_with_options(resource, options).await
+    }
+}
+
+fn env_var(name: &str) -> azure_core::Result<String> {
+    std::env::var(name).map_err(|_| {
+        Error::with_message(ErrorKind::Credential, || {
+            format!("missing {name} environment variable")
+        })
+    })
+}
@@ -3,5 +3,7 @@
 //! The IoT Hub crate contains a client that can be used to manage the IoT Hub.
 
 mod authorization_policy;
+/// The sas module contains utilities to generate SAS tokens for devices, modules and services.
+pub mod sas;
 /// The service module contains the IoT Hub Service Client that can be used to manage the IoT Hub.
 pub mod service;
@@ -5,9 +5,6 @@ use azure_core::{
     auth::TokenCredential, prelude::Timeout, ClientOptions, CollectedResponse, Context, Method,
     Pipeline, Request, Response, TimeoutPolicy, Url,
 };
-use base64::{decode, encode_config};
-use hmac::{Hmac, Mac};
-use sha2::Sha256;
 use std::sync::Arc;
 use std::time::Duration;
 use time::OffsetDateTime;
@@ -112,35 +109,13 @@ impl ServiceClient {
         private_key: &str,
         expires_in_seconds: u64,
     ) -> azure_core::Result<String> {
-        type HmacSHA256 = Hmac<Sha256>;
         let expiry_date = OffsetDateTime::now_utc() + Duration::from_secs(expires_in_seconds);
-        let expiry_date_seconds = expiry_date.unix_timestamp();
-        let data = format!(
-            "{}.azure-devices.net\n{}",
-            iot_hub_name, &expiry_date_seconds
-        );
-
-        let key = decode(private_key).with_context(ErrorKind::Other, || {
-            format!("failed to decode the given private key: {private_key}")
-        })?;
-
-        let mut hmac = HmacSHA256::new_from_slice(key.as_ref())
-            .with_context(ErrorKind::Other, || {
-                format!("failed to use the given private key for the hashing algorithm: {key:?}")
-            })?;
-
-        hmac.update(data.as_bytes());
-        let result = hmac.finalize();
-        let sas_token: &str = &encode_config(&result.into_bytes(), base64::STANDARD);
-
-        let encoded: String = url::form_urlencoded::Serializer::new(String::new())
-            .append_pair("sr", &format!("{}.azure-devices.net", iot_hub_name))
-            .append_pair("sig", sas_token)
-            .append_pair("skn", key_name)
-            .append_pair("se", &expiry_date_seconds.to_string())
-            .finish();
-
-        Ok(format!("SharedAccessSignature {}", encoded))
+        crate::sas::generate_service_sas_token(
+            &format!("{}.azure-devices.net", iot_hub_name),
+            key_name,
+            private_key,
+            expiry_date,
+        )
     }
 
     /// Create a new IoTHubService struct based on a given IoT Hub name and a private key
@@ -1,10 +1,11 @@
-use serde::{self, Serialize};
+use serde::{self, Deserialize, Serialize};
 use time::OffsetDateTime;
 use uuid::Uuid;
 
-#[derive(Serialize, Debug)]
+#[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
-/// An Event Grid Event, used to create new events that subscribers will receive.
+/// An Event Grid Event, used to create new events that subscribers will receive, or received
+/// from a subscription.
 /// In compliance with spec: <https://docs.microsoft.com/azure/event-grid/event-schema>
 pub struct Event<T>
 where
@@ -0,0 +1,459 @@
+//! Receives the events of a storage account delivered by an Event Grid subscription to a storage
+//! queue, e.g. to react to the blobs created or deleted in a container.
+//!
+//! The subscription itself is created with the Event Grid management API or the portal, with the
+//! queue as its endpoint.
+//! ```no_run
+//! use azure_messaging_eventgrid::storage_events::{BlobEvent, StorageEventReceiver};
+//! use azure_storage::clients::StorageClient;
+//! use azure_storage_queues::prelude::*;
+//! use futures::StreamExt;
+//!
+//! # async fn run() -> azure_core::Result<()> {
+//! let storage_client = StorageClient::new_access_key("account", "key");
+//! let receiver = StorageEventReceiver::new(storage_client.queue_client("blob-events"))
+//!     .dead_letter_queue(storage_client.queue_client("blob-events-poison"), 5);
+//! let mut events = Box::pin(receiver.stream());
+//! while let Some(event) = events.next().await {
+//!     let event = event?;
+//!     if let Some(BlobEvent::Created(data)) = event.blob_event() {
+//!         println!("{} was created", data.url);
+//!     }
+//!     event.complete().await?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use crate::system_events::{StorageBlobCreatedEventData, StorageBlobDeletedEventData};
+use crate::{deserialize_system_event, Event, SystemEvent};
+use azure_core::error::{Error, ErrorKind, ResultExt};
+use azure_storage_queues::{prelude::*, PopReceiptClient};
+use futures::stream::{self, Stream};
+use serde_json::Value;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// The number of messages received at once by default, the maximum allowed by the service.
+const DEFAULT_BATCH_SIZE: u8 = 32;
+const DEFAULT_VISIBILITY_TIMEOUT: Duration = Duration::from_secs(30);
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+const DEFAULT_REDELIVERY_DELAY: Duration = Duration::from_secs(5);
+const DEFAULT_MAX_REDELIVERY_DELAY: Duration = Duration::from_secs(5 * 60);
+
+/// A blob created or deleted in the storage account.
+#[derive(Debug, Clone)]
+pub enum BlobEvent {
+    Created(StorageBlobCreatedEventData),
+    Deleted(StorageBlobDeletedEventData),
+}
+
+/// An event received from the queue.
+///
+/// The event is received again once the visibility timeout expires unless it is completed, or
+/// after the redelivery delay if it is abandoned.
+#[derive(Debug)]
+pub struct ReceivedEvent {
+    pub event: Event<Value>,
+    pub system_event: SystemEvent,
+    /// The number of times the event has been received.
+    pub dequeue_count: u64,
+    message_text: String,
+    redelivery_delay: Duration,
+    pop_receipt_client: PopReceiptClient,
+}
+
+impl ReceivedEvent {
+    /// The blob created or deleted, if this is a blob event.
+    pub fn blob_event(&self) -> Option<BlobEvent> {
+        match &self.system_event {
+            SystemEvent::StorageBlobCreated(data) => Some(BlobEvent::Created(data.clone())),
+            SystemEvent::StorageBlobDeleted(data) => Some(BlobEvent::Deleted(data.clone())),
+            _ => None,
+        }
+    }
+
+    /// Removes the event from the queue once it has been processed.
+    pub async fn complete(self) -> azure_core::Result<()> {
+        self.pop_receipt_client.delete().into_future().await?;
+        Ok(())
+    }
+
+    /// Gives the event back to the queue after a failed processing, to be received again after
+    /// the redelivery delay, which doubles with every delivery.
+    pub async fn abandon(self) -> azure_core::Result<()> {
+        self.pop_receipt_client
+            .update(self.message_text, self.redelivery_delay)
+            .into_future()
+            .await?;
+        Ok(())
+    }
+}
+
+/// Receives the events delivered to a storage queue.
+///
+/// Messages which are not events, or which have been received more than the maximum dequeue count
+/// of the dead-letter queue, are moved to the dead-letter queue when one is set. Without one, the
+/// messages which are not events are received again after the redelivery delay.
+#[derive(Debug, Clone)]
+pub struct StorageEventReceiver {
+    queue_client: QueueClient,
+    dead_letter: Option<(QueueClient, u64)>,
+    batch_size: u8,
+    visibility_timeout: Duration,
+    poll_interval: Duration,
+    redelivery_delay: Duration,
+    max_redelivery_delay: Duration,
+}
+
+impl StorageEventReceiver {
+    /// Create a receiver of the events delivered to the queue of `queue_client`.
+    pub fn new(queue_client: QueueClient) -> Self {
+        Self {
+            queue_client,
+            dead_letter: None,
+            batch_size: DEFAULT_BATCH_SIZE,
+            visibility_timeout: DEFAULT_VISIBILITY_TIMEOUT,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            redelivery_delay: DEFAULT_REDELIVERY_DELAY,
+            max_redelivery_delay: DEFAULT_MAX_REDELIVERY_DELAY,
+        }
+    }
+
+    /// Move the messages which cannot be processed to another queue.
+    #[must_use]
+    pub fn dead_letter_queue(mut self, queue_client: QueueClient, max_dequeue_count: u64) -> Self {
+        self.dead_letter = Some((queue_client, max_dequeue_count));
+        self
+    }
+
+    /// Set the number of messages received at once, at most 32.
+    #[must_use]
+    pub fn batch_size(mut self, batch_size: u8) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Set the time during which a received event is hidden from the other receivers.
+    #[must_use]
+    pub fn visibility_timeout(mut self, visibility_timeout: Duration) -> Self {
+        self.visibility_timeout = visibility_timeout;
+        self
+    }
+
+    /// Set the time waited before polling an empty queue again.
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Set the time after which an abandoned event is received again the first time, doubled with
+    /// every delivery up to `max_redelivery_delay`.
+    #[must_use]
+    pub fn redelivery_delay(
+        mut self,
+        redelivery_delay: Duration,
+        max_redelivery_delay: Duration,
+    ) -> Self {
+        self.redelivery_delay = redelivery_delay;
+        self.max_redelivery_delay = max_redelivery_delay;
+        self
+    }
+
+    /// The events of the queue.
+    ///
+    /// The stream never ends: it polls the queue again after the poll interval when it is empty.
+    /// Messages which are not valid events are returned as errors.
+    pub fn stream(&self) -> impl Stream<Item = azure_core::Result<ReceivedEvent>> + '_ {
+        stream::unfold(VecDeque::new(), move |mut received| async move {
+            loop {
+                if let Some(event) = received.pop_front() {
+                    return Some((event, received));
+                }
+                match self.receive().await {
+                    Ok(events) if events.is_empty() => {
+                        azure_core::sleep::sleep(self.poll_interval).await;
+                    }
+                    Ok(events) => received.extend(events),
+                    Err(error) => return Some((Err(error), received)),
+                }
+            }
+        })
+    }
+
+    /// The time after which a message received `dequeue_count` times is received again.
+    fn redelivery_delay_of(&self, dequeue_count: u64) -> Duration {
+        let exponent = dequeue_count
+            .saturating_sub(1)
+            .min(u64::from(u32::BITS - 1)) as u32;
+        self.redelivery_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_redelivery_delay)
+    }
+
+    /// Receives a batch of messages, moving the ones which cannot be processed to the dead-letter
+    /// queue.
+    ///
+    /// A failure to move a message to the dead-letter queue is returned in place of the message:
+    /// it does not prevent the other messages of the batch from being received.
+    async fn receive(&self) -> azure_core::Result<Vec<azure_core::Result<ReceivedEvent>>> {
+        let response = self
+            .queue_client
+            .get_messages()
+            .number_of_messages(self.batch_size)
+            .visibility_timeout(self.visibility_timeout)
+            .into_future()
+            .await?;
+
+        let mut events = Vec::with_capacity(response.messages.len());
+        for message in response.messages {
+            let pop_receipt_client = self.queue_client.pop_receipt_client(message.pop_receipt());
+            if let Some((dead_letter_queue, max_dequeue_count)) = &self.dead_letter {
+                if message.dequeue_count > *max_dequeue_count {
+                    if let Err(error) = dead_letter(
+                        dead_letter_queue,
+                        &pop_receipt_client,
+                        &message.message_text,
+                    )
+                    .await
+                    {
+                        events.push(Err(error));
+                    }
+                    continue;
+                }
+            }
+            let event = parse_event(&message.message_text).and_then(|event| {
+                let system_event = deserialize_system_event(&event)?;
+                Ok((event, system_event))
+            });
+            match event {
+                Ok((event, system_event)) => events.push(Ok(ReceivedEvent {
+                    event,
+                    system_event,
+                    dequeue_count: message.dequeue_count,
+                    redelivery_delay: self.redelivery_delay_of(message.dequeue_count),
+                    message_text: message.message_text,
+                    pop_receipt_client,
+                })),
+                Err(error) => {
+                    events.push(Err(error));
+                    let moved = match &self.dead_letter {
+                        Some((dead_letter_queue, _)) => {
+                            dead_letter(
+                                dead_letter_queue,
+                                &pop_receipt_client,
+                                &message.message_text,
+                            )
+                            .await
+                        }
+                        None => pop_receipt_client
+                            .update(
+                                message.message_text,
+                                self.redelivery_delay_of(message.dequeue_count),
+                            )
+                            .into_future()
+                            .await
+                            .map(|_| ()),
+                    };
+                    if let Err(error) = moved {
+                        events.push(Err(error));
+                    }
+                }
+            }
+        }
+        Ok(events)
+    }
+}
+
+async fn dead_letter(
+    dead_letter_queue: &QueueClient,
+    pop_receipt_client: &PopReceiptClient,
+    message_text: &str,
+) -> azure_core::Result<()> {
+    dead_letter_queue
+        .put_message(message_text)
+        .into_future()
+        .await?;
+    pop_receipt_client.delete().into_future().await?;
+    Ok(())
+}
+
+/// Parses an event written to a queue by Event Grid, which encodes it in base64.
+fn parse_event(message_text: &str) -> azure_core::Result<Event<Value>> {
+    let message_text = message_text.trim();
+    let json = if message_text.starts_with('{') {
+        message_text.as_bytes().to_vec()
+    } else {
+        base64::decode(message_text).map_err(|error| {
+            Error::full(
+                ErrorKind::DataConversion,
+                error,
+                "the queue message is neither JSON nor base64",
+            )
+        })?
+    };
+    serde_json::from_slice(&json).context(
+        ErrorKind::DataConversion,
+        "the queue message is not an Event Grid event",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::{
+        headers::Headers, mock::MockTransportCannedPolicy, StatusCode, TransportOptions,
+    };
+    use std::sync::Arc;
+
+    const BLOB_CREATED: &str = r#"{
+        "topic": "/subscriptions/s/resourceGroups/rg/providers/Microsoft.Storage/storageAccounts/account",
+        "subject": "/blobServices/default/containers/c/blobs/b",
+        "eventType": "Microsoft.Storage.BlobCreated",
+        "id": "1",
+        "data": {
+            "api": "PutBlob",
+            "contentLength": 524288,
+            "url": "https://account.blob.core.windows.net/c/b"
+        },
+        "dataVersion": "",
+        "metadataVersion": "1",
+        "eventTime": "2022-09-01T10:00:00Z"
+    }"#;
+
+    fn queue_client(transport: &Arc<MockTransportCannedPolicy>, queue_name: &str) -> QueueClient {
+        QueueServiceClientBuilder::new("account", StorageCredentials::anonymous())
+            .transport(TransportOptions::new_custom_policy(transport.clone()))
+            .build()
+            .unwrap()
+            .storage_client()
+            .queue_client(queue_name)
+    }
+
+    fn response_headers() -> Headers {
+        let mut headers = Headers::new();
+        headers.insert("x-ms-request-id", "00000000-0000-0000-0000-000000000000");
+        headers.insert("x-ms-version", "2019-12-12");
+        headers.insert("date", "Thu, 15 Oct 2026 10:00:00 GMT");
+        headers.insert("server", "Windows-Azure-Queue/1.0");
+        headers
+    }
+
+    fn messages(messages: &[(&str, u64, &str)]) -> String {
+        let messages: String = messages
+            .iter()
+            .map(|(message_id, dequeue_count, message_text)| {
+                format!(
+                    "<QueueMessage>\
+                    <MessageId>{message_id}</MessageId>\
+                    <InsertionTime>Thu, 15 Oct 2026 10:00:00 GMT</InsertionTime>\
+                    <ExpirationTime>Thu, 22 Oct 2026 10:00:00 GMT</ExpirationTime>\
+                    <PopReceipt>receipt</PopReceipt>\
+                    <TimeNextVisible>Thu, 15 Oct 2026 10:00:30 GMT</TimeNextVisible>\
+                    <DequeueCount>{dequeue_count}</DequeueCount>\
+                    <MessageText>{message_text}</MessageText>\
+                    </QueueMessage>"
+                )
+            })
+            .collect();
+        format!(
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?><QueueMessagesList>{messages}</QueueMessagesList>"
+        )
+    }
+
+    #[test]
+    fn doubles_the_redelivery_delay() {
+        let transport = Arc::new(MockTransportCannedPolicy::new());
+        let receiver = StorageEventReceiver::new(queue_client(&transport, "events"))
+            .redelivery_delay(Duration::from_secs(5), Duration::from_secs(60));
+        assert_eq!(receiver.redelivery_delay_of(1), Duration::from_secs(5));
+        assert_eq!(receiver.redelivery_delay_of(3), Duration::from_secs(20));
+        assert_eq!(receiver.redelivery_delay_of(5), Duration::from_secs(60));
+        assert_eq!(
+            receiver.redelivery_delay_of(u64::MAX),
+            Duration::from_secs(60)
+        );
+    }
+
+    #[tokio::test]
+    async fn keeps_the_batch_when_dead_lettering_fails() {
+        let transport = Arc::new(
+            MockTransportCannedPolicy::new()
+                .reply(
+                    StatusCode::Ok,
+                    response_headers(),
+                    messages(&[
+                        ("poison", 6, "poison"),
+                        ("event", 1, &base64::encode(BLOB_CREATED)),
+                    ]),
+                )
+                .reply(StatusCode::BadRequest, response_headers(), ""),
+        );
+        let receiver = StorageEventReceiver::new(queue_client(&transport, "events"))
+            .dead_letter_queue(queue_client(&transport, "poison"), 5);
+
+        let events = receiver.receive().await.unwrap();
+        assert_eq!(events.len(), 2);
+        assert!(events[0].is_err());
+        let event = events[1].as_ref().unwrap();
+        assert!(matches!(event.blob_event(), Some(BlobEvent::Created(_))));
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1].url().path().starts_with("/poison/messages"));
+    }
+
+    #[tokio::test]
+    async fn abandons_after_the_redelivery_delay() {
+        let transport = Arc::new(MockTransportCannedPolicy::new().reply(
+            StatusCode::Ok,
+            response_headers(),
+            messages(&[("event", 3, &base64::encode(BLOB_CREATED))]),
+        ));
+        let receiver = StorageEventReceiver::new(queue_client(&transport, "events"))
+            .redelivery_delay(Duration::from_secs(5), Duration::from_secs(60));
+
+        let mut events = receiver.receive().await.unwrap();
+        let event = events.pop().unwrap().unwrap();
+        // no reply is canned: the update is only recorded
+        assert!(event.abandon().await.is_err());
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].method(), &azure_core::Method::Put);
+        assert!(requests[1]
+            .url()
+            .query_pairs()
+            .any(|(name, value)| name == "visibilitytimeout" && value == "20"));
+    }
+
+    #[test]
+    fn parses_queued_events() {
+        let json = r#"{
+            "topic": "/subscriptions/s/resourceGroups/rg/providers/Microsoft.Storage/storageAccounts/account",
+            "subject": "/blobServices/default/containers/c/blobs/b",
+            "eventType": "Microsoft.Storage.BlobCreated",
+            "id": "1",
+            "data": {
+                "api": "PutBlob",
+                "contentLength": 524288,
+                "url": "https://account.blob.core.windows.net/c/b"
+            },
+            "dataVersion": "",
+            "metadataVersion": "1",
+            "eventTime": "2022-09-01T10:00:00Z"
+        }"#;
+        for message_text in [json.to_owned(), base64::encode(json)] {
+            let event = parse_event(&message_text).unwrap();
+            match deserialize_system_event(&event).unwrap() {
+                SystemEvent::StorageBlobCreated(data) => {
+                    assert_eq!(data.url, "https://account.blob.core.windows.net/c/b");
+                    assert_eq!(data.content_length, Some(524288));
+                }
+                other => panic!("unexpected event {other:?}"),
+            }
+        }
+        assert!(parse_event("not an event").is_err());
+        assert!(parse_event(&base64::encode("{}")).is_err());
+    }
+}
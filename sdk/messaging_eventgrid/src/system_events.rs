@@ -0,0 +1,274 @@
+//! The data of the events published by Azure services to system topics.
+//!
+//! [`deserialize_system_event`] looks up the type of the data of an event from its `eventType`:
+//! ```
+//! use azure_messaging_eventgrid::{deserialize_system_event, Event, SystemEvent};
+//!
+//! let events: Vec<Event<serde_json::Value>> = serde_json::from_str(r#"[{
+//!     "id": "1", "eventType": "Microsoft.Storage.BlobDeleted", "subject": "/containers/c/blobs/b",
+//!     "eventTime": "2022-09-01T10:00:00Z", "dataVersion": "1", "metadataVersion": "1",
+//!     "data": { "api": "DeleteBlob", "url": "https://account.blob.core.windows.net/c/b" }
+//! }]"#)?;
+//! for event in &events {
+//!     if let SystemEvent::StorageBlobDeleted(data) = deserialize_system_event(event)? {
+//!         println!("{} was deleted", data.url);
+//!     }
+//! }
+//! # Ok::<(), azure_core::Error>(())
+//! ```
+use crate::Event;
+use azure_core::error::{ErrorKind, ResultExt};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+
+macro_rules! system_events {
+    ($($variant:ident($data:ty) => $event_type:literal,)*) => {
+        /// The `eventType` of the system events known by [`SystemEvent`].
+        #[allow(non_upper_case_globals)]
+        pub mod event_types {
+            $(pub const $variant: &str = $event_type;)*
+        }
+
+        /// The typed data of a system event.
+        #[derive(Debug, Clone)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum SystemEvent {
+            $(
+                #[doc = concat!("The data of a `", $event_type, "` event.")]
+                $variant($data),
+            )*
+            /// An event of a type without a typed representation, e.g. a custom event.
+            Unknown { event_type: String, data: Value },
+        }
+
+        impl SystemEvent {
+            /// Deserializes the data of an event of type `event_type`.
+            pub fn from_data(event_type: &str, data: Value) -> azure_core::Result<Self> {
+                Ok(match event_type {
+                    $($event_type => SystemEvent::$variant(from_value(event_type, data)?),)*
+                    _ => SystemEvent::Unknown {
+                        event_type: event_type.to_owned(),
+                        data,
+                    },
+                })
+            }
+
+            /// The `eventType` of the event.
+            pub fn event_type(&self) -> &str {
+                match self {
+                    $(SystemEvent::$variant(_) => $event_type,)*
+                    SystemEvent::Unknown { event_type, .. } => event_type,
+                }
+            }
+        }
+    };
+}
+
+system_events! {
+    EventGridSubscriptionValidation(SubscriptionValidationEventData) => "Microsoft.EventGrid.SubscriptionValidationEvent",
+    StorageBlobCreated(StorageBlobCreatedEventData) => "Microsoft.Storage.BlobCreated",
+    StorageBlobDeleted(StorageBlobDeletedEventData) => "Microsoft.Storage.BlobDeleted",
+    KeyVaultCertificateNewVersionCreated(KeyVaultObjectEventData) => "Microsoft.KeyVault.CertificateNewVersionCreated",
+    KeyVaultCertificateNearExpiry(KeyVaultObjectEventData) => "Microsoft.KeyVault.CertificateNearExpiry",
+    KeyVaultCertificateExpired(KeyVaultObjectEventData) => "Microsoft.KeyVault.CertificateExpired",
+    KeyVaultKeyNewVersionCreated(KeyVaultObjectEventData) => "Microsoft.KeyVault.KeyNewVersionCreated",
+    KeyVaultKeyNearExpiry(KeyVaultObjectEventData) => "Microsoft.KeyVault.KeyNearExpiry",
+    KeyVaultKeyExpired(KeyVaultObjectEventData) => "Microsoft.KeyVault.KeyExpired",
+    KeyVaultSecretNewVersionCreated(KeyVaultObjectEventData) => "Microsoft.KeyVault.SecretNewVersionCreated",
+    KeyVaultSecretNearExpiry(KeyVaultObjectEventData) => "Microsoft.KeyVault.SecretNearExpiry",
+    KeyVaultSecretExpired(KeyVaultObjectEventData) => "Microsoft.KeyVault.SecretExpired",
+    ResourceWriteSuccess(ResourceEventData) => "Microsoft.Resources.ResourceWriteSuccess",
+    ResourceWriteFailure(ResourceEventData) => "Microsoft.Resources.ResourceWriteFailure",
+    ResourceWriteCancel(ResourceEventData) => "Microsoft.Resources.ResourceWriteCancel",
+    ResourceDeleteSuccess(ResourceEventData) => "Microsoft.Resources.ResourceDeleteSuccess",
+    ResourceDeleteFailure(ResourceEventData) => "Microsoft.Resources.ResourceDeleteFailure",
+    ResourceDeleteCancel(ResourceEventData) => "Microsoft.Resources.ResourceDeleteCancel",
+    ResourceActionSuccess(ResourceEventData) => "Microsoft.Resources.ResourceActionSuccess",
+    ResourceActionFailure(ResourceEventData) => "Microsoft.Resources.ResourceActionFailure",
+    ResourceActionCancel(ResourceEventData) => "Microsoft.Resources.ResourceActionCancel",
+    IotHubDeviceCreated(IotHubDeviceLifeCycleEventData) => "Microsoft.Devices.DeviceCreated",
+    IotHubDeviceDeleted(IotHubDeviceLifeCycleEventData) => "Microsoft.Devices.DeviceDeleted",
+    IotHubDeviceConnected(IotHubDeviceConnectionEventData) => "Microsoft.Devices.DeviceConnected",
+    IotHubDeviceDisconnected(IotHubDeviceConnectionEventData) => "Microsoft.Devices.DeviceDisconnected",
+    IotHubDeviceTelemetry(IotHubDeviceTelemetryEventData) => "Microsoft.Devices.DeviceTelemetry",
+}
+
+/// Deserializes the data of a received event into the type matching its `eventType`.
+///
+/// Events of other types are returned as [`SystemEvent::Unknown`].
+pub fn deserialize_system_event(event: &Event<Value>) -> azure_core::Result<SystemEvent> {
+    SystemEvent::from_data(&event.event_type, event.data.clone().unwrap_or(Value::Null))
+}
+
+fn from_value<T: serde::de::DeserializeOwned>(
+    event_type: &str,
+    data: Value,
+) -> azure_core::Result<T> {
+    serde_json::from_value(data).with_context(ErrorKind::DataConversion, || {
+        format!("failed to deserialize the data of a {event_type} event")
+    })
+}
+
+/// The data of the event sent to validate a new webhook subscription.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionValidationEventData {
+    /// The code to echo back to validate the subscription.
+    pub validation_code: String,
+    /// The URL to call to validate the subscription manually.
+    pub validation_url: Option<String>,
+}
+
+/// The data of a `Microsoft.Storage.BlobCreated` event.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageBlobCreatedEventData {
+    /// The operation which created the blob, e.g. `PutBlob`.
+    pub api: String,
+    pub client_request_id: Option<String>,
+    pub request_id: Option<String>,
+    pub e_tag: Option<String>,
+    pub content_type: Option<String>,
+    pub content_length: Option<u64>,
+    pub blob_type: Option<String>,
+    pub url: String,
+    /// An opaque value ordering the events of a blob.
+    pub sequencer: Option<String>,
+    pub storage_diagnostics: Option<Value>,
+}
+
+/// The data of a `Microsoft.Storage.BlobDeleted` event.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageBlobDeletedEventData {
+    /// The operation which deleted the blob, e.g. `DeleteBlob`.
+    pub api: String,
+    pub client_request_id: Option<String>,
+    pub request_id: Option<String>,
+    pub content_type: Option<String>,
+    pub blob_type: Option<String>,
+    pub url: String,
+    /// An opaque value ordering the events of a blob.
+    pub sequencer: Option<String>,
+    pub storage_diagnostics: Option<Value>,
+}
+
+/// The data of the events about the certificates, keys and secrets of a key vault.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct KeyVaultObjectEventData {
+    /// The identifier of the object version.
+    pub id: String,
+    pub vault_name: String,
+    /// `Certificate`, `Key` or `Secret`.
+    pub object_type: String,
+    pub object_name: String,
+    pub version: String,
+    /// The not before date, in seconds since the Unix epoch.
+    #[serde(rename = "NBF")]
+    pub not_before: Option<f64>,
+    /// The expiration date, in seconds since the Unix epoch.
+    #[serde(rename = "EXP")]
+    pub expires: Option<f64>,
+}
+
+/// The data of the events about Azure Resource Manager operations.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceEventData {
+    pub tenant_id: Option<String>,
+    pub subscription_id: Option<String>,
+    pub resource_group: Option<String>,
+    pub resource_provider: Option<String>,
+    pub resource_uri: Option<String>,
+    pub operation_name: Option<String>,
+    pub status: Option<String>,
+    pub correlation_id: Option<String>,
+    pub authorization: Option<Value>,
+    pub claims: Option<Value>,
+    pub http_request: Option<Value>,
+}
+
+/// The data of the events about devices created or deleted in an IoT hub.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IotHubDeviceLifeCycleEventData {
+    pub device_id: String,
+    pub hub_name: String,
+    /// The device twin.
+    pub twin: Option<Value>,
+}
+
+/// The data of the events about devices connected to or disconnected from an IoT hub.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IotHubDeviceConnectionEventData {
+    pub device_id: String,
+    pub module_id: Option<String>,
+    pub hub_name: String,
+    pub device_connection_state_event_info: Option<DeviceConnectionStateEventInfo>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceConnectionStateEventInfo {
+    /// A number ordering the connection events of a device.
+    pub sequence_number: String,
+}
+
+/// The data of a telemetry message sent by a device to an IoT hub.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IotHubDeviceTelemetryEventData {
+    pub body: Value,
+    #[serde(default)]
+    pub properties: HashMap<String, String>,
+    #[serde(default)]
+    pub system_properties: HashMap<String, String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_system_events() {
+        let event: Event<Value> = serde_json::from_value(serde_json::json!({
+            "topic": "/subscriptions/s/resourceGroups/rg/providers/Microsoft.KeyVault/vaults/v",
+            "id": "1",
+            "eventType": "Microsoft.KeyVault.SecretNearExpiry",
+            "subject": "secret",
+            "eventTime": "2022-09-01T10:00:00Z",
+            "data": {
+                "Id": "https://v.vault.azure.net/secrets/secret/1",
+                "VaultName": "v",
+                "ObjectType": "Secret",
+                "ObjectName": "secret",
+                "Version": "1",
+                "NBF": null,
+                "EXP": 1662026400
+            },
+            "dataVersion": "1",
+            "metadataVersion": "1"
+        }))
+        .unwrap();
+        let system_event = deserialize_system_event(&event).unwrap();
+        assert_eq!(
+            system_event.event_type(),
+            event_types::KeyVaultSecretNearExpiry
+        );
+        match system_event {
+            SystemEvent::KeyVaultSecretNearExpiry(data) => {
+                assert_eq!(data.object_name, "secret");
+                assert_eq!(data.expires, Some(1662026400.0));
+            }
+            other => panic!("unexpected event {other:?}"),
+        }
+
+        let system_event =
+            SystemEvent::from_data("Contoso.Items.ItemReceived", serde_json::json!({})).unwrap();
+        assert!(matches!(system_event, SystemEvent::Unknown { .. }));
+        assert!(SystemEvent::from_data(event_types::StorageBlobCreated, Value::Null).is_err());
+    }
+}
@@ -0,0 +1,371 @@
+use super::EventPosition;
+use azure_core::error::{ErrorKind, ResultExt};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::PathBuf;
+use std::sync::Mutex;
+use time::OffsetDateTime;
+
+/// The last event processed by a consumer group in a partition.
+///
+/// The event is identified by its offset, sequence number and enqueued time, any of which is
+/// enough to resume after it: the offset is preferred, then the sequence number.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Checkpoint {
+    /// The namespace, e.g. `contoso.servicebus.windows.net`.
+    pub fully_qualified_namespace: String,
+    pub event_hub_name: String,
+    pub consumer_group: String,
+    pub partition_id: String,
+    #[serde(default)]
+    pub offset: Option<String>,
+    #[serde(default)]
+    pub sequence_number: Option<i64>,
+    #[serde(default, with = "azure_core::date::rfc3339::option")]
+    pub enqueued_time: Option<OffsetDateTime>,
+}
+
+impl Checkpoint {
+    /// A checkpoint of a partition in which no event was processed yet.
+    pub fn new(
+        fully_qualified_namespace: impl Into<String>,
+        event_hub_name: impl Into<String>,
+        consumer_group: impl Into<String>,
+        partition_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            fully_qualified_namespace: fully_qualified_namespace.into(),
+            event_hub_name: event_hub_name.into(),
+            consumer_group: consumer_group.into(),
+            partition_id: partition_id.into(),
+            offset: None,
+            sequence_number: None,
+            enqueued_time: None,
+        }
+    }
+
+    /// The checkpoint after the event with the given offset, sequence number and enqueued time.
+    #[must_use]
+    pub fn processed(
+        self,
+        offset: impl Into<String>,
+        sequence_number: i64,
+        enqueued_time: OffsetDateTime,
+    ) -> Self {
+        Self {
+            offset: Some(offset.into()),
+            sequence_number: Some(sequence_number),
+            enqueued_time: Some(enqueued_time),
+            ..self
+        }
+    }
+
+    /// The position of the event after the checkpointed one, or `None` if the checkpoint has no
+    /// event.
+    pub fn position(&self) -> Option<EventPosition> {
+        if let Some(offset) = &self.offset {
+            Some(EventPosition::after_offset(offset.clone()))
+        } else if let Some(sequence_number) = self.sequence_number {
+            Some(EventPosition::after_sequence_number(sequence_number))
+        } else {
+            self.enqueued_time.map(EventPosition::after_enqueued_time)
+        }
+    }
+
+    fn key(&self) -> (String, String, String, String) {
+        key(
+            &self.fully_qualified_namespace,
+            &self.event_hub_name,
+            &self.consumer_group,
+            &self.partition_id,
+        )
+    }
+}
+
+/// The namespaces and the names of the event hubs and consumer groups are case insensitive.
+fn key(
+    fully_qualified_namespace: &str,
+    event_hub_name: &str,
+    consumer_group: &str,
+    partition_id: &str,
+) -> (String, String, String, String) {
+    (
+        fully_qualified_namespace.to_lowercase(),
+        event_hub_name.to_lowercase(),
+        consumer_group.to_lowercase(),
+        partition_id.to_owned(),
+    )
+}
+
+/// Stores the checkpoints of the consumer groups, from which their consumers resume.
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+pub trait CheckpointStore: Send + Sync {
+    /// The checkpoints of the partitions of an event hub for a consumer group.
+    async fn list_checkpoints(
+        &self,
+        fully_qualified_namespace: &str,
+        event_hub_name: &str,
+        consumer_group: &str,
+    ) -> azure_core::Result<Vec<Checkpoint>>;
+
+    /// Saves a checkpoint, replacing the one of its partition.
+    async fn update_checkpoint(&self, checkpoint: Checkpoint) -> azure_core::Result<()>;
+
+    /// The position from which a consumer of the partition starts: after its checkpoint, or
+    /// `default` if it has none.
+    async fn starting_position(
+        &self,
+        fully_qualified_namespace: &str,
+        event_hub_name: &str,
+        consumer_group: &str,
+        partition_id: &str,
+        default: EventPosition,
+    ) -> azure_core::Result<EventPosition> {
+        let checkpoints = self
+            .list_checkpoints(fully_qualified_namespace, event_hub_name, consumer_group)
+            .await?;
+        Ok(checkpoints
+            .iter()
+            .find(|checkpoint| checkpoint.partition_id == partition_id)
+            .and_then(Checkpoint::position)
+            .unwrap_or(default))
+    }
+}
+
+type Checkpoints = BTreeMap<(String, String, String, String), Checkpoint>;
+
+fn list(
+    checkpoints: &Checkpoints,
+    fully_qualified_namespace: &str,
+    event_hub_name: &str,
+    consumer_group: &str,
+) -> Vec<Checkpoint> {
+    let (namespace, event_hub, group, _) = key(
+        fully_qualified_namespace,
+        event_hub_name,
+        consumer_group,
+        "",
+    );
+    checkpoints
+        .iter()
+        .filter(|((n, e, g, _), _)| *n == namespace && *e == event_hub && *g == group)
+        .map(|(_, checkpoint)| checkpoint.clone())
+        .collect()
+}
+
+/// Stores the checkpoints in memory, e.g. for tests or consumers which restart from a default
+/// position.
+#[derive(Debug, Default)]
+pub struct InMemoryCheckpointStore {
+    checkpoints: Mutex<Checkpoints>,
+}
+
+impl InMemoryCheckpointStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl CheckpointStore for InMemoryCheckpointStore {
+    async fn list_checkpoints(
+        &self,
+        fully_qualified_namespace: &str,
+        event_hub_name: &str,
+        consumer_group: &str,
+    ) -> azure_core::Result<Vec<Checkpoint>> {
+        Ok(list(
+            &self.checkpoints.lock().unwrap(),
+            fully_qualified_namespace,
+            event_hub_name,
+            consumer_group,
+        ))
+    }
+
+    async fn update_checkpoint(&self, checkpoint: Checkpoint) -> azure_core::Result<()> {
+        self.checkpoints
+            .lock()
+            .unwrap()
+            .insert(checkpoint.key(), checkpoint);
+        Ok(())
+    }
+}
+
+/// Stores the checkpoints in a JSON file, for the consumers of a single machine.
+///
+/// The file is rewritten on every update, which suits checkpointing every few seconds or every
+/// few hundred events, not after every event. The file is read and written with the asynchronous
+/// I/O of tokio, which must be the runtime of the consumers.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug)]
+pub struct FileCheckpointStore {
+    path: PathBuf,
+    // serializes the updates, which read and rewrite the file; held across the awaits of the I/O
+    lock: tokio::sync::Mutex<()>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileCheckpointStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    async fn read(&self) -> azure_core::Result<Checkpoints> {
+        let checkpoints = match tokio::fs::read(&self.path).await {
+            Ok(checkpoints) => checkpoints,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(Checkpoints::new())
+            }
+            Err(error) => {
+                return Err(error).with_context(ErrorKind::Io, || {
+                    format!("failed to read the checkpoints {}", self.path.display())
+                })
+            }
+        };
+        let checkpoints: Vec<Checkpoint> = serde_json::from_slice(&checkpoints)
+            .with_context(ErrorKind::DataConversion, || {
+                format!("the checkpoints {} are not valid", self.path.display())
+            })?;
+        Ok(checkpoints
+            .into_iter()
+            .map(|checkpoint| (checkpoint.key(), checkpoint))
+            .collect())
+    }
+
+    async fn write(&self, checkpoints: &Checkpoints) -> azure_core::Result<()> {
+        // the file is replaced atomically, so that a crash cannot leave it truncated
+        let temporary = self.path.with_extension("tmp");
+        let checkpoints = serde_json::to_vec_pretty(&checkpoints.values().collect::<Vec<_>>())?;
+        let written = match tokio::fs::write(&temporary, checkpoints).await {
+            Ok(()) => tokio::fs::rename(&temporary, &self.path).await,
+            Err(error) => Err(error),
+        };
+        written.with_context(ErrorKind::Io, || {
+            format!("failed to write the checkpoints {}", self.path.display())
+        })
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait::async_trait]
+impl CheckpointStore for FileCheckpointStore {
+    async fn list_checkpoints(
+        &self,
+        fully_qualified_namespace: &str,
+        event_hub_name: &str,
+        consumer_group: &str,
+    ) -> azure_core::Result<Vec<Checkpoint>> {
+        let _lock = self.lock.lock().await;
+        Ok(list(
+            &self.read().await?,
+            fully_qualified_namespace,
+            event_hub_name,
+            consumer_group,
+        ))
+    }
+
+    async fn update_checkpoint(&self, checkpoint: Checkpoint) -> azure_core::Result<()> {
+        let _lock = self.lock.lock().await;
+        let mut checkpoints = self.read().await?;
+        checkpoints.insert(checkpoint.key(), checkpoint);
+        self.write(&checkpoints).await
+    }
+}
+
+#[cfg(test)]
+#[cfg(not(target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn resumes_after_the_checkpoint() {
+        let path = std::env::temp_dir().join(format!(
+            "event-hubs-checkpoints-{}.json",
+            rand::random::<u64>()
+        ));
+        let store = FileCheckpointStore::new(&path);
+        let namespace = "contoso.servicebus.windows.net";
+        let position = store
+            .starting_position(
+                namespace,
+                "telemetry",
+                "$Default",
+                "0",
+                EventPosition::Latest,
+            )
+            .await
+            .unwrap();
+        assert_eq!(position, EventPosition::Latest);
+
+        let checkpoint = Checkpoint::new(namespace, "telemetry", "$Default", "0").processed(
+            "4096",
+            41,
+            OffsetDateTime::UNIX_EPOCH,
+        );
+        store.update_checkpoint(checkpoint.clone()).await.unwrap();
+        store
+            .update_checkpoint(Checkpoint::new(namespace, "telemetry", "other", "0"))
+            .await
+            .unwrap();
+
+        let reopened = FileCheckpointStore::new(&path);
+        assert_eq!(
+            reopened
+                .list_checkpoints(namespace, "Telemetry", "$default")
+                .await
+                .unwrap(),
+            vec![checkpoint]
+        );
+        let position = reopened
+            .starting_position(
+                namespace,
+                "telemetry",
+                "$Default",
+                "0",
+                EventPosition::Latest,
+            )
+            .await
+            .unwrap();
+        assert_eq!(position, EventPosition::after_offset("4096"));
+        assert_eq!(
+            position.filter_expression(),
+            "amqp.annotation.x-opt-offset > '4096'"
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn keeps_the_concurrent_updates() {
+        let path = std::env::temp_dir().join(format!(
+            "event-hubs-checkpoints-{}.json",
+            rand::random::<u64>()
+        ));
+        let store = std::sync::Arc::new(FileCheckpointStore::new(&path));
+        let namespace = "contoso.servicebus.windows.net";
+        let updates = (0..8).map(|partition| {
+            let store = store.clone();
+            tokio::spawn(async move {
+                let checkpoint =
+                    Checkpoint::new(namespace, "telemetry", "$Default", partition.to_string());
+                store.update_checkpoint(checkpoint).await
+            })
+        });
+        for update in updates.collect::<Vec<_>>() {
+            update.await.unwrap().unwrap();
+        }
+
+        let checkpoints = store
+            .list_checkpoints(namespace, "telemetry", "$Default")
+            .await
+            .unwrap();
+        assert_eq!(checkpoints.len(), 8);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
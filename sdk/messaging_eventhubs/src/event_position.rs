@@ -0,0 +1,96 @@
+use time::OffsetDateTime;
+
+/// The position in a partition from which a consumer starts receiving events.
+///
+/// An event is identified by its offset, its sequence number or its enqueued time: a position can
+/// be expressed with any of them, and is translated to the filter of the receiving link with
+/// [`filter_expression`](Self::filter_expression).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EventPosition {
+    /// The first event retained in the partition.
+    Earliest,
+    /// The events enqueued after the consumer starts receiving.
+    Latest,
+    /// The event at the offset, or the one after it if not `inclusive`.
+    Offset { offset: String, inclusive: bool },
+    /// The event with the sequence number, or the one after it if not `inclusive`.
+    SequenceNumber {
+        sequence_number: i64,
+        inclusive: bool,
+    },
+    /// The first event enqueued after the time.
+    EnqueuedTime(OffsetDateTime),
+}
+
+impl EventPosition {
+    /// The event after the one at `offset`.
+    pub fn after_offset(offset: impl Into<String>) -> Self {
+        Self::Offset {
+            offset: offset.into(),
+            inclusive: false,
+        }
+    }
+
+    /// The event at `offset`.
+    pub fn at_offset(offset: impl Into<String>) -> Self {
+        Self::Offset {
+            offset: offset.into(),
+            inclusive: true,
+        }
+    }
+
+    /// The event after the one with `sequence_number`.
+    pub fn after_sequence_number(sequence_number: i64) -> Self {
+        Self::SequenceNumber {
+            sequence_number,
+            inclusive: false,
+        }
+    }
+
+    /// The event with `sequence_number`.
+    pub fn at_sequence_number(sequence_number: i64) -> Self {
+        Self::SequenceNumber {
+            sequence_number,
+            inclusive: true,
+        }
+    }
+
+    /// The first event enqueued after `enqueued_time`.
+    pub fn after_enqueued_time(enqueued_time: OffsetDateTime) -> Self {
+        Self::EnqueuedTime(enqueued_time)
+    }
+
+    /// The AMQP filter selecting the events from this position, e.g.
+    /// `amqp.annotation.x-opt-sequence-number > '41'`, set on the source of the receiving link.
+    pub fn filter_expression(&self) -> String {
+        match self {
+            Self::Earliest => "amqp.annotation.x-opt-offset > '-1'".to_owned(),
+            Self::Latest => "amqp.annotation.x-opt-offset > '@latest'".to_owned(),
+            Self::Offset { offset, inclusive } => {
+                format!(
+                    "amqp.annotation.x-opt-offset {} '{offset}'",
+                    operator(*inclusive)
+                )
+            }
+            Self::SequenceNumber {
+                sequence_number,
+                inclusive,
+            } => format!(
+                "amqp.annotation.x-opt-sequence-number {} '{sequence_number}'",
+                operator(*inclusive)
+            ),
+            Self::EnqueuedTime(enqueued_time) => format!(
+                "amqp.annotation.x-opt-enqueued-time > '{}'",
+                enqueued_time.unix_timestamp_nanos() / 1_000_000
+            ),
+        }
+    }
+}
+
+fn operator(inclusive: bool) -> &'static str {
+    if inclusive {
+        ">="
+    } else {
+        ">"
+    }
+}
@@ -0,0 +1,14 @@
+//! Azure Event Hubs crate for the unofficial Microsoft Azure SDK for Rust. This crate is part of a collection of crates: for more information please refer to [https://github.com/azure/azure-sdk-for-rust](https://github.com/azure/azure-sdk-for-rust).
+//!
+//! A consumer group resumes receiving the events of a partition after the last event it
+//! checkpointed. The checkpoints are kept in a [`CheckpointStore`]: in memory, in a local file, or
+//! in any storage implementing the trait.
+
+mod checkpoint_store;
+mod event_position;
+pub mod prelude;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use checkpoint_store::FileCheckpointStore;
+pub use checkpoint_store::{Checkpoint, CheckpointStore, InMemoryCheckpointStore};
+pub use event_position::EventPosition;
@@ -0,0 +1,3 @@
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::FileCheckpointStore;
+pub use crate::{Checkpoint, CheckpointStore, EventPosition, InMemoryCheckpointStore};
@@ -0,0 +1,218 @@
+use crate::service_bus::generate_signature;
+use azure_core::error::{Error, ErrorKind, ResultExt};
+use ring::hmac;
+use std::time::Duration;
+use url::Url;
+
+// Key names.
+pub const ENDPOINT_KEY_NAME: &str = "Endpoint";
+pub const SHARED_ACCESS_KEY_NAME_KEY_NAME: &str = "SharedAccessKeyName";
+pub const SHARED_ACCESS_KEY_KEY_NAME: &str = "SharedAccessKey";
+pub const SHARED_ACCESS_SIGNATURE_KEY_NAME: &str = "SharedAccessSignature";
+pub const ENTITY_PATH_KEY_NAME: &str = "EntityPath";
+
+/// A Service Bus connection string, as shown in the "Shared access policies" of a namespace or
+/// of an entity in the Azure portal.
+///
+/// Event Hubs namespaces use the same format, see [`EventHubsConnectionString`].
+///
+/// ```
+/// use azure_messaging_servicebus::ServiceBusConnectionString;
+///
+/// let connection_string = ServiceBusConnectionString::new(
+///     "Endpoint=sb://mynamespace.servicebus.windows.net/;SharedAccessKeyName=RootManageSharedAccessKey;SharedAccessKey=c2VjcmV0;EntityPath=myqueue",
+/// )
+/// .unwrap();
+/// assert_eq!(connection_string.namespace().unwrap(), "mynamespace");
+/// assert_eq!(connection_string.entity_path, Some("myqueue"));
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct ServiceBusConnectionString<'a> {
+    /// The endpoint of the namespace, e.g. `sb://mynamespace.servicebus.windows.net/`.
+    pub endpoint: &'a str,
+    /// The name of the shared access policy.
+    pub shared_access_key_name: Option<&'a str>,
+    /// The key of the shared access policy.
+    pub shared_access_key: Option<&'a str>,
+    /// A pre-generated SAS (Shared Access Signature) token.
+    pub shared_access_signature: Option<&'a str>,
+    /// The queue, topic or event hub the connection string is scoped to.
+    pub entity_path: Option<&'a str>,
+}
+
+/// An Event Hubs connection string.
+///
+/// Event Hubs connection strings have the same format as the Service Bus ones, the entity path
+/// being the name of the event hub.
+pub type EventHubsConnectionString<'a> = ServiceBusConnectionString<'a>;
+
+impl<'a> ServiceBusConnectionString<'a> {
+    pub fn new(connection_string: &'a str) -> azure_core::Result<Self> {
+        let mut endpoint = None;
+        let mut shared_access_key_name = None;
+        let mut shared_access_key = None;
+        let mut shared_access_signature = None;
+        let mut entity_path = None;
+
+        let kv_str_pairs = connection_string
+            .split(';')
+            .filter(|s| !s.chars().all(char::is_whitespace));
+
+        for kv_pair_str in kv_str_pairs {
+            // keys are base64 encoded and may end with `=`
+            let (k, v) = match kv_pair_str.trim().split_once('=') {
+                Some((k, _)) if k.trim().is_empty() => {
+                    return Err(Error::message(
+                        ErrorKind::Other,
+                        "no key found in connection string",
+                    ))
+                }
+                Some((k, v)) if v.trim().is_empty() => {
+                    return Err(Error::with_message(ErrorKind::Other, || {
+                        format!("missing value in connection string for key: {k}")
+                    }))
+                }
+                Some((k, v)) => (k.trim(), v.trim()),
+                None => {
+                    return Err(Error::message(
+                        ErrorKind::Other,
+                        "no key/value found in connection string",
+                    ))
+                }
+            };
+
+            match k {
+                ENDPOINT_KEY_NAME => endpoint = Some(v),
+                SHARED_ACCESS_KEY_NAME_KEY_NAME => shared_access_key_name = Some(v),
+                SHARED_ACCESS_KEY_KEY_NAME => shared_access_key = Some(v),
+                SHARED_ACCESS_SIGNATURE_KEY_NAME => shared_access_signature = Some(v),
+                ENTITY_PATH_KEY_NAME => entity_path = Some(v),
+                k => {
+                    return Err(Error::with_message(ErrorKind::Other, || {
+                        format!("connection string unexpected key: {k}")
+                    }))
+                }
+            }
+        }
+
+        let endpoint = endpoint.ok_or_else(|| {
+            Error::with_message(ErrorKind::Other, || {
+                format!("connection string is missing the {ENDPOINT_KEY_NAME} key")
+            })
+        })?;
+
+        Ok(Self {
+            endpoint,
+            shared_access_key_name,
+            shared_access_key,
+            shared_access_signature,
+            entity_path,
+        })
+    }
+
+    /// The host name of the namespace, e.g. `mynamespace.servicebus.windows.net`.
+    pub fn fully_qualified_namespace(&self) -> azure_core::Result<String> {
+        let url = Url::parse(self.endpoint).with_context(ErrorKind::DataConversion, || {
+            format!("invalid endpoint in connection string: {}", self.endpoint)
+        })?;
+        url.host_str().map(ToOwned::to_owned).ok_or_else(|| {
+            Error::with_message(ErrorKind::DataConversion, || {
+                format!(
+                    "no host in the endpoint of connection string: {}",
+                    self.endpoint
+                )
+            })
+        })
+    }
+
+    /// The name of the namespace, e.g. `mynamespace`.
+    pub fn namespace(&self) -> azure_core::Result<String> {
+        let host = self.fully_qualified_namespace()?;
+        Ok(host.split('.').next().unwrap_or_default().to_owned())
+    }
+
+    /// Generates a SAS token valid for `ttl`, in the format expected by the `Authorization`
+    /// header.
+    ///
+    /// The token grants access to the entity of the connection string, or to the whole namespace
+    /// if there is none. A pre-generated `SharedAccessSignature` is returned as is.
+    pub fn generate_sas_token(&self, ttl: Duration) -> azure_core::Result<String> {
+        if let Some(signature) = self.shared_access_signature {
+            return Ok(signature.to_owned());
+        }
+        let (policy_name, key) = self.shared_access_policy()?;
+        let signing_key = hmac::Key::new(hmac::HMAC_SHA256, key.as_bytes());
+
+        let mut resource = format!("https://{}/", self.fully_qualified_namespace()?);
+        if let Some(entity_path) = self.entity_path {
+            resource.push_str(entity_path);
+        }
+        Ok(generate_signature(
+            policy_name,
+            &signing_key,
+            &resource,
+            ttl,
+        ))
+    }
+
+    /// The name and key of the shared access policy.
+    pub fn shared_access_policy(&self) -> azure_core::Result<(&'a str, &'a str)> {
+        match (self.shared_access_key_name, self.shared_access_key) {
+            (Some(name), Some(key)) => Ok((name, key)),
+            _ => Err(Error::with_message(ErrorKind::Credential, || {
+                format!(
+                    "connection string needs both {SHARED_ACCESS_KEY_NAME_KEY_NAME} and {SHARED_ACCESS_KEY_KEY_NAME} to sign requests"
+                )
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONNECTION_STRING: &str = "Endpoint=sb://mynamespace.servicebus.windows.net/;SharedAccessKeyName=RootManageSharedAccessKey;SharedAccessKey=c2VjcmV0a2V5=;EntityPath=myhub";
+
+    #[test]
+    fn parses_connection_strings() {
+        let connection_string = EventHubsConnectionString::new(CONNECTION_STRING).unwrap();
+        assert_eq!(
+            connection_string,
+            ServiceBusConnectionString {
+                endpoint: "sb://mynamespace.servicebus.windows.net/",
+                shared_access_key_name: Some("RootManageSharedAccessKey"),
+                shared_access_key: Some("c2VjcmV0a2V5="),
+                shared_access_signature: None,
+                entity_path: Some("myhub"),
+            }
+        );
+        assert_eq!(
+            connection_string.fully_qualified_namespace().unwrap(),
+            "mynamespace.servicebus.windows.net"
+        );
+        assert_eq!(connection_string.namespace().unwrap(), "mynamespace");
+
+        assert!(ServiceBusConnectionString::new("SharedAccessKeyName=name").is_err());
+        assert!(ServiceBusConnectionString::new("Endpoint=sb://a/;AccountKey=key").is_err());
+    }
+
+    #[test]
+    fn generates_sas_tokens() {
+        let connection_string = ServiceBusConnectionString::new(CONNECTION_STRING).unwrap();
+        let token = connection_string
+            .generate_sas_token(Duration::from_secs(3600))
+            .unwrap();
+        assert!(token.starts_with(
+            "SharedAccessSignature sr=https%3A%2F%2Fmynamespace.servicebus.windows.net%2Fmyhub&sig="
+        ));
+        assert!(token.ends_with("&skn=RootManageSharedAccessKey"));
+
+        let connection_string =
+            ServiceBusConnectionString::new("Endpoint=sb://mynamespace.servicebus.windows.net/")
+                .unwrap();
+        assert!(connection_string
+            .generate_sas_token(Duration::from_secs(3600))
+            .is_err());
+    }
+}
@@ -1 +1,2 @@
-pub use crate::service_bus::Client;
+pub use crate::service_bus::{Client, ClientBuilder, ConnectionState, ReconnectOptions};
+pub use crate::{EventHubsConnectionString, ServiceBusConnectionString};
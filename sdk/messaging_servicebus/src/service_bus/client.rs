@@ -1,16 +1,114 @@
 use std::sync::Arc;
 
 use crate::{
+    connection_string::ServiceBusConnectionString,
     service_bus::{
-        peek_lock_message, peek_lock_message2, receive_and_delete_message, send_message,
-        PeekLockResponse,
+        peek_lock_message, peek_lock_message2, receive_and_delete_message,
+        reconnect::{ConnectionMonitor, Replay},
+        send_message, ConnectionState, PeekLockResponse, ReceivedMessage, ReconnectOptions,
     },
-    utils::body_bytes_to_utf8,
+    utils::{body_bytes_to_utf8, JSON_CONTENT_TYPE},
 };
 use ring::hmac::Key;
+use serde::Serialize;
 use std::time::Duration;
 
-use azure_core::{error::Error, HttpClient};
+use azure_core::{
+    error::{Error, ErrorKind, ResultExt},
+    CollectedResponse, HttpClient,
+};
+use futures::Stream;
+
+/// A builder for the Service Bus client
+///
+/// The requests are sent with the default HTTP client unless another one is set with
+/// [`http_client`](Self::http_client). Unlike the builders of the HTTP clients of the other
+/// services, the client is located by its namespace and it has no `ClientOptions`, so its setters
+/// are named after what they set.
+#[derive(Clone)]
+pub struct ClientBuilder {
+    http_client: Arc<dyn HttpClient>,
+    namespace: String,
+    queue: String,
+    policy_name: String,
+    policy_key: String,
+    reconnect_options: ReconnectOptions,
+}
+
+impl std::fmt::Debug for ClientBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientBuilder")
+            .field("namespace", &self.namespace)
+            .field("queue", &self.queue)
+            .field("policy_name", &self.policy_name)
+            .field("reconnect_options", &self.reconnect_options)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ClientBuilder {
+    /// Creates a new builder for the queue of a namespace, authorized with a shared access policy
+    pub fn new(
+        namespace: impl Into<String>,
+        queue: impl Into<String>,
+        policy_name: impl Into<String>,
+        policy_key: impl Into<String>,
+    ) -> Self {
+        Self {
+            http_client: azure_core::new_http_client(),
+            namespace: namespace.into(),
+            queue: queue.into(),
+            policy_name: policy_name.into(),
+            policy_key: policy_key.into(),
+            reconnect_options: ReconnectOptions::default(),
+        }
+    }
+
+    /// Converts the builder into a client
+    pub fn build(self) -> Result<Client, Error> {
+        Ok(Client::new(
+            self.http_client,
+            self.namespace,
+            self.queue,
+            self.policy_name,
+            self.policy_key,
+        )?
+        .with_reconnect_options(self.reconnect_options))
+    }
+
+    /// Sets the namespace, e.g. `mynamespace` for `mynamespace.servicebus.windows.net`
+    #[must_use]
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = namespace.into();
+        self
+    }
+
+    /// Sets the name and the key of the shared access policy
+    #[must_use]
+    pub fn credential(
+        mut self,
+        policy_name: impl Into<String>,
+        policy_key: impl Into<String>,
+    ) -> Self {
+        self.policy_name = policy_name.into();
+        self.policy_key = policy_key.into();
+        self
+    }
+
+    /// Sets how the client reconnects, see [`Client::with_reconnect_options`]
+    #[must_use]
+    pub fn reconnect_options(mut self, reconnect_options: ReconnectOptions) -> Self {
+        self.reconnect_options = reconnect_options;
+        self
+    }
+
+    /// Sets the HTTP client the requests are sent with
+    #[must_use]
+    pub fn http_client(mut self, http_client: Arc<dyn HttpClient>) -> Self {
+        self.http_client = http_client;
+        self
+    }
+}
 
 /// Client object that allows interaction with the ServiceBus API
 #[derive(Debug, Clone)]
@@ -20,6 +118,8 @@ pub struct Client {
     queue: String,
     policy_name: String,
     signing_key: Key,
+    reconnect_options: ReconnectOptions,
+    connection: Arc<ConnectionMonitor>,
 }
 
 impl Client {
@@ -45,35 +145,142 @@ impl Client {
             queue: queue.into(),
             policy_name: policy_name.into(),
             signing_key,
+            reconnect_options: ReconnectOptions::default(),
+            connection: Arc::new(ConnectionMonitor::new()),
         })
     }
 
+    /// Creates a new builder for the queue of a namespace, authorized with a shared access policy
+    pub fn builder(
+        namespace: impl Into<String>,
+        queue: impl Into<String>,
+        policy_name: impl Into<String>,
+        policy_key: impl Into<String>,
+    ) -> ClientBuilder {
+        ClientBuilder::new(namespace, queue, policy_name, policy_key)
+    }
+
+    /// Sets how the client reconnects when the namespace is busy or unreachable
+    ///
+    /// The operations rejected by a busy namespace, e.g. during its scheduled maintenance, are
+    /// sent again. The operations interrupted by a lost connection are sent again only if it is
+    /// safe: peeking messages is, while sending or receiving and deleting a message is not, as it
+    /// could have been processed before the connection was lost.
+    #[must_use]
+    pub fn with_reconnect_options(mut self, reconnect_options: ReconnectOptions) -> Self {
+        self.reconnect_options = reconnect_options;
+        self
+    }
+
+    /// A stream of the states of the connection to the namespace, starting with the current one
+    ///
+    /// The clones of the client share their connection state, so hosts can report the health of
+    /// all of them from one stream.
+    pub fn connection_state(&self) -> impl Stream<Item = ConnectionState> + Send + Unpin {
+        self.connection.subscribe()
+    }
+
+    /// Creates a new client instance from a connection string
+    ///
+    /// The connection string must contain the name and key of a shared access policy, and the
+    /// name of the queue as `EntityPath`.
+    pub fn from_connection_string(
+        http_client: Arc<dyn HttpClient>,
+        connection_string: &str,
+    ) -> Result<Client, Error> {
+        let connection_string = ServiceBusConnectionString::new(connection_string)?;
+        let queue = connection_string.entity_path.ok_or_else(|| {
+            Error::message(
+                ErrorKind::Other,
+                "connection string is missing the EntityPath of the queue",
+            )
+        })?;
+        let (policy_name, policy_key) = connection_string.shared_access_policy()?;
+        Self::new(
+            http_client,
+            connection_string.namespace()?,
+            queue,
+            policy_name,
+            policy_key,
+        )
+    }
+
     /// Sends a message to the queue
     pub async fn send_message(&self, msg: &str) -> Result<(), Error> {
-        send_message(
-            &self.http_client,
-            &self.namespace,
-            &self.queue,
-            &self.policy_name,
-            &self.signing_key,
-            msg,
-        )
-        .await
+        self.connection
+            .run(&self.reconnect_options, Replay::IfRejected, || {
+                send_message(
+                    &self.http_client,
+                    &self.namespace,
+                    &self.queue,
+                    &self.policy_name,
+                    &self.signing_key,
+                    msg,
+                    None,
+                )
+            })
+            .await
+    }
+
+    /// Sends a message with the given content type to the queue
+    pub async fn send_message_with_content_type(
+        &self,
+        msg: &str,
+        content_type: &str,
+    ) -> Result<(), Error> {
+        self.connection
+            .run(&self.reconnect_options, Replay::IfRejected, || {
+                send_message(
+                    &self.http_client,
+                    &self.namespace,
+                    &self.queue,
+                    &self.policy_name,
+                    &self.signing_key,
+                    msg,
+                    Some(content_type),
+                )
+            })
+            .await
+    }
+
+    /// Serializes a message as JSON and sends it to the queue
+    ///
+    /// The content type of the message is set to `application/json` so that receivers can
+    /// deserialize it with `body_as`.
+    pub async fn send_json<T: Serialize>(&self, msg: &T) -> Result<(), Error> {
+        let msg = serde_json::to_string(msg).context(
+            ErrorKind::DataConversion,
+            "failed to serialize the message as JSON",
+        )?;
+        self.send_message_with_content_type(&msg, JSON_CONTENT_TYPE)
+            .await
     }
 
     /// Receive and delete a message
     pub async fn receive_and_delete_message(&self) -> Result<String, Error> {
-        body_bytes_to_utf8(
-            receive_and_delete_message(
-                &self.http_client,
-                &self.namespace,
-                &self.queue,
-                &self.policy_name,
-                &self.signing_key,
-            )
-            .await?
-            .body(),
-        )
+        body_bytes_to_utf8(self.receive_and_delete().await?.body())
+    }
+
+    /// Receive and delete a message, keeping its content type
+    ///
+    /// Note: This function returns a `ReceivedMessage`
+    /// that can deserialize JSON messages with `body_as`.
+    pub async fn receive_and_delete_message2(&self) -> Result<ReceivedMessage, Error> {
+        Ok(self.receive_and_delete().await?.into())
+    }
+
+    async fn receive_and_delete(&self) -> Result<CollectedResponse, Error> {
+        self.connection
+            .run(&self.reconnect_options, Replay::IfRejected, || {
+                receive_and_delete_message(
+                    &self.http_client,
+                    &self.namespace,
+                    &self.queue,
+                    &self.policy_name,
+                    &self.signing_key,
+                )
+            })
+            .await
     }
 
     /// Non-destructively read a message
@@ -85,18 +292,20 @@ impl Client {
     /// track of this message (i.e., have the possibility of deletion),
     /// use `peek_lock_message2`.
     pub async fn peek_lock_message(&self, lock_expiry: Option<Duration>) -> Result<String, Error> {
-        body_bytes_to_utf8(
-            peek_lock_message(
-                &self.http_client,
-                &self.namespace,
-                &self.queue,
-                &self.policy_name,
-                &self.signing_key,
-                lock_expiry,
-            )
-            .await?
-            .body(),
-        )
+        let response = self
+            .connection
+            .run(&self.reconnect_options, Replay::Always, || {
+                peek_lock_message(
+                    &self.http_client,
+                    &self.namespace,
+                    &self.queue,
+                    &self.policy_name,
+                    &self.signing_key,
+                    lock_expiry,
+                )
+            })
+            .await?;
+        body_bytes_to_utf8(response.body())
     }
 
     /// Non-destructively read a message but track it
@@ -107,14 +316,54 @@ impl Client {
         &self,
         timeout: Option<Duration>,
     ) -> Result<PeekLockResponse, Error> {
-        peek_lock_message2(
-            &self.http_client,
-            &self.namespace,
-            &self.queue,
-            &self.policy_name,
-            &self.signing_key,
-            timeout,
-        )
-        .await
+        self.connection
+            .run(&self.reconnect_options, Replay::Always, || {
+                peek_lock_message2(
+                    &self.http_client,
+                    &self.namespace,
+                    &self.queue,
+                    &self.policy_name,
+                    &self.signing_key,
+                    timeout,
+                )
+            })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signature(key: &Key) -> Vec<u8> {
+        ring::hmac::sign(key, b"message").as_ref().to_vec()
+    }
+
+    #[test]
+    fn builds_the_client_with_the_setters() {
+        let reconnect_options = ReconnectOptions::new().max_retries(1);
+        let client = Client::builder("namespace", "queue", "policy", "key")
+            .namespace("other-namespace")
+            .credential("other-policy", "other-key")
+            .reconnect_options(reconnect_options.clone())
+            .build()
+            .unwrap();
+        assert_eq!(client.namespace, "other-namespace");
+        assert_eq!(client.queue, "queue");
+        assert_eq!(client.policy_name, "other-policy");
+        assert_eq!(
+            signature(&client.signing_key),
+            signature(&Key::new(ring::hmac::HMAC_SHA256, b"other-key"))
+        );
+        assert_eq!(client.reconnect_options, reconnect_options);
+    }
+
+    #[test]
+    fn builds_the_client_with_the_defaults() {
+        let client = ClientBuilder::new("namespace", "queue", "policy", "key")
+            .build()
+            .unwrap();
+        assert_eq!(client.namespace, "namespace");
+        assert_eq!(client.reconnect_options, ReconnectOptions::default());
     }
 }
@@ -1,16 +1,21 @@
 use azure_core::{error::Error, headers, CollectedResponse, HttpClient, Request, Url};
 use azure_core::{Method, StatusCode};
 use ring::hmac;
+use serde::de::DeserializeOwned;
 use std::time::Duration;
 use std::{ops::Add, sync::Arc};
 use time::OffsetDateTime;
 use url::form_urlencoded::{self, Serializer};
 
 mod client;
+mod received_message;
+mod reconnect;
 
-use crate::utils::craft_peek_lock_url;
+use crate::utils::{body_from_json, craft_peek_lock_url};
 
-pub use self::client::Client;
+pub use self::client::{Client, ClientBuilder};
+pub use self::received_message::ReceivedMessage;
+pub use self::reconnect::{ConnectionState, ReconnectOptions};
 
 /// Default duration for the SAS token in days — We might want to make this configurable at some point
 const DEFAULT_SAS_DURATION: u64 = 3_600; // seconds = 1 hour
@@ -20,6 +25,7 @@ fn finalize_request(
     url: &str,
     method: azure_core::Method,
     body: Option<String>,
+    content_type: Option<&str>,
     policy_name: &str,
     signing_key: &hmac::Key,
 ) -> azure_core::Result<Request> {
@@ -37,6 +43,10 @@ fn finalize_request(
     // add auth header with sas
     request.insert_header(headers::AUTHORIZATION, sas);
 
+    if let Some(content_type) = content_type {
+        request.insert_header(headers::CONTENT_TYPE, content_type.to_owned());
+    }
+
     // get req body to return
     match body {
         Some(msg) => request.set_body(msg),
@@ -50,7 +60,7 @@ fn finalize_request(
 }
 
 /// Generates a SAS signature
-fn generate_signature(
+pub(crate) fn generate_signature(
     policy_name: &str,
     signing_key: &hmac::Key,
     url: &str,
@@ -85,6 +95,7 @@ async fn send_message(
     policy_name: &str,
     signing_key: &hmac::Key,
     msg: &str,
+    content_type: Option<&str>,
 ) -> azure_core::Result<()> {
     let url = format!(
         "https://{}.servicebus.windows.net/{}/messages",
@@ -95,6 +106,7 @@ async fn send_message(
         &url,
         Method::Post,
         Some(msg.to_string()),
+        content_type,
         policy_name,
         signing_key,
     )?;
@@ -119,7 +131,7 @@ async fn receive_and_delete_message(
         namespace, queue
     );
 
-    let req = finalize_request(&url, Method::Delete, None, policy_name, signing_key)?;
+    let req = finalize_request(&url, Method::Delete, None, None, policy_name, signing_key)?;
 
     http_client
         .as_ref()
@@ -145,7 +157,14 @@ async fn peek_lock_message(
 ) -> azure_core::Result<CollectedResponse> {
     let url = craft_peek_lock_url(namespace, queue, lock_expiry)?;
 
-    let req = finalize_request(url.as_ref(), Method::Post, None, policy_name, signing_key)?;
+    let req = finalize_request(
+        url.as_ref(),
+        Method::Post,
+        None,
+        None,
+        policy_name,
+        signing_key,
+    )?;
 
     http_client
         .as_ref()
@@ -167,7 +186,14 @@ async fn peek_lock_message2(
 ) -> azure_core::Result<PeekLockResponse> {
     let url = craft_peek_lock_url(namespace, queue, lock_expiry)?;
 
-    let req = finalize_request(url.as_ref(), Method::Post, None, policy_name, signing_key)?;
+    let req = finalize_request(
+        url.as_ref(),
+        Method::Post,
+        None,
+        None,
+        policy_name,
+        signing_key,
+    )?;
 
     let res = http_client.execute_request(&req).await?;
 
@@ -176,10 +202,12 @@ async fn peek_lock_message2(
         .headers()
         .get_optional_string(&headers::LOCATION)
         .unwrap_or_default();
+    let content_type = res.headers().get_optional_string(&headers::CONTENT_TYPE);
     let body = res.into_body().collect_string().await?;
 
     Ok(PeekLockResponse {
         body,
+        content_type,
         lock_location,
         status,
         http_client: http_client.clone(),
@@ -191,6 +219,7 @@ async fn peek_lock_message2(
 /// PeekLockResponse object that is returned by `peek_lock_message2`
 pub struct PeekLockResponse {
     body: String,
+    content_type: Option<String>,
     lock_location: String,
     status: StatusCode,
     http_client: Arc<dyn HttpClient>,
@@ -204,6 +233,18 @@ impl PeekLockResponse {
         self.body.clone()
     }
 
+    /// Get the content type of the message, if it was set by the sender
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// Deserialize the JSON message in the lock
+    ///
+    /// Fails if the message has a content type other than JSON.
+    pub fn body_as<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        body_from_json(self.body.as_bytes(), self.content_type())
+    }
+
     /// Get the status of the peek
     pub fn status(&self) -> &StatusCode {
         &self.status
@@ -215,6 +256,7 @@ impl PeekLockResponse {
             &self.lock_location.clone(),
             Method::Delete,
             None,
+            None,
             &self.policy_name,
             &self.signing_key,
         )?;
@@ -231,6 +273,7 @@ impl PeekLockResponse {
             &self.lock_location.clone(),
             Method::Put,
             None,
+            None,
             &self.policy_name,
             &self.signing_key,
         )?;
@@ -248,6 +291,7 @@ impl PeekLockResponse {
             &self.lock_location.clone(),
             Method::Post,
             None,
+            None,
             &self.policy_name,
             &self.signing_key,
         )?;
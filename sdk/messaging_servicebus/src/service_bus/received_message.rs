@@ -0,0 +1,46 @@
+use crate::utils::{body_bytes_to_utf8, body_from_json};
+use azure_core::{error::Error, headers, CollectedResponse};
+use bytes::Bytes;
+use serde::de::DeserializeOwned;
+
+/// A message received from the queue, along with its content type
+#[derive(Debug, Clone)]
+pub struct ReceivedMessage {
+    body: Bytes,
+    content_type: Option<String>,
+}
+
+impl ReceivedMessage {
+    /// Get the raw body of the message
+    pub fn body(&self) -> &Bytes {
+        &self.body
+    }
+
+    /// Get the body of the message as a string
+    pub fn body_as_str(&self) -> Result<String, Error> {
+        body_bytes_to_utf8(&self.body)
+    }
+
+    /// Deserialize the JSON body of the message
+    ///
+    /// Fails if the message has a content type other than JSON.
+    pub fn body_as<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        body_from_json(&self.body, self.content_type())
+    }
+
+    /// Get the content type of the message, if it was set by the sender
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+}
+
+impl From<CollectedResponse> for ReceivedMessage {
+    fn from(response: CollectedResponse) -> Self {
+        Self {
+            content_type: response
+                .headers()
+                .get_optional_string(&headers::CONTENT_TYPE),
+            body: response.body().clone(),
+        }
+    }
+}
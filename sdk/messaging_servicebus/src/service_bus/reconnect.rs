@@ -0,0 +1,274 @@
+use azure_core::error::{Error, ErrorKind};
+use azure_core::StatusCode;
+use futures::channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// How the client reconnects when the namespace is busy, e.g. during its scheduled maintenance,
+/// or when the connection to it is lost.
+///
+/// The attempts are delayed with an exponential back-off, with an added random delay so that
+/// the clients of a namespace do not all reconnect at the same time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReconnectOptions {
+    max_retries: u32,
+    initial_delay: Duration,
+    max_delay: Duration,
+}
+
+impl Default for ReconnectOptions {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+        }
+    }
+}
+
+impl ReconnectOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The maximum number of attempts after the first one. Zero disables reconnecting.
+    #[must_use]
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// The delay before the first reconnect, doubled for each of the next ones.
+    #[must_use]
+    pub fn initial_delay(mut self, initial_delay: Duration) -> Self {
+        self.initial_delay = initial_delay;
+        self
+    }
+
+    /// The maximum delay between two attempts.
+    #[must_use]
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    fn delay(&self, attempt: u32) -> Duration {
+        let delay = self
+            .initial_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)));
+        let jitter = Duration::from_millis(u64::from(rand::random::<u8>()) * 4);
+        (delay + jitter).min(self.max_delay)
+    }
+}
+
+/// The state of the connection of a client to its namespace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// The last operation reached the namespace.
+    Connected,
+    /// The namespace is busy or unreachable, and the operation is attempted again after `delay`.
+    Reconnecting { attempt: u32, delay: Duration },
+    /// The namespace stayed busy or unreachable after all the attempts.
+    Disconnected,
+}
+
+/// Whether an operation can be sent again after a failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Replay {
+    /// The operation is replayed after any transient failure: sending it twice has the same
+    /// effect as sending it once, or a lost response only delays a message, as a lost lock.
+    Always,
+    /// The operation is replayed only when the namespace rejected it without processing it, as
+    /// replaying it after a lost response could send or delete a message twice.
+    IfRejected,
+}
+
+/// Tracks the connection state and publishes its changes to the subscribers.
+#[derive(Debug)]
+pub(crate) struct ConnectionMonitor {
+    state: Mutex<ConnectionState>,
+    subscribers: Mutex<Vec<UnboundedSender<ConnectionState>>>,
+}
+
+impl ConnectionMonitor {
+    pub(crate) fn new() -> Self {
+        Self {
+            state: Mutex::new(ConnectionState::Connected),
+            subscribers: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// A stream of the state changes, starting with the current state.
+    pub(crate) fn subscribe(&self) -> UnboundedReceiver<ConnectionState> {
+        let (sender, receiver) = unbounded();
+        let state = self.state.lock().unwrap().clone();
+        // the receiver cannot be closed yet
+        let _ = sender.unbounded_send(state);
+        self.subscribers.lock().unwrap().push(sender);
+        receiver
+    }
+
+    fn set(&self, state: ConnectionState) {
+        let mut current = self.state.lock().unwrap();
+        if *current == state {
+            return;
+        }
+        *current = state.clone();
+        // the subscribers which dropped their stream are removed
+        self.subscribers
+            .lock()
+            .unwrap()
+            .retain(|subscriber| subscriber.unbounded_send(state.clone()).is_ok());
+    }
+
+    /// Runs `operation`, and runs it again after the transient failures it can be replayed on.
+    pub(crate) async fn run<F, Fut, T>(
+        &self,
+        options: &ReconnectOptions,
+        replay: Replay,
+        operation: F,
+    ) -> azure_core::Result<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = azure_core::Result<T>>,
+    {
+        let mut attempt = 0;
+        loop {
+            let error = match operation().await {
+                Ok(value) => {
+                    self.set(ConnectionState::Connected);
+                    return Ok(value);
+                }
+                Err(error) => error,
+            };
+            let failure = match classify(&error) {
+                Some(failure) => failure,
+                // the namespace processed the operation
+                None => {
+                    self.set(ConnectionState::Connected);
+                    return Err(error);
+                }
+            };
+            if attempt >= options.max_retries {
+                self.set(ConnectionState::Disconnected);
+                return Err(error);
+            }
+            if failure == Failure::ConnectionLost && replay == Replay::IfRejected {
+                // the namespace may have processed it, it is up to the caller to check
+                self.set(ConnectionState::Disconnected);
+                return Err(error);
+            }
+
+            attempt += 1;
+            let delay = options.delay(attempt);
+            log::debug!("reconnecting in {delay:?}, attempt {attempt}: {error}");
+            self.set(ConnectionState::Reconnecting { attempt, delay });
+            azure_core::sleep::sleep(delay).await;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Failure {
+    /// The namespace rejected the operation without processing it.
+    Busy,
+    /// The operation may or may not have been processed.
+    ConnectionLost,
+}
+
+fn classify(error: &Error) -> Option<Failure> {
+    match error.kind() {
+        // `ServerBusy`, returned when the namespace is throttled or under maintenance
+        ErrorKind::HttpResponse {
+            status: StatusCode::ServiceUnavailable | StatusCode::TooManyRequests,
+            ..
+        } => Some(Failure::Busy),
+        ErrorKind::HttpResponse {
+            status:
+                StatusCode::InternalServerError
+                | StatusCode::BadGateway
+                | StatusCode::GatewayTimeout
+                | StatusCode::RequestTimeout,
+            ..
+        }
+        | ErrorKind::Io
+        | ErrorKind::Timeout => Some(Failure::ConnectionLost),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn options() -> ReconnectOptions {
+        ReconnectOptions::new()
+            .max_retries(2)
+            .initial_delay(Duration::from_millis(1))
+            .max_delay(Duration::from_millis(1))
+    }
+
+    fn http_error(status: StatusCode) -> Error {
+        ErrorKind::http_response(status, None).into_error()
+    }
+
+    #[tokio::test]
+    async fn replays_operations_rejected_by_a_busy_namespace() {
+        let monitor = ConnectionMonitor::new();
+        let mut states = monitor.subscribe();
+        let calls = &AtomicU32::new(0);
+
+        let result = monitor
+            .run(&options(), Replay::IfRejected, || async move {
+                match calls.fetch_add(1, Ordering::SeqCst) {
+                    0 => Err(http_error(StatusCode::ServiceUnavailable)),
+                    _ => Ok(()),
+                }
+            })
+            .await;
+        assert!(result.is_ok());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        drop(monitor);
+        let states = states.by_ref().collect::<Vec<_>>().await;
+        assert_eq!(
+            states,
+            vec![
+                ConnectionState::Connected,
+                ConnectionState::Reconnecting {
+                    attempt: 1,
+                    delay: Duration::from_millis(1)
+                },
+                ConnectionState::Connected,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn replays_only_idempotent_operations_after_a_lost_connection() {
+        let monitor = ConnectionMonitor::new();
+        let calls = &AtomicU32::new(0);
+        let operation = || async move {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err::<(), _>(Error::message(ErrorKind::Io, "connection reset"))
+        };
+
+        assert!(monitor
+            .run(&options(), Replay::IfRejected, operation)
+            .await
+            .is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        assert!(monitor
+            .run(&options(), Replay::Always, operation)
+            .await
+            .is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+
+        let mut states = monitor.subscribe();
+        assert_eq!(states.next().await, Some(ConnectionState::Disconnected));
+    }
+}
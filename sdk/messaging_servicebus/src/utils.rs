@@ -1,4 +1,5 @@
 use azure_core::error::{Error, ErrorKind, ResultExt};
+use serde::de::DeserializeOwned;
 use std::time::Duration;
 use url::Url;
 
@@ -33,3 +34,64 @@ pub fn body_bytes_to_utf8(bytes: &[u8]) -> Result<String, Error> {
         )?
         .to_string())
 }
+
+/// The content type of JSON message bodies.
+pub const JSON_CONTENT_TYPE: &str = "application/json";
+
+/// Returns `true` if the content type is JSON (`application/json` or `application/*+json`).
+pub fn is_json_content_type(content_type: &str) -> bool {
+    let media_type = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    media_type == JSON_CONTENT_TYPE
+        || (media_type.starts_with("application/") && media_type.ends_with("+json"))
+}
+
+/// Deserializes a JSON message body.
+///
+/// Fails if the message has a content type which is not JSON. Messages without a content type
+/// are assumed to be JSON.
+pub fn body_from_json<T>(bytes: &[u8], content_type: Option<&str>) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    if let Some(content_type) = content_type {
+        if !is_json_content_type(content_type) {
+            return Err(Error::with_message(ErrorKind::DataConversion, || {
+                format!(
+                    "cannot deserialize a message body with content type {content_type} as JSON"
+                )
+            }));
+        }
+    }
+    serde_json::from_slice(bytes).context(
+        ErrorKind::DataConversion,
+        "failed to deserialize the message body",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_content_types() {
+        assert!(is_json_content_type("application/json"));
+        assert!(is_json_content_type("application/json; charset=utf-8"));
+        assert!(is_json_content_type("application/cloudevents+json"));
+        assert!(!is_json_content_type("text/plain"));
+    }
+
+    #[test]
+    fn body_from_json_checks_content_type() {
+        let body = br#"{"id":1}"#;
+        let value: serde_json::Value = body_from_json(body, Some("application/json")).unwrap();
+        assert_eq!(value["id"], 1);
+        let value: serde_json::Value = body_from_json(body, None).unwrap();
+        assert_eq!(value["id"], 1);
+        assert!(body_from_json::<serde_json::Value>(body, Some("text/plain")).is_err());
+    }
+}
@@ -0,0 +1,174 @@
+use crate::prelude::*;
+use azure_core::{error::Error, headers::Headers, CollectedResponse, Method, StatusCode};
+use serde::Deserialize;
+
+/// The collections probed for the recovery settings of the vault, in order.
+const PROBED_COLLECTIONS: [&str; 3] = ["secrets", "keys", "certificates"];
+
+operation! {
+    /// Reads the properties of the vault which matter to the data plane, by probing the service.
+    ///
+    /// The management plane is not called: the recovery level and retention days are read from
+    /// the attributes of the first secret, key or certificate which can be listed, and the
+    /// authorization mode from the error returned when listing is forbidden. The properties
+    /// which could not be observed are `None`, e.g. all of them for an empty vault.
+    GetVaultProperties,
+    client: KeyvaultClient,
+}
+
+impl GetVaultPropertiesBuilder {
+    pub fn into_future(mut self) -> GetVaultProperties {
+        Box::pin(async move {
+            let mut properties = VaultProperties::default();
+            for collection in PROBED_COLLECTIONS {
+                let mut url = self.client.vault_url.clone();
+                url.set_path(collection);
+                let mut request =
+                    self.client
+                        .finalize_request(url, Method::Get, Headers::new(), None)?;
+
+                let response = match self.client.send(&mut self.context, &mut request).await {
+                    Ok(response) => response,
+                    Err(error) => {
+                        let authorization_mode = authorization_mode_from_error(&error);
+                        if authorization_mode.is_none() {
+                            return Err(error);
+                        }
+                        properties.authorization_mode = authorization_mode;
+                        continue;
+                    }
+                };
+                let response = CollectedResponse::from_response(response).await?;
+                let items: ListResponse = azure_core::from_json(response.body())?;
+                if let Some(attributes) = items
+                    .value
+                    .into_iter()
+                    .map(|item| item.attributes)
+                    .find(|attributes| attributes.recovery_level.is_some())
+                {
+                    properties.recovery_level = attributes.recovery_level;
+                    properties.soft_delete_retention_days = attributes.recoverable_days;
+                    break;
+                }
+            }
+            Ok(properties)
+        })
+    }
+}
+
+/// How the access to the data plane of the vault is authorized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VaultAuthorizationMode {
+    /// Azure role-based access control.
+    Rbac,
+    /// The access policies of the vault.
+    AccessPolicy,
+}
+
+/// The properties of a vault, as observed by [`KeyvaultClient::get_vault_properties`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VaultProperties {
+    /// The deletion recovery level in effect for the objects of the vault, e.g.
+    /// `Recoverable+Purgeable`.
+    pub recovery_level: Option<String>,
+    /// The number of days a deleted object is retained before it is purged.
+    pub soft_delete_retention_days: Option<u32>,
+    /// How the access to the vault is authorized, known only if listing was forbidden.
+    pub authorization_mode: Option<VaultAuthorizationMode>,
+}
+
+impl VaultProperties {
+    /// Whether deleted objects can be recovered until they are purged.
+    pub fn soft_delete_enabled(&self) -> Option<bool> {
+        self.recovery_level
+            .as_deref()
+            .map(|level| level.contains("Recoverable"))
+    }
+
+    /// Whether purge protection is enabled: deleted objects cannot be purged before the end of
+    /// the retention period, so they must be recovered, or their names cannot be reused until
+    /// then.
+    pub fn purge_protection_enabled(&self) -> Option<bool> {
+        self.recovery_level
+            .as_deref()
+            .map(|level| level.contains("Recoverable") && !level.contains("Purgeable"))
+    }
+}
+
+pub type GetVaultPropertiesResponse = VaultProperties;
+
+#[derive(Deserialize)]
+struct ListResponse {
+    #[serde(default)]
+    value: Vec<ListItem>,
+}
+
+#[derive(Deserialize)]
+struct ListItem {
+    attributes: RecoveryAttributes,
+}
+
+#[derive(Deserialize)]
+struct RecoveryAttributes {
+    #[serde(rename = "recoveryLevel")]
+    recovery_level: Option<String>,
+    #[serde(rename = "recoverableDays")]
+    recoverable_days: Option<u32>,
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    error: ErrorDetails,
+}
+
+#[derive(Deserialize)]
+struct ErrorDetails {
+    innererror: Option<InnerError>,
+}
+
+#[derive(Deserialize)]
+struct InnerError {
+    code: String,
+}
+
+/// The authorization mode of the vault which forbade a request, e.g. from
+/// `{"error":{"code":"Forbidden","innererror":{"code":"ForbiddenByRbac"}}}`.
+fn authorization_mode_from_error(error: &Error) -> Option<VaultAuthorizationMode> {
+    let http_error = error.as_http_error()?;
+    if http_error.status() != StatusCode::Forbidden {
+        return None;
+    }
+    let response: ErrorResponse = serde_json::from_slice(http_error.body()).ok()?;
+    match response.error.innererror?.code.as_str() {
+        "ForbiddenByRbac" => Some(VaultAuthorizationMode::Rbac),
+        "ForbiddenByPolicy" | "AccessDenied" => Some(VaultAuthorizationMode::AccessPolicy),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derives_the_purge_protection_from_the_recovery_level() {
+        let properties = |level: &str| VaultProperties {
+            recovery_level: Some(level.to_owned()),
+            ..VaultProperties::default()
+        };
+        assert_eq!(
+            properties("Recoverable+Purgeable").purge_protection_enabled(),
+            Some(false)
+        );
+        assert_eq!(
+            properties("CustomizedRecoverable").purge_protection_enabled(),
+            Some(true)
+        );
+        assert_eq!(
+            properties("Recoverable+ProtectedSubscription").purge_protection_enabled(),
+            Some(true)
+        );
+        assert_eq!(properties("Purgeable").soft_delete_enabled(), Some(false));
+        assert_eq!(VaultProperties::default().soft_delete_enabled(), None);
+    }
+}
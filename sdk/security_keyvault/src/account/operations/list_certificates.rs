@@ -40,7 +40,7 @@ impl ListCertificatesBuilder {
                 let response = CollectedResponse::from_response(response).await?;
                 let body = response.body();
 
-                let response = serde_json::from_slice::<KeyVaultGetCertificatesResponse>(body)?;
+                let response = azure_core::from_json::<_, KeyVaultGetCertificatesResponse>(body)?;
                 Ok(response)
             }
         };
@@ -1,8 +1,10 @@
+mod get_vault_properties;
 mod list_certificates;
 mod list_secrets;
 mod restore_certificate;
 mod restore_secret;
 
+pub use get_vault_properties::*;
 pub use list_certificates::*;
 pub use list_secrets::*;
 pub use restore_certificate::*;
@@ -123,3 +123,38 @@ pub struct CertificateProperties {
     pub updated_on: OffsetDateTime,
     pub enabled: bool,
 }
+
+/// The issuance of a certificate.
+#[derive(Deserialize, Debug, Clone)]
+pub struct CertificateOperation {
+    pub id: String,
+    /// `inProgress`, `completed`, `failed` or `cancelled`.
+    pub status: String,
+    #[serde(default)]
+    pub status_details: Option<String>,
+    #[serde(default)]
+    pub cancellation_requested: bool,
+    #[serde(default)]
+    pub error: Option<CertificateOperationError>,
+    /// The certificate, once the issuance is completed.
+    #[serde(default)]
+    pub target: Option<String>,
+}
+
+impl CertificateOperation {
+    /// Whether the issuance is still in progress.
+    pub fn is_in_progress(&self) -> bool {
+        self.status.eq_ignore_ascii_case("inProgress")
+    }
+
+    /// Whether the certificate was issued.
+    pub fn is_completed(&self) -> bool {
+        self.status.eq_ignore_ascii_case("completed")
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct CertificateOperationError {
+    pub code: String,
+    pub message: String,
+}
@@ -28,7 +28,7 @@ impl CertificateBackupBuilder {
             let response = CollectedResponse::from_response(response).await?;
             let body = response.body();
 
-            let backup_blob = serde_json::from_slice(body)?;
+            let backup_blob = azure_core::from_json(body)?;
             Ok(backup_blob)
         })
     }
@@ -0,0 +1,103 @@
+use crate::prelude::*;
+use azure_core::{headers::Headers, CollectedResponse, Method};
+use serde::Serialize;
+
+operation! {
+    /// Creates a new version of a certificate, issued according to the policy of the certificate.
+    ///
+    /// The issuance is asynchronous: the returned operation can be followed with
+    /// [`CertificateClient::get_operation`](crate::CertificateClient::get_operation).
+    CreateCertificate,
+    client: CertificateClient,
+    name: String,
+    ?enabled: bool
+}
+
+#[derive(Serialize, Debug)]
+struct Attributes {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+}
+
+#[derive(Serialize, Debug)]
+struct CreateRequest {
+    attributes: Attributes,
+}
+
+impl CreateCertificateBuilder {
+    pub fn into_future(mut self) -> CreateCertificate {
+        Box::pin(async move {
+            let mut uri = self.client.keyvault_client.vault_url.clone();
+            uri.set_path(&format!("certificates/{}/create", self.name));
+
+            // without a policy, the existing policy of the certificate is used
+            let request = CreateRequest {
+                attributes: Attributes {
+                    enabled: self.enabled,
+                },
+            };
+            let body = serde_json::to_string(&request)?;
+
+            let headers = Headers::new();
+            let mut request = self.client.keyvault_client.finalize_request(
+                uri,
+                Method::Post,
+                headers,
+                Some(body.into()),
+            )?;
+
+            let response = self
+                .client
+                .keyvault_client
+                .send(&mut self.context, &mut request)
+                .await?;
+
+            let response = CollectedResponse::from_response(response).await?;
+            azure_core::from_json(response.body())
+        })
+    }
+}
+
+type CreateCertificateResponse = CertificateOperation;
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::mock_client;
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn creates_a_version_with_the_existing_policy() -> azure_core::Result<()> {
+        let _m = mock("POST", "/certificates/created-certificate/create")
+            .match_query(Matcher::UrlEncoded(
+                "api-version".into(),
+                crate::API_VERSION.into(),
+            ))
+            .match_header("authorization", "Bearer TOKEN")
+            .match_body(Matcher::Json(json!({ "attributes": { "enabled": false } })))
+            .with_status(202)
+            .with_body(
+                json!({
+                    "id": "https://vault/certificates/created-certificate/pending",
+                    "status": "inProgress",
+                    "status_details": "Pending certificate created.",
+                    "cancellation_requested": false
+                })
+                .to_string(),
+            )
+            .create();
+
+        let operation = mock_client()
+            .certificate_client()
+            .create("created-certificate")
+            .enabled(false)
+            .into_future()
+            .await?;
+        assert!(operation.is_in_progress());
+        assert_eq!(
+            operation.status_details.as_deref(),
+            Some("Pending certificate created.")
+        );
+        Ok(())
+    }
+}
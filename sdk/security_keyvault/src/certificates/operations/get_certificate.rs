@@ -30,7 +30,7 @@ impl GetCertificateBuilder {
             let response = CollectedResponse::from_response(response).await?;
             let body = response.body();
 
-            let response: KeyVaultGetCertificateResponse = serde_json::from_slice(body)?;
+            let response: KeyVaultGetCertificateResponse = azure_core::from_json(body)?;
 
             Ok(response)
         })
@@ -0,0 +1,69 @@
+use crate::prelude::*;
+use azure_core::{headers::Headers, CollectedResponse, Method};
+
+operation! {
+    GetCertificateOperation,
+    client: CertificateClient,
+    name: String
+}
+
+impl GetCertificateOperationBuilder {
+    pub fn into_future(mut self) -> GetCertificateOperation {
+        Box::pin(async move {
+            let mut uri = self.client.keyvault_client.vault_url.clone();
+            uri.set_path(&format!("certificates/{}/pending", self.name));
+
+            let headers = Headers::new();
+            let mut request =
+                self.client
+                    .keyvault_client
+                    .finalize_request(uri, Method::Get, headers, None)?;
+
+            let response = self
+                .client
+                .keyvault_client
+                .send(&mut self.context, &mut request)
+                .await?;
+
+            let response = CollectedResponse::from_response(response).await?;
+            azure_core::from_json(response.body())
+        })
+    }
+}
+
+type GetCertificateOperationResponse = CertificateOperation;
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::mock_client;
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn gets_the_pending_operation() -> azure_core::Result<()> {
+        let _m = mock("GET", "/certificates/failed-certificate/pending")
+            .match_query(Matcher::UrlEncoded(
+                "api-version".into(),
+                crate::API_VERSION.into(),
+            ))
+            .with_body(
+                json!({
+                    "id": "https://vault/certificates/failed-certificate/pending",
+                    "status": "failed",
+                    "error": { "code": "CertificateIssuerError", "message": "issuer rejected" }
+                })
+                .to_string(),
+            )
+            .create();
+
+        let operation = mock_client()
+            .certificate_client()
+            .get_operation("failed-certificate")
+            .into_future()
+            .await?;
+        assert!(!operation.is_in_progress());
+        assert!(!operation.is_completed());
+        assert_eq!(operation.error.unwrap().code, "CertificateIssuerError");
+        Ok(())
+    }
+}
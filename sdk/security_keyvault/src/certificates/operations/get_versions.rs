@@ -39,7 +39,7 @@ impl GetCertificateVersionsBuilder {
                 let response = CollectedResponse::from_response(response).await?;
                 let body = response.body();
 
-                let response = serde_json::from_slice::<KeyVaultGetCertificatesResponse>(body)?;
+                let response = azure_core::from_json::<_, KeyVaultGetCertificatesResponse>(body)?;
                 Ok(response)
             }
         };
@@ -1,8 +1,12 @@
 mod backup;
+mod create;
 mod get_certificate;
+mod get_operation;
 mod get_versions;
 mod update_properties;
 pub use backup::*;
+pub use create::*;
 pub use get_certificate::*;
+pub use get_operation::*;
 pub use get_versions::*;
 pub use update_properties::*;
@@ -0,0 +1,258 @@
+use crate::prelude::*;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use time::OffsetDateTime;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    name: String,
+    version: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct CachedSecret {
+    secret: KeyVaultGetSecretResponse,
+    expires_at: OffsetDateTime,
+}
+
+impl CachedSecret {
+    fn is_expired(&self) -> bool {
+        self.expires_at <= OffsetDateTime::now_utc()
+    }
+}
+
+/// A `SecretClient` decorator caching secrets for a fixed time to live.
+///
+/// Applications often read the same secret on every request, which quickly gets
+/// the vault to throttle them. This client serves repeated reads from memory
+/// until the cached value is older than the configured time to live (or the
+/// secret itself expires, whichever comes first).
+///
+/// Cached secrets can also be kept fresh in the background by spawning the
+/// future returned by [`CachingSecretClient::refresh_periodically`] on the
+/// application's executor.
+///
+/// # Example
+///
+/// ```no_run
+/// use azure_security_keyvault::{CachingSecretClient, SecretClient};
+/// use azure_identity::DefaultAzureCredential;
+/// use std::{sync::Arc, time::Duration};
+///
+/// async fn example() {
+///     let creds = Arc::new(DefaultAzureCredential::default());
+///     let client = SecretClient::new("KEYVAULT_URL", creds).unwrap();
+///     let client = CachingSecretClient::new(client, Duration::from_secs(300));
+///     tokio::spawn(client.clone().refresh_periodically(Duration::from_secs(60)));
+///     let secret = client.get("SECRET_NAME").await.unwrap();
+///     dbg!(&secret.value);
+/// }
+/// ```
+#[derive(Clone)]
+pub struct CachingSecretClient {
+    client: SecretClient,
+    ttl: Duration,
+    cache: Arc<Mutex<HashMap<CacheKey, CachedSecret>>>,
+}
+
+impl std::fmt::Debug for CachingSecretClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CachingSecretClient")
+            .field("client", &self.client)
+            .field("ttl", &self.ttl)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CachingSecretClient {
+    /// Creates a new `CachingSecretClient` keeping secrets in memory for `ttl`.
+    pub fn new(client: SecretClient, ttl: Duration) -> Self {
+        Self {
+            client,
+            ttl,
+            cache: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// The wrapped `SecretClient`.
+    pub fn secret_client(&self) -> &SecretClient {
+        &self.client
+    }
+
+    /// The time to live of cached secrets.
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Gets the latest version of a secret, from the cache if possible.
+    pub async fn get<N>(&self, name: N) -> azure_core::Result<KeyVaultGetSecretResponse>
+    where
+        N: Into<String>,
+    {
+        self.get_cached(CacheKey {
+            name: name.into(),
+            version: None,
+        })
+        .await
+    }
+
+    /// Gets a specific version of a secret, from the cache if possible.
+    pub async fn get_version<N, V>(
+        &self,
+        name: N,
+        version: V,
+    ) -> azure_core::Result<KeyVaultGetSecretResponse>
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        self.get_cached(CacheKey {
+            name: name.into(),
+            version: Some(version.into()),
+        })
+        .await
+    }
+
+    /// Removes every cached version of a secret, so the next read goes to the vault.
+    pub fn invalidate(&self, name: &str) {
+        self.lock().retain(|key, _| key.name != name);
+    }
+
+    /// Removes every cached secret.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Fetches again every cached secret from the vault.
+    ///
+    /// Secrets that can no longer be read are dropped from the cache and the
+    /// first error encountered is returned.
+    pub async fn refresh(&self) -> azure_core::Result<()> {
+        let keys = self.lock().keys().cloned().collect::<Vec<_>>();
+        let mut result = Ok(());
+        for key in keys {
+            if let Err(error) = self.fetch(key.clone()).await {
+                self.lock().remove(&key);
+                if result.is_ok() {
+                    result = Err(error);
+                }
+            }
+        }
+        result
+    }
+
+    /// Refreshes every cached secret each `interval`.
+    ///
+    /// The returned future never completes: it is meant to be spawned on the
+    /// application's executor and dropped to stop refreshing.
+    pub async fn refresh_periodically(self, interval: Duration) {
+        loop {
+            azure_core::sleep(interval).await;
+            // errors are already handled by dropping the failing entries, the
+            // next read will surface them to the caller.
+            let _ = self.refresh().await;
+        }
+    }
+
+    async fn get_cached(&self, key: CacheKey) -> azure_core::Result<KeyVaultGetSecretResponse> {
+        if let Some(cached) = self.lock().get(&key) {
+            if !cached.is_expired() {
+                return Ok(cached.secret.clone());
+            }
+        }
+        self.fetch(key).await
+    }
+
+    async fn fetch(&self, key: CacheKey) -> azure_core::Result<KeyVaultGetSecretResponse> {
+        let mut builder = self.client.get(key.name.clone());
+        if let Some(version) = &key.version {
+            builder = builder.version(version.clone());
+        }
+        let secret = builder.into_future().await?;
+
+        let mut expires_at = OffsetDateTime::now_utc() + self.ttl;
+        if let Some(secret_expiry) = secret.attributes.expires_on {
+            expires_at = expires_at.min(secret_expiry);
+        }
+        self.lock().insert(
+            key,
+            CachedSecret {
+                secret: secret.clone(),
+                expires_at,
+            },
+        );
+        Ok(secret)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<CacheKey, CachedSecret>> {
+        // the lock is never held across an await point and no code can panic
+        // while holding it, so it cannot be poisoned.
+        self.cache.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock_client;
+    use mockito::{mock, Mock};
+
+    fn mock_secret(name: &str, expires_on: Option<i64>, hits: usize) -> Mock {
+        let expires_on = expires_on.map_or_else(String::new, |exp| format!(r#""exp":{exp},"#));
+        mock("GET", format!("/secrets/{name}/").as_str())
+            .match_query(mockito::Matcher::Any)
+            .with_body(format!(
+                r#"{{"value":"secret","id":"https://vault/secrets/{name}/1","attributes":{{"enabled":true,{expires_on}"created":1,"updated":1,"recoveryLevel":"Recoverable"}}}}"#
+            ))
+            .expect(hits)
+            .create()
+    }
+
+    fn caching_client(ttl: Duration) -> CachingSecretClient {
+        CachingSecretClient::new(mock_client().secret_client(), ttl)
+    }
+
+    #[tokio::test]
+    async fn serves_repeated_reads_from_the_cache() {
+        let vault = mock_secret("cache-hit", None, 1);
+        let client = caching_client(Duration::from_secs(300));
+
+        assert_eq!(client.get("cache-hit").await.unwrap().value, "secret");
+        assert_eq!(client.get("cache-hit").await.unwrap().value, "secret");
+        vault.assert();
+    }
+
+    #[tokio::test]
+    async fn reads_again_after_the_time_to_live() {
+        let vault = mock_secret("cache-expiry", None, 2);
+        let client = caching_client(Duration::ZERO);
+
+        client.get("cache-expiry").await.unwrap();
+        client.get("cache-expiry").await.unwrap();
+        vault.assert();
+    }
+
+    #[tokio::test]
+    async fn reads_again_after_the_secret_expires() {
+        let vault = mock_secret("secret-expiry", Some(1), 2);
+        let client = caching_client(Duration::from_secs(300));
+
+        client.get("secret-expiry").await.unwrap();
+        client.get("secret-expiry").await.unwrap();
+        vault.assert();
+    }
+
+    #[tokio::test]
+    async fn reads_again_once_invalidated() {
+        let vault = mock_secret("cache-invalidation", None, 3);
+        let client = caching_client(Duration::from_secs(300));
+
+        client.get("cache-invalidation").await.unwrap();
+        client.invalidate("cache-invalidation");
+        client.get("cache-invalidation").await.unwrap();
+        client.clear();
+        client.get("cache-invalidation").await.unwrap();
+        vault.assert();
+    }
+}
@@ -79,6 +79,26 @@ impl CertificateClient {
         GetCertificateVersionsBuilder::new(self.clone(), name.into())
     }
 
+    /// Creates a new version of a certificate.
+    ///
+    /// The new version is issued according to the existing policy of the certificate. The issuance
+    /// is asynchronous and can be followed with [`get_operation`](Self::get_operation).
+    /// This operation requires the certificates/create permission.
+    pub fn create<N>(&self, name: N) -> CreateCertificateBuilder
+    where
+        N: Into<String>,
+    {
+        CreateCertificateBuilder::new(self.clone(), name.into())
+    }
+
+    /// Gets the pending issuance of a certificate.
+    pub fn get_operation<N>(&self, name: N) -> GetCertificateOperationBuilder
+    where
+        N: Into<String>,
+    {
+        GetCertificateOperationBuilder::new(self.clone(), name.into())
+    }
+
     pub fn update<N>(&self, name: N) -> UpdateCertificatePropertiesBuilder
     where
         N: Into<String>,
@@ -0,0 +1,262 @@
+use crate::prelude::*;
+use azure_core::error::{Error, ErrorKind};
+use std::time::Duration;
+use time::OffsetDateTime;
+
+/// An event reported by a [`CertificateRenewalWatcher`].
+#[derive(Debug)]
+pub enum CertificateRenewalEvent {
+    /// The certificate entered the renewal window and a new version was requested.
+    RenewalStarted {
+        name: String,
+        expires_on: Option<OffsetDateTime>,
+    },
+    /// The new version of the certificate was issued.
+    Renewed {
+        name: String,
+        certificate: KeyVaultGetCertificateResponse,
+    },
+    /// The certificate could not be renewed, the renewal will be attempted again at the next
+    /// check.
+    RenewalFailed {
+        name: String,
+        error: azure_core::Error,
+    },
+}
+
+/// Renews certificates before they expire.
+///
+/// The watcher polls the certificates with `get_certificate` and, once one of them expires within
+/// the renewal window, requests a new version with `create_certificate`, which is issued according
+/// to the existing policy of the certificate. The outcome of each renewal is reported as a
+/// [`CertificateRenewalEvent`], for example to reload the certificate in a server.
+///
+/// This is meant for certificates whose issuers are not renewed by Key Vault itself, or for
+/// services managing the lifetime of their certificates.
+///
+/// # Example
+///
+/// ```no_run
+/// use azure_security_keyvault::{CertificateClient, CertificateRenewalEvent, CertificateRenewalWatcher};
+/// use azure_identity::DefaultAzureCredential;
+/// use std::{sync::Arc, time::Duration};
+///
+/// async fn example() {
+///     let creds = Arc::new(DefaultAzureCredential::default());
+///     let client = CertificateClient::new("KEYVAULT_URL", creds).unwrap();
+///     let watcher = CertificateRenewalWatcher::new(client, ["CERTIFICATE_NAME"])
+///         .renewal_window(Duration::from_secs(14 * 24 * 3600));
+///     tokio::spawn(watcher.watch(Duration::from_secs(3600), |event| {
+///         if let CertificateRenewalEvent::Renewed { name, .. } = event {
+///             println!("{name} was renewed");
+///         }
+///     }));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CertificateRenewalWatcher {
+    client: CertificateClient,
+    names: Vec<String>,
+    renewal_window: Duration,
+    polling_interval: Duration,
+    issuance_timeout: Duration,
+}
+
+impl CertificateRenewalWatcher {
+    /// The default renewal window: certificates are renewed 30 days before they expire.
+    pub const DEFAULT_RENEWAL_WINDOW: Duration = Duration::from_secs(30 * 24 * 3600);
+
+    /// Creates a watcher for the certificates with the given names.
+    pub fn new<I, N>(client: CertificateClient, names: I) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: Into<String>,
+    {
+        Self {
+            client,
+            names: names.into_iter().map(Into::into).collect(),
+            renewal_window: Self::DEFAULT_RENEWAL_WINDOW,
+            polling_interval: Duration::from_secs(10),
+            issuance_timeout: Duration::from_secs(30 * 60),
+        }
+    }
+
+    /// Renew the certificates expiring within `renewal_window`.
+    pub fn renewal_window(self, renewal_window: Duration) -> Self {
+        Self {
+            renewal_window,
+            ..self
+        }
+    }
+
+    /// How long to wait between checks of a pending issuance. Defaults to 10 seconds.
+    pub fn polling_interval(self, polling_interval: Duration) -> Self {
+        Self {
+            polling_interval,
+            ..self
+        }
+    }
+
+    /// How long to wait for a new version to be issued before reporting the renewal as failed.
+    /// Defaults to 30 minutes.
+    ///
+    /// The issuance goes on in Key Vault: the next check waits for it again.
+    pub fn issuance_timeout(self, issuance_timeout: Duration) -> Self {
+        Self {
+            issuance_timeout,
+            ..self
+        }
+    }
+
+    /// Checks every certificate once, renewing the ones inside the renewal window.
+    ///
+    /// Renewals wait for the new version to be issued, at most the issuance timeout.
+    pub async fn check<F>(&self, mut on_event: F)
+    where
+        F: FnMut(CertificateRenewalEvent),
+    {
+        for name in &self.names {
+            let certificate = match self.client.get(name.clone()).into_future().await {
+                Ok(certificate) => certificate,
+                Err(error) => {
+                    on_event(CertificateRenewalEvent::RenewalFailed {
+                        name: name.clone(),
+                        error,
+                    });
+                    continue;
+                }
+            };
+            let expires_on = certificate.attributes.expires_on;
+            if !needs_renewal(expires_on, OffsetDateTime::now_utc(), self.renewal_window) {
+                continue;
+            }
+
+            on_event(CertificateRenewalEvent::RenewalStarted {
+                name: name.clone(),
+                expires_on,
+            });
+            on_event(match self.renew(name).await {
+                Ok(certificate) => CertificateRenewalEvent::Renewed {
+                    name: name.clone(),
+                    certificate,
+                },
+                Err(error) => CertificateRenewalEvent::RenewalFailed {
+                    name: name.clone(),
+                    error,
+                },
+            });
+        }
+    }
+
+    /// Checks the certificates each `interval`.
+    ///
+    /// The returned future never completes: it is meant to be spawned on the application's
+    /// executor and dropped to stop watching.
+    pub async fn watch<F>(self, interval: Duration, mut on_event: F)
+    where
+        F: FnMut(CertificateRenewalEvent),
+    {
+        loop {
+            self.check(&mut on_event).await;
+            azure_core::sleep(interval).await;
+        }
+    }
+
+    async fn renew(&self, name: &str) -> azure_core::Result<KeyVaultGetCertificateResponse> {
+        let mut operation = self.client.create(name).into_future().await?;
+        let deadline = OffsetDateTime::now_utc() + self.issuance_timeout;
+        while operation.is_in_progress() {
+            if OffsetDateTime::now_utc() >= deadline {
+                return Err(Error::with_message(ErrorKind::Other, || {
+                    format!(
+                        "the renewal of certificate {name} is still in progress after {:?}",
+                        self.issuance_timeout
+                    )
+                }));
+            }
+            azure_core::sleep(self.polling_interval).await;
+            operation = self.client.get_operation(name).into_future().await?;
+        }
+        if !operation.is_completed() {
+            return Err(Error::with_message(ErrorKind::Other, || {
+                let details = match &operation.error {
+                    Some(error) => format!("{}: {}", error.code, error.message),
+                    None => operation.status_details.clone().unwrap_or_default(),
+                };
+                format!(
+                    "the renewal of certificate {name} is {}: {details}",
+                    operation.status
+                )
+            }));
+        }
+        self.client.get(name).into_future().await
+    }
+}
+
+/// Whether a certificate expiring on `expires_on` must be renewed at `now`.
+///
+/// Certificates without an expiry date never need to be renewed.
+fn needs_renewal(
+    expires_on: Option<OffsetDateTime>,
+    now: OffsetDateTime,
+    renewal_window: Duration,
+) -> bool {
+    match expires_on {
+        Some(expires_on) => expires_on - now <= renewal_window,
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock_client;
+    use mockito::mock;
+
+    #[test]
+    fn renews_inside_the_renewal_window() {
+        let now = OffsetDateTime::now_utc();
+        let window = Duration::from_secs(30 * 24 * 3600);
+
+        assert!(!needs_renewal(None, now, window));
+        assert!(!needs_renewal(
+            Some(now + Duration::from_secs(31 * 24 * 3600)),
+            now,
+            window
+        ));
+        assert!(needs_renewal(
+            Some(now + Duration::from_secs(29 * 24 * 3600)),
+            now,
+            window
+        ));
+        // expired certificates are renewed too
+        assert!(needs_renewal(
+            Some(now - Duration::from_secs(60)),
+            now,
+            window
+        ));
+    }
+
+    #[tokio::test]
+    async fn stops_waiting_for_the_issuance_after_the_timeout() {
+        let operation =
+            r#"{"id":"https://vault/certificates/pending-renewal/pending","status":"inProgress"}"#;
+        let _create = mock("POST", "/certificates/pending-renewal/create")
+            .match_query(mockito::Matcher::Any)
+            .with_body(operation)
+            .create();
+        let pending = mock("GET", "/certificates/pending-renewal/pending")
+            .match_query(mockito::Matcher::Any)
+            .with_body(operation)
+            .expect_at_least(1)
+            .create();
+
+        let watcher =
+            CertificateRenewalWatcher::new(mock_client().certificate_client(), ["pending-renewal"])
+                .polling_interval(Duration::from_millis(10))
+                .issuance_timeout(Duration::from_millis(50));
+        let error = watcher.renew("pending-renewal").await.unwrap_err();
+        assert!(error.to_string().contains("still in progress"));
+        pending.assert();
+    }
+}
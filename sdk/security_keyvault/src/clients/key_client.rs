@@ -48,6 +48,22 @@ impl KeyClient {
         SignBuilder::new(self.clone(), name.into(), algorithm, digest.into())
     }
 
+    /// Encrypt an arbitrary sequence of bytes using an encryption key that is
+    /// stored in a key vault or HSM.
+    ///
+    /// The ENCRYPT operation only supports a single block of data, the size
+    /// of which is dependent on the target key and the encryption algorithm to
+    /// be used. AES-GCM and AES-CBC encryption with symmetric keys are only
+    /// supported by Managed HSM.
+    ///
+    /// This operation requires the keys/encrypt permission.
+    pub fn encrypt<N>(&self, name: N, encrypt_parameters: EncryptParameters) -> EncryptBuilder
+    where
+        N: Into<String>,
+    {
+        EncryptBuilder::new(self.clone(), name.into(), encrypt_parameters)
+    }
+
     /// Decrypt a single block of encrypted data.
     ///
     /// The DECRYPT operation decrypts a well-formed block of ciphertext using
@@ -4,7 +4,8 @@ use azure_core::{
     date,
     error::{Error, ErrorKind},
     headers::*,
-    Body, Context, Method, Pipeline, Request, Response,
+    AzureCloud, Body, ClientOptions, CloudConfiguration, Context, HealthReport, Method, Pipeline,
+    Policy, Request, Response,
 };
 use const_format::formatcp;
 use std::sync::Arc;
@@ -14,6 +15,129 @@ use url::Url;
 pub const API_VERSION: &str = "7.0";
 const API_VERSION_PARAM: &str = formatcp!("api-version={}", API_VERSION);
 
+/// A builder for the Key Vault client.
+///
+/// # Example
+///
+/// ```no_run
+/// use azure_core::RetryOptions;
+/// use azure_security_keyvault::{CloudLocation, KeyvaultClient, KeyvaultClientBuilder};
+/// use azure_identity::DefaultAzureCredential;
+/// use std::sync::Arc;
+/// let creds = Arc::new(DefaultAzureCredential::default());
+/// let client = KeyvaultClient::builder("https://test-key-vault.vault.azure.net", creds.clone())
+///     .retry(RetryOptions::none())
+///     .build()
+///     .unwrap();
+/// let location = CloudLocation::China { vault_name: "test-key-vault".to_owned() };
+/// let client = KeyvaultClientBuilder::with_location(location, creds).build().unwrap();
+/// ```
+#[derive(Clone)]
+pub struct KeyvaultClientBuilder {
+    cloud_location: CloudLocation,
+    token_credential: Arc<dyn TokenCredential>,
+    options: ClientOptions,
+}
+
+impl std::fmt::Debug for KeyvaultClientBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KeyvaultClientBuilder")
+            .field("cloud_location", &self.cloud_location)
+            .field("options", &self.options)
+            .finish_non_exhaustive()
+    }
+}
+
+impl KeyvaultClientBuilder {
+    /// Create a new instance of `KeyvaultClientBuilder` for the vault at `vault_url`.
+    #[must_use]
+    pub fn new(vault_url: impl Into<String>, token_credential: Arc<dyn TokenCredential>) -> Self {
+        Self::with_location(
+            CloudLocation::Custom {
+                vault_url: vault_url.into(),
+            },
+            token_credential,
+        )
+    }
+
+    /// Create a new instance of `KeyvaultClientBuilder` with a cloud location.
+    #[must_use]
+    pub fn with_location(
+        cloud_location: CloudLocation,
+        token_credential: Arc<dyn TokenCredential>,
+    ) -> Self {
+        Self {
+            cloud_location,
+            token_credential,
+            options: ClientOptions::default(),
+        }
+    }
+
+    /// Convert the builder into a `KeyvaultClient` instance.
+    pub fn build(self) -> azure_core::Result<KeyvaultClient> {
+        let vault_url = Url::parse(&self.cloud_location.url())?;
+        let audience = self.cloud_location.audience(&vault_url)?;
+        let pipeline = new_pipeline_from_options(self.token_credential, audience, self.options);
+        Ok(KeyvaultClient {
+            vault_url,
+            pipeline,
+        })
+    }
+
+    /// Set the cloud location.
+    #[must_use]
+    pub fn cloud_location(mut self, cloud_location: CloudLocation) -> Self {
+        self.cloud_location = cloud_location;
+        self
+    }
+
+    /// Set the URL of the vault, e.g. `https://myvault.vault.azure.net`.
+    #[must_use]
+    pub fn endpoint(mut self, vault_url: impl Into<String>) -> Self {
+        self.cloud_location = CloudLocation::Custom {
+            vault_url: vault_url.into(),
+        };
+        self
+    }
+
+    /// Set the credential the tokens of the requests are acquired with.
+    #[must_use]
+    pub fn credential(mut self, token_credential: Arc<dyn TokenCredential>) -> Self {
+        self.token_credential = token_credential;
+        self
+    }
+
+    /// Set the retry options.
+    #[must_use]
+    pub fn retry(mut self, retry: impl Into<azure_core::RetryOptions>) -> Self {
+        self.options = self.options.retry(retry);
+        self
+    }
+
+    /// Set the transport options.
+    #[must_use]
+    pub fn transport(mut self, transport: impl Into<azure_core::TransportOptions>) -> Self {
+        self.options = self.options.transport(transport);
+        self
+    }
+
+    /// Add a policy called once per call, before the retry policy.
+    #[must_use]
+    pub fn per_call_policy(mut self, policy: Arc<dyn Policy>) -> Self {
+        self.options.per_call_policies_mut().push(policy);
+        self
+    }
+
+    /// Override all of the client options.
+    ///
+    /// *Warning!*: This overrides all client options that have been previously set on this builder.
+    #[must_use]
+    pub fn client_options(mut self, options: impl Into<azure_core::ClientOptions>) -> Self {
+        self.options = options.into();
+        self
+    }
+}
+
 /// Client for Key Vault operations - getting a secret, listing secrets, etc.
 ///
 /// # Example
@@ -54,14 +178,16 @@ impl KeyvaultClient {
         vault_url: &str,
         token_credential: Arc<dyn TokenCredential>,
     ) -> azure_core::Result<Self> {
-        let vault_url = Url::parse(vault_url)?;
-        let endpoint = extract_endpoint(&vault_url)?;
-        let pipeline = new_pipeline_from_options(token_credential.clone(), endpoint);
-        let client = Self {
-            vault_url,
-            pipeline,
-        };
-        Ok(client)
+        KeyvaultClientBuilder::new(vault_url, token_credential).build()
+    }
+
+    /// Create a new `KeyvaultClientBuilder`.
+    #[must_use]
+    pub fn builder(
+        vault_url: impl Into<String>,
+        token_credential: Arc<dyn TokenCredential>,
+    ) -> KeyvaultClientBuilder {
+        KeyvaultClientBuilder::new(vault_url, token_credential)
     }
 
     pub(crate) fn finalize_request(
@@ -102,6 +228,19 @@ impl KeyvaultClient {
         self.pipeline.send(context, request).await
     }
 
+    /// Reads the recovery settings and authorization mode of the vault, so that delete and
+    /// recover flows can adapt to them, e.g. to skip the purge of deleted secrets when purge
+    /// protection is enabled.
+    pub fn get_vault_properties(&self) -> GetVaultPropertiesBuilder {
+        GetVaultPropertiesBuilder::new(self.clone())
+    }
+
+    /// Checks that the vault can be reached with the credential of the client, e.g. for a
+    /// readiness probe, with the health check of the [`SecretClient`].
+    pub async fn health_check(&self) -> HealthReport {
+        self.secret_client().health_check().await
+    }
+
     pub fn secret_client(&self) -> SecretClient {
         SecretClient::new_with_client(self.clone())
     }
@@ -115,6 +254,70 @@ impl KeyvaultClient {
     }
 }
 
+/// The cloud hosting the vault.
+#[derive(Debug, Clone)]
+pub enum CloudLocation {
+    /// Azure public cloud
+    Public {
+        /// The vault name
+        vault_name: String,
+    },
+    /// Azure China cloud
+    China {
+        /// The vault name
+        vault_name: String,
+    },
+    /// Azure US Government cloud
+    UsGovernment {
+        /// The vault name
+        vault_name: String,
+    },
+    /// Another cloud, such as Azure Stack
+    Sovereign {
+        /// The endpoints of the cloud
+        cloud: CloudConfiguration,
+        /// The vault name
+        vault_name: String,
+    },
+    /// A custom vault URL
+    Custom {
+        /// The vault URL, such as `https://myvault.vault.azure.net`
+        vault_url: String,
+    },
+}
+
+impl CloudLocation {
+    /// the vault URL for a given cloud location
+    fn url(&self) -> String {
+        match self {
+            CloudLocation::Public { vault_name } => {
+                AzureCloud::Public.configuration().keyvault_url(vault_name)
+            }
+            CloudLocation::China { vault_name } => {
+                AzureCloud::China.configuration().keyvault_url(vault_name)
+            }
+            CloudLocation::UsGovernment { vault_name } => AzureCloud::UsGovernment
+                .configuration()
+                .keyvault_url(vault_name),
+            CloudLocation::Sovereign { cloud, vault_name } => cloud.keyvault_url(vault_name),
+            CloudLocation::Custom { vault_url } => vault_url.clone(),
+        }
+    }
+
+    /// The audience of the tokens of the vault: the one of its cloud, or the endpoint of a custom
+    /// vault URL
+    fn audience(&self, vault_url: &Url) -> azure_core::Result<String> {
+        let cloud = match self {
+            CloudLocation::Public { .. } => AzureCloud::Public.configuration(),
+            CloudLocation::China { .. } => AzureCloud::China.configuration(),
+            CloudLocation::UsGovernment { .. } => AzureCloud::UsGovernment.configuration(),
+            CloudLocation::Sovereign { cloud, .. } => cloud.clone(),
+            CloudLocation::Custom { .. } => return extract_endpoint(vault_url),
+        };
+        Ok(cloud.keyvault_audience.into_owned())
+    }
+}
+
 /// Helper to get vault endpoint with a scheme and a trailing slash
 /// ex. `https://vault.azure.net/` where the full client url is `https://myvault.vault.azure.net`
 fn extract_endpoint(url: &Url) -> azure_core::Result<String> {
@@ -158,4 +361,58 @@ mod tests {
                 .unwrap();
         assert_eq!(suffix, "some-scheme://vault.azure.net");
     }
+
+    #[test]
+    fn can_build_from_cloud_location() {
+        let creds = Arc::new(azure_identity::AzureCliCredential::new());
+        let location = CloudLocation::China {
+            vault_name: "myvault".to_owned(),
+        };
+        let client = KeyvaultClientBuilder::with_location(location, creds)
+            .build()
+            .unwrap();
+        assert_eq!(client.vault_url.as_str(), "https://myvault.vault.azure.cn/");
+    }
+
+    #[test]
+    fn requests_the_tokens_of_the_audience_of_the_cloud() {
+        let location = CloudLocation::Sovereign {
+            cloud: CloudConfiguration {
+                keyvault_endpoint_suffix: "vault.contoso.com".into(),
+                keyvault_audience: "https://vault.azurestack.contoso.com".into(),
+                ..AzureCloud::Public.configuration()
+            },
+            vault_name: "myvault".to_owned(),
+        };
+        let vault_url = Url::parse(&location.url()).unwrap();
+        assert_eq!(vault_url.as_str(), "https://myvault.vault.contoso.com/");
+        assert_eq!(
+            location.audience(&vault_url).unwrap(),
+            "https://vault.azurestack.contoso.com"
+        );
+
+        let location = CloudLocation::Custom {
+            vault_url: "https://myvault.vault.azure.cn".to_owned(),
+        };
+        let vault_url = Url::parse(&location.url()).unwrap();
+        assert_eq!(
+            location.audience(&vault_url).unwrap(),
+            "https://vault.azure.cn"
+        );
+    }
+
+    #[test]
+    fn can_override_the_endpoint() {
+        let creds = Arc::new(azure_identity::AzureCliCredential::new());
+        let client = KeyvaultClient::builder("https://myvault.vault.azure.net", creds.clone())
+            .endpoint("https://myvault.privatelink.vaultcore.azure.net")
+            .credential(creds)
+            .client_options(ClientOptions::default())
+            .build()
+            .unwrap();
+        assert_eq!(
+            client.vault_url.as_str(),
+            "https://myvault.privatelink.vaultcore.azure.net/"
+        );
+    }
 }
@@ -1,11 +1,14 @@
+mod caching_secret_client;
 mod certificate_client;
+mod certificate_renewal_watcher;
 mod key_client;
 mod keyvault_client;
 mod pipeline;
-mod policy;
 mod secret_client;
 
+pub use caching_secret_client::CachingSecretClient;
 pub use certificate_client::CertificateClient;
+pub use certificate_renewal_watcher::{CertificateRenewalEvent, CertificateRenewalWatcher};
 pub use key_client::KeyClient;
-pub use keyvault_client::{KeyvaultClient, API_VERSION};
+pub use keyvault_client::{CloudLocation, KeyvaultClient, KeyvaultClientBuilder, API_VERSION};
 pub use secret_client::SecretClient;
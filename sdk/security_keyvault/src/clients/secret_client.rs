@@ -1,5 +1,6 @@
 use crate::prelude::*;
-use azure_core::auth::TokenCredential;
+use azure_core::{auth::TokenCredential, HealthReport};
+use futures::StreamExt;
 use std::sync::Arc;
 
 #[derive(Clone, Debug)]
@@ -217,6 +218,19 @@ impl SecretClient {
         ListSecretsBuilder::new(self.clone())
     }
 
+    /// Checks that the vault can be reached with the credential of the client, e.g. for a
+    /// readiness probe, by listing the first page of secrets.
+    /// This operation requires the secrets/list permission.
+    pub async fn health_check(&self) -> HealthReport {
+        let list_secrets = async {
+            match self.list_secrets().into_stream().next().await {
+                Some(page) => page.map(|_| ()),
+                None => Ok(()),
+            }
+        };
+        HealthReport::check(self.keyvault_client.vault_url.as_str(), list_secrets).await
+    }
+
     /// Restores a backed up secret and all its versions.
     /// This operation requires the secrets/restore permission.
     ///
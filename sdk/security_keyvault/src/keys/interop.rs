@@ -0,0 +1,342 @@
+//! Conversions between the public keys of [`JsonWebKey`] and other formats, e.g. to verify the
+//! signatures of a Key Vault key locally.
+//!
+//! The conversions to the `jsonwebtoken`, `rsa` and `p256` crates are enabled by the features of
+//! the same names.
+use super::JsonWebKey;
+use azure_core::error::{Error, ErrorKind};
+
+/// The OID of RSA public keys, 1.2.840.113549.1.1.1.
+const RSA_ENCRYPTION_OID: &[u8] = &[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x01];
+/// The OID of elliptic curve public keys, 1.2.840.10045.2.1.
+const EC_PUBLIC_KEY_OID: &[u8] = &[0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x02, 0x01];
+
+const TAG_INTEGER: u8 = 0x02;
+const TAG_BIT_STRING: u8 = 0x03;
+const TAG_NULL: u8 = 0x05;
+const TAG_OID: u8 = 0x06;
+const TAG_SEQUENCE: u8 = 0x30;
+
+/// The OID and the coordinate size of the curves supported by Key Vault.
+fn curve(curve_name: &str) -> azure_core::Result<(&'static [u8], usize)> {
+    match curve_name {
+        // 1.2.840.10045.3.1.7
+        "P-256" => Ok((&[0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x03, 0x01, 0x07], 32)),
+        // 1.3.132.0.10
+        "P-256K" => Ok((&[0x2B, 0x81, 0x04, 0x00, 0x0A], 32)),
+        // 1.3.132.0.34
+        "P-384" => Ok((&[0x2B, 0x81, 0x04, 0x00, 0x22], 48)),
+        // 1.3.132.0.35
+        "P-521" => Ok((&[0x2B, 0x81, 0x04, 0x00, 0x23], 66)),
+        _ => Err(Error::with_message(ErrorKind::DataConversion, || {
+            format!("unsupported curve {curve_name}")
+        })),
+    }
+}
+
+impl JsonWebKey {
+    fn is_rsa(&self) -> bool {
+        matches!(self.key_type.as_str(), "RSA" | "RSA-HSM")
+    }
+
+    fn is_ec(&self) -> bool {
+        matches!(self.key_type.as_str(), "EC" | "EC-HSM")
+    }
+
+    fn component<'a>(
+        &self,
+        name: &str,
+        value: &'a Option<Vec<u8>>,
+    ) -> azure_core::Result<&'a [u8]> {
+        value.as_deref().ok_or_else(|| {
+            Error::with_message(ErrorKind::DataConversion, || {
+                format!("the {} key has no {name} component", self.key_type)
+            })
+        })
+    }
+
+    /// The RSA modulus and public exponent.
+    fn rsa_components(&self) -> azure_core::Result<(&[u8], &[u8])> {
+        if !self.is_rsa() {
+            return Err(self.unsupported_key_type());
+        }
+        Ok((self.component("n", &self.n)?, self.component("e", &self.e)?))
+    }
+
+    /// The curve name and the coordinates of the point of an elliptic curve key.
+    fn ec_components(&self) -> azure_core::Result<(&str, &[u8], &[u8])> {
+        if !self.is_ec() {
+            return Err(self.unsupported_key_type());
+        }
+        let curve_name = self.curve_name.as_deref().ok_or_else(|| {
+            Error::message(ErrorKind::DataConversion, "the EC key has no curve name")
+        })?;
+        Ok((
+            curve_name,
+            self.component("x", &self.x)?,
+            self.component("y", &self.y)?,
+        ))
+    }
+
+    fn unsupported_key_type(&self) -> Error {
+        Error::with_message(ErrorKind::DataConversion, || {
+            format!("unsupported key type {}", self.key_type)
+        })
+    }
+
+    /// The DER encoded `SubjectPublicKeyInfo` of an RSA or elliptic curve key.
+    pub fn public_key_der(&self) -> azure_core::Result<Vec<u8>> {
+        if self.is_rsa() {
+            let (n, e) = self.rsa_components()?;
+            let algorithm = der(
+                TAG_SEQUENCE,
+                &[der(TAG_OID, RSA_ENCRYPTION_OID), der(TAG_NULL, &[])].concat(),
+            );
+            let public_key = der(TAG_SEQUENCE, &[der_integer(n), der_integer(e)].concat());
+            Ok(der(
+                TAG_SEQUENCE,
+                &[algorithm, der_bit_string(&public_key)].concat(),
+            ))
+        } else {
+            let (curve_name, x, y) = self.ec_components()?;
+            let (curve_oid, size) = curve(curve_name)?;
+            let algorithm = der(
+                TAG_SEQUENCE,
+                &[der(TAG_OID, EC_PUBLIC_KEY_OID), der(TAG_OID, curve_oid)].concat(),
+            );
+            // the uncompressed point
+            let mut point = vec![0x04];
+            point.extend(left_pad(x, size)?);
+            point.extend(left_pad(y, size)?);
+            Ok(der(
+                TAG_SEQUENCE,
+                &[algorithm, der_bit_string(&point)].concat(),
+            ))
+        }
+    }
+
+    /// The PEM encoded `SubjectPublicKeyInfo` of an RSA or elliptic curve key.
+    pub fn public_key_pem(&self) -> azure_core::Result<String> {
+        let der = base64::encode(self.public_key_der()?);
+        let mut pem = String::from("-----BEGIN PUBLIC KEY-----\n");
+        for line in der.as_bytes().chunks(64) {
+            pem.push_str(std::str::from_utf8(line).expect("base64 is ASCII"));
+            pem.push('\n');
+        }
+        pem.push_str("-----END PUBLIC KEY-----\n");
+        Ok(pem)
+    }
+
+    /// A key to verify the signatures of JWTs with `jsonwebtoken`.
+    #[cfg(feature = "jsonwebtoken")]
+    pub fn decoding_key(&self) -> azure_core::Result<jsonwebtoken::DecodingKey> {
+        if self.is_rsa() {
+            let (n, e) = self.rsa_components()?;
+            Ok(jsonwebtoken::DecodingKey::from_rsa_raw_components(n, e))
+        } else {
+            let (_, x, y) = self.ec_components()?;
+            jsonwebtoken::DecodingKey::from_ec_components(
+                &base64::encode_config(x, super::BASE64_URL_SAFE),
+                &base64::encode_config(y, super::BASE64_URL_SAFE),
+            )
+            .map_err(|error| Error::new(ErrorKind::DataConversion, error))
+        }
+    }
+
+    /// The JSON web key of a public key, e.g. to import it into Key Vault.
+    #[cfg(any(feature = "rsa", feature = "p256"))]
+    fn public_key(key_type: &str) -> Self {
+        Self {
+            curve_name: None,
+            d: None,
+            dp: None,
+            dq: None,
+            e: None,
+            k: None,
+            t: None,
+            key_ops: None,
+            id: None,
+            key_type: key_type.to_owned(),
+            n: None,
+            p: None,
+            q: None,
+            qi: None,
+            x: None,
+            y: None,
+        }
+    }
+}
+
+#[cfg(feature = "rsa")]
+impl TryFrom<&JsonWebKey> for rsa::RsaPublicKey {
+    type Error = Error;
+
+    fn try_from(key: &JsonWebKey) -> azure_core::Result<Self> {
+        let (n, e) = key.rsa_components()?;
+        rsa::RsaPublicKey::new(
+            rsa::BigUint::from_bytes_be(n),
+            rsa::BigUint::from_bytes_be(e),
+        )
+        .map_err(|error| Error::new(ErrorKind::DataConversion, error))
+    }
+}
+
+#[cfg(feature = "rsa")]
+impl From<&rsa::RsaPublicKey> for JsonWebKey {
+    fn from(public_key: &rsa::RsaPublicKey) -> Self {
+        use rsa::PublicKeyParts;
+
+        let mut key = JsonWebKey::public_key("RSA");
+        key.n = Some(public_key.n().to_bytes_be());
+        key.e = Some(public_key.e().to_bytes_be());
+        key
+    }
+}
+
+#[cfg(feature = "p256")]
+impl TryFrom<&JsonWebKey> for p256::PublicKey {
+    type Error = Error;
+
+    fn try_from(key: &JsonWebKey) -> azure_core::Result<Self> {
+        use p256::elliptic_curve::sec1::FromEncodedPoint;
+
+        let (curve_name, x, y) = key.ec_components()?;
+        if curve_name != "P-256" {
+            return Err(Error::with_message(ErrorKind::DataConversion, || {
+                format!("the key is on the {curve_name} curve instead of P-256")
+            }));
+        }
+        let (x, y) = (left_pad(x, 32)?, left_pad(y, 32)?);
+        let point = p256::EncodedPoint::from_affine_coordinates(
+            p256::FieldBytes::from_slice(&x),
+            p256::FieldBytes::from_slice(&y),
+            false,
+        );
+        Option::from(p256::PublicKey::from_encoded_point(&point)).ok_or_else(|| {
+            Error::message(
+                ErrorKind::DataConversion,
+                "the point is not on the P-256 curve",
+            )
+        })
+    }
+}
+
+#[cfg(feature = "p256")]
+impl From<&p256::PublicKey> for JsonWebKey {
+    fn from(public_key: &p256::PublicKey) -> Self {
+        use p256::elliptic_curve::sec1::ToEncodedPoint;
+
+        let point = public_key.to_encoded_point(false);
+        let mut key = JsonWebKey::public_key("EC");
+        key.curve_name = Some("P-256".to_owned());
+        key.x = point.x().map(|x| x.to_vec());
+        key.y = point.y().map(|y| y.to_vec());
+        key
+    }
+}
+
+/// Left pads a big endian coordinate to the size of the coordinates of its curve.
+fn left_pad(bytes: &[u8], size: usize) -> azure_core::Result<Vec<u8>> {
+    let bytes = &bytes[bytes.iter().take_while(|b| **b == 0).count()..];
+    if bytes.len() > size {
+        return Err(Error::message(
+            ErrorKind::DataConversion,
+            "the coordinate is larger than the curve",
+        ));
+    }
+    let mut padded = vec![0; size - bytes.len()];
+    padded.extend_from_slice(bytes);
+    Ok(padded)
+}
+
+fn der(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut encoded = vec![tag];
+    if content.len() < 0x80 {
+        encoded.push(content.len() as u8);
+    } else {
+        let length = content.len().to_be_bytes();
+        let length = &length[length.iter().take_while(|b| **b == 0).count()..];
+        encoded.push(0x80 | length.len() as u8);
+        encoded.extend_from_slice(length);
+    }
+    encoded.extend_from_slice(content);
+    encoded
+}
+
+/// An unsigned big endian integer.
+fn der_integer(bytes: &[u8]) -> Vec<u8> {
+    let bytes = &bytes[bytes.iter().take_while(|b| **b == 0).count()..];
+    let mut content = Vec::with_capacity(bytes.len() + 1);
+    if bytes.first().map_or(true, |b| b & 0x80 != 0) {
+        content.push(0);
+    }
+    content.extend_from_slice(bytes);
+    der(TAG_INTEGER, &content)
+}
+
+fn der_bit_string(bytes: &[u8]) -> Vec<u8> {
+    // no unused bits
+    der(TAG_BIT_STRING, &[&[0], bytes].concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keys::BASE64_URL_SAFE;
+
+    fn key(json: serde_json::Value) -> JsonWebKey {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn encodes_public_keys() {
+        let modulus = "BD15A1FE0FE26B9B47230E6168EC288900A546DE36D44472FEF03B726BBA3FBD\
+            16273937E6CD2EA2DEDF5518AFE2918723D479F77666C5C2E292636A19C0BD0F";
+        let modulus = (0..modulus.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&modulus[i..i + 2], 16).unwrap())
+            .collect::<Vec<_>>();
+        let rsa = key(serde_json::json!({
+            "kty": "RSA-HSM",
+            "n": base64::encode_config(modulus, BASE64_URL_SAFE),
+            "e": "AQAB",
+        }));
+        assert_eq!(
+            base64::encode(rsa.public_key_der().unwrap()),
+            "MFwwDQYJKoZIhvcNAQEBBQADSwAwSAJBAL0Vof4P4mubRyMOYWjsKIkApUbeNtREcv7wO3Jruj+9Fic5N+bN\
+            LqLe31UYr+KRhyPUefd2ZsXC4pJjahnAvQ8CAwEAAQ=="
+        );
+        assert_eq!(
+            rsa.public_key_pem().unwrap(),
+            "-----BEGIN PUBLIC KEY-----\n\
+            MFwwDQYJKoZIhvcNAQEBBQADSwAwSAJBAL0Vof4P4mubRyMOYWjsKIkApUbeNtRE\n\
+            cv7wO3Jruj+9Fic5N+bNLqLe31UYr+KRhyPUefd2ZsXC4pJjahnAvQ8CAwEAAQ==\n\
+            -----END PUBLIC KEY-----\n"
+        );
+
+        let point = base64::decode(
+            "BKxB+FqCCVl+avTRNr+R/pPNOUlVISlglrvVfgA+2GINbXveGtI9567gFPbtz2j2tyupvQHUgnlkE5boIgwYAzI=",
+        )
+        .unwrap();
+        let ec = key(serde_json::json!({
+            "kty": "EC",
+            "crv": "P-256",
+            "x": base64::encode_config(&point[1..33], BASE64_URL_SAFE),
+            "y": base64::encode_config(&point[33..], BASE64_URL_SAFE),
+        }));
+        assert_eq!(
+            base64::encode(ec.public_key_der().unwrap()),
+            "MFkwEwYHKoZIzj0CAQYIKoZIzj0DAQcDQgAErEH4WoIJWX5q9NE2v5H+k805SVUhKWCWu9V+AD7YYg1te94a\
+            0j3nruAU9u3PaPa3K6m9AdSCeWQTlugiDBgDMg=="
+        );
+
+        assert!(key(serde_json::json!({ "kty": "oct", "k": "AQAB" }))
+            .public_key_der()
+            .is_err());
+        assert!(
+            key(serde_json::json!({ "kty": "EC", "crv": "P-192", "x": "AQ", "y": "AQ" }))
+                .public_key_der()
+                .is_err()
+        );
+    }
+}
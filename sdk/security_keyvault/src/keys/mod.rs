@@ -1,3 +1,4 @@
+mod interop;
 mod models;
 mod operations;
 
@@ -389,3 +389,158 @@ pub struct DecryptResult {
     )]
     pub result: Vec<u8>,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EncryptParameters {
+    pub encrypt_parameters_encryption: EncryptParametersEncryption,
+    #[serde(serialize_with = "ser_base64", deserialize_with = "deser_base64")]
+    pub plaintext: Vec<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum EncryptParametersEncryption {
+    Rsa(RsaEncryptParameters),
+    AesGcm(AesGcmEncryptParameters),
+    AesCbc(AesCbcEncryptParameters),
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RsaEncryptParameters {
+    pub algorithm: EncryptionAlgorithm,
+}
+
+impl RsaEncryptParameters {
+    pub fn new(algorithm: EncryptionAlgorithm) -> Result<Self, Error> {
+        match algorithm {
+            EncryptionAlgorithm::Rsa15
+            | EncryptionAlgorithm::RsaOaep
+            | EncryptionAlgorithm::RsaOaep256 => Ok(Self { algorithm }),
+            _ => Err(Error::with_message(ErrorKind::Other, || {
+                format!("unexpected encryption algorithm: {algorithm}")
+            })),
+        }
+    }
+}
+
+/// Parameters of an AES-GCM encryption, only supported by Managed HSM.
+///
+/// The initialization vector is generated by the HSM and returned in the `EncryptResult`, along
+/// with the authentication tag: both are needed to decrypt the ciphertext.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AesGcmEncryptParameters {
+    pub algorithm: EncryptionAlgorithm,
+    #[serde(
+        serialize_with = "ser_base64_opt",
+        deserialize_with = "deser_base64_opt"
+    )]
+    pub additional_authenticated_data: Option<Vec<u8>>,
+}
+
+impl AesGcmEncryptParameters {
+    pub fn new(
+        algorithm: EncryptionAlgorithm,
+        additional_authenticated_data: Option<Vec<u8>>,
+    ) -> Result<Self, Error> {
+        match algorithm {
+            EncryptionAlgorithm::A128Gcm
+            | EncryptionAlgorithm::A192Gcm
+            | EncryptionAlgorithm::A256Gcm => Ok(Self {
+                algorithm,
+                additional_authenticated_data,
+            }),
+            _ => Err(Error::with_message(ErrorKind::Other, || {
+                format!("unexpected encryption algorithm: {algorithm}")
+            })),
+        }
+    }
+}
+
+/// Parameters of an AES-CBC encryption, only supported by Managed HSM.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AesCbcEncryptParameters {
+    pub algorithm: EncryptionAlgorithm,
+    #[serde(serialize_with = "ser_base64", deserialize_with = "deser_base64")]
+    pub iv: Vec<u8>,
+}
+
+impl AesCbcEncryptParameters {
+    pub fn new(algorithm: EncryptionAlgorithm, iv: Vec<u8>) -> Result<Self, Error> {
+        match algorithm {
+            EncryptionAlgorithm::A128Cbc
+            | EncryptionAlgorithm::A192Cbc
+            | EncryptionAlgorithm::A256Cbc
+            | EncryptionAlgorithm::A128CbcPad
+            | EncryptionAlgorithm::A192CbcPad
+            | EncryptionAlgorithm::A256CbcPad => Ok(Self { algorithm, iv }),
+            _ => Err(Error::with_message(ErrorKind::Other, || {
+                format!("unexpected encryption algorithm: {algorithm}")
+            })),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EncryptResult {
+    #[serde(skip)]
+    pub algorithm: EncryptionAlgorithm,
+    #[serde(rename = "kid")]
+    pub key_id: String,
+    #[serde(
+        rename = "value",
+        serialize_with = "ser_base64",
+        deserialize_with = "deser_base64"
+    )]
+    pub result: Vec<u8>,
+    #[serde(
+        serialize_with = "ser_base64_opt",
+        deserialize_with = "deser_base64_opt",
+        default
+    )]
+    pub iv: Option<Vec<u8>>,
+    #[serde(
+        rename = "tag",
+        serialize_with = "ser_base64_opt",
+        deserialize_with = "deser_base64_opt",
+        default
+    )]
+    pub authentication_tag: Option<Vec<u8>>,
+    #[serde(
+        rename = "aad",
+        serialize_with = "ser_base64_opt",
+        deserialize_with = "deser_base64_opt",
+        default
+    )]
+    pub additional_authenticated_data: Option<Vec<u8>>,
+}
+
+impl EncryptResult {
+    /// The parameters to decrypt the result of this encryption.
+    pub fn decrypt_parameters(&self) -> Result<DecryptParameters, Error> {
+        let decrypt_parameters_encryption = match &self.algorithm {
+            EncryptionAlgorithm::A128Gcm
+            | EncryptionAlgorithm::A192Gcm
+            | EncryptionAlgorithm::A256Gcm => {
+                DecryptParametersEncryption::AesGcm(AesGcmDecryptParameters::new(
+                    self.algorithm.clone(),
+                    self.iv.clone().unwrap_or_default(),
+                    self.authentication_tag.clone().unwrap_or_default(),
+                    self.additional_authenticated_data.clone(),
+                )?)
+            }
+            EncryptionAlgorithm::Rsa15
+            | EncryptionAlgorithm::RsaOaep
+            | EncryptionAlgorithm::RsaOaep256 => {
+                DecryptParametersEncryption::Rsa(RsaDecryptParameters::new(self.algorithm.clone())?)
+            }
+            _ => DecryptParametersEncryption::AesCbc(AesCbcDecryptParameters::new(
+                self.algorithm.clone(),
+                self.iv.clone().unwrap_or_default(),
+            )?),
+        };
+        Ok(DecryptParameters {
+            decrypt_parameters_encryption,
+            ciphertext: self.result.clone(),
+        })
+    }
+}
@@ -1,3 +1,4 @@
+use crate::keys::BASE64_URL_SAFE;
 use crate::prelude::*;
 use azure_core::{headers::Headers, CollectedResponse, Method};
 use serde_json::{Map, Value};
@@ -20,10 +21,13 @@ impl DecryptBuilder {
 
             uri.set_path(&path);
 
+            let encode =
+                |bytes: &[u8]| Value::String(base64::encode_config(bytes, BASE64_URL_SAFE));
+
             let mut request_body = Map::new();
             request_body.insert(
                 "value".to_owned(),
-                Value::String(base64::encode(self.decrypt_parameters.ciphertext)),
+                encode(&self.decrypt_parameters.ciphertext),
             );
 
             let algorithm = match self.decrypt_parameters.decrypt_parameters_encryption {
@@ -40,20 +44,17 @@ impl DecryptBuilder {
                 }) => {
                     request_body
                         .insert("alg".to_owned(), serde_json::to_value(&algorithm).unwrap());
-                    request_body.insert("iv".to_owned(), serde_json::to_value(iv).unwrap());
-                    request_body.insert(
-                        "tag".to_owned(),
-                        serde_json::to_value(authentication_tag).unwrap(),
-                    );
+                    request_body.insert("iv".to_owned(), encode(&iv));
+                    request_body.insert("tag".to_owned(), encode(&authentication_tag));
                     if let Some(aad) = additional_authenticated_data {
-                        request_body.insert("aad".to_owned(), serde_json::to_value(aad).unwrap());
+                        request_body.insert("aad".to_owned(), encode(&aad));
                     };
                     algorithm
                 }
                 DecryptParametersEncryption::AesCbc(AesCbcDecryptParameters { algorithm, iv }) => {
                     request_body
                         .insert("alg".to_owned(), serde_json::to_value(&algorithm).unwrap());
-                    request_body.insert("iv".to_owned(), serde_json::to_value(iv).unwrap());
+                    request_body.insert("iv".to_owned(), encode(&iv));
                     algorithm
                 }
             };
@@ -75,7 +76,7 @@ impl DecryptBuilder {
             let response = CollectedResponse::from_response(response).await?;
             let body = response.body();
 
-            let mut result = serde_json::from_slice::<DecryptResult>(body)?;
+            let mut result = azure_core::from_json::<_, DecryptResult>(body)?;
             result.algorithm = algorithm;
             Ok(result)
         })
@@ -0,0 +1,189 @@
+use crate::keys::BASE64_URL_SAFE;
+use crate::prelude::*;
+use azure_core::{headers::Headers, CollectedResponse, Method};
+use serde_json::{Map, Value};
+
+operation! {
+    Encrypt,
+    client: KeyClient,
+    name: String,
+    encrypt_parameters: EncryptParameters,
+    ?version: String
+}
+
+impl EncryptBuilder {
+    pub fn into_future(mut self) -> Encrypt {
+        Box::pin(async move {
+            // POST {vaultBaseUrl}/keys/{key-name}/{key-version}/encrypt?api-version=7.2
+            let version = self.version.unwrap_or_default();
+            let mut uri = self.client.keyvault_client.vault_url.clone();
+            let path = format!("keys/{}/{}/encrypt", self.name, version);
+
+            uri.set_path(&path);
+
+            let encode =
+                |bytes: &[u8]| Value::String(base64::encode_config(bytes, BASE64_URL_SAFE));
+
+            let mut request_body = Map::new();
+            request_body.insert(
+                "value".to_owned(),
+                encode(&self.encrypt_parameters.plaintext),
+            );
+
+            let algorithm = match self.encrypt_parameters.encrypt_parameters_encryption {
+                EncryptParametersEncryption::Rsa(RsaEncryptParameters { algorithm }) => {
+                    request_body.insert("alg".to_owned(), serde_json::to_value(&algorithm)?);
+                    algorithm
+                }
+                EncryptParametersEncryption::AesGcm(AesGcmEncryptParameters {
+                    algorithm,
+                    additional_authenticated_data,
+                }) => {
+                    request_body.insert("alg".to_owned(), serde_json::to_value(&algorithm)?);
+                    if let Some(aad) = additional_authenticated_data {
+                        request_body.insert("aad".to_owned(), encode(&aad));
+                    };
+                    algorithm
+                }
+                EncryptParametersEncryption::AesCbc(AesCbcEncryptParameters { algorithm, iv }) => {
+                    request_body.insert("alg".to_owned(), serde_json::to_value(&algorithm)?);
+                    request_body.insert("iv".to_owned(), encode(&iv));
+                    algorithm
+                }
+            };
+
+            let headers = Headers::new();
+            let mut request = self.client.keyvault_client.finalize_request(
+                uri,
+                Method::Post,
+                headers,
+                Some(Value::Object(request_body).to_string().into()),
+            )?;
+
+            let response = self
+                .client
+                .keyvault_client
+                .send(&mut self.context, &mut request)
+                .await?;
+
+            let response = CollectedResponse::from_response(response).await?;
+            let body = response.body();
+
+            let mut result = azure_core::from_json::<_, EncryptResult>(body)?;
+            result.algorithm = algorithm;
+            Ok(result)
+        })
+    }
+}
+
+type EncryptResponse = EncryptResult;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::mock_client;
+    use mockito::{mock, Matcher};
+    use serde_json::json;
+
+    fn encode(bytes: &[u8]) -> String {
+        base64::encode_config(bytes, BASE64_URL_SAFE)
+    }
+
+    #[tokio::test]
+    async fn encrypts_with_aes_gcm() {
+        let vault = mock("POST", "/keys/hsm-key/1/encrypt")
+            .match_query(Matcher::Any)
+            .match_body(Matcher::Json(json!({
+                "value": encode(b"plaintext"),
+                "alg": "A256GCM",
+                "aad": encode(b"aad"),
+            })))
+            .with_body(
+                json!({
+                    "kid": "https://vault/keys/hsm-key/1",
+                    "value": encode(b"ciphertext"),
+                    "iv": encode(b"iv"),
+                    "tag": encode(b"tag"),
+                    "aad": encode(b"aad"),
+                })
+                .to_string(),
+            )
+            .create();
+
+        let parameters = EncryptParameters {
+            encrypt_parameters_encryption: EncryptParametersEncryption::AesGcm(
+                AesGcmEncryptParameters::new(EncryptionAlgorithm::A256Gcm, Some(b"aad".to_vec()))
+                    .unwrap(),
+            ),
+            plaintext: b"plaintext".to_vec(),
+        };
+        let result = mock_client()
+            .key_client()
+            .encrypt("hsm-key", parameters)
+            .version("1")
+            .into_future()
+            .await
+            .unwrap();
+        vault.assert();
+
+        assert!(matches!(result.algorithm, EncryptionAlgorithm::A256Gcm));
+        assert_eq!(result.key_id, "https://vault/keys/hsm-key/1");
+        assert_eq!(result.result, b"ciphertext");
+        assert_eq!(result.iv.as_deref(), Some(&b"iv"[..]));
+        assert_eq!(result.authentication_tag.as_deref(), Some(&b"tag"[..]));
+
+        let decrypt_parameters = result.decrypt_parameters().unwrap();
+        assert_eq!(decrypt_parameters.ciphertext, b"ciphertext");
+        match decrypt_parameters.decrypt_parameters_encryption {
+            DecryptParametersEncryption::AesGcm(parameters) => {
+                assert_eq!(parameters.iv, b"iv");
+                assert_eq!(parameters.authentication_tag, b"tag");
+                assert_eq!(parameters.additional_authenticated_data.unwrap(), b"aad");
+            }
+            other => panic!("unexpected decrypt parameters {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn encrypts_with_rsa_without_iv() {
+        let vault = mock("POST", "/keys/rsa-key/2/encrypt")
+            .match_query(Matcher::Any)
+            .match_body(Matcher::Json(json!({
+                "value": encode(b"plaintext"),
+                "alg": "RSA-OAEP-256",
+            })))
+            .with_body(
+                json!({
+                    "kid": "https://vault/keys/rsa-key/2",
+                    "value": encode(b"ciphertext"),
+                })
+                .to_string(),
+            )
+            .create();
+
+        let parameters = EncryptParameters {
+            encrypt_parameters_encryption: EncryptParametersEncryption::Rsa(
+                RsaEncryptParameters::new(EncryptionAlgorithm::RsaOaep256).unwrap(),
+            ),
+            plaintext: b"plaintext".to_vec(),
+        };
+        let result = mock_client()
+            .key_client()
+            .encrypt("rsa-key", parameters)
+            .version("2")
+            .into_future()
+            .await
+            .unwrap();
+        vault.assert();
+
+        assert!(matches!(result.algorithm, EncryptionAlgorithm::RsaOaep256));
+        assert_eq!(result.iv, None);
+        assert!(matches!(
+            result
+                .decrypt_parameters()
+                .unwrap()
+                .decrypt_parameters_encryption,
+            DecryptParametersEncryption::Rsa(_)
+        ));
+    }
+}
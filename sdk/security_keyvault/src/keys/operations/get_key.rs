@@ -30,7 +30,7 @@ impl GetKeyBuilder {
 
             let response = CollectedResponse::from_response(response).await?;
             let body = response.body();
-            let response = serde_json::from_slice::<KeyVaultKey>(body)?;
+            let response = azure_core::from_json::<_, KeyVaultKey>(body)?;
 
             Ok(response)
         })
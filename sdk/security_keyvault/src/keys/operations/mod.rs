@@ -1,6 +1,8 @@
 mod decrypt;
+mod encrypt;
 mod get_key;
 mod sign;
 pub use decrypt::*;
+pub use encrypt::*;
 pub use get_key::*;
 pub use sign::*;
@@ -40,7 +40,7 @@ impl SignBuilder {
             let response = CollectedResponse::from_response(response).await?;
             let body = response.body();
 
-            let mut result = serde_json::from_slice::<SignResult>(body)?;
+            let mut result = azure_core::from_json::<_, SignResult>(body)?;
             result.algorithm = self.algorithm;
             Ok(result)
         })
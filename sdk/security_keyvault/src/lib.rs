@@ -9,3 +9,29 @@ pub mod prelude;
 mod secrets;
 
 pub use clients::*;
+
+#[cfg(test)]
+mod tests {
+    use crate::KeyvaultClient;
+    use azure_core::auth::{AccessToken, TokenCredential, TokenResponse};
+    use std::sync::Arc;
+    use time::OffsetDateTime;
+
+    /// A client of the mockito server.
+    pub(crate) fn mock_client() -> KeyvaultClient {
+        KeyvaultClient::new(&mockito::server_url(), Arc::new(MockCredential)).unwrap()
+    }
+
+    pub(crate) struct MockCredential;
+
+    #[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+    impl TokenCredential for MockCredential {
+        async fn get_token(&self, _resource: &str) -> azure_core::Result<TokenResponse> {
+            Ok(TokenResponse::new(
+                AccessToken::new("TOKEN"),
+                OffsetDateTime::now_utc() + time::Duration::hours(1),
+            ))
+        }
+    }
+}
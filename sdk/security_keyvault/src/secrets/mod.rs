@@ -1,5 +1,7 @@
 mod models;
 mod operations;
+mod value;
 
 pub use models::*;
 pub use operations::*;
+pub use value::*;
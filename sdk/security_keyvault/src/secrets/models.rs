@@ -1,3 +1,4 @@
+use super::SecretValue;
 use serde::Deserialize;
 use time::OffsetDateTime;
 
@@ -23,14 +24,23 @@ pub struct KeyVaultGetSecretsResponse {
     pub next_link: Option<String>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone)]
 pub struct KeyVaultGetSecretResponse {
     pub value: String,
     pub id: String,
+    #[serde(rename = "contentType")]
+    pub content_type: Option<String>,
     pub attributes: KeyVaultGetSecretResponseAttributes,
 }
 
-#[derive(Deserialize, Debug)]
+impl KeyVaultGetSecretResponse {
+    /// The value of the secret, interpreted according to its content type.
+    pub fn secret_value(&self) -> SecretValue<'_> {
+        SecretValue::new(&self.value, self.content_type.as_deref())
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
 pub struct KeyVaultGetSecretResponseAttributes {
     pub enabled: bool,
     #[serde(default)]
@@ -29,7 +29,7 @@ impl GetSecretBuilder {
 
             let response = CollectedResponse::from_response(response).await?;
             let body = response.body();
-            let response = serde_json::from_slice::<KeyVaultGetSecretResponse>(body)?;
+            let response = azure_core::from_json::<_, KeyVaultGetSecretResponse>(body)?;
             Ok(response)
         })
     }
@@ -39,7 +39,7 @@ impl GetSecretVersionsBuilder {
                 let response = CollectedResponse::from_response(response).await?;
                 let body = response.body();
 
-                let response = serde_json::from_slice::<KeyVaultGetSecretsResponse>(body)?;
+                let response = azure_core::from_json::<_, KeyVaultGetSecretsResponse>(body)?;
                 Ok(response)
             }
         };
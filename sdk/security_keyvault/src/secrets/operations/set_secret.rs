@@ -7,6 +7,7 @@ operation! {
     client: SecretClient,
     name: String,
     value: String,
+    ?content_type: String
 }
 
 impl SetSecretBuilder {
@@ -18,6 +19,9 @@ impl SetSecretBuilder {
             let mut request_body = Map::new();
 
             request_body.insert("value".to_string(), Value::String(self.value));
+            if let Some(content_type) = self.content_type {
+                request_body.insert("contentType".to_string(), Value::String(content_type));
+            }
 
             let body = Some(Value::Object(request_body).to_string().into());
 
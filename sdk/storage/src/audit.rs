@@ -0,0 +1,251 @@
+//! Hooks to keep an audit trail of the requests sent to the storage services.
+//!
+//! An [`AuditHook`] is called before each request is sent and after its response is received,
+//! with summaries of the request and of the response. The summaries never contain secrets: the
+//! signatures of shared access signatures are redacted from the URLs and no headers or bodies are
+//! included.
+//!
+//! The client builders of the blob, data lake and queue services take a hook with their
+//! `audit_hook` method. The table clients, created from a [`StorageClient`](crate::clients::StorageClient),
+//! take it with the options of the storage client:
+//! ```no_run
+//! # use azure_storage::audit::{AuditHook, AuditPolicy};
+//! # use azure_storage::clients::StorageClient;
+//! # use std::sync::Arc;
+//! # fn run(hook: Arc<dyn AuditHook>) {
+//! let mut options = azure_core::ClientOptions::default();
+//! options
+//!     .per_retry_policies_mut()
+//!     .push(Arc::new(AuditPolicy::new(hook)));
+//! let storage_client = StorageClient::new_access_key("account", "key").with_options(options);
+//! # }
+//! ```
+//! File shares are not supported by these crates, hence have no hook.
+//!
+//! A hook printing the requests:
+//! ```no_run
+//! use azure_storage::audit::{AuditHook, RequestSummary, ResponseSummary};
+//!
+//! #[derive(Debug)]
+//! struct PrintAuditHook;
+//!
+//! impl AuditHook for PrintAuditHook {
+//!     fn after_receive(&self, request: &RequestSummary, response: &ResponseSummary) {
+//!         println!(
+//!             "{} {} -> {:?} ({:?})",
+//!             request.method, request.url, response.status, response.request_id
+//!         );
+//!     }
+//! }
+//! ```
+use crate::clients::ServiceType;
+use azure_core::headers::{CLIENT_REQUEST_ID, REQUEST_ID};
+use azure_core::{Context, Method, Policy, PolicyResult, Request, StatusCode};
+use std::fmt::Debug;
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+use url::Url;
+
+const REDACTED: &str = "REDACTED";
+
+/// Query parameters whose values are never included in the summaries.
+const REDACTED_QUERY_PARAMETERS: &[&str] = &["sig"];
+
+/// Receives the summaries of the requests sent by a client.
+///
+/// The hook is called for every attempt, so a request retried twice is reported three times.
+/// It is called inline in the pipeline: slow hooks should hand the summaries over to another task.
+pub trait AuditHook: Debug + Send + Sync {
+    /// Called before the request is sent.
+    fn before_send(&self, _request: &RequestSummary) {}
+
+    /// Called once the response is received, or the request failed.
+    fn after_receive(&self, _request: &RequestSummary, _response: &ResponseSummary) {}
+}
+
+/// The summary of a request, without secrets.
+#[derive(Debug, Clone)]
+pub struct RequestSummary {
+    pub method: Method,
+    /// The URL of the request, with the signature of the shared access signature redacted.
+    pub url: String,
+    /// The `x-ms-client-request-id` sent with the request.
+    pub client_request_id: Option<String>,
+    pub service_type: Option<ServiceType>,
+    pub timestamp: OffsetDateTime,
+}
+
+impl RequestSummary {
+    fn new(ctx: &Context, request: &Request) -> Self {
+        Self {
+            method: request.method().clone(),
+            url: sanitize_url(request.url()),
+            client_request_id: request.headers().get_optional_string(&CLIENT_REQUEST_ID),
+            service_type: ctx.get::<ServiceType>().copied(),
+            timestamp: OffsetDateTime::now_utc(),
+        }
+    }
+}
+
+/// The summary of a response, without secrets.
+#[derive(Debug, Clone)]
+pub struct ResponseSummary {
+    /// The status of the response, `None` if no response was received.
+    pub status: Option<StatusCode>,
+    /// The `x-ms-request-id` of the response, to correlate with the storage logs of the account.
+    pub request_id: Option<String>,
+    pub duration: Duration,
+    /// The error, if no response was received.
+    pub error: Option<String>,
+}
+
+/// Calls an [`AuditHook`] for every request attempt.
+///
+/// The policy is added to the client options by the `audit_hook` methods of the client builders.
+#[derive(Debug, Clone)]
+pub struct AuditPolicy {
+    hook: Arc<dyn AuditHook>,
+}
+
+impl AuditPolicy {
+    pub fn new(hook: Arc<dyn AuditHook>) -> Self {
+        Self { hook }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+impl Policy for AuditPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let request_summary = RequestSummary::new(ctx, request);
+        self.hook.before_send(&request_summary);
+
+        let result = next[0].send(ctx, request, &next[1..]).await;
+
+        let duration = OffsetDateTime::now_utc() - request_summary.timestamp;
+        let response_summary = match &result {
+            Ok(response) => ResponseSummary {
+                status: Some(response.status()),
+                request_id: response.headers().get_optional_string(&REQUEST_ID),
+                duration,
+                error: None,
+            },
+            Err(error) => ResponseSummary {
+                status: None,
+                request_id: None,
+                duration,
+                error: Some(error.to_string()),
+            },
+        };
+        self.hook.after_receive(&request_summary, &response_summary);
+
+        result
+    }
+}
+
+fn sanitize_url(url: &Url) -> String {
+    let mut url = url.clone();
+    if url.query().is_some() {
+        let pairs = url
+            .query_pairs()
+            .map(|(name, value)| {
+                let value = if REDACTED_QUERY_PARAMETERS
+                    .iter()
+                    .any(|p| p.eq_ignore_ascii_case(&name))
+                {
+                    REDACTED.to_owned()
+                } else {
+                    value.into_owned()
+                };
+                (name.into_owned(), value)
+            })
+            .collect::<Vec<_>>();
+        url.query_pairs_mut().clear().extend_pairs(pairs);
+    }
+    url.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::headers::Headers;
+    use azure_core::Response;
+    use std::sync::Mutex;
+
+    #[derive(Debug, Default)]
+    struct RecordingHook {
+        summaries: Mutex<Vec<(RequestSummary, ResponseSummary)>>,
+    }
+
+    impl AuditHook for RecordingHook {
+        fn after_receive(&self, request: &RequestSummary, response: &ResponseSummary) {
+            self.summaries
+                .lock()
+                .unwrap()
+                .push((request.clone(), response.clone()));
+        }
+    }
+
+    #[derive(Debug)]
+    struct RespondPolicy;
+
+    #[cfg_attr(target_arch = "wasm32", async_trait::async_trait(?Send))]
+    #[cfg_attr(not(target_arch = "wasm32"), async_trait::async_trait)]
+    impl Policy for RespondPolicy {
+        async fn send(
+            &self,
+            _ctx: &Context,
+            _request: &mut Request,
+            _next: &[Arc<dyn Policy>],
+        ) -> PolicyResult {
+            let mut headers = Headers::new();
+            headers.insert(REQUEST_ID, "request-id");
+            Ok(Response::new(
+                StatusCode::Ok,
+                headers,
+                Box::pin(futures::stream::empty()),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn summarizes_requests_without_secrets() {
+        let hook = Arc::new(RecordingHook::default());
+        let policies: Vec<Arc<dyn Policy>> = vec![
+            Arc::new(AuditPolicy::new(hook.clone())),
+            Arc::new(RespondPolicy),
+        ];
+
+        let mut ctx = Context::new();
+        ctx.insert(ServiceType::Blob);
+        let mut request = Request::new(
+            Url::parse("https://account.blob.core.windows.net/c/b?sv=2020-08-04&sig=secret")
+                .unwrap(),
+            Method::Get,
+        );
+        request.insert_header(CLIENT_REQUEST_ID, "client-request-id");
+        policies[0]
+            .send(&ctx, &mut request, &policies[1..])
+            .await
+            .unwrap();
+
+        let summaries = hook.summaries.lock().unwrap();
+        let (request, response) = &summaries[0];
+        assert_eq!(
+            request.url,
+            "https://account.blob.core.windows.net/c/b?sv=2020-08-04&sig=REDACTED"
+        );
+        assert_eq!(
+            request.client_request_id.as_deref(),
+            Some("client-request-id")
+        );
+        assert!(matches!(request.service_type, Some(ServiceType::Blob)));
+        assert_eq!(response.status, Some(StatusCode::Ok));
+        assert_eq!(response.request_id.as_deref(), Some("request-id"));
+    }
+}
@@ -0,0 +1,161 @@
+//! Transactional checksums of the content sent to and received from the storage services.
+//!
+//! The services compute a CRC64 (the CRC-64/NVME variant, sent as `x-ms-content-crc64`) or an
+//! MD5 digest (sent as `Content-MD5`) of the content of a request, and return the one of the
+//! content of a response when it is asked for.
+//! ```
+//! use azure_storage::checksum::{crc64, Crc64};
+//!
+//! let mut crc = Crc64::new();
+//! crc.update(b"1234");
+//! crc.update(b"56789");
+//! assert_eq!(crc.finish(), crc64(b"123456789"));
+//! ```
+use crate::{ConsistencyCRC64, ConsistencyMD5};
+use azure_core::error::{Error, ErrorKind};
+
+/// The reflected polynomial of the CRC64 used by the storage services.
+const POLYNOMIAL: u64 = 0x9A6C_9329_AC4B_C9B5;
+
+const TABLE: [u64; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u64;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ POLYNOMIAL
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Computes the storage CRC64 of data received in chunks.
+#[derive(Debug, Clone, Copy)]
+pub struct Crc64 {
+    crc: u64,
+}
+
+impl Crc64 {
+    pub fn new() -> Self {
+        Self { crc: !0 }
+    }
+
+    pub fn update(&mut self, data: &[u8]) {
+        for &byte in data {
+            self.crc = TABLE[((self.crc ^ u64::from(byte)) & 0xff) as usize] ^ (self.crc >> 8);
+        }
+    }
+
+    /// The CRC64 of the data received so far.
+    pub fn finish(&self) -> u64 {
+        !self.crc
+    }
+}
+
+impl Default for Crc64 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The storage CRC64 of `data`.
+pub fn crc64(data: &[u8]) -> u64 {
+    let mut crc = Crc64::new();
+    crc.update(data);
+    crc.finish()
+}
+
+/// The MD5 digest of `data`.
+pub fn md5(data: &[u8]) -> [u8; 16] {
+    md5::compute(data).0
+}
+
+impl ConsistencyCRC64 {
+    /// The CRC64 of `data`.
+    pub fn compute(data: &[u8]) -> Self {
+        Self::from(crc64(data))
+    }
+
+    /// Fails unless this is the CRC64 of `data`.
+    pub fn verify(&self, data: &[u8]) -> azure_core::Result<()> {
+        self.verify_crc64(crc64(data))
+    }
+
+    /// Fails unless this is `crc64`, e.g. the result of a [`Crc64`].
+    pub fn verify_crc64(&self, crc64: u64) -> azure_core::Result<()> {
+        if self.as_slice() == &crc64.to_le_bytes() {
+            Ok(())
+        } else {
+            Err(Error::message(
+                ErrorKind::DataConversion,
+                "the CRC64 of the content does not match the x-ms-content-crc64 header",
+            ))
+        }
+    }
+}
+
+impl From<u64> for ConsistencyCRC64 {
+    fn from(crc64: u64) -> Self {
+        Self::from_bytes(crc64.to_le_bytes())
+    }
+}
+
+impl ConsistencyMD5 {
+    /// The MD5 digest of `data`.
+    pub fn compute(data: &[u8]) -> Self {
+        Self::from_bytes(md5(data))
+    }
+
+    /// Fails unless this is the MD5 digest of `data`.
+    pub fn verify(&self, data: &[u8]) -> azure_core::Result<()> {
+        self.verify_digest(md5(data))
+    }
+
+    /// Fails unless this is `digest`, e.g. the result of a [`md5::Context`].
+    pub fn verify_digest(&self, digest: [u8; 16]) -> azure_core::Result<()> {
+        if self.as_slice() == &digest {
+            Ok(())
+        } else {
+            Err(Error::message(
+                ErrorKind::DataConversion,
+                "the MD5 digest of the content does not match the Content-MD5 header",
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_and_verifies_checksums() {
+        assert_eq!(crc64(b"123456789"), 0xAE8B_1486_0A79_9888);
+        assert_eq!(crc64(b""), 0);
+
+        let crc = ConsistencyCRC64::compute(b"123456789");
+        assert_eq!(
+            crc,
+            ConsistencyCRC64::decode(base64::encode(0xAE8B_1486_0A79_9888u64.to_le_bytes()))
+                .unwrap()
+        );
+        assert!(crc.verify(b"123456789").is_ok());
+        assert!(crc.verify(b"12345678").is_err());
+
+        let md5 = ConsistencyMD5::compute(b"123456789");
+        assert_eq!(
+            md5,
+            ConsistencyMD5::decode("JfnnlDI7RTiF9RgfG2JNCw==").unwrap()
+        );
+        assert!(md5.verify(b"123456789").is_ok());
+        assert!(md5.verify(b"").is_err());
+    }
+}
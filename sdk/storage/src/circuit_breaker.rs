@@ -0,0 +1,94 @@
+//! A circuit breaker failing fast while a storage endpoint is busy.
+use azure_core::error::ErrorKind;
+use azure_core::headers::{Headers, ERROR_CODE};
+use azure_core::{
+    CircuitBreakerOptions, CircuitBreakerPolicy, FailureClassifier, HostPartition, PolicyResult,
+};
+
+/// The error codes of the responses counted as failures.
+const SERVER_BUSY_ERROR_CODES: &[&str] = &["ServerBusy", "InternalError"];
+
+/// Fails fast when a storage endpoint keeps answering that it is busy.
+///
+/// This is the circuit breaker of `azure_core`, counting only the `ServerBusy` and
+/// `InternalError` responses as failures, with a circuit for each endpoint, e.g.
+/// `myaccount.blob.core.windows.net`, so that a pipeline shared by many accounts keeps sending
+/// requests to the accounts which are not throttled. Create it with
+/// [`server_busy_circuit_breaker`].
+pub type ServerBusyCircuitBreakerPolicy = CircuitBreakerPolicy<ServerBusyClassifier, HostPartition>;
+
+/// Creates a [`ServerBusyCircuitBreakerPolicy`].
+///
+/// # Example
+///
+/// ```
+/// use azure_core::CircuitBreakerOptions;
+/// use azure_storage::circuit_breaker::server_busy_circuit_breaker;
+/// use std::time::Duration;
+/// let policy = server_busy_circuit_breaker(
+///     CircuitBreakerOptions::default()
+///         .failure_threshold(10u32)
+///         .cool_down(Duration::from_secs(60)),
+/// );
+/// ```
+pub fn server_busy_circuit_breaker(
+    options: CircuitBreakerOptions,
+) -> ServerBusyCircuitBreakerPolicy {
+    CircuitBreakerPolicy::with_classifier(options, ServerBusyClassifier, HostPartition)
+}
+
+/// Counts the `ServerBusy` and `InternalError` responses of the storage services as failures.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ServerBusyClassifier;
+
+impl FailureClassifier for ServerBusyClassifier {
+    fn is_failure(&self, result: &PolicyResult) -> bool {
+        match result {
+            Ok(response) => is_server_busy(response.headers()),
+            Err(error) => match error.kind() {
+                ErrorKind::HttpResponse {
+                    error_code: Some(error_code),
+                    ..
+                } => SERVER_BUSY_ERROR_CODES.contains(&error_code.as_str()),
+                _ => false,
+            },
+        }
+    }
+}
+
+fn is_server_busy(headers: &Headers) -> bool {
+    headers
+        .get_optional_str(&ERROR_CODE)
+        .map_or(false, |error_code| {
+            SERVER_BUSY_ERROR_CODES.contains(&error_code)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::error::Error;
+    use azure_core::StatusCode;
+
+    #[test]
+    fn counts_busy_error_codes() {
+        let mut headers = Headers::new();
+        assert!(!is_server_busy(&headers));
+        headers.insert(ERROR_CODE, "ServerBusy");
+        assert!(is_server_busy(&headers));
+        headers.insert(ERROR_CODE, "BlobNotFound");
+        assert!(!is_server_busy(&headers));
+
+        let error = |error_code: &str| -> PolicyResult {
+            Err(Error::message(
+                ErrorKind::HttpResponse {
+                    status: StatusCode::ServiceUnavailable,
+                    error_code: Some(error_code.to_owned()),
+                },
+                "busy",
+            ))
+        };
+        assert!(ServerBusyClassifier.is_failure(&error("InternalError")));
+        assert!(!ServerBusyClassifier.is_failure(&error("OperationTimedOut")));
+    }
+}
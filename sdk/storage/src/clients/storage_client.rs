@@ -1,13 +1,18 @@
 use crate::authorization_policy::AuthorizationPolicy;
-use crate::shared_access_signature::account_sas::{
-    AccountSasPermissions, AccountSasResource, AccountSasResourceType, AccountSharedAccessSignature,
+use crate::shared_access_signature::{
+    account_sas::{
+        AccountSasPermissions, AccountSasResource, AccountSasResourceType,
+        AccountSharedAccessSignature,
+    },
+    SasCredentialsError,
 };
-use crate::ConnectionString;
+use crate::{CloudLocation, ConnectionString};
 use azure_core::{
     auth::TokenCredential,
     error::{Error, ErrorKind, ResultExt},
     headers::*,
-    Body, ClientOptions, Context, Method, Pipeline, Request, Response,
+    AzureCloud, Body, ClientOptions, CloudConfiguration, Context, Method, Pipeline, Request,
+    Response,
 };
 use azure_core::{date, Policy, TransportOptions};
 use std::sync::Arc;
@@ -23,11 +28,19 @@ pub const EMULATOR_ACCOUNT: &str = "devstoreaccount1";
 pub const EMULATOR_ACCOUNT_KEY: &str =
     "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==";
 
+const AZURE_STORAGE_CONNECTION_STRING_ENV_KEY: &str = "AZURE_STORAGE_CONNECTION_STRING";
+const AZURE_STORAGE_ACCOUNT_ENV_KEY: &str = "AZURE_STORAGE_ACCOUNT";
+const AZURE_STORAGE_KEY_ENV_KEY: &str = "AZURE_STORAGE_KEY";
+
+/// The host used by the emulator endpoints when no development storage proxy is specified.
+const EMULATOR_HOST: &str = "http://127.0.0.1";
+
 const AZURE_VERSION: HeaderValue = HeaderValue::from_static("2019-12-12");
 
 #[derive(Clone)]
 pub enum StorageCredentials {
     Key(String, String),
+    KeyLite(String, String),
     SASToken(Vec<(String, String)>),
     BearerToken(String),
     TokenCredential(Arc<dyn TokenCredential>),
@@ -51,6 +64,21 @@ impl StorageCredentials {
         Self::Key(account.into(), key.into())
     }
 
+    /// Create an Access Key based credential signing the requests with Shared Key Lite
+    ///
+    /// Shared Key Lite signs fewer parts of the request than Shared Key. It is
+    /// only needed to interoperate with clients and emulators which expect it,
+    /// for example for the Table service.
+    ///
+    /// ref: <https://docs.microsoft.com/rest/api/storageservices/authorize-with-shared-key#shared-key-lite-and-table-service-format-for-2009-09-19-and-later>
+    pub fn access_key_lite<A, K>(account: A, key: K) -> Self
+    where
+        A: Into<String>,
+        K: Into<String>,
+    {
+        Self::KeyLite(account.into(), key.into())
+    }
+
     /// Create a Shared Access Signature (SAS) token based credential
     ///
     /// SAS token are HTTP query strings that provide delegated access to
@@ -128,6 +156,10 @@ impl std::fmt::Debug for StorageCredentials {
                 .debug_struct("StorageCredentials")
                 .field("credential", &"Key")
                 .finish(),
+            StorageCredentials::KeyLite(_, _) => f
+                .debug_struct("StorageCredentials")
+                .field("credential", &"KeyLite")
+                .finish(),
             StorageCredentials::SASToken(_) => f
                 .debug_struct("StorageCredentials")
                 .field("credential", &"SASToken")
@@ -154,7 +186,7 @@ impl From<Arc<dyn TokenCredential>> for StorageCredentials {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ServiceType {
     Blob,
     Queue,
@@ -172,6 +204,45 @@ impl ServiceType {
             ServiceType::DataLake => "dfs",
         }
     }
+
+    /// The default port of the service in the emulator.
+    pub fn emulator_port(&self) -> u16 {
+        match self {
+            ServiceType::Blob => 10000,
+            ServiceType::Queue => 10001,
+            ServiceType::Table => 10002,
+            ServiceType::DataLake => 10004,
+        }
+    }
+
+    /// Translates the URL of a resource to the endpoint of this service in the same account.
+    ///
+    /// Accounts with a hierarchical namespace expose the same data on the blob and the data lake
+    /// endpoints, for example `https://myaccount.dfs.core.windows.net/myfilesystem/dir/file` is
+    /// also `https://myaccount.blob.core.windows.net/myfilesystem/dir/file`. URLs that do not
+    /// follow the `<account>.<service>.<domain>` pattern, like the ones of the emulator, are
+    /// returned unchanged.
+    pub fn translate_url(&self, url: &Url) -> Url {
+        let mut translated = url.clone();
+        let host = match url.host_str() {
+            Some(host) => host,
+            None => return translated,
+        };
+        let mut labels = host.splitn(3, '.');
+        if let (Some(account), Some(subdomain), Some(domain)) =
+            (labels.next(), labels.next(), labels.next())
+        {
+            let is_service = [Self::Blob, Self::Queue, Self::Table, Self::DataLake]
+                .iter()
+                .any(|service_type| service_type.subdomain() == subdomain);
+            if is_service {
+                let host = format!("{account}.{}.{domain}", self.subdomain());
+                // the host was already valid with the original subdomain
+                let _ = translated.set_host(Some(&host));
+            }
+        }
+        translated
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -183,37 +254,61 @@ pub struct StorageClient {
     queue_storage_secondary_url: Url,
     filesystem_url: Url,
     account: String,
+    /// The audience of the tokens of a token credential, the one of the cloud of the account.
+    audience: String,
     pipeline: Pipeline,
 }
 
 impl StorageClient {
-    pub fn new_access_key<A, K>(account: A, key: K) -> Self
+    /// Create a new client for an account of the given cloud, e.g.
+    /// [`AzureCloud::UsGovernment`].
+    pub fn new_in_cloud<A>(
+        account: A,
+        storage_credentials: StorageCredentials,
+        cloud: impl Into<CloudConfiguration>,
+    ) -> Self
     where
         A: Into<String>,
-        K: Into<String>,
     {
         let account = account.into();
-        let storage_credentials = StorageCredentials::access_key(account.clone(), key);
-        let pipeline =
-            new_pipeline_from_options(ClientOptions::default(), storage_credentials.clone());
+        let cloud = cloud.into();
+        let endpoint_suffix = &cloud.storage_endpoint_suffix;
+        let audience = cloud.storage_audience.clone().into_owned();
+        let pipeline = new_pipeline_in_cloud(
+            ClientOptions::default(),
+            storage_credentials.clone(),
+            &audience,
+        );
 
         Self {
-            blob_storage_url: get_endpoint_uri(None, &account, "blob").unwrap(),
-            table_storage_url: get_endpoint_uri(None, &account, "table").unwrap(),
-            queue_storage_url: get_endpoint_uri(None, &account, "queue").unwrap(),
+            blob_storage_url: get_endpoint_uri(None, &account, "blob", endpoint_suffix).unwrap(),
+            table_storage_url: get_endpoint_uri(None, &account, "table", endpoint_suffix).unwrap(),
+            queue_storage_url: get_endpoint_uri(None, &account, "queue", endpoint_suffix).unwrap(),
             queue_storage_secondary_url: get_endpoint_uri(
                 None,
                 &format!("{account}-secondary"),
                 "queue",
+                endpoint_suffix,
             )
             .unwrap(),
-            filesystem_url: get_endpoint_uri(None, &account, "dfs").unwrap(),
+            filesystem_url: get_endpoint_uri(None, &account, "dfs", endpoint_suffix).unwrap(),
             storage_credentials,
             account,
+            audience,
             pipeline,
         }
     }
 
+    pub fn new_access_key<A, K>(account: A, key: K) -> Self
+    where
+        A: Into<String>,
+        K: Into<String>,
+    {
+        let account = account.into();
+        let storage_credentials = StorageCredentials::access_key(account.clone(), key);
+        Self::new_in_cloud(account, storage_credentials, AzureCloud::Public)
+    }
+
     /// Create a new client for customized emulator endpoints.
     pub fn new_emulator(
         blob_storage_url: &Url,
@@ -233,10 +328,10 @@ impl StorageClient {
 
     /// Create a new client using the default HttpClient and the default emulator endpoints.
     pub fn new_emulator_default() -> Self {
-        let blob_storage_url = Url::parse("http://127.0.0.1:10000").unwrap();
-        let queue_storage_url = Url::parse("http://127.0.0.1:10001").unwrap();
-        let table_storage_url = Url::parse("http://127.0.0.1:10002").unwrap();
-        let filesystem_url = Url::parse("http://127.0.0.1:10004").unwrap();
+        let blob_storage_url = Url::parse(&format!("{EMULATOR_HOST}:10000")).unwrap();
+        let queue_storage_url = Url::parse(&format!("{EMULATOR_HOST}:10001")).unwrap();
+        let table_storage_url = Url::parse(&format!("{EMULATOR_HOST}:10002")).unwrap();
+        let filesystem_url = Url::parse(&format!("{EMULATOR_HOST}:10004")).unwrap();
         Self::new_emulator(
             &blob_storage_url,
             &table_storage_url,
@@ -274,6 +369,7 @@ impl StorageClient {
             filesystem_url,
             storage_credentials,
             account,
+            audience: public_cloud().storage_audience.into_owned(),
             pipeline,
         }
     }
@@ -283,26 +379,12 @@ impl StorageClient {
         A: Into<String>,
         S: AsRef<str>,
     {
-        let account = account.into();
-
         let storage_credentials = StorageCredentials::sas_token(sas_token)?;
-        let pipeline =
-            new_pipeline_from_options(ClientOptions::default(), storage_credentials.clone());
-
-        Ok(Self {
-            blob_storage_url: get_endpoint_uri(None, &account, "blob")?,
-            table_storage_url: get_endpoint_uri(None, &account, "table")?,
-            queue_storage_url: get_endpoint_uri(None, &account, "queue")?,
-            queue_storage_secondary_url: get_endpoint_uri(
-                None,
-                &format!("{account}-secondary"),
-                "queue",
-            )?,
-            filesystem_url: get_endpoint_uri(None, &account, "dfs")?,
-            storage_credentials,
+        Ok(Self::new_in_cloud(
             account,
-            pipeline,
-        })
+            storage_credentials,
+            AzureCloud::Public,
+        ))
     }
 
     pub fn new_bearer_token<A, BT>(account: A, bearer_token: BT) -> Self
@@ -310,56 +392,77 @@ impl StorageClient {
         A: Into<String>,
         BT: Into<String>,
     {
-        let account = account.into();
         let storage_credentials = StorageCredentials::bearer_token(bearer_token);
-        let pipeline =
-            new_pipeline_from_options(ClientOptions::default(), storage_credentials.clone());
-
-        Self {
-            blob_storage_url: get_endpoint_uri(None, &account, "blob").unwrap(),
-            table_storage_url: get_endpoint_uri(None, &account, "table").unwrap(),
-            queue_storage_url: get_endpoint_uri(None, &account, "queue").unwrap(),
-            queue_storage_secondary_url: get_endpoint_uri(
-                None,
-                &format!("{}-secondary", account),
-                "queue",
-            )
-            .unwrap(),
-            filesystem_url: get_endpoint_uri(None, &account, "dfs").unwrap(),
-            storage_credentials,
-            account,
-            pipeline,
-        }
+        Self::new_in_cloud(account, storage_credentials, AzureCloud::Public)
     }
 
     pub fn new_token_credential<A>(account: A, token_credential: Arc<dyn TokenCredential>) -> Self
     where
         A: Into<String>,
     {
-        let account = account.into();
         let storage_credentials = StorageCredentials::token_credential(token_credential);
-        let pipeline =
-            new_pipeline_from_options(ClientOptions::default(), storage_credentials.clone());
+        Self::new_in_cloud(account, storage_credentials, AzureCloud::Public)
+    }
 
-        Self {
-            blob_storage_url: get_endpoint_uri(None, &account, "blob").unwrap(),
-            table_storage_url: get_endpoint_uri(None, &account, "table").unwrap(),
-            queue_storage_url: get_endpoint_uri(None, &account, "queue").unwrap(),
-            queue_storage_secondary_url: get_endpoint_uri(
-                None,
-                &format!("{}-secondary", account),
-                "queue",
-            )
-            .unwrap(),
-            filesystem_url: get_endpoint_uri(None, &account, "dfs").unwrap(),
-            storage_credentials,
-            account,
-            pipeline,
+    /// Create a new client from the environment.
+    ///
+    /// The client is created from the `AZURE_STORAGE_CONNECTION_STRING` environment variable if
+    /// set (including the `UseDevelopmentStorage=true` form, which targets the emulator) or from
+    /// the `AZURE_STORAGE_ACCOUNT` and `AZURE_STORAGE_KEY` environment variables otherwise.
+    pub fn from_env() -> azure_core::Result<Self> {
+        Self::from_env_vars(|key| std::env::var(key).ok())
+    }
+
+    fn from_env_vars(var: impl Fn(&str) -> Option<String>) -> azure_core::Result<Self> {
+        if let Some(connection_string) = var(AZURE_STORAGE_CONNECTION_STRING_ENV_KEY) {
+            return Self::new_connection_string(&connection_string).context(
+                ErrorKind::Credential,
+                "invalid AZURE_STORAGE_CONNECTION_STRING environment variable",
+            );
+        }
+
+        match (
+            var(AZURE_STORAGE_ACCOUNT_ENV_KEY),
+            var(AZURE_STORAGE_KEY_ENV_KEY),
+        ) {
+            (Some(account), Some(key)) => Ok(Self::new_access_key(account, key)),
+            _ => Err(Error::with_message(ErrorKind::Credential, || {
+                format!(
+                    "could not create a storage client from the environment. Set either {} or both {} and {}",
+                    AZURE_STORAGE_CONNECTION_STRING_ENV_KEY,
+                    AZURE_STORAGE_ACCOUNT_ENV_KEY,
+                    AZURE_STORAGE_KEY_ENV_KEY
+                )
+            })),
         }
     }
 
+    /// Create a new client from a connection string.
+    ///
+    /// The `UseDevelopmentStorage=true` connection string targets the emulator well-known
+    /// endpoints (on the `DevelopmentStorageProxyUri` host, if specified).
     pub fn new_connection_string(connection_string: &str) -> azure_core::Result<Self> {
         match ConnectionString::new(connection_string)? {
+            ConnectionString {
+                use_development_storage: Some(true),
+                development_storage_proxy_uri,
+                ..
+            } => {
+                let host = development_storage_proxy_uri
+                    .unwrap_or(EMULATOR_HOST)
+                    .trim_end_matches('/');
+                let emulator_url = |port: u16| {
+                    Url::parse(&format!("{host}:{port}")).with_context(ErrorKind::DataConversion, || {
+                        format!("failed to parse development storage proxy uri: {host}")
+                    })
+                };
+                Ok(Self::new_emulator(
+                    &emulator_url(10000)?,
+                    &emulator_url(10002)?,
+                    &emulator_url(10001)?,
+                    &emulator_url(10004)?,
+                ))
+            }
             ConnectionString {
                 account_name: Some(account),
                 account_key: Some(_),
@@ -368,8 +471,11 @@ impl StorageClient {
                 table_endpoint,
                 queue_endpoint,
                 file_endpoint,
+                endpoint_suffix,
                 ..
             } => {
+                let public_cloud = public_cloud();
+                let endpoint_suffix = endpoint_suffix.unwrap_or(&public_cloud.storage_endpoint_suffix);
                 log::warn!("Both account key and SAS defined in connection string. Using only the provided SAS.");
 
                 let storage_credentials =  StorageCredentials::sas_token(sas_token)?;
@@ -377,12 +483,13 @@ impl StorageClient {
 
                 Ok(Self {
                     storage_credentials,
-                    blob_storage_url: get_endpoint_uri(blob_endpoint, account, "blob")?,
-                    table_storage_url: get_endpoint_uri(table_endpoint, account, "table")?,
-                    queue_storage_url: get_endpoint_uri(queue_endpoint, account, "queue")?,
-                    queue_storage_secondary_url: get_endpoint_uri(queue_endpoint, &format!("{}-secondary", account), "queue")?,
-                    filesystem_url: get_endpoint_uri(file_endpoint, account, "dfs")?,
+                    blob_storage_url: get_endpoint_uri(blob_endpoint, account, "blob", endpoint_suffix)?,
+                    table_storage_url: get_endpoint_uri(table_endpoint, account, "table", endpoint_suffix)?,
+                    queue_storage_url: get_endpoint_uri(queue_endpoint, account, "queue", endpoint_suffix)?,
+                    queue_storage_secondary_url: get_endpoint_uri(queue_endpoint, &format!("{}-secondary", account), "queue", endpoint_suffix)?,
+                    filesystem_url: get_endpoint_uri(file_endpoint, account, "dfs", endpoint_suffix)?,
                     account: account.to_string(),
+                    audience: public_cloud.storage_audience.into_owned(),
                     pipeline
                 })
             }
@@ -393,19 +500,23 @@ impl StorageClient {
                 table_endpoint,
                 queue_endpoint,
                 file_endpoint,
+                endpoint_suffix,
                 ..
             } => {
+                let public_cloud = public_cloud();
+                let endpoint_suffix = endpoint_suffix.unwrap_or(&public_cloud.storage_endpoint_suffix);
                 let storage_credentials = StorageCredentials::sas_token(sas_token)?;
                 let pipeline =
                 new_pipeline_from_options(ClientOptions::default(), storage_credentials.clone());
                 Ok(Self {
                     storage_credentials,
-                    blob_storage_url: get_endpoint_uri(blob_endpoint, account, "blob")?,
-                    table_storage_url: get_endpoint_uri(table_endpoint, account, "table")?,
-                    queue_storage_url: get_endpoint_uri(queue_endpoint, account, "queue")?,
-                    queue_storage_secondary_url: get_endpoint_uri(queue_endpoint, &format!("{}-secondary", account), "queue")?,
-                    filesystem_url: get_endpoint_uri(file_endpoint, account, "dfs")?,
+                    blob_storage_url: get_endpoint_uri(blob_endpoint, account, "blob", endpoint_suffix)?,
+                    table_storage_url: get_endpoint_uri(table_endpoint, account, "table", endpoint_suffix)?,
+                    queue_storage_url: get_endpoint_uri(queue_endpoint, account, "queue", endpoint_suffix)?,
+                    queue_storage_secondary_url: get_endpoint_uri(queue_endpoint, &format!("{}-secondary", account), "queue", endpoint_suffix)?,
+                    filesystem_url: get_endpoint_uri(file_endpoint, account, "dfs", endpoint_suffix)?,
                     account: account.to_string(),
+                    audience: public_cloud.storage_audience.into_owned(),
                     pipeline
             })},
             ConnectionString {
@@ -415,19 +526,23 @@ impl StorageClient {
                 table_endpoint,
                 queue_endpoint,
                 file_endpoint,
+                endpoint_suffix,
                 ..
             } => {
+                let public_cloud = public_cloud();
+                let endpoint_suffix = endpoint_suffix.unwrap_or(&public_cloud.storage_endpoint_suffix);
 
                 let storage_credentials = StorageCredentials::access_key(account, key);
                 let pipeline = new_pipeline_from_options(ClientOptions::default(), storage_credentials.clone());
                 Ok(Self {
                 storage_credentials,
-                blob_storage_url: get_endpoint_uri(blob_endpoint, account, "blob")?,
-                table_storage_url: get_endpoint_uri(table_endpoint, account, "table")?,
-                queue_storage_url: get_endpoint_uri(queue_endpoint, account, "queue")?,
-                queue_storage_secondary_url: get_endpoint_uri(queue_endpoint, &format!("{}-secondary", account), "queue")?,
-                filesystem_url: get_endpoint_uri(file_endpoint, account, "dfs")?,
+                blob_storage_url: get_endpoint_uri(blob_endpoint, account, "blob", endpoint_suffix)?,
+                table_storage_url: get_endpoint_uri(table_endpoint, account, "table", endpoint_suffix)?,
+                queue_storage_url: get_endpoint_uri(queue_endpoint, account, "queue", endpoint_suffix)?,
+                queue_storage_secondary_url: get_endpoint_uri(queue_endpoint, &format!("{}-secondary", account), "queue", endpoint_suffix)?,
+                filesystem_url: get_endpoint_uri(file_endpoint, account, "dfs", endpoint_suffix)?,
                 account: account.to_string(),
+                audience: public_cloud.storage_audience.into_owned(),
                 pipeline
             })
         },
@@ -446,26 +561,8 @@ impl StorageClient {
     where
         A: Into<String>,
     {
-        let account = account.into();
         let storage_credentials = StorageCredentials::anonymous();
-        let pipeline =
-            new_pipeline_from_options(ClientOptions::default(), storage_credentials.clone());
-
-        Self {
-            blob_storage_url: get_endpoint_uri(None, &account, "blob").unwrap(),
-            table_storage_url: get_endpoint_uri(None, &account, "table").unwrap(),
-            queue_storage_url: get_endpoint_uri(None, &account, "queue").unwrap(),
-            queue_storage_secondary_url: get_endpoint_uri(
-                None,
-                &format!("{}-secondary", account),
-                "queue",
-            )
-            .unwrap(),
-            filesystem_url: get_endpoint_uri(None, &account, "dfs").unwrap(),
-            storage_credentials,
-            account,
-            pipeline,
-        }
+        Self::new_in_cloud(account, storage_credentials, AzureCloud::Public)
     }
 
     /// Create a new instance of `StorageClient` using a mock backend. The
@@ -479,19 +576,22 @@ impl StorageClient {
         let account = account.into();
         let options = ClientOptions::new(TransportOptions::new_custom_policy(transport_policy));
         let pipeline = new_pipeline_from_options(options, storage_credentials.clone());
+        let endpoint_suffix = &public_cloud().storage_endpoint_suffix;
         Self {
-            blob_storage_url: get_endpoint_uri(None, &account, "blob").unwrap(),
-            table_storage_url: get_endpoint_uri(None, &account, "table").unwrap(),
-            queue_storage_url: get_endpoint_uri(None, &account, "queue").unwrap(),
+            blob_storage_url: get_endpoint_uri(None, &account, "blob", endpoint_suffix).unwrap(),
+            table_storage_url: get_endpoint_uri(None, &account, "table", endpoint_suffix).unwrap(),
+            queue_storage_url: get_endpoint_uri(None, &account, "queue", endpoint_suffix).unwrap(),
             queue_storage_secondary_url: get_endpoint_uri(
                 None,
                 &format!("{}-secondary", account),
                 "queue",
+                endpoint_suffix,
             )
             .unwrap(),
-            filesystem_url: get_endpoint_uri(None, &account, "dfs").unwrap(),
+            filesystem_url: get_endpoint_uri(None, &account, "dfs", endpoint_suffix).unwrap(),
             storage_credentials,
             account,
+            audience: public_cloud().storage_audience.into_owned(),
             pipeline,
         }
     }
@@ -524,6 +624,160 @@ impl StorageClient {
         &self.storage_credentials
     }
 
+    /// Create a client for the account of a cloud location, e.g. the one of a blob or a data lake
+    /// client.
+    ///
+    /// The emulator endpoints are the default ports of `address`. A custom URL is the endpoint of
+    /// a single service, so no client can be created from it.
+    pub fn from_cloud_location(cloud_location: &CloudLocation) -> azure_core::Result<Self> {
+        let credentials = cloud_location.credentials().clone();
+        Ok(match cloud_location {
+            CloudLocation::Public { account, .. } => {
+                Self::new_in_cloud(account, credentials, AzureCloud::Public)
+            }
+            CloudLocation::China { account, .. } => {
+                Self::new_in_cloud(account, credentials, AzureCloud::China)
+            }
+            CloudLocation::UsGovernment { account, .. } => {
+                Self::new_in_cloud(account, credentials, AzureCloud::UsGovernment)
+            }
+            CloudLocation::Sovereign { cloud, account, .. } => {
+                Self::new_in_cloud(account, credentials, cloud.clone())
+            }
+            CloudLocation::Emulator { address, .. } => {
+                Self::new_emulator_at(address, |service_type| service_type.emulator_port())?
+            }
+            CloudLocation::Custom { uri, .. } => {
+                return Err(Error::with_message(ErrorKind::Other, || {
+                    format!("cannot create a storage client for the custom URL {uri}")
+                }))
+            }
+        })
+    }
+
+    /// Create a client for the account of the location of the endpoint of `service_type`, e.g. the
+    /// location of the builder of a service client.
+    ///
+    /// Unlike with [`from_cloud_location`](Self::from_cloud_location), the port of the emulator is
+    /// the one of `service_type`, the other services being at their default ports, and a custom
+    /// URL is the endpoint of `service_type`, see [`from_custom_url`](Self::from_custom_url).
+    pub fn from_service_location(
+        cloud_location: &CloudLocation,
+        service_type: ServiceType,
+    ) -> azure_core::Result<Self> {
+        match cloud_location {
+            CloudLocation::Emulator { address, port } => {
+                Self::new_emulator_at(address, |service| {
+                    if service == service_type {
+                        *port
+                    } else {
+                        service.emulator_port()
+                    }
+                })
+            }
+            CloudLocation::Custom { uri, credentials } => Ok(Self::from_custom_url(
+                &Url::parse(uri)?,
+                credentials.clone(),
+            )),
+            cloud_location => Self::from_cloud_location(cloud_location),
+        }
+    }
+
+    /// Create a client for the emulator at `address`, with the `port` of each service.
+    fn new_emulator_at(
+        address: &str,
+        port: impl Fn(ServiceType) -> u16,
+    ) -> azure_core::Result<Self> {
+        let url = |service_type: ServiceType| {
+            let port = port(service_type);
+            Url::parse(&format!("http://{address}:{port}"))
+                .with_context(ErrorKind::DataConversion, || {
+                    format!("invalid emulator address: {address}")
+                })
+        };
+        Ok(Self::new_emulator(
+            &url(ServiceType::Blob)?,
+            &url(ServiceType::Table)?,
+            &url(ServiceType::Queue)?,
+            &url(ServiceType::DataLake)?,
+        ))
+    }
+
+    /// Create a client for an account at a custom URL, e.g. a private endpoint or a proxy.
+    ///
+    /// The URL is the endpoint of one service. The endpoints of the other services are
+    /// translated from it with [`ServiceType::translate_url`], so they are the same URL unless it
+    /// follows the `<account>.<service>.<domain>` pattern.
+    pub fn from_custom_url(url: &Url, storage_credentials: StorageCredentials) -> Self {
+        let account = url
+            .host_str()
+            .and_then(|host| host.split('.').next())
+            .unwrap_or_default()
+            .to_owned();
+        let pipeline =
+            new_pipeline_from_options(ClientOptions::default(), storage_credentials.clone());
+        let queue_storage_url = ServiceType::Queue.translate_url(url);
+        Self {
+            blob_storage_url: ServiceType::Blob.translate_url(url),
+            table_storage_url: ServiceType::Table.translate_url(url),
+            queue_storage_secondary_url: queue_storage_url.clone(),
+            queue_storage_url,
+            filesystem_url: ServiceType::DataLake.translate_url(url),
+            storage_credentials,
+            account,
+            audience: public_cloud().storage_audience.into_owned(),
+            pipeline,
+        }
+    }
+
+    /// Send the requests of the client with `options`, e.g. other retry options.
+    #[must_use]
+    pub fn with_options(mut self, options: ClientOptions) -> Self {
+        self.pipeline =
+            new_pipeline_in_cloud(options, self.storage_credentials.clone(), &self.audience);
+        self
+    }
+
+    /// The location of the endpoint of a service of the account, to create the client of the
+    /// `azure_storage_blobs` or `azure_storage_datalake` crates.
+    pub fn cloud_location(&self, service_type: ServiceType) -> CloudLocation {
+        let url = match service_type {
+            ServiceType::Blob => &self.blob_storage_url,
+            ServiceType::Queue => &self.queue_storage_url,
+            ServiceType::Table => &self.table_storage_url,
+            ServiceType::DataLake => &self.filesystem_url,
+        };
+        let account = self.account.clone();
+        let credentials = self.storage_credentials.clone();
+        let in_cloud = |cloud: AzureCloud| {
+            let cloud_url = cloud
+                .configuration()
+                .storage_account_url(&self.account, service_type.subdomain());
+            Url::parse(&cloud_url).map_or(false, |cloud_url| &cloud_url == url)
+        };
+        if in_cloud(AzureCloud::Public) {
+            CloudLocation::Public {
+                account,
+                credentials,
+            }
+        } else if in_cloud(AzureCloud::China) {
+            CloudLocation::China {
+                account,
+                credentials,
+            }
+        } else if in_cloud(AzureCloud::UsGovernment) {
+            CloudLocation::UsGovernment {
+                account,
+                credentials,
+            }
+        } else {
+            CloudLocation::Custom {
+                uri: url.as_str().trim_end_matches('/').to_owned(),
+                credentials,
+            }
+        }
+    }
+
     pub fn finalize_request(
         &self,
         url: Url,
@@ -545,6 +799,10 @@ impl StorageClient {
             .await
     }
 
+    /// Create an account shared access signature, signed with the account key.
+    ///
+    /// Fails with a [`SasCredentialsError`] if the client has no account key, e.g. when it is
+    /// authorized with Azure Active Directory.
     pub fn shared_access_signature(
         &self,
         resource: AccountSasResource,
@@ -602,11 +860,16 @@ pub fn shared_access_signature(
     permissions: AccountSasPermissions,
 ) -> Result<AccountSharedAccessSignature, Error> {
     match storage_credentials {
-            StorageCredentials::Key(account, key) => {
-                Ok(AccountSharedAccessSignature::new(account.clone(), key.clone(), resource, resource_type, expiry, permissions))
-            }
-            _ => Err(Error::message(ErrorKind::Credential, "failed shared access signature generation. SAS can be generated only from key and account clients")),
-        }
+        StorageCredentials::Key(account, key) => Ok(AccountSharedAccessSignature::new(
+            account.clone(),
+            key.clone(),
+            resource,
+            resource_type,
+            expiry,
+            permissions,
+        )),
+        credentials => Err(SasCredentialsError::from(credentials).into()),
+    }
 }
 
 pub fn finalize_request(
@@ -664,25 +927,42 @@ fn get_endpoint_uri(
     url: Option<&str>,
     account: &str,
     endpoint_type: &str,
+    endpoint_suffix: &str,
 ) -> azure_core::Result<url::Url> {
     Ok(match url {
         Some(value) => url::Url::parse(value)?,
         None => url::Url::parse(&format!(
-            "https://{}.{}.core.windows.net",
-            account, endpoint_type
+            "https://{account}.{endpoint_type}.{endpoint_suffix}"
         ))
         .with_context(ErrorKind::DataConversion, || {
-            format!("failed to parse url: https://{account}.{endpoint_type}.core.windows.net")
+            format!("failed to parse url: https://{account}.{endpoint_type}.{endpoint_suffix}")
         })?,
     })
 }
 
+/// The cloud of the accounts created without one.
+fn public_cloud() -> CloudConfiguration {
+    AzureCloud::Public.configuration()
+}
+
 /// Create a Pipeline from ClientOptions
 pub fn new_pipeline_from_options(
     options: ClientOptions,
     credentials: StorageCredentials,
 ) -> Pipeline {
-    let auth_policy: Arc<dyn azure_core::Policy> = Arc::new(AuthorizationPolicy::new(credentials));
+    new_pipeline_in_cloud(options, credentials, &public_cloud().storage_audience)
+}
+
+/// Create a Pipeline from ClientOptions, requesting the tokens of a token credential for
+/// `audience`, the [`storage_audience`](CloudConfiguration::storage_audience) of the cloud of the
+/// account.
+pub fn new_pipeline_in_cloud(
+    options: ClientOptions,
+    credentials: StorageCredentials,
+    audience: &str,
+) -> Pipeline {
+    let auth_policy: Arc<dyn azure_core::Policy> =
+        Arc::new(AuthorizationPolicy::new(credentials, audience.to_owned()));
 
     // The `AuthorizationPolicy` must be the **last** retry policy.
     // Policies can change the url and/or the headers, and the `AuthorizationPolicy`
@@ -700,3 +980,190 @@ pub fn new_pipeline_from_options(
         per_retry_policies,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn translates_urls_between_services() {
+        let url = Url::parse("https://myaccount.dfs.core.windows.net/fs/dir/file.txt").unwrap();
+        assert_eq!(
+            ServiceType::Blob.translate_url(&url).as_str(),
+            "https://myaccount.blob.core.windows.net/fs/dir/file.txt"
+        );
+
+        let url = Url::parse("https://myaccount.blob.core.chinacloudapi.cn/fs?sv=1").unwrap();
+        assert_eq!(
+            ServiceType::DataLake.translate_url(&url).as_str(),
+            "https://myaccount.dfs.core.chinacloudapi.cn/fs?sv=1"
+        );
+
+        let url = Url::parse("http://127.0.0.1:10000/devstoreaccount1/fs/file").unwrap();
+        assert_eq!(ServiceType::Blob.translate_url(&url), url);
+    }
+
+    #[test]
+    fn converts_to_and_from_cloud_locations() {
+        let client = StorageClient::new_access_key("myaccount", "a2V5");
+        let location = client.cloud_location(ServiceType::DataLake);
+        assert!(
+            matches!(location, CloudLocation::Public { ref account, .. } if account == "myaccount")
+        );
+        let client = StorageClient::from_cloud_location(&location).unwrap();
+        assert_eq!(
+            client.blob_storage_url().as_str(),
+            "https://myaccount.blob.core.windows.net/"
+        );
+
+        let client = StorageClient::new_emulator_default();
+        let location = client.cloud_location(ServiceType::Blob);
+        assert_eq!(
+            location.url(ServiceType::Blob).unwrap().as_str(),
+            "http://127.0.0.1:10000/devstoreaccount1"
+        );
+        let client = StorageClient::from_cloud_location(&CloudLocation::Emulator {
+            address: "127.0.0.1".to_owned(),
+            port: 10000,
+        })
+        .unwrap();
+        assert_eq!(
+            client.queue_storage_url().as_str(),
+            "http://127.0.0.1:10001/devstoreaccount1"
+        );
+
+        assert!(StorageClient::from_cloud_location(&location).is_err());
+    }
+
+    #[test]
+    fn translates_custom_urls() {
+        let url = Url::parse("https://myaccount.queue.contoso.com").unwrap();
+        let client = StorageClient::from_custom_url(&url, StorageCredentials::anonymous());
+        assert_eq!(client.account(), "myaccount");
+        assert_eq!(client.queue_storage_url(), &url);
+        assert_eq!(
+            client.blob_storage_url().as_str(),
+            "https://myaccount.blob.contoso.com/"
+        );
+
+        let url = Url::parse("https://proxy.internal:8443/storage").unwrap();
+        let client = StorageClient::from_custom_url(&url, StorageCredentials::anonymous());
+        assert_eq!(client.filesystem_url(), &url);
+    }
+
+    fn from_vars(vars: &[(&str, &str)]) -> azure_core::Result<StorageClient> {
+        let vars = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect::<HashMap<_, _>>();
+        StorageClient::from_env_vars(|key| vars.get(key).cloned())
+    }
+
+    #[test]
+    fn development_storage_connection_string() {
+        let client = StorageClient::new_connection_string("UseDevelopmentStorage=true").unwrap();
+        assert_eq!(client.account(), EMULATOR_ACCOUNT);
+        assert_eq!(
+            client.blob_storage_url().as_str(),
+            "http://127.0.0.1:10000/devstoreaccount1"
+        );
+        assert_eq!(
+            client.queue_storage_url().as_str(),
+            "http://127.0.0.1:10001/devstoreaccount1"
+        );
+        assert_eq!(
+            client.table_storage_url().as_str(),
+            "http://127.0.0.1:10002/devstoreaccount1"
+        );
+    }
+
+    #[test]
+    fn development_storage_proxy_uri() {
+        let client = StorageClient::new_connection_string(
+            "UseDevelopmentStorage=true;DevelopmentStorageProxyUri=http://azurite",
+        )
+        .unwrap();
+        assert_eq!(
+            client.blob_storage_url().as_str(),
+            "http://azurite:10000/devstoreaccount1"
+        );
+    }
+
+    #[test]
+    fn from_env_prefers_connection_string() {
+        let client = from_vars(&[
+            (
+                AZURE_STORAGE_CONNECTION_STRING_ENV_KEY,
+                "UseDevelopmentStorage=true",
+            ),
+            (AZURE_STORAGE_ACCOUNT_ENV_KEY, "account"),
+            (AZURE_STORAGE_KEY_ENV_KEY, "a2V5"),
+        ])
+        .unwrap();
+        assert_eq!(client.account(), EMULATOR_ACCOUNT);
+    }
+
+    #[test]
+    fn from_env_account_and_key() {
+        let client = from_vars(&[
+            (AZURE_STORAGE_ACCOUNT_ENV_KEY, "account"),
+            (AZURE_STORAGE_KEY_ENV_KEY, "a2V5"),
+        ])
+        .unwrap();
+        assert_eq!(client.account(), "account");
+        assert_eq!(
+            client.blob_storage_url().as_str(),
+            "https://account.blob.core.windows.net/"
+        );
+    }
+
+    #[test]
+    fn sovereign_cloud_endpoints() {
+        let client = StorageClient::new_connection_string(
+            "AccountName=account;AccountKey=a2V5;EndpointSuffix=core.chinacloudapi.cn",
+        )
+        .unwrap();
+        assert_eq!(
+            client.blob_storage_url().as_str(),
+            "https://account.blob.core.chinacloudapi.cn/"
+        );
+
+        let client = StorageClient::new_in_cloud(
+            "account",
+            StorageCredentials::anonymous(),
+            AzureCloud::UsGovernment,
+        );
+        assert_eq!(
+            client.queue_storage_secondary_url().as_str(),
+            "https://account-secondary.queue.core.usgovcloudapi.net/"
+        );
+    }
+
+    #[test]
+    fn keeps_the_audience_of_the_cloud() {
+        let cloud = CloudConfiguration {
+            storage_endpoint_suffix: "storage.contoso.com".into(),
+            storage_audience: "https://storage.azurestack.contoso.com".into(),
+            ..AzureCloud::Public.configuration()
+        };
+        let client = StorageClient::new_in_cloud("account", StorageCredentials::anonymous(), cloud)
+            .with_options(ClientOptions::default());
+        assert_eq!(
+            client.blob_storage_url().as_str(),
+            "https://account.blob.storage.contoso.com/"
+        );
+        assert_eq!(client.audience, "https://storage.azurestack.contoso.com");
+
+        let client = StorageClient::new_connection_string(
+            "AccountName=account;AccountKey=a2V5;EndpointSuffix=core.chinacloudapi.cn",
+        )
+        .unwrap();
+        assert_eq!(client.audience, "https://storage.azure.com");
+    }
+
+    #[test]
+    fn from_env_missing_variables() {
+        assert!(from_vars(&[(AZURE_STORAGE_ACCOUNT_ENV_KEY, "account")]).is_err());
+    }
+}
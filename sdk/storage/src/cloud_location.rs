@@ -1,9 +1,9 @@
 use once_cell::sync::Lazy;
 
 use crate::clients::{ServiceType, StorageCredentials};
+use azure_core::{AzureCloud, CloudConfiguration};
 
 /// The cloud with which you want to interact.
-// TODO: Other govt clouds?
 #[derive(Debug, Clone)]
 pub enum CloudLocation {
     /// Azure public cloud
@@ -16,6 +16,17 @@ pub enum CloudLocation {
         account: String,
         credentials: StorageCredentials,
     },
+    /// Azure US Government cloud
+    UsGovernment {
+        account: String,
+        credentials: StorageCredentials,
+    },
+    /// Another cloud, such as Azure Stack
+    Sovereign {
+        cloud: CloudConfiguration,
+        account: String,
+        credentials: StorageCredentials,
+    },
     /// Use the well-known emulator
     Emulator { address: String, port: u16 },
     /// A custom base URL
@@ -29,19 +40,17 @@ impl CloudLocation {
     /// the base URL for a given cloud location
     pub fn url(&self, service_type: ServiceType) -> azure_core::Result<url::Url> {
         let url = match self {
-            CloudLocation::Public { account, .. } => {
-                format!(
-                    "https://{}.{}.core.windows.net",
-                    account,
-                    service_type.subdomain()
-                )
-            }
-            CloudLocation::China { account, .. } => {
-                format!(
-                    "https://{}.{}.core.chinacloudapi.cn",
-                    account,
-                    service_type.subdomain()
-                )
+            CloudLocation::Public { account, .. } => AzureCloud::Public
+                .configuration()
+                .storage_account_url(account, service_type.subdomain()),
+            CloudLocation::China { account, .. } => AzureCloud::China
+                .configuration()
+                .storage_account_url(account, service_type.subdomain()),
+            CloudLocation::UsGovernment { account, .. } => AzureCloud::UsGovernment
+                .configuration()
+                .storage_account_url(account, service_type.subdomain()),
+            CloudLocation::Sovereign { cloud, account, .. } => {
+                cloud.storage_account_url(account, service_type.subdomain())
             }
             CloudLocation::Custom { uri, .. } => uri.clone(),
             CloudLocation::Emulator { address, port } => {
@@ -51,10 +60,67 @@ impl CloudLocation {
         Ok(url::Url::parse(&url)?)
     }
 
+    /// The account name, unless the location is a custom URL.
+    pub fn account(&self) -> Option<&str> {
+        match self {
+            CloudLocation::Public { account, .. }
+            | CloudLocation::China { account, .. }
+            | CloudLocation::UsGovernment { account, .. }
+            | CloudLocation::Sovereign { account, .. } => Some(account),
+            CloudLocation::Emulator { .. } => Some(EMULATOR_ACCOUNT),
+            CloudLocation::Custom { .. } => None,
+        }
+    }
+
+    /// The same location accessed with other credentials.
+    ///
+    /// The emulator only accepts its well-known credentials, so the location of the emulator
+    /// becomes a custom location at the URL of the emulator, e.g. to access it through a proxy
+    /// which authenticates the requests.
+    #[must_use]
+    pub fn with_credentials(self, credentials: StorageCredentials) -> Self {
+        match self {
+            CloudLocation::Public { account, .. } => CloudLocation::Public {
+                account,
+                credentials,
+            },
+            CloudLocation::China { account, .. } => CloudLocation::China {
+                account,
+                credentials,
+            },
+            CloudLocation::UsGovernment { account, .. } => CloudLocation::UsGovernment {
+                account,
+                credentials,
+            },
+            CloudLocation::Sovereign { cloud, account, .. } => CloudLocation::Sovereign {
+                cloud,
+                account,
+                credentials,
+            },
+            CloudLocation::Emulator { address, port } => CloudLocation::Custom {
+                uri: format!("http://{address}:{port}/{EMULATOR_ACCOUNT}"),
+                credentials,
+            },
+            CloudLocation::Custom { uri, .. } => CloudLocation::Custom { uri, credentials },
+        }
+    }
+
+    /// A custom location at `uri` with the credentials of this location, e.g. for a private
+    /// endpoint of the account.
+    #[must_use]
+    pub fn with_endpoint(self, uri: impl Into<String>) -> Self {
+        CloudLocation::Custom {
+            uri: uri.into(),
+            credentials: self.credentials().clone(),
+        }
+    }
+
     pub fn credentials(&self) -> &StorageCredentials {
         match self {
             CloudLocation::Public { credentials, .. } => credentials,
             CloudLocation::China { credentials, .. } => credentials,
+            CloudLocation::UsGovernment { credentials, .. } => credentials,
+            CloudLocation::Sovereign { credentials, .. } => credentials,
             CloudLocation::Emulator { .. } => &EMULATOR_CREDENTIALS,
             CloudLocation::Custom { credentials, .. } => credentials,
         }
@@ -73,3 +139,46 @@ pub const EMULATOR_ACCOUNT: &str = "devstoreaccount1";
 /// <https://docs.microsoft.com/azure/storage/common/storage-use-azurite#well-known-storage-account-and-key>
 pub const EMULATOR_ACCOUNT_KEY: &str =
     "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn changes_the_endpoint_and_the_credentials() {
+        let location = CloudLocation::Public {
+            account: "myaccount".to_owned(),
+            credentials: StorageCredentials::anonymous(),
+        };
+        let location = location.with_credentials(StorageCredentials::bearer_token("token"));
+        assert_eq!(location.account(), Some("myaccount"));
+        assert!(matches!(
+            location.credentials(),
+            StorageCredentials::BearerToken(token) if token == "token"
+        ));
+
+        let location = location.with_endpoint("https://myaccount.privatelink.contoso.com");
+        assert_eq!(
+            location.url(ServiceType::Blob).unwrap().as_str(),
+            "https://myaccount.privatelink.contoso.com/"
+        );
+        assert!(matches!(
+            location.credentials(),
+            StorageCredentials::BearerToken(_)
+        ));
+
+        let location = CloudLocation::Emulator {
+            address: "127.0.0.1".to_owned(),
+            port: 10001,
+        }
+        .with_credentials(StorageCredentials::anonymous());
+        assert_eq!(
+            location.url(ServiceType::Queue).unwrap().as_str(),
+            "http://127.0.0.1:10001/devstoreaccount1"
+        );
+        assert!(matches!(
+            location.credentials(),
+            StorageCredentials::Anonymous
+        ));
+    }
+}
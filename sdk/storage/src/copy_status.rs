@@ -0,0 +1,13 @@
+use azure_core::headers::{Headers, COPY_STATUS};
+
+create_enum!(
+    CopyStatus,
+    (Pending, "pending"),
+    (Success, "success"),
+    (Aborted, "aborted"),
+    (Failed, "failed")
+);
+
+pub fn copy_status_from_headers(headers: &Headers) -> azure_core::Result<CopyStatus> {
+    headers.get_as(&COPY_STATUS)
+}
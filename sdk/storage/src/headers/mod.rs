@@ -32,6 +32,7 @@ impl TryFrom<&Headers> for CommonStorageResponseHeaders {
 }
 
 pub const CONTENT_CRC64: HeaderName = HeaderName::from_static("x-ms-content-crc64");
+pub const COPY_ACTION: HeaderName = HeaderName::from_static("x-ms-copy-action");
 pub const COPY_ID: HeaderName = HeaderName::from_static("x-ms-copy-id");
 pub const RENAME_SOURCE: HeaderName = HeaderName::from_static("x-ms-rename-source");
 
@@ -1,8 +1,23 @@
+//! Shared Key signing primitives.
+//!
+//! These are the building blocks used by the storage authorization policy to
+//! sign requests. They are exposed so that requests can be pre-signed outside of
+//! the SDK pipeline (for example on devices that cannot run the full SDK).
+//!
+//! ref: <https://docs.microsoft.com/rest/api/storageservices/authorize-with-shared-key>
+
+use crate::clients::ServiceType;
 use azure_core::error::{ErrorKind, ResultExt};
+use azure_core::headers::*;
+use azure_core::Method;
 use base64::encode;
 use hmac::{Hmac, Mac};
 use sha2::Sha256;
+use std::borrow::Cow;
+use url::Url;
 
+/// Signs `data` with the base64 encoded `key` using HMAC-SHA256 and returns the
+/// base64 encoded signature.
 pub fn sign(data: &str, key: &str) -> azure_core::Result<String> {
     let mut hmac = Hmac::<Sha256>::new_from_slice(
         &base64::decode(key).with_context(ErrorKind::DataConversion, || {
@@ -16,3 +31,275 @@ pub fn sign(data: &str, key: &str) -> azure_core::Result<String> {
     let signature = hmac.finalize().into_bytes();
     Ok(encode(&signature))
 }
+
+/// Generates the value of the `Authorization` header for a request signed with
+/// the account's Shared Key.
+///
+/// The headers must already contain every header that will be sent (in
+/// particular `x-ms-date` and `x-ms-version`), as they are part of the signature.
+pub fn generate_authorization(
+    h: &Headers,
+    u: &Url,
+    method: &Method,
+    account: &str,
+    key: &str,
+    service_type: &ServiceType,
+) -> azure_core::Result<String> {
+    let str_to_sign = string_to_sign(h, u, method, account, service_type);
+    let auth = sign(&str_to_sign, key).context(ErrorKind::Credential, "failed to sign the hmac")?;
+    Ok(format!("SharedKey {}:{}", account, auth))
+}
+
+/// Generates the value of the `Authorization` header for a request signed with
+/// the account's Shared Key, using the Shared Key Lite format.
+pub fn generate_authorization_lite(
+    h: &Headers,
+    u: &Url,
+    method: &Method,
+    account: &str,
+    key: &str,
+    service_type: &ServiceType,
+) -> azure_core::Result<String> {
+    let str_to_sign = string_to_sign_lite(h, u, method, account, service_type);
+    let auth = sign(&str_to_sign, key).context(ErrorKind::Credential, "failed to sign the hmac")?;
+    Ok(format!("SharedKeyLite {}:{}", account, auth))
+}
+
+fn add_if_exists<'a>(h: &'a Headers, key: &HeaderName) -> &'a str {
+    h.get_optional_str(key).unwrap_or_default()
+}
+
+/// Builds the string to sign for the given service.
+///
+/// The Table service uses a shorter form of the string to sign than the Blob
+/// and Queue services.
+#[allow(unknown_lints)]
+pub fn string_to_sign(
+    h: &Headers,
+    u: &Url,
+    method: &Method,
+    account: &str,
+    service_type: &ServiceType,
+) -> String {
+    match service_type {
+        ServiceType::Table => {
+            format!(
+                "{}\n{}\n{}\n{}\n{}",
+                method.as_ref(),
+                add_if_exists(h, &CONTENT_MD5),
+                add_if_exists(h, &CONTENT_TYPE),
+                add_if_exists(h, &MS_DATE),
+                canonicalized_resource_table(account, u)
+            )
+        }
+        _ => {
+            // content length must only be specified if != 0
+            // this is valid from 2015-02-21
+            let content_length = h
+                .get_optional_str(&CONTENT_LENGTH)
+                .filter(|&v| v != "0")
+                .unwrap_or_default();
+            format!(
+                "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}{}",
+                method.as_ref(),
+                add_if_exists(h, &CONTENT_ENCODING),
+                add_if_exists(h, &CONTENT_LANGUAGE),
+                content_length,
+                add_if_exists(h, &CONTENT_MD5),
+                add_if_exists(h, &CONTENT_TYPE),
+                add_if_exists(h, &DATE),
+                add_if_exists(h, &IF_MODIFIED_SINCE),
+                add_if_exists(h, &IF_MATCH),
+                add_if_exists(h, &IF_NONE_MATCH),
+                add_if_exists(h, &IF_UNMODIFIED_SINCE),
+                add_if_exists(h, &RANGE),
+                canonicalize_header(h),
+                canonicalized_resource(account, u)
+            )
+        }
+    }
+}
+
+/// Builds the Shared Key Lite string to sign for the given service.
+///
+/// The Table service signs only the date and the resource.
+pub fn string_to_sign_lite(
+    h: &Headers,
+    u: &Url,
+    method: &Method,
+    account: &str,
+    service_type: &ServiceType,
+) -> String {
+    match service_type {
+        ServiceType::Table => {
+            let date = h
+                .get_optional_str(&MS_DATE)
+                .unwrap_or_else(|| add_if_exists(h, &DATE));
+            format!("{}\n{}", date, canonicalized_resource_lite(account, u))
+        }
+        _ => format!(
+            "{}\n{}\n{}\n{}\n{}{}",
+            method.as_ref(),
+            add_if_exists(h, &CONTENT_MD5),
+            add_if_exists(h, &CONTENT_TYPE),
+            add_if_exists(h, &DATE),
+            canonicalize_header(h),
+            canonicalized_resource_lite(account, u)
+        ),
+    }
+}
+
+/// Builds the canonicalized resource string used by Shared Key Lite: the path
+/// and only the `comp` query parameter.
+pub fn canonicalized_resource_lite(account: &str, u: &Url) -> String {
+    let resource = format!("/{}{}", account, u.path());
+    match u.query_pairs().find(|(k, _)| k == "comp") {
+        Some((_, comp)) => format!("{resource}?comp={comp}"),
+        None => resource,
+    }
+}
+
+/// Builds the canonicalized headers string: every `x-ms-` header, sorted by
+/// name, each followed by a newline.
+pub fn canonicalize_header(headers: &Headers) -> String {
+    let mut names = headers
+        .iter()
+        .filter_map(|(k, _)| (k.as_str().starts_with("x-ms")).then(|| k))
+        .collect::<Vec<_>>();
+    names.sort_unstable();
+
+    let mut result = String::new();
+
+    for header_name in names {
+        let value = headers.get_optional_str(header_name).unwrap();
+        let name = header_name.as_str();
+        result = format!("{result}{name}:{value}\n");
+    }
+    result
+}
+
+/// Builds the canonicalized resource string used by the Table service.
+pub fn canonicalized_resource_table(account: &str, u: &Url) -> String {
+    format!("/{}{}", account, u.path())
+}
+
+/// Builds the canonicalized resource string used by the Blob and Queue services.
+pub fn canonicalized_resource(account: &str, uri: &Url) -> String {
+    let mut can_res: String = String::new();
+    can_res += "/";
+    can_res += account;
+
+    for p in uri.path_segments().into_iter().flatten() {
+        can_res.push('/');
+        can_res.push_str(p);
+    }
+    can_res += "\n";
+
+    // query parameters
+    let query_pairs = uri.query_pairs();
+    {
+        let mut qps: Vec<String> = Vec::new();
+        for (q, _) in query_pairs {
+            if !(qps.iter().any(|x| x == &*q)) {
+                qps.push(q.into_owned());
+            }
+        }
+
+        qps.sort();
+
+        for qparam in qps {
+            // find correct parameter
+            let ret = lexy_sort(query_pairs, &qparam);
+
+            can_res = can_res + &qparam.to_lowercase() + ":";
+
+            for (i, item) in ret.iter().enumerate() {
+                if i > 0 {
+                    can_res += ","
+                }
+                can_res += item;
+            }
+
+            can_res += "\n";
+        }
+    };
+
+    can_res[0..can_res.len() - 1].to_owned()
+}
+
+fn lexy_sort<'a>(
+    vec: impl Iterator<Item = (Cow<'a, str>, Cow<'a, str>)> + 'a,
+    query_param: &str,
+) -> Vec<Cow<'a, str>> {
+    let mut values = vec
+        .filter(|(k, _)| *k == query_param)
+        .map(|(_, v)| v)
+        .collect::<Vec<_>>();
+    values.sort_unstable();
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonicalized_resource_sorts_query_parameters() {
+        let url = Url::parse(
+            "https://myaccount.blob.core.windows.net/mycontainer?restype=container&comp=list&include=metadata&include=snapshots",
+        )
+        .unwrap();
+        assert_eq!(
+            canonicalized_resource("myaccount", &url),
+            "/myaccount/mycontainer\ncomp:list\ninclude:metadata,snapshots\nrestype:container"
+        );
+    }
+
+    #[test]
+    fn canonicalized_resource_table_uses_path_only() {
+        let url = Url::parse("https://myaccount.table.core.windows.net/mytable()?$top=1").unwrap();
+        assert_eq!(
+            canonicalized_resource_table("myaccount", &url),
+            "/myaccount/mytable()"
+        );
+    }
+
+    #[test]
+    fn string_to_sign_lite_signs_date_and_resource() {
+        let mut headers = Headers::new();
+        headers.insert(MS_DATE, "Fri, 26 Jun 2015 23:39:12 GMT");
+        headers.insert(CONTENT_TYPE, "text/plain");
+        let url = Url::parse(
+            "https://myaccount.blob.core.windows.net/mycontainer/myblob?comp=metadata&timeout=30",
+        )
+        .unwrap();
+        assert_eq!(
+            string_to_sign_lite(&headers, &url, &Method::Put, "myaccount", &ServiceType::Blob),
+            "PUT\n\ntext/plain\n\nx-ms-date:Fri, 26 Jun 2015 23:39:12 GMT\n/myaccount/mycontainer/myblob?comp=metadata"
+        );
+
+        let url = Url::parse("https://myaccount.table.core.windows.net/mytable()").unwrap();
+        assert_eq!(
+            string_to_sign_lite(
+                &headers,
+                &url,
+                &Method::Get,
+                "myaccount",
+                &ServiceType::Table
+            ),
+            "Fri, 26 Jun 2015 23:39:12 GMT\n/myaccount/mytable()"
+        );
+    }
+
+    #[test]
+    fn canonicalize_header_only_includes_ms_headers() {
+        let mut headers = Headers::new();
+        headers.insert(VERSION, "2019-12-12");
+        headers.insert(MS_DATE, "Fri, 26 Jun 2015 23:39:12 GMT");
+        headers.insert(CONTENT_TYPE, "text/plain");
+        assert_eq!(
+            canonicalize_header(&headers),
+            "x-ms-date:Fri, 26 Jun 2015 23:39:12 GMT\nx-ms-version:2019-12-12\n"
+        );
+    }
+}
@@ -13,14 +13,19 @@ extern crate azure_core;
 
 mod authorization_policy;
 
+pub mod audit;
+pub mod checksum;
+pub mod circuit_breaker;
 pub mod clients;
 mod cloud_location;
 mod connection_string;
 mod connection_string_builder;
 mod copy_id;
 mod copy_progress;
+mod copy_status;
 pub mod hmac;
 mod macros;
+pub mod metrics;
 pub mod prelude;
 pub mod shared_access_signature;
 
@@ -30,6 +35,7 @@ pub use cloud_location::*;
 pub mod headers;
 pub use copy_id::{copy_id_from_headers, CopyId};
 pub use copy_progress::CopyProgress;
+pub use copy_status::{copy_status_from_headers, CopyStatus};
 pub mod parsing_xml;
 pub mod storage_shared_key_credential;
 mod stored_access_policy;
@@ -71,6 +77,9 @@ mod consistency {
                 })),
             }
         }
+        pub(crate) fn from_bytes(bytes: [u8; CRC64_BYTE_LENGTH]) -> Self {
+            Self(Bytes::copy_from_slice(&bytes))
+        }
         pub fn bytes(&self) -> &Bytes {
             &self.0
         }
@@ -124,6 +133,9 @@ mod consistency {
                 })),
             }
         }
+        pub(crate) fn from_bytes(bytes: [u8; MD5_BYTE_LENGTH]) -> Self {
+            Self(Bytes::copy_from_slice(&bytes))
+        }
         pub fn bytes(&self) -> &Bytes {
             &self.0
         }
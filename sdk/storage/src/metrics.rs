@@ -0,0 +1,246 @@
+//! Entities of the [Storage Analytics metrics](https://learn.microsoft.com/azure/storage/common/storage-analytics-metrics)
+//! tables.
+//!
+//! When metrics are enabled, the storage services write hourly and minute aggregates to tables
+//! named `$Metrics...` of the account, which can be queried with the Table service (for example
+//! with `azure_data_tables`) and deserialized into [`TransactionMetrics`] and
+//! [`CapacityMetrics`].
+use azure_core::error::{Error, ErrorKind, ResultExt};
+use serde::{Deserialize, Deserializer};
+use std::fmt;
+use time::{macros::format_description, OffsetDateTime, PrimitiveDateTime};
+
+/// The table holding the daily capacity of the Blob service.
+pub const CAPACITY_BLOB_TABLE: &str = "$MetricsCapacityBlob";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricsService {
+    Blob,
+    Queue,
+    Table,
+    File,
+}
+
+impl fmt::Display for MetricsService {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MetricsService::Blob => "Blob",
+            MetricsService::Queue => "Queue",
+            MetricsService::Table => "Table",
+            MetricsService::File => "File",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricsInterval {
+    Hour,
+    Minute,
+}
+
+/// The name of the table holding the transaction metrics of `service` in the primary location,
+/// e.g. `$MetricsHourPrimaryTransactionsBlob`.
+pub fn transactions_table(service: MetricsService, interval: MetricsInterval) -> String {
+    transactions_table_for_location(service, interval, false)
+}
+
+/// Like [`transactions_table`], for the secondary location of geo-redundant accounts.
+pub fn secondary_transactions_table(service: MetricsService, interval: MetricsInterval) -> String {
+    transactions_table_for_location(service, interval, true)
+}
+
+fn transactions_table_for_location(
+    service: MetricsService,
+    interval: MetricsInterval,
+    secondary: bool,
+) -> String {
+    let interval = match interval {
+        MetricsInterval::Hour => "Hour",
+        MetricsInterval::Minute => "Minute",
+    };
+    let location = if secondary { "Secondary" } else { "Primary" };
+    format!("$Metrics{interval}{location}Transactions{service}")
+}
+
+/// Parses the partition key of a metrics entity, the start of the aggregation period in UTC,
+/// e.g. `20230101T0100`.
+pub fn parse_partition_key(partition_key: &str) -> azure_core::Result<OffsetDateTime> {
+    let format = format_description!("[year][month][day]T[hour][minute]");
+    PrimitiveDateTime::parse(partition_key, format)
+        .with_context(ErrorKind::DataConversion, || {
+            format!("invalid metrics partition key: {partition_key}")
+        })
+        .map(PrimitiveDateTime::assume_utc)
+}
+
+/// An entity of the `$MetricsCapacityBlob` table, written once a day.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CapacityMetrics {
+    pub partition_key: String,
+    /// `data` for the blobs of the account, `analytics` for the blobs holding the logs.
+    pub row_key: String,
+    /// The storage used, in bytes.
+    #[serde(deserialize_with = "deserialize_int64")]
+    pub capacity: u64,
+    #[serde(deserialize_with = "deserialize_int64")]
+    pub container_count: u64,
+    #[serde(deserialize_with = "deserialize_int64")]
+    pub object_count: u64,
+}
+
+impl CapacityMetrics {
+    /// The day the capacity was measured.
+    pub fn time(&self) -> azure_core::Result<OffsetDateTime> {
+        parse_partition_key(&self.partition_key)
+    }
+
+    /// Returns `true` for the capacity used by the analytics logs.
+    pub fn is_analytics(&self) -> bool {
+        self.row_key == "analytics"
+    }
+}
+
+/// An entity of the `$Metrics...Transactions...` tables.
+///
+/// Each entity aggregates the requests of a period, either for all the APIs (`user;All`) or for
+/// a single one (e.g. `user;GetBlob`), made by users or by the analytics system itself.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TransactionMetrics {
+    pub partition_key: String,
+    pub row_key: String,
+    #[serde(deserialize_with = "deserialize_int64")]
+    pub total_ingress: u64,
+    #[serde(deserialize_with = "deserialize_int64")]
+    pub total_egress: u64,
+    #[serde(deserialize_with = "deserialize_int64")]
+    pub total_requests: u64,
+    #[serde(deserialize_with = "deserialize_int64")]
+    pub total_billable_requests: u64,
+    /// The availability of the service, in percent.
+    pub availability: f64,
+    /// The average end-to-end latency of the successful requests, in milliseconds.
+    #[serde(rename = "AverageE2ELatency")]
+    pub average_e2e_latency: f64,
+    /// The average server latency of the successful requests, in milliseconds.
+    pub average_server_latency: f64,
+    pub percent_success: f64,
+    #[serde(default, deserialize_with = "deserialize_int64")]
+    pub success: u64,
+    #[serde(default, deserialize_with = "deserialize_int64")]
+    pub throttling_error: u64,
+    #[serde(default, deserialize_with = "deserialize_int64")]
+    pub server_timeout_error: u64,
+    #[serde(default, deserialize_with = "deserialize_int64")]
+    pub client_timeout_error: u64,
+    #[serde(default, deserialize_with = "deserialize_int64")]
+    pub authorization_error: u64,
+    #[serde(default, deserialize_with = "deserialize_int64")]
+    pub network_error: u64,
+}
+
+impl TransactionMetrics {
+    /// The start of the period of the metrics.
+    pub fn time(&self) -> azure_core::Result<OffsetDateTime> {
+        parse_partition_key(&self.partition_key)
+    }
+
+    /// Who made the requests: `user` or `system`.
+    pub fn access_type(&self) -> azure_core::Result<&str> {
+        self.row_key_parts().map(|(access_type, _)| access_type)
+    }
+
+    /// The API of the requests, or `All` for the aggregate of all the APIs.
+    pub fn api(&self) -> azure_core::Result<&str> {
+        self.row_key_parts().map(|(_, api)| api)
+    }
+
+    fn row_key_parts(&self) -> azure_core::Result<(&str, &str)> {
+        self.row_key.split_once(';').ok_or_else(|| {
+            Error::with_message(ErrorKind::DataConversion, || {
+                format!("invalid metrics row key: {}", self.row_key)
+            })
+        })
+    }
+}
+
+/// The Table service serializes `Edm.Int64` properties as strings.
+fn deserialize_int64<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Int64 {
+        Number(u64),
+        String(String),
+    }
+
+    match Int64::deserialize(deserializer)? {
+        Int64::Number(n) => Ok(n),
+        Int64::String(s) => s.parse().map_err(serde::de::Error::custom),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_transaction_metrics() {
+        let entity = r#"{
+            "odata.etag": "W/\"datetime'2023-01-01T02%3A00%3A00Z'\"",
+            "PartitionKey": "20230101T0100",
+            "RowKey": "user;GetBlob",
+            "Timestamp": "2023-01-01T02:00:00Z",
+            "TotalIngress@odata.type": "Edm.Int64",
+            "TotalIngress": "1024",
+            "TotalEgress": 2048,
+            "TotalRequests": 10,
+            "TotalBillableRequests": 9,
+            "Availability": 100.0,
+            "AverageE2ELatency": 12.5,
+            "AverageServerLatency": 4.0,
+            "PercentSuccess": 90.0,
+            "Success": 9,
+            "ThrottlingError": 1
+        }"#;
+        let metrics: TransactionMetrics = serde_json::from_str(entity).unwrap();
+        assert_eq!(metrics.access_type().unwrap(), "user");
+        assert_eq!(metrics.api().unwrap(), "GetBlob");
+        assert_eq!(metrics.total_ingress, 1024);
+        assert_eq!(metrics.throttling_error, 1);
+        assert_eq!(metrics.network_error, 0);
+        assert_eq!(
+            metrics.time().unwrap(),
+            time::macros::datetime!(2023-01-01 01:00 UTC)
+        );
+
+        assert_eq!(
+            transactions_table(MetricsService::Blob, MetricsInterval::Hour),
+            "$MetricsHourPrimaryTransactionsBlob"
+        );
+        assert_eq!(
+            secondary_transactions_table(MetricsService::Queue, MetricsInterval::Minute),
+            "$MetricsMinuteSecondaryTransactionsQueue"
+        );
+    }
+
+    #[test]
+    fn parses_capacity_metrics() {
+        let entity = r#"{
+            "PartitionKey": "20230101T0000",
+            "RowKey": "data",
+            "Capacity@odata.type": "Edm.Int64",
+            "Capacity": "23789775",
+            "ContainerCount": 3,
+            "ObjectCount": 110000
+        }"#;
+        let metrics: CapacityMetrics = serde_json::from_str(entity).unwrap();
+        assert!(!metrics.is_analytics());
+        assert_eq!(metrics.capacity, 23789775);
+        assert!(metrics.time().is_ok());
+        assert!(parse_partition_key("2023-01-01").is_err());
+    }
+}
@@ -3,8 +3,10 @@ pub use crate::{
     consistency::{ConsistencyCRC64, ConsistencyMD5},
     shared_access_signature::{
         account_sas::{AccountSasPermissions, AccountSasResource, AccountSasResourceType},
-        service_sas::{BlobSasPermissions, BlobSignedResource},
-        SasProtocol, SasToken,
+        service_sas::{
+            BlobSasPermissions, BlobSignedResource, QueueSasPermissions, UserDelegationKey,
+        },
+        SasCredentialsError, SasProtocol, SasToken,
     },
-    CopyId, IPRange,
+    CloudLocation, CopyId, IPRange,
 };
@@ -1,3 +1,5 @@
+use crate::clients::StorageCredentials;
+use azure_core::error::{Error, ErrorKind};
 use std::fmt;
 use time::OffsetDateTime;
 use url::form_urlencoded;
@@ -44,6 +46,56 @@ impl fmt::Display for SasProtocol {
     }
 }
 
+/// The credentials of a client cannot sign a shared access signature.
+///
+/// Returned, as the source of an [`ErrorKind::Credential`] error, by the methods creating a shared
+/// access signature signed with the account key, when the client has no key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SasCredentialsError {
+    /// The client is authorized with Azure Active Directory: only user delegation shared access
+    /// signatures, of the blob service, can be created, signed with a key requested from the
+    /// service.
+    TokenCredential,
+    /// The client is authorized with a shared access signature, or is anonymous.
+    NoAccountKey,
+}
+
+impl From<&StorageCredentials> for SasCredentialsError {
+    fn from(credentials: &StorageCredentials) -> Self {
+        match credentials {
+            StorageCredentials::TokenCredential(_) | StorageCredentials::BearerToken(_) => {
+                Self::TokenCredential
+            }
+            _ => Self::NoAccountKey,
+        }
+    }
+}
+
+impl From<SasCredentialsError> for Error {
+    fn from(error: SasCredentialsError) -> Self {
+        Error::new(ErrorKind::Credential, error)
+    }
+}
+
+impl std::error::Error for SasCredentialsError {}
+
+impl fmt::Display for SasCredentialsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SasCredentialsError::TokenCredential => f.write_str(
+                "a shared access signature cannot be signed with Azure Active Directory \
+                credentials: use a user delegation shared access signature of the blob service, \
+                e.g. with `BlobClient::user_delegation_shared_access_signature`, or create the \
+                client with the account key",
+            ),
+            SasCredentialsError::NoAccountKey => f.write_str(
+                "a shared access signature can only be signed by a client created with the \
+                account name and key",
+            ),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -55,4 +107,16 @@ mod tests {
         let date = datetime!(2022-08-22 15:11:43.4185122 +00:00:00);
         assert_eq!(format_date(date), "2022-08-22T15:11:43Z");
     }
+
+    #[test]
+    fn token_credentials_are_told_to_use_user_delegation() {
+        let error: Error =
+            SasCredentialsError::from(&StorageCredentials::bearer_token("token")).into();
+        assert_eq!(error.kind(), &ErrorKind::Credential);
+        assert_eq!(
+            error.downcast_ref::<SasCredentialsError>(),
+            Some(&SasCredentialsError::TokenCredential)
+        );
+        assert!(error.to_string().contains("user delegation"));
+    }
 }
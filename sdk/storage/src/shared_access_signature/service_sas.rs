@@ -90,8 +90,40 @@ impl fmt::Display for BlobSasPermissions {
     }
 }
 
+/// A key obtained with the Get User Delegation Key operation, to sign shared access signatures
+/// with Azure Active Directory credentials instead of the account key.
+#[derive(Clone, PartialEq, Eq)]
+pub struct UserDelegationKey {
+    pub signed_oid: String,
+    pub signed_tid: String,
+    pub signed_start: OffsetDateTime,
+    pub signed_expiry: OffsetDateTime,
+    pub signed_service: String,
+    pub signed_version: String,
+    /// The base64 encoded key.
+    pub value: String,
+}
+
+impl fmt::Debug for UserDelegationKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("UserDelegationKey")
+            .field("signed_oid", &self.signed_oid)
+            .field("signed_tid", &self.signed_tid)
+            .field("signed_start", &self.signed_start)
+            .field("signed_expiry", &self.signed_expiry)
+            .field("signed_service", &self.signed_service)
+            .field("signed_version", &self.signed_version)
+            .finish_non_exhaustive()
+    }
+}
+
+enum SigningKey {
+    Account(String),
+    UserDelegation(UserDelegationKey),
+}
+
 pub struct BlobSharedAccessSignature {
-    key: String,
+    key: SigningKey,
     canonicalized_resource: String,
     resource: BlobSignedResource,
     permissions: BlobSasPermissions, // sp
@@ -100,6 +132,7 @@ pub struct BlobSharedAccessSignature {
     identifier: Option<String>,
     ip: Option<String>,
     protocol: Option<SasProtocol>,
+    directory_depth: Option<usize>, // sdd
 }
 
 impl BlobSharedAccessSignature {
@@ -109,6 +142,39 @@ impl BlobSharedAccessSignature {
         permissions: BlobSasPermissions,
         expiry: OffsetDateTime,
         resource: BlobSignedResource,
+    ) -> Self {
+        Self::with_key(
+            SigningKey::Account(key),
+            canonicalized_resource,
+            permissions,
+            expiry,
+            resource,
+        )
+    }
+
+    /// A user delegation SAS, signed with a key obtained with Azure Active Directory credentials.
+    pub fn with_user_delegation_key(
+        key: UserDelegationKey,
+        canonicalized_resource: String,
+        permissions: BlobSasPermissions,
+        expiry: OffsetDateTime,
+        resource: BlobSignedResource,
+    ) -> Self {
+        Self::with_key(
+            SigningKey::UserDelegation(key),
+            canonicalized_resource,
+            permissions,
+            expiry,
+            resource,
+        )
+    }
+
+    fn with_key(
+        key: SigningKey,
+        canonicalized_resource: String,
+        permissions: BlobSasPermissions,
+        expiry: OffsetDateTime,
+        resource: BlobSignedResource,
     ) -> Self {
         Self {
             key,
@@ -120,6 +186,7 @@ impl BlobSharedAccessSignature {
             identifier: None,
             ip: None,
             protocol: None,
+            directory_depth: None,
         }
     }
 
@@ -128,9 +195,16 @@ impl BlobSharedAccessSignature {
         identifier: String => Some(identifier),
         ip: String => Some(ip),
         protocol: SasProtocol => Some(protocol),
+        // The number of directories below the root of the container in the path of a
+        // [`BlobSignedResource::Directory`] resource, required by hierarchical namespace accounts.
+        directory_depth: usize => Some(directory_depth),
     }
 
     fn sign(&self) -> String {
+        let key = match &self.key {
+            SigningKey::Account(key) => key,
+            SigningKey::UserDelegation(key) => return self.sign_user_delegation(key),
+        };
         let content = vec![
             self.permissions.to_string(),
             self.start.map_or("".to_string(), format_date),
@@ -154,7 +228,39 @@ impl BlobSharedAccessSignature {
             "".to_string(), // rsct
         ];
 
-        hmac::sign(&content.join("\n"), &self.key).expect("HMAC signing failed")
+        hmac::sign(&content.join("\n"), key).expect("HMAC signing failed")
+    }
+
+    fn sign_user_delegation(&self, key: &UserDelegationKey) -> String {
+        let content = vec![
+            self.permissions.to_string(),
+            self.start.map_or("".to_string(), format_date),
+            format_date(self.expiry),
+            self.canonicalized_resource.clone(),
+            key.signed_oid.clone(),
+            key.signed_tid.clone(),
+            format_date(key.signed_start),
+            format_date(key.signed_expiry),
+            key.signed_service.clone(),
+            key.signed_version.clone(),
+            "".to_string(), // saoid
+            "".to_string(), // suoid
+            "".to_string(), // scid
+            self.ip.clone().unwrap_or_default(),
+            self.protocol
+                .map(|x| x.to_string())
+                .unwrap_or_else(|| "".to_string()),
+            SERVICE_SAS_VERSION.to_string(),
+            self.resource.to_string(),
+            "".to_string(), // snapshot time
+            "".to_string(), // rscc
+            "".to_string(), // rscd
+            "".to_string(), // rsce
+            "".to_string(), // rscl
+            "".to_string(), // rsct
+        ];
+
+        hmac::sign(&content.join("\n"), &key.value).expect("HMAC signing failed")
     }
 }
 
@@ -167,10 +273,142 @@ impl SasToken for BlobSharedAccessSignature {
             format!("se={}", format_form(format_date(self.expiry))),
         ];
 
+        if let Some(directory_depth) = &self.directory_depth {
+            elements.push(format!("sdd={}", directory_depth))
+        }
+
+        if let SigningKey::UserDelegation(key) = &self.key {
+            elements.push(format!("skoid={}", key.signed_oid));
+            elements.push(format!("sktid={}", key.signed_tid));
+            elements.push(format!(
+                "skt={}",
+                format_form(format_date(key.signed_start))
+            ));
+            elements.push(format!(
+                "ske={}",
+                format_form(format_date(key.signed_expiry))
+            ));
+            elements.push(format!("sks={}", key.signed_service));
+            elements.push(format!("skv={}", key.signed_version));
+        }
+
+        if let Some(start) = &self.start {
+            elements.push(format!("st={}", format_form(format_date(*start))))
+        }
+
+        if let Some(ip) = &self.ip {
+            elements.push(format!("sip={}", ip))
+        }
+
+        if let Some(protocol) = &self.protocol {
+            elements.push(format!("spr={}", protocol))
+        }
+
+        let sig = self.sign();
+        elements.push(format!("sig={}", format_form(sig)));
+
+        elements.join("&")
+    }
+}
+
+/// Permissions granted by a queue service SAS.
+#[derive(Default)]
+pub struct QueueSasPermissions {
+    pub read: bool,    // r - peek messages and get queue metadata
+    pub add: bool,     // a - put messages
+    pub update: bool,  // u - update messages
+    pub process: bool, // p - get and delete messages
+}
+
+impl fmt::Display for QueueSasPermissions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.read {
+            write!(f, "r")?
+        };
+        if self.add {
+            write!(f, "a")?
+        };
+        if self.update {
+            write!(f, "u")?
+        };
+        if self.process {
+            write!(f, "p")?
+        };
+        Ok(())
+    }
+}
+
+pub struct QueueSharedAccessSignature {
+    key: String,
+    canonicalized_resource: String,
+    permissions: QueueSasPermissions, // sp
+    expiry: OffsetDateTime,           // se
+    start: Option<OffsetDateTime>,    // st
+    identifier: Option<String>,
+    ip: Option<String>,
+    protocol: Option<SasProtocol>,
+}
+
+impl QueueSharedAccessSignature {
+    pub fn new(
+        key: String,
+        canonicalized_resource: String,
+        permissions: QueueSasPermissions,
+        expiry: OffsetDateTime,
+    ) -> Self {
+        Self {
+            key,
+            canonicalized_resource,
+            permissions,
+            expiry,
+            start: None,
+            identifier: None,
+            ip: None,
+            protocol: None,
+        }
+    }
+
+    setters! {
+        start: OffsetDateTime => Some(start),
+        identifier: String => Some(identifier),
+        ip: String => Some(ip),
+        protocol: SasProtocol => Some(protocol),
+    }
+
+    fn sign(&self) -> String {
+        let content = vec![
+            self.permissions.to_string(),
+            self.start.map_or("".to_string(), format_date),
+            format_date(self.expiry),
+            self.canonicalized_resource.clone(),
+            self.identifier.clone().unwrap_or_default(),
+            self.ip.clone().unwrap_or_default(),
+            self.protocol
+                .map(|x| x.to_string())
+                .unwrap_or_else(|| "".to_string()),
+            SERVICE_SAS_VERSION.to_string(),
+        ];
+
+        hmac::sign(&content.join("\n"), &self.key).expect("HMAC signing failed")
+    }
+}
+
+impl SasToken for QueueSharedAccessSignature {
+    fn token(&self) -> String {
+        let mut elements: Vec<String> = vec![
+            format!("sv={}", SERVICE_SAS_VERSION),
+            format!("sp={}", self.permissions),
+            format!("se={}", format_form(format_date(self.expiry))),
+        ];
+
         if let Some(start) = &self.start {
             elements.push(format!("st={}", format_form(format_date(*start))))
         }
 
+        if let Some(identifier) = &self.identifier {
+            elements.push(format!("si={}", format_form(identifier.clone())))
+        }
+
         if let Some(ip) = &self.ip {
             elements.push(format!("sip={}", ip))
         }
@@ -185,3 +423,106 @@ impl SasToken for BlobSharedAccessSignature {
         elements.join("&")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use time::macros::datetime;
+
+    #[test]
+    fn queue_sas_token() {
+        let permissions = QueueSasPermissions {
+            add: true,
+            ..Default::default()
+        };
+        let sas = QueueSharedAccessSignature::new(
+            base64::encode("key"),
+            "/queue/account/queue".to_owned(),
+            permissions,
+            datetime!(2022-08-22 15:11:43 +00:00:00),
+        );
+        let token = sas.token();
+        assert!(token.starts_with("sv=2020-06-12&sp=a&se=2022-08-22T15%3A11%3A43Z&sig="));
+        assert!(!token.contains("sr="));
+    }
+
+    #[test]
+    fn directory_sas_token() {
+        let permissions = BlobSasPermissions {
+            read: true,
+            list: true,
+            ..Default::default()
+        };
+        let sas = BlobSharedAccessSignature::new(
+            base64::encode("key"),
+            "/blob/account/filesystem/dir/subdir".to_owned(),
+            permissions,
+            datetime!(2022-08-22 15:11:43 +00:00:00),
+            BlobSignedResource::Directory,
+        )
+        .directory_depth(2);
+        let token = sas.token();
+        assert!(
+            token.starts_with("sv=2020-06-12&sp=rl&sr=d&se=2022-08-22T15%3A11%3A43Z&sdd=2&sig=")
+        );
+    }
+
+    #[test]
+    fn user_delegation_sas_token() {
+        let key = UserDelegationKey {
+            signed_oid: "oid".to_owned(),
+            signed_tid: "tid".to_owned(),
+            signed_start: datetime!(2022-08-22 14:00:00 +00:00:00),
+            signed_expiry: datetime!(2022-08-22 16:00:00 +00:00:00),
+            signed_service: "b".to_owned(),
+            signed_version: "2020-06-12".to_owned(),
+            value: base64::encode("key"),
+        };
+        let permissions = BlobSasPermissions {
+            read: true,
+            ..Default::default()
+        };
+        let sas = BlobSharedAccessSignature::with_user_delegation_key(
+            key.clone(),
+            "/blob/account/container/blob".to_owned(),
+            permissions,
+            datetime!(2022-08-22 15:11:43 +00:00:00),
+            BlobSignedResource::Blob,
+        );
+
+        let string_to_sign = [
+            "r",
+            "",
+            "2022-08-22T15:11:43Z",
+            "/blob/account/container/blob",
+            "oid",
+            "tid",
+            "2022-08-22T14:00:00Z",
+            "2022-08-22T16:00:00Z",
+            "b",
+            "2020-06-12",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "2020-06-12",
+            "b",
+            "",
+            "",
+            "",
+            "",
+            "",
+            "",
+        ]
+        .join("\n");
+        let signature = hmac::sign(&string_to_sign, &key.value).unwrap();
+        assert_eq!(sas.sign(), signature);
+
+        let token = sas.token();
+        assert!(token.starts_with(
+            "sv=2020-06-12&sp=r&sr=b&se=2022-08-22T15%3A11%3A43Z&skoid=oid&sktid=tid&skt=2022-08-22T14%3A00%3A00Z&ske=2022-08-22T16%3A00%3A00Z&sks=b&skv=2020-06-12&sig="
+        ));
+        assert!(token.ends_with(&format!("sig={}", format_form(signature))));
+    }
+}
@@ -1,4 +1,3 @@
-use azure_core::{error::ErrorKind, StatusCode};
 use azure_storage::prelude::*;
 use azure_storage_blobs::prelude::*;
 use uuid::Uuid;
@@ -23,17 +22,13 @@ async fn main() -> azure_core::Result<()> {
     let blob_client = container_client.blob_client(&blob_name);
 
     println!("getting properties for {}/{}", container_name, blob_name);
-    let result = blob_client.get_properties().into_future().await;
-    let error = result.expect_err("get_properties on missing blob should fail");
-    if let ErrorKind::HttpResponse {
-        status: StatusCode::NotFound,
-        ..
-    } = error.kind()
-    {
-        println!("{}/{} does not exist", container_name, blob_name);
-    } else {
-        panic!("unexpected error: {}", error);
-    }
+    let properties = blob_client.get_properties_if_exists().await?;
+    assert!(
+        properties.is_none(),
+        "get_properties_if_exists on missing blob should return None"
+    );
+    assert!(!blob_client.exists().await?);
+    println!("{}/{} does not exist", container_name, blob_name);
 
     // cleanup
     container_client.delete().into_future().await?;
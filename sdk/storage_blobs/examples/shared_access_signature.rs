@@ -3,12 +3,10 @@ use azure_storage::prelude::*;
 use azure_storage_blobs::prelude::*;
 use time::OffsetDateTime;
 
-fn main() {
+#[tokio::main]
+async fn main() -> azure_core::Result<()> {
     env_logger::init();
-    code().unwrap();
-}
 
-fn code() -> azure_core::Result<()> {
     // First we retrieve the account name and access key from environment variables.
     let account =
         std::env::var("STORAGE_ACCOUNT").expect("Set env variable STORAGE_ACCOUNT first!");
@@ -54,7 +52,8 @@ fn code() -> azure_core::Result<()> {
                 ..Default::default()
             },
             later,
-        )?
+        )
+        .await?
         .start(now);
     println!("blob service token: {}", sas.token());
     let url = blob_client.generate_signed_blob_url(&sas)?;
@@ -69,7 +68,8 @@ fn code() -> azure_core::Result<()> {
                 ..Default::default()
             },
             later,
-        )?
+        )
+        .await?
         .start(now)
         .protocol(SasProtocol::HttpHttps);
 
@@ -0,0 +1,284 @@
+use crate::{blob::operations::CopyBlobResponse, prelude::*};
+use azure_core::error::{Error, ErrorKind};
+use azure_core::{Context, Deadline};
+use azure_storage::{CopyId, CopyProgress, CopyStatus};
+use std::time::Duration;
+
+/// Tracks an asynchronous copy to a blob, started with
+/// [`BlobClient::start_copy_from_url`], until it completes.
+///
+/// The copy is tracked with the `x-ms-copy-status` of the destination blob. It fails if another
+/// copy to the blob is started meanwhile.
+///
+/// The requests of the poller are sent with its [`Context`], and with the lease of the
+/// destination blob when it is leased.
+#[derive(Debug, Clone)]
+pub struct CopyBlobPoller {
+    client: BlobClient,
+    context: Context,
+    lease_id: Option<LeaseId>,
+    copy_id: CopyId,
+    status: CopyStatus,
+    progress: Option<CopyProgress>,
+    status_description: Option<String>,
+}
+
+impl CopyBlobPoller {
+    /// Tracks the copy started by a Copy Blob request on `client`, e.g. one sent with
+    /// [`BlobClient::copy`] for its options.
+    pub fn new(client: BlobClient, response: &CopyBlobResponse) -> Self {
+        Self {
+            client,
+            context: Context::new(),
+            lease_id: None,
+            copy_id: response.copy_id.clone(),
+            status: response.copy_status,
+            progress: None,
+            status_description: None,
+        }
+    }
+
+    /// Send the requests with this context, e.g. one with a [`Deadline`] bounding [`wait`](Self::wait).
+    #[must_use]
+    pub fn context(mut self, context: Context) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// The lease of the destination blob, required to abort the copy to a leased blob.
+    #[must_use]
+    pub fn lease_id(mut self, lease_id: LeaseId) -> Self {
+        self.lease_id = Some(lease_id);
+        self
+    }
+
+    pub fn copy_id(&self) -> &CopyId {
+        &self.copy_id
+    }
+
+    /// The status of the copy when last polled.
+    pub fn status(&self) -> CopyStatus {
+        self.status
+    }
+
+    /// The bytes copied when last polled.
+    pub fn progress(&self) -> Option<&CopyProgress> {
+        self.progress.as_ref()
+    }
+
+    /// Whether the copy is over, successful or not.
+    pub fn is_done(&self) -> bool {
+        self.status != CopyStatus::Pending
+    }
+
+    /// Reads the status of the copy from the properties of the blob.
+    pub async fn poll(&mut self) -> azure_core::Result<CopyStatus> {
+        let mut get_properties = self.client.get_properties().context(self.context.clone());
+        if let Some(lease_id) = self.lease_id {
+            get_properties = get_properties.lease_id(lease_id);
+        }
+        let properties = get_properties.into_future().await?.blob.properties;
+        if properties.copy_id.as_ref() != Some(&self.copy_id) {
+            return Err(Error::with_message(ErrorKind::Other, || {
+                format!(
+                    "the copy {} was replaced by another copy to the blob",
+                    self.copy_id
+                )
+            }));
+        }
+        if let Some(status) = properties.copy_status {
+            self.status = status;
+        }
+        self.progress = properties.copy_progress;
+        self.status_description = properties.copy_status_description;
+        Ok(self.status)
+    }
+
+    /// Polls the copy every `interval` until it is over, and fails unless it succeeded.
+    ///
+    /// Waiting fails with an [`ErrorKind::Timeout`] error after `max_wait`, or once the
+    /// [`Deadline`] of the context of the poller expires; the copy goes on meanwhile.
+    pub async fn wait(&mut self, interval: Duration, max_wait: Duration) -> azure_core::Result<()> {
+        let mut deadline = Deadline::after(max_wait);
+        if let Some(context_deadline) = self.context.get::<Deadline>() {
+            deadline = std::cmp::min(deadline, *context_deadline);
+        }
+        while !self.is_done() {
+            if deadline.is_expired() {
+                return Err(Error::with_message(ErrorKind::Timeout, || {
+                    format!(
+                        "the copy {} is still pending at {}",
+                        self.copy_id,
+                        deadline.at()
+                    )
+                }));
+            }
+            azure_core::sleep::sleep(std::cmp::min(interval, deadline.remaining())).await;
+            self.poll().await?;
+        }
+        match self.status {
+            CopyStatus::Success => Ok(()),
+            status => Err(Error::with_message(ErrorKind::Other, || {
+                format!(
+                    "the copy {} is {:?}: {}",
+                    self.copy_id,
+                    status,
+                    self.status_description
+                        .as_deref()
+                        .unwrap_or("no description")
+                )
+            })),
+        }
+    }
+
+    /// Aborts the copy, leaving the destination blob empty.
+    pub async fn abort(&mut self) -> azure_core::Result<()> {
+        let mut abort_copy = self
+            .client
+            .abort_copy(self.copy_id.clone())
+            .context(self.context.clone());
+        if let Some(lease_id) = self.lease_id {
+            abort_copy = abort_copy.lease_id(lease_id);
+        }
+        abort_copy.into_future().await?;
+        self.status = CopyStatus::Aborted;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{blob_properties_headers, mock_blob_client, response_headers};
+    use azure_core::{mock::MockTransportCannedPolicy, Method, StatusCode};
+    use std::sync::Arc;
+    use url::Url;
+
+    const COPY_ID: &str = "4d8ef5a2-2ac1-4b4a-8bfe-4b9a7ba4e3a9";
+
+    fn copy_started() -> MockTransportCannedPolicy {
+        MockTransportCannedPolicy::new().reply(
+            StatusCode::Accepted,
+            response_headers(&[
+                ("etag", "\"0x8DAAE8D3C2C1A5B\""),
+                ("last-modified", "Thu, 15 Oct 2026 09:30:00 GMT"),
+                ("x-ms-version", "2020-06-12"),
+                ("server", "Windows-Azure-Blob/1.0"),
+                ("x-ms-copy-id", COPY_ID),
+                ("x-ms-copy-status", "pending"),
+            ]),
+            "",
+        )
+    }
+
+    async fn start(transport: &Arc<MockTransportCannedPolicy>) -> CopyBlobPoller {
+        mock_blob_client(transport.clone())
+            .start_copy_from_url(Url::parse("https://source.blob.core.windows.net/c/b").unwrap())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn waits_for_the_copy_with_the_lease() {
+        let transport = Arc::new(
+            copy_started()
+                .reply(
+                    StatusCode::Ok,
+                    blob_properties_headers(&[
+                        ("x-ms-copy-id", COPY_ID),
+                        ("x-ms-copy-status", "pending"),
+                        ("x-ms-copy-progress", "512/1024"),
+                    ]),
+                    "",
+                )
+                .reply(
+                    StatusCode::Ok,
+                    blob_properties_headers(&[
+                        ("x-ms-copy-id", COPY_ID),
+                        ("x-ms-copy-status", "success"),
+                        ("x-ms-copy-progress", "1024/1024"),
+                    ]),
+                    "",
+                ),
+        );
+        let lease_id = LeaseId::new();
+        let mut poller = start(&transport).await.lease_id(lease_id);
+        assert_eq!(poller.status(), CopyStatus::Pending);
+
+        poller
+            .wait(Duration::from_millis(1), Duration::from_secs(60))
+            .await
+            .unwrap();
+        assert!(poller.is_done());
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 3);
+        for request in &requests[1..] {
+            assert_eq!(request.method(), &Method::Head);
+            assert_eq!(
+                request
+                    .headers()
+                    .get_str(&azure_core::headers::LEASE_ID)
+                    .unwrap(),
+                lease_id.to_string()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn stops_waiting_after_the_max_wait() {
+        let transport = Arc::new(copy_started());
+        let mut poller = start(&transport).await;
+        let error = poller
+            .wait(Duration::from_millis(1), Duration::ZERO)
+            .await
+            .unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::Timeout);
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn fails_when_another_copy_replaced_it() {
+        let transport = Arc::new(copy_started().reply(
+            StatusCode::Ok,
+            blob_properties_headers(&[
+                ("x-ms-copy-id", "0b3e5c5e-6b6a-4cf0-9d7c-5b7a1d0e0f11"),
+                ("x-ms-copy-status", "pending"),
+            ]),
+            "",
+        ));
+        let mut poller = start(&transport).await;
+        assert!(poller.poll().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn aborts_the_copy_with_the_lease() {
+        let transport =
+            Arc::new(copy_started().reply(StatusCode::NoContent, response_headers(&[]), ""));
+        let lease_id = LeaseId::new();
+        let mut poller = start(&transport).await.lease_id(lease_id);
+        poller.abort().await.unwrap();
+        assert_eq!(poller.status(), CopyStatus::Aborted);
+
+        let request = &transport.requests()[1];
+        assert_eq!(request.method(), &Method::Put);
+        assert_eq!(
+            request.url().query(),
+            Some(format!("comp=copy&copyid={COPY_ID}").as_str())
+        );
+        assert_eq!(
+            request
+                .headers()
+                .get_str(&azure_storage::headers::COPY_ACTION)
+                .unwrap(),
+            "abort"
+        );
+        assert_eq!(
+            request
+                .headers()
+                .get_str(&azure_core::headers::LEASE_ID)
+                .unwrap(),
+            lease_id.to_string()
+        );
+    }
+}
@@ -7,23 +7,29 @@ mod blob_block_with_size;
 mod block_list;
 mod block_list_type;
 mod block_with_size_list;
+mod copy_blob_poller;
 mod page_range_list;
+mod upload_checkpoint;
+mod validate;
 
 pub use blob_block_type::BlobBlockType;
 pub use blob_block_with_size::BlobBlockWithSize;
 pub use block_list::BlockList;
 pub use block_list_type::BlockListType;
 pub use block_with_size_list::BlockWithSizeList;
+pub use copy_blob_poller::CopyBlobPoller;
 pub use lease_blob_options::{LeaseBlobOptions, LEASE_BLOB_OPTIONS_DEFAULT};
 pub use page_range_list::PageRangeList;
+pub use upload_checkpoint::{FileUploadCheckpointStore, UploadCheckpoint, UploadCheckpointStore};
 
-use crate::options::{AccessTier, Snapshot, Tags, SNAPSHOT};
+use crate::options::{AccessTier, Snapshot, Tags, IS_CURRENT_VERSION, SNAPSHOT, VERSION_ID};
 use azure_core::{
     content_type, date,
     headers::{self, Headers},
     parsing::from_azure_time,
     Etag, LeaseDuration, LeaseState, LeaseStatus,
 };
+pub use azure_storage::CopyStatus;
 use azure_storage::{ConsistencyCRC64, ConsistencyMD5, CopyId, CopyProgress};
 use serde::{self, Deserialize, Deserializer};
 use std::collections::HashMap;
@@ -48,14 +54,6 @@ create_enum!(
     (AppendBlob, "AppendBlob")
 );
 
-create_enum!(
-    CopyStatus,
-    (Pending, "pending"),
-    (Success, "success"),
-    (Aborted, "aborted"),
-    (Failed, "failed")
-);
-
 create_enum!(RehydratePriority, (High, "High"), (Standard, "Standard"));
 
 create_enum!(PageWriteType, (Update, "update"), (Clear, "clear"));
@@ -234,13 +232,15 @@ impl Blob {
         let tags = h.get_optional_as(&headers::TAGS)?;
 
         let snapshot = h.get_optional_as(&SNAPSHOT)?;
+        let version_id = h.get_optional_string(&VERSION_ID);
+        let is_current_version = h.get_optional_as(&IS_CURRENT_VERSION)?;
 
         Ok(Blob {
             name: blob_name.into(),
             snapshot,
-            deleted: None,            //TODO
-            is_current_version: None, //TODO
-            version_id: None,         //TODO
+            deleted: None, //TODO
+            is_current_version,
+            version_id,
             properties: BlobProperties {
                 creation_time,
                 last_modified,
@@ -284,7 +284,3 @@ impl Blob {
         })
     }
 }
-
-pub(crate) fn copy_status_from_headers(headers: &Headers) -> azure_core::Result<CopyStatus> {
-    headers.get_as(&headers::COPY_STATUS)
-}
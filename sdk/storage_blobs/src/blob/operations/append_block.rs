@@ -1,5 +1,7 @@
-use crate::{blob::operations::put_block::PutBlockResponse, prelude::*};
-use azure_core::{headers::*, prelude::*, Body};
+use crate::prelude::*;
+use azure_core::{headers::*, prelude::*, Body, RequestId};
+use azure_storage::{headers::consistency_from_headers, ConsistencyCRC64, ConsistencyMD5};
+use time::OffsetDateTime;
 
 operation! {
     AppendBlock,
@@ -27,6 +29,7 @@ impl AppendBlockBuilder {
             headers.add(self.condition_append_position);
             headers.add(self.if_modified_since);
             headers.add(self.if_match);
+            headers.add(self.if_tag);
             headers.add(self.lease_id);
 
             let mut request = self.client.finalize_request(
@@ -38,9 +41,40 @@ impl AppendBlockBuilder {
 
             let response = self.client.send(&mut self.context, &mut request).await?;
 
-            PutBlockResponse::from_headers(response.headers())
+            AppendBlockResponse::from_headers(response.headers())
         })
     }
 }
 
-type AppendBlockResponse = PutBlockResponse;
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppendBlockResponse {
+    pub etag: String,
+    pub last_modified: OffsetDateTime,
+    pub content_md5: Option<ConsistencyMD5>,
+    pub content_crc64: Option<ConsistencyCRC64>,
+    pub request_id: RequestId,
+    pub date: OffsetDateTime,
+    pub request_server_encrypted: bool,
+    /// The offset in the blob at which the block was appended.
+    pub blob_append_offset: u64,
+    /// The number of blocks of the blob, the appended one included.
+    pub blob_committed_block_count: u64,
+}
+
+impl AppendBlockResponse {
+    pub(crate) fn from_headers(headers: &Headers) -> azure_core::Result<AppendBlockResponse> {
+        let (content_md5, content_crc64) = consistency_from_headers(headers)?;
+
+        Ok(AppendBlockResponse {
+            etag: etag_from_headers(headers)?,
+            last_modified: last_modified_from_headers(headers)?,
+            content_md5,
+            content_crc64,
+            request_id: request_id_from_headers(headers)?,
+            date: date_from_headers(headers)?,
+            request_server_encrypted: request_server_encrypted_from_headers(headers)?,
+            blob_append_offset: headers.get_as(&BLOB_APPEND_OFFSET)?,
+            blob_committed_block_count: headers.get_as(&BLOB_COMMITTED_BLOCK_COUNT)?,
+        })
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{
+    blob::operations::AppendBlockResponse, blob::SourceContentMD5, options::FROM_URL_VERSION,
+    prelude::*,
+};
+use azure_core::{headers::*, prelude::*};
+use url::Url;
+
+operation! {
+    AppendBlockFromUrl,
+    client: BlobClient,
+    source_url: Url,
+    ?range: Range,
+    ?source_content_md5: SourceContentMD5,
+    ?copy_source_authorization: CopySourceAuthorization,
+    ?condition_max_size: ConditionMaxSize,
+    ?condition_append_position: ConditionAppendPosition,
+    ?if_modified_since: IfModifiedSinceCondition,
+    ?if_match: IfMatchCondition,
+    ?if_tag: IfTags,
+    ?if_source_since: IfSourceModifiedSinceCondition,
+    ?if_source_match: IfSourceMatchCondition,
+    ?lease_id: LeaseId
+}
+
+impl AppendBlockFromUrlBuilder {
+    /// Appends the content of the blob at `source_url`, or of its `range`, as a new block. The
+    /// source is read by the service, so that the content does not transit through the client.
+    pub fn into_future(mut self) -> AppendBlockFromUrl {
+        Box::pin(async move {
+            let mut url = self.client.url()?;
+
+            url.query_pairs_mut().append_pair("comp", "appendblock");
+
+            let mut headers = Headers::new();
+            headers.add(SourceUri::new(self.source_url.clone()));
+            if let Some(range) = &self.range {
+                headers.insert(SOURCE_RANGE, range.to_string());
+            }
+            headers.add(self.source_content_md5);
+            let authorizes_copy_source = self.copy_source_authorization.is_some();
+            headers.add(self.copy_source_authorization);
+            headers.add(self.condition_max_size);
+            headers.add(self.condition_append_position);
+            headers.add(self.if_modified_since);
+            headers.add(self.if_match);
+            headers.add(self.if_tag);
+            headers.add(self.if_source_since);
+            headers.add(self.if_source_match);
+            headers.add(self.lease_id);
+
+            let mut request =
+                self.client
+                    .finalize_request(url, azure_core::Method::Put, headers, None)?;
+            if authorizes_copy_source {
+                request.insert_header(VERSION, FROM_URL_VERSION);
+            }
+
+            let response = self.client.send(&mut self.context, &mut request).await?;
+            AppendBlockResponse::from_headers(response.headers())
+        })
+    }
+}
+
+pub type AppendBlockFromUrlResponse = AppendBlockResponse;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{mock_blob_client, response_headers};
+    use azure_core::{mock::MockTransportCannedPolicy, Request, StatusCode};
+    use std::sync::Arc;
+
+    async fn sent_request(
+        build: impl FnOnce(AppendBlockFromUrlBuilder) -> AppendBlockFromUrlBuilder,
+    ) -> Request {
+        let transport = Arc::new(MockTransportCannedPolicy::new().reply(
+            StatusCode::Created,
+            response_headers(&[
+                ("etag", "\"0x8D9\""),
+                ("last-modified", "Thu, 15 Oct 2026 10:00:00 GMT"),
+                ("x-ms-blob-append-offset", "0"),
+                ("x-ms-blob-committed-block-count", "1"),
+                ("x-ms-request-server-encrypted", "true"),
+            ]),
+            "",
+        ));
+        let append_block = mock_blob_client(transport.clone())
+            .append_blob_client()
+            .append_block_from_url(Url::parse("https://source.blob.core.windows.net/c/b").unwrap());
+        build(append_block).into_future().await.unwrap();
+
+        transport.requests().remove(0)
+    }
+
+    #[tokio::test]
+    async fn sends_the_source_range_and_the_conditions() {
+        let request = sent_request(|append_block| {
+            append_block
+                .range(Range::new(0, 512))
+                .condition_max_size(4096u64)
+                .condition_append_position(1024u64)
+                .if_match(IfMatchCondition::Match("\"0x8D9\"".into()))
+                .if_tag(IfTags::new("\"tier\" = 'hot'"))
+                .if_source_match(IfSourceMatchCondition::Match("\"0x8DA\"".into()))
+        })
+        .await;
+
+        assert_eq!(request.url().query(), Some("comp=appendblock"));
+        let headers = request.headers();
+        let header = |name: &'static str| headers.get_optional_str(&HeaderName::from_static(name));
+        assert_eq!(
+            header("x-ms-copy-source"),
+            Some("https://source.blob.core.windows.net/c/b")
+        );
+        assert_eq!(header("x-ms-source-range"), Some("bytes=0-511"));
+        assert_eq!(header("x-ms-blob-condition-maxsize"), Some("4096"));
+        assert_eq!(header("x-ms-blob-condition-appendpos"), Some("1024"));
+        assert_eq!(header("if-match"), Some("\"0x8D9\""));
+        assert_eq!(header("x-ms-if-tags"), Some("\"tier\" = 'hot'"));
+        assert_eq!(header("x-ms-source-if-match"), Some("\"0x8DA\""));
+        assert_eq!(header("x-ms-copy-source-authorization"), None);
+        assert_eq!(header("x-ms-version"), Some("2019-12-12"));
+    }
+
+    #[tokio::test]
+    async fn authorizing_the_source_requires_a_newer_service_version() {
+        let request = sent_request(|append_block| {
+            append_block.copy_source_authorization(CopySourceAuthorization::bearer("token"))
+        })
+        .await;
+
+        let headers = request.headers();
+        assert_eq!(
+            headers.get_optional_str(&HeaderName::from_static("x-ms-copy-source-authorization")),
+            Some("Bearer token")
+        );
+        assert_eq!(headers.get_optional_str(&VERSION), Some("2020-10-02"));
+    }
+}
@@ -1,9 +1,6 @@
-use crate::{
-    blob::{copy_status_from_headers, CopyStatus},
-    prelude::*,
-};
+use crate::{blob::CopyStatus, prelude::*};
 use azure_core::{headers::*, prelude::*, RequestId};
-use azure_storage::{copy_id_from_headers, CopyId};
+use azure_storage::{copy_id_from_headers, copy_status_from_headers, CopyId};
 use std::convert::{TryFrom, TryInto};
 use time::OffsetDateTime;
 use url::Url;
@@ -31,7 +28,7 @@ impl CopyBlobBuilder {
             let url = self.client.url()?;
 
             let mut headers = Headers::new();
-            headers.insert(COPY_SOURCE, self.source_url.as_str().to_owned());
+            headers.add(SourceUri::new(self.source_url.clone()));
             if let Some(metadata) = &self.metadata {
                 for m in metadata.iter() {
                     headers.add(m);
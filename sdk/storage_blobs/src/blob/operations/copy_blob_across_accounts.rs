@@ -0,0 +1,236 @@
+use crate::{clients::none_if_not_found, prelude::*};
+use azure_core::prelude::*;
+use azure_storage::{clients::StorageCredentials, prelude::BlobSasPermissions};
+use time::{Duration, OffsetDateTime};
+use url::Url;
+
+/// Blobs up to this size are copied with a single Copy Blob From URL.
+const MAX_SYNC_COPY_SIZE: u64 = 256 * 1024 * 1024;
+const DEFAULT_BLOCK_SIZE: u64 = 100 * 1024 * 1024;
+const DEFAULT_SAS_DURATION: Duration = Duration::hours(1);
+
+operation! {
+    /// Copies a blob of another storage account.
+    ///
+    /// The destination account reads the source blob from a URL authorized with a short-lived
+    /// SAS: a service SAS when the source client has the account key, or a user delegation SAS
+    /// when it has Azure Active Directory credentials. Use `source_url` to provide an authorized
+    /// URL instead.
+    ///
+    /// Blobs of up to 256 MiB are copied with a single Copy Blob From URL. Larger blobs are copied
+    /// block by block with Put Block From URL, then committed: when a previous copy of the same
+    /// version of the source was interrupted, the blocks it already staged are not copied again.
+    /// Every copy is conditioned on the ETag of the source, so a source modified during the copy
+    /// fails it instead of mixing its versions.
+    CopyBlobAcrossAccounts,
+    client: BlobClient,
+    source: BlobClient,
+    ?source_url: Url,
+    ?sas_duration: Duration,
+    ?block_size: u64,
+    ?progress: ProgressHandler
+}
+
+impl CopyBlobAcrossAccountsBuilder {
+    pub fn into_future(self) -> CopyBlobAcrossAccounts {
+        Box::pin(async move {
+            let properties = self
+                .source
+                .get_properties()
+                .context(self.context.clone())
+                .into_future()
+                .await?
+                .blob
+                .properties;
+            let content_length = properties.content_length;
+            let source_url = match &self.source_url {
+                Some(source_url) => source_url.clone(),
+                None => self.authorized_source_url().await?,
+            };
+            let report = |copied| {
+                if let Some(progress) = &self.progress {
+                    progress.report(copied, content_length);
+                }
+            };
+
+            let if_source_match = IfSourceMatchCondition::Match(properties.etag.to_string());
+
+            if content_length <= MAX_SYNC_COPY_SIZE {
+                self.client
+                    .copy_from_url(source_url)
+                    .is_synchronous(true)
+                    .if_source_match(if_source_match)
+                    .context(self.context.clone())
+                    .into_future()
+                    .await?;
+                report(content_length);
+                return Ok(CopyBlobAcrossAccountsResponse {
+                    content_length,
+                    block_count: None,
+                });
+            }
+
+            let block_size = self.block_size.unwrap_or(DEFAULT_BLOCK_SIZE).max(1);
+            let staged = self.staged_blocks().await?;
+            let mut block_list = BlockList::default();
+            for block in plan_blocks(
+                content_length,
+                block_size,
+                properties.etag.as_ref(),
+                &staged,
+            ) {
+                if !block.staged {
+                    self.client
+                        .put_block_from_url(block.block_id.clone(), source_url.clone())
+                        .range(block.range)
+                        .if_source_match(if_source_match.clone())
+                        .context(self.context.clone())
+                        .into_future()
+                        .await?;
+                }
+                block_list
+                    .blocks
+                    .push(BlobBlockType::new_uncommitted(block.block_id));
+                report(block.range.end);
+            }
+
+            let block_count = block_list.blocks.len();
+            self.client
+                .put_block_list(block_list)
+                .content_type(properties.content_type)
+                .context(self.context.clone())
+                .into_future()
+                .await?;
+            Ok(CopyBlobAcrossAccountsResponse {
+                content_length,
+                block_count: Some(block_count),
+            })
+        })
+    }
+
+    /// The URL of the source blob with a read-only SAS.
+    async fn authorized_source_url(&self) -> azure_core::Result<Url> {
+        let now = OffsetDateTime::now_utc();
+        let expiry = now + self.sas_duration.unwrap_or(DEFAULT_SAS_DURATION);
+        let permissions = BlobSasPermissions {
+            read: true,
+            ..Default::default()
+        };
+        let sas = match self.source.container_client().credentials() {
+            StorageCredentials::SASToken(query_pairs) => {
+                let mut url = self.source.url()?;
+                url.query_pairs_mut().extend_pairs(query_pairs);
+                return Ok(url);
+            }
+            // a public blob
+            StorageCredentials::Anonymous => return self.source.url(),
+            _ => {
+                self.source
+                    .shared_access_signature(permissions, expiry)
+                    .await?
+            }
+        };
+        self.source.generate_signed_blob_url(&sas)
+    }
+
+    /// The blocks staged, but not committed, by a previous copy.
+    async fn staged_blocks(&self) -> azure_core::Result<Vec<(BlockId, u64)>> {
+        let block_list = none_if_not_found(
+            self.client
+                .get_block_list()
+                .block_list_type(BlockListType::Uncommitted)
+                .context(self.context.clone())
+                .into_future()
+                .await,
+        )?;
+        Ok(block_list
+            .map(|response| response.block_with_size_list.blocks)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|block| match block.block_list_type {
+                BlobBlockType::Uncommitted(block_id) => Some((block_id, block.size_in_bytes)),
+                _ => None,
+            })
+            .collect())
+    }
+}
+
+/// A block of the destination blob.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PlannedBlock {
+    block_id: BlockId,
+    range: Range,
+    /// Whether a previous copy already staged the block.
+    staged: bool,
+}
+
+/// Splits the source in blocks of `block_size`. The ids of the blocks identify the version of the
+/// source, so that only the blocks staged from the same version are reused.
+fn plan_blocks(
+    content_length: u64,
+    block_size: u64,
+    etag: &str,
+    staged: &[(BlockId, u64)],
+) -> Vec<PlannedBlock> {
+    let version = format!("{:x}", md5::compute(etag));
+    let mut blocks = Vec::new();
+    let mut offset = 0;
+    while offset < content_length {
+        let end = std::cmp::min(offset + block_size, content_length);
+        let block_id = BlockId::new(format!("{:016}{version}", blocks.len()));
+        let staged = staged.contains(&(block_id.clone(), end - offset));
+        blocks.push(PlannedBlock {
+            block_id,
+            range: Range::new(offset, end),
+            staged,
+        });
+        offset = end;
+    }
+    blocks
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CopyBlobAcrossAccountsResponse {
+    /// The number of bytes copied.
+    pub content_length: u64,
+    /// The number of blocks the blob was copied in, or `None` when it was copied with a single
+    /// Copy Blob From URL.
+    pub block_count: Option<usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_the_source_in_blocks() {
+        let blocks = plan_blocks(25, 10, "\"0x1\"", &[]);
+        assert_eq!(
+            blocks.iter().map(|block| block.range).collect::<Vec<_>>(),
+            vec![Range::new(0, 10), Range::new(10, 20), Range::new(20, 25)]
+        );
+        assert!(blocks.iter().all(|block| !block.staged));
+        assert!(plan_blocks(0, 10, "\"0x1\"", &[]).is_empty());
+    }
+
+    #[test]
+    fn skips_the_blocks_staged_from_the_same_version() {
+        let previous = plan_blocks(25, 10, "\"0x1\"", &[]);
+        // the previous copy staged the first block, and a truncated second one
+        let staged = vec![
+            (previous[0].block_id.clone(), 10),
+            (previous[1].block_id.clone(), 5),
+        ];
+
+        let blocks = plan_blocks(25, 10, "\"0x1\"", &staged);
+        assert_eq!(
+            blocks.iter().map(|block| block.staged).collect::<Vec<_>>(),
+            vec![true, false, false]
+        );
+
+        // the source was modified since
+        let blocks = plan_blocks(25, 10, "\"0x2\"", &staged);
+        assert!(blocks.iter().all(|block| !block.staged));
+        assert_ne!(blocks[0].block_id, previous[0].block_id);
+    }
+}
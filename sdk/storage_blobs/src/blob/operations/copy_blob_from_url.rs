@@ -1,10 +1,12 @@
 use crate::{
-    blob::{copy_status_from_headers, CopyStatus, SourceContentMD5},
+    blob::{CopyStatus, SourceContentMD5},
+    options::FROM_URL_VERSION,
     prelude::*,
 };
 use azure_core::{headers::*, prelude::*, RequestId};
 use azure_storage::{
-    copy_id_from_headers, headers::content_md5_from_headers_optional, ConsistencyMD5, CopyId,
+    copy_id_from_headers, copy_status_from_headers, headers::content_md5_from_headers_optional,
+    ConsistencyMD5, CopyId,
 };
 use std::convert::{TryFrom, TryInto};
 use time::OffsetDateTime;
@@ -21,7 +23,8 @@ operation! {
     ?if_source_since: IfSourceModifiedSinceCondition,
     ?if_source_match: IfSourceMatchCondition,
     ?lease_id: LeaseId,
-    ?source_content_md5: SourceContentMD5
+    ?source_content_md5: SourceContentMD5,
+    ?copy_source_authorization: CopySourceAuthorization
 }
 
 impl CopyBlobFromUrlBuilder {
@@ -30,7 +33,7 @@ impl CopyBlobFromUrlBuilder {
             let url = self.client.url()?;
 
             let mut headers = Headers::new();
-            headers.insert(COPY_SOURCE, self.source_url.to_string());
+            headers.add(SourceUri::new(self.source_url.clone()));
             headers.insert(
                 REQUIRES_SYNC,
                 format!("{}", self.is_synchronous.unwrap_or(false)),
@@ -46,10 +49,15 @@ impl CopyBlobFromUrlBuilder {
             headers.add(self.if_source_since);
             headers.add(self.if_source_match);
             headers.add(self.source_content_md5);
+            let authorizes_copy_source = self.copy_source_authorization.is_some();
+            headers.add(self.copy_source_authorization);
 
             let mut request =
                 self.client
                     .finalize_request(url, azure_core::Method::Put, headers, None)?;
+            if authorizes_copy_source {
+                request.insert_header(VERSION, FROM_URL_VERSION);
+            }
 
             let response = self.client.send(&mut self.context, &mut request).await?;
 
@@ -87,3 +95,40 @@ impl TryFrom<&Headers> for CopyBlobFromUrlResponse {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{mock_blob_client, response_headers};
+    use azure_core::{mock::MockTransportCannedPolicy, StatusCode};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn authorizing_the_source_requires_a_newer_service_version() {
+        let transport = Arc::new(MockTransportCannedPolicy::new().reply(
+            StatusCode::Accepted,
+            response_headers(&[
+                ("etag", "\"0x8DAAE8D3C2C1A5B\""),
+                ("last-modified", "Thu, 15 Oct 2026 09:30:00 GMT"),
+                ("server", "Windows-Azure-Blob/1.0"),
+                ("x-ms-version", "2020-10-02"),
+                ("x-ms-copy-id", "00000000-0000-0000-0000-000000000001"),
+                ("x-ms-copy-status", "success"),
+            ]),
+            "",
+        ));
+
+        let response = mock_blob_client(transport.clone())
+            .copy_blob_from_url(Url::parse("https://source.blob.core.windows.net/c/b").unwrap())
+            .copy_source_authorization(CopySourceAuthorization::bearer("token"))
+            .into_future()
+            .await
+            .unwrap();
+        assert_eq!(response.copy_status, CopyStatus::Success);
+
+        let requests = transport.requests();
+        let headers = requests[0].headers();
+        assert_eq!(headers.get_optional_str(&VERSION), Some("2020-10-02"));
+        assert_eq!(headers.get_optional_str(&REQUIRES_SYNC), Some("true"));
+    }
+}
@@ -0,0 +1,218 @@
+use crate::prelude::*;
+use azure_core::{
+    error::{Error, ErrorKind, ResultExt},
+    prelude::*,
+    Context, Etag,
+};
+use futures::{
+    channel::{mpsc, oneshot},
+    stream, SinkExt, StreamExt, TryStreamExt,
+};
+use std::{fs::File, io::Write, path::PathBuf};
+
+const DEFAULT_RANGE_SIZE: u64 = 4 * 1024 * 1024;
+const DEFAULT_PARALLELISM: usize = 4;
+
+operation! {
+    DownloadBlobToFile,
+    client: BlobClient,
+    path: PathBuf,
+    ?range_size: u64,
+    ?parallelism: usize,
+}
+
+impl DownloadBlobToFileBuilder {
+    /// Downloads the blob to the file at `path`, created or truncated.
+    ///
+    /// The blob is downloaded in ranges of `range_size` bytes, 4 MiB by default, up to
+    /// `parallelism` of them at once, and the ranges are appended to the file in order. At most
+    /// `parallelism` ranges are held in memory, besides the one being written. The ranges are
+    /// requested with the etag of the blob, so that the download fails if the blob is modified
+    /// meanwhile.
+    ///
+    /// The file is written, and the ranges digested, on a thread of its own, so that neither
+    /// blocks the executor. Once downloaded, the length of the file is checked against the one of
+    /// the blob, and its MD5 digest against the `Content-MD5` of the blob, if it has one.
+    pub fn into_future(self) -> DownloadBlobToFile {
+        Box::pin(async move {
+            let properties = self
+                .client
+                .get_properties()
+                .context(self.context.clone())
+                .into_future()
+                .await?
+                .blob
+                .properties;
+            let content_length = properties.content_length;
+            let etag = properties.etag;
+
+            let (mut ranges_sender, ranges_receiver) = mpsc::channel(0);
+            let written = spawn_writer(
+                self.path.clone(),
+                properties.content_md5.is_some(),
+                ranges_receiver,
+            );
+
+            let ranges = ranges(
+                content_length,
+                self.range_size.unwrap_or(DEFAULT_RANGE_SIZE),
+            );
+            let range_count = ranges.len();
+            let client = &self.client;
+            let context = &self.context;
+            let etag = &etag;
+            let downloaded = stream::iter(ranges)
+                .map(|range| async move {
+                    download_range(client, context, etag, range)
+                        .await
+                        .map(|data| (range, data))
+                })
+                .buffered(self.parallelism.unwrap_or(DEFAULT_PARALLELISM).max(1));
+            futures::pin_mut!(downloaded);
+            while let Some((range, data)) = downloaded.try_next().await? {
+                if data.len() as u64 != range.len() {
+                    return Err(Error::with_message(ErrorKind::Io, || {
+                        format!(
+                            "received {} bytes for the range {}-{}",
+                            data.len(),
+                            range.start,
+                            range.end - 1
+                        )
+                    }));
+                }
+                if ranges_sender.send(data).await.is_err() {
+                    // the writer failed: its error is reported below
+                    break;
+                }
+            }
+            drop(ranges_sender);
+
+            let (file_length, md5) = written
+                .await
+                .map_err(|_| Error::message(ErrorKind::Io, "the file writer panicked"))?
+                .with_context(ErrorKind::Io, || {
+                    format!("failed to write {}", self.path.display())
+                })?;
+            if file_length != content_length {
+                return Err(Error::with_message(ErrorKind::Io, || {
+                    format!("the file is {file_length} bytes long, the blob {content_length} bytes")
+                }));
+            }
+            if let (Some(content_md5), Some(md5)) = (&properties.content_md5, md5) {
+                content_md5.verify_digest(md5)?;
+            }
+
+            Ok(DownloadBlobToFileResponse {
+                content_length,
+                etag: etag.clone(),
+                range_count,
+            })
+        })
+    }
+}
+
+/// Writes the ranges received to the file at `path` on a thread of its own, returning the length
+/// of the file and, if `digest`, its MD5 digest.
+fn spawn_writer(
+    path: PathBuf,
+    digest: bool,
+    ranges: mpsc::Receiver<Vec<u8>>,
+) -> oneshot::Receiver<std::io::Result<(u64, Option<[u8; 16]>)>> {
+    let (sender, written) = oneshot::channel();
+    std::thread::spawn(move || {
+        let _ = sender.send(write_ranges(path, digest, ranges));
+    });
+    written
+}
+
+fn write_ranges(
+    path: PathBuf,
+    digest: bool,
+    ranges: mpsc::Receiver<Vec<u8>>,
+) -> std::io::Result<(u64, Option<[u8; 16]>)> {
+    let mut file = File::create(path)?;
+    let mut md5 = digest.then(md5::Context::new);
+    for data in futures::executor::block_on_stream(ranges) {
+        if let Some(md5) = &mut md5 {
+            md5.consume(&data);
+        }
+        file.write_all(&data)?;
+    }
+    file.flush()?;
+    Ok((file.metadata()?.len(), md5.map(|md5| md5.compute().0)))
+}
+
+/// Downloads one range of the blob, in a single request unless the connection is interrupted.
+async fn download_range(
+    client: &BlobClient,
+    context: &Context,
+    etag: &Etag,
+    range: Range,
+) -> azure_core::Result<Vec<u8>> {
+    let mut data = Vec::with_capacity(range.len() as usize);
+    let mut pages = client
+        .get()
+        .range(range)
+        .chunk_size(range.len())
+        .if_match(IfMatchCondition::Match(etag.to_string()))
+        .context(context.clone())
+        .into_stream();
+    while let Some(page) = pages.next().await {
+        let mut body = page?.data;
+        while let Some(chunk) = body.next().await {
+            data.extend_from_slice(&chunk?);
+        }
+    }
+    Ok(data)
+}
+
+/// Splits `content_length` bytes in consecutive ranges of `range_size` bytes, the last one
+/// possibly shorter.
+fn ranges(content_length: u64, range_size: u64) -> Vec<Range> {
+    let range_size = range_size.max(1);
+    (0..content_length)
+        .step_by(range_size as usize)
+        .map(|start| Range::new(start, (start + range_size).min(content_length)))
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadBlobToFileResponse {
+    /// The number of bytes downloaded.
+    pub content_length: u64,
+    /// The etag of the downloaded version of the blob.
+    pub etag: Etag,
+    /// The number of ranges the blob was downloaded in.
+    pub range_count: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_the_blob_in_ranges() {
+        assert_eq!(
+            ranges(10, 4),
+            vec![Range::new(0, 4), Range::new(4, 8), Range::new(8, 10)]
+        );
+        assert_eq!(ranges(8, 4), vec![Range::new(0, 4), Range::new(4, 8)]);
+        assert!(ranges(0, 4).is_empty());
+    }
+
+    #[tokio::test]
+    async fn writes_and_digests_the_ranges_in_order() {
+        let path = std::env::temp_dir().join(format!("download-{}", uuid::Uuid::new_v4()));
+        let (mut sender, receiver) = mpsc::channel(0);
+        let written = spawn_writer(path.clone(), true, receiver);
+        sender.send(b"hello ".to_vec()).await.unwrap();
+        sender.send(b"world".to_vec()).await.unwrap();
+        drop(sender);
+
+        let (length, md5) = written.await.unwrap().unwrap();
+        assert_eq!(length, 11);
+        assert_eq!(md5, Some(md5::compute(b"hello world").0));
+        assert_eq!(std::fs::read(&path).unwrap(), b"hello world");
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -1,11 +1,19 @@
-use crate::{blob::Blob, prelude::*};
+use crate::{
+    blob::{validate, Blob},
+    prelude::*,
+};
 use azure_core::{
-    error::Error, headers::*, prelude::*, Pageable, RequestId, Response as AzureResponse,
-    ResponseBody,
+    decompress_body,
+    error::{Error, ErrorKind},
+    headers::*,
+    prelude::*,
+    Pageable, RequestId, Response as AzureResponse, ResponseBody,
 };
 use time::OffsetDateTime;
 
 const DEFAULT_CHUNK_SIZE: u64 = 0x1000 * 0x1000;
+// the service only computes the checksum of ranges up to 4 MiB
+const MAX_VALIDATED_CHUNK_SIZE: u64 = 4 * 1024 * 1024;
 
 operation! {
     #[stream]
@@ -18,27 +26,54 @@ operation! {
     ?if_modified_since: IfModifiedSinceCondition,
     ?if_match: IfMatchCondition,
     ?if_tags: IfTags,
+    ?decompress: bool,
+    ?validate_checksum: bool,
 }
 
 impl GetBlobBuilder {
+    fn effective_chunk_size(&self) -> u64 {
+        let chunk_size = self.chunk_size.unwrap_or(DEFAULT_CHUNK_SIZE);
+        if self.validate_checksum.unwrap_or_default() {
+            std::cmp::min(chunk_size, MAX_VALIDATED_CHUNK_SIZE)
+        } else {
+            chunk_size
+        }
+    }
+
+    /// Downloads the blob, in chunks of `chunk_size`.
+    ///
+    /// With `decompress`, a blob stored with a `gzip` or `deflate` content encoding is downloaded
+    /// in a single response and decompressed as it is streamed. Only whole blobs can be
+    /// decompressed: setting a `range` too fails with an [`ErrorKind::Other`] error. The
+    /// `content_md5`, `content_crc64` and `content_encoding` of the returned blob properties are
+    /// cleared then, since they describe the compressed content, while the `content_length`
+    /// remains the stored, compressed, length of the blob.
     pub fn into_stream(self) -> Pageable<GetBlobResponse, Error> {
         let make_request = move |continuation: Option<Range>| {
             let this = self.clone();
             let mut ctx = self.context.clone();
             async move {
+                if this.decompress.unwrap_or_default() && this.range.is_some() {
+                    return Err(Error::message(
+                        ErrorKind::Other,
+                        "a range of a blob cannot be decompressed, only the whole blob can",
+                    ));
+                }
                 let mut url = this.client.url()?;
 
                 let range = match continuation {
-                    Some(range) => range,
-                    None => {
-                        initial_range(this.chunk_size.unwrap_or(DEFAULT_CHUNK_SIZE), this.range)
-                    }
+                    Some(range) => Some(range),
+                    // a compressed stream cannot be decompressed chunk by chunk
+                    None if this.decompress.unwrap_or_default() => this.range,
+                    None => Some(initial_range(this.effective_chunk_size(), this.range)),
                 };
+                let range_checksum =
+                    this.validate_checksum.unwrap_or_default() && requests_range_checksum(range);
 
                 this.blob_versioning.append_to_url_query(&mut url);
 
                 let mut headers = Headers::new();
-                for (name, value) in range.as_headers() {
+                for (name, value) in range.iter().flat_map(|range| range.as_headers()) {
                     headers.insert(name, value);
                 }
 
@@ -46,6 +81,9 @@ impl GetBlobBuilder {
                 headers.add(this.if_modified_since);
                 headers.add(this.if_match.clone());
                 headers.add(this.if_tags.clone());
+                if range_checksum {
+                    headers.insert(RANGE_GET_CONTENT_CRC64, "true");
+                }
 
                 let mut request =
                     this.client
@@ -53,7 +91,7 @@ impl GetBlobBuilder {
 
                 let response = this.client.send(&mut ctx, &mut request).await?;
 
-                GetBlobResponse::try_from(this, response).await
+                GetBlobResponse::try_from(this, response, range_checksum).await
             }
         };
         Pageable::new(make_request)
@@ -73,6 +111,7 @@ impl GetBlobResponse {
     async fn try_from(
         request: GetBlobBuilder,
         response: AzureResponse,
+        range_checksum: bool,
     ) -> azure_core::Result<Self> {
         let headers = response.headers();
 
@@ -81,13 +120,45 @@ impl GetBlobResponse {
 
         let content_range = headers.get_optional_as(&CONTENT_RANGE)?;
 
-        let remaining_range = remaining_range(
-            request.chunk_size.unwrap_or(DEFAULT_CHUNK_SIZE),
-            request.range,
-            content_range,
-        );
-        let blob = Blob::from_headers(request.client.blob_name(), headers)?;
-        let data = response.into_body();
+        let decompress = request.decompress.unwrap_or_default();
+        let remaining_range = if decompress {
+            None
+        } else {
+            remaining_range(request.effective_chunk_size(), request.range, content_range)
+        };
+        let mut blob = Blob::from_headers(request.client.blob_name(), headers)?;
+        let mut data = response.into_body();
+        if request.validate_checksum.unwrap_or_default() {
+            // the CRC64 is the one of the range when it was asked for, but the MD5 is the one of
+            // the whole blob and only applies when the whole blob was returned
+            let crc64 = blob.properties.content_crc64.clone();
+            let md5 = match content_range {
+                Some(content_range)
+                    if content_range.start() != 0
+                        || content_range.end() + 1 < content_range.total_length() =>
+                {
+                    None
+                }
+                _ => blob.properties.content_md5.clone(),
+            };
+            if crc64.is_none() && md5.is_none() {
+                return Err(Error::message(
+                    ErrorKind::DataConversion,
+                    if range_checksum {
+                        "the service did not return the x-ms-content-crc64 of the requested range"
+                    } else {
+                        "the blob has no Content-MD5 to validate the downloaded content against"
+                    },
+                ));
+            }
+            data = validate::validate(data, crc64, md5);
+        }
+        if decompress && is_compressed(blob.properties.content_encoding.as_deref()) {
+            data = decompress_body(data, blob.properties.content_encoding.as_deref());
+            blob.properties.content_md5 = None;
+            blob.properties.content_crc64 = None;
+            blob.properties.content_encoding = None;
+        }
 
         Ok(Self {
             request_id,
@@ -106,6 +177,22 @@ impl Continuable for GetBlobResponse {
     }
 }
 
+// Whether the content encoding is one `decompress_body` decompresses.
+fn is_compressed(content_encoding: Option<&str>) -> bool {
+    content_encoding.map_or(false, |content_encoding| {
+        matches!(
+            content_encoding.trim().to_ascii_lowercase().as_str(),
+            "gzip" | "x-gzip" | "deflate"
+        )
+    })
+}
+
+// The service returns the checksum of a range only when asked to, and only for ranges of at most
+// 4 MiB.
+fn requests_range_checksum(range: Option<Range>) -> bool {
+    range.map_or(false, |range| range.len() <= MAX_VALIDATED_CHUNK_SIZE)
+}
+
 // caclculate the first Range for use at the beginning of the Pageable.
 fn initial_range(chunk_size: u64, request_range: Option<Range>) -> Range {
     match request_range {
@@ -167,6 +254,16 @@ fn remaining_range(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tests::{blob_properties_headers, mock_blob_client};
+    use azure_core::{mock::MockTransportCannedPolicy, StatusCode};
+    use futures::StreamExt;
+    use std::sync::Arc;
+
+    /// "hello, decompressed world", gzipped.
+    const GZIPPED: &[u8] = &[
+        31, 139, 8, 0, 0, 0, 0, 0, 2, 3, 203, 72, 205, 201, 201, 215, 81, 72, 73, 77, 206, 207, 45,
+        40, 74, 45, 46, 78, 77, 81, 40, 207, 47, 202, 73, 1, 0, 178, 225, 8, 83, 25, 0, 0, 0,
+    ];
 
     #[test]
     fn test_initial_range() -> azure_core::Result<()> {
@@ -220,4 +317,78 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn caps_chunks_when_validating() {
+        let blob_client = BlobServiceClientBuilder::emulator()
+            .build()
+            .container_client("container")
+            .blob_client("blob");
+        let get_blob = blob_client.get().chunk_size(DEFAULT_CHUNK_SIZE);
+        assert_eq!(get_blob.effective_chunk_size(), DEFAULT_CHUNK_SIZE);
+        let get_blob = get_blob.validate_checksum(true);
+        assert_eq!(get_blob.effective_chunk_size(), MAX_VALIDATED_CHUNK_SIZE);
+        let get_blob = get_blob.chunk_size(1024u64);
+        assert_eq!(get_blob.effective_chunk_size(), 1024);
+    }
+
+    #[test]
+    fn test_requests_range_checksum() {
+        assert!(!requests_range_checksum(None));
+        assert!(requests_range_checksum(Some(Range::new(
+            0,
+            MAX_VALIDATED_CHUNK_SIZE
+        ))));
+        assert!(!requests_range_checksum(Some(Range::new(
+            0,
+            MAX_VALIDATED_CHUNK_SIZE + 1
+        ))));
+    }
+
+    #[tokio::test]
+    async fn decompresses_the_whole_blob() {
+        let transport = Arc::new(MockTransportCannedPolicy::new().reply(
+            StatusCode::Ok,
+            blob_properties_headers(&[
+                ("content-length", "45"),
+                ("content-encoding", "gzip"),
+                ("content-md5", "kZwDkD9b4Y9c0iHGLJI6Ow=="),
+            ]),
+            GZIPPED.to_vec(),
+        ));
+        let mut stream = mock_blob_client(transport.clone())
+            .get()
+            .decompress(true)
+            .into_stream();
+        let response = stream.next().await.unwrap().unwrap();
+
+        assert!(response.blob.properties.content_md5.is_none());
+        assert!(response.blob.properties.content_encoding.is_none());
+        assert_eq!(
+            response.data.collect().await.unwrap(),
+            "hello, decompressed world"
+        );
+        assert!(stream.next().await.is_none());
+        // a compressed blob cannot be downloaded in chunks
+        assert_eq!(
+            transport.requests()[0]
+                .headers()
+                .get_optional_str(&HeaderName::from_static("x-ms-range")),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn cannot_decompress_a_range() {
+        let transport = Arc::new(MockTransportCannedPolicy::new());
+        let mut stream = mock_blob_client(transport.clone())
+            .get()
+            .decompress(true)
+            .range(Range::new(0, 10))
+            .into_stream();
+
+        let error = stream.next().await.unwrap().unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::Other);
+        assert!(transport.requests().is_empty());
+    }
 }
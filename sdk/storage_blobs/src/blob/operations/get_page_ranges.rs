@@ -10,18 +10,24 @@ operation! {
     ?if_match: IfMatchCondition,
     ?if_tags: IfTags,
     ?blob_versioning: BlobVersioning,
+    ?previous_snapshot: PreviousSnapshot,
+    ?range: BA512Range,
     ?lease_id: LeaseId
 }
 
 impl GetPageRangesBuilder {
+    /// Lists the valid page ranges of the blob, or, with a `previous_snapshot`, the page ranges
+    /// written and cleared since that snapshot. The list is limited to `range` if set.
     pub fn into_future(mut self) -> GetPageRanges {
         Box::pin(async move {
             let mut url = self.client.url()?;
 
             url.query_pairs_mut().append_pair("comp", "pagelist");
             self.blob_versioning.append_to_url_query(&mut url);
+            self.previous_snapshot.append_to_url_query(&mut url);
 
             let mut headers = Headers::new();
+            headers.add(self.range);
             headers.add(self.lease_id);
             headers.add(self.if_modified_since);
             headers.add(self.if_match);
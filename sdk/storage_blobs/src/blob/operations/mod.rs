@@ -1,13 +1,17 @@
+mod abort_copy;
 mod acquire_lease;
 mod append_block;
+mod append_block_from_url;
 mod break_lease;
 mod change_lease;
 mod clear_page;
 mod copy_blob;
+mod copy_blob_across_accounts;
 mod copy_blob_from_url;
 mod delete_blob;
 mod delete_blob_snapshot;
 mod delete_blob_version;
+mod download_blob_to_file;
 mod get_blob;
 mod get_block_list;
 mod get_metadata;
@@ -15,30 +19,40 @@ mod get_page_ranges;
 mod get_properties;
 mod get_tags;
 mod put_append_blob;
+mod put_blob_from_url;
 mod put_block;
 mod put_block_blob;
+mod put_block_from_url;
 mod put_block_list;
 mod put_page;
 mod put_page_blob;
 mod release_lease;
 mod renew_lease;
+mod resize_page_blob;
+mod seal_append_blob;
 mod set_blob_tier;
 mod set_expiry;
 mod set_metadata;
 mod set_properties;
 mod set_tags;
 mod snapshot_blob;
+mod update_sequence_number;
+mod upload_block_blob;
 
+pub use abort_copy::*;
 pub use acquire_lease::*;
 pub use append_block::*;
+pub use append_block_from_url::*;
 pub use break_lease::*;
 pub use change_lease::*;
 pub use clear_page::*;
 pub use copy_blob::*;
+pub use copy_blob_across_accounts::*;
 pub use copy_blob_from_url::*;
 pub use delete_blob::*;
 pub use delete_blob_snapshot::*;
 pub use delete_blob_version::*;
+pub use download_blob_to_file::*;
 pub use get_blob::*;
 pub use get_block_list::*;
 pub use get_metadata::*;
@@ -46,16 +60,22 @@ pub use get_page_ranges::*;
 pub use get_properties::*;
 pub use get_tags::*;
 pub use put_append_blob::*;
+pub use put_blob_from_url::*;
 pub use put_block::*;
 pub use put_block_blob::*;
+pub use put_block_from_url::*;
 pub use put_block_list::*;
 pub use put_page::*;
 pub use put_page_blob::*;
 pub use release_lease::*;
 pub use renew_lease::*;
+pub use resize_page_blob::*;
+pub use seal_append_blob::*;
 pub use set_blob_tier::*;
 pub use set_expiry::*;
 pub use set_metadata::*;
 pub use set_properties::*;
 pub use set_tags::*;
 pub use snapshot_blob::*;
+pub use update_sequence_number::*;
+pub use upload_block_blob::*;
@@ -0,0 +1,156 @@
+use crate::{blob::SourceContentMD5, options::FROM_URL_VERSION, prelude::*};
+use azure_core::{headers::*, prelude::*};
+use url::Url;
+
+operation! {
+    PutBlobFromUrl,
+    client: BlobClient,
+    source_url: Url,
+    ?content_type: BlobContentType,
+    ?content_encoding: BlobContentEncoding,
+    ?content_language: BlobContentLanguage,
+    ?content_disposition: BlobContentDisposition,
+    ?cache_control: BlobCacheControl,
+    ?metadata: Metadata,
+    ?access_tier: AccessTier,
+    ?tags: Tags,
+    ?lease_id: LeaseId,
+    ?if_modified_since: IfModifiedSinceCondition,
+    ?if_match: IfMatchCondition,
+    ?if_source_since: IfSourceModifiedSinceCondition,
+    ?if_source_match: IfSourceMatchCondition,
+    ?source_content_md5: SourceContentMD5,
+    ?copy_source_authorization: CopySourceAuthorization,
+    ?copy_source_blob_properties: bool
+}
+
+impl PutBlobFromUrlBuilder {
+    pub fn into_future(mut self) -> PutBlobFromUrl {
+        Box::pin(async move {
+            let url = self.client.url()?;
+
+            let mut headers = Headers::new();
+            headers.insert(BLOB_TYPE, "BlockBlob");
+            headers.add(SourceUri::new(self.source_url.clone()));
+            headers.add(self.content_type);
+            headers.add(self.content_encoding);
+            headers.add(self.content_language);
+            headers.add(self.content_disposition);
+            headers.add(self.cache_control);
+            headers.add(self.tags);
+            if let Some(metadata) = &self.metadata {
+                for m in metadata.iter() {
+                    headers.add(m);
+                }
+            }
+            headers.add(self.access_tier);
+            headers.add(self.lease_id);
+            headers.add(self.if_modified_since);
+            headers.add(self.if_match);
+            headers.add(self.if_source_since);
+            headers.add(self.if_source_match);
+            headers.add(self.source_content_md5);
+            headers.add(self.copy_source_authorization);
+            if let Some(copy_source_blob_properties) = self.copy_source_blob_properties {
+                headers.insert(
+                    "x-ms-copy-source-blob-properties",
+                    copy_source_blob_properties.to_string(),
+                );
+            }
+
+            let mut request =
+                self.client
+                    .finalize_request(url, azure_core::Method::Put, headers, None)?;
+            request.insert_header(VERSION, FROM_URL_VERSION);
+
+            let response = self.client.send(&mut self.context, &mut request).await?;
+            PutBlockBlobResponse::from_headers(response.headers())
+        })
+    }
+}
+
+/// The blob is written synchronously: the response is the one of a put block blob.
+pub type PutBlobFromUrlResponse = PutBlockBlobResponse;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{mock_blob_client, response_headers};
+    use azure_core::{mock::MockTransportCannedPolicy, StatusCode};
+    use std::sync::Arc;
+
+    fn created() -> Arc<MockTransportCannedPolicy> {
+        Arc::new(MockTransportCannedPolicy::new().reply(
+            StatusCode::Created,
+            response_headers(&[
+                ("etag", "\"0x8DAAE8D3C2C1A5B\""),
+                ("last-modified", "Thu, 15 Oct 2026 09:30:00 GMT"),
+                ("x-ms-request-server-encrypted", "true"),
+            ]),
+            "",
+        ))
+    }
+
+    #[tokio::test]
+    async fn puts_the_blob_from_the_source_url() {
+        let transport = created();
+        let source = Url::parse("https://source.blob.core.windows.net/container/source").unwrap();
+
+        let response = mock_blob_client(transport.clone())
+            .put_blob_from_url(source.clone())
+            .content_type("text/plain")
+            .if_source_match(IfSourceMatchCondition::Match("\"source-etag\"".to_owned()))
+            .copy_source_authorization(CopySourceAuthorization::bearer("token"))
+            .copy_source_blob_properties(false)
+            .into_future()
+            .await
+            .unwrap();
+        assert_eq!(response.etag, "\"0x8DAAE8D3C2C1A5B\"");
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        let request = &requests[0];
+        let headers = request.headers();
+        assert_eq!(request.method(), &azure_core::Method::Put);
+        assert_eq!(request.url().path(), "/container/blob");
+        assert_eq!(request.url().query(), None);
+        assert_eq!(headers.get_optional_str(&BLOB_TYPE), Some("BlockBlob"));
+        assert_eq!(
+            headers.get_optional_str(&COPY_SOURCE),
+            Some(source.as_str())
+        );
+        assert_eq!(headers.get_optional_str(&CONTENT_LENGTH), Some("0"));
+        assert_eq!(
+            headers.get_optional_str(&HeaderName::from_static("x-ms-blob-content-type")),
+            Some("text/plain")
+        );
+        assert_eq!(
+            headers.get_optional_str(&SOURCE_IF_MATCH),
+            Some("\"source-etag\"")
+        );
+        assert_eq!(
+            headers.get_optional_str(&HeaderName::from_static("x-ms-copy-source-authorization")),
+            Some("Bearer token")
+        );
+        assert_eq!(
+            headers.get_optional_str(&HeaderName::from_static("x-ms-copy-source-blob-properties")),
+            Some("false")
+        );
+    }
+
+    #[tokio::test]
+    async fn sends_a_service_version_supporting_put_blob_from_url() {
+        let transport = created();
+
+        mock_blob_client(transport.clone())
+            .put_blob_from_url(Url::parse("https://source.blob.core.windows.net/c/b").unwrap())
+            .into_future()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            transport.requests()[0].headers().get_optional_str(&VERSION),
+            Some("2020-10-02")
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{blob::SourceContentMD5, options::FROM_URL_VERSION, prelude::*};
+use azure_core::{headers::*, prelude::*};
+use url::Url;
+
+operation! {
+    PutBlockFromUrl,
+    client: BlobClient,
+    block_id: BlockId,
+    source_url: Url,
+    ?range: Range,
+    ?lease_id: LeaseId,
+    ?if_source_since: IfSourceModifiedSinceCondition,
+    ?if_source_match: IfSourceMatchCondition,
+    ?source_content_md5: SourceContentMD5,
+    ?copy_source_authorization: CopySourceAuthorization
+}
+
+impl PutBlockFromUrlBuilder {
+    pub fn into_future(mut self) -> PutBlockFromUrl {
+        Box::pin(async move {
+            let mut url = self.client.url()?;
+
+            self.block_id.append_to_url_query(&mut url);
+            url.query_pairs_mut().append_pair("comp", "block");
+
+            let mut headers = Headers::new();
+            headers.add(SourceUri::new(self.source_url.clone()));
+            if let Some(range) = &self.range {
+                headers.insert(SOURCE_RANGE, range.to_string());
+            }
+            headers.add(self.lease_id);
+            headers.add(self.if_source_since);
+            headers.add(self.if_source_match);
+            headers.add(self.source_content_md5);
+            let authorizes_copy_source = self.copy_source_authorization.is_some();
+            headers.add(self.copy_source_authorization);
+
+            let mut request =
+                self.client
+                    .finalize_request(url, azure_core::Method::Put, headers, None)?;
+            if authorizes_copy_source {
+                request.insert_header(VERSION, FROM_URL_VERSION);
+            }
+
+            let response = self.client.send(&mut self.context, &mut request).await?;
+            PutBlockResponse::from_headers(response.headers())
+        })
+    }
+}
+
+pub type PutBlockFromUrlResponse = PutBlockResponse;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{mock_blob_client, response_headers};
+    use azure_core::{mock::MockTransportCannedPolicy, StatusCode};
+    use std::sync::Arc;
+
+    async fn sent_version(copy_source_authorization: Option<CopySourceAuthorization>) -> String {
+        let transport = Arc::new(MockTransportCannedPolicy::new().reply(
+            StatusCode::Created,
+            response_headers(&[("x-ms-request-server-encrypted", "true")]),
+            "",
+        ));
+        let mut put_block = mock_blob_client(transport.clone()).put_block_from_url(
+            "block",
+            Url::parse("https://source.blob.core.windows.net/c/b").unwrap(),
+        );
+        if let Some(authorization) = copy_source_authorization {
+            put_block = put_block.copy_source_authorization(authorization);
+        }
+        put_block.into_future().await.unwrap();
+
+        transport.requests()[0]
+            .headers()
+            .get_optional_string(&VERSION)
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn authorizing_the_source_requires_a_newer_service_version() {
+        assert_eq!(
+            sent_version(Some(CopySourceAuthorization::bearer("token"))).await,
+            "2020-10-02"
+        );
+        assert_eq!(sent_version(None).await, "2019-12-12");
+    }
+}
@@ -0,0 +1,50 @@
+use crate::prelude::*;
+use azure_core::{headers::*, prelude::*, RequestId};
+use time::OffsetDateTime;
+
+operation! {
+    ResizePageBlob,
+    client: BlobClient,
+    length: u128,
+    ?if_sequence_number: IfSequenceNumber,
+    ?if_modified_since: IfModifiedSinceCondition,
+    ?if_match: IfMatchCondition,
+    ?if_tags: IfTags,
+    ?lease_id: LeaseId
+}
+
+impl ResizePageBlobBuilder {
+    /// Resizes the page blob to `length` bytes, a multiple of 512. The pages beyond the new
+    /// length are cleared.
+    pub fn into_future(mut self) -> ResizePageBlob {
+        Box::pin(async move {
+            let mut url = self.client.url()?;
+
+            url.query_pairs_mut().append_pair("comp", "properties");
+
+            let mut headers = Headers::new();
+            headers.insert(BLOB_CONTENT_LENGTH, self.length.to_string());
+            headers.add(self.if_sequence_number);
+            headers.add(self.if_modified_since);
+            headers.add(self.if_match);
+            headers.add(self.if_tags);
+            headers.add(self.lease_id);
+
+            let mut request =
+                self.client
+                    .finalize_request(url, azure_core::Method::Put, headers, None)?;
+
+            let response = self.client.send(&mut self.context, &mut request).await?;
+
+            ResizePageBlobResponse::from_headers(response.headers())
+        })
+    }
+}
+
+azure_storage::response_from_headers!(ResizePageBlobResponse,
+    etag_from_headers => etag: String,
+    last_modified_from_headers => last_modified: OffsetDateTime,
+    sequence_number_from_headers => sequence_number: u64,
+    request_id_from_headers => request_id: RequestId,
+    date_from_headers => date: OffsetDateTime
+);
@@ -0,0 +1,45 @@
+use crate::prelude::*;
+use azure_core::{headers::*, prelude::*, RequestId};
+use time::OffsetDateTime;
+
+operation! {
+    UpdateSequenceNumber,
+    client: BlobClient,
+    action: SequenceNumberAction,
+    ?if_modified_since: IfModifiedSinceCondition,
+    ?if_match: IfMatchCondition,
+    ?if_tags: IfTags,
+    ?lease_id: LeaseId
+}
+
+impl UpdateSequenceNumberBuilder {
+    pub fn into_future(mut self) -> UpdateSequenceNumber {
+        Box::pin(async move {
+            let mut url = self.client.url()?;
+
+            url.query_pairs_mut().append_pair("comp", "properties");
+
+            let mut headers = self.action.to_headers();
+            headers.add(self.if_modified_since);
+            headers.add(self.if_match);
+            headers.add(self.if_tags);
+            headers.add(self.lease_id);
+
+            let mut request =
+                self.client
+                    .finalize_request(url, azure_core::Method::Put, headers, None)?;
+
+            let response = self.client.send(&mut self.context, &mut request).await?;
+
+            UpdateSequenceNumberResponse::from_headers(response.headers())
+        })
+    }
+}
+
+azure_storage::response_from_headers!(UpdateSequenceNumberResponse,
+    etag_from_headers => etag: String,
+    last_modified_from_headers => last_modified: OffsetDateTime,
+    sequence_number_from_headers => sequence_number: u64,
+    request_id_from_headers => request_id: RequestId,
+    date_from_headers => date: OffsetDateTime
+);
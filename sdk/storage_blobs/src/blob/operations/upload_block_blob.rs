@@ -0,0 +1,405 @@
+use crate::{
+    blob::{UploadCheckpoint, UploadCheckpointStore},
+    prelude::*,
+};
+use azure_core::{prelude::*, Context};
+use bytes::Bytes;
+use futures::{
+    io::{AsyncRead, AsyncReadExt},
+    stream, StreamExt, TryStreamExt,
+};
+use std::sync::Arc;
+
+const DEFAULT_BLOCK_SIZE: usize = 8 * 1024 * 1024;
+const DEFAULT_PARALLELISM: usize = 4;
+
+/// Uploads the content of a reader to a block blob.
+///
+/// The content is read in blocks of `block_size` bytes, 8 MiB by default, which are staged with
+/// Put Block, up to `parallelism` of them at once, then committed with Put Block List. Content
+/// which fits in a single block is uploaded with a single Put Blob instead. At most
+/// `parallelism + 1` blocks are held in memory.
+///
+/// Files can be read with `futures::io::AllowStdIo`, or with the compatibility layer of the
+/// async runtime, e.g. `tokio_util::compat`.
+///
+/// With a `checkpoint_store`, the progress of the upload is saved after every block, so that an
+/// interrupted upload is resumed where it left off when it is started again with the same store
+/// and a reader of the same content: the staged blocks are skipped in the reader, and the block
+/// size of the interrupted upload is kept. A checkpoint of an upload to another blob, or of
+/// another `content_length` when it is set, is rejected. The staged blocks are discarded by the
+/// service if the blob is not committed within a week.
+pub struct UploadBlockBlobBuilder<R> {
+    client: BlobClient,
+    reader: R,
+    block_size: Option<usize>,
+    parallelism: Option<usize>,
+    content_type: Option<ContentType>,
+    metadata: Option<Metadata>,
+    access_tier: Option<AccessTier>,
+    tags: Option<Tags>,
+    content_length: Option<u64>,
+    checkpoint_store: Option<Arc<dyn UploadCheckpointStore>>,
+    context: Context,
+}
+
+impl<R> UploadBlockBlobBuilder<R>
+where
+    R: AsyncRead + Unpin + Send + 'static,
+{
+    pub(crate) fn new(client: BlobClient, reader: R) -> Self {
+        Self {
+            client,
+            reader,
+            block_size: None,
+            parallelism: None,
+            content_type: None,
+            metadata: None,
+            access_tier: None,
+            tags: None,
+            content_length: None,
+            checkpoint_store: None,
+            context: Context::new(),
+        }
+    }
+
+    setters! {
+        block_size: usize => Some(block_size),
+        parallelism: usize => Some(parallelism),
+        content_type: ContentType => Some(content_type),
+        metadata: Metadata => Some(metadata),
+        access_tier: AccessTier => Some(access_tier),
+        tags: Tags => Some(tags),
+        content_length: u64 => Some(content_length),
+        context: Context => context,
+    }
+
+    /// Save the progress of the upload in `checkpoint_store`, and resume the upload it
+    /// checkpointed, if any.
+    pub fn checkpoint_store(self, checkpoint_store: impl UploadCheckpointStore + 'static) -> Self {
+        Self {
+            checkpoint_store: Some(Arc::new(checkpoint_store)),
+            ..self
+        }
+    }
+
+    pub fn into_future(mut self) -> UploadBlockBlob {
+        Box::pin(async move {
+            let block_size = self.block_size.unwrap_or(DEFAULT_BLOCK_SIZE).max(1);
+            let parallelism = self.parallelism.unwrap_or(DEFAULT_PARALLELISM).max(1);
+
+            let mut blob_url = self.client.url()?;
+            // the SAS of the blob may be renewed before the upload is resumed
+            blob_url.set_query(None);
+            let blob_url = blob_url.to_string();
+
+            let resumed = match &self.checkpoint_store {
+                Some(store) => store.load()?,
+                None => None,
+            };
+            let mut checkpoint = match resumed {
+                Some(checkpoint) => {
+                    checkpoint.check(&blob_url, self.content_length)?;
+                    log::debug!(
+                        "resuming the upload after {} blocks",
+                        checkpoint.block_count()
+                    );
+                    skip(&mut self.reader, checkpoint.offset()).await?;
+                    checkpoint
+                }
+                None => UploadCheckpoint::new(blob_url, self.content_length, block_size as u64),
+            };
+            let block_size = checkpoint.block_size() as usize;
+
+            let first = read_block(&mut self.reader, block_size).await?;
+            if checkpoint.block_count() == 0 && first.len() < block_size {
+                let content_length = first.len() as u64;
+                let mut put_block_blob = self
+                    .client
+                    .put_block_blob(first)
+                    .context(self.context.clone());
+                if let Some(content_type) = self.content_type {
+                    put_block_blob = put_block_blob.content_type(content_type);
+                }
+                if let Some(metadata) = self.metadata {
+                    put_block_blob = put_block_blob.metadata(metadata);
+                }
+                if let Some(access_tier) = self.access_tier {
+                    put_block_blob = put_block_blob.access_tier(access_tier);
+                }
+                if let Some(tags) = self.tags {
+                    put_block_blob = put_block_blob.tags(tags);
+                }
+                put_block_blob.into_future().await?;
+                if let Some(store) = &self.checkpoint_store {
+                    store.clear()?;
+                }
+                return Ok(UploadBlockBlobResponse {
+                    content_length,
+                    block_count: None,
+                });
+            }
+
+            let client = &self.client;
+            let context = &self.context;
+            let first_index = checkpoint.block_count();
+            // the ids of the blocks, while the checkpoint is updated
+            let upload = &checkpoint.clone();
+            let blocks = stream::iter((!first.is_empty()).then(|| Ok(first))).chain(
+                stream::try_unfold(self.reader, move |mut reader| async move {
+                    let block = read_block(&mut reader, block_size).await?;
+                    Ok::<_, azure_core::Error>((!block.is_empty()).then(|| (block, reader)))
+                }),
+            );
+            let staged = blocks
+                .enumerate()
+                .map(|(index, block)| block.map(|block| (first_index + index, block)))
+                .map_ok(|(index, block)| async move {
+                    let length = block.len() as u64;
+                    client
+                        .put_block(upload.block_id(index), block)
+                        .context(context.clone())
+                        .into_future()
+                        .await?;
+                    Ok::<_, azure_core::Error>(length)
+                })
+                .try_buffered(parallelism);
+            futures::pin_mut!(staged);
+            // the blocks are yielded in order, so the checkpoint covers a prefix of the content
+            while let Some(length) = staged.try_next().await? {
+                checkpoint.push(length);
+                if let Some(store) = &self.checkpoint_store {
+                    store.save(&checkpoint)?;
+                }
+            }
+
+            let block_count = checkpoint.block_count();
+            let content_length = checkpoint.offset();
+            let block_list = BlockList {
+                blocks: checkpoint
+                    .block_ids()
+                    .map(BlobBlockType::new_uncommitted)
+                    .collect(),
+            };
+            let mut put_block_list = self
+                .client
+                .put_block_list(block_list)
+                .context(self.context.clone());
+            if let Some(content_type) = self.content_type {
+                put_block_list = put_block_list.content_type(content_type);
+            }
+            if let Some(metadata) = self.metadata {
+                put_block_list = put_block_list.metadata(metadata);
+            }
+            if let Some(access_tier) = self.access_tier {
+                put_block_list = put_block_list.access_tier(access_tier);
+            }
+            if let Some(tags) = self.tags {
+                put_block_list = put_block_list.tags(tags);
+            }
+            put_block_list.into_future().await?;
+            if let Some(store) = &self.checkpoint_store {
+                store.clear()?;
+            }
+
+            Ok(UploadBlockBlobResponse {
+                content_length,
+                block_count: Some(block_count),
+            })
+        })
+    }
+}
+
+impl<R> std::fmt::Debug for UploadBlockBlobBuilder<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UploadBlockBlobBuilder")
+            .field("client", &self.client)
+            .field("block_size", &self.block_size)
+            .field("parallelism", &self.parallelism)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Skips the content already uploaded.
+async fn skip<R>(reader: &mut R, offset: u64) -> azure_core::Result<()>
+where
+    R: AsyncRead + Unpin,
+{
+    let skipped = futures::io::copy(reader.take(offset), &mut futures::io::sink()).await?;
+    if skipped < offset {
+        return Err(azure_core::Error::with_message(
+            azure_core::error::ErrorKind::Io,
+            || format!("the content is shorter than the {offset} bytes already uploaded"),
+        ));
+    }
+    Ok(())
+}
+
+/// Reads up to `block_size` bytes, less only at the end of the content.
+async fn read_block<R>(reader: &mut R, block_size: usize) -> azure_core::Result<Bytes>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut block = Vec::with_capacity(block_size);
+    reader
+        .take(block_size as u64)
+        .read_to_end(&mut block)
+        .await?;
+    Ok(block.into())
+}
+
+azure_core::future!(UploadBlockBlob);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UploadBlockBlobResponse {
+    /// The number of bytes uploaded.
+    pub content_length: u64,
+    /// The number of blocks the blob was uploaded in, or `None` when it was uploaded with a
+    /// single Put Blob.
+    pub block_count: Option<usize>,
+}
+
+#[cfg(feature = "into_future")]
+impl<R> std::future::IntoFuture for UploadBlockBlobBuilder<R>
+where
+    R: AsyncRead + Unpin + Send + 'static,
+{
+    type IntoFuture = UploadBlockBlob;
+    type Output = <UploadBlockBlob as std::future::Future>::Output;
+    fn into_future(self) -> Self::IntoFuture {
+        Self::into_future(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{mock_blob_client, response_headers};
+    use azure_core::headers::Headers;
+    use azure_core::{mock::MockTransportCannedPolicy, Body, Method, Request, StatusCode};
+    use std::sync::Mutex;
+
+    /// Keeps the checkpoint in memory.
+    #[derive(Debug, Default)]
+    struct MemoryCheckpointStore(Mutex<Option<UploadCheckpoint>>);
+
+    impl UploadCheckpointStore for Arc<MemoryCheckpointStore> {
+        fn load(&self) -> azure_core::Result<Option<UploadCheckpoint>> {
+            Ok(self.0.lock().unwrap().clone())
+        }
+
+        fn save(&self, checkpoint: &UploadCheckpoint) -> azure_core::Result<()> {
+            *self.0.lock().unwrap() = Some(checkpoint.clone());
+            Ok(())
+        }
+
+        fn clear(&self) -> azure_core::Result<()> {
+            *self.0.lock().unwrap() = None;
+            Ok(())
+        }
+    }
+
+    fn block_staged() -> Headers {
+        response_headers(&[("x-ms-request-server-encrypted", "true")])
+    }
+
+    fn block_list_committed() -> Headers {
+        response_headers(&[
+            ("etag", "\"0x8DAAE8D3C2C1A5B\""),
+            ("last-modified", "Thu, 15 Oct 2026 09:30:00 GMT"),
+            ("content-md5", "1B2M2Y8AsgTpgAmY7PhCfg=="),
+            ("x-ms-request-server-encrypted", "true"),
+        ])
+    }
+
+    fn body(request: &Request) -> Bytes {
+        match request.body() {
+            Body::Bytes(bytes) => bytes.clone(),
+            Body::SeekableStream(_) => panic!("the blocks are uploaded from memory"),
+        }
+    }
+
+    #[tokio::test]
+    async fn resumes_after_the_staged_blocks() {
+        let transport = Arc::new(
+            MockTransportCannedPolicy::new()
+                .reply(StatusCode::Created, block_staged(), "")
+                .reply(StatusCode::Created, block_staged(), "")
+                .reply(StatusCode::Created, block_list_committed(), ""),
+        );
+        let client = mock_blob_client(transport.clone());
+        let blob_url = client.url().unwrap().to_string();
+        // the first block of 4 bytes was staged before the upload was interrupted
+        let mut interrupted = UploadCheckpoint::new(blob_url, Some(10), 4);
+        interrupted.push(4);
+        let store = Arc::new(MemoryCheckpointStore::default());
+        store.save(&interrupted).unwrap();
+
+        let content: Vec<u8> = (0..10).collect();
+        let response = client
+            .upload(futures::io::Cursor::new(content))
+            // the block size of the interrupted upload is kept
+            .block_size(8)
+            .parallelism(1)
+            .content_length(10)
+            .checkpoint_store(store.clone())
+            .into_future()
+            .await
+            .unwrap();
+        assert_eq!(response.content_length, 10);
+        assert_eq!(response.block_count, Some(3));
+        assert_eq!(store.load().unwrap(), None);
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 3);
+        for (request, (index, block)) in requests
+            .iter()
+            .zip([(1, vec![4, 5, 6, 7]), (2, vec![8, 9])])
+        {
+            assert_eq!(request.method(), &Method::Put);
+            let query: Vec<(String, String)> = request.url().query_pairs().into_owned().collect();
+            assert!(query.contains(&("comp".to_owned(), "block".to_owned())));
+            let block_id = interrupted.block_id(index);
+            assert!(query.contains(&("blockid".to_owned(), base64::encode(block_id.as_ref()))));
+            assert_eq!(body(request), Bytes::from(block));
+        }
+        let block_list = String::from_utf8(body(&requests[2]).to_vec()).unwrap();
+        for index in 0..3 {
+            assert!(block_list.contains(&format!(
+                "<Uncommitted>{}</Uncommitted>",
+                base64::encode(interrupted.block_id(index).as_ref())
+            )));
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_the_checkpoint_of_another_blob() {
+        let transport = Arc::new(MockTransportCannedPolicy::new());
+        let store = Arc::new(MemoryCheckpointStore::default());
+        let mut checkpoint = UploadCheckpoint::new(
+            "https://account.blob.core.windows.net/container/other".to_owned(),
+            None,
+            4,
+        );
+        checkpoint.push(4);
+        store.save(&checkpoint).unwrap();
+
+        let result = mock_blob_client(transport.clone())
+            .upload(futures::io::Cursor::new(vec![0u8; 10]))
+            .checkpoint_store(store.clone())
+            .into_future()
+            .await;
+        assert!(result.is_err());
+        assert!(transport.requests().is_empty());
+        assert_eq!(store.load().unwrap(), Some(checkpoint));
+    }
+
+    #[tokio::test]
+    async fn reads_full_blocks_until_the_end() {
+        let mut reader = futures::io::Cursor::new(vec![7u8; 10]);
+        assert_eq!(read_block(&mut reader, 4).await.unwrap().len(), 4);
+        assert_eq!(read_block(&mut reader, 4).await.unwrap().len(), 4);
+        assert_eq!(read_block(&mut reader, 4).await.unwrap().len(), 2);
+        assert!(read_block(&mut reader, 4).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,203 @@
+use crate::options::BlockId;
+use azure_core::error::{Error, ErrorKind, ResultExt};
+use std::path::PathBuf;
+
+/// The progress of an upload in blocks, from which an interrupted upload can be resumed.
+///
+/// The blocks staged so far are identified by the id of the upload and their index, and cover
+/// the first `offset` bytes of the content: a resumed upload skips them in the reader, which must
+/// return the same content again. The checkpoint is only resumed by an upload to the same blob,
+/// of content of the same length.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UploadCheckpoint {
+    upload_id: String,
+    blob_url: String,
+    content_length: Option<u64>,
+    block_size: u64,
+    block_count: usize,
+    offset: u64,
+}
+
+impl UploadCheckpoint {
+    pub(crate) fn new(blob_url: String, content_length: Option<u64>, block_size: u64) -> Self {
+        Self {
+            upload_id: uuid::Uuid::new_v4().simple().to_string(),
+            blob_url,
+            content_length,
+            block_size,
+            block_count: 0,
+            offset: 0,
+        }
+    }
+
+    /// The URL of the uploaded blob, without its query.
+    pub fn blob_url(&self) -> &str {
+        &self.blob_url
+    }
+
+    /// The length of the uploaded content, if it was known.
+    pub fn content_length(&self) -> Option<u64> {
+        self.content_length
+    }
+
+    /// Fails if the checkpoint is not the one of an upload of `content_length` bytes to
+    /// `blob_url`, whose blocks would be committed in another blob or with other content.
+    pub(crate) fn check(
+        &self,
+        blob_url: &str,
+        content_length: Option<u64>,
+    ) -> azure_core::Result<()> {
+        if self.blob_url != blob_url {
+            return Err(Error::with_message(ErrorKind::Other, || {
+                format!(
+                    "the checkpoint is the one of an upload to {}, not {blob_url}",
+                    self.blob_url
+                )
+            }));
+        }
+        if self.content_length != content_length {
+            return Err(Error::with_message(ErrorKind::Other, || {
+                format!(
+                    "the checkpoint is the one of an upload of {:?} bytes, not {content_length:?}",
+                    self.content_length
+                )
+            }));
+        }
+        Ok(())
+    }
+
+    /// The size of the blocks, which a resumed upload keeps.
+    pub fn block_size(&self) -> u64 {
+        self.block_size
+    }
+
+    /// The number of blocks staged, in order.
+    pub fn block_count(&self) -> usize {
+        self.block_count
+    }
+
+    /// The number of bytes staged.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    pub(crate) fn block_id(&self, index: usize) -> BlockId {
+        BlockId::new(format!("{}-{:016}", self.upload_id, index))
+    }
+
+    pub(crate) fn block_ids(&self) -> impl Iterator<Item = BlockId> + '_ {
+        (0..self.block_count).map(|index| self.block_id(index))
+    }
+
+    pub(crate) fn push(&mut self, length: u64) {
+        self.block_count += 1;
+        self.offset += length;
+    }
+}
+
+/// Stores the checkpoint of an upload, saved after every staged block.
+pub trait UploadCheckpointStore: Send + Sync {
+    /// The checkpoint of an interrupted upload, if any.
+    fn load(&self) -> azure_core::Result<Option<UploadCheckpoint>>;
+
+    fn save(&self, checkpoint: &UploadCheckpoint) -> azure_core::Result<()>;
+
+    /// Called once the upload is committed.
+    fn clear(&self) -> azure_core::Result<()>;
+}
+
+/// Stores the checkpoint of an upload in a JSON file, e.g. next to the uploaded file.
+#[derive(Debug, Clone)]
+pub struct FileUploadCheckpointStore {
+    path: PathBuf,
+}
+
+impl FileUploadCheckpointStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl UploadCheckpointStore for FileUploadCheckpointStore {
+    fn load(&self) -> azure_core::Result<Option<UploadCheckpoint>> {
+        let checkpoint = match std::fs::read(&self.path) {
+            Ok(checkpoint) => checkpoint,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => {
+                return Err(error).with_context(ErrorKind::Io, || {
+                    format!("failed to read the checkpoint {}", self.path.display())
+                })
+            }
+        };
+        serde_json::from_slice(&checkpoint)
+            .with_context(ErrorKind::DataConversion, || {
+                format!("the checkpoint {} is not valid", self.path.display())
+            })
+            .map(Some)
+    }
+
+    fn save(&self, checkpoint: &UploadCheckpoint) -> azure_core::Result<()> {
+        // the checkpoint is replaced atomically, so that a crash cannot leave it truncated
+        let temporary = self.path.with_extension("tmp");
+        let checkpoint = serde_json::to_vec(checkpoint)?;
+        std::fs::write(&temporary, checkpoint)
+            .and_then(|_| std::fs::rename(&temporary, &self.path))
+            .with_context(ErrorKind::Io, || {
+                format!("failed to write the checkpoint {}", self.path.display())
+            })
+    }
+
+    fn clear(&self) -> azure_core::Result<()> {
+        match std::fs::remove_file(&self.path) {
+            Err(error) if error.kind() != std::io::ErrorKind::NotFound => Err(error)
+                .with_context(ErrorKind::Io, || {
+                    format!("failed to remove the checkpoint {}", self.path.display())
+                }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saves_and_resumes_checkpoints() {
+        let path = std::env::temp_dir().join(format!("checkpoint-{}.json", uuid::Uuid::new_v4()));
+        let store = FileUploadCheckpointStore::new(&path);
+        assert_eq!(store.load().unwrap(), None);
+
+        let mut checkpoint = UploadCheckpoint::new(
+            "https://account.blob.core.windows.net/c/b".to_owned(),
+            Some(8),
+            4,
+        );
+        checkpoint.push(4);
+        checkpoint.push(4);
+        store.save(&checkpoint).unwrap();
+
+        let resumed = store.load().unwrap().unwrap();
+        assert_eq!(resumed, checkpoint);
+        assert_eq!(resumed.offset(), 8);
+        assert_eq!(
+            resumed.block_ids().collect::<Vec<_>>(),
+            vec![checkpoint.block_id(0), checkpoint.block_id(1)]
+        );
+
+        store.clear().unwrap();
+        assert_eq!(store.load().unwrap(), None);
+    }
+
+    #[test]
+    fn rejects_the_checkpoints_of_other_uploads() {
+        let blob_url = "https://account.blob.core.windows.net/c/b";
+        let checkpoint = UploadCheckpoint::new(blob_url.to_owned(), Some(8), 4);
+        assert!(checkpoint.check(blob_url, Some(8)).is_ok());
+        assert!(checkpoint
+            .check("https://account.blob.core.windows.net/c/other", Some(8))
+            .is_err());
+        assert!(checkpoint.check(blob_url, Some(9)).is_err());
+        assert!(checkpoint.check(blob_url, None).is_err());
+    }
+}
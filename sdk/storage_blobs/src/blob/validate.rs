@@ -0,0 +1,109 @@
+use azure_core::ResponseBody;
+use azure_storage::{checksum::Crc64, ConsistencyCRC64, ConsistencyMD5};
+use bytes::Bytes;
+use futures::{ready, Stream};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+enum Checksum {
+    Crc64(ConsistencyCRC64, Crc64),
+    Md5(ConsistencyMD5, md5::Context),
+}
+
+impl Checksum {
+    fn update(&mut self, data: &[u8]) {
+        match self {
+            Checksum::Crc64(_, crc64) => crc64.update(data),
+            Checksum::Md5(_, md5) => md5.consume(data),
+        }
+    }
+
+    fn verify(&self) -> azure_core::Result<()> {
+        match self {
+            Checksum::Crc64(expected, crc64) => expected.verify_crc64(crc64.finish()),
+            Checksum::Md5(expected, md5) => expected.verify_digest(md5.clone().compute().0),
+        }
+    }
+}
+
+/// A response body whose checksum is verified once it has been streamed entirely.
+struct ValidatedBody {
+    body: ResponseBody,
+    checksum: Checksum,
+    done: bool,
+}
+
+impl Stream for ValidatedBody {
+    type Item = azure_core::Result<Bytes>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.done {
+            return Poll::Ready(None);
+        }
+        match ready!(Pin::new(&mut this.body).poll_next(cx)) {
+            Some(Ok(data)) => {
+                this.checksum.update(&data);
+                Poll::Ready(Some(Ok(data)))
+            }
+            Some(Err(error)) => {
+                this.done = true;
+                Poll::Ready(Some(Err(error)))
+            }
+            None => {
+                this.done = true;
+                Poll::Ready(this.checksum.verify().err().map(Err))
+            }
+        }
+    }
+}
+
+/// Wraps `body` so that the last item of the stream is an error if its content does not match
+/// `crc64`, or `md5` if there is no CRC64.
+///
+/// Bodies without any checksum are returned unchanged.
+pub(crate) fn validate(
+    body: ResponseBody,
+    crc64: Option<ConsistencyCRC64>,
+    md5: Option<ConsistencyMD5>,
+) -> ResponseBody {
+    let checksum = match (crc64, md5) {
+        (Some(crc64), _) => Checksum::Crc64(crc64, Crc64::new()),
+        (None, Some(md5)) => Checksum::Md5(md5, md5::Context::new()),
+        (None, None) => return body,
+    };
+    ResponseBody::from_stream(ValidatedBody {
+        body,
+        checksum,
+        done: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::stream;
+
+    fn chunked_body(data: &'static [u8]) -> ResponseBody {
+        let chunks = data
+            .chunks(3)
+            .map(|chunk| Ok(Bytes::from_static(chunk)))
+            .collect::<Vec<_>>();
+        ResponseBody::from_stream(stream::iter(chunks))
+    }
+
+    #[tokio::test]
+    async fn validates_checksums() {
+        let crc64 = ConsistencyCRC64::compute(b"123456789");
+        let body = validate(chunked_body(b"123456789"), Some(crc64.clone()), None);
+        assert_eq!(body.collect().await.unwrap(), "123456789");
+        let body = validate(chunked_body(b"12345678"), Some(crc64), None);
+        assert!(body.collect().await.is_err());
+
+        let md5 = ConsistencyMD5::compute(b"123456789");
+        let body = validate(chunked_body(b"123456789"), None, Some(md5.clone()));
+        assert_eq!(body.collect().await.unwrap(), "123456789");
+        let body = validate(chunked_body(b"023456789"), None, Some(md5));
+        assert!(body.collect().await.is_err());
+    }
+}
@@ -0,0 +1,152 @@
+//! Synchronous clients for the common blob and container operations.
+//!
+//! These clients wrap the asynchronous ones and run their requests with
+//! [`azure_core::blocking::block_on`]. Other operations are available through the wrapped
+//! client, e.g. `block_on(client.as_async().set_blob_tier(tier).into_future())`.
+//!
+//! ```no_run
+//! use azure_storage::prelude::*;
+//! use azure_storage_blobs::{blocking, prelude::*};
+//!
+//! let storage_credentials = StorageCredentials::Anonymous;
+//! let container = blocking::ContainerClient::new(
+//!     BlobServiceClient::new("account", storage_credentials).container_client("container"),
+//! );
+//! for blob in container.list_blobs(None) {
+//!     println!("{}", blob?.name);
+//! }
+//! let content = container.blob_client("file.txt").get_content()?;
+//! # Ok::<(), azure_core::Error>(())
+//! ```
+use crate::{
+    blob::{operations as blob_operations, Blob},
+    container::operations as container_operations,
+    prelude,
+};
+use azure_core::{blocking::block_on, Body};
+use futures::StreamExt;
+
+/// A synchronous [`BlobClient`](prelude::BlobClient).
+#[derive(Debug, Clone)]
+pub struct BlobClient {
+    client: prelude::BlobClient,
+}
+
+impl BlobClient {
+    pub fn new(client: prelude::BlobClient) -> Self {
+        Self { client }
+    }
+
+    /// The wrapped asynchronous client.
+    pub fn as_async(&self) -> &prelude::BlobClient {
+        &self.client
+    }
+
+    /// Return an entire blob.
+    pub fn get_content(&self) -> azure_core::Result<Vec<u8>> {
+        block_on(self.client.get_content())
+    }
+
+    /// Get all user-defined metadata, standard HTTP properties, and system properties for the blob.
+    pub fn get_properties(&self) -> azure_core::Result<blob_operations::GetPropertiesResponse> {
+        block_on(self.client.get_properties().into_future())
+    }
+
+    /// Get the properties of the blob, or `None` if it does not exist.
+    pub fn get_properties_if_exists(
+        &self,
+    ) -> azure_core::Result<Option<blob_operations::GetPropertiesResponse>> {
+        block_on(self.client.get_properties_if_exists())
+    }
+
+    /// Check whether the blob exists.
+    pub fn exists(&self) -> azure_core::Result<bool> {
+        block_on(self.client.exists())
+    }
+
+    /// Creates a new block blob, or update the content of an existing block blob.
+    pub fn put_block_blob(
+        &self,
+        body: impl Into<Body>,
+    ) -> azure_core::Result<blob_operations::PutBlockBlobResponse> {
+        block_on(self.client.put_block_blob(body).into_future())
+    }
+
+    /// Delete the blob.
+    pub fn delete(&self) -> azure_core::Result<blob_operations::DeleteBlobResponse> {
+        block_on(self.client.delete().into_future())
+    }
+}
+
+impl From<prelude::BlobClient> for BlobClient {
+    fn from(client: prelude::BlobClient) -> Self {
+        Self::new(client)
+    }
+}
+
+/// A synchronous [`ContainerClient`](prelude::ContainerClient).
+#[derive(Debug, Clone)]
+pub struct ContainerClient {
+    client: prelude::ContainerClient,
+}
+
+impl ContainerClient {
+    pub fn new(client: prelude::ContainerClient) -> Self {
+        Self { client }
+    }
+
+    /// The wrapped asynchronous client.
+    pub fn as_async(&self) -> &prelude::ContainerClient {
+        &self.client
+    }
+
+    /// Create the container.
+    pub fn create(&self) -> azure_core::Result<()> {
+        block_on(self.client.create().into_future())
+    }
+
+    /// Delete the container.
+    pub fn delete(&self) -> azure_core::Result<()> {
+        block_on(self.client.delete().into_future())
+    }
+
+    /// Get the properties of the container.
+    pub fn get_properties(
+        &self,
+    ) -> azure_core::Result<container_operations::GetPropertiesResponse> {
+        block_on(self.client.get_properties().into_future())
+    }
+
+    /// Check whether the container exists.
+    pub fn exists(&self) -> azure_core::Result<bool> {
+        block_on(self.client.exists())
+    }
+
+    /// List the blobs of the container, optionally only the ones starting with `prefix`.
+    ///
+    /// The pages are requested as the iterator is consumed.
+    pub fn list_blobs(
+        &self,
+        prefix: Option<String>,
+    ) -> impl Iterator<Item = azure_core::Result<Blob>> {
+        let mut builder = self.client.list_blobs();
+        if let Some(prefix) = prefix {
+            builder = builder.prefix(prefix);
+        }
+        let pages = builder.into_stream().map(|page| match page {
+            Ok(page) => page.blobs.blobs.into_iter().map(Ok).collect(),
+            Err(error) => vec![Err(error)],
+        });
+        azure_core::blocking::iter(pages).flatten()
+    }
+
+    pub fn blob_client<BN: Into<String>>(&self, blob_name: BN) -> BlobClient {
+        BlobClient::new(self.client.blob_client(blob_name))
+    }
+}
+
+impl From<prelude::ContainerClient> for ContainerClient {
+    fn from(client: prelude::ContainerClient) -> Self {
+        Self::new(client)
+    }
+}
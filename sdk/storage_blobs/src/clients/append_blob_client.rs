@@ -0,0 +1,48 @@
+use crate::{blob::operations::*, prelude::*};
+use azure_core::Body;
+use url::Url;
+
+/// A client for the operations specific to append blobs, e.g. logs.
+///
+/// The blocks of an append blob can only be appended to its end. The appends can be conditioned
+/// on the length of the blob with `condition_append_position`, so that a writer retrying an
+/// append does not append the block twice, and with `condition_max_size`, so that the blob does
+/// not grow beyond a size, e.g. to roll over to another blob.
+#[derive(Debug, Clone)]
+pub struct AppendBlobClient {
+    blob_client: BlobClient,
+}
+
+impl AppendBlobClient {
+    pub(crate) fn new(blob_client: BlobClient) -> Self {
+        Self { blob_client }
+    }
+
+    /// Creates the append blob, empty.
+    pub fn create(&self) -> PutAppendBlobBuilder {
+        self.blob_client.put_append_blob()
+    }
+
+    /// Appends `body` as a new block.
+    pub fn append_block(&self, body: impl Into<Body>) -> AppendBlockBuilder {
+        self.blob_client.append_block(body)
+    }
+
+    /// Appends the content of the blob at `source_url` as a new block.
+    pub fn append_block_from_url(&self, source_url: Url) -> AppendBlockFromUrlBuilder {
+        AppendBlockFromUrlBuilder::new(self.blob_client.clone(), source_url)
+    }
+
+    /// Seals the blob, so that no block can be appended to it anymore.
+    pub fn seal(&self) -> SealAppendBlobBuilder {
+        SealAppendBlobBuilder::new(self.blob_client.clone())
+    }
+
+    pub fn container_client(&self) -> &ContainerClient {
+        self.blob_client.container_client()
+    }
+
+    pub fn blob_client(&self) -> &BlobClient {
+        &self.blob_client
+    }
+}
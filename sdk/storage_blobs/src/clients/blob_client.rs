@@ -1,5 +1,6 @@
 use crate::{
-    blob::operations::*,
+    blob::{operations::*, CopyBlobPoller},
+    clients::none_if_not_found,
     options::{BA512Range, Snapshot, Tags},
     prelude::*,
 };
@@ -7,15 +8,16 @@ use azure_core::{
     error::{Error, ErrorKind},
     headers::Headers,
     prelude::*,
-    Body, Method, Request, Response, StatusCode,
+    Body, Method, Request, Response,
 };
 use azure_storage::{
     clients::StorageCredentials,
     prelude::*,
     shared_access_signature::{
         service_sas::{BlobSharedAccessSignature, BlobSignedResource},
-        SasToken,
+        SasCredentialsError, SasToken,
     },
+    CopyId,
 };
 use futures::StreamExt;
 use time::OffsetDateTime;
@@ -54,12 +56,24 @@ impl BlobClient {
         // than restarting the whole blob on a failure.
         let mut stream = self.get().into_stream();
         while let Some(value) = stream.next().await {
-            let data = value?.data.collect().await?;
-            blob.extend(&data);
+            let mut response = value?;
+            blob.reserve(response.blob.properties.content_length as usize);
+            // copy the chunk as it is received, rather than collecting it first
+            while let Some(data) = response.data.next().await {
+                blob.extend_from_slice(&data?);
+            }
         }
         Ok(blob)
     }
 
+    /// Download a blob to a local file, in ranges downloaded in parallel.
+    pub fn download_to_file(
+        &self,
+        path: impl Into<std::path::PathBuf>,
+    ) -> DownloadBlobToFileBuilder {
+        DownloadBlobToFileBuilder::new(self.clone(), path.into())
+    }
+
     /// Get all user-defined metadata, standard HTTP properties, and system properties for the blob.
     pub fn get_properties(&self) -> GetPropertiesBuilder {
         GetPropertiesBuilder::new(self.clone())
@@ -113,6 +127,20 @@ impl BlobClient {
         PutBlockBlobBuilder::new(self.clone(), body.into())
     }
 
+    /// Uploads the content of a reader to a block blob, in blocks uploaded in parallel.
+    pub fn upload<R>(&self, reader: R) -> UploadBlockBlobBuilder<R>
+    where
+        R: futures::io::AsyncRead + Unpin + Send + 'static,
+    {
+        UploadBlockBlobBuilder::new(self.clone(), reader)
+    }
+
+    /// Creates a new block blob, or replaces the blob, with the content read from a URL, e.g.
+    /// another blob. Unlike a copy, the blob is written before the response is returned.
+    pub fn put_blob_from_url(&self, source_url: Url) -> PutBlobFromUrlBuilder {
+        PutBlobFromUrlBuilder::new(self.clone(), source_url)
+    }
+
     /// Copy the blob to a destination within the storage account.
     pub fn copy(&self, copy_source: Url) -> CopyBlobBuilder {
         CopyBlobBuilder::new(self.clone(), copy_source)
@@ -123,7 +151,40 @@ impl BlobClient {
         CopyBlobFromUrlBuilder::new(self.clone(), copy_source)
     }
 
+    /// Copy a blob of up to 256 MiB to this blob synchronously: the copy is completed when the
+    /// response is returned. The source can be restricted with the `if_source_*` conditions.
+    pub fn copy_blob_from_url(&self, copy_source: Url) -> CopyBlobFromUrlBuilder {
+        self.copy_from_url(copy_source).is_synchronous(true)
+    }
+
+    /// Start an asynchronous copy of a blob or file to this blob, and return a poller tracking
+    /// it until it completes.
+    ///
+    /// The options of [`copy`](Self::copy) are set by starting the copy with it, then creating
+    /// the poller with [`CopyBlobPoller::new`].
+    pub async fn start_copy_from_url(
+        &self,
+        copy_source: Url,
+    ) -> azure_core::Result<CopyBlobPoller> {
+        let response = self.copy(copy_source).into_future().await?;
+        Ok(CopyBlobPoller::new(self.clone(), &response))
+    }
+
+    /// Abort a pending asynchronous copy to this blob, leaving it empty.
+    pub fn abort_copy(&self, copy_id: CopyId) -> AbortCopyBuilder {
+        AbortCopyBuilder::new(self.clone(), copy_id)
+    }
+
+    /// Copy a blob of another storage account to this blob, authorizing the read of the source
+    /// blob with a short-lived SAS.
+    pub fn copy_blob_across_accounts(&self, source: BlobClient) -> CopyBlobAcrossAccountsBuilder {
+        CopyBlobAcrossAccountsBuilder::new(self.clone(), source)
+    }
+
     /// Create a lease on the blob to lock for write and delete operations.
+    ///
+    /// The operations on the leased blob are sent with the lease id by the `BlobLeaseClient` of
+    /// the acquired lease, see [`blob_lease_client`](Self::blob_lease_client).
     pub fn acquire_lease<LD: Into<LeaseDuration>>(
         &self,
         lease_duration: LD,
@@ -151,6 +212,40 @@ impl BlobClient {
         DeleteBlobVersionBuilder::new(self.clone(), version_id)
     }
 
+    /// List the versions of the blob, oldest first, for accounts with blob versioning enabled.
+    ///
+    /// The current version has `is_current_version` set. A specific version is read, or its
+    /// tier set, with the `blob_versioning` option of the operations.
+    pub async fn list_versions(&self) -> azure_core::Result<Vec<Blob>> {
+        let mut versions = Vec::new();
+        let mut pages = self
+            .container_client
+            .list_blobs()
+            .prefix(self.blob_name.clone())
+            .include_versions(true)
+            .into_stream();
+        while let Some(page) = pages.next().await {
+            versions.extend(
+                page?
+                    .blobs
+                    .blobs
+                    .into_iter()
+                    .filter(|blob| blob.name == self.blob_name),
+            );
+        }
+        Ok(versions)
+    }
+
+    /// Make a previous version of the blob its current version, by copying it over the blob.
+    ///
+    /// The copy creates a new version with the content, properties and metadata of the copied
+    /// one. The copy of a version of the same blob is completed synchronously.
+    pub fn promote_version(&self, version_id: VersionId) -> azure_core::Result<CopyBlobBuilder> {
+        let mut source_url = self.url()?;
+        version_id.append_to_url_query(&mut source_url);
+        Ok(self.copy(source_url))
+    }
+
     /* Operations specific to certain blob types */
 
     /// Creates a new block to be committed as part of a block blob.
@@ -162,6 +257,16 @@ impl BlobClient {
         PutBlockBuilder::new(self.clone(), block_id.into(), body.into())
     }
 
+    /// Creates a new block to be committed as part of a block blob, reading its content from a
+    /// URL, e.g. a range of another blob.
+    pub fn put_block_from_url(
+        &self,
+        block_id: impl Into<BlockId>,
+        source_url: Url,
+    ) -> PutBlockFromUrlBuilder {
+        PutBlockFromUrlBuilder::new(self.clone(), block_id.into(), source_url)
+    }
+
     /// Retrieve the list of blocks that have been uploaded as part of a block blob.
     pub fn get_block_list(&self) -> GetBlockListBuilder {
         GetBlockListBuilder::new(self.clone())
@@ -204,28 +309,78 @@ impl BlobClient {
         ClearPageBuilder::new(self.clone(), ba512_range)
     }
 
-    /// Create a shared access signature.
-    pub fn shared_access_signature(
+    /// Create a shared access signature with the credentials of the client: signed with the
+    /// account key or, for clients authorized with Azure Active Directory, a user delegation
+    /// shared access signature signed with the
+    /// [cached user delegation key](BlobServiceClient::cached_user_delegation_key) of the service
+    /// client.
+    ///
+    /// Fails with a [`SasCredentialsError`] if the client has neither, e.g. when it is
+    /// authorized with a shared access signature.
+    pub async fn shared_access_signature(
         &self,
         permissions: BlobSasPermissions,
         expiry: OffsetDateTime,
     ) -> azure_core::Result<BlobSharedAccessSignature> {
         match self.container_client.credentials() {
             StorageCredentials::Key(account, ref key) => {
+                let canonicalized_resource = format!(
+                    "/blob/{}/{}/{}",
+                    account,
+                    self.container_client.container_name(),
+                    self.blob_name()
+                );
+                Ok(BlobSharedAccessSignature::new(
+                    key.to_string(),
+                    canonicalized_resource,
+                    permissions,
+                    expiry,
+                    BlobSignedResource::Blob,
+                ))
+            }
+            StorageCredentials::TokenCredential(_) | StorageCredentials::BearerToken(_) => {
+                let user_delegation_key = self
+                    .container_client
+                    .service_client()
+                    .cached_user_delegation_key(expiry)
+                    .await?;
+                self.user_delegation_shared_access_signature(
+                    permissions,
+                    expiry,
+                    user_delegation_key,
+                )
+            }
+            credentials => Err(SasCredentialsError::from(credentials).into()),
+        }
+    }
 
+    /// Create a user delegation shared access signature, signed with a key obtained with
+    /// [`BlobServiceClient::get_user_delegation_key`].
+    pub fn user_delegation_shared_access_signature(
+        &self,
+        permissions: BlobSasPermissions,
+        expiry: OffsetDateTime,
+        user_delegation_key: UserDelegationKey,
+    ) -> azure_core::Result<BlobSharedAccessSignature> {
+        let account = self.container_client.service_client().account().ok_or_else(|| {
+            Error::message(
+                ErrorKind::Other,
+                "Shared access signature generation - the account name of a custom location is unknown",
+            )
+        })?;
         let canonicalized_resource = format!(
             "/blob/{}/{}/{}",
             account,
             self.container_client.container_name(),
             self.blob_name()
         );
-                Ok(
-                BlobSharedAccessSignature::new(key.to_string(), canonicalized_resource, permissions, expiry, BlobSignedResource::Blob)
-            )},
-            _ => Err(Error::message(ErrorKind::Credential,
-                "Shared access signature generation - SAS can be generated only from key and account clients",
-            )),
-        }
+        Ok(BlobSharedAccessSignature::with_user_delegation_key(
+            user_delegation_key,
+            canonicalized_resource,
+            permissions,
+            expiry,
+            BlobSignedResource::Blob,
+        ))
     }
 
     /// Create a signed blob url
@@ -238,17 +393,16 @@ impl BlobClient {
         Ok(url)
     }
 
+    /// Get the properties of the blob, or `None` if the blob does not exist.
+    pub async fn get_properties_if_exists(
+        &self,
+    ) -> azure_core::Result<Option<GetPropertiesResponse>> {
+        none_if_not_found(self.get_properties().into_future().await)
+    }
+
     /// Check whether blob exists.
     pub async fn exists(&self) -> azure_core::Result<bool> {
-        let result = self.get_properties().into_future().await.map(|_| true);
-        if let Err(err) = result {
-            if let ErrorKind::HttpResponse { status, .. } = err.kind() {
-                return Ok(status != &StatusCode::NotFound);
-            } else {
-                return Err(err);
-            }
-        }
-        result
+        Ok(self.get_properties_if_exists().await?.is_some())
     }
 
     /// Create a blob snapshot
@@ -261,10 +415,68 @@ impl BlobClient {
     }
 
     /// Turn into a `BlobLeaseClient`
+    ///
+    /// ```no_run
+    /// # async fn run(blob_client: azure_storage_blobs::prelude::BlobClient) -> azure_core::Result<()> {
+    /// let lease = blob_client.acquire_lease(std::time::Duration::from_secs(60)).into_future().await?;
+    /// let lease_client = blob_client.blob_lease_client(lease.lease_id);
+    /// lease_client.put_block_blob("content").into_future().await?;
+    /// lease_client.release().into_future().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn blob_lease_client(&self, lease_id: LeaseId) -> BlobLeaseClient {
         BlobLeaseClient::new(self.clone(), lease_id)
     }
 
+    /// Turn into a `PageBlobClient`
+    ///
+    /// ```no_run
+    /// # async fn run(blob_client: azure_storage_blobs::prelude::BlobClient) -> azure_core::Result<()> {
+    /// use azure_storage_blobs::prelude::*;
+    ///
+    /// let page_blob_client = blob_client.page_blob_client();
+    /// page_blob_client.create(1024).into_future().await?;
+    /// let range = BA512Range::new(0, 511)?;
+    /// page_blob_client
+    ///     .upload_pages(range, vec![0u8; 512])
+    ///     .into_future()
+    ///     .await?;
+    /// page_blob_client
+    ///     .update_sequence_number(SequenceNumberAction::Increment)
+    ///     .into_future()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn page_blob_client(&self) -> PageBlobClient {
+        PageBlobClient::new(self.clone())
+    }
+
+    /// Turn into an `AppendBlobClient`
+    ///
+    /// ```no_run
+    /// # async fn run(blob_client: azure_storage_blobs::prelude::BlobClient) -> azure_core::Result<()> {
+    /// let append_blob_client = blob_client.append_blob_client();
+    /// append_blob_client.create().into_future().await?;
+    /// let appended = append_blob_client
+    ///     .append_block("first line\n")
+    ///     .condition_append_position(0u64)
+    ///     .into_future()
+    ///     .await?;
+    /// append_blob_client
+    ///     .append_block("second line\n")
+    ///     .condition_append_position(appended.blob_append_offset + 11)
+    ///     .into_future()
+    ///     .await?;
+    /// append_blob_client.seal().into_future().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn append_blob_client(&self) -> AppendBlobClient {
+        AppendBlobClient::new(self.clone())
+    }
+
     pub fn container_client(&self) -> &ContainerClient {
         &self.container_client
     }
@@ -302,6 +514,9 @@ impl BlobClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tests::{blob_properties_headers, mock_blob_client, response_headers};
+    use azure_core::{headers::LEASE_ID, mock::MockTransportCannedPolicy, StatusCode};
+    use std::sync::Arc;
 
     struct FakeSas {
         token: String,
@@ -339,4 +554,60 @@ mod tests {
             "http://127.0.0.1:10000/devstoreaccount1/a/b/c/d?fake_token"
         );
     }
+
+    #[tokio::test]
+    async fn the_lease_client_sends_the_lease_id() {
+        let written = || {
+            response_headers(&[
+                ("etag", "\"0x8D9\""),
+                ("last-modified", "Thu, 15 Oct 2026 10:00:00 GMT"),
+                ("x-ms-request-server-encrypted", "true"),
+                ("server", "Windows-Azure-Blob/1.0"),
+            ])
+        };
+        let transport = Arc::new(
+            MockTransportCannedPolicy::new()
+                .reply(StatusCode::Created, written(), "")
+                .reply(StatusCode::Ok, blob_properties_headers(&[]), "")
+                .reply(StatusCode::Ok, written(), "")
+                .reply(
+                    StatusCode::Accepted,
+                    response_headers(&[
+                        ("etag", "\"0x8D9\""),
+                        ("last-modified", "Thu, 15 Oct 2026 10:00:00 GMT"),
+                        ("x-ms-lease-time", "0"),
+                    ]),
+                    "",
+                )
+                .reply(
+                    StatusCode::Accepted,
+                    response_headers(&[("x-ms-delete-type-permanent", "true")]),
+                    "",
+                ),
+        );
+        let lease_id = LeaseId::new();
+        let lease_client = mock_blob_client(transport.clone()).blob_lease_client(lease_id);
+
+        lease_client
+            .put_block_blob("content")
+            .into_future()
+            .await
+            .unwrap();
+        lease_client.get_properties().into_future().await.unwrap();
+        lease_client.set_metadata().into_future().await.unwrap();
+        lease_client.break_lease().into_future().await.unwrap();
+        lease_client.delete().into_future().await.unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 5);
+        for request in requests {
+            assert_eq!(
+                request.headers().get_optional_string(&LEASE_ID),
+                Some(lease_id.to_string()),
+                "{} {} without the lease id",
+                request.method(),
+                request.url()
+            );
+        }
+    }
 }
@@ -1,6 +1,10 @@
 use crate::{blob::operations::*, prelude::*};
 use azure_core::{headers::Headers, prelude::*, Body, Context, Method, Request, Response, Url};
 
+/// A client for a blob leased with a lease id.
+///
+/// The operations of this client which a lease restricts, the writes and deletes of the blob and
+/// the reads which can be conditioned on it, are sent with the lease id.
 #[derive(Debug, Clone)]
 pub struct BlobLeaseClient {
     blob_client: BlobClient,
@@ -15,18 +19,97 @@ impl BlobLeaseClient {
         }
     }
 
+    /// Change the id of the lease. The lease client of the new id is
+    /// `blob_client().blob_lease_client(proposed_lease_id)`.
     pub fn change(&self, proposed_lease_id: ProposedLeaseId) -> ChangeLeaseBuilder {
         ChangeLeaseBuilder::new(self.clone(), proposed_lease_id)
     }
 
+    /// Release the lease, so that another client can acquire a lease on the blob immediately.
     pub fn release(&self) -> ReleaseLeaseBuilder {
         ReleaseLeaseBuilder::new(self.clone())
     }
 
+    /// Renew the lease, restarting its duration.
     pub fn renew(&self) -> RenewLeaseBuilder {
         RenewLeaseBuilder::new(self.clone())
     }
 
+    /// End the lease but ensure that another client cannot acquire a new lease until the current lease period has expired.
+    pub fn break_lease(&self) -> BreakLeaseBuilder {
+        self.blob_client.break_lease().lease_id(self.lease_id)
+    }
+
+    /// Stream the leased blob in chunks.
+    pub fn get(&self) -> GetBlobBuilder {
+        self.blob_client.get().lease_id(self.lease_id)
+    }
+
+    /// Get all user-defined metadata, standard HTTP properties, and system properties for the leased blob.
+    pub fn get_properties(&self) -> GetPropertiesBuilder {
+        self.blob_client.get_properties().lease_id(self.lease_id)
+    }
+
+    /// Set the properties of the leased blob.
+    pub fn set_properties(&self) -> SetPropertiesBuilder {
+        self.blob_client.set_properties().lease_id(self.lease_id)
+    }
+
+    /// Get all user-defined metadata of the leased blob.
+    pub fn get_metadata(&self) -> GetMetadataBuilder {
+        self.blob_client.get_metadata().lease_id(self.lease_id)
+    }
+
+    /// Set all user-defined metadata of the leased blob.
+    pub fn set_metadata(&self) -> SetMetadataBuilder {
+        self.blob_client.set_metadata().lease_id(self.lease_id)
+    }
+
+    /// Retrieve the user-defined tags of the leased blob.
+    pub fn get_tags(&self) -> GetTagsBuilder {
+        self.blob_client.get_tags().lease_id(self.lease_id)
+    }
+
+    /// Set the user-defined tags of the leased blob.
+    pub fn set_tags(&self, tags: impl Into<Tags>) -> SetTagsBuilder {
+        self.blob_client.set_tags(tags).lease_id(self.lease_id)
+    }
+
+    /// Creates a snapshot of the leased blob.
+    pub fn snapshot(&self) -> SnapshotBlobBuilder {
+        self.blob_client.snapshot().lease_id(self.lease_id)
+    }
+
+    /// Update the content of the leased block blob.
+    pub fn put_block_blob(&self, body: impl Into<Body>) -> PutBlockBlobBuilder {
+        self.blob_client
+            .put_block_blob(body)
+            .lease_id(self.lease_id)
+    }
+
+    /// Creates a new block to be committed as part of the leased block blob.
+    pub fn put_block(
+        &self,
+        block_id: impl Into<BlockId>,
+        body: impl Into<Body>,
+    ) -> PutBlockBuilder {
+        self.blob_client
+            .put_block(block_id, body)
+            .lease_id(self.lease_id)
+    }
+
+    /// Write the leased block blob by specifying the list of block IDs that make up the blob.
+    pub fn put_block_list(&self, block_list: BlockList) -> PutBlockListBuilder {
+        self.blob_client
+            .put_block_list(block_list)
+            .lease_id(self.lease_id)
+    }
+
+    /// Delete the leased blob.
+    pub fn delete(&self) -> DeleteBlobBuilder {
+        self.blob_client.delete().lease_id(self.lease_id)
+    }
+
     pub fn lease_id(&self) -> LeaseId {
         self.lease_id
     }
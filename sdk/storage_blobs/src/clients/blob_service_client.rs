@@ -1,19 +1,49 @@
 use crate::service::operations::*;
 use azure_core::{
-    headers::Headers, Body, ClientOptions, Context, Method, Pipeline, Request, Response, Url,
+    error::{Error, ErrorKind},
+    headers::Headers,
+    Body, CircuitBreakerOptions, ClientOptions, Context, HealthReport, Method, Pipeline, Request,
+    Response, Url,
 };
 use azure_storage::{
+    audit::{AuditHook, AuditPolicy},
+    circuit_breaker::{server_busy_circuit_breaker, ServerBusyCircuitBreakerPolicy},
     clients::{
-        new_pipeline_from_options, shared_access_signature, ServiceType, StorageCredentials,
+        new_pipeline_from_options, shared_access_signature, ServiceType, StorageClient,
+        StorageCredentials,
+    },
+    prelude::{
+        AccountSasPermissions, AccountSasResource, AccountSasResourceType, UserDelegationKey,
     },
-    prelude::{AccountSasPermissions, AccountSasResource, AccountSasResourceType},
     shared_access_signature::account_sas::AccountSharedAccessSignature,
     CloudLocation,
 };
-use time::OffsetDateTime;
+use futures::lock::Mutex;
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
 
 use super::ContainerClient;
 
+/// User delegation keys start this long before they are requested, so that the signatures are
+/// valid on servers whose clock is behind.
+const USER_DELEGATION_KEY_CLOCK_SKEW: Duration = Duration::minutes(5);
+/// The shortest lifetime of a requested user delegation key, so that it signs the next
+/// signatures as well.
+const USER_DELEGATION_KEY_MIN_LIFETIME: Duration = Duration::hours(1);
+/// The longest lifetime of a user delegation key accepted by the service.
+const USER_DELEGATION_KEY_MAX_LIFETIME: Duration = Duration::days(7);
+
+pub trait AsBlobServiceClient {
+    fn blob_service_client(&self) -> BlobServiceClient;
+}
+
+impl AsBlobServiceClient for StorageClient {
+    /// Create a blob service client for the account and the credentials of the storage client.
+    fn blob_service_client(&self) -> BlobServiceClient {
+        BlobServiceClientBuilder::with_location(self.cloud_location(ServiceType::Blob)).build()
+    }
+}
+
 /// A builder for the blob service client.
 #[derive(Debug, Clone)]
 pub struct BlobServiceClientBuilder {
@@ -56,6 +86,7 @@ impl BlobServiceClientBuilder {
         BlobServiceClient {
             pipeline: new_pipeline_from_options(self.options, credentials.clone()),
             cloud_location: self.cloud_location,
+            user_delegation_key: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -66,6 +97,21 @@ impl BlobServiceClientBuilder {
         self
     }
 
+    /// Set the endpoint of the service, e.g. a private endpoint of the account, keeping the
+    /// credentials.
+    #[must_use]
+    pub fn endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.cloud_location = self.cloud_location.with_endpoint(endpoint);
+        self
+    }
+
+    /// Set the credentials, keeping the endpoint.
+    #[must_use]
+    pub fn credential(mut self, credentials: impl Into<StorageCredentials>) -> Self {
+        self.cloud_location = self.cloud_location.with_credentials(credentials.into());
+        self
+    }
+
     /// Set the retry options.
     #[must_use]
     pub fn retry(mut self, retry: impl Into<azure_core::RetryOptions>) -> Self {
@@ -80,6 +126,26 @@ impl BlobServiceClientBuilder {
         self
     }
 
+    /// Call `hook` before each request is sent and after its response is received, e.g. to keep
+    /// an audit trail of the accesses to the data.
+    #[must_use]
+    pub fn audit_hook(mut self, hook: Arc<dyn AuditHook>) -> Self {
+        self.options
+            .per_retry_policies_mut()
+            .push(Arc::new(AuditPolicy::new(hook)));
+        self
+    }
+
+    /// Fail fast the requests to an endpoint which keeps answering that it is busy, instead of
+    /// retrying them. See [`ServerBusyCircuitBreakerPolicy`].
+    #[must_use]
+    pub fn circuit_breaker(mut self, options: CircuitBreakerOptions) -> Self {
+        self.options
+            .per_retry_policies_mut()
+            .push(Arc::new(server_busy_circuit_breaker(options)));
+        self
+    }
+
     /// Override all of the client options.
     ///
     /// *Warning!*: This overrides all client options that have been previously set on this builder.
@@ -94,6 +160,8 @@ impl BlobServiceClientBuilder {
 pub struct BlobServiceClient {
     pipeline: Pipeline,
     cloud_location: CloudLocation,
+    /// The key signing the user delegation shared access signatures, shared by the clones.
+    user_delegation_key: Arc<Mutex<Option<UserDelegationKey>>>,
 }
 
 impl BlobServiceClient {
@@ -116,6 +184,13 @@ impl BlobServiceClient {
         GetAccountInformationBuilder::new(self.clone())
     }
 
+    /// Checks that the account can be reached with the credentials of the client, e.g. for a
+    /// readiness probe, with a Get Account Information request.
+    pub async fn health_check(&self) -> HealthReport {
+        let endpoint = self.url().map(String::from).unwrap_or_default();
+        HealthReport::check(endpoint, self.get_account_information().into_future()).await
+    }
+
     pub fn find_blobs_by_tags(&self, expression: String) -> FindBlobsByTagsBuilder {
         FindBlobsByTagsBuilder::new(self.clone(), expression)
     }
@@ -124,6 +199,50 @@ impl BlobServiceClient {
         ListContainersBuilder::new(self.clone())
     }
 
+    /// Get a key to sign user delegation shared access signatures.
+    ///
+    /// The client must be authorized with Azure Active Directory credentials.
+    pub fn get_user_delegation_key(
+        &self,
+        start: OffsetDateTime,
+        expiry: OffsetDateTime,
+    ) -> GetUserDelegationKeyBuilder {
+        GetUserDelegationKeyBuilder::new(self.clone(), start, expiry)
+    }
+
+    /// Get a key to sign the user delegation shared access signatures expiring at `expiry`.
+    ///
+    /// The key is cached, and shared with the container and blob clients of this client, until
+    /// it expires before a signature. A new key starts a few minutes in the past, to allow for
+    /// clock skew, and lasts at least an hour. User delegation keys expire within seven days.
+    pub async fn cached_user_delegation_key(
+        &self,
+        expiry: OffsetDateTime,
+    ) -> azure_core::Result<UserDelegationKey> {
+        let mut cached = self.user_delegation_key.lock().await;
+        if let Some(key) = cached.as_ref().filter(|key| key.signed_expiry >= expiry) {
+            return Ok(key.clone());
+        }
+
+        let now = OffsetDateTime::now_utc();
+        if expiry > now + USER_DELEGATION_KEY_MAX_LIFETIME {
+            return Err(Error::message(
+                ErrorKind::Other,
+                "user delegation shared access signatures expire within seven days",
+            ));
+        }
+        let key_expiry = expiry
+            .max(now + USER_DELEGATION_KEY_MIN_LIFETIME)
+            .min(now + USER_DELEGATION_KEY_MAX_LIFETIME);
+        let key = self
+            .get_user_delegation_key(now - USER_DELEGATION_KEY_CLOCK_SKEW, key_expiry)
+            .into_future()
+            .await?
+            .user_delegation_key;
+        *cached = Some(key.clone());
+        Ok(key)
+    }
+
     pub fn url(&self) -> azure_core::Result<url::Url> {
         self.cloud_location.url(ServiceType::Blob)
     }
@@ -147,10 +266,22 @@ impl BlobServiceClient {
         )
     }
 
+    /// Create a storage client for the account, e.g. to use the queue or table services with the
+    /// same credentials.
+    ///
+    /// Fails if the client was built with a custom URL.
+    pub fn storage_client(&self) -> azure_core::Result<StorageClient> {
+        StorageClient::from_cloud_location(&self.cloud_location)
+    }
+
     pub(crate) fn credentials(&self) -> &StorageCredentials {
         self.cloud_location.credentials()
     }
 
+    pub(crate) fn account(&self) -> Option<&str> {
+        self.cloud_location.account()
+    }
+
     pub(crate) fn finalize_request(
         &self,
         url: Url,
@@ -7,10 +7,10 @@ use azure_core::{
 };
 use azure_storage::{
     clients::StorageCredentials,
-    prelude::BlobSasPermissions,
+    prelude::{BlobSasPermissions, UserDelegationKey},
     shared_access_signature::{
         service_sas::{BlobSharedAccessSignature, BlobSignedResource},
-        SasToken,
+        SasCredentialsError, SasToken,
     },
 };
 use time::OffsetDateTime;
@@ -49,6 +49,23 @@ impl ContainerClient {
         GetPropertiesBuilder::new(self.clone())
     }
 
+    /// Replace all the user-defined metadata of the container.
+    pub fn set_metadata(&self, metadata: Metadata) -> SetMetadataBuilder {
+        SetMetadataBuilder::new(self.clone(), metadata)
+    }
+
+    /// Get the properties of the container, or `None` if the container does not exist.
+    pub async fn get_properties_if_exists(
+        &self,
+    ) -> azure_core::Result<Option<GetPropertiesResponse>> {
+        none_if_not_found(self.get_properties().into_future().await)
+    }
+
+    /// Check whether the container exists.
+    pub async fn exists(&self) -> azure_core::Result<bool> {
+        Ok(self.get_properties_if_exists().await?.is_some())
+    }
+
     pub fn list_blobs(&self) -> ListBlobsBuilder {
         ListBlobsBuilder::new(self.clone())
     }
@@ -80,32 +97,73 @@ impl ContainerClient {
         self.service_client.credentials()
     }
 
-    /// Create a shared access signature.
-    pub fn shared_access_signature(
+    pub(crate) fn service_client(&self) -> &BlobServiceClient {
+        &self.service_client
+    }
+
+    /// Create a shared access signature of the container with the credentials of the client:
+    /// signed with the account key or, for clients authorized with Azure Active Directory, a
+    /// user delegation shared access signature signed with the
+    /// [cached user delegation key](BlobServiceClient::cached_user_delegation_key) of the service
+    /// client.
+    ///
+    /// Fails with a [`SasCredentialsError`] if the client has neither, e.g. when it is
+    /// authorized with a shared access signature.
+    pub async fn shared_access_signature(
         &self,
         permissions: BlobSasPermissions,
         expiry: OffsetDateTime,
     ) -> azure_core::Result<BlobSharedAccessSignature> {
         match self.service_client.credentials() {
             StorageCredentials::Key(account, ref key) => {
-                let canonicalized_resource =
-                    format!("/blob/{}/{}", account, self.container_name(),);
+                let canonicalized_resource = format!("/blob/{}/{}", account, self.container_name());
                 Ok(BlobSharedAccessSignature::new(
                     key.to_string(),
                     canonicalized_resource,
                     permissions,
                     expiry,
-                    BlobSignedResource::Blob,
+                    BlobSignedResource::Container,
                 ))
             }
-            _ => Err(Error::message(
-                ErrorKind::Credential,
-                "Shared access signature generation - \
-                SAS can be generated only from key and account clients",
-            )),
+            StorageCredentials::TokenCredential(_) | StorageCredentials::BearerToken(_) => {
+                let user_delegation_key = self
+                    .service_client
+                    .cached_user_delegation_key(expiry)
+                    .await?;
+                self.user_delegation_shared_access_signature(
+                    permissions,
+                    expiry,
+                    user_delegation_key,
+                )
+            }
+            credentials => Err(SasCredentialsError::from(credentials).into()),
         }
     }
 
+    /// Create a user delegation shared access signature of the container, signed with a key
+    /// obtained with [`BlobServiceClient::get_user_delegation_key`].
+    pub fn user_delegation_shared_access_signature(
+        &self,
+        permissions: BlobSasPermissions,
+        expiry: OffsetDateTime,
+        user_delegation_key: UserDelegationKey,
+    ) -> azure_core::Result<BlobSharedAccessSignature> {
+        let account = self.service_client.account().ok_or_else(|| {
+            Error::message(
+                ErrorKind::Other,
+                "Shared access signature generation - the account name of a custom location is unknown",
+            )
+        })?;
+        let canonicalized_resource = format!("/blob/{}/{}", account, self.container_name());
+        Ok(BlobSharedAccessSignature::with_user_delegation_key(
+            user_delegation_key,
+            canonicalized_resource,
+            permissions,
+            expiry,
+            BlobSignedResource::Container,
+        ))
+    }
+
     pub fn generate_signed_container_url<T>(&self, signature: &T) -> azure_core::Result<url::Url>
     where
         T: SasToken,
@@ -151,6 +209,108 @@ impl ContainerClient {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::response_headers;
+    use azure_core::{date, mock::MockTransportCannedPolicy, StatusCode, TransportOptions};
+    use std::sync::Arc;
+
+    const USER_DELEGATION_KEY: &str = "<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+        <UserDelegationKey>\
+        <SignedOid>oid</SignedOid>\
+        <SignedTid>tid</SignedTid>\
+        <SignedStart>2026-10-15T09:55:00Z</SignedStart>\
+        <SignedExpiry>2099-01-01T00:00:00Z</SignedExpiry>\
+        <SignedService>b</SignedService>\
+        <SignedVersion>2020-06-12</SignedVersion>\
+        <Value>a2V5</Value>\
+        </UserDelegationKey>";
+
+    fn container_client(
+        credentials: StorageCredentials,
+        transport: Arc<MockTransportCannedPolicy>,
+    ) -> ContainerClient {
+        BlobServiceClientBuilder::new("account", credentials)
+            .transport(TransportOptions::new_custom_policy(transport))
+            .build()
+            .container_client("container")
+    }
+
+    fn permissions() -> BlobSasPermissions {
+        BlobSasPermissions {
+            read: true,
+            list: true,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn signs_with_the_account_key() {
+        let transport = Arc::new(MockTransportCannedPolicy::new());
+        let container = container_client(
+            StorageCredentials::access_key("account", "a2V5"),
+            transport.clone(),
+        );
+        let expiry = OffsetDateTime::now_utc() + time::Duration::hours(1);
+
+        let token = container
+            .shared_access_signature(permissions(), expiry)
+            .await
+            .unwrap()
+            .token();
+        assert!(token.contains("sr=c"));
+        assert!(!token.contains("skoid"));
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn signs_with_a_cached_user_delegation_key() {
+        let transport = Arc::new(MockTransportCannedPolicy::new().reply(
+            StatusCode::Ok,
+            response_headers(&[
+                ("x-ms-version", "2020-06-12"),
+                ("server", "Windows-Azure-Blob/1.0"),
+            ]),
+            USER_DELEGATION_KEY,
+        ));
+        let container =
+            container_client(StorageCredentials::bearer_token("token"), transport.clone());
+        let now = OffsetDateTime::now_utc();
+        let expiry = now + time::Duration::hours(1);
+
+        let container_token = container
+            .shared_access_signature(permissions(), expiry)
+            .await
+            .unwrap()
+            .token();
+        assert!(container_token.contains("sr=c"));
+        assert!(container_token.contains("skoid=oid"));
+        let blob_token = container
+            .blob_client("blob")
+            .shared_access_signature(permissions(), expiry)
+            .await
+            .unwrap()
+            .token();
+        assert!(blob_token.contains("sr=b"));
+        assert!(blob_token.contains("skoid=oid"));
+
+        // the key is requested once, starting before now to allow for clock skew
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        let body = match requests[0].body() {
+            Body::Bytes(bytes) => String::from_utf8(bytes.to_vec()).unwrap(),
+            Body::SeekableStream(_) => panic!("the key info is not streamed"),
+        };
+        let start = body
+            .split_once("<Start>")
+            .and_then(|(_, rest)| rest.split_once("</Start>"))
+            .map(|(start, _)| date::parse_rfc3339(start).unwrap())
+            .unwrap();
+        assert!(start <= now - time::Duration::minutes(4));
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "test_integration")]
 mod integration_tests {
@@ -23,6 +23,37 @@ impl ContainerLeaseClient {
         RenewLeaseBuilder::new(self.clone())
     }
 
+    /// Get the properties of the leased container.
+    pub fn get_properties(&self) -> GetPropertiesBuilder {
+        self.container_client
+            .get_properties()
+            .lease_id(self.lease_id)
+    }
+
+    /// Replace all the user-defined metadata of the leased container.
+    pub fn set_metadata(&self, metadata: Metadata) -> SetMetadataBuilder {
+        self.container_client
+            .set_metadata(metadata)
+            .lease_id(self.lease_id)
+    }
+
+    /// Get the access policies of the leased container.
+    pub fn get_acl(&self) -> GetACLBuilder {
+        self.container_client.get_acl().lease_id(self.lease_id)
+    }
+
+    /// Set the public access and the access policies of the leased container.
+    pub fn set_acl(&self, public_access: PublicAccess) -> SetACLBuilder {
+        self.container_client
+            .set_acl(public_access)
+            .lease_id(self.lease_id)
+    }
+
+    /// Delete the leased container.
+    pub fn delete(&self) -> DeleteBuilder {
+        self.container_client.delete().lease_id(self.lease_id)
+    }
+
     pub(crate) fn finalize_request(
         &self,
         url: Url,
@@ -1,11 +1,48 @@
+mod append_blob_client;
 mod blob_client;
 mod blob_lease_client;
 mod blob_service_client;
 mod container_client;
 mod container_lease_client;
+mod page_blob_client;
 
+pub use append_blob_client::AppendBlobClient;
 pub use blob_client::BlobClient;
 pub use blob_lease_client::BlobLeaseClient;
-pub use blob_service_client::{BlobServiceClient, BlobServiceClientBuilder};
+pub use blob_service_client::{AsBlobServiceClient, BlobServiceClient, BlobServiceClientBuilder};
 pub use container_client::ContainerClient;
 pub use container_lease_client::ContainerLeaseClient;
+pub use page_blob_client::PageBlobClient;
+
+use azure_core::{error::ErrorKind, StatusCode};
+
+/// Maps a `404 Not Found` error to `None`.
+pub(crate) fn none_if_not_found<T>(result: azure_core::Result<T>) -> azure_core::Result<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(error) => match error.kind() {
+            ErrorKind::HttpResponse {
+                status: StatusCode::NotFound,
+                ..
+            } => Ok(None),
+            _ => Err(error),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::error::Error;
+
+    #[test]
+    fn maps_not_found_to_none() {
+        assert_eq!(none_if_not_found(Ok(1)).unwrap(), Some(1));
+
+        let not_found = Error::message(ErrorKind::http_response(StatusCode::NotFound, None), "");
+        assert_eq!(none_if_not_found::<()>(Err(not_found)).unwrap(), None);
+
+        let forbidden = Error::message(ErrorKind::http_response(StatusCode::Forbidden, None), "");
+        assert!(none_if_not_found::<()>(Err(forbidden)).is_err());
+    }
+}
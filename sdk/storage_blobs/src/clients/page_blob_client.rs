@@ -0,0 +1,78 @@
+use crate::{blob::operations::*, prelude::*};
+use azure_core::Body;
+
+/// A client for the operations specific to page blobs, e.g. the disks of virtual machines.
+///
+/// The pages of a page blob are 512 bytes long: the blob lengths and the ranges of pages written,
+/// cleared or listed must be aligned on 512 bytes. The writes can be conditioned on the sequence
+/// number of the blob with `if_sequence_number`, so that concurrent writers detect each other.
+#[derive(Debug, Clone)]
+pub struct PageBlobClient {
+    blob_client: BlobClient,
+}
+
+impl PageBlobClient {
+    pub(crate) fn new(blob_client: BlobClient) -> Self {
+        Self { blob_client }
+    }
+
+    /// Creates the page blob with a length of `length` bytes, all pages cleared.
+    pub fn create(&self, length: u128) -> PutPageBlobBuilder {
+        self.blob_client.put_page_blob(length)
+    }
+
+    /// Writes `content` to the pages of `ba512_range`.
+    pub fn upload_pages(
+        &self,
+        ba512_range: BA512Range,
+        content: impl Into<Body>,
+    ) -> PutPageBuilder {
+        self.blob_client.put_page(ba512_range, content)
+    }
+
+    /// Clears the pages of `ba512_range`, which no longer count toward the billed size of the
+    /// blob.
+    pub fn clear_pages(&self, ba512_range: BA512Range) -> ClearPageBuilder {
+        self.blob_client.clear_page(ba512_range)
+    }
+
+    /// Lists the ranges of valid pages of the blob.
+    pub fn get_page_ranges(&self) -> GetPageRangesBuilder {
+        self.blob_client.get_page_ranges()
+    }
+
+    /// Lists the ranges of pages written and cleared since `previous_snapshot`, e.g. for the
+    /// incremental backup of a disk.
+    pub fn get_page_ranges_diff(&self, previous_snapshot: Snapshot) -> GetPageRangesBuilder {
+        self.blob_client
+            .get_page_ranges()
+            .previous_snapshot(PreviousSnapshot::from_snapshot(previous_snapshot))
+    }
+
+    /// Resizes the blob to `length` bytes, a multiple of 512.
+    pub fn resize(&self, length: u128) -> ResizePageBlobBuilder {
+        ResizePageBlobBuilder::new(self.blob_client.clone(), length)
+    }
+
+    /// Changes the sequence number of the blob.
+    pub fn update_sequence_number(
+        &self,
+        action: SequenceNumberAction,
+    ) -> UpdateSequenceNumberBuilder {
+        UpdateSequenceNumberBuilder::new(self.blob_client.clone(), action)
+    }
+
+    /// Creates a snapshot of the blob, from which the later changes can be listed with
+    /// [`get_page_ranges_diff`](Self::get_page_ranges_diff).
+    pub fn snapshot(&self) -> SnapshotBlobBuilder {
+        self.blob_client.snapshot()
+    }
+
+    pub fn container_client(&self) -> &ContainerClient {
+        self.blob_client.container_client()
+    }
+
+    pub fn blob_client(&self) -> &BlobClient {
+        &self.blob_client
+    }
+}
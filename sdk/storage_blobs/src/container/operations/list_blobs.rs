@@ -1,4 +1,7 @@
-use crate::{blob::Blob, prelude::*};
+use crate::{
+    blob::{Blob, BlobType},
+    prelude::*,
+};
 use azure_core::Method;
 use azure_core::{
     error::Error,
@@ -23,13 +26,21 @@ operation! {
     ?include_deleted: bool,
     ?include_tags: bool,
     ?include_versions: bool,
+    ?filter: BlobFilter,
+    ?continuation: NextMarker,
 }
 
 impl ListBlobsBuilder {
+    /// The stream of the pages of blobs.
+    ///
+    /// The listing starts after the blobs of a previous listing when a
+    /// [`continuation`](Self::continuation) is set, e.g. the persisted
+    /// [`ContinuationToken`](azure_core::ContinuationToken) of its last page.
     pub fn into_stream(self) -> Pageable<ListBlobsResponse, Error> {
         let make_request = move |continuation: Option<NextMarker>| {
             let this = self.clone();
             let mut ctx = self.context.clone();
+            let continuation = continuation.or_else(|| self.continuation.clone());
             async move {
                 let mut url = this.client.url()?;
 
@@ -51,7 +62,11 @@ impl ListBlobsBuilder {
                 if this.include_snapshots.unwrap_or(false) {
                     optional_includes.push("snapshots");
                 }
-                if this.include_metadata.unwrap_or(false) {
+                let filter_metadata = this
+                    .filter
+                    .as_ref()
+                    .map_or(false, BlobFilter::needs_metadata);
+                if this.include_metadata.unwrap_or(false) || filter_metadata {
                     optional_includes.push("metadata");
                 }
                 if this.include_uncommitted_blobs.unwrap_or(false) {
@@ -80,7 +95,11 @@ impl ListBlobsBuilder {
 
                 let response = this.client.send(&mut ctx, &mut request).await?;
 
-                ListBlobsResponse::try_from(response).await
+                let mut response = ListBlobsResponse::try_from(response).await?;
+                if let Some(filter) = &this.filter {
+                    response.blobs.blobs.retain(|blob| filter.matches(blob));
+                }
+                Ok(response)
             }
         };
 
@@ -88,6 +107,112 @@ impl ListBlobsBuilder {
     }
 }
 
+/// Client-side filters for the blobs returned by [`ListBlobsBuilder`].
+///
+/// The service only filters blobs by prefix, so these filters are applied to each page as it is
+/// received. Pages can therefore contain fewer blobs than `max_results`, or none at all.
+///
+/// A blob is kept only if it matches every condition. Conditions on the same property, such as
+/// several access tiers, are alternatives.
+///
+/// # Example
+///
+/// ```
+/// use azure_storage_blobs::container::operations::BlobFilter;
+/// use azure_storage_blobs::prelude::AccessTier;
+/// use time::{Duration, OffsetDateTime};
+///
+/// // cool and archived blobs not modified for a year, flagged for cleanup
+/// let filter = BlobFilter::new()
+///     .access_tier(AccessTier::Cool)
+///     .access_tier(AccessTier::Archive)
+///     .metadata("cleanup", "true")
+///     .modified_before(OffsetDateTime::now_utc() - Duration::days(365));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlobFilter {
+    access_tiers: Vec<AccessTier>,
+    blob_types: Vec<BlobType>,
+    metadata: Vec<(String, Option<String>)>,
+    modified_after: Option<OffsetDateTime>,
+    modified_before: Option<OffsetDateTime>,
+}
+
+impl BlobFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps blobs in the access tier.
+    pub fn access_tier(mut self, access_tier: AccessTier) -> Self {
+        self.access_tiers.push(access_tier);
+        self
+    }
+
+    /// Keeps blobs of the type.
+    pub fn blob_type(mut self, blob_type: BlobType) -> Self {
+        self.blob_types.push(blob_type);
+        self
+    }
+
+    /// Keeps blobs having the metadata key, whatever its value.
+    pub fn metadata_key(mut self, key: impl Into<String>) -> Self {
+        self.metadata.push((key.into(), None));
+        self
+    }
+
+    /// Keeps blobs having the metadata key with the value.
+    pub fn metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.push((key.into(), Some(value.into())));
+        self
+    }
+
+    /// Keeps blobs last modified after the time.
+    pub fn modified_after(mut self, time: OffsetDateTime) -> Self {
+        self.modified_after = Some(time);
+        self
+    }
+
+    /// Keeps blobs last modified before the time.
+    pub fn modified_before(mut self, time: OffsetDateTime) -> Self {
+        self.modified_before = Some(time);
+        self
+    }
+
+    /// Whether the blob matches the filter.
+    pub fn matches(&self, blob: &Blob) -> bool {
+        let properties = &blob.properties;
+        if !self.access_tiers.is_empty()
+            && !properties
+                .access_tier
+                .map_or(false, |tier| self.access_tiers.contains(&tier))
+        {
+            return false;
+        }
+        if !self.blob_types.is_empty() && !self.blob_types.contains(&properties.blob_type) {
+            return false;
+        }
+        if matches!(self.modified_after, Some(after) if properties.last_modified <= after) {
+            return false;
+        }
+        if matches!(self.modified_before, Some(before) if properties.last_modified >= before) {
+            return false;
+        }
+        self.metadata.iter().all(|(key, value)| {
+            // metadata keys are case insensitive
+            let metadata = blob.metadata.iter().flatten();
+            metadata
+                .filter(|(k, _)| k.eq_ignore_ascii_case(key))
+                .any(|(_, v)| value.as_ref().map_or(true, |value| value == v))
+        })
+    }
+
+    /// Whether blobs must be listed with their metadata to be filtered.
+    fn needs_metadata(&self) -> bool {
+        !self.metadata.is_empty()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ListBlobsResponse {
     pub prefix: Option<String>,
@@ -160,10 +285,12 @@ mod tests {
     use bytes::Bytes;
 
     use super::*;
+    use crate::tests::{mock_blob_client, response_headers};
+    use azure_core::{mock::MockTransportCannedPolicy, StatusCode};
+    use futures::StreamExt;
+    use std::sync::Arc;
 
-    #[test]
-    fn deserde_azure() {
-        const S: &str = "<?xml version=\"1.0\" encoding=\"utf-8\"?>
+    const AZURE_RESPONSE: &str = "<?xml version=\"1.0\" encoding=\"utf-8\"?>
 <EnumerationResults ServiceEndpoint=\"https://azureskdforrust.blob.core.windows.net/\" ContainerName=\"osa2\">
     <Blobs>
         <Blob>
@@ -241,6 +368,10 @@ mod tests {
     <NextMarker />
 </EnumerationResults>";
 
+    #[test]
+    fn deserde_azure() {
+        const S: &str = AZURE_RESPONSE;
+
         let bytes = Bytes::from(S);
         let _list_blobs_response_internal: ListBlobsResponseInternal = read_xml(&bytes).unwrap();
     }
@@ -315,4 +446,82 @@ mod tests {
         let bytes = Bytes::from(S);
         let _list_blobs_response_internal: ListBlobsResponseInternal = read_xml(&bytes).unwrap();
     }
+
+    #[test]
+    fn filter_blobs() {
+        let bytes = Bytes::from(AZURE_RESPONSE);
+        let response: ListBlobsResponseInternal = read_xml(&bytes).unwrap();
+        let last_modified =
+            azure_core::date::parse_rfc1123("Thu, 01 Jul 2021 10:44:59 GMT").unwrap();
+        let filtered = |filter: BlobFilter| {
+            response
+                .blobs
+                .blobs
+                .iter()
+                .filter(|blob| filter.matches(blob))
+                .map(|blob| blob.name.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(filtered(BlobFilter::new()).len(), 3);
+        assert_eq!(
+            filtered(BlobFilter::new().metadata_key("UserKey")),
+            vec!["blob0.txt"]
+        );
+        assert!(filtered(BlobFilter::new().metadata("userkey", "othervalue")).is_empty());
+        assert!(filtered(BlobFilter::new().access_tier(AccessTier::Cool)).is_empty());
+        assert_eq!(
+            filtered(
+                BlobFilter::new()
+                    .access_tier(AccessTier::Cool)
+                    .access_tier(AccessTier::Hot)
+                    .blob_type(BlobType::BlockBlob)
+            )
+            .len(),
+            3
+        );
+        assert!(filtered(BlobFilter::new().modified_after(last_modified)).is_empty());
+        assert_eq!(
+            filtered(BlobFilter::new().modified_before(last_modified + time::Duration::days(1)))
+                .len(),
+            3
+        );
+    }
+
+    #[tokio::test]
+    async fn resumes_from_the_continuation() {
+        let first_page =
+            AZURE_RESPONSE.replace("<NextMarker />", "<NextMarker>marker2</NextMarker>");
+        let transport = Arc::new(
+            MockTransportCannedPolicy::new()
+                .reply(StatusCode::Ok, response_headers(&[]), first_page)
+                .reply(StatusCode::Ok, response_headers(&[]), AZURE_RESPONSE),
+        );
+
+        let pages: Vec<_> = mock_blob_client(transport.clone())
+            .container_client()
+            .list_blobs()
+            .continuation(NextMarker::new("marker1".to_owned()))
+            .into_stream()
+            .collect()
+            .await;
+        assert_eq!(pages.len(), 2);
+        assert!(pages.iter().all(Result::is_ok));
+
+        let markers: Vec<_> = transport
+            .requests()
+            .iter()
+            .map(|request| {
+                request
+                    .url()
+                    .query_pairs()
+                    .find(|(key, _)| key == "marker")
+                    .map(|(_, marker)| marker.into_owned())
+            })
+            .collect();
+        assert_eq!(
+            markers,
+            [Some("marker1".to_owned()), Some("marker2".to_owned())]
+        );
+    }
 }
@@ -8,6 +8,7 @@ pub mod list_blobs;
 pub mod release_lease;
 pub mod renew_lease;
 pub mod set_acl;
+pub mod set_metadata;
 pub use self::acquire_lease::*;
 pub use self::break_lease::*;
 pub use self::create::*;
@@ -18,3 +19,4 @@ pub use self::list_blobs::*;
 pub use self::release_lease::*;
 pub use self::renew_lease::*;
 pub use self::set_acl::*;
+pub use self::set_metadata::*;
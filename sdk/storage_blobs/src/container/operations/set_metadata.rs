@@ -0,0 +1,110 @@
+use crate::prelude::*;
+use azure_core::{headers::*, prelude::*, Method, RequestId};
+use time::OffsetDateTime;
+
+operation! {
+    SetMetadata,
+    client: ContainerClient,
+    metadata: Metadata,
+    ?lease_id: LeaseId,
+    ?if_modified_since: IfModifiedSinceCondition
+}
+
+impl SetMetadataBuilder {
+    /// Replaces all the metadata of the container.
+    ///
+    /// The service supports only the `Modified` condition of
+    /// [`if_modified_since`](Self::if_modified_since) for this operation.
+    pub fn into_future(mut self) -> SetMetadata {
+        Box::pin(async move {
+            let mut url = self.client.url()?;
+
+            url.query_pairs_mut().append_pair("restype", "container");
+            url.query_pairs_mut().append_pair("comp", "metadata");
+
+            let mut headers = Headers::new();
+            headers.add(self.lease_id);
+            headers.add(self.if_modified_since);
+            for m in self.metadata.iter() {
+                headers.add(m);
+            }
+
+            let mut request = self
+                .client
+                .finalize_request(url, Method::Put, headers, None)?;
+
+            let response = self.client.send(&mut self.context, &mut request).await?;
+
+            SetMetadataResponse::from_headers(response.headers())
+        })
+    }
+}
+
+azure_storage::response_from_headers!(SetMetadataResponse,
+    etag_from_headers => etag: String,
+    last_modified_from_headers => last_modified: OffsetDateTime,
+    request_id_from_headers => request_id: RequestId,
+    date_from_headers => date: OffsetDateTime
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{mock_blob_client, response_headers};
+    use azure_core::{mock::MockTransportCannedPolicy, StatusCode};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn sets_the_metadata_of_the_leased_container() {
+        let transport = Arc::new(MockTransportCannedPolicy::new().reply(
+            StatusCode::Ok,
+            response_headers(&[
+                ("etag", "\"0x8DAAE8D3C2C1A5B\""),
+                ("last-modified", "Thu, 15 Oct 2026 09:30:00 GMT"),
+            ]),
+            "",
+        ));
+        let lease_id = LeaseId::new();
+        let mut metadata = Metadata::new();
+        metadata.insert("owner", "team");
+        let modified_since = OffsetDateTime::from_unix_timestamp(1_792_054_800).unwrap();
+
+        let response = mock_blob_client(transport.clone())
+            .container_client()
+            .container_lease_client(lease_id)
+            .set_metadata(metadata)
+            .if_modified_since(IfModifiedSinceCondition::Modified(modified_since))
+            .into_future()
+            .await
+            .unwrap();
+        assert_eq!(response.etag, "\"0x8DAAE8D3C2C1A5B\"");
+        assert_eq!(
+            response.last_modified,
+            OffsetDateTime::from_unix_timestamp(1_792_056_600).unwrap()
+        );
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        let request = &requests[0];
+        assert_eq!(request.method(), &Method::Put);
+        assert_eq!(request.url().path(), "/container");
+        assert_eq!(
+            request.url().query(),
+            Some("restype=container&comp=metadata")
+        );
+        assert_eq!(
+            request.headers().get_optional_string(&LEASE_ID),
+            Some(lease_id.to_string())
+        );
+        assert_eq!(
+            request
+                .headers()
+                .get_optional_str(&HeaderName::from_static("x-ms-meta-owner")),
+            Some("team")
+        );
+        assert!(request
+            .headers()
+            .get_optional_str(&IF_MODIFIED_SINCE)
+            .is_some());
+    }
+}
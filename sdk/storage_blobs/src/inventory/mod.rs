@@ -0,0 +1,353 @@
+//! Parsers for the output of [blob inventory](https://learn.microsoft.com/azure/storage/blobs/blob-inventory) runs.
+//!
+//! Each run of an inventory rule writes a `<rule>-manifest.json` blob describing the run, along
+//! with the inventory files themselves, in CSV or Apache Parquet format. Download the manifest and
+//! parse it with [`InventoryManifest::from_json`] to find the inventory files, then parse them
+//! with [`InventoryManifest::parse_file`].
+//!
+//! Only the CSV format is parsed: Parquet files fail to parse with an
+//! [`UnsupportedInventoryFormatError`]. They have the same columns and can be read with a Parquet
+//! reader.
+use azure_core::date::parse_rfc3339;
+use azure_core::error::{Error, ErrorKind, ResultExt};
+use serde::{Deserialize, Deserializer};
+use std::collections::HashMap;
+use std::fmt;
+use time::OffsetDateTime;
+
+/// The manifest written at the end of an inventory run.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryManifest {
+    pub destination_container: String,
+    pub endpoint: String,
+    pub files: Vec<InventoryFile>,
+    #[serde(deserialize_with = "deserialize_rfc3339")]
+    pub inventory_start_time: OffsetDateTime,
+    #[serde(deserialize_with = "deserialize_rfc3339")]
+    pub inventory_completion_time: OffsetDateTime,
+    pub rule_name: String,
+    pub rule_definition: InventoryRuleDefinition,
+    pub status: String,
+    pub summary: InventorySummary,
+    pub version: Option<String>,
+}
+
+impl InventoryManifest {
+    pub fn from_json(body: &[u8]) -> azure_core::Result<Self> {
+        serde_json::from_slice(body).context(
+            ErrorKind::DataConversion,
+            "failed to parse inventory manifest",
+        )
+    }
+
+    /// The format of the inventory files.
+    pub fn format(&self) -> InventoryFormat {
+        self.rule_definition.format
+    }
+
+    /// Returns `true` if the run completed successfully.
+    pub fn succeeded(&self) -> bool {
+        self.status == "Succeeded"
+    }
+
+    /// Parses one of the inventory files of the run, in the format of the rule.
+    ///
+    /// Fails with an [`UnsupportedInventoryFormatError`] if the files are not in CSV format.
+    pub fn parse_file(&self, body: &[u8]) -> azure_core::Result<Vec<InventoryRecord>> {
+        match self.format() {
+            InventoryFormat::Csv => parse_csv(body),
+            format @ InventoryFormat::Parquet => {
+                Err(UnsupportedInventoryFormatError(format).into())
+            }
+        }
+    }
+}
+
+/// An inventory file, relative to the destination container.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryFile {
+    pub blob: String,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryRuleDefinition {
+    pub format: InventoryFormat,
+    pub object_type: Option<String>,
+    pub schedule: Option<String>,
+    #[serde(default)]
+    pub schema_fields: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum InventoryFormat {
+    Csv,
+    /// Apache Parquet, which is not parsed by this module.
+    Parquet,
+}
+
+/// Returned, as the source of an [`ErrorKind::DataConversion`] error, when parsing inventory
+/// files in a format which is not supported, i.e. Parquet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnsupportedInventoryFormatError(pub InventoryFormat);
+
+impl From<UnsupportedInventoryFormatError> for Error {
+    fn from(error: UnsupportedInventoryFormatError) -> Self {
+        Error::new(ErrorKind::DataConversion, error)
+    }
+}
+
+impl std::error::Error for UnsupportedInventoryFormatError {}
+
+impl fmt::Display for UnsupportedInventoryFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:?} inventory files are not supported: use the Csv format in the inventory rule, or \
+            read the files with a Parquet reader",
+            self.0
+        )
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InventorySummary {
+    pub object_count: u64,
+    pub total_object_count: Option<u64>,
+    pub total_object_size: u64,
+}
+
+fn deserialize_rfc3339<'de, D>(deserializer: D) -> Result<OffsetDateTime, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    parse_rfc3339(&s).map_err(serde::de::Error::custom)
+}
+
+/// A blob, or a container, listed in an inventory file.
+///
+/// The columns depend on the schema fields of the rule: the common ones are parsed, and all of
+/// them are available in [`fields`](Self::fields).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InventoryRecord {
+    pub name: String,
+    pub creation_time: Option<OffsetDateTime>,
+    pub last_modified: Option<OffsetDateTime>,
+    pub content_length: Option<u64>,
+    pub blob_type: Option<String>,
+    pub access_tier: Option<String>,
+    pub snapshot: Option<String>,
+    pub version_id: Option<String>,
+    pub is_current_version: Option<bool>,
+    pub deleted: Option<bool>,
+    pub fields: HashMap<String, String>,
+}
+
+impl InventoryRecord {
+    fn from_fields(fields: HashMap<String, String>) -> azure_core::Result<Self> {
+        let name = fields.get("Name").cloned().ok_or_else(|| {
+            Error::message(ErrorKind::DataConversion, "inventory record has no Name")
+        })?;
+        Ok(Self {
+            name,
+            creation_time: parse_field(&fields, "Creation-Time", parse_rfc3339)?,
+            last_modified: parse_field(&fields, "Last-Modified", parse_rfc3339)?,
+            content_length: parse_field(&fields, "Content-Length", parse_from_str)?,
+            blob_type: fields.get("BlobType").cloned().filter(|s| !s.is_empty()),
+            access_tier: fields.get("AccessTier").cloned().filter(|s| !s.is_empty()),
+            snapshot: fields.get("Snapshot").cloned().filter(|s| !s.is_empty()),
+            version_id: fields.get("VersionId").cloned().filter(|s| !s.is_empty()),
+            is_current_version: parse_field(&fields, "IsCurrentVersion", parse_bool)?,
+            deleted: parse_field(&fields, "Deleted", parse_bool)?,
+            fields,
+        })
+    }
+}
+
+fn parse_field<T>(
+    fields: &HashMap<String, String>,
+    name: &str,
+    parse: impl FnOnce(&str) -> azure_core::Result<T>,
+) -> azure_core::Result<Option<T>> {
+    match fields.get(name).map(String::as_str) {
+        None | Some("") => Ok(None),
+        Some(value) => parse(value)
+            .with_context(ErrorKind::DataConversion, || {
+                format!("invalid {name} in inventory record: {value}")
+            })
+            .map(Some),
+    }
+}
+
+fn parse_from_str<T>(value: &str) -> azure_core::Result<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    value.parse().map_kind(ErrorKind::DataConversion)
+}
+
+fn parse_bool(value: &str) -> azure_core::Result<bool> {
+    match value.to_ascii_lowercase().as_str() {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        _ => Err(Error::message(ErrorKind::DataConversion, "not a boolean")),
+    }
+}
+
+/// Parses an inventory file in CSV format.
+///
+/// The first line holds the names of the columns.
+pub fn parse_csv(body: &[u8]) -> azure_core::Result<Vec<InventoryRecord>> {
+    let body = std::str::from_utf8(body).context(
+        ErrorKind::DataConversion,
+        "inventory file is not valid UTF-8",
+    )?;
+    let mut rows = csv_rows(body)?.into_iter();
+    let header = match rows.next() {
+        Some(header) => header,
+        None => return Ok(Vec::new()),
+    };
+    rows.filter(|row| !(row.len() == 1 && row[0].is_empty()))
+        .map(|row| {
+            if row.len() != header.len() {
+                return Err(Error::with_message(ErrorKind::DataConversion, || {
+                    format!(
+                        "inventory row has {} columns, expected {}",
+                        row.len(),
+                        header.len()
+                    )
+                }));
+            }
+            InventoryRecord::from_fields(header.iter().cloned().zip(row).collect())
+        })
+        .collect()
+}
+
+/// Splits RFC 4180 CSV into rows of fields.
+fn csv_rows(body: &str) -> azure_core::Result<Vec<Vec<String>>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = body.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes => {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    field.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            }
+            '"' if field.is_empty() => in_quotes = true,
+            ',' if !in_quotes => row.push(std::mem::take(&mut field)),
+            '\r' if !in_quotes && chars.peek() == Some(&'\n') => {}
+            '\n' if !in_quotes => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            c => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(Error::message(
+            ErrorKind::DataConversion,
+            "unterminated quoted field in inventory file",
+        ));
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_manifests() {
+        let manifest = br#"{
+            "destinationContainer": "inventory",
+            "endpoint": "https://myaccount.blob.core.windows.net",
+            "files": [
+                { "blob": "2021/05/26/13-25-36/Rule_1/Rule_1.csv", "size": 12710092 }
+            ],
+            "inventoryCompletionTime": "2021-05-26T13:35:56Z",
+            "inventoryStartTime": "2021-05-26T13:25:36Z",
+            "ruleDefinition": {
+                "filters": { "blobTypes": ["blockBlob"] },
+                "format": "Csv",
+                "objectType": "Blob",
+                "schedule": "Daily",
+                "schemaFields": ["Name", "Content-Length"]
+            },
+            "ruleName": "Rule_1",
+            "status": "Succeeded",
+            "summary": { "objectCount": 110000, "totalObjectCount": 110000, "totalObjectSize": 23789775 },
+            "version": "1.0"
+        }"#;
+        let manifest = InventoryManifest::from_json(manifest).unwrap();
+        assert!(manifest.succeeded());
+        assert_eq!(manifest.format(), InventoryFormat::Csv);
+        assert_eq!(manifest.files[0].size, 12710092);
+        assert_eq!(manifest.summary.total_object_size, 23789775);
+
+        let records = manifest
+            .parse_file(b"Name,Content-Length\na.txt,1\n")
+            .unwrap();
+        assert_eq!(records[0].content_length, Some(1));
+    }
+
+    #[test]
+    fn rejects_parquet_files() {
+        let manifest = br#"{
+            "destinationContainer": "inventory",
+            "endpoint": "https://myaccount.blob.core.windows.net",
+            "files": [{ "blob": "Rule_1.parquet", "size": 1024 }],
+            "inventoryCompletionTime": "2021-05-26T13:35:56Z",
+            "inventoryStartTime": "2021-05-26T13:25:36Z",
+            "ruleDefinition": { "format": "Parquet", "objectType": "Blob" },
+            "ruleName": "Rule_1",
+            "status": "Succeeded",
+            "summary": { "objectCount": 1, "totalObjectSize": 1024 }
+        }"#;
+        let manifest = InventoryManifest::from_json(manifest).unwrap();
+        assert_eq!(manifest.format(), InventoryFormat::Parquet);
+
+        let error = manifest.parse_file(b"PAR1").unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::DataConversion);
+        assert_eq!(
+            error.downcast_ref::<UnsupportedInventoryFormatError>(),
+            Some(&UnsupportedInventoryFormatError(InventoryFormat::Parquet))
+        );
+    }
+
+    #[test]
+    fn parses_csv_files() {
+        let csv = "Name,Creation-Time,Content-Length,BlobType,AccessTier,Metadata\r\n\
+            dir/a.txt,2021-05-26T13:25:36.1234567Z,42,BlockBlob,Hot,\r\n\
+            \"b,\"\"quoted\"\".txt\",2021-05-26T13:25:36Z,0,BlockBlob,,\"{\"\"k\"\": \"\"v\"\"}\"\r\n";
+        let records = parse_csv(csv.as_bytes()).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].name, "dir/a.txt");
+        assert_eq!(records[0].content_length, Some(42));
+        assert_eq!(records[0].access_tier.as_deref(), Some("Hot"));
+        assert!(records[0].creation_time.is_some());
+        assert_eq!(records[1].name, "b,\"quoted\".txt");
+        assert_eq!(records[1].access_tier, None);
+        assert_eq!(records[1].fields["Metadata"], "{\"k\": \"v\"}");
+
+        assert!(parse_csv(b"Name,Content-Length\na.txt,NaN\n").is_err());
+        assert!(parse_csv(b"Name,Content-Length\na.txt\n").is_err());
+    }
+}
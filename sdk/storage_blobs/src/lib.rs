@@ -6,9 +6,55 @@ extern crate azure_core;
 pub use azure_core::error::{Error, ErrorKind, ResultExt};
 
 pub mod blob;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod container;
+pub mod inventory;
 pub mod prelude;
 pub mod service;
 
 mod clients;
 mod options;
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use azure_core::{headers::Headers, mock::MockTransportCannedPolicy, TransportOptions};
+    use std::sync::Arc;
+
+    /// A client of the blob `container/blob`, whose requests are answered by `transport`.
+    pub(crate) fn mock_blob_client(transport: Arc<MockTransportCannedPolicy>) -> BlobClient {
+        BlobServiceClientBuilder::new("account", StorageCredentials::anonymous())
+            .transport(TransportOptions::new_custom_policy(transport))
+            .build()
+            .container_client("container")
+            .blob_client("blob")
+    }
+
+    /// The headers of a response, with the request id and date every response has.
+    pub(crate) fn response_headers(headers: &[(&'static str, &str)]) -> Headers {
+        let mut response_headers = Headers::new();
+        response_headers.insert("x-ms-request-id", "00000000-0000-0000-0000-000000000000");
+        response_headers.insert("date", "Thu, 15 Oct 2026 10:00:00 GMT");
+        for (name, value) in headers {
+            response_headers.insert(*name, value.to_string());
+        }
+        response_headers
+    }
+
+    /// The headers of a Get Blob Properties response.
+    pub(crate) fn blob_properties_headers(headers: &[(&'static str, &str)]) -> Headers {
+        let mut properties = vec![
+            ("x-ms-creation-time", "Thu, 15 Oct 2026 09:00:00 GMT"),
+            ("last-modified", "Thu, 15 Oct 2026 09:30:00 GMT"),
+            ("etag", "\"0x8DAAE8D3C2C1A5B\""),
+            ("content-length", "1024"),
+            ("x-ms-blob-type", "BlockBlob"),
+            ("x-ms-lease-status", "unlocked"),
+            ("x-ms-lease-state", "available"),
+            ("x-ms-server-encrypted", "true"),
+        ];
+        properties.extend_from_slice(headers);
+        response_headers(&properties)
+    }
+}
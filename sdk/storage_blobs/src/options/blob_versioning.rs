@@ -28,3 +28,19 @@ impl AppendToUrlQuery for BlobVersioning {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn addresses_versions_with_the_versionid_query() {
+        let mut url = url::Url::parse("https://contoso.blob.core.windows.net/c/b").unwrap();
+        BlobVersioning::from(VersionId::new("2022-08-01T10:00:00.0000000Z"))
+            .append_to_url_query(&mut url);
+        assert_eq!(
+            url.query(),
+            Some("versionid=2022-08-01T10%3A00%3A00.0000000Z")
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use azure_core::headers::{self, Header};
+
+/// The authorization of the read of the source of a copy, when it is not a public object or
+/// authorized with a SAS in its URL.
+///
+/// Only OAuth bearer tokens are supported by the service, for the `https://storage.azure.com`
+/// resource.
+#[derive(Clone, PartialEq, Eq)]
+pub struct CopySourceAuthorization(String);
+
+impl CopySourceAuthorization {
+    /// Authorizes the read of the source with an OAuth bearer token.
+    pub fn bearer(token: impl AsRef<str>) -> Self {
+        Self(format!("Bearer {}", token.as_ref()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Debug for CopySourceAuthorization {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CopySourceAuthorization(<REDACTED>)")
+    }
+}
+
+impl Header for CopySourceAuthorization {
+    fn name(&self) -> headers::HeaderName {
+        "x-ms-copy-source-authorization".into()
+    }
+
+    fn value(&self) -> headers::HeaderValue {
+        self.0.clone().into()
+    }
+}
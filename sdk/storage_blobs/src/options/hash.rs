@@ -1,12 +1,25 @@
 use azure_core::headers::{self, Header, CONTENT_MD5};
 use azure_storage::headers::CONTENT_CRC64;
 
+/// A transactional checksum of the content of a request, checked by the service.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord)]
 pub enum Hash {
     MD5([u8; 16]),
     CRC64(u64),
 }
 
+impl Hash {
+    /// The MD5 digest of `data`, sent as `Content-MD5`.
+    pub fn md5(data: &[u8]) -> Self {
+        Hash::MD5(azure_storage::checksum::md5(data))
+    }
+
+    /// The storage CRC64 of `data`, sent as `x-ms-content-crc64`.
+    pub fn crc64(data: &[u8]) -> Self {
+        Hash::CRC64(azure_storage::checksum::crc64(data))
+    }
+}
+
 impl Header for Hash {
     fn name(&self) -> headers::HeaderName {
         match self {
@@ -18,7 +31,7 @@ impl Header for Hash {
     fn value(&self) -> headers::HeaderValue {
         match self {
             Hash::MD5(md5) => base64::encode(md5),
-            Hash::CRC64(crc64) => format!("{}", crc64),
+            Hash::CRC64(crc64) => base64::encode(crc64.to_le_bytes()),
         }
         .into()
     }
@@ -29,3 +42,20 @@ impl From<md5::Digest> for Hash {
         Hash::MD5(md5.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_hashes() {
+        assert_eq!(
+            Hash::crc64(b"123456789").value().as_str(),
+            base64::encode(0xAE8B_1486_0A79_9888u64.to_le_bytes())
+        );
+        assert_eq!(
+            Hash::md5(b"123456789").value().as_str(),
+            "JfnnlDI7RTiF9RgfG2JNCw=="
+        );
+    }
+}
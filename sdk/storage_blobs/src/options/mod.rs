@@ -13,9 +13,12 @@ mod blob_versioning;
 mod block_id;
 mod condition_append_position;
 mod condition_max_size;
+mod copy_source_authorization;
 mod delete_snapshot_method;
 mod hash;
+mod progress_handler;
 mod rehydrate_policy;
+mod sequence_number_action;
 mod tags;
 
 pub use access_tier::AccessTier;
@@ -31,22 +34,26 @@ pub use blob_versioning::BlobVersioning;
 pub use block_id::BlockId;
 pub use condition_append_position::ConditionAppendPosition;
 pub use condition_max_size::ConditionMaxSize;
+pub use copy_source_authorization::CopySourceAuthorization;
 pub use delete_snapshot_method::DeleteSnapshotsMethod;
 pub use hash::Hash;
+pub use progress_handler::ProgressHandler;
 pub use rehydrate_policy::RehydratePriority;
+pub use sequence_number_action::SequenceNumberAction;
 pub use tags::Tags;
 
 use std::str::FromStr;
 
 use azure_core::error::Error;
-use azure_core::headers::HeaderName;
+use azure_core::headers::{HeaderName, HeaderValue};
 
 request_query!(
     /// This type could also be a DateTime but the docs clearly states to treat is as opaque so we do not convert it in any way.
     ///
     ///See: <https://docs.microsoft.com/rest/api/storageservices/get-blob>"]
+    #[derive(PartialEq, Eq)]
     VersionId,
-    "version_id"
+    "versionid"
 );
 
 request_query!(
@@ -66,4 +73,30 @@ impl FromStr for Snapshot {
     }
 }
 
+request_query!(
+    /// The snapshot from which the changes to the pages of a page blob are listed.
+    ///
+    /// See: <https://docs.microsoft.com/rest/api/storageservices/get-page-ranges>
+    #[derive(PartialEq, Eq)]
+    PreviousSnapshot,
+    "prevsnapshot"
+);
+
+impl PreviousSnapshot {
+    pub fn from_snapshot(snapshot: Snapshot) -> Self {
+        Self(snapshot.0)
+    }
+}
+
 pub const SNAPSHOT: HeaderName = HeaderName::from_static("x-ms-snapshot");
+pub const VERSION_ID: HeaderName = HeaderName::from_static("x-ms-version-id");
+pub const IS_CURRENT_VERSION: HeaderName = HeaderName::from_static("x-ms-is-current-version");
+pub const BLOB_APPEND_OFFSET: HeaderName = HeaderName::from_static("x-ms-blob-append-offset");
+pub const BLOB_COMMITTED_BLOCK_COUNT: HeaderName =
+    HeaderName::from_static("x-ms-blob-committed-block-count");
+pub const BLOB_SEALED: HeaderName = HeaderName::from_static("x-ms-blob-sealed");
+
+/// The service version sent by the operations writing from a source URL, instead of the one of
+/// the storage clients: Put Blob From URL requires 2020-04-08 and the
+/// `x-ms-copy-source-authorization` header requires 2020-10-02.
+pub(crate) const FROM_URL_VERSION: HeaderValue = HeaderValue::from_static("2020-10-02");
@@ -0,0 +1,38 @@
+use std::fmt;
+use std::sync::Arc;
+
+/// A function called with the number of bytes transferred so far and the total number of bytes
+/// of a transfer.
+///
+/// Closures convert into a `ProgressHandler`:
+/// ```
+/// # use azure_storage_blobs::prelude::ProgressHandler;
+/// let handler: ProgressHandler = (|transferred, total| println!("{transferred}/{total}")).into();
+/// ```
+#[derive(Clone)]
+pub struct ProgressHandler(Arc<dyn Fn(u64, u64) + Send + Sync>);
+
+impl ProgressHandler {
+    pub fn new(handler: impl Fn(u64, u64) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(handler))
+    }
+
+    pub(crate) fn report(&self, transferred: u64, total: u64) {
+        (self.0)(transferred, total)
+    }
+}
+
+impl<F> From<F> for ProgressHandler
+where
+    F: Fn(u64, u64) + Send + Sync + 'static,
+{
+    fn from(handler: F) -> Self {
+        Self::new(handler)
+    }
+}
+
+impl fmt::Debug for ProgressHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ProgressHandler").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,58 @@
+use azure_core::headers::{Headers, BLOB_SEQUENCE_NUMBER};
+
+const SEQUENCE_NUMBER_ACTION: &str = "x-ms-sequence-number-action";
+
+/// How the sequence number of a page blob is changed.
+///
+/// See: <https://docs.microsoft.com/rest/api/storageservices/set-blob-properties>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SequenceNumberAction {
+    /// Sets the sequence number to the higher of the given one and the current one.
+    Max(u64),
+    /// Sets the sequence number to the given one.
+    Update(u64),
+    /// Increments the sequence number by one.
+    Increment,
+}
+
+impl SequenceNumberAction {
+    pub fn to_headers(&self) -> Headers {
+        let mut headers = Headers::new();
+        match self {
+            SequenceNumberAction::Max(sequence_number) => {
+                headers.insert(SEQUENCE_NUMBER_ACTION, "max");
+                headers.insert(BLOB_SEQUENCE_NUMBER, sequence_number.to_string());
+            }
+            SequenceNumberAction::Update(sequence_number) => {
+                headers.insert(SEQUENCE_NUMBER_ACTION, "update");
+                headers.insert(BLOB_SEQUENCE_NUMBER, sequence_number.to_string());
+            }
+            SequenceNumberAction::Increment => {
+                headers.insert(SEQUENCE_NUMBER_ACTION, "increment");
+            }
+        }
+        headers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn increment_has_no_sequence_number() {
+        let headers = SequenceNumberAction::Update(7).to_headers();
+        assert_eq!(
+            headers.get_str(&SEQUENCE_NUMBER_ACTION.into()).unwrap(),
+            "update"
+        );
+        assert_eq!(headers.get_str(&BLOB_SEQUENCE_NUMBER).unwrap(), "7");
+
+        let headers = SequenceNumberAction::Increment.to_headers();
+        assert_eq!(
+            headers.get_str(&SEQUENCE_NUMBER_ACTION.into()).unwrap(),
+            "increment"
+        );
+        assert!(headers.get_optional_str(&BLOB_SEQUENCE_NUMBER).is_none());
+    }
+}
@@ -3,8 +3,10 @@ pub use crate::options::*;
 pub use crate::{
     blob::{Blob, BlobBlockType, BlockList, BlockListType},
     clients::{
-        BlobClient, BlobLeaseClient, BlobServiceClient, BlobServiceClientBuilder, ContainerClient,
-        ContainerLeaseClient,
+        AppendBlobClient, AsBlobServiceClient, BlobClient, BlobLeaseClient, BlobServiceClient,
+        BlobServiceClientBuilder, ContainerClient, ContainerLeaseClient, PageBlobClient,
     },
 };
-pub use azure_storage::{StoredAccessPolicy, StoredAccessPolicyList};
+pub use azure_storage::{
+    clients::StorageCredentials, CloudLocation, StoredAccessPolicy, StoredAccessPolicyList,
+};
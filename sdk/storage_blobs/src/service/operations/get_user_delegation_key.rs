@@ -0,0 +1,90 @@
+use crate::prelude::BlobServiceClient;
+use azure_core::{
+    date::{parse_rfc3339, to_rfc3339},
+    headers::Headers,
+    xml::read_xml,
+    Method, Response as HttpResponse,
+};
+use azure_storage::{headers::CommonStorageResponseHeaders, prelude::UserDelegationKey};
+use bytes::Bytes;
+use time::OffsetDateTime;
+
+operation! {
+    GetUserDelegationKey,
+    client: BlobServiceClient,
+    start: OffsetDateTime,
+    expiry: OffsetDateTime,
+}
+
+impl GetUserDelegationKeyBuilder {
+    pub fn into_future(mut self) -> GetUserDelegationKey {
+        Box::pin(async move {
+            let mut url = self.client.url()?;
+
+            for (k, v) in [("restype", "service"), ("comp", "userdelegationkey")].iter() {
+                url.query_pairs_mut().append_pair(k, v);
+            }
+
+            let body = format!(
+                "<?xml version=\"1.0\" encoding=\"utf-8\"?><KeyInfo><Start>{}</Start><Expiry>{}</Expiry></KeyInfo>",
+                format_date(self.start),
+                format_date(self.expiry)
+            );
+
+            let mut request = self.client.finalize_request(
+                url,
+                Method::Post,
+                Headers::new(),
+                Some(Bytes::from(body).into()),
+            )?;
+
+            let response = self.client.send(&mut self.context, &mut request).await?;
+
+            GetUserDelegationKeyResponse::try_from(response).await
+        })
+    }
+}
+
+/// The key dates are sent without fractional seconds, as they are signed in this format.
+fn format_date(date: OffsetDateTime) -> String {
+    to_rfc3339(&date.replace_nanosecond(0).unwrap())
+}
+
+#[derive(Debug, Clone)]
+pub struct GetUserDelegationKeyResponse {
+    pub common: CommonStorageResponseHeaders,
+    pub user_delegation_key: UserDelegationKey,
+}
+
+impl GetUserDelegationKeyResponse {
+    async fn try_from(response: HttpResponse) -> azure_core::Result<Self> {
+        let (_status_code, headers, body) = response.deconstruct();
+        let body = body.collect().await?;
+        let key: UserDelegationKeyBody = read_xml(&body)?;
+
+        Ok(Self {
+            common: CommonStorageResponseHeaders::try_from(&headers)?,
+            user_delegation_key: UserDelegationKey {
+                signed_oid: key.signed_oid,
+                signed_tid: key.signed_tid,
+                signed_start: parse_rfc3339(&key.signed_start)?,
+                signed_expiry: parse_rfc3339(&key.signed_expiry)?,
+                signed_service: key.signed_service,
+                signed_version: key.signed_version,
+                value: key.value,
+            },
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct UserDelegationKeyBody {
+    signed_oid: String,
+    signed_tid: String,
+    signed_start: String,
+    signed_expiry: String,
+    signed_service: String,
+    signed_version: String,
+    value: String,
+}
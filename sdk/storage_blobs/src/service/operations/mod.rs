@@ -1,7 +1,9 @@
 mod find_blobs_by_tags;
 mod get_account_information;
+mod get_user_delegation_key;
 mod list_containers;
 
 pub use find_blobs_by_tags::*;
 pub use get_account_information::*;
+pub use get_user_delegation_key::*;
 pub use list_containers::*;
@@ -1,9 +1,23 @@
 use crate::clients::FileSystemClient;
 use crate::operations::ListFileSystemsBuilder;
-use azure_core::{ClientOptions, Pipeline};
-use azure_storage::clients::{new_pipeline_from_options, ServiceType};
+use azure_core::{CircuitBreakerOptions, ClientOptions, Pipeline};
+use azure_storage::audit::{AuditHook, AuditPolicy};
+use azure_storage::circuit_breaker::{server_busy_circuit_breaker, ServerBusyCircuitBreakerPolicy};
+use azure_storage::clients::{new_pipeline_from_options, ServiceType, StorageClient};
 use azure_storage::prelude::StorageCredentials;
 use azure_storage::CloudLocation;
+use std::sync::Arc;
+
+pub trait AsDataLakeClient {
+    fn data_lake_service_client(&self) -> DataLakeClient;
+}
+
+impl AsDataLakeClient for StorageClient {
+    /// Create a data lake client for the account and the credentials of the storage client.
+    fn data_lake_service_client(&self) -> DataLakeClient {
+        DataLakeClientBuilder::with_location(self.cloud_location(ServiceType::DataLake)).build()
+    }
+}
 
 /// A builder for the blob service client.
 #[derive(Debug, Clone)]
@@ -48,6 +62,21 @@ impl DataLakeClientBuilder {
         self
     }
 
+    /// Set the endpoint of the service, e.g. a private endpoint of the account, keeping the
+    /// credentials.
+    #[must_use]
+    pub fn endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.cloud_location = self.cloud_location.with_endpoint(endpoint);
+        self
+    }
+
+    /// Set the credentials, keeping the endpoint.
+    #[must_use]
+    pub fn credential(mut self, credentials: impl Into<StorageCredentials>) -> Self {
+        self.cloud_location = self.cloud_location.with_credentials(credentials.into());
+        self
+    }
+
     /// Set the retry options.
     #[must_use]
     pub fn retry(mut self, retry: impl Into<azure_core::RetryOptions>) -> Self {
@@ -62,6 +91,26 @@ impl DataLakeClientBuilder {
         self
     }
 
+    /// Call `hook` before each request is sent and after its response is received, e.g. to keep
+    /// an audit trail of the accesses to the data.
+    #[must_use]
+    pub fn audit_hook(mut self, hook: Arc<dyn AuditHook>) -> Self {
+        self.options
+            .per_retry_policies_mut()
+            .push(Arc::new(AuditPolicy::new(hook)));
+        self
+    }
+
+    /// Fail fast the requests to an endpoint which keeps answering that it is busy, instead of
+    /// retrying them. See [`ServerBusyCircuitBreakerPolicy`].
+    #[must_use]
+    pub fn circuit_breaker(mut self, options: CircuitBreakerOptions) -> Self {
+        self.options
+            .per_retry_policies_mut()
+            .push(Arc::new(server_busy_circuit_breaker(options)));
+        self
+    }
+
     /// Override all of the client options.
     ///
     /// *Warning!*: This overrides all client options that have been previously set on this builder.
@@ -96,6 +145,18 @@ impl DataLakeClient {
         self.cloud_location.url(ServiceType::DataLake)
     }
 
+    /// Create a storage client for the account, e.g. to use the queue or table services with the
+    /// same credentials.
+    ///
+    /// Fails if the client was built with a custom URL.
+    pub fn storage_client(&self) -> azure_core::Result<StorageClient> {
+        StorageClient::from_cloud_location(&self.cloud_location)
+    }
+
+    pub(crate) fn credentials(&self) -> &StorageCredentials {
+        self.cloud_location.credentials()
+    }
+
     pub fn list_file_systems(&self) -> ListFileSystemsBuilder {
         ListFileSystemsBuilder::new(self.clone())
     }
@@ -121,10 +182,11 @@ impl DataLakeClient {
             request.headers().clone(),
             Some(request.body().clone()),
         )?;
-        let result = self
-            .pipeline
-            .send(ctx.insert(ServiceType::DataLake), &mut r)
-            .await;
+        // copies are sent to the blob endpoint of the account and set their own service type
+        if ctx.get::<ServiceType>().is_none() {
+            ctx.insert(ServiceType::DataLake);
+        }
+        let result = self.pipeline.send(ctx, &mut r).await;
 
         *request = r;
         result
@@ -2,6 +2,14 @@ use crate::operations::*;
 use crate::request_options::*;
 use crate::{clients::FileSystemClient, prelude::PathClient, Properties};
 use azure_core::prelude::IfMatchCondition;
+use azure_storage::{
+    clients::StorageCredentials,
+    shared_access_signature::{
+        service_sas::{BlobSasPermissions, BlobSharedAccessSignature, BlobSignedResource},
+        SasCredentialsError,
+    },
+};
+use time::OffsetDateTime;
 use url::Url;
 
 #[derive(Debug, Clone)]
@@ -51,7 +59,7 @@ impl DirectoryClient {
 
     pub fn create_if_not_exists(&self) -> PutPathBuilder<Self> {
         self.create()
-            .if_match_condition(IfMatchCondition::NotMatch("*".to_string()))
+            .if_match_condition(IfMatchCondition::not_exists())
     }
 
     pub fn rename<P>(&self, destination_path: P) -> RenamePathBuilder<Self>
@@ -74,7 +82,7 @@ impl DirectoryClient {
         P: Into<String>,
     {
         self.rename(destination_path)
-            .if_match_condition(IfMatchCondition::NotMatch("*".to_string()))
+            .if_match_condition(IfMatchCondition::not_exists())
     }
 
     pub fn delete<R>(&self, recursive: R) -> DeletePathBuilder<Self>
@@ -113,4 +121,84 @@ impl DirectoryClient {
 
         PatchPathBuilder::new(self.clone(), action).acl(acl)
     }
+
+    /// Create a shared access signature granting access to the directory and its subtree.
+    ///
+    /// Fails with a [`SasCredentialsError`] if the client was not created with the account key.
+    pub fn shared_access_signature(
+        &self,
+        permissions: BlobSasPermissions,
+        expiry: OffsetDateTime,
+    ) -> azure_core::Result<BlobSharedAccessSignature> {
+        match self.file_system_client.credentials() {
+            StorageCredentials::Key(account, ref key) => {
+                let canonicalized_resource = format!(
+                    "/blob/{}/{}/{}",
+                    account,
+                    self.file_system_client.name(),
+                    self.dir_path.trim_matches('/')
+                );
+                let directory_depth = self
+                    .dir_path
+                    .split('/')
+                    .filter(|segment| !segment.is_empty())
+                    .count();
+                Ok(BlobSharedAccessSignature::new(
+                    key.to_string(),
+                    canonicalized_resource,
+                    permissions,
+                    expiry,
+                    BlobSignedResource::Directory,
+                )
+                .directory_depth(directory_depth))
+            }
+            credentials => Err(SasCredentialsError::from(credentials).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clients::DataLakeClient;
+    use azure_core::error::ErrorKind;
+    use azure_storage::shared_access_signature::SasToken;
+
+    fn directory_client(credentials: StorageCredentials) -> DirectoryClient {
+        DataLakeClient::new("account", credentials)
+            .file_system_client("filesystem")
+            .into_directory_client("a/b/")
+    }
+
+    #[test]
+    fn signs_the_directory_with_the_account_key() {
+        let expiry = OffsetDateTime::from_unix_timestamp(1700000000).unwrap();
+        let signature = directory_client(StorageCredentials::access_key("account", "a2V5"))
+            .shared_access_signature(BlobSasPermissions::default(), expiry)
+            .unwrap();
+        let token = signature.token();
+        assert!(token.contains("sr=d"));
+        assert!(token.contains("sdd=2"));
+    }
+
+    #[test]
+    fn signing_requires_the_account_key() {
+        let expiry = OffsetDateTime::from_unix_timestamp(1700000000).unwrap();
+        let error = directory_client(StorageCredentials::bearer_token("token"))
+            .shared_access_signature(BlobSasPermissions::default(), expiry)
+            .unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::Credential);
+        assert_eq!(
+            error.downcast_ref::<SasCredentialsError>(),
+            Some(&SasCredentialsError::TokenCredential)
+        );
+
+        let error = directory_client(StorageCredentials::anonymous())
+            .shared_access_signature(BlobSasPermissions::default(), expiry)
+            .unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<SasCredentialsError>(),
+            Some(&SasCredentialsError::NoAccountKey)
+        );
+    }
 }
@@ -1,6 +1,7 @@
 use super::{FileSystemClient, PathClient};
 use crate::{operations::*, request_options::*, Properties};
 use azure_core::prelude::IfMatchCondition;
+use azure_storage::clients::ServiceType;
 use bytes::Bytes;
 use url::Url;
 
@@ -42,7 +43,7 @@ impl FileClient {
 
     pub fn create_if_not_exists(&self) -> PutPathBuilder<Self> {
         self.create()
-            .if_match_condition(IfMatchCondition::NotMatch("*".to_string()))
+            .if_match_condition(IfMatchCondition::not_exists())
     }
 
     pub fn append<B>(&self, position: i64, bytes: B) -> PatchPathBuilder<Self>
@@ -54,6 +55,14 @@ impl FileClient {
             .bytes(bytes.into())
     }
 
+    /// Commits the data appended before `position`, the length of the file after the flush.
+    ///
+    /// When several writers append to the same file, each flush should be conditional on the
+    /// ETag of the previous one, or hold the lease of the file, so that no writer commits the
+    /// data of another. The data appended after `position` is discarded, unless
+    /// [`retain_uncommitted_data`](PatchPathBuilder::retain_uncommitted_data) is set, and
+    /// [`close`](PatchPathBuilder::close) marks the final flush of the file in the change
+    /// notifications.
     pub fn flush(&self, position: i64) -> PatchPathBuilder<Self> {
         PatchPathBuilder::new(self.clone(), PathUpdateAction::Flush).position(position)
     }
@@ -80,7 +89,34 @@ impl FileClient {
         P: Into<String>,
     {
         self.rename(destination_path)
-            .if_match_condition(IfMatchCondition::NotMatch("*".to_string()))
+            .if_match_condition(IfMatchCondition::not_exists())
+    }
+
+    /// The URL of the file on the blob endpoint of the account.
+    pub fn blob_url(&self) -> azure_core::Result<Url> {
+        Ok(ServiceType::Blob.translate_url(&self.url()?))
+    }
+
+    /// Copies a blob of the same account into this file.
+    ///
+    /// The source can be given with either its blob or its data lake URL.
+    pub fn copy_from_blob(&self, source_url: Url) -> azure_core::Result<CopyPathBuilder<Self>> {
+        Ok(CopyPathBuilder::new(
+            self.clone(),
+            source_url,
+            self.blob_url()?,
+        ))
+    }
+
+    /// Copies this file to a blob of the same account.
+    ///
+    /// The destination can be given with either its blob or its data lake URL.
+    pub fn copy_to_blob(&self, destination_url: Url) -> azure_core::Result<CopyPathBuilder<Self>> {
+        Ok(CopyPathBuilder::new(
+            self.clone(),
+            self.blob_url()?,
+            destination_url,
+        ))
     }
 
     pub fn delete(&self) -> DeletePathBuilder<Self> {
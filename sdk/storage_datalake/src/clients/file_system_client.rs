@@ -1,6 +1,7 @@
 use super::{DataLakeClient, DirectoryClient, FileClient};
 use crate::operations::*;
 use crate::Properties;
+use azure_storage::prelude::StorageCredentials;
 use url::Url;
 
 #[derive(Debug, Clone)]
@@ -21,6 +22,14 @@ impl FileSystemClient {
         Ok(self.data_lake_client.url()?.join(&self.name)?)
     }
 
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn credentials(&self) -> &StorageCredentials {
+        self.data_lake_client.credentials()
+    }
+
     pub fn get_directory_client<P>(&self, path: P) -> DirectoryClient
     where
         P: Into<String>,
@@ -3,7 +3,7 @@ mod directory_client;
 mod file_client;
 mod file_system_client;
 
-pub use data_lake_client::{DataLakeClient, DataLakeClientBuilder};
+pub use data_lake_client::{AsDataLakeClient, DataLakeClient, DataLakeClientBuilder};
 pub use directory_client::DirectoryClient;
 pub use file_client::FileClient;
 pub use file_system_client::FileSystemClient;
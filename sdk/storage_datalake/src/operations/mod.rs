@@ -7,6 +7,7 @@ mod file_system_delete;
 mod file_system_get_properties;
 mod file_system_set_properties;
 mod file_systems_list;
+mod path_copy;
 mod path_delete;
 mod path_get;
 mod path_head;
@@ -19,6 +20,7 @@ pub use file_system_delete::*;
 pub use file_system_get_properties::*;
 pub use file_system_set_properties::*;
 pub use file_systems_list::*;
+pub use path_copy::*;
 pub use path_delete::*;
 pub use path_get::*;
 pub use path_head::*;
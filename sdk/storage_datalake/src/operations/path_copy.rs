@@ -0,0 +1,73 @@
+use crate::clients::PathClient;
+use crate::request_options::*;
+use azure_core::headers::{etag_from_headers, last_modified_from_headers, SourceUri};
+use azure_core::prelude::*;
+use azure_core::Request;
+use azure_core::Response as HttpResponse;
+use azure_storage::clients::ServiceType;
+use azure_storage::headers::CommonStorageResponseHeaders;
+use azure_storage::{copy_id_from_headers, copy_status_from_headers, CopyId, CopyStatus};
+use std::convert::TryInto;
+use time::OffsetDateTime;
+use url::Url;
+
+operation! {
+    /// Copies a path with the `Copy Blob` operation of the blob endpoint of the account.
+    ///
+    /// Both URLs can use either the blob or the data lake endpoint: they are sent to the blob
+    /// endpoint. The properties of the source (the metadata of the blob) are copied along with
+    /// its content.
+    CopyPath<C: PathClient + 'static>,
+    client: C,
+    source_url: Url,
+    destination_url: Url,
+    ?if_match_condition: IfMatchCondition,
+    ?if_modified_since: IfModifiedSinceCondition,
+}
+
+impl<C: PathClient + 'static> CopyPathBuilder<C> {
+    pub fn into_future(self) -> CopyPath {
+        Box::pin(async move {
+            let url = ServiceType::Blob.translate_url(&self.destination_url);
+            let source_url = ServiceType::Blob.translate_url(&self.source_url);
+
+            let mut request = Request::new(url, azure_core::Method::Put);
+
+            request.insert_headers(&SourceUri::new(source_url));
+            request.insert_headers(&self.if_match_condition);
+            request.insert_headers(&self.if_modified_since);
+            request.insert_headers(&ContentLength::new(0));
+
+            let mut ctx = self.context.clone();
+            ctx.insert(ServiceType::Blob);
+            let response = self.client.send(&mut ctx, &mut request).await?;
+
+            CopyPathResponse::try_from(response).await
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CopyPathResponse {
+    pub common_storage_response_headers: CommonStorageResponseHeaders,
+    pub etag: String,
+    pub last_modified: OffsetDateTime,
+    pub copy_id: CopyId,
+    /// The copy is usually completed synchronously within an account, but can be `Pending` for
+    /// large files.
+    pub copy_status: CopyStatus,
+}
+
+impl CopyPathResponse {
+    pub async fn try_from(response: HttpResponse) -> azure_core::Result<Self> {
+        let (_status_code, headers, _pinned_stream) = response.deconstruct();
+
+        Ok(Self {
+            common_storage_response_headers: (&headers).try_into()?,
+            etag: etag_from_headers(&headers)?,
+            last_modified: last_modified_from_headers(&headers)?,
+            copy_id: copy_id_from_headers(&headers)?,
+            copy_status: copy_status_from_headers(&headers)?,
+        })
+    }
+}
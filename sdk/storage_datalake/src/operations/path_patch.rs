@@ -19,6 +19,8 @@ operation! {
     ?continuation: NextMarker,
     ?position: Position,
     ?retain_uncommitted_data: RetainUncommittedData,
+    ?content_md5: FileContentMD5,
+    ?lease_id: LeaseId,
     ?if_match_condition: IfMatchCondition,
     ?if_modified_since: IfModifiedSince,
     ?properties: Properties,
@@ -42,6 +44,8 @@ impl<C: PathClient + 'static> PatchPathBuilder<C> {
 
             request.insert_headers(&self.acl);
             request.insert_headers(&self.properties);
+            request.insert_headers(&self.content_md5);
+            request.insert_headers(&self.lease_id);
             request.insert_headers(&self.if_match_condition);
             request.insert_headers(&self.if_modified_since);
 
@@ -93,3 +97,102 @@ impl PatchPathResponse {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use azure_core::{
+        headers::{self, HeaderName, Headers},
+        mock::MockTransportCannedPolicy,
+        prelude::*,
+        StatusCode, TransportOptions,
+    };
+    use std::sync::Arc;
+    use time::OffsetDateTime;
+
+    fn file_client(transport: Arc<MockTransportCannedPolicy>) -> FileClient {
+        DataLakeClient::builder("account", StorageCredentials::anonymous())
+            .transport(TransportOptions::new_custom_policy(transport))
+            .build()
+            .file_system_client("filesystem")
+            .get_file_client("file.txt")
+    }
+
+    fn response_headers() -> Headers {
+        let mut headers = Headers::new();
+        headers.insert("x-ms-request-id", "00000000-0000-0000-0000-000000000000");
+        headers.insert("x-ms-version", "2019-12-12");
+        headers.insert("date", "Thu, 15 Oct 2026 10:00:00 GMT");
+        headers.insert("server", "Windows-Azure-HDFS/1.0");
+        headers.insert("etag", "\"0x8DAAE8D3C2C1A5B\"");
+        headers
+    }
+
+    #[tokio::test]
+    async fn flush_sends_the_md5_the_lease_and_the_conditions() {
+        let transport = Arc::new(MockTransportCannedPolicy::new().reply(
+            StatusCode::Ok,
+            response_headers(),
+            "",
+        ));
+        let lease_id: LeaseId = "b9e9a3c2-2e7f-4c2a-8f6e-9d6f1e4e5a6b".parse().unwrap();
+
+        let response = file_client(transport.clone())
+            .flush(1024)
+            .close(true)
+            .content_md5([0xab; 16])
+            .lease_id(lease_id)
+            .if_modified_since(OffsetDateTime::from_unix_timestamp(1792054800).unwrap())
+            .into_future()
+            .await
+            .unwrap();
+        assert_eq!(response.etag.as_deref(), Some("\"0x8DAAE8D3C2C1A5B\""));
+
+        let request = &transport.requests()[0];
+        assert_eq!(request.method(), &azure_core::Method::Patch);
+        assert_eq!(request.url().path(), "/filesystem/file.txt");
+        let query: Vec<_> = request.url().query_pairs().collect();
+        assert!(query.contains(&("action".into(), "flush".into())));
+        assert!(query.contains(&("position".into(), "1024".into())));
+        assert!(query.contains(&("close".into(), "true".into())));
+        let request_headers = request.headers();
+        assert_eq!(
+            request_headers.get_optional_str(&HeaderName::from_static("x-ms-content-md5")),
+            Some(base64::encode([0xab; 16]).as_str())
+        );
+        assert_eq!(
+            request_headers.get_optional_str(&headers::LEASE_ID),
+            Some("b9e9a3c2-2e7f-4c2a-8f6e-9d6f1e4e5a6b")
+        );
+        assert_eq!(
+            request_headers.get_optional_str(&headers::IF_MODIFIED_SINCE),
+            Some("Thu, 15 Oct 2026 09:00:00 GMT")
+        );
+        assert_eq!(
+            request_headers.get_optional_str(&headers::CONTENT_LENGTH),
+            Some("0")
+        );
+    }
+
+    #[tokio::test]
+    async fn flush_sends_no_md5_or_lease_by_default() {
+        let transport = Arc::new(MockTransportCannedPolicy::new().reply(
+            StatusCode::Ok,
+            response_headers(),
+            "",
+        ));
+
+        file_client(transport.clone())
+            .flush(1024)
+            .into_future()
+            .await
+            .unwrap();
+
+        let request_headers = transport.requests()[0].headers().clone();
+        assert_eq!(
+            request_headers.get_optional_str(&HeaderName::from_static("x-ms-content-md5")),
+            None
+        );
+        assert_eq!(request_headers.get_optional_str(&headers::LEASE_ID), None);
+    }
+}
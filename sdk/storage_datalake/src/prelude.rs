@@ -2,3 +2,4 @@ pub use crate::clients::*;
 pub use crate::file_system::*;
 pub use crate::operations::*;
 pub use crate::Properties;
+pub use azure_storage::{clients::StorageCredentials, CloudLocation};
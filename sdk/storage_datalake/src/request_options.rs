@@ -176,6 +176,34 @@ impl AppendToUrlQuery for RetainUncommittedData {
     }
 }
 
+/// The MD5 hash of the whole file, stored by a flush as the `Content-MD5` property of the file.
+///
+/// The service does not validate it, as the file is appended in several requests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileContentMD5([u8; 16]);
+
+impl From<[u8; 16]> for FileContentMD5 {
+    fn from(md5: [u8; 16]) -> Self {
+        Self(md5)
+    }
+}
+
+impl From<azure_storage::ConsistencyMD5> for FileContentMD5 {
+    fn from(md5: azure_storage::ConsistencyMD5) -> Self {
+        Self(*md5.as_slice())
+    }
+}
+
+impl Header for FileContentMD5 {
+    fn name(&self) -> azure_core::headers::HeaderName {
+        "x-ms-content-md5".into()
+    }
+
+    fn value(&self) -> azure_core::headers::HeaderValue {
+        base64::encode(self.0).into()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct RenameSource(String);
 
@@ -2,18 +2,16 @@ use azure_storage::prelude::StorageCredentials;
 use azure_storage_datalake::prelude::*;
 
 pub async fn create_data_lake_client(transaction_name: &str) -> azure_core::Result<DataLakeClient> {
-    let account_name = (std::env::var(mock_transport::TESTING_MODE_KEY).as_deref()
-        == Ok(mock_transport::TESTING_MODE_RECORD))
-    .then(get_account)
-    .unwrap_or_else(String::new);
+    let account_name = azure_core::mock::is_recording()
+        .then(get_account)
+        .unwrap_or_else(String::new);
 
-    let account_key = (std::env::var(mock_transport::TESTING_MODE_KEY).as_deref()
-        == Ok(mock_transport::TESTING_MODE_RECORD))
-    .then(get_key)
-    .unwrap_or_else(String::new);
+    let account_key = azure_core::mock::is_recording()
+        .then(get_key)
+        .unwrap_or_else(String::new);
 
     let transport_options = azure_core::TransportOptions::new_custom_policy(
-        mock_transport::new_mock_transport(transaction_name.into()),
+        azure_core::mock::new_mock_transport(transaction_name.into()),
     );
 
     let storage_credentials = StorageCredentials::Key(account_name.clone(), account_key);
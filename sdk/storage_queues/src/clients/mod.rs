@@ -1,6 +1,10 @@
 mod pop_receipt_client;
 mod queue_client;
+mod queue_producer;
 mod queue_service_client;
 pub use pop_receipt_client::PopReceiptClient;
 pub use queue_client::{AsQueueClient, QueueClient};
-pub use queue_service_client::{AsQueueServiceClient, QueueServiceClient};
+pub use queue_producer::QueueProducer;
+pub use queue_service_client::{
+    AsQueueServiceClient, QueueServiceClient, QueueServiceClientBuilder,
+};
@@ -1,7 +1,18 @@
 use crate::{operations::*, PopReceipt, PopReceiptClient, QueueStoredAccessPolicy};
-use azure_core::{prelude::*, Context, Request, Response};
-use azure_storage::clients::{ServiceType, StorageClient};
+use azure_core::{
+    error::{Error, ErrorKind},
+    prelude::*,
+    Context, Request, Response,
+};
+use azure_storage::{
+    clients::{ServiceType, StorageClient, StorageCredentials},
+    shared_access_signature::{
+        service_sas::{QueueSasPermissions, QueueSharedAccessSignature},
+        SasCredentialsError, SasToken,
+    },
+};
 use std::fmt::Debug;
+use time::OffsetDateTime;
 
 pub trait AsQueueClient<QN: Into<String>> {
     fn queue_client(&self, queue_name: QN) -> QueueClient;
@@ -82,6 +93,17 @@ impl QueueClient {
         PutMessageBuilder::new(self.clone(), message.into())
     }
 
+    /// Puts many messages in the queue, sending the requests in parallel.
+    ///
+    /// There is no batch operation in the service: see [`PutMessagesBuilder`] for the semantics.
+    pub fn put_messages<I, S>(&self, messages: I) -> PutMessagesBuilder
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        PutMessagesBuilder::new(self.clone(), messages.into_iter().map(Into::into).collect())
+    }
+
     /// Peeks, without removing, one or more messages.
     pub fn peek_messages(&self) -> PeekMessagesBuilder {
         PeekMessagesBuilder::new(self.clone())
@@ -102,10 +124,72 @@ impl QueueClient {
         PopReceiptClient::new(self.clone(), pop_receipt.into())
     }
 
+    /// Create a shared access signature.
+    ///
+    /// Fails with a [`SasCredentialsError`] if the client was not created with the account key.
+    pub fn shared_access_signature(
+        &self,
+        permissions: QueueSasPermissions,
+        expiry: OffsetDateTime,
+    ) -> azure_core::Result<QueueSharedAccessSignature> {
+        match self.storage_client.storage_credentials() {
+            StorageCredentials::Key(account, ref key) => {
+                let canonicalized_resource = format!("/queue/{}/{}", account, self.queue_name());
+                Ok(QueueSharedAccessSignature::new(
+                    key.to_string(),
+                    canonicalized_resource,
+                    permissions,
+                    expiry,
+                ))
+            }
+            credentials => Err(SasCredentialsError::from(credentials).into()),
+        }
+    }
+
+    /// Create a signed queue url
+    pub fn generate_signed_queue_url<T>(&self, signature: &T) -> azure_core::Result<url::Url>
+    where
+        T: SasToken,
+    {
+        let mut url = self.url()?;
+        url.set_query(Some(&signature.token()));
+        Ok(url)
+    }
+
+    /// Create a signed queue url that only allows to put messages in the queue.
+    ///
+    /// The url can be handed to untrusted parties, like edge devices, which use it to create a
+    /// [`QueueProducer`](crate::QueueProducer). The `add` permission is always granted, and only
+    /// the `update` permission may be granted in addition: permissions allowing to read or
+    /// process messages are rejected.
+    pub fn producer_sas(
+        &self,
+        expiry: OffsetDateTime,
+        permissions: QueueSasPermissions,
+    ) -> azure_core::Result<url::Url> {
+        if permissions.read || permissions.process {
+            return Err(Error::message(
+                ErrorKind::Other,
+                "a producer SAS cannot grant the read or process permissions",
+            ));
+        }
+        let permissions = QueueSasPermissions {
+            add: true,
+            ..permissions
+        };
+        let signature = self.shared_access_signature(permissions, expiry)?;
+        self.generate_signed_queue_url(&signature)
+    }
+
     pub fn queue_name(&self) -> &str {
         &self.queue_name
     }
 
+    /// Full URL for the queue.
+    pub fn url(&self) -> azure_core::Result<url::Url> {
+        self.url_with_segments(None)
+    }
+
     pub(crate) async fn send(
         &self,
         context: &mut Context,
@@ -129,6 +213,66 @@ impl QueueClient {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QueueProducer;
+
+    fn queue_client() -> QueueClient {
+        StorageClient::new_access_key("account", "a2V5").queue_client("queue")
+    }
+
+    #[test]
+    fn producer_sas_can_only_add() {
+        let expiry = OffsetDateTime::from_unix_timestamp(1700000000).unwrap();
+        let url = queue_client()
+            .producer_sas(expiry, QueueSasPermissions::default())
+            .unwrap();
+        assert_eq!(url.path(), "/queue");
+        assert!(url.query_pairs().any(|(k, v)| k == "sp" && v == "a"));
+
+        let producer = QueueProducer::from_sas_url(&url).unwrap();
+        assert_eq!(producer.queue_name(), "queue");
+
+        let permissions = QueueSasPermissions {
+            process: true,
+            ..Default::default()
+        };
+        assert!(queue_client().producer_sas(expiry, permissions).is_err());
+    }
+
+    #[test]
+    fn signing_requires_the_account_key() {
+        let expiry = OffsetDateTime::from_unix_timestamp(1700000000).unwrap();
+        let signature = queue_client()
+            .shared_access_signature(QueueSasPermissions::default(), expiry)
+            .unwrap();
+        assert!(signature.token().contains("sig="));
+
+        let queue_client =
+            StorageClient::new_bearer_token("account", "token").queue_client("queue");
+        let error = queue_client
+            .shared_access_signature(QueueSasPermissions::default(), expiry)
+            .unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::Credential);
+        assert_eq!(
+            error.downcast_ref::<SasCredentialsError>(),
+            Some(&SasCredentialsError::TokenCredential)
+        );
+
+        let queue_client = StorageClient::new_sas_token("account", "sv=2020-08-04&sig=signature")
+            .unwrap()
+            .queue_client("queue");
+        let error = queue_client
+            .shared_access_signature(QueueSasPermissions::default(), expiry)
+            .unwrap_err();
+        assert_eq!(
+            error.downcast_ref::<SasCredentialsError>(),
+            Some(&SasCredentialsError::NoAccountKey)
+        );
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "test_integration")]
 mod integration_tests {
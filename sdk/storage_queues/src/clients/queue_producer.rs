@@ -0,0 +1,74 @@
+use crate::{
+    clients::AsQueueClient,
+    operations::{PutMessageBuilder, PutMessagesBuilder},
+    QueueClient,
+};
+use azure_core::error::{Error, ErrorKind};
+use azure_storage::clients::StorageClient;
+use url::Url;
+
+/// A client that can only put messages in a single queue.
+///
+/// It is created from a signed queue url, as generated by
+/// [`QueueClient::producer_sas`](crate::QueueClient::producer_sas), so that devices producing
+/// messages never need the account key.
+#[derive(Debug, Clone)]
+pub struct QueueProducer {
+    queue_client: QueueClient,
+}
+
+impl QueueProducer {
+    /// Create a producer from a signed queue url, for example
+    /// `https://account.queue.core.windows.net/queue?sv=...&sig=...`.
+    pub fn from_sas_url(url: &Url) -> azure_core::Result<Self> {
+        let sas_token = url
+            .query()
+            .ok_or_else(|| Error::message(ErrorKind::Credential, "the queue url is not signed"))?;
+        let mut segments = url
+            .path_segments()
+            .map(|segments| segments.filter(|s| !s.is_empty()).collect::<Vec<_>>())
+            .unwrap_or_default();
+        let queue_name = segments
+            .pop()
+            .ok_or_else(|| Error::message(ErrorKind::Other, "the url does not name a queue"))?
+            .to_owned();
+        let host = url
+            .host_str()
+            .ok_or_else(|| Error::message(ErrorKind::Other, "the queue url has no host"))?;
+
+        // the account name is not needed to authenticate with a SAS, but it is part of the path
+        // of emulator urls (`http://127.0.0.1:10001/devstoreaccount1/queue`)
+        let account = segments
+            .first()
+            .copied()
+            .unwrap_or_else(|| host.split('.').next().unwrap_or(host));
+        let mut queue_endpoint = url.clone();
+        queue_endpoint.set_query(None);
+        queue_endpoint.set_path(&segments.join("/"));
+
+        let storage_client = StorageClient::new_connection_string(&format!(
+            "AccountName={account};SharedAccessSignature={sas_token};QueueEndpoint={queue_endpoint}"
+        ))?;
+        Ok(Self {
+            queue_client: storage_client.queue_client(queue_name),
+        })
+    }
+
+    /// Puts a message in the queue.
+    pub fn put_message<S: Into<String>>(&self, message: S) -> PutMessageBuilder {
+        self.queue_client.put_message(message)
+    }
+
+    /// Puts many messages in the queue, sending the requests in parallel.
+    pub fn put_messages<I, S>(&self, messages: I) -> PutMessagesBuilder
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.queue_client.put_messages(messages)
+    }
+
+    pub fn queue_name(&self) -> &str {
+        self.queue_client.queue_name()
+    }
+}
@@ -1,7 +1,12 @@
 use crate::{operations::*, QueueServiceProperties};
-use azure_core::{Context, Request, Response};
-use azure_storage::clients::{ServiceType, StorageClient};
+use azure_core::{ClientOptions, Context, Request, Response};
+use azure_storage::{
+    audit::{AuditHook, AuditPolicy},
+    clients::{ServiceType, StorageClient, StorageCredentials},
+    CloudLocation,
+};
 use std::fmt::Debug;
+use std::sync::Arc;
 
 pub trait AsQueueServiceClient {
     fn queue_service_client(&self) -> QueueServiceClient;
@@ -13,6 +18,106 @@ impl AsQueueServiceClient for StorageClient {
     }
 }
 
+/// A builder for the queue service client.
+#[derive(Debug, Clone)]
+pub struct QueueServiceClientBuilder {
+    cloud_location: CloudLocation,
+    options: ClientOptions,
+}
+
+impl QueueServiceClientBuilder {
+    /// Create a new instance of `QueueServiceClientBuilder`.
+    #[must_use]
+    pub fn new(account: impl Into<String>, credentials: impl Into<StorageCredentials>) -> Self {
+        Self::with_location(CloudLocation::Public {
+            account: account.into(),
+            credentials: credentials.into(),
+        })
+    }
+
+    /// Create a new instance of `QueueServiceClientBuilder` with a cloud location.
+    #[must_use]
+    pub fn with_location(cloud_location: CloudLocation) -> Self {
+        Self {
+            options: ClientOptions::default(),
+            cloud_location,
+        }
+    }
+
+    /// Use the emulator with default settings
+    #[must_use]
+    pub fn emulator() -> Self {
+        Self::with_location(CloudLocation::Emulator {
+            address: "127.0.0.1".to_owned(),
+            port: 10001,
+        })
+    }
+
+    /// Convert the builder into a `QueueServiceClient` instance.
+    pub fn build(self) -> azure_core::Result<QueueServiceClient> {
+        let storage_client =
+            StorageClient::from_service_location(&self.cloud_location, ServiceType::Queue)?;
+        Ok(QueueServiceClient::new(
+            storage_client.with_options(self.options),
+        ))
+    }
+
+    /// Set the cloud location.
+    #[must_use]
+    pub fn cloud_location(mut self, cloud_location: CloudLocation) -> Self {
+        self.cloud_location = cloud_location;
+        self
+    }
+
+    /// Set the endpoint of the service, e.g. a private endpoint of the account, keeping the
+    /// credentials.
+    #[must_use]
+    pub fn endpoint(mut self, endpoint: impl Into<String>) -> Self {
+        self.cloud_location = self.cloud_location.with_endpoint(endpoint);
+        self
+    }
+
+    /// Set the credentials, keeping the endpoint.
+    #[must_use]
+    pub fn credential(mut self, credentials: impl Into<StorageCredentials>) -> Self {
+        self.cloud_location = self.cloud_location.with_credentials(credentials.into());
+        self
+    }
+
+    /// Set the retry options.
+    #[must_use]
+    pub fn retry(mut self, retry: impl Into<azure_core::RetryOptions>) -> Self {
+        self.options = self.options.retry(retry);
+        self
+    }
+
+    /// Set the transport options.
+    #[must_use]
+    pub fn transport(mut self, transport: impl Into<azure_core::TransportOptions>) -> Self {
+        self.options = self.options.transport(transport);
+        self
+    }
+
+    /// Call `hook` before each request is sent and after its response is received, e.g. to keep
+    /// an audit trail of the accesses to the messages.
+    #[must_use]
+    pub fn audit_hook(mut self, hook: Arc<dyn AuditHook>) -> Self {
+        self.options
+            .per_retry_policies_mut()
+            .push(Arc::new(AuditPolicy::new(hook)));
+        self
+    }
+
+    /// Override all of the client options.
+    ///
+    /// *Warning!*: This overrides all client options that have been previously set on this builder.
+    #[must_use]
+    pub fn client_options(mut self, options: impl Into<ClientOptions>) -> Self {
+        self.options = options.into();
+        self
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct QueueServiceClient {
     pub(crate) storage_client: StorageClient,
@@ -23,6 +128,21 @@ impl QueueServiceClient {
         Self { storage_client }
     }
 
+    /// Create a new `QueueServiceClientBuilder`.
+    #[must_use]
+    pub fn builder(
+        account: impl Into<String>,
+        credentials: impl Into<StorageCredentials>,
+    ) -> QueueServiceClientBuilder {
+        QueueServiceClientBuilder::new(account, credentials)
+    }
+
+    /// The storage client of the account, e.g. to create the blob or data lake clients with the
+    /// same credentials.
+    pub fn storage_client(&self) -> &StorageClient {
+        &self.storage_client
+    }
+
     pub fn list_queues(&self) -> ListQueuesBuilder {
         ListQueuesBuilder::new(self.clone())
     }
@@ -56,3 +176,104 @@ impl QueueServiceClient {
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::{mock::MockTransportCannedPolicy, RetryOptions, TransportOptions};
+    use azure_storage::audit::{RequestSummary, ResponseSummary};
+    use std::sync::Mutex;
+
+    #[derive(Debug, Default)]
+    struct RecordingHook {
+        urls: Mutex<Vec<String>>,
+    }
+
+    impl AuditHook for RecordingHook {
+        fn after_receive(&self, request: &RequestSummary, _response: &ResponseSummary) {
+            self.urls.lock().unwrap().push(request.url.clone());
+        }
+    }
+
+    #[tokio::test]
+    async fn calls_the_audit_hook() {
+        let hook = Arc::new(RecordingHook::default());
+        let client = QueueServiceClient::builder("account", StorageCredentials::anonymous())
+            .transport(TransportOptions::new_custom_policy(Arc::new(
+                MockTransportCannedPolicy::new(),
+            )))
+            .audit_hook(hook.clone())
+            .build()
+            .unwrap();
+
+        // no reply is canned: the request fails once it is recorded
+        let _ = client
+            .storage_client()
+            .queue_client("queue")
+            .get_metadata()
+            .into_future()
+            .await;
+        assert_eq!(
+            hook.urls.lock().unwrap().as_slice(),
+            ["https://account.queue.core.windows.net/queue?comp=metadata"]
+        );
+    }
+
+    #[test]
+    fn builds_the_client_of_the_emulator_at_its_port() {
+        let client = QueueServiceClientBuilder::emulator().build().unwrap();
+        assert_eq!(
+            client.storage_client().queue_storage_url().as_str(),
+            "http://127.0.0.1:10001/devstoreaccount1"
+        );
+
+        let client = QueueServiceClientBuilder::with_location(CloudLocation::Emulator {
+            address: "azurite".to_owned(),
+            port: 20001,
+        })
+        .build()
+        .unwrap();
+        assert_eq!(
+            client.storage_client().queue_storage_url().as_str(),
+            "http://azurite:20001/devstoreaccount1"
+        );
+    }
+
+    #[test]
+    fn the_endpoint_keeps_the_credentials() {
+        let client = QueueServiceClient::builder("account", StorageCredentials::anonymous())
+            .endpoint("https://account.privatelink.queue.core.windows.net")
+            .build()
+            .unwrap();
+        assert_eq!(
+            client.storage_client().queue_storage_url().as_str(),
+            "https://account.privatelink.queue.core.windows.net/"
+        );
+        assert!(matches!(
+            client.storage_client().storage_credentials(),
+            StorageCredentials::Anonymous
+        ));
+    }
+
+    #[test]
+    fn the_credential_keeps_the_endpoint() {
+        let location = CloudLocation::China {
+            account: "account".to_owned(),
+            credentials: StorageCredentials::anonymous(),
+        };
+        let client = QueueServiceClientBuilder::with_location(location)
+            .credential(StorageCredentials::bearer_token("token"))
+            .retry(RetryOptions::none())
+            .client_options(ClientOptions::default())
+            .build()
+            .unwrap();
+        assert_eq!(
+            client.storage_client().queue_storage_url().as_str(),
+            "https://account.queue.core.chinacloudapi.cn/"
+        );
+        assert!(matches!(
+            client.storage_client().storage_credentials(),
+            StorageCredentials::BearerToken(token) if token == "token"
+        ));
+    }
+}
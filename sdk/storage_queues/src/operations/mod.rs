@@ -10,6 +10,7 @@ mod get_queue_service_stats;
 mod list_queues;
 mod peek_messages;
 mod put_message;
+mod put_messages;
 mod set_queue_acl;
 mod set_queue_metadata;
 mod set_queue_service_properties;
@@ -27,6 +28,7 @@ pub use get_queue_service_stats::GetQueueServiceStatsBuilder;
 pub use list_queues::ListQueuesBuilder;
 pub use peek_messages::PeekMessagesBuilder;
 pub use put_message::PutMessageBuilder;
+pub use put_messages::PutMessagesBuilder;
 pub use set_queue_acl::SetQueueACLBuilder;
 pub use set_queue_metadata::SetQueueMetadataBuilder;
 pub use set_queue_service_properties::SetQueueServicePropertiesBuilder;
@@ -0,0 +1,212 @@
+use super::put_message::PutMessageResponse;
+use crate::prelude::*;
+use futures::StreamExt;
+
+operation! {
+    /// Puts many messages in the queue.
+    ///
+    /// The service has no batch operation, so each message is put with its own request. The
+    /// requests are sent in parallel, at most [`max_concurrency`](Self::max_concurrency) at a
+    /// time, so the messages are not necessarily enqueued in order, and some of them can be
+    /// enqueued while others fail: the response has the result of each message.
+    PutMessages,
+    client: QueueClient,
+    messages: Vec<String>,
+    ?max_concurrency: usize,
+    ?visibility_timeout: VisibilityTimeout,
+    ?ttl: MessageTTL
+}
+
+impl PutMessagesBuilder {
+    /// The default number of messages put at the same time.
+    pub const DEFAULT_MAX_CONCURRENCY: usize = 10;
+
+    /// Convert into a future
+    ///
+    /// The future does not fail: each message has its own result in the response.
+    pub fn into_future(self) -> PutMessages {
+        Box::pin(async move {
+            let client = self.client;
+            let visibility_timeout = self.visibility_timeout;
+            let ttl = self.ttl;
+            let context = self.context;
+            let max_concurrency = self
+                .max_concurrency
+                .unwrap_or(Self::DEFAULT_MAX_CONCURRENCY)
+                .max(1);
+            let puts = self.messages.into_iter().map(|message| {
+                let mut builder = client.put_message(message).context(context.clone());
+                if let Some(visibility_timeout) = visibility_timeout.clone() {
+                    builder = builder.visibility_timeout(visibility_timeout);
+                }
+                if let Some(ttl) = ttl.clone() {
+                    builder = builder.ttl(ttl);
+                }
+                builder.into_future()
+            });
+            let results = futures::stream::iter(puts)
+                .buffered(max_concurrency)
+                .collect()
+                .await;
+            Ok(PutMessagesResponse { results })
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct PutMessagesResponse {
+    /// The result of each message, in the order they were given.
+    pub results: Vec<azure_core::Result<PutMessageResponse>>,
+}
+
+impl PutMessagesResponse {
+    /// Whether all the messages were put in the queue.
+    pub fn is_success(&self) -> bool {
+        self.results.iter().all(Result::is_ok)
+    }
+
+    /// The errors of the messages which were not put in the queue, with their index.
+    pub fn errors(&self) -> impl Iterator<Item = (usize, &azure_core::Error)> {
+        self.results
+            .iter()
+            .enumerate()
+            .filter_map(|(index, result)| result.as_ref().err().map(|error| (index, error)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_core::{
+        headers::Headers, mock::MockTransportCannedPolicy, Body, StatusCode, TransportOptions,
+    };
+    use std::sync::Arc;
+
+    fn queue_client(transport: Arc<MockTransportCannedPolicy>) -> QueueClient {
+        QueueServiceClientBuilder::new("account", StorageCredentials::anonymous())
+            .transport(TransportOptions::new_custom_policy(transport))
+            .build()
+            .unwrap()
+            .storage_client()
+            .queue_client("queue")
+    }
+
+    fn response_headers() -> Headers {
+        let mut headers = Headers::new();
+        headers.insert("x-ms-request-id", "00000000-0000-0000-0000-000000000000");
+        headers.insert("x-ms-version", "2019-12-12");
+        headers.insert("date", "Thu, 15 Oct 2026 10:00:00 GMT");
+        headers.insert("server", "Windows-Azure-Queue/1.0");
+        headers
+    }
+
+    fn put_message_response(message_id: &str) -> String {
+        format!(
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?>\
+            <QueueMessagesList><QueueMessage>\
+            <MessageId>{message_id}</MessageId>\
+            <InsertionTime>Thu, 15 Oct 2026 10:00:00 GMT</InsertionTime>\
+            <ExpirationTime>Thu, 22 Oct 2026 10:00:00 GMT</ExpirationTime>\
+            <PopReceipt>receipt</PopReceipt>\
+            <TimeNextVisible>Thu, 15 Oct 2026 10:00:00 GMT</TimeNextVisible>\
+            </QueueMessage></QueueMessagesList>"
+        )
+    }
+
+    fn body(request: &azure_core::Request) -> String {
+        match request.body() {
+            Body::Bytes(bytes) => String::from_utf8(bytes.to_vec()).unwrap(),
+            Body::SeekableStream(_) => panic!("the message is not streamed"),
+        }
+    }
+
+    #[tokio::test]
+    async fn puts_each_message_with_its_own_request() {
+        let transport = Arc::new(
+            MockTransportCannedPolicy::new()
+                .reply(
+                    StatusCode::Created,
+                    response_headers(),
+                    put_message_response("1"),
+                )
+                .reply(
+                    StatusCode::Created,
+                    response_headers(),
+                    put_message_response("2"),
+                )
+                .reply(
+                    StatusCode::Created,
+                    response_headers(),
+                    put_message_response("3"),
+                ),
+        );
+
+        let response = queue_client(transport.clone())
+            .put_messages(["first", "second", "third"])
+            .max_concurrency(2)
+            .visibility_timeout(VisibilityTimeout::new(std::time::Duration::from_secs(30)))
+            .ttl(MessageTTL::new(std::time::Duration::from_secs(3600)))
+            .into_future()
+            .await
+            .unwrap();
+
+        assert!(response.is_success());
+        let message_ids: Vec<_> = response
+            .results
+            .iter()
+            .map(|result| result.as_ref().unwrap().queue_message.message_id.as_str())
+            .collect();
+        assert_eq!(message_ids, ["1", "2", "3"]);
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 3);
+        for (request, message) in requests.iter().zip(["first", "second", "third"]) {
+            assert_eq!(request.method(), &azure_core::Method::Post);
+            assert_eq!(request.url().path(), "/queue/messages");
+            let query: Vec<_> = request.url().query_pairs().collect();
+            assert!(query.contains(&("visibilitytimeout".into(), "30".into())));
+            assert!(query.contains(&("messagettl".into(), "3600".into())));
+            assert_eq!(
+                body(request),
+                format!("<QueueMessage><MessageText>{message}</MessageText></QueueMessage>")
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn keeps_the_result_of_each_message() {
+        let transport = Arc::new(
+            MockTransportCannedPolicy::new()
+                .reply(
+                    StatusCode::Created,
+                    response_headers(),
+                    put_message_response("1"),
+                )
+                .reply(StatusCode::BadRequest, response_headers(), "")
+                .reply(
+                    StatusCode::Created,
+                    response_headers(),
+                    put_message_response("3"),
+                ),
+        );
+
+        let response = queue_client(transport.clone())
+            .put_messages(["first", "second", "third"])
+            .max_concurrency(1)
+            .into_future()
+            .await
+            .unwrap();
+
+        assert!(!response.is_success());
+        assert_eq!(
+            response
+                .errors()
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>(),
+            [1]
+        );
+        assert!(response.results[0].is_ok());
+        assert!(response.results[2].is_ok());
+        assert_eq!(transport.requests().len(), 3);
+    }
+}
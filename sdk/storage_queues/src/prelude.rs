@@ -1,4 +1,6 @@
 pub use crate::{
     clients::AsQueueClient, AsQueueServiceClient, MessageTTL, NumberOfMessages, PopReceipt,
-    QueueClient, QueueServiceClient, QueueStoredAccessPolicy, VisibilityTimeout,
+    QueueClient, QueueProducer, QueueServiceClient, QueueServiceClientBuilder,
+    QueueStoredAccessPolicy, VisibilityTimeout,
 };
+pub use azure_storage::{clients::StorageCredentials, CloudLocation};
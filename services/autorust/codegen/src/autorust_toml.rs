@@ -14,6 +14,10 @@ pub struct PackageConfig {
     /// A section for workarounds that apply to properties
     #[serde(default)]
     pub properties: Properties,
+
+    /// The hand-written modules of the crate, which are kept when it is generated again
+    #[serde(default)]
+    pub modules: Vec<Module>,
 }
 
 const NO_LIMIT: i32 = -1;
@@ -96,6 +100,16 @@ pub struct Properties {
     pub invalid_type: Vec<Vec<String>>,
 }
 
+/// A hand-written module, in `src/{name}.rs`
+#[derive(Deserialize, Debug, Default)]
+pub struct Module {
+    /// The name of the module
+    pub name: String,
+
+    /// The feature enabling the module, usually the one of the tag it builds on
+    pub feature: Option<String>,
+}
+
 impl<'a> PackageConfig {
     pub fn default_tag(&self) -> Option<&str> {
         self.tags.default.as_deref()
@@ -346,4 +360,23 @@ mod tests {
         assert_eq!("innererror", config.properties.boxed[0][2]);
         Ok(())
     }
+
+    #[test]
+    fn modules() -> Result<()> {
+        let config: PackageConfig = toml::from_str(
+            r#"
+            [[modules]]
+            name = "tags"
+            feature = "package-resources-2021-04"
+
+            [[modules]]
+            name = "helpers"
+            "#,
+        )?;
+        assert_eq!(2, config.modules.len());
+        assert_eq!("tags", config.modules[0].name);
+        assert_eq!(Some("package-resources-2021-04"), config.modules[0].feature.as_deref());
+        assert_eq!(None, config.modules[1].feature);
+        Ok(())
+    }
 }
@@ -1,7 +1,7 @@
 use crate::{
     autorust_toml, cargo_toml, io, lib_rs,
     readme_md::{self, ReadmeMd},
-    CrateConfig, Error, Result, RunConfig, SpecReadme,
+    CrateConfig, Error, ErrorKind, Result, ResultExt, RunConfig, SpecReadme,
 };
 use std::{collections::HashMap, fs};
 
@@ -33,6 +33,15 @@ pub fn gen_crate(spec: &SpecReadme, run_config: &RunConfig, output_folder: &str)
     }
 
     let src_folder = io::join(output_folder, "src")?;
+    // the hand-written modules are written back once the crate is generated
+    let modules = package_config
+        .modules
+        .iter()
+        .map(|module| {
+            let path = io::join(&src_folder, format!("{}.rs", module.name))?;
+            Ok((io::read_file(&path)?, path))
+        })
+        .collect::<Result<Vec<_>>>()?;
     if src_folder.exists() {
         fs::remove_dir_all(&src_folder)?;
     }
@@ -72,7 +81,10 @@ pub fn gen_crate(spec: &SpecReadme, run_config: &RunConfig, output_folder: &str)
     };
     let default_tag = cargo_toml::get_default_tag(tags, default_tag_name);
     cargo_toml::create(package_name, tags, default_tag, &io::join(output_folder, "Cargo.toml")?)?;
-    lib_rs::create(tags, &io::join(src_folder, "lib.rs")?, false)?;
+    lib_rs::create(tags, &package_config.modules, &io::join(&src_folder, "lib.rs")?, false)?;
+    for (code, path) in modules {
+        fs::write(&path, code).with_context(ErrorKind::Io, || format!("write file {path}"))?;
+    }
     let readme = ReadmeMd {
         package_name,
         readme_url: readme_md::url(spec.readme().as_str()),
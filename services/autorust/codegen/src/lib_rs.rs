@@ -1,11 +1,11 @@
-use crate::{config_parser::Tag, identifier::parse_ident, write_file};
+use crate::{autorust_toml::Module, config_parser::Tag, identifier::parse_ident, write_file};
 use crate::{ErrorKind, Result, ResultExt};
 use camino::Utf8Path;
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, ToTokens};
 
-pub fn create(tags: &[&Tag], path: &Utf8Path, print_writing_file: bool) -> Result<()> {
-    write_file(path, &create_body(tags)?.into_token_stream(), print_writing_file)
+pub fn create(tags: &[&Tag], modules: &[Module], path: &Utf8Path, print_writing_file: bool) -> Result<()> {
+    write_file(path, &create_body(tags, modules)?.into_token_stream(), print_writing_file)
 }
 
 struct Feature {
@@ -13,11 +13,17 @@ struct Feature {
     pub mod_name: Ident,
 }
 
+struct HandWrittenModule {
+    pub feature_name: Option<String>,
+    pub mod_name: Ident,
+}
+
 struct BodyCode {
     pub features: Vec<Feature>,
+    pub modules: Vec<HandWrittenModule>,
 }
 
-fn create_body(tags: &[&Tag]) -> Result<BodyCode> {
+fn create_body(tags: &[&Tag], modules: &[Module]) -> Result<BodyCode> {
     let features: Vec<Feature> = tags
         .iter()
         .map(|tag| {
@@ -26,7 +32,17 @@ fn create_body(tags: &[&Tag]) -> Result<BodyCode> {
             Ok(Feature { feature_name, mod_name })
         })
         .collect::<Result<_>>()?;
-    Ok(BodyCode { features })
+    let modules: Vec<HandWrittenModule> = modules
+        .iter()
+        .map(|module| {
+            let mod_name = parse_ident(&module.name).context(ErrorKind::Parse, "mod name")?;
+            Ok(HandWrittenModule {
+                feature_name: module.feature.clone(),
+                mod_name,
+            })
+        })
+        .collect::<Result<_>>()?;
+    Ok(BodyCode { features, modules })
 }
 
 impl ToTokens for BodyCode {
@@ -42,6 +58,17 @@ impl ToTokens for BodyCode {
                 pub use #mod_name::*;
             });
         }
+        for module in &self.modules {
+            let mod_name = &module.mod_name;
+            let cfg = module
+                .feature_name
+                .as_ref()
+                .map(|feature_name| quote! { #[cfg(feature = #feature_name)] });
+            cfgs.extend(quote! {
+                #cfg
+                pub mod #mod_name;
+            });
+        }
         tokens.extend(quote! {
             #![allow(clippy::module_inception)]
             #![allow(clippy::too_many_arguments)]
@@ -0,0 +1,38 @@
+/*
+Purges content from a CDN endpoint and waits for the purge to complete, similar to:
+az cdn endpoint purge --resource-group $RESOURCE_GROUP_NAME --profile-name $PROFILE_NAME --name $ENDPOINT_NAME --content-paths /images/logo.png
+
+export RESOURCE_GROUP_NAME=...
+export PROFILE_NAME=...
+export ENDPOINT_NAME=...
+cargo run --example purge_endpoint
+*/
+
+use azure_identity::AzureCliCredential;
+use azure_mgmt_cdn::models::PurgeParameters;
+use std::sync::Arc;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let resource_group_name = std::env::var("RESOURCE_GROUP_NAME").expect("Set env variable RESOURCE_GROUP_NAME first!");
+    let profile_name = std::env::var("PROFILE_NAME").expect("Set env variable PROFILE_NAME first!");
+    let endpoint_name = std::env::var("ENDPOINT_NAME").expect("Set env variable ENDPOINT_NAME first!");
+
+    let credential = Arc::new(AzureCliCredential::new());
+    let subscription_id = AzureCliCredential::get_subscription()?;
+    let client = azure_mgmt_cdn::Client::builder(credential).build();
+
+    client
+        .purge_endpoint(
+            resource_group_name,
+            profile_name,
+            endpoint_name,
+            PurgeParameters::new(vec!["/images/logo.png".to_owned()]),
+            subscription_id,
+        )?
+        .send()
+        .await?;
+    println!("purge completed");
+
+    Ok(())
+}
@@ -23,3 +23,5 @@ pub use package_2019_12::*;
 pub mod package_2019_06_preview;
 #[cfg(all(feature = "package-2019-06-preview", not(feature = "no-default-tag")))]
 pub use package_2019_06_preview::*;
+#[cfg(feature = "package-2021-06")]
+pub mod purge;
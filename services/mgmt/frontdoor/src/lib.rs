@@ -23,3 +23,5 @@ pub use package_2020_05::*;
 pub mod package_2020_04;
 #[cfg(all(feature = "package-2020-04", not(feature = "no-default-tag")))]
 pub use package_2020_04::*;
+#[cfg(feature = "package-2022-05")]
+pub mod purge;
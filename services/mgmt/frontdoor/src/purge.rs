@@ -0,0 +1,116 @@
+//! Purging of cached content.
+//!
+//! Purges are long running operations: the service accepts the request and removes the content
+//! from the edge nodes asynchronously, which can take several minutes. The builder in this
+//! module sends the purge request and then polls the operation until it completes.
+//!
+//! This module is not generated and must be kept when regenerating the crate.
+
+use crate::package_2022_05::{models, Client};
+use azure_core::headers::AUTHORIZATION;
+use azure_core::lro::LroPoller;
+use azure_core::{CancellationToken, Method, Request, Url};
+use bytes::Bytes;
+use std::time::Duration;
+
+const API_VERSION: &str = "2021-06-01";
+
+impl Client {
+    /// Purges content from a Front Door and waits for the purge to complete.
+    ///
+    /// Arguments:
+    /// * `resource_group_name`: Name of the Resource group within the Azure subscription.
+    /// * `front_door_name`: Name of the Front Door which is globally unique.
+    /// * `content_file_paths`: The paths to the content to be purged, e.g. `/pictures/city.png`
+    ///   for a single file or `/pictures/*` for a whole directory.
+    /// * `subscription_id`: The subscription credentials which uniquely identify the Microsoft Azure subscription. The subscription ID forms part of the URI for every service call.
+    pub fn purge_front_door(
+        &self,
+        resource_group_name: impl Into<String>,
+        front_door_name: impl Into<String>,
+        content_file_paths: impl Into<models::PurgeParameters>,
+        subscription_id: impl Into<String>,
+    ) -> azure_core::Result<PurgeBuilder> {
+        let path = format!(
+            "subscriptions/{}/resourceGroups/{}/providers/Microsoft.Network/frontDoors/{}/purge",
+            subscription_id.into(),
+            resource_group_name.into(),
+            front_door_name.into()
+        );
+        PurgeBuilder::new(self.clone(), &path, &content_file_paths.into())
+    }
+}
+
+/// Sends a purge request and waits for the purge to complete.
+#[derive(Clone)]
+pub struct PurgeBuilder {
+    client: Client,
+    url: Url,
+    body: Bytes,
+    poller: LroPoller,
+    cancellation_token: Option<CancellationToken>,
+}
+
+impl PurgeBuilder {
+    fn new<T: serde::Serialize>(client: Client, path: &str, body: &T) -> azure_core::Result<Self> {
+        let mut url = Url::parse(&format!("{}/{}", client.endpoint(), path))?;
+        url.query_pairs_mut().append_pair(azure_core::query_param::API_VERSION, API_VERSION);
+        Ok(Self {
+            client,
+            url,
+            body: azure_core::to_json(body)?,
+            poller: LroPoller::default(),
+            cancellation_token: None,
+        })
+    }
+
+    /// How long to wait between status checks when the service does not specify it with a
+    /// `Retry-After` header. Defaults to 10 seconds.
+    pub fn polling_interval(mut self, polling_interval: Duration) -> Self {
+        self.poller = self.poller.polling_interval(polling_interval);
+        self
+    }
+
+    /// How long to wait for the purge to complete, at most. Defaults to 30 minutes.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.poller = self.poller.timeout(timeout);
+        self
+    }
+
+    /// Stops waiting for the purge when the token is cancelled. The purge itself is not cancelled
+    /// once the service accepted it.
+    pub fn cancellation_token(mut self, cancellation_token: CancellationToken) -> Self {
+        self.cancellation_token = Some(cancellation_token);
+        self
+    }
+
+    /// Sends the purge request and waits for the purge to complete.
+    pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<()>> {
+        Box::pin(async move {
+            let purge = async {
+                let mut request = self.request(Method::Post, self.url.clone()).await?;
+                request.insert_header("content-type", "application/json");
+                request.set_body(self.body.clone());
+                let response = self.client.send(&mut request).await?;
+                let this = &self;
+                self.poller
+                    .wait(response, move |url| async move {
+                        let mut request = this.request(Method::Get, url).await?;
+                        this.client.send(&mut request).await
+                    })
+                    .await
+            };
+            match &self.cancellation_token {
+                Some(token) => token.run(purge).await,
+                None => purge.await,
+            }
+        })
+    }
+
+    async fn request(&self, method: Method, url: Url) -> azure_core::Result<Request> {
+        let mut request = Request::new(url, method);
+        let token_response = self.client.token_credential().get_token(&self.client.scopes().join(" ")).await?;
+        request.insert_header(AUTHORIZATION, format!("Bearer {}", token_response.token.secret()));
+        Ok(request)
+    }
+}
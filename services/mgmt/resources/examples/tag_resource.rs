@@ -0,0 +1,28 @@
+/*
+Adds a tag to a resource and removes another one, similar to:
+az tag update --resource-id $RESOURCE_ID --operation merge --tags environment=production
+az tag update --resource-id $RESOURCE_ID --operation delete --tags temporary
+
+export RESOURCE_ID=/subscriptions/.../resourceGroups/.../providers/Microsoft.Storage/storageAccounts/...
+cargo run --package azure_mgmt_resources --example tag_resource
+*/
+
+use azure_identity::AzureCliCredential;
+use std::env;
+use std::sync::Arc;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let credential = Arc::new(AzureCliCredential::new());
+    let resource_id = env::var("RESOURCE_ID").map_err(|_| "RESOURCE_ID required")?;
+    let client = azure_mgmt_resources::Client::builder(credential).build();
+
+    let tags = client
+        .update_tags(resource_id)
+        .tag("environment", "production")
+        .remove_tag("temporary")
+        .send()
+        .await?;
+    println!("tags: {:#?}", tags);
+    Ok(())
+}
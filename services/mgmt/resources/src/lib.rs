@@ -23,3 +23,5 @@ pub use package_locks_2020_05::*;
 pub mod package_features_2021_07;
 #[cfg(all(feature = "package-features-2021-07", not(feature = "no-default-tag")))]
 pub use package_features_2021_07::*;
+#[cfg(feature = "package-resources-2021-04")]
+pub mod tags;
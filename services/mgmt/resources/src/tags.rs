@@ -0,0 +1,353 @@
+//! Updating the tags of any resource.
+//!
+//! The tags of every resource, resource group and subscription can be updated through the tags
+//! API of Azure Resource Manager, whatever the resource provider and its API version, so tagging
+//! sweeps across resource types only need this client.
+//!
+//! This module is not generated: it is listed in the `modules` of `autorust.toml`, so that it is
+//! kept when the crate is generated again.
+
+use crate::package_resources_2021_04::{models, Client};
+use azure_core::error::{Error, ErrorKind};
+use azure_core::headers::{Headers, AUTHORIZATION, ETAG, IF_MATCH};
+use azure_core::{Method, Request, StatusCode, Url};
+use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet};
+
+const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+/// The tags of a resource, by name.
+pub type TagMap = BTreeMap<String, String>;
+
+impl Client {
+    /// Updates the tags of a resource, resource group or subscription.
+    ///
+    /// Arguments:
+    /// * `resource_id`: The id of the resource, e.g.
+    ///   `/subscriptions/{subscription_id}/resourceGroups/{resource_group_name}/providers/Microsoft.Storage/storageAccounts/{name}`.
+    pub fn update_tags(&self, resource_id: impl Into<String>) -> UpdateTagsBuilder {
+        UpdateTagsBuilder {
+            client: self.clone(),
+            scope: resource_id.into().trim_start_matches('/').to_owned(),
+            set: TagMap::new(),
+            remove: BTreeSet::new(),
+            replace: false,
+            api_version: None,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+        }
+    }
+}
+
+/// Updates the tags of a resource.
+///
+/// By default the tags are updated with the patch operations of the tags API, without reading
+/// them first: tags are removed with a `Delete` patch and set with a `Merge` patch, or all of them
+/// replaced with a `Replace` patch. Each patch is applied atomically by the service, so the tags
+/// changed concurrently by others are kept.
+///
+/// With the [`api_version`](Self::api_version) of the resource provider, the tags are instead read
+/// and written through the resource itself: the write is conditional on the `ETag` of the read when
+/// the resource has one, and the update is attempted again if the resource changed in between, at
+/// most [`max_attempts`](Self::max_attempts) times.
+#[derive(Clone)]
+pub struct UpdateTagsBuilder {
+    client: Client,
+    scope: String,
+    set: TagMap,
+    remove: BTreeSet<String>,
+    replace: bool,
+    api_version: Option<String>,
+    max_attempts: u32,
+}
+
+impl UpdateTagsBuilder {
+    /// Sets the tag `name` to `value`, adding it if it does not exist.
+    pub fn tag(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        let name = name.into();
+        self.remove.remove(&name);
+        self.set.insert(name, value.into());
+        self
+    }
+
+    /// Sets many tags, see [`tag`](Self::tag).
+    pub fn tags<N: Into<String>, V: Into<String>>(self, tags: impl IntoIterator<Item = (N, V)>) -> Self {
+        tags.into_iter().fold(self, |builder, (name, value)| builder.tag(name, value))
+    }
+
+    /// Removes the tag `name`, if it exists.
+    pub fn remove_tag(mut self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        self.set.remove(&name);
+        self.remove.insert(name);
+        self
+    }
+
+    /// Removes the tags which are not set with this builder.
+    pub fn replace_all(mut self, replace: bool) -> Self {
+        self.replace = replace;
+        self
+    }
+
+    /// Reads and writes the tags through the resource with this API version of its resource
+    /// provider, conditionally on the `ETag` of the resource.
+    pub fn api_version(mut self, api_version: impl Into<String>) -> Self {
+        self.api_version = Some(api_version.into());
+        self
+    }
+
+    /// How many times the update is attempted when the resource changes concurrently. Defaults to
+    /// 3. Only used with an [`api_version`](Self::api_version).
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Updates the tags and returns the tags of the resource after the update.
+    pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<TagMap>> {
+        Box::pin(async move {
+            let api_version = match &self.api_version {
+                Some(api_version) => api_version.clone(),
+                None => return self.update_with_tags_api().await,
+            };
+
+            let mut attempt = 1;
+            loop {
+                match self.update_with_resource(&api_version).await {
+                    // the resource changed since it was read
+                    Err(error) if is_precondition_failed(&error) && attempt < self.max_attempts => attempt += 1,
+                    result => return result,
+                }
+            }
+        })
+    }
+
+    async fn update_with_tags_api(&self) -> azure_core::Result<TagMap> {
+        let tags_client = self.client.tags_client();
+        let mut tags = TagMap::new();
+        for patch in self.tags_api_patches() {
+            let updated = tags_client.update_at_scope(self.scope.clone(), patch).into_body().await?;
+            tags = from_tags(updated.properties.tags.as_ref())?;
+        }
+        Ok(tags)
+    }
+
+    /// The patches of the tags API applying the update, in order.
+    ///
+    /// Removals are applied before the tags set, as a tag removed and then set again is set.
+    fn tags_api_patches(&self) -> Vec<models::TagsPatchResource> {
+        use models::tags_patch_resource::Operation;
+        let patch = |operation, tags: &TagMap| models::TagsPatchResource {
+            operation: Some(operation),
+            properties: Some(to_tags(tags)),
+        };
+        if self.replace {
+            return vec![patch(Operation::Replace, &self.set)];
+        }
+        let mut patches = Vec::new();
+        if !self.remove.is_empty() {
+            // without a value, a tag is deleted whatever its value
+            let removed: TagMap = self.remove.iter().map(|name| (name.clone(), String::new())).collect();
+            patches.push(patch(Operation::Delete, &removed));
+        }
+        if !self.set.is_empty() || patches.is_empty() {
+            patches.push(patch(Operation::Merge, &self.set));
+        }
+        patches
+    }
+
+    async fn update_with_resource(&self, api_version: &str) -> azure_core::Result<TagMap> {
+        let mut request = self.resource_request(Method::Get, api_version).await?;
+        let response = self.client.send(&mut request).await?;
+        let (_, headers, body) = response.deconstruct();
+        let current: TaggedResource = azure_core::from_json(body.collect().await?)?;
+
+        let tags = self.apply(from_tags(current.tags.as_ref())?);
+        let mut request = self.resource_request(Method::Patch, api_version).await?;
+        request.insert_header("content-type", "application/json");
+        // resources without an ETag are updated unconditionally
+        if let Some(etag) = current.etag(&headers) {
+            request.insert_header(IF_MATCH, etag);
+        }
+        request.set_body(azure_core::to_json(&to_tags(&tags))?);
+        let body = self.client.send(&mut request).await?.into_body().collect().await?;
+        // the resource is not returned when it is updated asynchronously
+        match azure_core::from_json::<_, TaggedResource>(&body) {
+            Ok(TaggedResource { tags: Some(updated), .. }) => from_tags(Some(&updated)),
+            _ => Ok(tags),
+        }
+    }
+
+    async fn resource_request(&self, method: Method, api_version: &str) -> azure_core::Result<Request> {
+        let mut url = Url::parse(&format!("{}/{}", self.client.endpoint(), self.scope))?;
+        url.query_pairs_mut().append_pair(azure_core::query_param::API_VERSION, api_version);
+        let mut request = Request::new(url, method);
+        let token_response = self.client.token_credential().get_token(&self.client.scopes().join(" ")).await?;
+        request.insert_header(AUTHORIZATION, format!("Bearer {}", token_response.token.secret()));
+        Ok(request)
+    }
+
+    /// The tags after the update of `current`.
+    fn apply(&self, mut current: TagMap) -> TagMap {
+        if self.replace {
+            current.clear();
+        }
+        current.retain(|name, _| !self.remove.contains(name));
+        current.extend(self.set.clone());
+        current
+    }
+}
+
+/// The parts of any resource needed to update its tags.
+#[derive(Debug, Deserialize)]
+struct TaggedResource {
+    #[serde(default)]
+    etag: Option<String>,
+    #[serde(default)]
+    tags: Option<serde_json::Value>,
+}
+
+impl TaggedResource {
+    /// The `ETag` of the resource, from the headers or else the body of the response.
+    fn etag(&self, headers: &Headers) -> Option<String> {
+        headers.get_optional_string(&ETAG).or_else(|| self.etag.clone())
+    }
+}
+
+fn is_precondition_failed(error: &Error) -> bool {
+    matches!(
+        error.kind(),
+        ErrorKind::HttpResponse {
+            status: StatusCode::PreconditionFailed,
+            ..
+        }
+    )
+}
+
+fn to_tags(tags: &TagMap) -> models::Tags {
+    models::Tags {
+        tags: Some(serde_json::Value::Object(
+            tags.iter()
+                .map(|(name, value)| (name.clone(), serde_json::Value::String(value.clone())))
+                .collect(),
+        )),
+    }
+}
+
+fn from_tags(tags: Option<&serde_json::Value>) -> azure_core::Result<TagMap> {
+    match tags {
+        None | Some(serde_json::Value::Null) => Ok(TagMap::new()),
+        Some(serde_json::Value::Object(tags)) => Ok(tags
+            .iter()
+            .map(|(name, value)| match value {
+                serde_json::Value::String(value) => (name.clone(), value.clone()),
+                value => (name.clone(), value.to_string()),
+            })
+            .collect()),
+        Some(tags) => Err(Error::with_message(ErrorKind::DataConversion, || format!("invalid tags: {tags}"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use azure_identity::AzureCliCredential;
+    use serde_json::json;
+    use std::sync::Arc;
+
+    fn builder() -> UpdateTagsBuilder {
+        Client::builder(Arc::new(AzureCliCredential::new()))
+            .build()
+            .update_tags("/subscriptions/s/resourceGroups/rg")
+    }
+
+    fn tag_map(tags: &[(&str, &str)]) -> TagMap {
+        tags.iter().map(|(name, value)| ((*name).to_owned(), (*value).to_owned())).collect()
+    }
+
+    #[test]
+    fn apply_sets_and_removes_tags() {
+        let current = tag_map(&[("environment", "test"), ("temporary", "yes"), ("owner", "a")]);
+        let builder = builder().tag("environment", "production").remove_tag("temporary");
+        assert_eq!(
+            builder.apply(current.clone()),
+            tag_map(&[("environment", "production"), ("owner", "a")])
+        );
+        assert_eq!(builder.replace_all(true).apply(current), tag_map(&[("environment", "production")]));
+    }
+
+    #[test]
+    fn the_last_change_of_a_tag_wins() {
+        let builder = builder()
+            .remove_tag("owner")
+            .tag("owner", "b")
+            .tag("temporary", "yes")
+            .remove_tag("temporary");
+        assert_eq!(builder.apply(tag_map(&[("owner", "a")])), tag_map(&[("owner", "b")]));
+    }
+
+    #[test]
+    fn the_tags_api_patches_do_not_overwrite_other_tags() -> azure_core::Result<()> {
+        let patches = |builder: UpdateTagsBuilder| {
+            builder
+                .tags_api_patches()
+                .iter()
+                .map(serde_json::to_value)
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        assert_eq!(
+            patches(builder().tag("environment", "production"))?,
+            vec![json!({"operation": "Merge", "properties": {"tags": {"environment": "production"}}})]
+        );
+        assert_eq!(
+            patches(builder().remove_tag("temporary").tag("environment", "production"))?,
+            vec![
+                json!({"operation": "Delete", "properties": {"tags": {"temporary": ""}}}),
+                json!({"operation": "Merge", "properties": {"tags": {"environment": "production"}}}),
+            ]
+        );
+        assert_eq!(
+            patches(builder().remove_tag("temporary"))?,
+            vec![json!({"operation": "Delete", "properties": {"tags": {"temporary": ""}}})]
+        );
+        assert_eq!(
+            patches(builder().tag("environment", "production").remove_tag("temporary").replace_all(true))?,
+            vec![json!({"operation": "Replace", "properties": {"tags": {"environment": "production"}}})]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn from_tags_reads_the_tags_of_a_resource() -> azure_core::Result<()> {
+        assert_eq!(from_tags(None)?, TagMap::new());
+        assert_eq!(from_tags(Some(&json!(null)))?, TagMap::new());
+        assert_eq!(
+            from_tags(Some(&json!({"environment": "production", "count": 2})))?,
+            tag_map(&[("count", "2"), ("environment", "production")])
+        );
+        let error = from_tags(Some(&json!(["environment"]))).unwrap_err();
+        assert_eq!(error.kind(), &ErrorKind::DataConversion);
+        Ok(())
+    }
+
+    #[test]
+    fn to_tags_is_the_body_of_a_resource_patch() -> azure_core::Result<()> {
+        let tags = tag_map(&[("environment", "production")]);
+        assert_eq!(
+            serde_json::to_value(to_tags(&tags))?,
+            json!({"tags": {"environment": "production"}})
+        );
+        assert_eq!(from_tags(to_tags(&tags).tags.as_ref())?, tags);
+        Ok(())
+    }
+
+    #[test]
+    fn prefers_the_etag_header() -> azure_core::Result<()> {
+        let resource: TaggedResource = serde_json::from_value(json!({"etag": "\"body\"", "tags": {}}))?;
+        let mut headers = Headers::new();
+        assert_eq!(resource.etag(&headers).as_deref(), Some("\"body\""));
+        headers.insert(ETAG, "\"header\"");
+        assert_eq!(resource.etag(&headers).as_deref(), Some("\"header\""));
+        Ok(())
+    }
+}
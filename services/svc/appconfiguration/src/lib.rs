@@ -7,3 +7,5 @@
 pub mod package_2019_07;
 #[cfg(all(feature = "package-2019-07", not(feature = "no-default-tag")))]
 pub use package_2019_07::*;
+#[cfg(feature = "package-2019-07")]
+pub mod transfer;
@@ -0,0 +1,511 @@
+//! Exporting and importing key-values in bulk.
+//!
+//! The documents are the ones of `az appconfig kv export` and `az appconfig kv import`: a JSON
+//! object or a properties file mapping the keys to their values, without labels. The label is
+//! chosen when exporting, by filtering the key-values, and when importing, by applying it to all
+//! the key-values of the document.
+//!
+//! This module is not generated: it is listed in the `modules` of `autorust.toml`, so that it is
+//! kept when the crate is generated again.
+
+use crate::package_2019_07::{models, Client};
+use azure_core::error::{Error, ErrorKind};
+use azure_core::StatusCode;
+use futures::StreamExt;
+use std::collections::BTreeMap;
+
+/// The format of an exported document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentFormat {
+    /// A JSON object, e.g. `{"App:Color": "blue"}`. When importing, nested objects are flattened,
+    /// their keys joined with the separator of the import.
+    Json,
+    /// A Java properties file, e.g. `App:Color=blue`.
+    Properties,
+}
+
+/// What an import does with the key-values which already exist with the same key and label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictStrategy {
+    /// The existing key-values are kept.
+    Skip,
+    /// The existing key-values are replaced.
+    Overwrite,
+}
+
+/// The outcome of an import.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportSummary {
+    /// The key-values written.
+    pub imported: usize,
+    /// The keys of the key-values which existed and were kept.
+    pub skipped: Vec<String>,
+}
+
+impl Client {
+    /// Exports the key-values matching the filters to a document.
+    pub fn export_key_values(&self, format: DocumentFormat) -> ExportKeyValuesBuilder {
+        ExportKeyValuesBuilder {
+            client: self.clone(),
+            format,
+            key: None,
+            label: None,
+            trim_prefix: None,
+        }
+    }
+
+    /// Imports the key-values of a document.
+    ///
+    /// Arguments:
+    /// * `document`: The content of a document of the given format.
+    pub fn import_key_values(&self, document: impl Into<String>, format: DocumentFormat) -> ImportKeyValuesBuilder {
+        ImportKeyValuesBuilder {
+            client: self.clone(),
+            document: document.into(),
+            format,
+            label: None,
+            prefix: None,
+            separator: ":".to_owned(),
+            content_type: None,
+            conflict_strategy: ConflictStrategy::Skip,
+        }
+    }
+}
+
+/// Exports key-values to a document.
+#[derive(Clone)]
+pub struct ExportKeyValuesBuilder {
+    client: Client,
+    format: DocumentFormat,
+    key: Option<String>,
+    label: Option<String>,
+    trim_prefix: Option<String>,
+}
+
+impl ExportKeyValuesBuilder {
+    /// A filter of the keys, e.g. `App:*`. All the keys are exported by default.
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    /// A filter of the labels, e.g. `prod`, or `\0` for the key-values without a label, which are
+    /// the ones exported by default.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// A prefix removed from the exported keys, e.g. `App:`.
+    pub fn trim_prefix(mut self, trim_prefix: impl Into<String>) -> Self {
+        self.trim_prefix = Some(trim_prefix.into());
+        self
+    }
+
+    /// Exports the key-values and returns the document.
+    ///
+    /// Fails if the filters match a key with more than one label, which a document cannot hold.
+    pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<String>> {
+        Box::pin(async move {
+            let mut request = self.client.get_key_values();
+            if let Some(key) = &self.key {
+                request = request.key(key);
+            }
+            request = request.label(self.label.clone().unwrap_or_else(|| "\0".to_owned()));
+
+            let mut key_values = BTreeMap::new();
+            let mut pages = request.into_stream();
+            while let Some(page) = pages.next().await {
+                for key_value in page?.items {
+                    let key = key_value.key.unwrap_or_default();
+                    let key = match &self.trim_prefix {
+                        Some(prefix) => key.strip_prefix(prefix.as_str()).unwrap_or(&key).to_owned(),
+                        None => key,
+                    };
+                    if key_values.insert(key.clone(), key_value.value.unwrap_or_default()).is_some() {
+                        return Err(Error::with_message(ErrorKind::DataConversion, || {
+                            format!("the key {key} is exported more than once, filter a single label")
+                        }));
+                    }
+                }
+            }
+            to_document(&key_values, self.format)
+        })
+    }
+}
+
+/// Imports key-values from a document.
+#[derive(Clone)]
+pub struct ImportKeyValuesBuilder {
+    client: Client,
+    document: String,
+    format: DocumentFormat,
+    label: Option<String>,
+    prefix: Option<String>,
+    separator: String,
+    content_type: Option<String>,
+    conflict_strategy: ConflictStrategy,
+}
+
+impl ImportKeyValuesBuilder {
+    /// The label of the imported key-values. They have no label by default.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// A prefix added to the imported keys, e.g. `App:`.
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// The separator joining the keys of the nested objects of a JSON document. Defaults to `:`.
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// The content type of the imported key-values.
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+
+    /// What to do with the key-values which already exist. Defaults to [`ConflictStrategy::Skip`].
+    pub fn conflict_strategy(mut self, conflict_strategy: ConflictStrategy) -> Self {
+        self.conflict_strategy = conflict_strategy;
+        self
+    }
+
+    /// Imports the key-values, one at a time.
+    ///
+    /// The document is parsed before any key-value is written. If a write fails, the key-values
+    /// of the document before it were imported.
+    pub fn send(self) -> futures::future::BoxFuture<'static, azure_core::Result<ImportSummary>> {
+        Box::pin(async move {
+            let key_values = from_document(&self.document, self.format, &self.separator)?;
+            let mut summary = ImportSummary::default();
+            for (key, value) in key_values {
+                let key = format!("{}{}", self.prefix.as_deref().unwrap_or_default(), key);
+                let entity = models::KeyValue {
+                    key: Some(key.clone()),
+                    label: self.label.clone(),
+                    content_type: self.content_type.clone(),
+                    value: Some(value),
+                    ..models::KeyValue::new()
+                };
+                let mut request = self.client.put_key_value(key.clone()).entity(entity);
+                if let Some(label) = &self.label {
+                    request = request.label(label);
+                }
+                if self.conflict_strategy == ConflictStrategy::Skip {
+                    // written only if it does not exist
+                    request = request.if_none_match("*");
+                }
+                match request.send().await {
+                    Ok(_) => summary.imported += 1,
+                    Err(error) if is_precondition_failed(&error) => summary.skipped.push(key),
+                    Err(error) => return Err(error),
+                }
+            }
+            Ok(summary)
+        })
+    }
+}
+
+fn is_precondition_failed(error: &Error) -> bool {
+    matches!(
+        error.kind(),
+        ErrorKind::HttpResponse {
+            status: StatusCode::PreconditionFailed,
+            ..
+        }
+    )
+}
+
+/// Writes the key-values to a document.
+pub fn to_document(key_values: &BTreeMap<String, String>, format: DocumentFormat) -> azure_core::Result<String> {
+    match format {
+        DocumentFormat::Json => Ok(serde_json::to_string_pretty(key_values)?),
+        DocumentFormat::Properties => Ok(key_values
+            .iter()
+            .map(|(key, value)| format!("{}={}\n", escape_property(key, true), escape_property(value, false)))
+            .collect()),
+    }
+}
+
+/// Reads the key-values of a document. The keys of the nested objects of a JSON document are
+/// joined with `separator`.
+pub fn from_document(document: &str, format: DocumentFormat, separator: &str) -> azure_core::Result<Vec<(String, String)>> {
+    match format {
+        DocumentFormat::Json => {
+            let document: serde_json::Value = serde_json::from_str(document)?;
+            let object = match document {
+                serde_json::Value::Object(object) => object,
+                _ => return Err(Error::message(ErrorKind::DataConversion, "the JSON document is not an object")),
+            };
+            let mut key_values = Vec::new();
+            flatten_json(String::new(), object, separator, &mut key_values);
+            Ok(key_values)
+        }
+        DocumentFormat::Properties => parse_properties(document),
+    }
+}
+
+fn flatten_json(
+    prefix: String,
+    object: serde_json::Map<String, serde_json::Value>,
+    separator: &str,
+    key_values: &mut Vec<(String, String)>,
+) {
+    for (key, value) in object {
+        let key = if prefix.is_empty() {
+            key
+        } else {
+            format!("{prefix}{separator}{key}")
+        };
+        match value {
+            serde_json::Value::Object(object) => flatten_json(key, object, separator, key_values),
+            serde_json::Value::String(value) => key_values.push((key, value)),
+            // stored as their JSON text, e.g. `true` or `[1,2]`
+            value => key_values.push((key, value.to_string())),
+        }
+    }
+}
+
+fn escape_property(s: &str, is_key: bool) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for (i, c) in s.chars().enumerate() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            '=' | ':' if is_key => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '#' | '!' if is_key && i == 0 => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            ' ' if is_key || i == 0 => escaped.push_str("\\ "),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn parse_properties(document: &str) -> azure_core::Result<Vec<(String, String)>> {
+    let mut key_values = Vec::new();
+    let mut lines = document.lines();
+    while let Some(line) = lines.next() {
+        let mut line = line.trim_start().to_owned();
+        if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+            continue;
+        }
+        // a line ending with an odd number of backslashes continues on the next one
+        while line.chars().rev().take_while(|c| *c == '\\').count() % 2 == 1 {
+            line.pop();
+            match lines.next() {
+                Some(next) => line.push_str(next.trim_start()),
+                None => break,
+            }
+        }
+
+        let mut key = String::new();
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => key.push(unescape(&mut chars)?),
+                '=' | ':' => break,
+                c if c.is_whitespace() => {
+                    // the key ends at a whitespace, optionally followed by a separator
+                    while chars.peek().map_or(false, |c| c.is_whitespace()) {
+                        chars.next();
+                    }
+                    if matches!(chars.peek(), Some('=') | Some(':')) {
+                        chars.next();
+                    }
+                    break;
+                }
+                c => key.push(c),
+            }
+        }
+        while chars.peek().map_or(false, |c| c.is_whitespace()) {
+            chars.next();
+        }
+        let mut value = String::new();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => value.push(unescape(&mut chars)?),
+                c => value.push(c),
+            }
+        }
+        key_values.push((key, value));
+    }
+    Ok(key_values)
+}
+
+fn unescape(chars: &mut impl Iterator<Item = char>) -> azure_core::Result<char> {
+    match chars.next() {
+        Some('n') => Ok('\n'),
+        Some('r') => Ok('\r'),
+        Some('t') => Ok('\t'),
+        Some('f') => Ok('\u{c}'),
+        Some('u') => {
+            let mut code = unescape_code_unit(chars)?;
+            // the characters outside of the basic multilingual plane are escaped as surrogate pairs
+            if (0xD800..0xDC00).contains(&code) {
+                let low = match (chars.next(), chars.next()) {
+                    (Some('\\'), Some('u')) => unescape_code_unit(chars)?,
+                    _ => return Err(Error::message(ErrorKind::DataConversion, "unpaired surrogate escape")),
+                };
+                if !(0xDC00..0xE000).contains(&low) {
+                    return Err(Error::message(ErrorKind::DataConversion, "unpaired surrogate escape"));
+                }
+                code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+            }
+            char::from_u32(code).ok_or_else(|| Error::with_message(ErrorKind::DataConversion, || format!("invalid escape of {code:#x}")))
+        }
+        Some(c) => Ok(c),
+        None => Ok('\\'),
+    }
+}
+
+/// The four hexadecimal digits following `\u`.
+fn unescape_code_unit(chars: &mut impl Iterator<Item = char>) -> azure_core::Result<u32> {
+    let code: String = chars.take(4).collect();
+    if code.len() != 4 || !code.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(Error::with_message(ErrorKind::DataConversion, || {
+            format!("invalid escape \\u{code}")
+        }));
+    }
+    u32::from_str_radix(&code, 16).map_err(|error| Error::full(ErrorKind::DataConversion, error, format!("invalid escape \\u{code}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key_values(key_values: &[(&str, &str)]) -> Vec<(String, String)> {
+        key_values
+            .iter()
+            .map(|(key, value)| ((*key).to_owned(), (*value).to_owned()))
+            .collect()
+    }
+
+    fn properties(document: &str) -> Vec<(String, String)> {
+        from_document(document, DocumentFormat::Properties, ":").unwrap()
+    }
+
+    #[test]
+    fn properties_round_trip() -> azure_core::Result<()> {
+        let expected = key_values(&[
+            ("", "empty key"),
+            ("!bang", "not a comment"),
+            ("#hash", "not a comment either"),
+            (" leading space", "  leading spaces"),
+            ("App:Color", "blue"),
+            ("a=b", "c=d:e"),
+            ("key with spaces", "value with trailing space "),
+            ("multi", "line one\nline two\r\n\ttabbed"),
+            ("path", "C:\\temp\\"),
+            ("unicode", "café ☕ 😀"),
+            ("x", ""),
+        ]);
+        let map: BTreeMap<String, String> = expected.into_iter().collect();
+        let document = to_document(&map, DocumentFormat::Properties)?;
+        assert_eq!(
+            from_document(&document, DocumentFormat::Properties, ":")?,
+            map.into_iter().collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn escape_property_escapes_separators_of_keys_only() {
+        assert_eq!(escape_property("a b=c:d#!", true), "a\\ b\\=c\\:d#!");
+        assert_eq!(escape_property("#a", true), "\\#a");
+        assert_eq!(escape_property("!a", true), "\\!a");
+        assert_eq!(escape_property(" a b=c:d", false), "\\ a b=c:d");
+        assert_eq!(escape_property("\\\n\r\t", false), "\\\\\\n\\r\\t");
+    }
+
+    #[test]
+    fn parse_properties_separators_and_comments() {
+        let document = "# a comment\n! another one\n\n  indented = 1\nspaced   2\ncolon:3\nmixed \t: 4\nempty\n";
+        assert_eq!(
+            properties(document),
+            key_values(&[("indented", "1"), ("spaced", "2"), ("colon", "3"), ("mixed", "4"), ("empty", "")])
+        );
+    }
+
+    #[test]
+    fn parse_properties_continuation_lines() {
+        let document =
+            "fruits = apple, \\\n    banana, \\\r\n    cherry\nnext=\\\n# not a comment\nescaped=ends with a backslash\\\\\nlast=\\";
+        assert_eq!(
+            properties(document),
+            key_values(&[
+                ("fruits", "apple, banana, cherry"),
+                ("next", "# not a comment"),
+                ("escaped", "ends with a backslash\\"),
+                ("last", ""),
+            ])
+        );
+    }
+
+    #[test]
+    fn unescape_unicode() -> azure_core::Result<()> {
+        assert_eq!(properties("caf\\u00e9=\\u2615 \\uD83D\\uDE00"), key_values(&[("café", "☕ 😀")]));
+        assert_eq!(unescape(&mut "u00E9".chars())?, 'é');
+        assert_eq!(unescape(&mut "f".chars())?, '\u{c}');
+        assert_eq!(unescape(&mut "q".chars())?, 'q');
+        assert_eq!(unescape(&mut "".chars())?, '\\');
+        for invalid in ["u12", "u12g4", "u+123", "uD83D", "uD83Dx", "uD83D\\u0041"] {
+            let error = unescape(&mut invalid.chars()).unwrap_err();
+            assert_eq!(error.kind(), &ErrorKind::DataConversion, "{invalid}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn flatten_json_joins_nested_keys() -> azure_core::Result<()> {
+        let document = r#"{
+            "App": {"Color": "blue", "Size": {"Width": 3, "Empty": {}}},
+            "Flag": true,
+            "List": [1, "two"],
+            "Null": null,
+            "Text": "a \"quoted\" \u00e9"
+        }"#;
+        let mut actual = from_document(document, DocumentFormat::Json, "/")?;
+        actual.sort();
+        assert_eq!(
+            actual,
+            key_values(&[
+                ("App/Color", "blue"),
+                ("App/Size/Width", "3"),
+                ("Flag", "true"),
+                ("List", "[1,\"two\"]"),
+                ("Null", "null"),
+                ("Text", "a \"quoted\" é"),
+            ])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn json_round_trip() -> azure_core::Result<()> {
+        let expected = key_values(&[("App:Color", "blue"), ("multi", "line\none"), ("unicode", "😀")]);
+        let map: BTreeMap<String, String> = expected.iter().cloned().collect();
+        let document = to_document(&map, DocumentFormat::Json)?;
+        assert_eq!(from_document(&document, DocumentFormat::Json, ":")?, expected);
+        assert_eq!(
+            from_document("[1]", DocumentFormat::Json, ":").unwrap_err().kind(),
+            &ErrorKind::DataConversion
+        );
+        Ok(())
+    }
+}